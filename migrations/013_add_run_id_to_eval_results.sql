@@ -0,0 +1,7 @@
+-- Offline golden-dataset runs tag every result with the run that produced it
+-- so regressions can be compared run-over-run. NULL for passive outbox results.
+ALTER TABLE eval_results
+    ADD COLUMN IF NOT EXISTS run_id UUID DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_eval_results_run_id ON eval_results(run_id)
+    WHERE run_id IS NOT NULL;
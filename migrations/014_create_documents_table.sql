@@ -0,0 +1,17 @@
+CREATE TABLE IF NOT EXISTS documents (
+    id             UUID PRIMARY KEY,
+    filename       TEXT NOT NULL,
+    content_type   TEXT NOT NULL,
+    size_bytes     BIGINT NOT NULL,
+    content_hash   TEXT,
+    chunk_count    INTEGER NOT NULL DEFAULT 0,
+    source_url     TEXT,
+    storage_path   TEXT NOT NULL,
+    staged_upload  BOOLEAN NOT NULL DEFAULT false,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ingested_at    TIMESTAMPTZ
+);
+
+-- Catalog listing is newest-first
+CREATE INDEX idx_documents_created_at ON documents(created_at DESC);
+CREATE INDEX idx_documents_content_hash ON documents(content_hash);
@@ -0,0 +1,6 @@
+-- Identical content maps to exactly one catalog entry
+DROP INDEX IF EXISTS idx_documents_content_hash;
+CREATE UNIQUE INDEX idx_documents_content_hash ON documents(content_hash) WHERE content_hash IS NOT NULL;
+
+-- Same-filename lookups pick the most recent entry
+CREATE INDEX idx_documents_filename ON documents(filename, created_at DESC);
@@ -0,0 +1,18 @@
+-- Tenant isolation: every scoped row belongs to exactly one tenant.
+-- Rows created before tenancy existed are assigned to the default tenant.
+ALTER TABLE documents ADD COLUMN tenant_id TEXT NOT NULL DEFAULT 'default';
+ALTER TABLE jobs ADD COLUMN tenant_id TEXT NOT NULL DEFAULT 'default';
+ALTER TABLE conversations ADD COLUMN tenant_id TEXT NOT NULL DEFAULT 'default';
+ALTER TABLE eval_events ADD COLUMN tenant_id TEXT NOT NULL DEFAULT 'default';
+
+CREATE INDEX idx_documents_tenant_created_at ON documents(tenant_id, created_at DESC);
+CREATE INDEX idx_jobs_tenant_id ON jobs(tenant_id);
+CREATE INDEX idx_conversations_tenant_id ON conversations(tenant_id);
+CREATE INDEX idx_eval_events_tenant_id ON eval_events(tenant_id);
+
+-- Deduplication and same-filename replacement are per tenant
+DROP INDEX IF EXISTS idx_documents_content_hash;
+CREATE UNIQUE INDEX idx_documents_content_hash ON documents(tenant_id, content_hash) WHERE content_hash IS NOT NULL;
+
+DROP INDEX IF EXISTS idx_documents_filename;
+CREATE INDEX idx_documents_filename ON documents(tenant_id, filename, created_at DESC);
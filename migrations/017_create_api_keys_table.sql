@@ -0,0 +1,16 @@
+-- API keys: only the SHA-256 of the secret is stored.
+CREATE TABLE api_keys (
+    id UUID PRIMARY KEY,
+    name TEXT NOT NULL,
+    tenant_id TEXT NOT NULL,
+    scopes TEXT[] NOT NULL,
+    key_prefix TEXT NOT NULL,
+    key_hash TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_api_keys_tenant_id ON api_keys(tenant_id);
+
+-- Authenticated caller that produced the event (e.g. api_key:<uuid>)
+ALTER TABLE eval_events ADD COLUMN principal TEXT;
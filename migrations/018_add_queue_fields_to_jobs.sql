@@ -0,0 +1,9 @@
+-- The jobs table is the durable ingestion queue. The payload holds everything a worker needs
+-- to rebuild the run after a restart; the lease marks which worker holds an in-flight job and
+-- when it is presumed dead. Jobs created before this migration have no payload.
+ALTER TABLE jobs ADD COLUMN payload JSONB;
+ALTER TABLE jobs ADD COLUMN attempts INT NOT NULL DEFAULT 0;
+ALTER TABLE jobs ADD COLUMN lease_owner TEXT;
+ALTER TABLE jobs ADD COLUMN lease_expires_at TIMESTAMPTZ;
+
+CREATE INDEX idx_jobs_queued ON jobs(created_at) WHERE status = 'QUEUED';
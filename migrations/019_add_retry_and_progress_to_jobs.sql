@@ -0,0 +1,6 @@
+-- Retries wait out their backoff in the queue: a job is claimable once next_attempt_at has
+-- passed. cancel_requested is how the API stops an in-flight job; its worker sees the flag on
+-- the next lease renewal. progress holds the counters a worker reports while it runs.
+ALTER TABLE jobs ADD COLUMN next_attempt_at TIMESTAMPTZ;
+ALTER TABLE jobs ADD COLUMN cancel_requested BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE jobs ADD COLUMN progress JSONB NOT NULL DEFAULT '{}'::jsonb;
@@ -0,0 +1,2 @@
+-- Job listings page through one tenant's jobs newest first.
+CREATE INDEX idx_jobs_tenant_created_at ON jobs(tenant_id, created_at DESC);
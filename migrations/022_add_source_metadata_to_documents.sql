@@ -0,0 +1,6 @@
+-- Source metadata supplied by the caller at ingestion. It lives on the catalog row so a
+-- re-ingestion writes it to the chunk payload again.
+ALTER TABLE documents ADD COLUMN title TEXT;
+ALTER TABLE documents ADD COLUMN author TEXT;
+ALTER TABLE documents ADD COLUMN tags TEXT[] NOT NULL DEFAULT '{}';
+ALTER TABLE documents ADD COLUMN attributes JSONB NOT NULL DEFAULT '{}'::jsonb;
@@ -0,0 +1,3 @@
+-- URLs a web crawl job could not ingest, as [{"url": ..., "error": ...}]. Reset whenever the
+-- job is claimed, so the list always describes the latest attempt.
+ALTER TABLE jobs ADD COLUMN fetch_failures JSONB NOT NULL DEFAULT '[]'::jsonb;
@@ -0,0 +1,3 @@
+-- Caption file (SRT or WebVTT) that replaces transcription of an audio or video document.
+-- Kept on the catalog row so a re-ingestion reads the captions again.
+ALTER TABLE documents ADD COLUMN captions_path TEXT;
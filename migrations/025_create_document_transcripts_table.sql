@@ -0,0 +1,7 @@
+-- Full transcript of an audio, video or caption document, one row per document.
+-- segments is an array of {"start_time", "end_time", "text"} objects in playback order.
+CREATE TABLE document_transcripts (
+    document_id UUID PRIMARY KEY REFERENCES documents(id) ON DELETE CASCADE,
+    segments JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
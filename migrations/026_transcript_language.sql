@@ -0,0 +1,3 @@
+-- Spoken language of a transcribed document, as an ISO 639-1 code, when it was configured
+-- or detected. Segments may now carry "words": [{"start_time", "end_time", "text"}].
+ALTER TABLE document_transcripts ADD COLUMN language TEXT;
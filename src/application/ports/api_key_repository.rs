@@ -0,0 +1,23 @@
+use async_trait::async_trait;
+
+use super::RepositoryError;
+use crate::domain::{ApiKey, ApiKeyId, TenantId};
+
+#[async_trait]
+pub trait ApiKeyRepository: Send + Sync {
+    async fn create(&self, key: &ApiKey) -> Result<(), RepositoryError>;
+
+    /// Non-revoked key whose secret hashes to `key_hash`.
+    async fn find_active_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError>;
+
+    /// Keys of one tenant (or of every tenant when `None`), newest first, including revoked ones.
+    async fn list(&self, tenant_id: Option<&TenantId>) -> Result<Vec<ApiKey>, RepositoryError>;
+
+    /// Marks the key revoked, if it belongs to `tenant_id` (any tenant when `None`). Returns
+    /// `false` when no such key exists or it was already revoked.
+    async fn revoke(
+        &self,
+        id: ApiKeyId,
+        tenant_id: Option<&TenantId>,
+    ) -> Result<bool, RepositoryError>;
+}
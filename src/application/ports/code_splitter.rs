@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use super::TextSplitterError;
+use crate::domain::{Chunk, DocumentId, DocumentMetadata};
+
+/// Language-aware chunking of source files.
+#[async_trait]
+pub trait CodeSplitter: Send + Sync {
+    /// Splits `source` along syntactic boundaries (functions, impls, classes). The language
+    /// is detected from `path`, and every chunk carries a [`CodeSpan`](crate::domain::CodeSpan)
+    /// with `path`, the enclosing symbol and the chunk's line range.
+    async fn split_code(
+        &self,
+        source: &str,
+        path: &str,
+        document_id: DocumentId,
+        metadata: Option<Arc<DocumentMetadata>>,
+    ) -> Result<Vec<Chunk>, TextSplitterError>;
+}
@@ -26,6 +26,22 @@ impl CollectionConfig {
                     field_name: "tenant_id".to_string(),
                     field_type: PayloadFieldType::Keyword,
                 },
+                PayloadIndex {
+                    field_name: "content_type".to_string(),
+                    field_type: PayloadFieldType::Keyword,
+                },
+                PayloadIndex {
+                    field_name: "title".to_string(),
+                    field_type: PayloadFieldType::Keyword,
+                },
+                PayloadIndex {
+                    field_name: "tags".to_string(),
+                    field_type: PayloadFieldType::Keyword,
+                },
+                PayloadIndex {
+                    field_name: "ingested_at".to_string(),
+                    field_type: PayloadFieldType::Integer,
+                },
             ],
             hybrid: false,
         }
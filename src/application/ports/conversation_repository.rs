@@ -1,4 +1,4 @@
-use crate::domain::{Conversation, ConversationId, Message};
+use crate::domain::{Conversation, ConversationId, Message, TenantId};
 use async_trait::async_trait;
 
 use super::RepositoryError;
@@ -13,6 +13,12 @@ pub trait ConversationRepository: Send + Sync {
         id: ConversationId,
     ) -> Result<Option<Conversation>, RepositoryError>;
 
+    /// Owner of the conversation without loading its messages; `None` when it does not exist.
+    async fn get_conversation_tenant(
+        &self,
+        id: ConversationId,
+    ) -> Result<Option<TenantId>, RepositoryError>;
+
     async fn append_message(&self, message: &Message) -> Result<(), RepositoryError>;
 
     async fn get_messages(
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+
+use super::RepositoryError;
+use crate::domain::{DocumentId, DocumentRecord, TenantId};
+
+#[async_trait]
+pub trait DocumentRepository: Send + Sync {
+    async fn create(&self, document: &DocumentRecord) -> Result<(), RepositoryError>;
+
+    async fn get_by_id(&self, id: DocumentId) -> Result<Option<DocumentRecord>, RepositoryError>;
+
+    async fn find_by_content_hash(
+        &self,
+        tenant_id: &TenantId,
+        content_hash: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError>;
+
+    /// Most recently created document with this filename within the tenant.
+    async fn find_by_filename(
+        &self,
+        tenant_id: &TenantId,
+        filename: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError>;
+
+    /// Points an existing catalog entry at a new source (filename, type, size, hash, path and
+    /// source metadata).
+    async fn update_source(&self, document: &DocumentRecord) -> Result<(), RepositoryError>;
+
+    /// The tenant's documents, newest first.
+    async fn list(
+        &self,
+        tenant_id: &TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError>;
+
+    /// The tenant's documents whose filename starts with `prefix`, oldest first.
+    async fn list_by_filename_prefix(
+        &self,
+        tenant_id: &TenantId,
+        prefix: &str,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError>;
+
+    /// Records a completed ingestion. `NotFound` when the document was deleted meanwhile.
+    async fn mark_ingested(
+        &self,
+        id: DocumentId,
+        chunk_count: usize,
+        content_hash: &str,
+    ) -> Result<(), RepositoryError>;
+
+    async fn delete(&self, id: DocumentId) -> Result<(), RepositoryError>;
+}
@@ -1,14 +1,16 @@
 use async_trait::async_trait;
 
-use crate::domain::Document;
+use crate::domain::{Document, PageSegment};
 
 #[async_trait]
 pub trait FileLoader: Send + Sync {
-    async fn extract_text(
+    /// Extracts the document's text in reading order: one segment per page for paginated
+    /// formats, a single unpaged segment otherwise.
+    async fn extract_pages(
         &self,
         data: &[u8],
         document: &Document,
-    ) -> Result<String, FileLoaderError>;
+    ) -> Result<Vec<PageSegment>, FileLoaderError>;
 }
 
 #[derive(Debug, thiserror::Error)]
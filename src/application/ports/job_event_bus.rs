@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+use crate::domain::JobEvent;
+
+/// Fans job status and progress changes out to live subscribers (SSE clients). Delivery is
+/// best effort: the job row stays the source of truth, so publish failures are logged by the
+/// adapter rather than failing the job, and a lagging subscriber may miss events.
+#[async_trait]
+pub trait JobEventBus: Send + Sync {
+    async fn publish(&self, event: &JobEvent);
+
+    /// Events published from now on, by this process or any worker sharing the adapter's
+    /// transport.
+    fn subscribe(&self) -> broadcast::Receiver<JobEvent>;
+}
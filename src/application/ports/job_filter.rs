@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::{JobStatus, TenantId};
+
+/// Restricts a job listing. Populated fields are combined with AND; `tenant_id` is never
+/// optional, so listings stay confined to one tenant.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JobFilter {
+    pub tenant_id: TenantId,
+    pub status: Option<JobStatus>,
+    pub job_type: Option<String>,
+    /// Inclusive lower bound on the job's creation time.
+    pub created_after: Option<DateTime<Utc>>,
+    /// Inclusive upper bound on the job's creation time.
+    pub created_before: Option<DateTime<Utc>>,
+}
+
+impl JobFilter {
+    pub fn for_tenant(tenant_id: TenantId) -> Self {
+        Self {
+            tenant_id,
+            ..Self::default()
+        }
+    }
+}
@@ -1,8 +1,25 @@
-use crate::domain::{Job, JobId, JobStatus};
+use std::time::Duration;
+
+use crate::domain::{DocumentId, FetchFailure, Job, JobId, JobProgress, JobStatus};
 use async_trait::async_trait;
 
-use super::RepositoryError;
+use super::{JobFilter, RepositoryError};
+
+/// Error message recorded on jobs stopped through cancellation.
+pub const JOB_CANCELLED: &str = "cancelled on request";
+
+/// Outcome of a worker's lease heartbeat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaseRenewal {
+    Renewed,
+    /// Renewed, but cancellation was requested; the worker should stop the job.
+    CancelRequested,
+    /// The worker no longer holds the job (it finished or was released to another worker).
+    Lost,
+}
 
+/// Job lifecycle and the durable ingestion queue: a `QUEUED` row with a payload is a pending
+/// unit of work, claimed by exactly one worker under a renewable lease.
 #[async_trait]
 pub trait JobRepository: Send + Sync {
     async fn create(&self, job: &Job) -> Result<(), RepositoryError>;
@@ -16,5 +33,86 @@ pub trait JobRepository: Send + Sync {
         error_message: Option<&str>,
     ) -> Result<(), RepositoryError>;
 
+    /// Moves an in-flight job held by `worker_id` to `status`. Returns `false`, leaving the
+    /// job untouched, when the worker lost its lease, so a worker whose lease expired cannot
+    /// overwrite the outcome of the worker the job was released to.
+    async fn update_leased_status(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        status: JobStatus,
+        error_message: Option<&str>,
+    ) -> Result<bool, RepositoryError>;
+
     async fn list_by_status(&self, status: JobStatus) -> Result<Vec<Job>, RepositoryError>;
+
+    /// Jobs matching `filter`, newest first.
+    async fn list(
+        &self,
+        filter: &JobFilter,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Job>, RepositoryError>;
+
+    async fn find_latest_by_document(
+        &self,
+        document_id: DocumentId,
+    ) -> Result<Option<Job>, RepositoryError>;
+
+    /// Atomically takes the oldest queued job with a payload whose retry backoff has passed,
+    /// moving it to `PROCESSING` under a lease held by `worker_id` and resetting its progress
+    /// and fetch failures.
+    /// Concurrent callers never receive the same job.
+    async fn claim_next(
+        &self,
+        worker_id: &str,
+        lease: Duration,
+    ) -> Result<Option<Job>, RepositoryError>;
+
+    /// Extends the lease of an in-flight job held by `worker_id`.
+    async fn renew_lease(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        lease: Duration,
+    ) -> Result<LeaseRenewal, RepositoryError>;
+
+    /// Puts an in-flight job whose lease has expired (or that never had one) back in the
+    /// queue. Returns `false` when the job is not in flight or its lease is still live.
+    async fn release_expired(&self, id: JobId) -> Result<bool, RepositoryError>;
+
+    /// Re-queues a failed attempt of `worker_id` to be claimed after `delay`, keeping
+    /// `error_message` for inspection. Returns `false`, leaving the job untouched, when
+    /// cancellation was requested or the worker lost its lease.
+    async fn schedule_retry(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        delay: Duration,
+        error_message: &str,
+    ) -> Result<bool, RepositoryError>;
+
+    /// Records the progress of an in-flight job held by `worker_id`. Returns `false`,
+    /// leaving the job untouched, when the worker lost its lease.
+    async fn update_progress(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        progress: &JobProgress,
+    ) -> Result<bool, RepositoryError>;
+
+    /// Appends a URL a crawl job could not ingest. Claiming the job clears the list.
+    async fn record_fetch_failure(
+        &self,
+        id: JobId,
+        failure: &FetchFailure,
+    ) -> Result<(), RepositoryError>;
+
+    /// Cancels a queued job outright and flags an in-flight one for its worker. Returns the
+    /// resulting status, or `None` when the job is missing or already terminal.
+    async fn request_cancel(&self, id: JobId) -> Result<Option<JobStatus>, RepositoryError>;
+
+    /// Re-queues a failed or cancelled job with a fresh attempt budget. Returns `false` when
+    /// the job is missing, not in one of those statuses, or has no payload to run.
+    async fn retry(&self, id: JobId) -> Result<bool, RepositoryError>;
 }
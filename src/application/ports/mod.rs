@@ -1,53 +1,80 @@
 mod agent_message;
+mod api_key_repository;
+mod code_splitter;
 mod collection_config;
 mod conversation_repository;
 mod distance_metric;
+mod document_repository;
 mod embedder;
 mod eval_event_repository;
 mod eval_outbox_repository;
 mod eval_result_repository;
 mod file_loader;
+mod job_event_bus;
+mod job_filter;
 mod job_repository;
 mod llm_client;
 mod mcp_client_port;
 mod payload_field_type;
 mod payload_index;
+mod public_address;
 mod rag_source_collector;
 mod repository_error;
+mod reranker;
 mod retrieval_service_port;
+mod search_filter;
 mod search_result;
+mod source_tree_reader;
 mod sparse_embedder;
 mod staging_store;
 mod text_splitter;
 mod tool_registry;
+mod transcript_repository;
 mod transcription_engine;
 mod vector_store;
 mod vector_store_error;
+mod web_fetcher;
+mod webhook_repository;
+mod webhook_sender;
 
 pub use agent_message::AgentMessage;
+pub use api_key_repository::ApiKeyRepository;
+pub use code_splitter::CodeSplitter;
 pub use collection_config::CollectionConfig;
 pub use conversation_repository::ConversationRepository;
 pub use distance_metric::DistanceMetric;
+pub use document_repository::DocumentRepository;
 pub use embedder::{Embedder, EmbedderError};
 pub use eval_event_repository::{EvalEventError, EvalEventRepository};
 pub use eval_outbox_repository::{EvalOutboxError, EvalOutboxRepository};
 pub use eval_result_repository::{EvalResultError, EvalResultRepository};
 pub use file_loader::{FileLoader, FileLoaderError};
-pub use job_repository::JobRepository;
+pub use job_event_bus::JobEventBus;
+pub use job_filter::JobFilter;
+pub use job_repository::{JOB_CANCELLED, JobRepository, LeaseRenewal};
 pub use llm_client::{LlmClient, LlmClientError, LlmTokenStream, LlmToolResponse, ToolSchema};
 pub use mcp_client_port::{McpClientPort, McpError};
 pub use payload_field_type::PayloadFieldType;
 pub use payload_index::PayloadIndex;
+pub use public_address::{BlockedTarget, check_target, is_public_ip};
 pub use rag_source_collector::RagSourceCollector;
 pub use repository_error::RepositoryError;
+pub use reranker::{Reranker, RerankerError};
 pub use retrieval_service_port::{RetrievalError, RetrievalServicePort, SourceChunk};
+pub use search_filter::{SearchFilter, SearchFilterError};
 pub use search_result::SearchResult;
+pub use source_tree_reader::{SourceFile, SourceTreeError, SourceTreeReader};
 pub use sparse_embedder::SparseEmbedder;
 pub use staging_store::{StagingStore, StagingStoreError};
 pub use text_splitter::{TextSplitter, TextSplitterError};
 pub use tool_registry::ToolRegistry;
+pub use transcript_repository::TranscriptRepository;
 pub use transcription_engine::{
-    AudioDecoder, AudioDecoderError, TranscriptionEngine, TranscriptionError,
+    AudioDecoder, AudioDecoderError, PcmReader, TranscriptionEngine, TranscriptionError,
+    TranscriptionObserver, TranscriptionOptions,
 };
 pub use vector_store::VectorStore;
 pub use vector_store_error::VectorStoreError;
+pub use web_fetcher::{WebFetchError, WebFetcher, WebResource, host_in_domains};
+pub use webhook_repository::WebhookRepository;
+pub use webhook_sender::{WebhookSendError, WebhookSender};
@@ -0,0 +1,101 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use url::Url;
+
+use super::host_in_domains;
+
+/// An outbound request target refused because it is not on the public internet or leaves
+/// the allowed domains.
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct BlockedTarget(pub String);
+
+/// `true` for addresses routable on the public internet. Loopback, private, shared, link-local
+/// (which includes cloud metadata endpoints), documentation, benchmarking, multicast and
+/// reserved ranges are not, nor are IPv6 addresses embedding one of them.
+pub fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_v4(v4),
+        IpAddr::V6(v6) => is_public_v6(v6),
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, c, _] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || a == 0
+        // Shared address space (RFC 6598).
+        || (a == 100 && (64..128).contains(&b))
+        // IETF protocol assignments (RFC 6890).
+        || (a == 192 && b == 0 && c == 0)
+        // Benchmarking (RFC 2544).
+        || (a == 198 && (18..20).contains(&b))
+        // Reserved (RFC 1112).
+        || a >= 240)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_public_v4(v4);
+    }
+    let segments = ip.segments();
+    // NAT64 (RFC 6052) carries an IPv4 address in its last 32 bits.
+    if segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0] {
+        let [.., hi, lo] = segments;
+        return is_public_v4(Ipv4Addr::from((u32::from(hi) << 16) | u32::from(lo)));
+    }
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // IPv4-compatible (deprecated) and discard-only (RFC 6666) prefixes.
+        || segments[..6] == [0; 6]
+        || segments[..4] == [0x100, 0, 0, 0]
+        // Unique local (fc00::/7), link-local (fe80::/10) and site-local (fec0::/10).
+        || (segments[0] & 0xfe00) == 0xfc00
+        || (segments[0] & 0xffc0) == 0xfe80
+        || (segments[0] & 0xffc0) == 0xfec0
+        // Documentation (RFC 3849).
+        || (segments[0] == 0x2001 && segments[1] == 0xdb8))
+}
+
+/// Checks what can be checked before resolving: the scheme, an IP-literal host and, when
+/// `allowed_domains` is given, the host name.
+pub fn check_target(
+    url: &Url,
+    allowed_domains: Option<&[String]>,
+    allow_private: bool,
+) -> Result<(), BlockedTarget> {
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(BlockedTarget(format!(
+            "unsupported scheme: {}",
+            url.scheme()
+        )));
+    }
+    let ip = match url.host() {
+        Some(url::Host::Ipv4(ip)) => Some(IpAddr::V4(ip)),
+        Some(url::Host::Ipv6(ip)) => Some(IpAddr::V6(ip)),
+        Some(url::Host::Domain(_)) => None,
+        None => return Err(BlockedTarget(format!("no host in {url}"))),
+    };
+    if let Some(ip) = ip
+        && !allow_private
+        && !is_public_ip(ip)
+    {
+        return Err(BlockedTarget(format!("non-public address {ip}")));
+    }
+    if let Some(domains) = allowed_domains {
+        let host = url.host_str().unwrap_or_default();
+        if !host_in_domains(host, domains) {
+            return Err(BlockedTarget(format!(
+                "{host} is outside the allowed domains"
+            )));
+        }
+    }
+    Ok(())
+}
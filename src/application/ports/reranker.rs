@@ -0,0 +1,25 @@
+use async_trait::async_trait;
+
+/// Cross-encoder style relevance scoring of candidate passages against a query.
+///
+/// Unlike embedding similarity, the query and each passage are scored jointly, which is
+/// slower but considerably more precise; it is applied to a widened candidate set after
+/// vector search.
+#[async_trait]
+pub trait Reranker: Send + Sync {
+    /// One relevance score per document, in input order. Higher is more relevant; scores
+    /// are only comparable within a single call.
+    async fn rerank(&self, query: &str, documents: &[&str]) -> Result<Vec<f32>, RerankerError>;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RerankerError {
+    #[error("rerank api request failed: {0}")]
+    ApiRequestFailed(String),
+    #[error("invalid rerank response: {0}")]
+    InvalidResponse(String),
+    #[error("model loading failed: {0}")]
+    ModelLoadFailed(String),
+    #[error("inference failed: {0}")]
+    InferenceFailed(String),
+}
@@ -1,20 +1,36 @@
+use std::collections::BTreeMap;
+
 use async_trait::async_trait;
 
-use crate::application::ports::{EmbedderError, RepositoryError, VectorStoreError};
+use crate::application::ports::{EmbedderError, RepositoryError, SearchFilter, VectorStoreError};
+use crate::domain::CodeSpan;
 
 /// A raw source passage retrieved from the vector store.
 #[derive(Debug, Clone)]
 pub struct SourceChunk {
     pub text: String,
     pub page: Option<u32>,
+    /// Last page of the passage; differs from `page` when it spans a page break.
+    pub end_page: Option<u32>,
     pub score: f32,
     pub title: Option<String>,
     /// Base source URL for the document (without timestamp suffix).
     pub source_url: Option<String>,
     pub content_type: Option<String>,
+    pub author: Option<String>,
+    pub tags: Vec<String>,
+    /// Key/value tags supplied when the document was ingested.
+    pub attributes: BTreeMap<String, String>,
+    /// Targets of the links in the passage (markdown and HTML sources).
+    pub links: Vec<String>,
     /// Start time of the chunk within the media file, in seconds.
     /// `None` for non-media sources (PDF, plain text).
     pub start_time: Option<f32>,
+    /// Path, symbol and line range for passages from source files.
+    pub code: Option<CodeSpan>,
+    /// Cross-encoder relevance score, present when a reranker reordered the results.
+    /// `score` always holds the vector-search score.
+    pub rerank_score: Option<f32>,
 }
 
 impl SourceChunk {
@@ -51,5 +67,9 @@ pub enum RetrievalError {
 /// passages and produce their own synthesis, avoiding a double-LLM chain.
 #[async_trait]
 pub trait RetrievalServicePort: Send + Sync {
-    async fn search_chunks(&self, query: &str) -> Result<Vec<SourceChunk>, RetrievalError>;
+    async fn search_chunks(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SourceChunk>, RetrievalError>;
 }
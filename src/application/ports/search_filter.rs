@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::domain::{ContentType, DocumentId, TenantId};
+
+/// Backend-neutral restriction on which chunks a search may return.
+///
+/// Populated fields are combined with AND; a list field matches when the chunk carries any
+/// of the listed values. `tenant_id` is never optional: every search is confined to one
+/// tenant, so `SearchFilter::default()` matches everything in the default tenant.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchFilter {
+    pub tenant_id: TenantId,
+    pub document_ids: Vec<DocumentId>,
+    pub content_types: Vec<ContentType>,
+    pub titles: Vec<String>,
+    /// Inclusive lower bound on the chunk's ingestion time. Both bounds exclude chunks
+    /// stored without an `ingested_at` payload, i.e. ingested before it was recorded.
+    pub ingested_after: Option<DateTime<Utc>>,
+    /// Inclusive upper bound on the chunk's ingestion time.
+    pub ingested_before: Option<DateTime<Utc>>,
+    pub tags: Vec<String>,
+}
+
+impl SearchFilter {
+    pub fn for_tenant(tenant_id: TenantId) -> Self {
+        Self {
+            tenant_id,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = tenant_id;
+        self
+    }
+
+    /// `true` when nothing beyond the tenant scope restricts the search.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::for_tenant(self.tenant_id.clone())
+    }
+
+    /// Parses the JSON shape shared by HTTP request bodies and tool-call arguments:
+    ///
+    /// ```json
+    /// { "document_ids": ["<uuid>"], "content_types": ["application/pdf"],
+    ///   "titles": ["..."], "tags": ["..."],
+    ///   "ingested_after": "2025-01-01T00:00:00Z", "ingested_before": "..." }
+    /// ```
+    ///
+    /// The tenant is deliberately not accepted from the payload; the result is scoped to the
+    /// default tenant until the caller applies [`SearchFilter::with_tenant`].
+    pub fn from_json(value: &serde_json::Value) -> Result<Self, SearchFilterError> {
+        let raw: RawSearchFilter = serde_json::from_value(value.clone())
+            .map_err(|e| SearchFilterError::Malformed(e.to_string()))?;
+
+        let document_ids = raw
+            .document_ids
+            .iter()
+            .map(|id| {
+                Uuid::parse_str(id)
+                    .map(DocumentId::from_uuid)
+                    .map_err(|_| SearchFilterError::InvalidDocumentId(id.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let content_types = raw
+            .content_types
+            .iter()
+            .map(|mime| {
+                ContentType::from_mime(mime)
+                    .ok_or_else(|| SearchFilterError::UnknownContentType(mime.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if let (Some(after), Some(before)) = (raw.ingested_after, raw.ingested_before) {
+            if after > before {
+                return Err(SearchFilterError::EmptyDateRange);
+            }
+        }
+
+        Ok(Self {
+            tenant_id: TenantId::default(),
+            document_ids,
+            content_types,
+            titles: raw.titles,
+            ingested_after: raw.ingested_after,
+            ingested_before: raw.ingested_before,
+            tags: raw.tags,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawSearchFilter {
+    #[serde(default)]
+    document_ids: Vec<String>,
+    #[serde(default)]
+    content_types: Vec<String>,
+    #[serde(default)]
+    titles: Vec<String>,
+    #[serde(default)]
+    ingested_after: Option<DateTime<Utc>>,
+    #[serde(default)]
+    ingested_before: Option<DateTime<Utc>>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SearchFilterError {
+    #[error("malformed filter: {0}")]
+    Malformed(String),
+    #[error("invalid document id in filter: {0}")]
+    InvalidDocumentId(String),
+    #[error("unsupported content type in filter: {0}")]
+    UnknownContentType(String),
+    #[error("ingested_after is later than ingested_before")]
+    EmptyDateRange,
+}
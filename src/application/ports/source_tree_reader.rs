@@ -0,0 +1,37 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+
+#[derive(Debug, thiserror::Error)]
+pub enum SourceTreeError {
+    #[error("not found: {0}")]
+    NotFound(String),
+    #[error("not a git checkout: {0}")]
+    NotACheckout(String),
+    #[error("path escapes the checkout: {0}")]
+    OutsideCheckout(String),
+    #[error("io: {0}")]
+    Io(String),
+}
+
+/// A file of a source checkout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceFile {
+    /// Path relative to the checkout root, `/`-separated.
+    pub path: String,
+    pub size_bytes: u64,
+}
+
+/// Read access to local git checkouts for repository ingestion.
+#[async_trait]
+pub trait SourceTreeReader: Send + Sync {
+    /// Canonical path of the checkout at `root`.
+    async fn resolve(&self, root: &Path) -> Result<PathBuf, SourceTreeError>;
+
+    /// Regular files under `root` that git does not ignore, sorted by path. Hidden files,
+    /// the `.git` directory and symbolic links are skipped.
+    async fn list_files(&self, root: &Path) -> Result<Vec<SourceFile>, SourceTreeError>;
+
+    /// Contents of the file at `path`, relative to `root`.
+    async fn read(&self, root: &Path, path: &str) -> Result<Vec<u8>, SourceTreeError>;
+}
@@ -3,9 +3,15 @@ use async_trait::async_trait;
 use super::EmbedderError;
 use crate::domain::SparseEmbedding;
 
+/// Lexical (sparse) embeddings for hybrid search.
+///
+/// Queries and documents are weighted differently: documents carry per-term weights, queries
+/// only select the terms to match. Corpus-wide statistics such as IDF are the vector store's
+/// concern.
 #[async_trait]
 pub trait SparseEmbedder: Send + Sync {
-    async fn embed_sparse(&self, text: &str) -> Result<SparseEmbedding, EmbedderError>;
+    async fn embed_sparse_query(&self, query: &str) -> Result<SparseEmbedding, EmbedderError>;
+    /// Embeds document chunks for indexing.
     async fn embed_sparse_batch(
         &self,
         texts: &[&str],
@@ -1,6 +1,7 @@
 use std::io;
 
 use bytes::Bytes;
+use futures::StreamExt;
 use futures::stream::BoxStream;
 
 use crate::domain::StoragePath;
@@ -16,6 +17,16 @@ pub trait StagingStore: Send + Sync {
 
     async fn fetch(&self, path: &StoragePath) -> Result<Vec<u8>, StagingStoreError>;
 
+    /// Streams the object in chunks, so a multi-hour recording never sits in memory whole.
+    /// The default fetches it in one piece.
+    async fn fetch_stream(
+        &self,
+        path: &StoragePath,
+    ) -> Result<BoxStream<'static, Result<Bytes, StagingStoreError>>, StagingStoreError> {
+        let data = self.fetch(path).await?;
+        Ok(futures::stream::once(async move { Ok(Bytes::from(data)) }).boxed())
+    }
+
     async fn delete(&self, path: &StoragePath) -> Result<(), StagingStoreError>;
 
     async fn head(&self, path: &StoragePath) -> Result<u64, StagingStoreError>;
@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 
-use crate::domain::{Chunk, DocumentId, DocumentMetadata, TranscriptSegment};
+use crate::domain::{Chunk, DocumentId, DocumentMetadata, PageSegment, TranscriptSegment};
 
 #[async_trait]
 pub trait TextSplitter: Send + Sync {
@@ -13,6 +13,15 @@ pub trait TextSplitter: Send + Sync {
         metadata: Option<Arc<DocumentMetadata>>,
     ) -> Result<Vec<Chunk>, TextSplitterError>;
 
+    /// Splits extracted pages as one continuous text. Each chunk records the first and last
+    /// page its text came from (`page` / `end_page`); unpaged segments leave both unset.
+    async fn split_pages(
+        &self,
+        pages: &[PageSegment],
+        document_id: DocumentId,
+        metadata: Option<Arc<DocumentMetadata>>,
+    ) -> Result<Vec<Chunk>, TextSplitterError>;
+
     /// Splits a sequence of timed transcript segments into token-budgeted chunks.
     ///
     /// Each output chunk inherits the `start_time` of the first segment that contributed to it,
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+use super::RepositoryError;
+use crate::domain::{DocumentId, Transcript};
+
+/// Full transcript of an audio, video or caption document, kept alongside its chunks.
+#[async_trait]
+pub trait TranscriptRepository: Send + Sync {
+    /// Stores the document's transcript, replacing any earlier one. A transcript without a
+    /// language, as captions are, keeps the language recorded before it.
+    async fn save(
+        &self,
+        document_id: DocumentId,
+        transcript: &Transcript,
+    ) -> Result<(), RepositoryError>;
+
+    async fn get(&self, document_id: DocumentId) -> Result<Option<Transcript>, RepositoryError>;
+
+    async fn delete(&self, document_id: DocumentId) -> Result<(), RepositoryError>;
+}
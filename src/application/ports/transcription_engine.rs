@@ -1,6 +1,8 @@
+use std::path::Path;
+
 use async_trait::async_trait;
 
-use crate::domain::TranscriptSegment;
+use crate::domain::{Transcript, TranscriptSegment};
 
 #[async_trait]
 pub trait TranscriptionEngine: Send + Sync {
@@ -8,10 +10,41 @@ pub trait TranscriptionEngine: Send + Sync {
     ///
     /// Each segment carries its own `start_time` and `end_time` (in seconds), enabling
     /// the ingestion pipeline to attach timestamps to retrieved chunks for deep-link citations.
-    async fn transcribe(
+    async fn transcribe(&self, audio_data: &[u8]) -> Result<Transcript, TranscriptionError>;
+
+    /// Transcribes the media file at `path`, telling `observer` about each segment as it is
+    /// produced. Engines that decode locally stream the file so memory stays bounded by the
+    /// window size, whatever the recording's length; the default reads it whole.
+    async fn transcribe_file(
         &self,
-        audio_data: &[u8],
-    ) -> Result<Vec<TranscriptSegment>, TranscriptionError>;
+        path: &Path,
+        observer: &dyn TranscriptionObserver,
+    ) -> Result<Transcript, TranscriptionError> {
+        let data = tokio::fs::read(path)
+            .await
+            .map_err(|e| TranscriptionError::DecodingFailed(format!("read media: {e}")))?;
+        let transcript = self.transcribe(&data).await?;
+        for segment in &transcript.segments {
+            observer.segment_transcribed(segment);
+        }
+        Ok(transcript)
+    }
+}
+
+/// How Whisper engines transcribe, shared by every provider.
+#[derive(Debug, Clone, Default)]
+pub struct TranscriptionOptions {
+    /// ISO 639-1 code of the spoken language; detected per recording when `None`.
+    pub language: Option<String>,
+    /// Translate the speech into English instead of transcribing it as spoken.
+    pub translate: bool,
+    /// Time each word of every segment.
+    pub word_timestamps: bool,
+}
+
+/// Follows a long transcription as it progresses.
+pub trait TranscriptionObserver: Send + Sync {
+    fn segment_transcribed(&self, segment: &TranscriptSegment);
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -30,6 +63,17 @@ pub enum TranscriptionError {
 
 pub trait AudioDecoder: Send + Sync {
     fn decode(&self, data: &[u8]) -> Result<Vec<f32>, AudioDecoderError>;
+
+    /// Decodes the media file at `path` incrementally, as 16 kHz mono PCM.
+    fn open(&self, path: &Path) -> Result<Box<dyn PcmReader>, AudioDecoderError>;
+}
+
+/// 16 kHz mono PCM, read a buffer at a time. Reads block while the audio is decoded, so
+/// async callers run them on a blocking thread.
+pub trait PcmReader: Send {
+    /// Fills `buffer` with the next samples and returns how many were written; `0` once the
+    /// audio has ended.
+    fn read(&mut self, buffer: &mut [f32]) -> Result<usize, AudioDecoderError>;
 }
 
 #[derive(Debug, thiserror::Error)]
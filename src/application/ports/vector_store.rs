@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 
-use super::{CollectionConfig, SearchResult, VectorStoreError};
-use crate::domain::{Chunk, ChunkId, Embedding, SparseEmbedding};
+use super::{CollectionConfig, PayloadIndex, SearchFilter, SearchResult, VectorStoreError};
+use crate::domain::{Chunk, ChunkId, DocumentId, Embedding, SparseEmbedding};
 
 #[async_trait]
 pub trait VectorStore: Send + Sync {
@@ -15,6 +15,22 @@ pub trait VectorStore: Send + Sync {
         Ok(false)
     }
 
+    /// Turns on store-side IDF weighting of the sparse vector for an existing hybrid
+    /// collection created before it was the default. Returns `true` when the schema changed.
+    async fn ensure_sparse_idf(&self) -> Result<bool, VectorStoreError> {
+        Ok(false)
+    }
+
+    /// Creates the `indexes` an existing collection lacks, such as ones added to
+    /// [`CollectionConfig`] after it was created. Returns the fields that were indexed.
+    async fn ensure_payload_indexes(
+        &self,
+        indexes: &[PayloadIndex],
+    ) -> Result<Vec<String>, VectorStoreError> {
+        let _ = indexes;
+        Ok(Vec::new())
+    }
+
     async fn delete_collection(&self) -> Result<(), VectorStoreError>;
 
     async fn upsert(
@@ -33,10 +49,12 @@ pub trait VectorStore: Send + Sync {
         self.upsert(chunks, dense).await
     }
 
+    /// Nearest-neighbour search restricted to chunks matching `filter`.
     async fn search(
         &self,
         embedding: &Embedding,
         top_k: usize,
+        filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError>;
 
     async fn search_hybrid(
@@ -44,10 +62,18 @@ pub trait VectorStore: Send + Sync {
         dense: &Embedding,
         sparse: &SparseEmbedding,
         top_k: usize,
+        filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         let _ = sparse;
-        self.search(dense, top_k).await
+        self.search(dense, top_k, filter).await
     }
 
     async fn delete(&self, chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError>;
+
+    /// Removes every chunk belonging to `document_id` except those listed in `keep`.
+    async fn delete_by_document(
+        &self,
+        document_id: DocumentId,
+        keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError>;
 }
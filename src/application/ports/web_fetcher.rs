@@ -0,0 +1,68 @@
+use async_trait::async_trait;
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebFetchError {
+    #[error("invalid URL: {0}")]
+    InvalidUrl(String),
+    #[error("HTTP {0}")]
+    Status(u16),
+    #[error("timed out")]
+    Timeout,
+    #[error("response larger than {0} bytes")]
+    TooLarge(u64),
+    #[error("request failed: {0}")]
+    RequestFailed(String),
+    /// The URL or a redirect hop targets a non-public address or leaves the allowed domains.
+    #[error("blocked: {0}")]
+    Blocked(String),
+}
+
+impl WebFetchError {
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Status(status) => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// A fetched web resource, with the links a crawler follows already extracted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WebResource {
+    /// Final URL after redirects.
+    pub url: String,
+    /// Media type of the response, lowercased and without parameters.
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+    /// `<title>` of an HTML page.
+    pub title: Option<String>,
+    /// Absolute `<link rel="canonical">` target of an HTML page.
+    pub canonical_url: Option<String>,
+    /// Absolute http(s) link targets of an HTML page, or the `<loc>` entries of an XML
+    /// sitemap, in document order.
+    pub links: Vec<String>,
+    /// The resource is a sitemap index, so its `links` are further sitemaps.
+    pub sitemap_index: bool,
+}
+
+/// HTTP GET for web ingestion.
+#[async_trait]
+pub trait WebFetcher: Send + Sync {
+    /// Fetches `url`, following redirects only while they stay within `allowed_domains`
+    /// (subdomains included; empty allows any host). Non-2xx responses are errors.
+    async fn fetch(
+        &self,
+        url: &str,
+        allowed_domains: &[String],
+    ) -> Result<WebResource, WebFetchError>;
+}
+
+/// `true` when `host` is one of `domains` or a subdomain of one.
+pub fn host_in_domains(host: &str, domains: &[String]) -> bool {
+    domains.iter().any(|d| {
+        host == d
+            || host
+                .strip_suffix(d.as_str())
+                .is_some_and(|p| p.ends_with('.'))
+    })
+}
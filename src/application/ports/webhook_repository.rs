@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use super::RepositoryError;
+use crate::domain::{
+    ClaimedWebhookDelivery, TenantId, WebhookDelivery, WebhookEvent, WebhookSubscription,
+    WebhookSubscriptionId,
+};
+
+/// Webhook subscriptions and their durable delivery outbox.
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), RepositoryError>;
+
+    /// Subscriptions of one tenant, newest first.
+    async fn list_subscriptions(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError>;
+
+    async fn get_subscription(
+        &self,
+        id: WebhookSubscriptionId,
+    ) -> Result<Option<WebhookSubscription>, RepositoryError>;
+
+    /// Deletes the subscription together with its delivery log. Returns `false` when it does
+    /// not exist.
+    async fn delete_subscription(&self, id: WebhookSubscriptionId)
+    -> Result<bool, RepositoryError>;
+
+    /// Queues one pending delivery per subscription of the event's tenant listening to its
+    /// type, returning how many were queued.
+    async fn enqueue(&self, event: &WebhookEvent) -> Result<usize, RepositoryError>;
+
+    /// Claims up to `batch_size` due deliveries, counting an attempt on each. A claimed
+    /// delivery that is not settled within `lease` becomes due again.
+    async fn claim_due(
+        &self,
+        batch_size: usize,
+        lease: Duration,
+    ) -> Result<Vec<ClaimedWebhookDelivery>, RepositoryError>;
+
+    async fn mark_delivered(&self, id: Uuid, response_status: u16) -> Result<(), RepositoryError>;
+
+    /// Leaves the delivery pending until `delay` has passed, recording why the attempt failed.
+    async fn schedule_retry(
+        &self,
+        id: Uuid,
+        delay: Duration,
+        error: &str,
+        response_status: Option<u16>,
+    ) -> Result<(), RepositoryError>;
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        response_status: Option<u16>,
+    ) -> Result<(), RepositoryError>;
+
+    /// Delivery log of one subscription, newest first.
+    async fn list_deliveries(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+}
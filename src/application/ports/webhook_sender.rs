@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+
+use crate::domain::ClaimedWebhookDelivery;
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookSendError {
+    #[error("webhook HTTP {0}")]
+    Rejected(u16),
+    #[error("webhook timeout")]
+    Timeout,
+    #[error("webhook request failed: {0}")]
+    RequestFailed(String),
+}
+
+impl WebhookSendError {
+    pub fn response_status(&self) -> Option<u16> {
+        match self {
+            Self::Rejected(status) => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// Signs and POSTs one webhook delivery to its subscriber.
+#[async_trait]
+pub trait WebhookSender: Send + Sync {
+    /// Returns the 2xx status the endpoint answered with.
+    async fn send(&self, delivery: &ClaimedWebhookDelivery) -> Result<u16, WebhookSendError>;
+}
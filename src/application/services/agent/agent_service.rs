@@ -12,9 +12,10 @@ use crate::application::ports::{
     LlmClientError, LlmTokenStream, LlmToolResponse, McpClientPort, McpError, RagSourceCollector,
     ToolRegistry,
 };
+use crate::application::services::{current_principal, run_as_tenant};
 use crate::domain::{
     AgenticTrace, Conversation, ConversationId, EvalEvent, EvalSource, Message, MessageRole,
-    ToolCallTrace, ToolName,
+    TenantId, ToolCallTrace, ToolName,
 };
 use crate::presentation::config::AgentServiceConfig;
 
@@ -396,6 +397,7 @@ impl AgentService {
 
     fn fire_and_forget_eval(
         &self,
+        tenant_id: &TenantId,
         question: &str,
         answer: &str,
         correlation_id: Option<String>,
@@ -419,7 +421,9 @@ impl AgentService {
                 &self.config.model_config,
                 correlation_id,
                 agentic_trace,
-            );
+            )
+            .with_tenant(tenant_id.clone())
+            .with_principal(current_principal().map(|p| p.subject));
             let event_repo = Arc::clone(event_repo);
             let outbox_repo = Arc::clone(outbox_repo);
             let span = tracing::Span::current();
@@ -652,7 +656,7 @@ impl AgentServicePort for AgentService {
         let conversation_id = match request.conversation_id {
             Some(id) => id,
             None => {
-                let conv = Conversation::new(None);
+                let conv = Conversation::new(None).with_tenant(request.tenant_id.clone());
                 let id = conv.id;
                 if let Err(e) = self
                     .conversation_repository
@@ -668,14 +672,20 @@ impl AgentServicePort for AgentService {
         // Bounded channel — progress events are cheap and numerous, 64 slots is ample.
         let (progress_tx, progress_rx) = tokio::sync::mpsc::channel(64);
 
-        let (candidate_answer, candidate_messages, history_end) = self
-            .run_react_loop(request.user_message.clone(), conversation_id, &progress_tx)
-            .await?;
+        // Tool handlers resolve the tenant from the ambient scope (see `run_as_tenant`).
+        let (candidate_answer, candidate_messages, history_end) = run_as_tenant(
+            request.tenant_id.clone(),
+            self.run_react_loop(request.user_message.clone(), conversation_id, &progress_tx),
+        )
+        .await?;
 
         let (answer, final_messages, reflection_score, reflection_issues) =
             if self.config.reflection.enabled {
-                self.reflect_and_correct(candidate_answer, candidate_messages, &progress_tx)
-                    .await?
+                run_as_tenant(
+                    request.tenant_id.clone(),
+                    self.reflect_and_correct(candidate_answer, candidate_messages, &progress_tx),
+                )
+                .await?
             } else {
                 let (a, m) = (candidate_answer, candidate_messages);
                 (a, m, None, Vec::new())
@@ -758,6 +768,7 @@ impl AgentServicePort for AgentService {
                 AgentMessage::ToolResult(r) => Some(EvalSource {
                     text: r.content.clone(),
                     page: None,
+                    end_page: None,
                     score: 1.0,
                 }),
                 _ => None,
@@ -765,6 +776,7 @@ impl AgentServicePort for AgentService {
             .collect();
 
         self.fire_and_forget_eval(
+            &request.tenant_id,
             &request.user_message,
             &answer,
             request.correlation_id.clone(),
@@ -1,11 +1,13 @@
 use crate::application::errors::AgentError;
 use crate::application::ports::LlmTokenStream;
-use crate::domain::ConversationId;
+use crate::domain::{ConversationId, TenantId};
 
 pub struct AgentChatRequest {
     pub conversation_id: Option<ConversationId>,
     pub user_message: String,
     pub correlation_id: Option<String>,
+    /// Scopes the conversation, tool-driven retrieval and the recorded eval event.
+    pub tenant_id: TenantId,
 }
 
 pub struct AgentChatResponse {
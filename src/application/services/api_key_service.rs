@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use crate::application::ports::{ApiKeyRepository, RepositoryError};
+use crate::domain::{ApiKey, ApiKeyId, ApiScope, Principal, TenantId};
+
+/// Minting, revocation and verification of API keys.
+pub struct ApiKeyService {
+    repository: Arc<dyn ApiKeyRepository>,
+}
+
+impl ApiKeyService {
+    pub fn new(repository: Arc<dyn ApiKeyRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Creates a key and returns it with its plaintext secret, which is not recoverable later.
+    #[tracing::instrument(skip(self, scopes), fields(tenant_id = %tenant_id))]
+    pub async fn mint(
+        &self,
+        name: &str,
+        tenant_id: TenantId,
+        scopes: Vec<ApiScope>,
+    ) -> Result<(ApiKey, String), ApiKeyServiceError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ApiKeyServiceError::InvalidRequest(
+                "name must not be empty".to_string(),
+            ));
+        }
+        let mut scopes = scopes;
+        scopes.sort_by_key(|s| s.as_str());
+        scopes.dedup();
+        if scopes.is_empty() {
+            return Err(ApiKeyServiceError::InvalidRequest(
+                "at least one scope is required".to_string(),
+            ));
+        }
+
+        let (key, secret) = ApiKey::generate(name, tenant_id, scopes);
+        self.repository
+            .create(&key)
+            .await
+            .map_err(ApiKeyServiceError::Repository)?;
+        tracing::info!(api_key_id = %key.id, "API key minted");
+        Ok((key, secret))
+    }
+
+    /// Resolves a presented secret to its principal; `None` for unknown or revoked keys.
+    pub async fn authenticate(
+        &self,
+        secret: &str,
+    ) -> Result<Option<Principal>, ApiKeyServiceError> {
+        let key = self
+            .repository
+            .find_active_by_hash(&ApiKey::hash_secret(secret))
+            .await
+            .map_err(ApiKeyServiceError::Repository)?;
+        Ok(key.as_ref().map(Principal::from_api_key))
+    }
+
+    pub async fn list(
+        &self,
+        tenant_id: Option<&TenantId>,
+    ) -> Result<Vec<ApiKey>, ApiKeyServiceError> {
+        self.repository
+            .list(tenant_id)
+            .await
+            .map_err(ApiKeyServiceError::Repository)
+    }
+
+    /// Revokes a key of `tenant_id` (of any tenant when `None`); a key of another tenant is
+    /// reported as not found.
+    #[tracing::instrument(skip(self), fields(api_key_id = %id))]
+    pub async fn revoke(
+        &self,
+        id: ApiKeyId,
+        tenant_id: Option<&TenantId>,
+    ) -> Result<(), ApiKeyServiceError> {
+        if !self
+            .repository
+            .revoke(id, tenant_id)
+            .await
+            .map_err(ApiKeyServiceError::Repository)?
+        {
+            return Err(ApiKeyServiceError::NotFound(id));
+        }
+        tracing::info!("API key revoked");
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ApiKeyServiceError {
+    #[error("invalid API key request: {0}")]
+    InvalidRequest(String),
+    #[error("API key not found or already revoked: {0}")]
+    NotFound(ApiKeyId),
+    #[error("repository: {0}")]
+    Repository(RepositoryError),
+}
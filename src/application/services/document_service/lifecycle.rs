@@ -0,0 +1,206 @@
+use super::{DocumentService, DocumentServiceError, IngestionSubmission, SubmissionOutcome};
+use crate::application::ports::{StagingStoreError, VectorStore};
+use crate::application::services::current_principal;
+use crate::domain::{
+    Document, DocumentId, DocumentRecord, IngestionPayload, Job, JobId, SourceMetadata,
+    StoragePath, TenantId,
+};
+
+impl<V> DocumentService<V>
+where
+    V: VectorStore,
+{
+    /// Removes the document's chunks, its catalog row and — for API uploads — the staged
+    /// source file, along with a corrected transcript. A pending or running ingestion is
+    /// cancelled first, and chunks go before the row so a partial failure never leaves
+    /// searchable orphans.
+    #[tracing::instrument(skip(self), fields(document_id = %id.as_uuid(), tenant_id = %tenant_id))]
+    pub async fn delete(
+        &self,
+        tenant_id: &TenantId,
+        id: DocumentId,
+    ) -> Result<(), DocumentServiceError> {
+        let document = self.get(tenant_id, id).await?;
+        self.cancel_live_job(id).await?;
+
+        self.vector_store
+            .delete_by_document(id, &[])
+            .await
+            .map_err(DocumentServiceError::VectorStore)?;
+
+        if document.staged_upload {
+            if let Err(e) = self.staging_store.delete(&document.storage_path).await {
+                tracing::warn!(
+                    error = %e,
+                    path = %document.storage_path,
+                    "Failed to delete staged source file for removed document"
+                );
+            }
+        }
+        if let Some(captions) = document
+            .captions_path
+            .as_ref()
+            .filter(|path| **path == document.corrected_transcript_path())
+        {
+            self.discard_staged(captions).await;
+        }
+
+        self.document_repository
+            .delete(id)
+            .await
+            .map_err(DocumentServiceError::Repository)?;
+
+        tracing::info!(filename = %document.filename, "Document deleted");
+        Ok(())
+    }
+
+    /// Enqueues a fresh ingestion of the stored source file under the same `DocumentId`.
+    #[tracing::instrument(skip(self), fields(document_id = %id.as_uuid(), tenant_id = %tenant_id))]
+    pub async fn reingest(
+        &self,
+        tenant_id: &TenantId,
+        id: DocumentId,
+    ) -> Result<JobId, DocumentServiceError> {
+        let document = self.get(tenant_id, id).await?;
+
+        match self.staging_store.head(&document.storage_path).await {
+            Ok(_) => {}
+            Err(StagingStoreError::NotFound(path)) => {
+                return Err(DocumentServiceError::SourceUnavailable(path));
+            }
+            Err(e) => return Err(DocumentServiceError::Staging(e)),
+        }
+
+        let job_id = self
+            .enqueue(&document, "document_reingestion", true)
+            .await?;
+
+        tracing::info!(job_id = %job_id.as_uuid(), "Document re-ingestion job enqueued");
+        Ok(job_id)
+    }
+
+    /// Points `existing` at the new source and re-ingests it under the same `DocumentId`.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) async fn replace(
+        &self,
+        existing: DocumentRecord,
+        document: Document,
+        storage_path: StoragePath,
+        staged_upload: bool,
+        content_hash: String,
+        source: SourceMetadata,
+        captions: Option<StoragePath>,
+    ) -> Result<IngestionSubmission, DocumentServiceError> {
+        self.cancel_live_job(existing.id).await?;
+
+        let previous_path = existing.storage_path.clone();
+        let previous_staged = existing.staged_upload;
+        let previous_correction = existing
+            .captions_path
+            .clone()
+            .filter(|path| *path == existing.corrected_transcript_path());
+        let source = if source.is_empty() {
+            existing.source.clone()
+        } else {
+            source
+        };
+
+        let record = DocumentRecord {
+            filename: document.filename,
+            content_type: document.content_type,
+            size_bytes: document.size_bytes,
+            content_hash: Some(content_hash),
+            storage_path,
+            staged_upload,
+            captions_path: captions,
+            source,
+            ..existing
+        };
+        self.document_repository
+            .update_source(&record)
+            .await
+            .map_err(DocumentServiceError::Repository)?;
+
+        if previous_staged && previous_path != record.storage_path {
+            self.discard_staged(&previous_path).await;
+        }
+        if let Some(correction) = previous_correction {
+            self.discard_staged(&correction).await;
+        }
+
+        let job_id = self.enqueue(&record, "document_ingestion", true).await?;
+
+        tracing::info!(
+            document_id = %record.id.as_uuid(),
+            job_id = %job_id.as_uuid(),
+            "Document content changed; replacing previous version"
+        );
+        Ok(IngestionSubmission {
+            document_id: record.id,
+            job_id,
+            outcome: SubmissionOutcome::Replaced,
+        })
+    }
+
+    /// Cancels the document's queued or running ingestion, so its worker does not write
+    /// chunks for a version that is being deleted or replaced. A worker already past its
+    /// last cancellation check notices the deletion when it records the result.
+    pub(super) async fn cancel_live_job(&self, id: DocumentId) -> Result<(), DocumentServiceError> {
+        let latest_job = self
+            .job_repository
+            .find_latest_by_document(id)
+            .await
+            .map_err(DocumentServiceError::Repository)?;
+        let Some(job) = latest_job.filter(|job| !job.status.is_terminal()) else {
+            return Ok(());
+        };
+
+        let status = self
+            .job_repository
+            .request_cancel(job.id)
+            .await
+            .map_err(DocumentServiceError::Repository)?;
+        tracing::info!(
+            job_id = %job.id.as_uuid(),
+            status = status.map(|s| s.as_str()),
+            "Cancelled live ingestion of the document"
+        );
+        Ok(())
+    }
+
+    pub(super) async fn enqueue(
+        &self,
+        record: &DocumentRecord,
+        job_type: &str,
+        replace_existing: bool,
+    ) -> Result<JobId, DocumentServiceError> {
+        let payload = IngestionPayload {
+            document: record.to_document(),
+            storage_path: record.storage_path.clone(),
+            replace_existing,
+            principal: current_principal().map(|p| p.subject),
+            source: record.source.clone(),
+            captions: record.captions_path.clone(),
+        };
+        let job = Job::new(Some(record.id), job_type.to_string())
+            .with_tenant(record.tenant_id.clone())
+            .with_payload(payload);
+        let job_id = job.id;
+        self.job_repository
+            .create(&job)
+            .await
+            .map_err(DocumentServiceError::Repository)?;
+
+        if let Some(wakeup) = &self.ingestion_wakeup {
+            wakeup.notify_one();
+        }
+
+        Ok(job_id)
+    }
+
+    pub(super) async fn discard_staged(&self, path: &StoragePath) {
+        if let Err(e) = self.staging_store.delete(path).await {
+            tracing::warn!(error = %e, path = %path, "Failed to delete superseded staged file");
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use tokio::sync::Notify;
+
+use crate::application::ports::{
+    DocumentRepository, JobRepository, RepositoryError, StagingStore, StagingStoreError,
+    TranscriptRepository, VectorStore, VectorStoreError,
+};
+use crate::domain::{DocumentId, DocumentRecord, JobId, TenantId};
+
+mod lifecycle;
+mod submission;
+mod transcript;
+
+/// Document catalog lifecycle: listing, inspection, deletion, re-ingestion and transcripts.
+///
+/// The catalog row is the source of truth for what is in the knowledge base; chunks in the
+/// vector store are always addressed through their `document_id` payload. Every operation is
+/// scoped to the caller's tenant — documents owned by another tenant behave as missing.
+pub struct DocumentService<V>
+where
+    V: VectorStore,
+{
+    document_repository: Arc<dyn DocumentRepository>,
+    job_repository: Arc<dyn JobRepository>,
+    vector_store: Arc<V>,
+    staging_store: Arc<dyn StagingStore>,
+    transcript_repository: Arc<dyn TranscriptRepository>,
+    ingestion_wakeup: Option<Arc<Notify>>,
+}
+
+impl<V> DocumentService<V>
+where
+    V: VectorStore,
+{
+    pub fn new(
+        document_repository: Arc<dyn DocumentRepository>,
+        job_repository: Arc<dyn JobRepository>,
+        vector_store: Arc<V>,
+        staging_store: Arc<dyn StagingStore>,
+        transcript_repository: Arc<dyn TranscriptRepository>,
+    ) -> Self {
+        Self {
+            document_repository,
+            job_repository,
+            vector_store,
+            staging_store,
+            transcript_repository,
+            ingestion_wakeup: None,
+        }
+    }
+
+    /// Signals an in-process [`IngestionWorker`](crate::application::services::IngestionWorker)
+    /// after each enqueue; without it, workers find new jobs on their next poll.
+    pub fn with_ingestion_wakeup(mut self, wakeup: Arc<Notify>) -> Self {
+        self.ingestion_wakeup = Some(wakeup);
+        self
+    }
+
+    pub async fn list(
+        &self,
+        tenant_id: &TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<DocumentRecord>, DocumentServiceError> {
+        self.document_repository
+            .list(tenant_id, limit, offset)
+            .await
+            .map_err(DocumentServiceError::Repository)
+    }
+
+    /// Documents whose filename starts with `prefix`, such as every file of a synced
+    /// repository.
+    pub async fn list_by_filename_prefix(
+        &self,
+        tenant_id: &TenantId,
+        prefix: &str,
+    ) -> Result<Vec<DocumentRecord>, DocumentServiceError> {
+        self.document_repository
+            .list_by_filename_prefix(tenant_id, prefix)
+            .await
+            .map_err(DocumentServiceError::Repository)
+    }
+
+    pub async fn get(
+        &self,
+        tenant_id: &TenantId,
+        id: DocumentId,
+    ) -> Result<DocumentRecord, DocumentServiceError> {
+        self.document_repository
+            .get_by_id(id)
+            .await
+            .map_err(DocumentServiceError::Repository)?
+            .filter(|document| document.tenant_id == *tenant_id)
+            .ok_or(DocumentServiceError::NotFound(id))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Created,
+    Replaced,
+    Duplicate,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct IngestionSubmission {
+    pub document_id: DocumentId,
+    pub job_id: JobId,
+    pub outcome: SubmissionOutcome,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum DocumentServiceError {
+    #[error("document not found: {}", .0.as_uuid())]
+    NotFound(DocumentId),
+    #[error("source file no longer available: {0}")]
+    SourceUnavailable(String),
+    #[error("invalid source metadata: {0}")]
+    InvalidSource(String),
+    #[error("document has no transcript: {}", .0.as_uuid())]
+    TranscriptNotFound(DocumentId),
+    #[error("invalid transcript: {0}")]
+    InvalidTranscript(String),
+    #[error("repository: {0}")]
+    Repository(RepositoryError),
+    #[error("vector store: {0}")]
+    VectorStore(VectorStoreError),
+    #[error("staging store: {0}")]
+    Staging(StagingStoreError),
+}
@@ -0,0 +1,182 @@
+use super::{DocumentService, DocumentServiceError, IngestionSubmission, SubmissionOutcome};
+use crate::application::ports::{RepositoryError, VectorStore};
+use crate::domain::{Document, DocumentRecord, JobStatus, SourceMetadata, StoragePath, TenantId};
+
+impl<V> DocumentService<V>
+where
+    V: VectorStore,
+{
+    /// Catalogs a freshly staged source and enqueues its ingestion, deduplicating by content.
+    ///
+    /// - Content already in the catalog (and not stuck on a failed job) short-circuits to the
+    ///   existing document and its latest job; a redundant staged upload is discarded.
+    /// - A known filename with different content replaces the earlier version in place,
+    ///   keeping its `DocumentId`.
+    /// - Anything else becomes a new document.
+    ///
+    /// `source` is validated first. A replacement keeps the stored source metadata unless
+    /// new metadata is supplied; a duplicate keeps it unchanged.
+    pub async fn submit(
+        &self,
+        tenant_id: &TenantId,
+        document: Document,
+        storage_path: StoragePath,
+        staged_upload: bool,
+        content_hash: String,
+        source: SourceMetadata,
+    ) -> Result<IngestionSubmission, DocumentServiceError> {
+        self.submit_with_captions(
+            tenant_id,
+            document,
+            storage_path,
+            staged_upload,
+            content_hash,
+            source,
+            None,
+        )
+        .await
+    }
+
+    /// [`Self::submit`] for an audio or video document whose transcript comes from the
+    /// caption file at `captions` rather than from transcription. `content_hash` should
+    /// cover both files (see [`DocumentRecord::hash_captioned`]).
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip(self, document, content_hash, source), fields(filename = %document.filename, tenant_id = %tenant_id))]
+    pub async fn submit_with_captions(
+        &self,
+        tenant_id: &TenantId,
+        document: Document,
+        storage_path: StoragePath,
+        staged_upload: bool,
+        content_hash: String,
+        source: SourceMetadata,
+        captions: Option<StoragePath>,
+    ) -> Result<IngestionSubmission, DocumentServiceError> {
+        let source = source
+            .normalized()
+            .map_err(DocumentServiceError::InvalidSource)?;
+
+        if let Some(existing) = self
+            .document_repository
+            .find_by_content_hash(tenant_id, &content_hash)
+            .await
+            .map_err(DocumentServiceError::Repository)?
+        {
+            if let Some(submission) = self
+                .existing_submission(&existing, &storage_path, staged_upload)
+                .await?
+            {
+                return Ok(submission);
+            }
+            return self
+                .replace(
+                    existing,
+                    document,
+                    storage_path,
+                    staged_upload,
+                    content_hash,
+                    source,
+                    captions.clone(),
+                )
+                .await;
+        }
+
+        if let Some(existing) = self
+            .document_repository
+            .find_by_filename(tenant_id, &document.filename)
+            .await
+            .map_err(DocumentServiceError::Repository)?
+        {
+            return self
+                .replace(
+                    existing,
+                    document,
+                    storage_path,
+                    staged_upload,
+                    content_hash,
+                    source,
+                    captions.clone(),
+                )
+                .await;
+        }
+
+        let mut record = DocumentRecord::new(&document, storage_path.clone(), staged_upload)
+            .with_tenant(tenant_id.clone())
+            .with_source(source.clone())
+            .with_captions(captions.clone());
+        record.content_hash = Some(content_hash.clone());
+        match self.document_repository.create(&record).await {
+            Ok(()) => {}
+            Err(RepositoryError::ConstraintViolation(_)) => {
+                // Lost a race against a concurrent submission of the same content.
+                let existing = self
+                    .document_repository
+                    .find_by_content_hash(tenant_id, &content_hash)
+                    .await
+                    .map_err(DocumentServiceError::Repository)?
+                    .ok_or(DocumentServiceError::NotFound(document.id))?;
+                if let Some(submission) = self
+                    .existing_submission(&existing, &storage_path, staged_upload)
+                    .await?
+                {
+                    return Ok(submission);
+                }
+                return self
+                    .replace(
+                        existing,
+                        document,
+                        storage_path,
+                        staged_upload,
+                        content_hash,
+                        source,
+                        captions.clone(),
+                    )
+                    .await;
+            }
+            Err(e) => return Err(DocumentServiceError::Repository(e)),
+        }
+
+        let job_id = self.enqueue(&record, "document_ingestion", false).await?;
+        Ok(IngestionSubmission {
+            document_id: record.id,
+            job_id,
+            outcome: SubmissionOutcome::Created,
+        })
+    }
+
+    /// Resolves a content match to its latest job. Returns `None` when the previous attempt
+    /// failed, was cancelled or never got a job, in which case the caller retries ingestion.
+    async fn existing_submission(
+        &self,
+        existing: &DocumentRecord,
+        storage_path: &StoragePath,
+        staged_upload: bool,
+    ) -> Result<Option<IngestionSubmission>, DocumentServiceError> {
+        let latest_job = self
+            .job_repository
+            .find_latest_by_document(existing.id)
+            .await
+            .map_err(DocumentServiceError::Repository)?;
+
+        let Some(job) = latest_job
+            .filter(|job| !matches!(job.status, JobStatus::Failed | JobStatus::Cancelled))
+        else {
+            return Ok(None);
+        };
+
+        if staged_upload && *storage_path != existing.storage_path {
+            self.discard_staged(storage_path).await;
+        }
+
+        tracing::info!(
+            document_id = %existing.id.as_uuid(),
+            job_id = %job.id.as_uuid(),
+            "Identical content already cataloged; skipping ingestion"
+        );
+        Ok(Some(IngestionSubmission {
+            document_id: existing.id,
+            job_id: job.id,
+            outcome: SubmissionOutcome::Duplicate,
+        }))
+    }
+}
@@ -0,0 +1,81 @@
+use futures::StreamExt;
+
+use super::{DocumentService, DocumentServiceError};
+use crate::application::ports::{StagingStoreError, VectorStore};
+use crate::domain::{ContentType, DocumentId, JobId, TenantId, Transcript, TranscriptSegment};
+
+impl<V> DocumentService<V>
+where
+    V: VectorStore,
+{
+    /// The document's stored transcript, in playback order, with its spoken language.
+    pub async fn transcript(
+        &self,
+        tenant_id: &TenantId,
+        id: DocumentId,
+    ) -> Result<Transcript, DocumentServiceError> {
+        self.get(tenant_id, id).await?;
+        self.transcript_repository
+            .get(id)
+            .await
+            .map_err(DocumentServiceError::Repository)?
+            .ok_or(DocumentServiceError::TranscriptNotFound(id))
+    }
+
+    /// Replaces the transcript of an audio, video or caption document and re-ingests it.
+    ///
+    /// The segments are staged as the document's caption file, so the new chunks, the
+    /// stored transcript and any later re-ingestion all follow the correction instead of
+    /// transcription or the original captions.
+    #[tracing::instrument(skip(self, segments), fields(document_id = %id.as_uuid(), tenant_id = %tenant_id, segments = segments.len()))]
+    pub async fn correct_transcript(
+        &self,
+        tenant_id: &TenantId,
+        id: DocumentId,
+        segments: &[TranscriptSegment],
+    ) -> Result<JobId, DocumentServiceError> {
+        let document = self.get(tenant_id, id).await?;
+        if !matches!(
+            document.content_type,
+            ContentType::Audio | ContentType::Video | ContentType::Subtitles
+        ) {
+            return Err(DocumentServiceError::InvalidTranscript(format!(
+                "{} documents have no transcript",
+                document.content_type.as_mime()
+            )));
+        }
+        TranscriptSegment::validate_transcript(segments)
+            .map_err(DocumentServiceError::InvalidTranscript)?;
+
+        match self.staging_store.head(&document.storage_path).await {
+            Ok(_) => {}
+            Err(StagingStoreError::NotFound(path)) => {
+                return Err(DocumentServiceError::SourceUnavailable(path));
+            }
+            Err(e) => return Err(DocumentServiceError::Staging(e)),
+        }
+
+        let captions_path = document.corrected_transcript_path();
+        let captions = bytes::Bytes::from(TranscriptSegment::to_vtt(segments));
+        let size = captions.len() as u64;
+        self.staging_store
+            .store(
+                &captions_path,
+                futures::stream::once(async move { Ok(captions) }).boxed(),
+                Some(size),
+            )
+            .await
+            .map_err(DocumentServiceError::Staging)?;
+
+        let record = document.with_captions(Some(captions_path));
+        self.document_repository
+            .update_source(&record)
+            .await
+            .map_err(DocumentServiceError::Repository)?;
+
+        let job_id = self.enqueue(&record, "transcript_correction", true).await?;
+
+        tracing::info!(job_id = %job_id.as_uuid(), "Transcript correction job enqueued");
+        Ok(job_id)
+    }
+}
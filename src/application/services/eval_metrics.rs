@@ -5,7 +5,8 @@ use tracing::Instrument;
 use crate::application::ports::{Embedder, EmbedderError, LlmClient, LlmClientError};
 use crate::domain::{EvalSource, ToolCallTrace};
 
-/// Context recall: fraction of expected source pages present in retrieved sources.
+/// Context recall: fraction of expected source pages covered by retrieved sources.
+/// A source spanning a page break covers every page from `page` to `end_page`.
 /// Returns 1.0 when `expected_pages` is `None` or empty (no penalty without ground truth).
 pub fn compute_context_recall(
     expected_pages: Option<&[u32]>,
@@ -17,7 +18,11 @@ pub fn compute_context_recall(
     if pages.is_empty() {
         return 1.0;
     }
-    let retrieved: HashSet<u32> = retrieved_sources.iter().filter_map(|s| s.page).collect();
+    let retrieved: HashSet<u32> = retrieved_sources
+        .iter()
+        .filter_map(|s| s.page.map(|first| first..=s.end_page.unwrap_or(first)))
+        .flatten()
+        .collect();
     let found = pages.iter().filter(|p| retrieved.contains(p)).count();
     found as f32 / pages.len() as f32
 }
@@ -304,7 +309,11 @@ fn build_chunk_quality_prompt(
         .iter()
         .enumerate()
         .map(|(i, s)| {
-            let page_info = s.page.map(|p| format!(" (page {p})")).unwrap_or_default();
+            let page_info = match (s.page, s.end_page) {
+                (Some(first), Some(last)) if last > first => format!(" (pages {first}-{last})"),
+                (Some(p), _) => format!(" (page {p})"),
+                (None, _) => String::new(),
+            };
             format!("Chunk {}{page_info}:\n{}", i + 1, s.text)
         })
         .collect::<Vec<_>>()
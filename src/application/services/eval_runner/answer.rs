@@ -0,0 +1,84 @@
+use futures::StreamExt;
+
+use super::{EvalRunner, EvalRunnerError, EvalTarget};
+use crate::application::ports::{LlmClient, SearchFilter, SourceChunk, VectorStore};
+use crate::application::services::AgentChatRequest;
+use crate::domain::{EvalSource, TenantId};
+
+impl<L, V> EvalRunner<L, V>
+where
+    L: LlmClient,
+    V: VectorStore,
+{
+    /// The target pipeline's answer to `question` and the chunks it was grounded on.
+    pub(super) async fn answer(
+        &self,
+        question: &str,
+        target: EvalTarget,
+    ) -> Result<(String, Vec<EvalSource>), EvalRunnerError> {
+        match target {
+            EvalTarget::Retrieval => self.answer_with_retrieval(question).await,
+            EvalTarget::Agent => self.answer_with_agent(question).await,
+        }
+    }
+
+    async fn answer_with_retrieval(
+        &self,
+        question: &str,
+    ) -> Result<(String, Vec<EvalSource>), EvalRunnerError> {
+        let response = self
+            .retrieval_service
+            .query(question, None, None, &SearchFilter::default())
+            .await
+            .map_err(|e| EvalRunnerError::Pipeline(e.to_string()))?;
+        let sources = response.sources.iter().map(to_eval_source).collect();
+        Ok((response.answer, sources))
+    }
+
+    async fn answer_with_agent(
+        &self,
+        question: &str,
+    ) -> Result<(String, Vec<EvalSource>), EvalRunnerError> {
+        let agent = self
+            .agent_service
+            .as_ref()
+            .ok_or(EvalRunnerError::AgentUnavailable)?;
+
+        // Drop stale sources left by a previous question before this run starts collecting.
+        if let Some(collector) = &self.rag_source_collector {
+            collector.drain();
+        }
+
+        let response = agent
+            .chat(AgentChatRequest {
+                conversation_id: None,
+                user_message: question.to_string(),
+                correlation_id: None,
+                tenant_id: TenantId::default(),
+            })
+            .await
+            .map_err(|e| EvalRunnerError::Pipeline(e.to_string()))?;
+
+        let mut answer = String::new();
+        let mut token_stream = response.token_stream;
+        while let Some(token) = token_stream.next().await {
+            answer.push_str(&token.map_err(|e| EvalRunnerError::Pipeline(e.to_string()))?);
+        }
+
+        let sources = self
+            .rag_source_collector
+            .as_ref()
+            .map(|c| c.drain())
+            .unwrap_or_default();
+        Ok((answer, sources))
+    }
+}
+
+fn to_eval_source(source: &SourceChunk) -> EvalSource {
+    EvalSource {
+        text: source.text.clone(),
+        page: source.page,
+        end_page: source.end_page,
+        score: source.score,
+    }
+}
@@ -0,0 +1,248 @@
+use std::sync::Arc;
+
+use tracing::Instrument;
+
+use crate::application::ports::{
+    Embedder, EvalEventRepository, EvalResultRepository, LlmClient, RagSourceCollector, VectorStore,
+};
+use crate::application::services::{AgentServicePort, RetrievalService, eval_metrics};
+use crate::domain::{EvalEntry, EvalEvent, EvalResult, EvalRunId, EvalRunItem, EvalRunReport};
+
+mod answer;
+
+/// Which pipeline answers the golden questions during an offline run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalTarget {
+    Retrieval,
+    Agent,
+}
+
+/// Offline golden-dataset evaluator.
+///
+/// Replays every `EvalEntry` through the selected pipeline, scores the answer with the
+/// same `eval_metrics` functions the online `EvalWorker` uses plus the ground-truth
+/// metrics (`context_recall`, `correctness`), and persists one `EvalEvent` + `EvalResult`
+/// pair per question tagged with the run id. Scoring is sequential so judge rate limits
+/// and result ordering stay predictable.
+pub struct EvalRunner<L, V>
+where
+    L: LlmClient,
+    V: VectorStore,
+{
+    retrieval_service: Arc<RetrievalService<L, V>>,
+    agent_service: Option<Arc<dyn AgentServicePort>>,
+    rag_source_collector: Option<Arc<dyn RagSourceCollector>>,
+    event_repository: Arc<dyn EvalEventRepository>,
+    result_repository: Arc<dyn EvalResultRepository>,
+    judge: Arc<dyn LlmClient>,
+    embedder: Arc<dyn Embedder>,
+    faithfulness_threshold: f32,
+    model_config: String,
+    judge_model: String,
+}
+
+impl<L, V> EvalRunner<L, V>
+where
+    L: LlmClient,
+    V: VectorStore,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        retrieval_service: Arc<RetrievalService<L, V>>,
+        event_repository: Arc<dyn EvalEventRepository>,
+        result_repository: Arc<dyn EvalResultRepository>,
+        judge: Arc<dyn LlmClient>,
+        embedder: Arc<dyn Embedder>,
+        faithfulness_threshold: f32,
+        model_config: &str,
+        judge_model: &str,
+    ) -> Self {
+        Self {
+            retrieval_service,
+            agent_service: None,
+            rag_source_collector: None,
+            event_repository,
+            result_repository,
+            judge,
+            embedder,
+            faithfulness_threshold,
+            model_config: model_config.to_string(),
+            judge_model: judge_model.to_string(),
+        }
+    }
+
+    /// Enables `EvalTarget::Agent`. The collector must be the same instance handed to
+    /// `RagSearchAdapter` so the runner can recover the chunks the agent retrieved.
+    pub fn with_agent(
+        mut self,
+        agent_service: Arc<dyn AgentServicePort>,
+        rag_source_collector: Option<Arc<dyn RagSourceCollector>>,
+    ) -> Self {
+        self.agent_service = Some(agent_service);
+        self.rag_source_collector = rag_source_collector;
+        self
+    }
+
+    pub async fn run(
+        &self,
+        entries: &[EvalEntry],
+        target: EvalTarget,
+    ) -> Result<EvalRunReport, EvalRunnerError> {
+        if target == EvalTarget::Agent && self.agent_service.is_none() {
+            return Err(EvalRunnerError::AgentUnavailable);
+        }
+
+        let run_id = EvalRunId::new();
+        let span = tracing::info_span!(
+            "eval_run",
+            run_id = %run_id,
+            target = ?target,
+            entries = entries.len(),
+        );
+
+        async {
+            tracing::info!("Offline eval run started");
+            let mut items = Vec::with_capacity(entries.len());
+            for (index, entry) in entries.iter().enumerate() {
+                let item = self
+                    .evaluate_entry(run_id, entry, target)
+                    .instrument(tracing::info_span!("eval_entry", index))
+                    .await
+                    .unwrap_or_else(|e| {
+                        tracing::warn!(error = %e, question = %entry.question, "Eval entry failed");
+                        EvalRunItem::errored(&entry.question, e.to_string())
+                    });
+                items.push(item);
+            }
+
+            let report = EvalRunReport::from_items(run_id, &self.judge_model, items);
+            tracing::info!(
+                total_evaluated = report.total_evaluated,
+                below_threshold_count = report.below_threshold_count,
+                errored_count = report.errored_count,
+                mean_faithfulness = ?report.mean_faithfulness,
+                mean_context_recall = ?report.mean_context_recall,
+                mean_correctness = ?report.mean_correctness,
+                "eval.run_completed"
+            );
+            Ok(report)
+        }
+        .instrument(span)
+        .await
+    }
+
+    async fn evaluate_entry(
+        &self,
+        run_id: EvalRunId,
+        entry: &EvalEntry,
+        target: EvalTarget,
+    ) -> Result<EvalRunItem, EvalRunnerError> {
+        let (answer, sources) = self.answer(&entry.question, target).await?;
+
+        let event = match target {
+            EvalTarget::Retrieval => {
+                EvalEvent::new(&entry.question, &answer, sources, &self.model_config, None)
+            }
+            EvalTarget::Agent => EvalEvent::new_agentic(
+                &entry.question,
+                &answer,
+                sources,
+                &self.model_config,
+                None,
+                None,
+            ),
+        };
+        self.event_repository
+            .record(&event)
+            .await
+            .map_err(|e| EvalRunnerError::EventRepository(e.to_string()))?;
+
+        let context = event.context_text();
+        let faithfulness =
+            eval_metrics::compute_faithfulness(self.judge.as_ref(), &answer, &context)
+                .await
+                .map_err(|e| EvalRunnerError::Judge(e.to_string()))?;
+        let answer_relevancy =
+            eval_metrics::compute_answer_relevancy(self.judge.as_ref(), &entry.question, &answer)
+                .await
+                .map_err(|e| EvalRunnerError::Judge(e.to_string()))?;
+        let context_precision = eval_metrics::compute_context_precision(
+            self.judge.as_ref(),
+            &entry.question,
+            &event.retrieved_sources,
+        )
+        .await
+        .map_err(|e| EvalRunnerError::Judge(e.to_string()))?;
+        let context_recall = eval_metrics::compute_context_recall(
+            entry.expected_source_pages.as_deref(),
+            &event.retrieved_sources,
+        );
+        let correctness = eval_metrics::compute_correctness(
+            self.embedder.as_ref(),
+            &answer,
+            &entry.expected_answer,
+        )
+        .await
+        .map_err(|e| EvalRunnerError::Embedding(e.to_string()))?;
+
+        let eval_description = eval_metrics::generate_eval_description(
+            self.judge.as_ref(),
+            event.operation_type.as_str(),
+            &entry.question,
+            &answer,
+            faithfulness,
+            Some(answer_relevancy),
+            Some(context_precision),
+            0,
+        )
+        .await
+        .map_err(|e| EvalRunnerError::Judge(e.to_string()))?;
+        let result = EvalResult::new(
+            event.id,
+            entry.question.clone(),
+            answer,
+            eval_description,
+            faithfulness,
+            Some(answer_relevancy),
+            Some(context_precision),
+            Some(context_recall),
+            Some(correctness),
+            self.faithfulness_threshold,
+        )
+        .with_run_id(run_id);
+
+        self.result_repository
+            .save(&result)
+            .await
+            .map_err(|e| EvalRunnerError::ResultRepository(e.to_string()))?;
+
+        tracing::info!(
+            eval_event_id = %event.id,
+            faithfulness,
+            answer_relevancy,
+            context_precision,
+            context_recall,
+            correctness,
+            below_threshold = result.below_threshold,
+            "eval.result"
+        );
+
+        Ok(EvalRunItem::scored(&result))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum EvalRunnerError {
+    #[error("agent target requested but agent service is not configured")]
+    AgentUnavailable,
+    #[error("pipeline: {0}")]
+    Pipeline(String),
+    #[error("event repository: {0}")]
+    EventRepository(String),
+    #[error("result repository: {0}")]
+    ResultRepository(String),
+    #[error("judge: {0}")]
+    Judge(String),
+    #[error("embedding: {0}")]
+    Embedding(String),
+}
@@ -5,10 +5,12 @@ use std::time::Duration;
 use tracing::Instrument;
 
 use crate::application::ports::{
-    EvalEventRepository, EvalOutboxRepository, EvalResultRepository, LlmClient,
+    EvalEventRepository, EvalOutboxRepository, EvalResultRepository, LlmClient, WebhookRepository,
 };
 use crate::application::services::eval_metrics;
-use crate::domain::{EvalOperationType, EvalOutboxEntry, EvalResult};
+use crate::domain::{
+    EvalEvent, EvalOperationType, EvalOutboxEntry, EvalResult, WebhookEvent, WebhookEventType,
+};
 
 pub struct EvalWorker {
     outbox_repository: Arc<dyn EvalOutboxRepository>,
@@ -18,6 +20,7 @@ pub struct EvalWorker {
     faithfulness_threshold: f32,
     poll_interval: Duration,
     batch_size: usize,
+    webhooks: Option<Arc<dyn WebhookRepository>>,
 }
 
 impl EvalWorker {
@@ -38,9 +41,16 @@ impl EvalWorker {
             faithfulness_threshold,
             poll_interval,
             batch_size,
+            webhooks: None,
         }
     }
 
+    /// Queues an `eval.below_threshold` webhook delivery for every result under the threshold.
+    pub fn with_webhooks(mut self, webhooks: Arc<dyn WebhookRepository>) -> Self {
+        self.webhooks = Some(webhooks);
+        self
+    }
+
     pub async fn run(self) {
         tracing::info!("EvalWorker started");
         let mut interval = tokio::time::interval(self.poll_interval);
@@ -174,6 +184,7 @@ impl EvalWorker {
             .save(&result)
             .await
             .map_err(|e| EvalWorkerError::ResultRepository(e.to_string()))?;
+        self.notify_below_threshold(event, &result).await;
 
         tracing::info!(
             eval_event_id = %entry.eval_event_id,
@@ -290,6 +301,7 @@ impl EvalWorker {
             .save(&result)
             .await
             .map_err(|e| EvalWorkerError::ResultRepository(e.to_string()))?;
+        self.notify_below_threshold(event, &result).await;
 
         let reflection_score = event
             .agentic_trace
@@ -346,6 +358,7 @@ impl EvalWorker {
                 .save(&result)
                 .await
                 .map_err(|e| EvalWorkerError::ResultRepository(e.to_string()))?;
+            self.notify_below_threshold(event, &result).await;
             tracing::info!(
                 eval_event_id = %entry.eval_event_id,
                 operation_type = event.operation_type.as_str(),
@@ -414,6 +427,7 @@ impl EvalWorker {
             .save(&result)
             .await
             .map_err(|e| EvalWorkerError::ResultRepository(e.to_string()))?;
+        self.notify_below_threshold(event, &result).await;
 
         tracing::info!(
             eval_event_id = %entry.eval_event_id,
@@ -433,6 +447,33 @@ impl EvalWorker {
         Ok(())
     }
 
+    async fn notify_below_threshold(&self, event: &EvalEvent, result: &EvalResult) {
+        let Some(webhooks) = &self.webhooks else {
+            return;
+        };
+        if !result.below_threshold {
+            return;
+        }
+        let webhook_event = WebhookEvent::new(
+            event.tenant_id.clone(),
+            WebhookEventType::EvalBelowThreshold,
+            serde_json::json!({
+                "eval_event_id": result.eval_event_id.as_uuid(),
+                "eval_result_id": result.id.as_uuid(),
+                "operation_type": event.operation_type.as_str(),
+                "question": result.question,
+                "faithfulness": result.faithfulness,
+                "answer_relevancy": result.answer_relevancy,
+                "context_precision": result.context_precision,
+                "threshold": self.faithfulness_threshold,
+                "eval_description": result.eval_description,
+            }),
+        );
+        if let Err(e) = webhooks.enqueue(&webhook_event).await {
+            tracing::warn!(error = %e, "Failed to queue eval.below_threshold webhook");
+        }
+    }
+
     pub async fn process_batch(&self) -> Result<usize, EvalWorkerError> {
         let entries = self.receive_batch().await?;
         let count = entries.len();
@@ -93,21 +93,22 @@ where
         const MAX_EVAL_CHUNK_SAMPLES: usize = 5;
 
         let result: Result<(DocumentId, Vec<EvalSource>), IngestionError> = async {
-            let text = self
+            let pages = self
                 .file_loader
-                .extract_text(data, &document)
+                .extract_pages(data, &document)
                 .await
                 .map_err(IngestionError::FileLoading)?;
 
             let metadata = Arc::new(DocumentMetadata::from_document(&document, None));
 
-            let splitter = match content_type {
-                ContentType::Pdf => &self.markdown_splitter,
-                _ => &self.text_splitter,
+            let splitter = if content_type.is_markdown_source() {
+                &self.markdown_splitter
+            } else {
+                &self.text_splitter
             };
 
             let chunks = splitter
-                .split(&text, doc_id, Some(Arc::clone(&metadata)))
+                .split_pages(&pages, doc_id, Some(Arc::clone(&metadata)))
                 .await
                 .map_err(IngestionError::Splitting)?;
 
@@ -121,6 +122,7 @@ where
                 .map(|c| EvalSource {
                     text: c.text.clone(),
                     page: c.page,
+                    end_page: c.end_page,
                     score: 0.0,
                 })
                 .collect();
@@ -130,7 +132,7 @@ where
             let texts: Vec<&str> = contextual_strings.iter().map(String::as_str).collect();
 
             tracing::info!(
-                ingestionText = text,
+                ingestionPages = pages.len(),
                 ingestionMetadata = ?metadata,
                 chunks = ?chunks,
                 chunksWithContextuals = ?contextual_strings,
@@ -199,9 +201,16 @@ where
             (&self.eval_event_repository, &self.eval_outbox_repository)
         {
             let op_type = match content_type {
-                ContentType::Audio | ContentType::Video => EvalOperationType::IngestionMp4,
-                ContentType::Pdf => EvalOperationType::IngestionPdf,
-                ContentType::Text => EvalOperationType::Query,
+                ContentType::Audio | ContentType::Video | ContentType::Subtitles => {
+                    EvalOperationType::IngestionMp4
+                }
+                ContentType::Pdf | ContentType::Docx | ContentType::Pptx | ContentType::Xlsx => {
+                    EvalOperationType::IngestionPdf
+                }
+                ContentType::Text
+                | ContentType::Markdown
+                | ContentType::Html
+                | ContentType::Code => EvalOperationType::Query,
             };
             let event = EvalEvent::new_ingestion(
                 op_type,
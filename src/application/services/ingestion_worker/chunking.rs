@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use super::pipeline::StagedContent;
+use super::{IngestionWorker, IngestionWorkerError, JobRun};
+use crate::application::ports::{FileLoader, VectorStore};
+use crate::domain::{
+    Chunk, ContentType, DocumentMetadata, IngestionPayload, JobStatus, PageSegment, Transcript,
+};
+
+impl<F, V> IngestionWorker<F, V>
+where
+    F: FileLoader + 'static,
+    V: VectorStore + 'static,
+{
+    /// Extracts the staged document and splits it with the splitter for its content type.
+    /// Timed documents also yield the transcript their chunks were split from.
+    pub(super) async fn chunk_document(
+        &self,
+        run: &mut JobRun,
+        payload: &IngestionPayload,
+        staged: &mut StagedContent,
+        metadata: Arc<DocumentMetadata>,
+    ) -> Result<(Vec<Chunk>, Option<Transcript>), IngestionWorkerError> {
+        let document = &payload.document;
+        let doc_id = document.id;
+
+        match document.content_type {
+            ContentType::Audio | ContentType::Video | ContentType::Subtitles => {
+                let transcript = self.capture_transcript(run, payload, staged).await?;
+                run.progress.segments_transcribed = transcript.segments.len() as u32;
+                self.report_progress(run).await;
+
+                let chunks = self
+                    .text_splitter
+                    .split_segments(&transcript.segments, doc_id, Some(metadata))
+                    .await
+                    .map_err(IngestionWorkerError::Splitting)?;
+                Ok((chunks, Some(transcript)))
+            }
+            ContentType::Pdf
+            | ContentType::Text
+            | ContentType::Markdown
+            | ContentType::Html
+            | ContentType::Docx
+            | ContentType::Pptx
+            | ContentType::Xlsx => {
+                self.enter_stage(run, JobStatus::Processing).await?;
+                let pages = Self::interruptible(run, async {
+                    self.file_loader
+                        .extract_pages(&staged.data, document)
+                        .await
+                        .map_err(IngestionWorkerError::FileLoading)
+                })
+                .await?;
+                run.progress.pages_processed = pages.len() as u32;
+                self.report_progress(run).await;
+
+                let splitter = if document.content_type.is_markdown_source() {
+                    &self.markdown_splitter
+                } else {
+                    &self.text_splitter
+                };
+                let chunks = splitter
+                    .split_pages(&pages, doc_id, Some(metadata))
+                    .await
+                    .map_err(IngestionWorkerError::Splitting)?;
+                Ok((chunks, None))
+            }
+            ContentType::Code => {
+                self.enter_stage(run, JobStatus::Processing).await?;
+                let source = String::from_utf8_lossy(&staged.data);
+                run.progress.pages_processed = 1;
+                self.report_progress(run).await;
+
+                let chunks = match &self.code_splitter {
+                    Some(code_splitter) => {
+                        code_splitter
+                            .split_code(&source, &document.filename, doc_id, Some(metadata))
+                            .await
+                    }
+                    None => {
+                        self.text_splitter
+                            .split_pages(
+                                &[PageSegment::unpaged(source.into_owned())],
+                                doc_id,
+                                Some(metadata),
+                            )
+                            .await
+                    }
+                }
+                .map_err(IngestionWorkerError::Splitting)?;
+                Ok((chunks, None))
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use tokio::sync::watch;
+use tracing::Instrument;
+
+use super::{IngestionWorker, IngestionWorkerError};
+use crate::application::ports::{FileLoader, JOB_CANCELLED, VectorStore};
+use crate::application::services::UrlIngestionError;
+use crate::domain::{CrawlRequest, Job, JobStatus};
+
+const URL_INGESTION_UNAVAILABLE: &str = "URL ingestion is not configured on this worker";
+const NOTHING_CRAWLED: &str = "none of the crawled URLs could be ingested; see fetch_failures";
+
+impl<F, V> IngestionWorker<F, V>
+where
+    F: FileLoader + 'static,
+    V: VectorStore + 'static,
+{
+    /// Runs a crawl job under the same lease and cancellation handling as an ingestion job.
+    /// The crawl fails only when no page could be submitted; per-URL failures are recorded
+    /// on the job by the crawl itself. Crawls are not retried, since a re-run would refetch
+    /// every page.
+    pub(super) async fn run_crawl(&self, job: &Job, request: &CrawlRequest, worker_id: &str) {
+        let Some(url_ingestion) = &self.url_ingestion else {
+            let _ = self
+                .fail_job(job, Some(worker_id), URL_INGESTION_UNAVAILABLE)
+                .await;
+            return;
+        };
+
+        let span = tracing::info_span!(
+            "url_ingestion_job",
+            job_id = %job.id.as_uuid(),
+            url = %request.url,
+            tenant_id = %job.tenant_id,
+            principal = request.principal.as_deref().unwrap_or("-"),
+            worker_id,
+            attempt = job.attempts,
+        );
+
+        let (cancel, cancelled) = watch::channel(job.cancel_requested);
+        let heartbeat = self.spawn_heartbeat(job.id, worker_id.to_string(), cancel);
+        let result = url_ingestion
+            .crawl(job, request, cancelled)
+            .instrument(span)
+            .await;
+        heartbeat.abort();
+
+        let settled = match result {
+            Ok(outcome) if outcome.ingested > 0 => {
+                self.update_status(
+                    job.id,
+                    &job.tenant_id,
+                    Some(worker_id),
+                    JobStatus::Completed,
+                    None,
+                )
+                .await
+            }
+            Ok(_) => self.fail_job(job, Some(worker_id), NOTHING_CRAWLED).await,
+            Err(UrlIngestionError::Cancelled) => {
+                tracing::info!(job_id = %job.id.as_uuid(), "URL ingestion job cancelled");
+                self.update_status(
+                    job.id,
+                    &job.tenant_id,
+                    Some(worker_id),
+                    JobStatus::Cancelled,
+                    Some(JOB_CANCELLED),
+                )
+                .await
+            }
+            Err(e) => {
+                tracing::error!(job_id = %job.id.as_uuid(), error = %e, "URL ingestion job failed");
+                self.fail_job(job, Some(worker_id), &e.to_string()).await
+            }
+        };
+        match settled {
+            Ok(()) => {}
+            Err(IngestionWorkerError::LeaseLost) => tracing::warn!(
+                job_id = %job.id.as_uuid(),
+                "URL ingestion job lease lost; outcome left to its new owner"
+            ),
+            Err(e) => {
+                tracing::error!(job_id = %job.id.as_uuid(), error = %e, "Failed to settle URL ingestion job")
+            }
+        }
+    }
+}
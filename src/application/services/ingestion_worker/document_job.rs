@@ -0,0 +1,193 @@
+use std::sync::Arc;
+
+use tokio::sync::watch;
+use tracing::Instrument;
+
+use super::{IngestionWorker, IngestionWorkerError, JobRun};
+use crate::application::ports::{FileLoader, RepositoryError, VectorStore};
+use crate::domain::{
+    ContentType, EvalEvent, EvalOperationType, EvalSource, IngestionPayload, Job, JobProgress,
+    JobStatus, TenantId, WebhookEvent, WebhookEventType,
+};
+
+impl<F, V> IngestionWorker<F, V>
+where
+    F: FileLoader + 'static,
+    V: VectorStore + 'static,
+{
+    pub(super) async fn run_document_job(
+        &self,
+        job: &Job,
+        payload: IngestionPayload,
+        worker_id: &str,
+    ) {
+        let span = tracing::info_span!(
+            "ingestion_job",
+            job_id = %job.id.as_uuid(),
+            document_id = %payload.document.id.as_uuid(),
+            filename = %payload.document.filename,
+            tenant_id = %job.tenant_id,
+            principal = payload.principal.as_deref().unwrap_or("-"),
+            worker_id,
+            attempt = job.attempts,
+        );
+
+        let (cancel, cancelled) = watch::channel(job.cancel_requested);
+        let heartbeat = self.spawn_heartbeat(job.id, worker_id.to_string(), cancel);
+        let mut run = JobRun {
+            job_id: job.id,
+            tenant_id: job.tenant_id.clone(),
+            worker_id: worker_id.to_string(),
+            cancelled,
+            progress: JobProgress::default(),
+        };
+        let result = self
+            .process_job(job, payload, &mut run)
+            .instrument(span)
+            .await;
+        heartbeat.abort();
+
+        match result {
+            Ok(()) => {}
+            Err(IngestionWorkerError::Cancelled) => {
+                tracing::info!(job_id = %job.id.as_uuid(), "Ingestion job cancelled")
+            }
+            Err(IngestionWorkerError::LeaseLost) => tracing::warn!(
+                job_id = %job.id.as_uuid(),
+                "Ingestion job abandoned after its lease was lost; left to its new owner"
+            ),
+            Err(e) => {
+                tracing::error!(job_id = %job.id.as_uuid(), error = %e, "Ingestion job failed")
+            }
+        }
+    }
+
+    async fn process_job(
+        &self,
+        job: &Job,
+        payload: IngestionPayload,
+        run: &mut JobRun,
+    ) -> Result<(), IngestionWorkerError> {
+        let doc_id = payload.document.id;
+        let content_type = payload.document.content_type;
+        let filename = payload.document.filename.clone();
+
+        let result = self.process_pipeline(run, &payload).await;
+
+        match &result {
+            Ok(output) => {
+                if let Some(documents) = &self.document_repository {
+                    match documents
+                        .mark_ingested(doc_id, output.chunk_count, &output.content_hash)
+                        .await
+                    {
+                        Ok(()) => {}
+                        Err(RepositoryError::NotFound(_)) => {
+                            // Deleted after the last check: drop the chunks just written,
+                            // which nothing could list or delete any more.
+                            self.vector_store
+                                .delete_by_document(doc_id, &[])
+                                .await
+                                .map_err(IngestionWorkerError::VectorStore)?;
+                            let error = IngestionWorkerError::DocumentDeleted;
+                            self.settle_failure(job, &run.worker_id, &error).await?;
+                            return Err(error);
+                        }
+                        Err(e) => return Err(IngestionWorkerError::Repository(e)),
+                    }
+                }
+                if let Some(transcripts) = &self.transcript_repository {
+                    match &output.transcript {
+                        Some(transcript) => transcripts.save(doc_id, transcript).await,
+                        None if payload.replace_existing => transcripts.delete(doc_id).await,
+                        None => Ok(()),
+                    }
+                    .map_err(IngestionWorkerError::Repository)?;
+                }
+                self.enter_stage(run, JobStatus::Completed).await?;
+                tracing::info!(
+                    document_id = %doc_id.as_uuid(),
+                    chunk_count = output.chunk_count,
+                    "Ingestion completed"
+                );
+                self.notify_webhooks(WebhookEvent::new(
+                    job.tenant_id.clone(),
+                    WebhookEventType::DocumentIngested,
+                    serde_json::json!({
+                        "job_id": job.id.as_uuid(),
+                        "document_id": doc_id.as_uuid(),
+                        "filename": filename,
+                        "content_type": content_type.as_mime(),
+                        "chunk_count": output.chunk_count,
+                    }),
+                ))
+                .await;
+                self.fire_and_forget_eval(
+                    &job.tenant_id,
+                    payload.principal.clone(),
+                    content_type,
+                    &filename,
+                    output.chunk_count,
+                    output.chunk_samples.clone(),
+                );
+            }
+            Err(e) => self.settle_failure(job, &run.worker_id, e).await?,
+        }
+
+        result.map(|_| ())
+    }
+
+    fn fire_and_forget_eval(
+        &self,
+        tenant_id: &TenantId,
+        principal: Option<String>,
+        content_type: ContentType,
+        filename: &str,
+        chunk_count: usize,
+        chunk_samples: Vec<EvalSource>,
+    ) {
+        if let (Some(event_repo), Some(outbox_repo)) =
+            (&self.eval_event_repository, &self.eval_outbox_repository)
+        {
+            let op_type = match content_type {
+                ContentType::Audio | ContentType::Video | ContentType::Subtitles => {
+                    EvalOperationType::IngestionMp4
+                }
+                ContentType::Pdf | ContentType::Docx | ContentType::Pptx | ContentType::Xlsx => {
+                    EvalOperationType::IngestionPdf
+                }
+                // Text ingestion treated as a query-type event — no distinct scoring path needed.
+                ContentType::Text
+                | ContentType::Markdown
+                | ContentType::Html
+                | ContentType::Code => EvalOperationType::Query,
+            };
+            let event = EvalEvent::new_ingestion(
+                op_type,
+                filename,
+                chunk_count,
+                &self.model_config,
+                None,
+                chunk_samples,
+            )
+            .with_tenant(tenant_id.clone())
+            .with_principal(principal);
+            let event_repo = Arc::clone(event_repo);
+            let outbox_repo = Arc::clone(outbox_repo);
+            let span = tracing::Span::current();
+            tokio::spawn(
+                async move {
+                    match event_repo.record(&event).await {
+                        Ok(_) => {
+                            if let Err(e) = outbox_repo.enqueue(event.id).await {
+                                tracing::warn!(error = %e, "Failed to enqueue ingestion eval outbox");
+                            }
+                        }
+                        Err(e) => tracing::warn!(error = %e, "Failed to record ingestion eval event"),
+                    }
+                }
+                .instrument(span),
+            );
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::application::ports::{
+    EmbedderError, FileLoaderError, RepositoryError, StagingStoreError, TextSplitterError,
+    TranscriptionError, VectorStoreError,
+};
+use crate::application::services::FailureClass;
+
+#[derive(Debug, thiserror::Error)]
+pub enum IngestionWorkerError {
+    #[error("file loading: {0}")]
+    FileLoading(FileLoaderError),
+    #[error("transcription: {0}")]
+    Transcription(TranscriptionError),
+    #[error("captions: {0}")]
+    Captions(String),
+    #[error("text splitting: {0}")]
+    Splitting(TextSplitterError),
+    #[error("embedding: {0}")]
+    Embedding(EmbedderError),
+    #[error("vector store: {0}")]
+    VectorStore(VectorStoreError),
+    #[error("repository: {0}")]
+    Repository(RepositoryError),
+    #[error("staging store: {0}")]
+    Staging(StagingStoreError),
+    #[error("cancelled")]
+    Cancelled,
+    /// The worker's lease expired and the job may have been released to another worker.
+    #[error("lease lost")]
+    LeaseLost,
+    /// The document was deleted while it was being ingested.
+    #[error("document deleted during ingestion")]
+    DocumentDeleted,
+}
+
+impl IngestionWorkerError {
+    /// Network and availability failures are retried; anything rooted in the document
+    /// itself or in configuration is not.
+    pub fn failure_class(&self) -> FailureClass {
+        match self {
+            Self::Embedding(EmbedderError::RateLimited) => FailureClass::RateLimited,
+            Self::Embedding(EmbedderError::ApiRequestFailed(_))
+            | Self::Transcription(TranscriptionError::ApiRequestFailed(_))
+            | Self::VectorStore(
+                VectorStoreError::ConnectionFailed(_)
+                | VectorStoreError::UpsertFailed(_)
+                | VectorStoreError::DeleteFailed(_),
+            )
+            | Self::Repository(RepositoryError::ConnectionFailed(_))
+            | Self::Staging(StagingStoreError::DownloadFailed(_) | StagingStoreError::Io(_)) => {
+                FailureClass::Transient
+            }
+            _ => FailureClass::Permanent,
+        }
+    }
+}
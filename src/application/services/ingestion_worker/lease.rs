@@ -0,0 +1,196 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use tokio::sync::watch;
+use tracing::Instrument;
+
+use super::{IngestionWorker, MISSING_PAYLOAD};
+use crate::application::ports::{FileLoader, JOB_CANCELLED, LeaseRenewal, VectorStore};
+use crate::application::services::RetryPolicy;
+use crate::domain::{Job, JobEvent, JobId, JobStatus};
+
+/// How an [`IngestionWorker`] pulls jobs from the durable queue in the `jobs` table.
+#[derive(Debug, Clone)]
+pub struct IngestionQueueOptions {
+    /// Prefix of the lease owner recorded on claimed jobs; each slot appends its index.
+    pub worker_id: String,
+    /// Jobs processed concurrently by this worker.
+    pub concurrency: usize,
+    /// Wait between queue polls when no submission wakes the worker earlier.
+    pub poll_interval: Duration,
+    /// How long a claimed job stays reserved without a heartbeat. Also the interval at which
+    /// abandoned jobs are swept back into the queue. Heartbeats, which also pick up
+    /// cancellation requests, run at a third of it.
+    pub lease: Duration,
+    pub retry: RetryPolicy,
+}
+
+impl Default for IngestionQueueOptions {
+    fn default() -> Self {
+        Self {
+            worker_id: format!("worker-{}", std::process::id()),
+            concurrency: 1,
+            poll_interval: Duration::from_secs(1),
+            lease: Duration::from_secs(60),
+            retry: RetryPolicy::default(),
+        }
+    }
+}
+
+impl<F, V> IngestionWorker<F, V>
+where
+    F: FileLoader + 'static,
+    V: VectorStore + 'static,
+{
+    pub(super) async fn claim_loop(self: Arc<Self>, slot: usize) {
+        let worker_id = format!("{}-{slot}", self.queue.worker_id);
+        let mut last_sweep = Instant::now();
+
+        loop {
+            if slot == 0 && last_sweep.elapsed() >= self.queue.lease {
+                self.recover_stale_jobs().await;
+                last_sweep = Instant::now();
+            }
+
+            match self
+                .job_repository
+                .claim_next(&worker_id, self.queue.lease)
+                .await
+            {
+                Ok(Some(job)) => self.run_claimed(job, &worker_id).await,
+                Ok(None) => {
+                    tokio::select! {
+                        _ = self.wakeup.notified() => {}
+                        _ = tokio::time::sleep(self.queue.poll_interval) => {}
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to claim ingestion job");
+                    tokio::time::sleep(self.queue.poll_interval).await;
+                }
+            }
+        }
+    }
+
+    /// Renews the lease at a third of its length until aborted or the lease is lost, raising
+    /// `cancel` once the job is flagged for cancellation or the lease is lost. A run stopped
+    /// by a lost lease cannot settle the job, since its status writes need the lease.
+    pub(super) fn spawn_heartbeat(
+        &self,
+        job_id: JobId,
+        worker_id: String,
+        cancel: watch::Sender<bool>,
+    ) -> tokio::task::JoinHandle<()> {
+        let job_repository = Arc::clone(&self.job_repository);
+        let lease = self.queue.lease;
+        let span = tracing::Span::current();
+        tokio::spawn(
+            async move {
+                let mut interval = tokio::time::interval(lease / 3);
+                interval.tick().await;
+                loop {
+                    interval.tick().await;
+                    match job_repository.renew_lease(job_id, &worker_id, lease).await {
+                        Ok(LeaseRenewal::Renewed) => {}
+                        Ok(LeaseRenewal::CancelRequested) => {
+                            cancel.send_replace(true);
+                        }
+                        Ok(LeaseRenewal::Lost) => {
+                            tracing::warn!(
+                                job_id = %job_id.as_uuid(),
+                                "Lease on ingestion job lost; stopping this attempt"
+                            );
+                            cancel.send_replace(true);
+                            break;
+                        }
+                        Err(e) => tracing::warn!(error = %e, "Failed to renew ingestion job lease"),
+                    }
+                }
+            }
+            .instrument(span),
+        )
+    }
+
+    /// Puts in-flight jobs whose worker stopped heartbeating back in the queue, and settles
+    /// jobs that cannot be resumed: rows without a payload or flagged for cancellation, and
+    /// jobs that already took `max_attempts` workers down with them.
+    pub(super) async fn recover_stale_jobs(&self) {
+        let now = Utc::now();
+
+        for status in JobStatus::IN_FLIGHT {
+            let jobs = match self.job_repository.list_by_status(status).await {
+                Ok(jobs) => jobs,
+                Err(e) => {
+                    tracing::error!(error = %e, status = %status, "Failed to list stale jobs");
+                    continue;
+                }
+            };
+            let stale = jobs
+                .into_iter()
+                .filter(|job| job.lease_expires_at.is_none_or(|expiry| expiry < now));
+            for job in stale {
+                self.recover_job(job).await;
+            }
+        }
+
+        match self.job_repository.list_by_status(JobStatus::Queued).await {
+            Ok(jobs) => {
+                for job in jobs.into_iter().filter(|job| job.payload.is_none()) {
+                    let _ = self.fail_job(&job, None, MISSING_PAYLOAD).await;
+                }
+            }
+            Err(e) => tracing::error!(error = %e, "Failed to list queued jobs"),
+        }
+    }
+
+    async fn recover_job(&self, job: Job) {
+        let job_id = job.id;
+        if job.payload.is_none() {
+            let _ = self.fail_job(&job, None, MISSING_PAYLOAD).await;
+            return;
+        }
+
+        if job.cancel_requested {
+            let _ = self
+                .update_status(
+                    job_id,
+                    &job.tenant_id,
+                    None,
+                    JobStatus::Cancelled,
+                    Some(JOB_CANCELLED),
+                )
+                .await;
+            return;
+        }
+
+        if job.attempts >= self.queue.retry.max_attempts {
+            let error = format!(
+                "worker stopped during ingestion {} times; giving up",
+                job.attempts
+            );
+            tracing::warn!(job_id = %job_id.as_uuid(), attempts = job.attempts, "Abandoning ingestion job");
+            let _ = self.fail_job(&job, None, &error).await;
+            return;
+        }
+
+        match self.job_repository.release_expired(job_id).await {
+            Ok(true) => {
+                tracing::info!(
+                    job_id = %job_id.as_uuid(),
+                    previous_owner = job.lease_owner.as_deref().unwrap_or("-"),
+                    "Re-queued ingestion job abandoned by a stopped worker"
+                );
+                self.publish(JobEvent::status_changed(
+                    job_id,
+                    job.tenant_id.clone(),
+                    JobStatus::Queued,
+                    None,
+                ))
+                .await;
+            }
+            Ok(false) => {}
+            Err(e) => tracing::error!(error = %e, "Failed to re-queue stale ingestion job"),
+        }
+    }
+}
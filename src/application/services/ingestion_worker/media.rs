@@ -0,0 +1,178 @@
+use std::path::Path;
+
+use futures::StreamExt;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+use super::pipeline::StagedContent;
+use super::{IngestionWorker, IngestionWorkerError, JobRun};
+use crate::application::ports::{FileLoader, TranscriptionObserver, VectorStore};
+use crate::domain::{
+    ContentHasher, ContentType, DocumentRecord, IngestionPayload, JobStatus, StoragePath,
+    Transcript, TranscriptSegment,
+};
+
+impl<F, V> IngestionWorker<F, V>
+where
+    F: FileLoader + 'static,
+    V: VectorStore + 'static,
+{
+    /// Fetches the document from staging, spooling audio and video to a local file.
+    pub(super) async fn stage_content(
+        &self,
+        run: &JobRun,
+        payload: &IngestionPayload,
+    ) -> Result<StagedContent, IngestionWorkerError> {
+        let storage_path = &payload.storage_path;
+        if matches!(
+            payload.document.content_type,
+            ContentType::Audio | ContentType::Video
+        ) {
+            let (media, content_hash) =
+                Self::interruptible(run, self.spool_media(storage_path)).await?;
+            return Ok(StagedContent {
+                data: Vec::new(),
+                media: Some(media),
+                content_hash,
+            });
+        }
+
+        let data = self.fetch_staged(run, storage_path).await?;
+        let content_hash = DocumentRecord::hash_content(&data);
+        Ok(StagedContent {
+            data,
+            media: None,
+            content_hash,
+        })
+    }
+
+    /// Produces the timed transcript of an audio, video or caption document: from uploaded
+    /// captions when there are any, otherwise by transcribing the spooled media. Captions
+    /// are folded into the content hash, so replacing them re-ingests the recording.
+    pub(super) async fn capture_transcript(
+        &self,
+        run: &mut JobRun,
+        payload: &IngestionPayload,
+        staged: &mut StagedContent,
+    ) -> Result<Transcript, IngestionWorkerError> {
+        match (&payload.captions, &staged.media) {
+            (Some(captions_path), _) => {
+                self.enter_stage(run, JobStatus::Processing).await?;
+                let captions = self.fetch_staged(run, captions_path).await?;
+                staged.content_hash =
+                    DocumentRecord::hash_captioned(&staged.content_hash, &captions);
+                let segments = Self::parse_captions(&captions)?;
+                tracing::info!(
+                    segment_count = segments.len(),
+                    "Captions used instead of transcription"
+                );
+                Ok(Transcript::new(segments))
+            }
+            (None, None) => {
+                self.enter_stage(run, JobStatus::Processing).await?;
+                Ok(Transcript::new(Self::parse_captions(&staged.data)?))
+            }
+            (None, Some(media)) => {
+                self.enter_stage(run, JobStatus::MediaExtraction).await?;
+                tracing::debug!(content_type = ?payload.document.content_type, "Starting media extraction");
+
+                self.enter_stage(run, JobStatus::Transcribing).await?;
+                tracing::debug!("Starting audio transcription");
+
+                let transcript = self.transcribe_media(run, media.path()).await?;
+
+                tracing::info!(
+                    segment_count = transcript.segments.len(),
+                    language = transcript.language.as_deref(),
+                    "Transcription produced segments"
+                );
+                Ok(transcript)
+            }
+        }
+    }
+
+    async fn fetch_staged(
+        &self,
+        run: &JobRun,
+        path: &StoragePath,
+    ) -> Result<Vec<u8>, IngestionWorkerError> {
+        Self::interruptible(run, async {
+            self.staging_store
+                .fetch(path)
+                .await
+                .map_err(IngestionWorkerError::Staging)
+        })
+        .await
+    }
+
+    fn parse_captions(data: &[u8]) -> Result<Vec<TranscriptSegment>, IngestionWorkerError> {
+        let text = std::str::from_utf8(data)
+            .map_err(|e| IngestionWorkerError::Captions(format!("not UTF-8: {e}")))?;
+        TranscriptSegment::parse_captions(text).map_err(IngestionWorkerError::Captions)
+    }
+
+    /// Streams staged media into a local temporary file, hashing it on the way.
+    async fn spool_media(
+        &self,
+        path: &StoragePath,
+    ) -> Result<(tempfile::NamedTempFile, String), IngestionWorkerError> {
+        let io_error = |e: std::io::Error| IngestionWorkerError::Staging(e.into());
+        let mut stream = self
+            .staging_store
+            .fetch_stream(path)
+            .await
+            .map_err(IngestionWorkerError::Staging)?;
+        let spool = tempfile::Builder::new()
+            .suffix(".media")
+            .tempfile()
+            .map_err(io_error)?;
+        let mut file = tokio::fs::File::from_std(spool.as_file().try_clone().map_err(io_error)?);
+
+        let mut hasher = ContentHasher::new();
+        while let Some(bytes) = stream.next().await {
+            let bytes = bytes.map_err(IngestionWorkerError::Staging)?;
+            hasher.update(&bytes);
+            file.write_all(&bytes).await.map_err(io_error)?;
+        }
+        file.flush().await.map_err(io_error)?;
+
+        Ok((spool, hasher.finish()))
+    }
+
+    /// Transcribes spooled media, recording each segment in the job's progress as the engine
+    /// produces it. Cancellation abandons the transcription between segments.
+    async fn transcribe_media(
+        &self,
+        run: &mut JobRun,
+        media: &Path,
+    ) -> Result<Transcript, IngestionWorkerError> {
+        let (progress, mut transcribed) = mpsc::unbounded_channel();
+        let observer = SegmentProgress(progress);
+        let transcription = self.transcription_engine.transcribe_file(media, &observer);
+        tokio::pin!(transcription);
+
+        let mut cancelled = run.cancelled.clone();
+        loop {
+            tokio::select! {
+                result = &mut transcription => {
+                    return result.map_err(IngestionWorkerError::Transcription);
+                }
+                Some(()) = transcribed.recv() => {}
+                Ok(_) = cancelled.wait_for(|cancelled| *cancelled) => {
+                    return Err(IngestionWorkerError::Cancelled);
+                }
+            }
+            run.progress.segments_transcribed += 1;
+            self.report_progress(run).await;
+        }
+    }
+}
+
+/// Forwards each transcribed segment to the job running the transcription.
+struct SegmentProgress(mpsc::UnboundedSender<()>);
+
+impl TranscriptionObserver for SegmentProgress {
+    fn segment_transcribed(&self, _segment: &TranscriptSegment) {
+        let _ = self.0.send(());
+    }
+}
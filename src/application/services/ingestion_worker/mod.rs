@@ -0,0 +1,224 @@
+use std::sync::Arc;
+
+use tokio::sync::{Notify, watch};
+use tokio::task::JoinSet;
+
+use crate::application::ports::{
+    CodeSplitter, DocumentRepository, Embedder, EvalEventRepository, EvalOutboxRepository,
+    FileLoader, JobEventBus, JobRepository, SparseEmbedder, StagingStore, TextSplitter,
+    TranscriptRepository, TranscriptionEngine, VectorStore, WebhookRepository,
+};
+use crate::application::services::{RepositoryIngestionService, UrlIngestionService};
+use crate::domain::{Job, JobId, JobPayload, JobProgress, TenantId};
+
+mod chunking;
+mod crawl_job;
+mod document_job;
+mod error;
+mod lease;
+mod media;
+mod pipeline;
+mod repository_sync_job;
+mod settlement;
+
+pub use error::IngestionWorkerError;
+pub use lease::IngestionQueueOptions;
+
+/// State of one claimed run, shared by the pipeline stages.
+struct JobRun {
+    job_id: JobId,
+    tenant_id: TenantId,
+    /// Lease owner of the claim; every status write is conditional on still holding it.
+    worker_id: String,
+    cancelled: watch::Receiver<bool>,
+    progress: JobProgress,
+}
+
+/// Claims ingestion jobs from the [`JobRepository`] queue and runs the extraction, splitting
+/// and embedding pipeline. Any number of workers, in or out of the server process, can share
+/// one queue; a job abandoned by a crashed worker is re-queued once its lease expires.
+/// Web crawl and repository sync jobs are claimed from the same queue and handed to a
+/// [`UrlIngestionService`] and a [`RepositoryIngestionService`] respectively.
+pub struct IngestionWorker<F, V>
+where
+    V: VectorStore,
+{
+    file_loader: Arc<F>,
+    embedder: Arc<dyn Embedder>,
+    vector_store: Arc<V>,
+    text_splitter: Arc<dyn TextSplitter>,
+    markdown_splitter: Arc<dyn TextSplitter>,
+    code_splitter: Option<Arc<dyn CodeSplitter>>,
+    job_repository: Arc<dyn JobRepository>,
+    transcription_engine: Arc<dyn TranscriptionEngine>,
+    staging_store: Arc<dyn StagingStore>,
+    sparse_embedder: Option<Arc<dyn SparseEmbedder>>,
+    document_repository: Option<Arc<dyn DocumentRepository>>,
+    transcript_repository: Option<Arc<dyn TranscriptRepository>>,
+    eval_event_repository: Option<Arc<dyn EvalEventRepository>>,
+    eval_outbox_repository: Option<Arc<dyn EvalOutboxRepository>>,
+    model_config: String,
+    job_events: Option<Arc<dyn JobEventBus>>,
+    webhooks: Option<Arc<dyn WebhookRepository>>,
+    url_ingestion: Option<Arc<UrlIngestionService<V>>>,
+    repository_ingestion: Option<Arc<RepositoryIngestionService<V>>>,
+    queue: IngestionQueueOptions,
+    wakeup: Arc<Notify>,
+}
+
+impl<F, V> IngestionWorker<F, V>
+where
+    F: FileLoader + 'static,
+    V: VectorStore + 'static,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        file_loader: Arc<F>,
+        embedder: Arc<dyn Embedder>,
+        vector_store: Arc<V>,
+        text_splitter: Arc<dyn TextSplitter>,
+        markdown_splitter: Arc<dyn TextSplitter>,
+        job_repository: Arc<dyn JobRepository>,
+        transcription_engine: Arc<dyn TranscriptionEngine>,
+        staging_store: Arc<dyn StagingStore>,
+    ) -> Self {
+        Self {
+            file_loader,
+            embedder,
+            vector_store,
+            text_splitter,
+            markdown_splitter,
+            code_splitter: None,
+            job_repository,
+            transcription_engine,
+            staging_store,
+            sparse_embedder: None,
+            document_repository: None,
+            transcript_repository: None,
+            eval_event_repository: None,
+            eval_outbox_repository: None,
+            model_config: String::new(),
+            job_events: None,
+            webhooks: None,
+            url_ingestion: None,
+            repository_ingestion: None,
+            queue: IngestionQueueOptions::default(),
+            wakeup: Arc::new(Notify::new()),
+        }
+    }
+
+    pub fn with_eval(
+        mut self,
+        eval_event_repository: Arc<dyn EvalEventRepository>,
+        eval_outbox_repository: Arc<dyn EvalOutboxRepository>,
+        model_config: &str,
+    ) -> Self {
+        self.eval_event_repository = Some(eval_event_repository);
+        self.eval_outbox_repository = Some(eval_outbox_repository);
+        self.model_config = model_config.to_string();
+        self
+    }
+
+    pub fn with_sparse_embedder(mut self, sparse_embedder: Arc<dyn SparseEmbedder>) -> Self {
+        self.sparse_embedder = Some(sparse_embedder);
+        self
+    }
+
+    pub fn with_document_repository(
+        mut self,
+        document_repository: Arc<dyn DocumentRepository>,
+    ) -> Self {
+        self.document_repository = Some(document_repository);
+        self
+    }
+
+    /// Keeps the full transcript of each audio, video and caption document.
+    pub fn with_transcript_repository(
+        mut self,
+        transcript_repository: Arc<dyn TranscriptRepository>,
+    ) -> Self {
+        self.transcript_repository = Some(transcript_repository);
+        self
+    }
+
+    /// Publishes status transitions and progress for live subscribers.
+    pub fn with_job_events(mut self, job_events: Arc<dyn JobEventBus>) -> Self {
+        self.job_events = Some(job_events);
+        self
+    }
+
+    /// Queues `document.ingested` and `job.failed` webhook deliveries for the job's tenant.
+    pub fn with_webhooks(mut self, webhooks: Arc<dyn WebhookRepository>) -> Self {
+        self.webhooks = Some(webhooks);
+        self
+    }
+
+    /// Runs web crawl jobs; without it they fail as unsupported.
+    pub fn with_url_ingestion(mut self, url_ingestion: Arc<UrlIngestionService<V>>) -> Self {
+        self.url_ingestion = Some(url_ingestion);
+        self
+    }
+
+    /// Runs repository sync jobs; without it they fail as unsupported.
+    pub fn with_repository_ingestion(
+        mut self,
+        repository_ingestion: Arc<RepositoryIngestionService<V>>,
+    ) -> Self {
+        self.repository_ingestion = Some(repository_ingestion);
+        self
+    }
+
+    /// Chunks source code along syntactic boundaries; without it source files are split as
+    /// plain text.
+    pub fn with_code_splitter(mut self, code_splitter: Arc<dyn CodeSplitter>) -> Self {
+        self.code_splitter = Some(code_splitter);
+        self
+    }
+
+    pub fn with_queue_options(mut self, queue: IngestionQueueOptions) -> Self {
+        self.queue = queue;
+        self
+    }
+
+    /// Shares the signal submitters raise after enqueueing, so an idle worker in the same
+    /// process picks the job up without waiting for the next poll.
+    pub fn with_wakeup(mut self, wakeup: Arc<Notify>) -> Self {
+        self.wakeup = wakeup;
+        self
+    }
+
+    pub async fn run(self) {
+        let concurrency = self.queue.concurrency.max(1);
+        tracing::info!(
+            worker_id = %self.queue.worker_id,
+            concurrency,
+            "Ingestion worker started"
+        );
+        let worker = Arc::new(self);
+        worker.recover_stale_jobs().await;
+
+        let mut slots = JoinSet::new();
+        for slot in 0..concurrency {
+            slots.spawn(Arc::clone(&worker).claim_loop(slot));
+        }
+        while slots.join_next().await.is_some() {}
+    }
+
+    async fn run_claimed(&self, job: Job, worker_id: &str) {
+        match job.payload.clone() {
+            Some(JobPayload::Ingestion(payload)) => {
+                self.run_document_job(&job, payload, worker_id).await
+            }
+            Some(JobPayload::Crawl(request)) => self.run_crawl(&job, &request, worker_id).await,
+            Some(JobPayload::RepositorySync(request)) => {
+                self.run_repository_sync(&job, &request, worker_id).await
+            }
+            None => {
+                let _ = self.fail_job(&job, Some(worker_id), MISSING_PAYLOAD).await;
+            }
+        }
+    }
+}
+
+const MISSING_PAYLOAD: &str =
+    "job was queued before ingestion jobs were persisted; resubmit the document";
@@ -0,0 +1,199 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use super::{IngestionWorker, IngestionWorkerError, JobRun};
+use crate::application::ports::{FileLoader, VectorStore};
+use crate::domain::{
+    Chunk, ChunkId, DocumentId, DocumentMetadata, Embedding, EvalSource, IngestionPayload,
+    JobStatus, SparseEmbedding, Transcript,
+};
+
+const MAX_EVAL_CHUNK_SAMPLES: usize = 5;
+/// Chunks embedded per call; progress is reported and cancellation checked in between.
+const EMBED_BATCH_SIZE: usize = 32;
+
+pub(super) struct PipelineOutput {
+    pub(super) chunk_count: usize,
+    pub(super) chunk_samples: Vec<EvalSource>,
+    pub(super) content_hash: String,
+    /// Timed segments of audio, video and caption documents.
+    pub(super) transcript: Option<Transcript>,
+}
+
+/// A document fetched from staging. Media is spooled to disk rather than loaded, so the
+/// transcription engine can stream it however long the recording is.
+pub(super) struct StagedContent {
+    pub(super) data: Vec<u8>,
+    pub(super) media: Option<tempfile::NamedTempFile>,
+    pub(super) content_hash: String,
+}
+
+impl<F, V> IngestionWorker<F, V>
+where
+    F: FileLoader + 'static,
+    V: VectorStore + 'static,
+{
+    pub(super) async fn process_pipeline(
+        &self,
+        run: &mut JobRun,
+        payload: &IngestionPayload,
+    ) -> Result<PipelineOutput, IngestionWorkerError> {
+        let document = &payload.document;
+        let doc_id = document.id;
+
+        let mut staged = self.stage_content(run, payload).await?;
+        let metadata = Arc::new(
+            DocumentMetadata::from_document(document, None)
+                .with_source(&payload.source)
+                .with_tenant(run.tenant_id.clone()),
+        );
+        let (chunks, transcript) = self
+            .chunk_document(run, payload, &mut staged, metadata)
+            .await?;
+        Self::ensure_not_cancelled(run)?;
+
+        if chunks.is_empty() {
+            self.vector_store
+                .delete_by_document(doc_id, &[])
+                .await
+                .map_err(IngestionWorkerError::VectorStore)?;
+            return Ok(PipelineOutput {
+                chunk_count: 0,
+                chunk_samples: vec![],
+                content_hash: staged.content_hash,
+                transcript,
+            });
+        }
+
+        let chunk_samples: Vec<EvalSource> = chunks
+            .iter()
+            .take(MAX_EVAL_CHUNK_SAMPLES)
+            .map(|c| EvalSource {
+                text: c.text.clone(),
+                page: c.page,
+                end_page: c.end_page,
+                score: 0.0,
+            })
+            .collect();
+
+        self.enter_stage(run, JobStatus::Embedding).await?;
+        run.progress.chunks_total = chunks.len() as u32;
+        self.report_progress(run).await;
+        let (embeddings, sparse_embeddings) = self.embed_chunks(run, &chunks).await?;
+
+        // Last checkpoint: once chunks are written the run completes.
+        Self::ensure_not_cancelled(run)?;
+        self.ensure_document_exists(doc_id).await?;
+
+        if let Some(sparse_embeddings) = sparse_embeddings {
+            self.vector_store
+                .upsert_hybrid(&chunks, &embeddings, &sparse_embeddings)
+                .await
+                .map_err(IngestionWorkerError::VectorStore)?;
+        } else {
+            self.vector_store
+                .upsert(&chunks, &embeddings)
+                .await
+                .map_err(IngestionWorkerError::VectorStore)?;
+        }
+
+        // Chunk ids are fresh on every attempt, so this prunes both a previous document
+        // version and whatever an earlier attempt of this job upserted before it failed.
+        let current: Vec<ChunkId> = chunks.iter().map(|c| c.id).collect();
+        self.vector_store
+            .delete_by_document(doc_id, &current)
+            .await
+            .map_err(IngestionWorkerError::VectorStore)?;
+        tracing::debug!("Stale chunks of the document pruned");
+
+        Ok(PipelineOutput {
+            chunk_count: chunks.len(),
+            chunk_samples,
+            content_hash: staged.content_hash,
+            transcript,
+        })
+    }
+
+    /// Embeds the chunks in batches, densely and, with a sparse embedder, sparsely too.
+    async fn embed_chunks(
+        &self,
+        run: &mut JobRun,
+        chunks: &[Chunk],
+    ) -> Result<(Vec<Embedding>, Option<Vec<SparseEmbedding>>), IngestionWorkerError> {
+        let contextual_strings: Vec<String> =
+            chunks.iter().map(|c| c.as_contextual_string()).collect();
+        let texts: Vec<&str> = contextual_strings.iter().map(String::as_str).collect();
+
+        let mut embeddings = Vec::with_capacity(texts.len());
+        let mut sparse_embeddings = self
+            .sparse_embedder
+            .as_ref()
+            .map(|_| Vec::with_capacity(texts.len()));
+
+        for batch in texts.chunks(EMBED_BATCH_SIZE) {
+            embeddings.extend(
+                Self::interruptible(run, async {
+                    self.embedder
+                        .embed_batch(batch)
+                        .await
+                        .map_err(IngestionWorkerError::Embedding)
+                })
+                .await?,
+            );
+            if let (Some(sparse), Some(sparse_embeddings)) =
+                (&self.sparse_embedder, sparse_embeddings.as_mut())
+            {
+                sparse_embeddings.extend(
+                    Self::interruptible(run, async {
+                        sparse
+                            .embed_sparse_batch(batch)
+                            .await
+                            .map_err(IngestionWorkerError::Embedding)
+                    })
+                    .await?,
+                );
+            }
+            run.progress.chunks_embedded += batch.len() as u32;
+            self.report_progress(run).await;
+        }
+
+        Ok((embeddings, sparse_embeddings))
+    }
+
+    /// Stops the run when its document was deleted while it was processed, so no chunks are
+    /// written for a catalog row that no longer exists.
+    async fn ensure_document_exists(&self, id: DocumentId) -> Result<(), IngestionWorkerError> {
+        let Some(documents) = &self.document_repository else {
+            return Ok(());
+        };
+        match documents
+            .get_by_id(id)
+            .await
+            .map_err(IngestionWorkerError::Repository)?
+        {
+            Some(_) => Ok(()),
+            None => Err(IngestionWorkerError::DocumentDeleted),
+        }
+    }
+
+    /// Runs a stage that writes nothing, abandoning it as soon as cancellation is raised.
+    pub(super) async fn interruptible<T>(
+        run: &JobRun,
+        stage: impl Future<Output = Result<T, IngestionWorkerError>>,
+    ) -> Result<T, IngestionWorkerError> {
+        let mut cancelled = run.cancelled.clone();
+        tokio::select! {
+            result = stage => result,
+            Ok(_) = cancelled.wait_for(|cancelled| *cancelled) => {
+                Err(IngestionWorkerError::Cancelled)
+            }
+        }
+    }
+
+    fn ensure_not_cancelled(run: &JobRun) -> Result<(), IngestionWorkerError> {
+        if *run.cancelled.borrow() {
+            return Err(IngestionWorkerError::Cancelled);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use tokio::sync::watch;
+use tracing::Instrument;
+
+use super::{IngestionWorker, IngestionWorkerError};
+use crate::application::ports::{FileLoader, JOB_CANCELLED, VectorStore};
+use crate::application::services::RepositoryIngestionError;
+use crate::domain::{Job, JobStatus, RepositorySyncRequest};
+
+const REPOSITORY_INGESTION_UNAVAILABLE: &str =
+    "repository ingestion is not configured on this worker";
+const NOTHING_SYNCED: &str = "none of the repository files could be ingested; see fetch_failures";
+
+impl<F, V> IngestionWorker<F, V>
+where
+    F: FileLoader + 'static,
+    V: VectorStore + 'static,
+{
+    /// Runs a repository sync job under the same lease and cancellation handling as a crawl.
+    /// Per-file failures are recorded on the job; the sync fails only when files failed and
+    /// none could be ingested or confirmed unchanged.
+    pub(super) async fn run_repository_sync(
+        &self,
+        job: &Job,
+        request: &RepositorySyncRequest,
+        worker_id: &str,
+    ) {
+        let Some(repository_ingestion) = &self.repository_ingestion else {
+            let _ = self
+                .fail_job(job, Some(worker_id), REPOSITORY_INGESTION_UNAVAILABLE)
+                .await;
+            return;
+        };
+
+        let span = tracing::info_span!(
+            "repository_sync_job",
+            job_id = %job.id.as_uuid(),
+            repository = %request.name,
+            tenant_id = %job.tenant_id,
+            principal = request.principal.as_deref().unwrap_or("-"),
+            worker_id,
+            attempt = job.attempts,
+        );
+
+        let (cancel, cancelled) = watch::channel(job.cancel_requested);
+        let heartbeat = self.spawn_heartbeat(job.id, worker_id.to_string(), cancel);
+        let result = repository_ingestion
+            .sync(job, request, cancelled)
+            .instrument(span)
+            .await;
+        heartbeat.abort();
+
+        let settled = match result {
+            Ok(outcome) if outcome.failed > 0 && outcome.ingested + outcome.unchanged == 0 => {
+                self.fail_job(job, Some(worker_id), NOTHING_SYNCED).await
+            }
+            Ok(_) => {
+                self.update_status(
+                    job.id,
+                    &job.tenant_id,
+                    Some(worker_id),
+                    JobStatus::Completed,
+                    None,
+                )
+                .await
+            }
+            Err(RepositoryIngestionError::Cancelled) => {
+                tracing::info!(job_id = %job.id.as_uuid(), "Repository sync job cancelled");
+                self.update_status(
+                    job.id,
+                    &job.tenant_id,
+                    Some(worker_id),
+                    JobStatus::Cancelled,
+                    Some(JOB_CANCELLED),
+                )
+                .await
+            }
+            Err(e) => {
+                tracing::error!(job_id = %job.id.as_uuid(), error = %e, "Repository sync job failed");
+                self.fail_job(job, Some(worker_id), &e.to_string()).await
+            }
+        };
+        match settled {
+            Ok(()) => {}
+            Err(IngestionWorkerError::LeaseLost) => tracing::warn!(
+                job_id = %job.id.as_uuid(),
+                "Repository sync job lease lost; outcome left to its new owner"
+            ),
+            Err(e) => {
+                tracing::error!(job_id = %job.id.as_uuid(), error = %e, "Failed to settle repository sync job")
+            }
+        }
+    }
+}
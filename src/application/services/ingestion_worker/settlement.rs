@@ -0,0 +1,207 @@
+use super::{IngestionWorker, IngestionWorkerError, JobRun};
+use crate::application::ports::{FileLoader, JOB_CANCELLED, VectorStore};
+use crate::domain::{Job, JobEvent, JobId, JobStatus, TenantId, WebhookEvent, WebhookEventType};
+
+impl<F, V> IngestionWorker<F, V>
+where
+    F: FileLoader + 'static,
+    V: VectorStore + 'static,
+{
+    /// Re-queues a failed attempt with backoff when its [`FailureClass`] and the attempt
+    /// budget allow it; otherwise the job ends `CANCELLED` or `FAILED`. Nothing is written
+    /// once `worker_id` has lost the lease.
+    ///
+    /// [`FailureClass`]: crate::application::services::FailureClass
+    pub(super) async fn settle_failure(
+        &self,
+        job: &Job,
+        worker_id: &str,
+        error: &IngestionWorkerError,
+    ) -> Result<(), IngestionWorkerError> {
+        let error_msg = error.to_string();
+        let mut status = JobStatus::Failed;
+
+        if matches!(error, IngestionWorkerError::LeaseLost) {
+            return Ok(());
+        }
+        if matches!(
+            error,
+            IngestionWorkerError::Cancelled | IngestionWorkerError::DocumentDeleted
+        ) {
+            status = JobStatus::Cancelled;
+        } else if let Some(delay) = self
+            .queue
+            .retry
+            .backoff(error.failure_class(), job.attempts)
+        {
+            if self
+                .job_repository
+                .schedule_retry(job.id, worker_id, delay, &error_msg)
+                .await
+                .map_err(IngestionWorkerError::Repository)?
+            {
+                tracing::info!(
+                    attempt = job.attempts,
+                    retry_in_ms = delay.as_millis() as u64,
+                    "Ingestion attempt failed; job re-queued"
+                );
+                self.publish(JobEvent::status_changed(
+                    job.id,
+                    job.tenant_id.clone(),
+                    JobStatus::Queued,
+                    Some(error_msg),
+                ))
+                .await;
+                return Ok(());
+            }
+            // Cancellation was requested while the attempt was failing, or the lease was
+            // lost, in which case the cancellation write below fails as well.
+            status = JobStatus::Cancelled;
+        }
+
+        match status {
+            JobStatus::Cancelled => {
+                let message = match error {
+                    IngestionWorkerError::DocumentDeleted => error_msg.as_str(),
+                    _ => JOB_CANCELLED,
+                };
+                self.update_status(
+                    job.id,
+                    &job.tenant_id,
+                    Some(worker_id),
+                    status,
+                    Some(message),
+                )
+                .await
+            }
+            _ => self.fail_job(job, Some(worker_id), &error_msg).await,
+        }
+    }
+
+    /// Moves a run into its next pipeline stage under the run's lease.
+    pub(super) async fn enter_stage(
+        &self,
+        run: &JobRun,
+        status: JobStatus,
+    ) -> Result<(), IngestionWorkerError> {
+        self.update_status(
+            run.job_id,
+            &run.tenant_id,
+            Some(&run.worker_id),
+            status,
+            None,
+        )
+        .await
+    }
+
+    /// Progress is informational, so a failed write is logged rather than failing the job.
+    /// A refused write means the lease was lost, which the heartbeat acts on.
+    pub(super) async fn report_progress(&self, run: &JobRun) {
+        match self
+            .job_repository
+            .update_progress(run.job_id, &run.worker_id, &run.progress)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                tracing::debug!("Progress not recorded: job lease lost");
+                return;
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to record ingestion job progress");
+                return;
+            }
+        }
+        self.publish(JobEvent::progress(
+            run.job_id,
+            run.tenant_id.clone(),
+            run.progress.clone(),
+        ))
+        .await;
+    }
+
+    /// Writes a status transition. With a `lease_owner` the write only lands while that
+    /// worker still holds the job's lease, and fails with `LeaseLost` otherwise.
+    pub(super) async fn update_status(
+        &self,
+        job_id: JobId,
+        tenant_id: &TenantId,
+        lease_owner: Option<&str>,
+        status: JobStatus,
+        error_message: Option<&str>,
+    ) -> Result<(), IngestionWorkerError> {
+        tracing::debug!(status = %status, "Job status transition");
+        match lease_owner {
+            Some(worker_id) => {
+                let updated = self
+                    .job_repository
+                    .update_leased_status(job_id, worker_id, status, error_message)
+                    .await
+                    .map_err(IngestionWorkerError::Repository)?;
+                if !updated {
+                    return Err(IngestionWorkerError::LeaseLost);
+                }
+            }
+            None => self
+                .job_repository
+                .update_status(job_id, status, error_message)
+                .await
+                .map_err(IngestionWorkerError::Repository)?,
+        }
+        self.publish(JobEvent::status_changed(
+            job_id,
+            tenant_id.clone(),
+            status,
+            error_message.map(str::to_string),
+        ))
+        .await;
+        Ok(())
+    }
+
+    /// Ends the job `FAILED` and tells `job.failed` subscribers.
+    pub(super) async fn fail_job(
+        &self,
+        job: &Job,
+        lease_owner: Option<&str>,
+        error: &str,
+    ) -> Result<(), IngestionWorkerError> {
+        self.update_status(
+            job.id,
+            &job.tenant_id,
+            lease_owner,
+            JobStatus::Failed,
+            Some(error),
+        )
+        .await?;
+        let document = job.ingestion_payload().map(|payload| &payload.document);
+        self.notify_webhooks(WebhookEvent::new(
+            job.tenant_id.clone(),
+            WebhookEventType::JobFailed,
+            serde_json::json!({
+                "job_id": job.id.as_uuid(),
+                "job_type": job.job_type,
+                "document_id": job.document_id.map(|id| id.as_uuid()),
+                "filename": document.map(|d| d.filename.as_str()),
+                "attempts": job.attempts,
+                "error": error,
+            }),
+        ))
+        .await;
+        Ok(())
+    }
+
+    /// Webhooks are best-effort from the job's point of view; a failed enqueue is logged.
+    pub(super) async fn notify_webhooks(&self, event: WebhookEvent) {
+        if let Some(webhooks) = &self.webhooks
+            && let Err(e) = webhooks.enqueue(&event).await
+        {
+            tracing::warn!(error = %e, event_type = %event.event_type, "Failed to queue webhook event");
+        }
+    }
+
+    pub(super) async fn publish(&self, event: JobEvent) {
+        if let Some(job_events) = &self.job_events {
+            job_events.publish(&event).await;
+        }
+    }
+}
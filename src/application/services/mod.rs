@@ -1,18 +1,47 @@
 mod agent;
+mod api_key_service;
+mod document_service;
 pub mod eval_metrics;
+mod eval_runner;
 mod eval_worker;
 mod ingestion_service;
 mod ingestion_worker;
+mod principal_scope;
+mod query_transformer;
+mod repository_ingestion_service;
 mod retrieval_service;
+mod retry_policy;
+mod tenant_scope;
 mod token_counter;
+mod url_ingestion_service;
+mod webhook_dispatcher;
+mod webhook_service;
 
 pub use crate::application::errors::AgentError;
 pub use agent::{
     AgentChatRequest, AgentChatResponse, AgentProgressEvent, AgentService, AgentServicePort,
     DEFAULT_AGENT_SYSTEM_PROMPT, DEFAULT_CRITIC_PROMPT,
 };
+pub use api_key_service::{ApiKeyService, ApiKeyServiceError};
+pub use document_service::{
+    DocumentService, DocumentServiceError, IngestionSubmission, SubmissionOutcome,
+};
+pub use eval_runner::{EvalRunner, EvalRunnerError, EvalTarget};
 pub use eval_worker::{EvalWorker, EvalWorkerError};
 pub use ingestion_service::{IngestionError, IngestionService};
-pub use ingestion_worker::{IngestionMessage, IngestionWorker, IngestionWorkerError};
+pub use ingestion_worker::{IngestionQueueOptions, IngestionWorker, IngestionWorkerError};
+pub use principal_scope::{current_principal, run_as_principal};
+pub use query_transformer::QueryTransformOptions;
+pub use repository_ingestion_service::{
+    REPOSITORY_ROOT_ATTRIBUTE, REPOSITORY_SYNC_JOB, RepositoryIngestionError,
+    RepositoryIngestionOptions, RepositoryIngestionService, SyncOutcome,
+};
 pub use retrieval_service::{QueryResponse, RetrievalService, StreamingQueryResponse};
+pub use retry_policy::{FailureClass, RetryPolicy};
+pub use tenant_scope::{current_tenant, run_as_tenant};
 pub use token_counter::count_tokens;
+pub use url_ingestion_service::{
+    CrawlOutcome, URL_INGESTION_JOB, UrlIngestionError, UrlIngestionOptions, UrlIngestionService,
+};
+pub use webhook_dispatcher::{WebhookDispatchOptions, WebhookDispatcher};
+pub use webhook_service::{WebhookService, WebhookServiceError};
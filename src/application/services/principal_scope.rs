@@ -0,0 +1,23 @@
+use std::future::Future;
+
+use crate::domain::Principal;
+
+tokio::task_local! {
+    static CURRENT_PRINCIPAL: Principal;
+}
+
+/// Runs `future` with `principal` as the authenticated caller.
+///
+/// Set by the HTTP auth middleware around each request so services can attribute the
+/// records they produce (eval events, ingestion jobs) without threading it through every call.
+pub async fn run_as_principal<F>(principal: Principal, future: F) -> F::Output
+where
+    F: Future,
+{
+    CURRENT_PRINCIPAL.scope(principal, future).await
+}
+
+/// The ambient principal, or `None` outside [`run_as_principal`] (CLI, background workers).
+pub fn current_principal() -> Option<Principal> {
+    CURRENT_PRINCIPAL.try_with(Clone::clone).ok()
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use crate::application::ports::{LlmClient, SearchResult};
+use crate::domain::{ChunkId, Message, MessageRole};
+
+/// Standard RRF damping constant (Cormack et al.); rank 1 scores 1/61.
+const RRF_K: f32 = 60.0;
+
+/// Which query transformations run before retrieval. Everything is off by default, in
+/// which case the question is embedded verbatim.
+#[derive(Debug, Clone, Default)]
+pub struct QueryTransformOptions {
+    /// Rewrite follow-up questions into standalone ones using the conversation history.
+    pub condense: bool,
+    /// Most recent user/assistant messages shown to the LLM when condensing.
+    pub history_messages: usize,
+    /// Number of LLM paraphrases searched alongside the question.
+    pub paraphrases: usize,
+    /// Also search with a hypothetical answer passage (HyDE).
+    pub hyde: bool,
+}
+
+impl QueryTransformOptions {
+    /// `true` when retrieval may issue more than one search.
+    pub fn expands(&self) -> bool {
+        self.paraphrases > 0 || self.hyde
+    }
+}
+
+/// Rewrites `question` into a standalone question given the preceding turns. Falls back to
+/// the original question when there is no usable history or the LLM call fails.
+#[tracing::instrument(skip_all, fields(history_messages = history.len(), condensed))]
+pub(crate) async fn condense_question<L: LlmClient>(
+    llm_client: &L,
+    history: &[Message],
+    question: &str,
+) -> String {
+    let transcript: Vec<String> = history
+        .iter()
+        .filter_map(|m| match m.role {
+            MessageRole::User => Some(format!("User: {}", m.content)),
+            MessageRole::Assistant => Some(format!("Assistant: {}", m.content)),
+            _ => None,
+        })
+        .collect();
+
+    if transcript.is_empty() {
+        tracing::Span::current().record("condensed", false);
+        return question.to_string();
+    }
+
+    let prompt = format!(
+        "Given the conversation below and a follow-up question, rewrite the follow-up as a \
+         standalone question that can be understood without the conversation. Resolve \
+         pronouns and references. Keep the language of the follow-up. Respond ONLY with the \
+         rewritten question.\n\n\
+         Conversation:\n{}\n\n\
+         Follow-up question: {question}",
+        transcript.join("\n")
+    );
+
+    match llm_client.complete(&prompt, "").await {
+        Ok(raw) => match first_line(&raw) {
+            Some(standalone) => {
+                tracing::Span::current().record("condensed", true);
+                tracing::debug!(standalone = %standalone, "Condensed follow-up question");
+                standalone
+            }
+            None => {
+                tracing::Span::current().record("condensed", false);
+                question.to_string()
+            }
+        },
+        Err(e) => {
+            tracing::warn!(error = %e, "Question condensing failed; using original question");
+            tracing::Span::current().record("condensed", false);
+            question.to_string()
+        }
+    }
+}
+
+/// Asks for `count` alternative phrasings of `question`, one per line. Returns fewer (or
+/// none) when the LLM fails or answers with fewer lines.
+#[tracing::instrument(skip(llm_client, question), fields(generated))]
+pub(crate) async fn paraphrase_question<L: LlmClient>(
+    llm_client: &L,
+    question: &str,
+    count: usize,
+) -> Vec<String> {
+    let prompt = format!(
+        "Write {count} different search queries that express the same information need as \
+         the question below, using different wording and synonyms. Keep the language of the \
+         question. Respond ONLY with the queries, one per line, without numbering.\n\n\
+         Question: {question}"
+    );
+
+    let paraphrases: Vec<String> = match llm_client.complete(&prompt, "").await {
+        Ok(raw) => raw
+            .lines()
+            .filter_map(clean_line)
+            .filter(|line| !line.eq_ignore_ascii_case(question))
+            .take(count)
+            .collect(),
+        Err(e) => {
+            tracing::warn!(error = %e, "Query paraphrasing failed; searching original only");
+            Vec::new()
+        }
+    };
+
+    tracing::Span::current().record("generated", paraphrases.len());
+    paraphrases
+}
+
+/// Generates a short passage that would answer `question`, to be embedded in its place
+/// (Hypothetical Document Embeddings).
+#[tracing::instrument(skip_all, fields(generated))]
+pub(crate) async fn hypothetical_answer<L: LlmClient>(
+    llm_client: &L,
+    question: &str,
+) -> Option<String> {
+    let prompt = format!(
+        "Write a short, factual passage (3-5 sentences) that directly answers the question \
+         below, as it might appear in a reference document. Keep the language of the \
+         question. Respond ONLY with the passage.\n\n\
+         Question: {question}"
+    );
+
+    let passage = match llm_client.complete(&prompt, "").await {
+        Ok(raw) => Some(raw.trim().to_string()).filter(|p| !p.is_empty()),
+        Err(e) => {
+            tracing::warn!(error = %e, "HyDE generation failed; searching original only");
+            None
+        }
+    };
+
+    tracing::Span::current().record("generated", passage.is_some());
+    passage
+}
+
+/// Merges ranked result lists with Reciprocal Rank Fusion and returns the best `limit`.
+///
+/// Order follows the fused score; each result keeps its best vector `score` from any list,
+/// so the similarity threshold still applies to the fused list.
+pub(crate) fn reciprocal_rank_fusion(
+    result_lists: Vec<Vec<SearchResult>>,
+    limit: usize,
+) -> Vec<SearchResult> {
+    let mut fused: HashMap<ChunkId, (f32, SearchResult)> = HashMap::new();
+
+    for results in result_lists {
+        for (rank, result) in results.into_iter().enumerate() {
+            let contribution = 1.0 / (RRF_K + rank as f32 + 1.0);
+            fused
+                .entry(result.chunk.id)
+                .and_modify(|(rrf, best)| {
+                    *rrf += contribution;
+                    if result.score > best.score {
+                        best.score = result.score;
+                    }
+                })
+                .or_insert((contribution, result));
+        }
+    }
+
+    let mut ranked: Vec<(f32, SearchResult)> = fused.into_values().collect();
+    ranked.sort_by(|(a, ra), (b, rb)| b.total_cmp(a).then(rb.score.total_cmp(&ra.score)));
+    ranked.truncate(limit);
+    ranked.into_iter().map(|(_, result)| result).collect()
+}
+
+fn first_line(raw: &str) -> Option<String> {
+    raw.lines().find_map(clean_line)
+}
+
+/// Trims list markers and quotes that models add despite instructions.
+fn clean_line(line: &str) -> Option<String> {
+    let mut line = line.trim();
+    if let Some(rest) = line.strip_prefix(['-', '*', '•']) {
+        line = rest.trim_start();
+    } else {
+        let digits = line.chars().take_while(char::is_ascii_digit).count();
+        if let Some(rest) = line[digits..]
+            .strip_prefix(['.', ')'])
+            .filter(|_| digits > 0)
+        {
+            line = rest.trim_start();
+        }
+    }
+    let line = line.trim_matches('"').trim();
+    (!line.is_empty()).then(|| line.to_string())
+}
@@ -0,0 +1,99 @@
+use std::path::{Path, PathBuf};
+
+use super::{REPOSITORY_ROOT_ATTRIBUTE, RepositoryIngestionError, RepositoryIngestionService};
+use crate::application::ports::VectorStore;
+use crate::domain::{ContentType, DocumentRecord, SourceLanguage, SourceMetadata, TenantId};
+
+impl<V> RepositoryIngestionService<V>
+where
+    V: VectorStore,
+{
+    /// Canonical checkout path, which must lie under one of the allowed roots.
+    pub(super) async fn resolve_root(
+        &self,
+        path: &str,
+    ) -> Result<PathBuf, RepositoryIngestionError> {
+        if self.options.allowed_roots.is_empty() {
+            return Err(RepositoryIngestionError::Disabled);
+        }
+        if path.trim().is_empty() {
+            return Err(RepositoryIngestionError::InvalidRequest(
+                "path is required".to_string(),
+            ));
+        }
+
+        let root = self
+            .reader
+            .resolve(Path::new(path.trim()))
+            .await
+            .map_err(|e| RepositoryIngestionError::InvalidRequest(e.to_string()))?;
+        if !self
+            .options
+            .allowed_roots
+            .iter()
+            .any(|allowed| root.starts_with(allowed))
+        {
+            return Err(RepositoryIngestionError::Forbidden(format!(
+                "{} is outside the allowed repository roots",
+                root.display()
+            )));
+        }
+        Ok(root)
+    }
+
+    /// Documents named under `name`, after checking none of them was synced from a checkout
+    /// other than `root`.
+    pub(super) async fn ensure_name_bound_to(
+        &self,
+        tenant_id: &TenantId,
+        name: &str,
+        root: &Path,
+    ) -> Result<Vec<DocumentRecord>, RepositoryIngestionError> {
+        let existing = self
+            .document_service
+            .list_by_filename_prefix(tenant_id, &format!("{name}/"))
+            .await
+            .map_err(RepositoryIngestionError::Catalog)?;
+        let root = root.to_string_lossy();
+        if let Some(other) = existing
+            .iter()
+            .filter_map(|r| r.source.attributes.get(REPOSITORY_ROOT_ATTRIBUTE))
+            .find(|bound| **bound != *root)
+        {
+            return Err(RepositoryIngestionError::Conflict(format!(
+                "name {name:?} is already used by the repository at {other}; choose another name"
+            )));
+        }
+        Ok(existing)
+    }
+}
+
+/// The caller's source metadata for every file of the checkout at `root`: no source URL,
+/// and the root recorded under [`REPOSITORY_ROOT_ATTRIBUTE`], replacing any value the
+/// caller gave.
+pub(super) fn bound_source(
+    source: &SourceMetadata,
+    root: &Path,
+) -> Result<SourceMetadata, RepositoryIngestionError> {
+    let mut source = SourceMetadata {
+        source_url: None,
+        ..source.clone()
+    };
+    source.attributes.insert(
+        REPOSITORY_ROOT_ATTRIBUTE.to_string(),
+        root.to_string_lossy().into_owned(),
+    );
+    source
+        .normalized()
+        .map_err(RepositoryIngestionError::InvalidRequest)
+}
+
+/// Source files in a supported language are chunked as code, markdown files (READMEs,
+/// docs) as markdown; everything else in the checkout is skipped.
+pub(super) fn content_type_for(path: &str) -> Option<ContentType> {
+    if SourceLanguage::from_path(path).is_some() {
+        return Some(ContentType::Code);
+    }
+    let extension = path.rsplit_once('.')?.1.to_ascii_lowercase();
+    matches!(extension.as_str(), "md" | "markdown").then_some(ContentType::Markdown)
+}
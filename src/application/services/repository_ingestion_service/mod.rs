@@ -0,0 +1,204 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use tokio::sync::{Notify, watch};
+
+use super::{DocumentService, DocumentServiceError, current_principal};
+use crate::application::ports::{
+    JobEventBus, JobRepository, RepositoryError, SourceTreeReader, StagingStore, VectorStore,
+};
+use crate::domain::{Job, JobId, JobProgress, RepositorySyncRequest, SourceMetadata, TenantId};
+
+mod checkout;
+mod settlement;
+mod sync;
+
+use checkout::bound_source;
+
+pub const REPOSITORY_SYNC_JOB: &str = "repository_sync";
+
+/// Source attribute holding the canonical checkout path a repository document was synced
+/// from. A sync only removes documents carrying its own root.
+pub const REPOSITORY_ROOT_ATTRIBUTE: &str = "repository_root";
+
+/// Where repositories may be read from and how much of each is ingested.
+#[derive(Debug, Clone)]
+pub struct RepositoryIngestionOptions {
+    /// Canonical directories checkouts must live under; empty disables repository ingestion.
+    pub allowed_roots: Vec<PathBuf>,
+    /// Source files ingested per sync; the rest are skipped and summarized in one failure.
+    pub max_files: usize,
+    /// Files above this size are recorded as failures.
+    pub max_file_bytes: u64,
+}
+
+impl Default for RepositoryIngestionOptions {
+    fn default() -> Self {
+        Self {
+            allowed_roots: Vec::new(),
+            max_files: 5_000,
+            max_file_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// What a finished sync did with the checkout's files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyncOutcome {
+    pub ingested: u32,
+    pub unchanged: u32,
+    pub removed: u32,
+    pub failed: u32,
+}
+
+/// Source-code ingestion from local git checkouts: queues sync jobs and runs them once a
+/// worker claims one.
+///
+/// Source files (by extension) and markdown files not ignored by git become documents named
+/// `<name>/<path>`, submitted through [`DocumentService`]. A re-sync submits only files
+/// whose content differs from the ingested version and deletes the documents of files that
+/// are gone, so unchanged files are never re-embedded. Files with identical content are
+/// stored once, as the catalog deduplicates by content.
+///
+/// Each document records its checkout's root in [`REPOSITORY_ROOT_ATTRIBUTE`], which binds
+/// the name to that checkout: another checkout cannot sync under a name already in use.
+pub struct RepositoryIngestionService<V>
+where
+    V: VectorStore,
+{
+    reader: Arc<dyn SourceTreeReader>,
+    staging_store: Arc<dyn StagingStore>,
+    document_service: Arc<DocumentService<V>>,
+    job_repository: Arc<dyn JobRepository>,
+    job_events: Option<Arc<dyn JobEventBus>>,
+    ingestion_wakeup: Option<Arc<Notify>>,
+    options: RepositoryIngestionOptions,
+}
+
+impl<V> RepositoryIngestionService<V>
+where
+    V: VectorStore,
+{
+    pub fn new(
+        reader: Arc<dyn SourceTreeReader>,
+        staging_store: Arc<dyn StagingStore>,
+        document_service: Arc<DocumentService<V>>,
+        job_repository: Arc<dyn JobRepository>,
+    ) -> Self {
+        Self {
+            reader,
+            staging_store,
+            document_service,
+            job_repository,
+            job_events: None,
+            ingestion_wakeup: None,
+            options: RepositoryIngestionOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: RepositoryIngestionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Publishes sync progress for live subscribers.
+    pub fn with_job_events(mut self, job_events: Arc<dyn JobEventBus>) -> Self {
+        self.job_events = Some(job_events);
+        self
+    }
+
+    /// Signals an in-process worker after a sync is queued.
+    pub fn with_ingestion_wakeup(mut self, wakeup: Arc<Notify>) -> Self {
+        self.ingestion_wakeup = Some(wakeup);
+        self
+    }
+
+    /// Validates `request` and queues it as a sync job of the caller's tenant. The name
+    /// defaults to the checkout's directory name and must not be bound to another checkout.
+    #[tracing::instrument(skip(self, request), fields(path = %request.path, tenant_id = %tenant_id))]
+    pub async fn submit(
+        &self,
+        tenant_id: &TenantId,
+        mut request: RepositorySyncRequest,
+    ) -> Result<JobId, RepositoryIngestionError> {
+        let root = self.resolve_root(&request.path).await?;
+
+        if request.name.trim().is_empty() {
+            request.name = root
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default();
+        }
+        request.name = request.name.trim().to_string();
+        if request.name.is_empty() || request.name.contains(['/', '\\']) {
+            return Err(RepositoryIngestionError::InvalidRequest(format!(
+                "name must be non-empty and contain no path separators: {:?}",
+                request.name
+            )));
+        }
+
+        request.path = root.to_string_lossy().into_owned();
+        request.source = bound_source(&request.source, &root)?;
+        self.ensure_name_bound_to(tenant_id, &request.name, &root)
+            .await?;
+        request.principal = current_principal().map(|p| p.subject);
+
+        let job = Job::new(None, REPOSITORY_SYNC_JOB.to_string())
+            .with_tenant(tenant_id.clone())
+            .with_payload(request);
+        let job_id = job.id;
+        self.job_repository
+            .create(&job)
+            .await
+            .map_err(RepositoryIngestionError::Repository)?;
+        if let Some(wakeup) = &self.ingestion_wakeup {
+            wakeup.notify_one();
+        }
+
+        tracing::info!(job_id = %job_id.as_uuid(), "Repository sync job enqueued");
+        Ok(job_id)
+    }
+}
+
+/// State of one sync run.
+struct SyncRun<'a> {
+    job: &'a Job,
+    request: &'a RepositorySyncRequest,
+    root: PathBuf,
+    /// The request's source metadata, bound to `root`.
+    source: SourceMetadata,
+    cancelled: watch::Receiver<bool>,
+    progress: JobProgress,
+    failed: u32,
+}
+
+impl SyncRun<'_> {
+    fn filename(&self, path: &str) -> String {
+        format!("{}/{}", self.request.name, path)
+    }
+
+    fn ensure_not_cancelled(&self) -> Result<(), RepositoryIngestionError> {
+        if *self.cancelled.borrow() {
+            return Err(RepositoryIngestionError::Cancelled);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RepositoryIngestionError {
+    #[error("repository ingestion is disabled; configure ingestion.repository.allowed_roots")]
+    Disabled,
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("forbidden: {0}")]
+    Forbidden(String),
+    #[error("conflict: {0}")]
+    Conflict(String),
+    #[error("repository: {0}")]
+    Repository(RepositoryError),
+    #[error("document catalog: {0}")]
+    Catalog(DocumentServiceError),
+    #[error("cancelled")]
+    Cancelled,
+}
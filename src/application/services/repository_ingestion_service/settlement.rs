@@ -0,0 +1,115 @@
+use futures::StreamExt;
+
+use super::{RepositoryIngestionError, RepositoryIngestionService, SyncRun};
+use crate::application::ports::VectorStore;
+use crate::application::services::{SubmissionOutcome, run_as_principal};
+use crate::domain::{
+    ApiScope, ContentType, Document, FetchFailure, JobEvent, Principal, StoragePath,
+};
+
+impl<V> RepositoryIngestionService<V>
+where
+    V: VectorStore,
+{
+    /// Stages `data` and submits it as the document `filename`.
+    pub(super) async fn submit_file(
+        &self,
+        sync: &SyncRun<'_>,
+        filename: String,
+        content_type: ContentType,
+        data: Vec<u8>,
+        content_hash: String,
+    ) -> Result<SubmissionOutcome, String> {
+        let size = data.len() as u64;
+        let document = Document::new(filename, content_type, size);
+        let storage_path = StoragePath::new(&document.id, "source");
+        let body = bytes::Bytes::from(data);
+        self.staging_store
+            .store(
+                &storage_path,
+                futures::stream::once(async move { Ok(body) }).boxed(),
+                Some(size),
+            )
+            .await
+            .map_err(|e| format!("staging failed: {e}"))?;
+
+        let submission = self.document_service.submit(
+            &sync.job.tenant_id,
+            document,
+            storage_path,
+            true,
+            content_hash,
+            sync.source.clone(),
+        );
+        let result = match &sync.request.principal {
+            Some(subject) => {
+                let principal = Principal {
+                    subject: subject.clone(),
+                    tenant_id: sync.job.tenant_id.clone(),
+                    scopes: vec![ApiScope::Ingest],
+                    all_tenants: false,
+                };
+                run_as_principal(principal, submission).await
+            }
+            None => submission.await,
+        };
+
+        let submission = result.map_err(|e| e.to_string())?;
+        tracing::debug!(
+            document_id = %submission.document_id.as_uuid(),
+            outcome = ?submission.outcome,
+            "Repository file submitted"
+        );
+        Ok(submission.outcome)
+    }
+
+    pub(super) async fn record_failure(
+        &self,
+        sync: &mut SyncRun<'_>,
+        filename: &str,
+        error: String,
+    ) -> Result<(), RepositoryIngestionError> {
+        tracing::info!(filename = %filename, error = %error, "Repository file not ingested");
+        sync.failed += 1;
+        self.job_repository
+            .record_fetch_failure(
+                sync.job.id,
+                &FetchFailure {
+                    url: filename.to_string(),
+                    error,
+                },
+            )
+            .await
+            .map_err(RepositoryIngestionError::Repository)
+    }
+
+    /// Progress is informational, so a failed write is logged rather than failing the sync.
+    /// It only lands while the worker that claimed the job still holds its lease.
+    pub(super) async fn report_progress(&self, sync: &SyncRun<'_>) {
+        let worker_id = sync.job.lease_owner.as_deref().unwrap_or_default();
+        match self
+            .job_repository
+            .update_progress(sync.job.id, worker_id, &sync.progress)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                tracing::debug!("Progress not recorded: job lease lost");
+                return;
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to record repository sync progress");
+                return;
+            }
+        }
+        if let Some(job_events) = &self.job_events {
+            job_events
+                .publish(&JobEvent::progress(
+                    sync.job.id,
+                    sync.job.tenant_id.clone(),
+                    sync.progress.clone(),
+                ))
+                .await;
+        }
+    }
+}
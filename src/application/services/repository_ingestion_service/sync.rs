@@ -0,0 +1,150 @@
+use std::collections::HashSet;
+
+use tokio::sync::watch;
+
+use super::checkout::{bound_source, content_type_for};
+use super::{
+    REPOSITORY_ROOT_ATTRIBUTE, RepositoryIngestionError, RepositoryIngestionService, SyncOutcome,
+    SyncRun,
+};
+use crate::application::ports::VectorStore;
+use crate::application::services::SubmissionOutcome;
+use crate::domain::{DocumentRecord, Job, JobProgress, RepositorySyncRequest};
+
+impl<V> RepositoryIngestionService<V>
+where
+    V: VectorStore,
+{
+    /// Runs a claimed sync job, stopping early once `cancelled` is raised. Removed files
+    /// are deleted first, so a file that was renamed is ingested under its new name rather
+    /// than deduplicated against its soon-deleted copy.
+    pub async fn sync(
+        &self,
+        job: &Job,
+        request: &RepositorySyncRequest,
+        cancelled: watch::Receiver<bool>,
+    ) -> Result<SyncOutcome, RepositoryIngestionError> {
+        let root = self.resolve_root(&request.path).await?;
+        let existing = self
+            .ensure_name_bound_to(&job.tenant_id, &request.name, &root)
+            .await?;
+        let source = bound_source(&request.source, &root)?;
+        let mut sync = SyncRun {
+            job,
+            request,
+            root,
+            source,
+            cancelled,
+            progress: JobProgress::default(),
+            failed: 0,
+        };
+
+        let files: Vec<_> = self
+            .reader
+            .list_files(&sync.root)
+            .await
+            .map_err(|e| RepositoryIngestionError::InvalidRequest(e.to_string()))?
+            .into_iter()
+            .filter_map(|file| content_type_for(&file.path).map(|ct| (file, ct)))
+            .collect();
+        let present: HashSet<String> = files
+            .iter()
+            .map(|(file, _)| sync.filename(&file.path))
+            .collect();
+
+        let root_attribute = sync.root.to_string_lossy().into_owned();
+        let stale = existing.iter().filter(|r| {
+            !present.contains(&r.filename)
+                && r.source.attributes.get(REPOSITORY_ROOT_ATTRIBUTE) == Some(&root_attribute)
+        });
+        for record in stale {
+            sync.ensure_not_cancelled()?;
+            match self
+                .document_service
+                .delete(&job.tenant_id, record.id)
+                .await
+            {
+                Ok(()) => {
+                    sync.progress.files_removed += 1;
+                    self.report_progress(&sync).await;
+                }
+                Err(e) => {
+                    self.record_failure(&mut sync, &record.filename, format!("delete failed: {e}"))
+                        .await?
+                }
+            }
+        }
+
+        for (file, content_type) in files.iter().take(self.options.max_files) {
+            sync.ensure_not_cancelled()?;
+            let filename = sync.filename(&file.path);
+            if file.size_bytes > self.options.max_file_bytes {
+                let error = format!("larger than {} bytes", self.options.max_file_bytes);
+                self.record_failure(&mut sync, &filename, error).await?;
+                continue;
+            }
+
+            let data = match self.reader.read(&sync.root, &file.path).await {
+                Ok(data) => data,
+                Err(e) => {
+                    self.record_failure(&mut sync, &filename, e.to_string())
+                        .await?;
+                    continue;
+                }
+            };
+            if data.contains(&0) {
+                self.record_failure(&mut sync, &filename, "binary content".to_string())
+                    .await?;
+                continue;
+            }
+
+            let content_hash = DocumentRecord::hash_content(&data);
+            let unchanged = existing.iter().any(|r| {
+                r.filename == filename
+                    && r.ingested_at.is_some()
+                    && r.content_hash.as_deref() == Some(content_hash.as_str())
+            });
+            if unchanged {
+                sync.progress.files_unchanged += 1;
+                continue;
+            }
+
+            match self
+                .submit_file(&sync, filename.clone(), *content_type, data, content_hash)
+                .await
+            {
+                Ok(SubmissionOutcome::Duplicate) => sync.progress.files_unchanged += 1,
+                Ok(_) => sync.progress.files_ingested += 1,
+                Err(error) => {
+                    self.record_failure(&mut sync, &filename, error).await?;
+                    continue;
+                }
+            }
+            self.report_progress(&sync).await;
+        }
+        if files.len() > self.options.max_files {
+            let error = format!(
+                "{} files skipped: limit of {} files per sync reached",
+                files.len() - self.options.max_files,
+                self.options.max_files
+            );
+            self.record_failure(&mut sync, &request.name, error).await?;
+        }
+        self.report_progress(&sync).await;
+
+        let outcome = SyncOutcome {
+            ingested: sync.progress.files_ingested,
+            unchanged: sync.progress.files_unchanged,
+            removed: sync.progress.files_removed,
+            failed: sync.failed,
+        };
+        tracing::info!(
+            ingested = outcome.ingested,
+            unchanged = outcome.unchanged,
+            removed = outcome.removed,
+            failed = outcome.failed,
+            "Repository sync finished"
+        );
+        Ok(outcome)
+    }
+}
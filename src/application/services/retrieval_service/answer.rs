@@ -0,0 +1,221 @@
+use std::sync::Arc;
+
+use tracing::Instrument;
+
+use super::fusion::Retrieval;
+use super::rerank::RankedResult;
+use super::{QueryResponse, RetrievalService, StreamingQueryResponse};
+use crate::application::ports::{
+    LlmClient, RetrievalError, SearchFilter, SearchResult, SourceChunk, VectorStore,
+};
+use crate::application::services::current_principal;
+use crate::domain::{ConversationId, EvalEvent, EvalSource, Message, MessageRole};
+
+impl<L, V> RetrievalService<L, V>
+where
+    L: LlmClient,
+    V: VectorStore,
+{
+    fn any_above_threshold(&self, results: &[SearchResult]) -> bool {
+        results.iter().any(|r| r.score >= self.similarity_threshold)
+    }
+
+    #[tracing::instrument(
+        skip(self, question, conversation_id, correlation_id, filter),
+        fields(retrieved_chunks_count, similarity_score, tenant_id = %filter.tenant_id, filtered = !filter.is_empty())
+    )]
+    pub async fn query(
+        &self,
+        question: &str,
+        conversation_id: Option<ConversationId>,
+        correlation_id: Option<String>,
+        filter: &SearchFilter,
+    ) -> Result<QueryResponse, RetrievalError> {
+        let Retrieval {
+            question: search_question,
+            results,
+        } = self.retrieve(question, conversation_id, filter).await?;
+
+        if !self.any_above_threshold(&results) {
+            tracing::Span::current().record("retrieved_chunks_count", 0);
+            tracing::Span::current().record(
+                "similarity_score",
+                results.first().map(|r| r.score).unwrap_or(0.0),
+            );
+            return Ok(QueryResponse {
+                answer: self.fallback_message.clone(),
+                sources: Vec::new(),
+            });
+        }
+
+        let trimmed_chunks = self.select_context(&search_question, results).await;
+
+        tracing::Span::current().record("retrieved_chunks_count", trimmed_chunks.len());
+        tracing::Span::current().record(
+            "similarity_score",
+            trimmed_chunks
+                .first()
+                .map(|r| r.result.score)
+                .unwrap_or(0.0),
+        );
+
+        let context = trimmed_chunks
+            .iter()
+            .map(|r| r.result.chunk.as_contextual_string())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let answer = self
+            .llm_client
+            .complete(&search_question, &context)
+            .await
+            .map_err(RetrievalError::Completion)?;
+
+        if let Some(conv_id) = conversation_id {
+            let user_message = Message::new(conv_id, MessageRole::User, question.to_string());
+            self.conversation_repository
+                .append_message(&user_message)
+                .await
+                .map_err(RetrievalError::Repository)?;
+
+            let assistant_message = Message::new(conv_id, MessageRole::Assistant, answer.clone());
+            self.conversation_repository
+                .append_message(&assistant_message)
+                .await
+                .map_err(RetrievalError::Repository)?;
+        }
+
+        let sources: Vec<SourceChunk> = trimmed_chunks
+            .iter()
+            .map(RankedResult::to_source_chunk)
+            .collect();
+
+        if let (Some(event_repo), Some(outbox_repo)) =
+            (&self.eval_event_repository, &self.eval_outbox_repository)
+        {
+            let eval_sources: Vec<EvalSource> = sources
+                .iter()
+                .map(|s| EvalSource {
+                    text: s.text.clone(),
+                    page: s.page,
+                    end_page: s.end_page,
+                    score: s.score,
+                })
+                .collect();
+            let eval_event = EvalEvent::new(
+                question,
+                &answer,
+                eval_sources,
+                &self.model_config,
+                correlation_id,
+            )
+            .with_tenant(filter.tenant_id.clone())
+            .with_principal(current_principal().map(|p| p.subject));
+            let event_repo = Arc::clone(event_repo);
+            let outbox_repo = Arc::clone(outbox_repo);
+            let span = tracing::Span::current();
+            tokio::spawn(
+                async move {
+                    match event_repo.record(&eval_event).await {
+                        Ok(_) => {
+                            if let Err(e) = outbox_repo.enqueue(eval_event.id).await {
+                                tracing::warn!(error = %e, "Failed to enqueue eval outbox");
+                            }
+                        }
+                        Err(e) => tracing::warn!(error = %e, "Failed to record eval event"),
+                    }
+                }
+                .instrument(span),
+            );
+        }
+
+        Ok(QueryResponse { answer, sources })
+    }
+
+    #[tracing::instrument(
+        skip(self, question, conversation_id, filter),
+        fields(retrieved_chunks_count, similarity_score, tenant_id = %filter.tenant_id, filtered = !filter.is_empty())
+    )]
+    pub async fn query_stream(
+        &self,
+        question: &str,
+        conversation_id: Option<ConversationId>,
+        filter: &SearchFilter,
+    ) -> Result<StreamingQueryResponse, RetrievalError> {
+        let Retrieval {
+            question: search_question,
+            results,
+        } = self.retrieve(question, conversation_id, filter).await?;
+
+        if !self.any_above_threshold(&results) {
+            tracing::Span::current().record("retrieved_chunks_count", 0);
+            tracing::Span::current().record(
+                "similarity_score",
+                results.first().map(|r| r.score).unwrap_or(0.0),
+            );
+
+            let fallback = self.fallback_message.clone();
+            let token_stream = Box::pin(futures::stream::once(async move { Ok(fallback) }));
+            return Ok(StreamingQueryResponse {
+                token_stream,
+                sources: Vec::new(),
+                conversation_id,
+            });
+        }
+
+        let trimmed_chunks = self.select_context(&search_question, results).await;
+
+        tracing::Span::current().record("retrieved_chunks_count", trimmed_chunks.len());
+        tracing::Span::current().record(
+            "similarity_score",
+            trimmed_chunks
+                .first()
+                .map(|r| r.result.score)
+                .unwrap_or(0.0),
+        );
+
+        let context = trimmed_chunks
+            .iter()
+            .map(|r| r.result.chunk.as_contextual_string())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let token_stream = self
+            .llm_client
+            .complete_stream(&search_question, &context)
+            .await
+            .map_err(RetrievalError::Completion)?;
+
+        let sources = trimmed_chunks
+            .iter()
+            .map(RankedResult::to_source_chunk)
+            .collect();
+
+        Ok(StreamingQueryResponse {
+            token_stream,
+            sources,
+            conversation_id,
+        })
+    }
+
+    pub async fn search_chunks(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SourceChunk>, RetrievalError> {
+        let Retrieval { question, results } = self.retrieve(query, None, filter).await?;
+
+        if !self.any_above_threshold(&results) {
+            return Ok(Vec::new());
+        }
+
+        let trimmed_chunks = self.select_context(&question, results).await;
+
+        let chunks = trimmed_chunks
+            .iter()
+            .map(RankedResult::to_source_chunk)
+            .collect();
+
+        Ok(chunks)
+    }
+}
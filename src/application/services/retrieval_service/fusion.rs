@@ -0,0 +1,131 @@
+use super::RetrievalService;
+use crate::application::ports::{
+    LlmClient, RetrievalError, SearchFilter, SearchResult, VectorStore,
+};
+use crate::application::services::query_transformer::{
+    condense_question, hypothetical_answer, paraphrase_question, reciprocal_rank_fusion,
+};
+use crate::domain::ConversationId;
+
+/// Vector hits for a question, together with the (possibly condensed) question that the
+/// rest of the pipeline should use.
+pub(super) struct Retrieval {
+    pub(super) question: String,
+    pub(super) results: Vec<SearchResult>,
+}
+
+impl<L, V> RetrievalService<L, V>
+where
+    L: LlmClient,
+    V: VectorStore,
+{
+    fn search_limit(&self) -> usize {
+        self.rerank
+            .as_ref()
+            .map_or(self.top_k, |stage| stage.candidates)
+    }
+
+    /// Runs the configured query transformations, searches with every resulting query in
+    /// parallel and fuses the hit lists with RRF. Transformation failures degrade to the
+    /// original question.
+    #[tracing::instrument(
+        skip_all,
+        fields(
+            condense = self.query_transform.condense && conversation_id.is_some(),
+            paraphrases = self.query_transform.paraphrases,
+            hyde = self.query_transform.hyde,
+            search_count
+        )
+    )]
+    pub(super) async fn retrieve(
+        &self,
+        question: &str,
+        conversation_id: Option<ConversationId>,
+        filter: &SearchFilter,
+    ) -> Result<Retrieval, RetrievalError> {
+        let options = &self.query_transform;
+        let question = match conversation_id.filter(|_| options.condense) {
+            Some(conv_id) => match self
+                .conversation_repository
+                .get_messages(conv_id, options.history_messages)
+                .await
+            {
+                Ok(history) => {
+                    condense_question(self.llm_client.as_ref(), &history, question).await
+                }
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to load history for condensing");
+                    question.to_string()
+                }
+            },
+            None => question.to_string(),
+        };
+
+        if !options.expands() {
+            tracing::Span::current().record("search_count", 1);
+            let results = self.vector_search(&question, filter).await?;
+            return Ok(Retrieval { question, results });
+        }
+
+        let (paraphrases, passage) = tokio::join!(
+            async {
+                if options.paraphrases > 0 {
+                    paraphrase_question(self.llm_client.as_ref(), &question, options.paraphrases)
+                        .await
+                } else {
+                    Vec::new()
+                }
+            },
+            async {
+                if options.hyde {
+                    hypothetical_answer(self.llm_client.as_ref(), &question).await
+                } else {
+                    None
+                }
+            }
+        );
+
+        let mut queries = vec![question.clone()];
+        queries.extend(paraphrases);
+        queries.extend(passage);
+        tracing::Span::current().record("search_count", queries.len());
+
+        let result_lists =
+            futures::future::try_join_all(queries.iter().map(|q| self.vector_search(q, filter)))
+                .await?;
+
+        Ok(Retrieval {
+            question,
+            results: reciprocal_rank_fusion(result_lists, self.search_limit()),
+        })
+    }
+
+    async fn vector_search(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SearchResult>, RetrievalError> {
+        let limit = self.search_limit();
+        let query_embedding = self
+            .embedder
+            .embed(query)
+            .await
+            .map_err(RetrievalError::Embedding)?;
+
+        if let Some(sparse) = &self.sparse_embedder {
+            let sparse_embedding = sparse
+                .embed_sparse_query(query)
+                .await
+                .map_err(RetrievalError::Embedding)?;
+            self.vector_store
+                .search_hybrid(&query_embedding, &sparse_embedding, limit, filter)
+                .await
+                .map_err(RetrievalError::Search)
+        } else {
+            self.vector_store
+                .search(&query_embedding, limit, filter)
+                .await
+                .map_err(RetrievalError::Search)
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::ports::{
+    ConversationRepository, Embedder, EvalEventRepository, EvalOutboxRepository, LlmClient,
+    LlmTokenStream, Reranker, RetrievalError, RetrievalServicePort, SearchFilter, SourceChunk,
+    SparseEmbedder, VectorStore,
+};
+use crate::application::services::query_transformer::QueryTransformOptions;
+use crate::domain::ConversationId;
+
+mod answer;
+mod fusion;
+mod rerank;
+
+use rerank::RerankStage;
+
+pub struct RetrievalService<L, V>
+where
+    L: LlmClient,
+    V: VectorStore,
+{
+    embedder: Arc<dyn Embedder>,
+    llm_client: Arc<L>,
+    vector_store: Arc<V>,
+    conversation_repository: Arc<dyn ConversationRepository>,
+    eval_event_repository: Option<Arc<dyn EvalEventRepository>>,
+    eval_outbox_repository: Option<Arc<dyn EvalOutboxRepository>>,
+    sparse_embedder: Option<Arc<dyn SparseEmbedder>>,
+    model_config: String,
+    top_k: usize,
+    similarity_threshold: f32,
+    max_context_tokens: usize,
+    fallback_message: String,
+    rerank: Option<RerankStage>,
+    query_transform: QueryTransformOptions,
+}
+
+impl<L, V> RetrievalService<L, V>
+where
+    L: LlmClient,
+    V: VectorStore,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        embedder: Arc<dyn Embedder>,
+        llm_client: Arc<L>,
+        vector_store: Arc<V>,
+        conversation_repository: Arc<dyn ConversationRepository>,
+        eval_event_repository: Option<Arc<dyn EvalEventRepository>>,
+        eval_outbox_repository: Option<Arc<dyn EvalOutboxRepository>>,
+        sparse_embedder: Option<Arc<dyn SparseEmbedder>>,
+        model_config: String,
+        top_k: usize,
+        similarity_threshold: f32,
+        max_context_tokens: usize,
+        fallback_message: String,
+    ) -> Self {
+        Self {
+            embedder,
+            llm_client,
+            vector_store,
+            conversation_repository,
+            eval_event_repository,
+            eval_outbox_repository,
+            sparse_embedder,
+            model_config,
+            top_k,
+            similarity_threshold,
+            max_context_tokens,
+            fallback_message,
+            rerank: None,
+            query_transform: QueryTransformOptions::default(),
+        }
+    }
+
+    /// Retrieves `candidates` hits instead of `top_k`, rescores them with `reranker` and
+    /// keeps the best `top_n` before the context token budget is applied.
+    pub fn with_reranker(
+        mut self,
+        reranker: Arc<dyn Reranker>,
+        candidates: usize,
+        top_n: usize,
+    ) -> Self {
+        self.rerank = Some(RerankStage {
+            reranker,
+            candidates: candidates.max(top_n),
+            top_n,
+        });
+        self
+    }
+
+    /// Enables question condensing, multi-query expansion and HyDE; see
+    /// [`QueryTransformOptions`].
+    pub fn with_query_transform(mut self, options: QueryTransformOptions) -> Self {
+        self.query_transform = options;
+        self
+    }
+}
+
+#[async_trait]
+impl<L, V> RetrievalServicePort for RetrievalService<L, V>
+where
+    L: LlmClient,
+    V: VectorStore,
+{
+    async fn search_chunks(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SourceChunk>, RetrievalError> {
+        self.search_chunks(query, filter).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QueryResponse {
+    pub answer: String,
+    pub sources: Vec<SourceChunk>,
+}
+
+pub struct StreamingQueryResponse {
+    pub token_stream: LlmTokenStream,
+    pub sources: Vec<SourceChunk>,
+    pub conversation_id: Option<ConversationId>,
+}
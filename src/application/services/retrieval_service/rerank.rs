@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use super::RetrievalService;
+use crate::application::ports::{LlmClient, Reranker, SearchResult, SourceChunk, VectorStore};
+use crate::application::services::count_tokens;
+
+/// Optional second stage: `candidates` vector hits are rescored and cut to `top_n`.
+pub(super) struct RerankStage {
+    pub(super) reranker: Arc<dyn Reranker>,
+    pub(super) candidates: usize,
+    pub(super) top_n: usize,
+}
+
+/// A search hit selected for the context, with its rerank score when reranking ran.
+pub(super) struct RankedResult {
+    pub(super) result: SearchResult,
+    pub(super) rerank_score: Option<f32>,
+}
+
+impl RankedResult {
+    pub(super) fn to_source_chunk(&self) -> SourceChunk {
+        let chunk = &self.result.chunk;
+        SourceChunk {
+            text: chunk.text.clone(),
+            page: chunk.page,
+            end_page: chunk.end_page,
+            score: self.result.score,
+            title: chunk.metadata.as_ref().map(|m| m.title.clone()),
+            source_url: chunk.metadata.as_ref().and_then(|m| m.source_url.clone()),
+            content_type: chunk
+                .metadata
+                .as_ref()
+                .map(|m| m.content_type.as_mime().to_string()),
+            author: chunk.metadata.as_ref().and_then(|m| m.author.clone()),
+            tags: chunk
+                .metadata
+                .as_ref()
+                .map(|m| m.tags.clone())
+                .unwrap_or_default(),
+            attributes: chunk
+                .metadata
+                .as_ref()
+                .map(|m| m.attributes.clone())
+                .unwrap_or_default(),
+            links: chunk.links.clone(),
+            start_time: chunk.start_time,
+            code: chunk.code.clone(),
+            rerank_score: self.rerank_score,
+        }
+    }
+}
+
+impl<L, V> RetrievalService<L, V>
+where
+    L: LlmClient,
+    V: VectorStore,
+{
+    /// Applies the similarity threshold, the optional rerank stage and the context token
+    /// budget, in that order.
+    pub(super) async fn select_context(
+        &self,
+        query: &str,
+        results: Vec<SearchResult>,
+    ) -> Vec<RankedResult> {
+        let candidates: Vec<RankedResult> = results
+            .into_iter()
+            .filter(|r| r.score >= self.similarity_threshold)
+            .map(|result| RankedResult {
+                result,
+                rerank_score: None,
+            })
+            .collect();
+
+        let ranked = match &self.rerank {
+            Some(stage) => Self::rerank(stage, query, candidates).await,
+            None => candidates,
+        };
+
+        let mut accumulated_tokens = 0;
+        let mut selected = Vec::new();
+
+        for ranked_result in ranked {
+            let chunk_tokens = count_tokens(&ranked_result.result.chunk.as_contextual_string());
+            if accumulated_tokens + chunk_tokens <= self.max_context_tokens {
+                accumulated_tokens += chunk_tokens;
+                selected.push(ranked_result);
+            } else {
+                break;
+            }
+        }
+
+        selected
+    }
+
+    /// Reorders candidates by rerank score and keeps `top_n`. A failing reranker degrades to
+    /// vector order rather than failing the request.
+    #[tracing::instrument(skip_all, fields(candidates = candidates.len(), top_n = stage.top_n))]
+    async fn rerank(
+        stage: &RerankStage,
+        query: &str,
+        mut candidates: Vec<RankedResult>,
+    ) -> Vec<RankedResult> {
+        if candidates.is_empty() {
+            return candidates;
+        }
+
+        let documents: Vec<&str> = candidates
+            .iter()
+            .map(|c| c.result.chunk.text.as_str())
+            .collect();
+
+        match stage.reranker.rerank(query, &documents).await {
+            Ok(scores) if scores.len() == candidates.len() => {
+                for (candidate, score) in candidates.iter_mut().zip(scores) {
+                    candidate.rerank_score = Some(score);
+                }
+                candidates.sort_by(|a, b| {
+                    let (a, b) = (
+                        a.rerank_score.unwrap_or(f32::MIN),
+                        b.rerank_score.unwrap_or(f32::MIN),
+                    );
+                    b.total_cmp(&a)
+                });
+            }
+            Ok(scores) => tracing::warn!(
+                expected = candidates.len(),
+                received = scores.len(),
+                "Reranker returned a mismatched score count; keeping vector order"
+            ),
+            Err(e) => tracing::warn!(error = %e, "Reranking failed; keeping vector order"),
+        }
+
+        candidates.truncate(stage.top_n);
+        candidates
+    }
+}
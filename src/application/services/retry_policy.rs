@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+/// How a failed ingestion attempt is treated by the [`RetryPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureClass {
+    /// A provider asked us to slow down.
+    RateLimited,
+    /// Network or availability errors that are likely to clear on their own.
+    Transient,
+    /// Bad input or configuration; another attempt would fail the same way.
+    Permanent,
+}
+
+/// Exponential backoff for failed ingestion attempts, keyed on [`FailureClass`].
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Claims a job gets in total, including the first. Also bounds how often a job whose
+    /// worker keeps dying is re-queued.
+    pub max_attempts: u32,
+    /// Delay after the first transient failure, doubled on every further attempt.
+    pub base_delay: Duration,
+    /// Delay after the first rate-limited attempt, so the provider's window can reset.
+    pub rate_limited_base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(2),
+            rate_limited_base_delay: Duration::from_secs(30),
+            max_delay: Duration::from_secs(300),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay before re-running a job whose `attempt` (1-based) failed with `class`, or `None`
+    /// when the job should fail for good.
+    pub fn backoff(&self, class: FailureClass, attempt: u32) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+        let base = match class {
+            FailureClass::Permanent => return None,
+            FailureClass::RateLimited => self.rate_limited_base_delay,
+            FailureClass::Transient => self.base_delay,
+        };
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        Some(base.saturating_mul(factor).min(self.max_delay))
+    }
+}
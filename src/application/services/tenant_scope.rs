@@ -0,0 +1,23 @@
+use std::future::Future;
+
+use crate::domain::TenantId;
+
+tokio::task_local! {
+    static CURRENT_TENANT: TenantId;
+}
+
+/// Runs `future` with `tenant` as the ambient tenant.
+///
+/// Tool handlers are invoked through the generic MCP `ToolHandler` interface, which carries
+/// no request context; tenant-sensitive tools (e.g. `rag_search`) read it from here instead.
+pub async fn run_as_tenant<F>(tenant: TenantId, future: F) -> F::Output
+where
+    F: Future,
+{
+    CURRENT_TENANT.scope(tenant, future).await
+}
+
+/// The ambient tenant, or `None` outside [`run_as_tenant`].
+pub fn current_tenant() -> Option<TenantId> {
+    CURRENT_TENANT.try_with(Clone::clone).ok()
+}
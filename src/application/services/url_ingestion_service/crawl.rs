@@ -0,0 +1,183 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use tokio::sync::watch;
+use url::Url;
+
+use super::pages::canonical_url;
+use super::{CrawlOutcome, UrlIngestionError, UrlIngestionService, parse_web_url};
+use crate::application::ports::{VectorStore, host_in_domains};
+use crate::domain::{CrawlRequest, FetchFailure, Job, JobEvent, JobProgress, RobotsRules};
+
+impl<V> UrlIngestionService<V>
+where
+    V: VectorStore,
+{
+    /// Runs a claimed crawl job until its page budget or frontier is exhausted, stopping
+    /// early once `cancelled` is raised.
+    pub async fn crawl(
+        &self,
+        job: &Job,
+        request: &CrawlRequest,
+        cancelled: watch::Receiver<bool>,
+    ) -> Result<CrawlOutcome, UrlIngestionError> {
+        let start = parse_web_url(&request.url).map_err(UrlIngestionError::InvalidRequest)?;
+        let mut crawl = Crawl {
+            job,
+            request,
+            cancelled,
+            robots: HashMap::new(),
+            seen: HashSet::new(),
+            submitted: HashSet::new(),
+            frontier: VecDeque::new(),
+            progress: JobProgress::default(),
+            failed: 0,
+        };
+
+        let seeds = if request.sitemap {
+            self.sitemap_pages(&mut crawl, start).await?
+        } else {
+            vec![start]
+        };
+        for seed in seeds {
+            crawl.enqueue(seed, 0);
+        }
+
+        while let Some((url, depth)) = crawl.frontier.pop_front() {
+            if crawl.progress.urls_ingested >= request.max_pages {
+                break;
+            }
+            crawl.ensure_not_cancelled()?;
+
+            if !self.robots_allow(&mut crawl, &url).await {
+                self.record_failure(&mut crawl, &url, "disallowed by robots.txt".to_string())
+                    .await?;
+                continue;
+            }
+            let resource = match self
+                .fetcher
+                .fetch(url.as_str(), &request.allowed_domains)
+                .await
+            {
+                Ok(resource) => resource,
+                Err(e) => {
+                    self.record_failure(&mut crawl, &url, e.to_string()).await?;
+                    continue;
+                }
+            };
+
+            let page_url = canonical_url(&url, &resource);
+            if crawl.submitted.insert(page_url.to_string()) {
+                match self.submit_page(&crawl, &page_url, &resource).await {
+                    Ok(()) => {
+                        crawl.progress.urls_ingested += 1;
+                        self.report_progress(&crawl).await;
+                    }
+                    Err(error) => self.record_failure(&mut crawl, &page_url, error).await?,
+                }
+            } else {
+                tracing::debug!(url = %url, canonical = %page_url, "Page already ingested by this crawl");
+            }
+
+            if depth < request.max_depth {
+                for link in resource.links.iter().filter_map(|l| parse_web_url(l).ok()) {
+                    crawl.enqueue(link, depth + 1);
+                }
+            }
+        }
+
+        tracing::info!(
+            ingested = crawl.progress.urls_ingested,
+            failed = crawl.failed,
+            "URL ingestion crawl finished"
+        );
+        Ok(CrawlOutcome {
+            ingested: crawl.progress.urls_ingested,
+            failed: crawl.failed,
+        })
+    }
+
+    pub(super) async fn record_failure(
+        &self,
+        crawl: &mut Crawl<'_>,
+        url: &Url,
+        error: String,
+    ) -> Result<(), UrlIngestionError> {
+        tracing::info!(url = %url, error = %error, "URL not ingested");
+        crawl.failed += 1;
+        self.job_repository
+            .record_fetch_failure(
+                crawl.job.id,
+                &FetchFailure {
+                    url: url.to_string(),
+                    error,
+                },
+            )
+            .await
+            .map_err(UrlIngestionError::Repository)
+    }
+
+    /// Progress is informational, so a failed write is logged rather than failing the crawl.
+    /// It only lands while the worker that claimed the job still holds its lease.
+    pub(super) async fn report_progress(&self, crawl: &Crawl<'_>) {
+        let worker_id = crawl.job.lease_owner.as_deref().unwrap_or_default();
+        match self
+            .job_repository
+            .update_progress(crawl.job.id, worker_id, &crawl.progress)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                tracing::debug!("Progress not recorded: job lease lost");
+                return;
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to record URL ingestion progress");
+                return;
+            }
+        }
+        if let Some(job_events) = &self.job_events {
+            job_events
+                .publish(&JobEvent::progress(
+                    crawl.job.id,
+                    crawl.job.tenant_id.clone(),
+                    crawl.progress.clone(),
+                ))
+                .await;
+        }
+    }
+}
+
+/// State of one crawl run.
+pub(super) struct Crawl<'a> {
+    pub(super) job: &'a Job,
+    pub(super) request: &'a CrawlRequest,
+    pub(super) cancelled: watch::Receiver<bool>,
+    /// robots.txt rules per origin.
+    pub(super) robots: HashMap<String, RobotsRules>,
+    /// URLs queued so far, so each is fetched at most once.
+    pub(super) seen: HashSet<String>,
+    /// Canonical URLs of the pages submitted so far.
+    pub(super) submitted: HashSet<String>,
+    pub(super) frontier: VecDeque<(Url, u32)>,
+    pub(super) progress: JobProgress,
+    pub(super) failed: u32,
+}
+
+impl Crawl<'_> {
+    /// Queues `url` unless it was queued before or leads outside the allowed domains.
+    pub(super) fn enqueue(&mut self, url: Url, depth: u32) {
+        let in_scope = url
+            .host_str()
+            .is_some_and(|host| host_in_domains(host, &self.request.allowed_domains));
+        if in_scope && self.seen.insert(url.to_string()) {
+            self.frontier.push_back((url, depth));
+        }
+    }
+
+    pub(super) fn ensure_not_cancelled(&self) -> Result<(), UrlIngestionError> {
+        if *self.cancelled.borrow() {
+            return Err(UrlIngestionError::Cancelled);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use tokio::sync::Notify;
+use url::Url;
+
+use super::{DocumentService, current_principal};
+use crate::application::ports::{
+    JobEventBus, JobRepository, RepositoryError, StagingStore, VectorStore, WebFetcher,
+};
+use crate::domain::{CrawlRequest, Job, JobId, SourceMetadata, TenantId};
+
+mod crawl;
+mod pages;
+
+pub const URL_INGESTION_JOB: &str = "url_ingestion";
+
+/// Ceilings and crawler identity for web ingestion.
+#[derive(Debug, Clone)]
+pub struct UrlIngestionOptions {
+    /// Product token matched against `User-agent` lines in robots.txt.
+    pub robots_user_agent: String,
+    /// Highest `max_depth` a request may ask for.
+    pub max_depth: u32,
+    /// Highest `max_pages` a request may ask for.
+    pub max_pages: u32,
+    /// Sitemaps read per crawl when following sitemap indexes.
+    pub max_sitemaps: usize,
+}
+
+impl Default for UrlIngestionOptions {
+    fn default() -> Self {
+        Self {
+            robots_user_agent: "sandakan".to_string(),
+            max_depth: 3,
+            max_pages: 200,
+            max_sitemaps: 50,
+        }
+    }
+}
+
+/// Pages a finished crawl submitted and URLs it recorded as failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrawlOutcome {
+    pub ingested: u32,
+    pub failed: u32,
+}
+
+/// Web ingestion: queues crawl jobs and runs them once a worker claims one.
+///
+/// A crawl fetches its start page (or the pages a sitemap lists) and follows links
+/// breadth-first within the allowed domains, honouring each site's robots.txt. Every page is
+/// staged and submitted through [`DocumentService`] with the page's canonical URL as its
+/// filename and source URL, so re-crawling a page replaces or deduplicates the earlier copy.
+/// URLs that cannot be ingested are recorded on the crawl job rather than failing it.
+pub struct UrlIngestionService<V>
+where
+    V: VectorStore,
+{
+    fetcher: Arc<dyn WebFetcher>,
+    staging_store: Arc<dyn StagingStore>,
+    document_service: Arc<DocumentService<V>>,
+    job_repository: Arc<dyn JobRepository>,
+    job_events: Option<Arc<dyn JobEventBus>>,
+    ingestion_wakeup: Option<Arc<Notify>>,
+    options: UrlIngestionOptions,
+}
+
+impl<V> UrlIngestionService<V>
+where
+    V: VectorStore,
+{
+    pub fn new(
+        fetcher: Arc<dyn WebFetcher>,
+        staging_store: Arc<dyn StagingStore>,
+        document_service: Arc<DocumentService<V>>,
+        job_repository: Arc<dyn JobRepository>,
+    ) -> Self {
+        Self {
+            fetcher,
+            staging_store,
+            document_service,
+            job_repository,
+            job_events: None,
+            ingestion_wakeup: None,
+            options: UrlIngestionOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: UrlIngestionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Publishes crawl progress for live subscribers.
+    pub fn with_job_events(mut self, job_events: Arc<dyn JobEventBus>) -> Self {
+        self.job_events = Some(job_events);
+        self
+    }
+
+    /// Signals an in-process worker after a crawl is queued.
+    pub fn with_ingestion_wakeup(mut self, wakeup: Arc<Notify>) -> Self {
+        self.ingestion_wakeup = Some(wakeup);
+        self
+    }
+
+    pub fn options(&self) -> &UrlIngestionOptions {
+        &self.options
+    }
+
+    /// Validates `request` and queues it as a crawl job of the caller's tenant.
+    #[tracing::instrument(skip(self, request), fields(url = %request.url, tenant_id = %tenant_id))]
+    pub async fn submit(
+        &self,
+        tenant_id: &TenantId,
+        mut request: CrawlRequest,
+    ) -> Result<JobId, UrlIngestionError> {
+        let start = parse_web_url(&request.url).map_err(UrlIngestionError::InvalidRequest)?;
+        if request.max_depth > self.options.max_depth {
+            return Err(UrlIngestionError::InvalidRequest(format!(
+                "max_depth must be at most {}",
+                self.options.max_depth
+            )));
+        }
+        if request.max_pages == 0 || request.max_pages > self.options.max_pages {
+            return Err(UrlIngestionError::InvalidRequest(format!(
+                "max_pages must be between 1 and {}",
+                self.options.max_pages
+            )));
+        }
+
+        let mut domains: Vec<String> = Vec::new();
+        for domain in &request.allowed_domains {
+            let domain = domain.trim().trim_start_matches('.').to_ascii_lowercase();
+            if domain.is_empty() || domain.contains(['/', ':']) {
+                return Err(UrlIngestionError::InvalidRequest(format!(
+                    "allowed_domains entries must be host names: {domain:?}"
+                )));
+            }
+            if !domains.contains(&domain) {
+                domains.push(domain);
+            }
+        }
+        if domains.is_empty() {
+            domains.extend(start.host_str().map(str::to_string));
+        }
+
+        request.url = start.to_string();
+        request.allowed_domains = domains;
+        request.source = SourceMetadata {
+            source_url: None,
+            ..request.source
+        }
+        .normalized()
+        .map_err(UrlIngestionError::InvalidRequest)?;
+        request.principal = current_principal().map(|p| p.subject);
+
+        let job = Job::new(None, URL_INGESTION_JOB.to_string())
+            .with_tenant(tenant_id.clone())
+            .with_payload(request);
+        let job_id = job.id;
+        self.job_repository
+            .create(&job)
+            .await
+            .map_err(UrlIngestionError::Repository)?;
+        if let Some(wakeup) = &self.ingestion_wakeup {
+            wakeup.notify_one();
+        }
+
+        tracing::info!(job_id = %job_id.as_uuid(), "URL ingestion job enqueued");
+        Ok(job_id)
+    }
+}
+
+/// Absolute http(s) URL without its fragment.
+fn parse_web_url(raw: &str) -> Result<Url, String> {
+    let mut url = Url::parse(raw.trim()).map_err(|e| format!("invalid URL {raw:?}: {e}"))?;
+    if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+        return Err(format!("URL must be absolute http(s): {raw}"));
+    }
+    url.set_fragment(None);
+    Ok(url)
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UrlIngestionError {
+    #[error("invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("repository: {0}")]
+    Repository(RepositoryError),
+    #[error("cancelled")]
+    Cancelled,
+}
@@ -0,0 +1,176 @@
+use std::collections::{HashSet, VecDeque};
+
+use futures::StreamExt;
+use url::Url;
+
+use super::crawl::Crawl;
+use super::{UrlIngestionError, UrlIngestionService, parse_web_url};
+use crate::application::ports::{VectorStore, WebResource};
+use crate::application::services::run_as_principal;
+use crate::domain::{
+    ApiScope, ContentType, Document, DocumentRecord, Principal, RobotsRules, SourceMetadata,
+    StoragePath,
+};
+
+impl<V> UrlIngestionService<V>
+where
+    V: VectorStore,
+{
+    /// Page URLs listed by the sitemap at `start`, reading nested sitemaps of sitemap indexes
+    /// up to the configured limit.
+    pub(super) async fn sitemap_pages(
+        &self,
+        crawl: &mut Crawl<'_>,
+        start: Url,
+    ) -> Result<Vec<Url>, UrlIngestionError> {
+        let mut sitemaps = VecDeque::from([start.clone()]);
+        let mut queued = HashSet::from([start.to_string()]);
+        let mut pages = Vec::new();
+        let mut read = 0;
+
+        while let Some(sitemap) = sitemaps.pop_front() {
+            crawl.ensure_not_cancelled()?;
+            if read == self.options.max_sitemaps {
+                let error = format!("sitemap limit of {} reached", self.options.max_sitemaps);
+                self.record_failure(crawl, &sitemap, error).await?;
+                continue;
+            }
+            read += 1;
+
+            let resource = match self
+                .fetcher
+                .fetch(sitemap.as_str(), &crawl.request.allowed_domains)
+                .await
+            {
+                Ok(resource) => resource,
+                Err(e) => {
+                    self.record_failure(crawl, &sitemap, e.to_string()).await?;
+                    continue;
+                }
+            };
+            for entry in resource.links.iter().filter_map(|l| parse_web_url(l).ok()) {
+                if !resource.sitemap_index {
+                    pages.push(entry);
+                } else if queued.insert(entry.to_string()) {
+                    sitemaps.push_back(entry);
+                }
+            }
+        }
+        Ok(pages)
+    }
+
+    /// Checks `url` against its origin's robots.txt, fetched once per crawl. A missing
+    /// robots.txt allows everything; one that cannot be fetched allows nothing.
+    pub(super) async fn robots_allow(&self, crawl: &mut Crawl<'_>, url: &Url) -> bool {
+        let origin = url.origin().ascii_serialization();
+        if !crawl.robots.contains_key(&origin) {
+            let rules = match self
+                .fetcher
+                .fetch(
+                    &format!("{origin}/robots.txt"),
+                    &crawl.request.allowed_domains,
+                )
+                .await
+            {
+                Ok(resource) => RobotsRules::parse(
+                    &String::from_utf8_lossy(&resource.body),
+                    &self.options.robots_user_agent,
+                ),
+                Err(e) if e.status().is_some_and(|s| (400..500).contains(&s)) => {
+                    RobotsRules::allow_all()
+                }
+                Err(e) => {
+                    tracing::warn!(origin = %origin, error = %e, "robots.txt unavailable; skipping site");
+                    RobotsRules::disallow_all()
+                }
+            };
+            crawl.robots.insert(origin.clone(), rules);
+        }
+
+        let path = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        crawl.robots[&origin].allows(&path)
+    }
+
+    /// Stages the fetched body and submits it as a document named after `page_url`.
+    pub(super) async fn submit_page(
+        &self,
+        crawl: &Crawl<'_>,
+        page_url: &Url,
+        resource: &WebResource,
+    ) -> Result<(), String> {
+        let mime = resource.content_type.as_deref().unwrap_or("unknown");
+        let content_type = ContentType::from_mime(mime)
+            .ok_or_else(|| format!("unsupported content type: {mime}"))?;
+
+        let document = Document::new(
+            page_url.to_string(),
+            content_type,
+            resource.body.len() as u64,
+        );
+        let storage_path = StoragePath::new(&document.id, "page");
+        let body = bytes::Bytes::from(resource.body.clone());
+        self.staging_store
+            .store(
+                &storage_path,
+                futures::stream::once(async move { Ok(body) }).boxed(),
+                Some(resource.body.len() as u64),
+            )
+            .await
+            .map_err(|e| format!("staging failed: {e}"))?;
+
+        let source = SourceMetadata {
+            source_url: Some(page_url.to_string()),
+            title: crawl
+                .request
+                .source
+                .title
+                .clone()
+                .or_else(|| resource.title.clone()),
+            ..crawl.request.source.clone()
+        };
+        let submission = self.document_service.submit(
+            &crawl.job.tenant_id,
+            document,
+            storage_path,
+            true,
+            DocumentRecord::hash_content(&resource.body),
+            source,
+        );
+        let result = match &crawl.request.principal {
+            Some(subject) => {
+                let principal = Principal {
+                    subject: subject.clone(),
+                    tenant_id: crawl.job.tenant_id.clone(),
+                    scopes: vec![ApiScope::Ingest],
+                    all_tenants: false,
+                };
+                run_as_principal(principal, submission).await
+            }
+            None => submission.await,
+        };
+
+        let submission = result.map_err(|e| e.to_string())?;
+        tracing::debug!(
+            url = %page_url,
+            document_id = %submission.document_id.as_uuid(),
+            outcome = ?submission.outcome,
+            "Crawled page submitted"
+        );
+        Ok(())
+    }
+}
+
+/// The page's declared canonical URL when it stays on the fetched host, otherwise the URL
+/// the fetch ended at.
+pub(super) fn canonical_url(requested: &Url, resource: &WebResource) -> Url {
+    let fetched = parse_web_url(&resource.url).unwrap_or_else(|_| requested.clone());
+    resource
+        .canonical_url
+        .as_deref()
+        .and_then(|c| parse_web_url(c).ok())
+        .filter(|c| c.host_str() == fetched.host_str())
+        .unwrap_or(fetched)
+}
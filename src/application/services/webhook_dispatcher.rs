@@ -0,0 +1,148 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tracing::Instrument;
+
+use crate::application::ports::{
+    RepositoryError, WebhookRepository, WebhookSendError, WebhookSender,
+};
+use crate::application::services::{FailureClass, RetryPolicy};
+use crate::domain::ClaimedWebhookDelivery;
+
+#[derive(Debug, Clone)]
+pub struct WebhookDispatchOptions {
+    /// Wait between polls when the outbox has nothing due.
+    pub poll_interval: Duration,
+    pub batch_size: usize,
+    /// How long a claimed delivery stays invisible to other dispatchers.
+    pub lease: Duration,
+    pub retry: RetryPolicy,
+}
+
+impl Default for WebhookDispatchOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(1),
+            batch_size: 20,
+            lease: Duration::from_secs(60),
+            retry: RetryPolicy {
+                max_attempts: 8,
+                base_delay: Duration::from_secs(10),
+                rate_limited_base_delay: Duration::from_secs(60),
+                max_delay: Duration::from_secs(3600),
+            },
+        }
+    }
+}
+
+/// Drains the webhook outbox: claims due deliveries, POSTs them through the
+/// [`WebhookSender`] and records the outcome, retrying failures with backoff. Dispatchers in
+/// several processes can share one outbox.
+pub struct WebhookDispatcher {
+    repository: Arc<dyn WebhookRepository>,
+    sender: Arc<dyn WebhookSender>,
+    options: WebhookDispatchOptions,
+}
+
+impl WebhookDispatcher {
+    pub fn new(repository: Arc<dyn WebhookRepository>, sender: Arc<dyn WebhookSender>) -> Self {
+        Self {
+            repository,
+            sender,
+            options: WebhookDispatchOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: WebhookDispatchOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub async fn run(self) {
+        tracing::info!(
+            batch_size = self.options.batch_size,
+            "WebhookDispatcher started"
+        );
+        loop {
+            match self.process_batch().await {
+                Ok(0) => tokio::time::sleep(self.options.poll_interval).await,
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to claim webhook deliveries");
+                    tokio::time::sleep(self.options.poll_interval).await;
+                }
+            }
+        }
+    }
+
+    /// Sends one batch of due deliveries, returning how many were claimed.
+    pub async fn process_batch(&self) -> Result<usize, RepositoryError> {
+        let claimed = self
+            .repository
+            .claim_due(self.options.batch_size, self.options.lease)
+            .await?;
+        let count = claimed.len();
+        futures::future::join_all(claimed.iter().map(|c| self.deliver(c))).await;
+        Ok(count)
+    }
+
+    async fn deliver(&self, claimed: &ClaimedWebhookDelivery) {
+        let delivery = &claimed.delivery;
+        let span = tracing::info_span!(
+            "webhook_delivery",
+            delivery_id = %delivery.id,
+            subscription_id = %delivery.subscription_id,
+            event_type = %delivery.event_type,
+            attempt = delivery.attempts,
+        );
+        if let Err(e) = self.send_and_record(claimed).instrument(span).await {
+            tracing::error!(error = %e, "Failed to record webhook delivery outcome");
+        }
+    }
+
+    async fn send_and_record(
+        &self,
+        claimed: &ClaimedWebhookDelivery,
+    ) -> Result<(), RepositoryError> {
+        let delivery = &claimed.delivery;
+        match self.sender.send(claimed).await {
+            Ok(status) => {
+                tracing::debug!(status, "Webhook delivered");
+                self.repository.mark_delivered(delivery.id, status).await
+            }
+            Err(e) => {
+                let error = e.to_string();
+                match self
+                    .options
+                    .retry
+                    .backoff(failure_class(&e), delivery.attempts)
+                {
+                    Some(delay) => {
+                        tracing::info!(
+                            error = %error,
+                            retry_in_ms = delay.as_millis() as u64,
+                            "Webhook delivery failed; retrying"
+                        );
+                        self.repository
+                            .schedule_retry(delivery.id, delay, &error, e.response_status())
+                            .await
+                    }
+                    None => {
+                        tracing::warn!(error = %error, "Webhook delivery failed for good");
+                        self.repository
+                            .mark_failed(delivery.id, &error, e.response_status())
+                            .await
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Every failure is worth another attempt; a 429 waits for the subscriber's rate window.
+fn failure_class(error: &WebhookSendError) -> FailureClass {
+    match error {
+        WebhookSendError::Rejected(429) => FailureClass::RateLimited,
+        _ => FailureClass::Transient,
+    }
+}
@@ -0,0 +1,134 @@
+use std::sync::Arc;
+
+use url::Url;
+
+use crate::application::ports::{RepositoryError, WebhookRepository, check_target};
+use crate::domain::{
+    TenantId, WebhookDelivery, WebhookEventType, WebhookSubscription, WebhookSubscriptionId,
+};
+
+/// Management of a tenant's webhook subscriptions and access to their delivery log.
+pub struct WebhookService {
+    repository: Arc<dyn WebhookRepository>,
+    allow_private_networks: bool,
+}
+
+impl WebhookService {
+    pub fn new(repository: Arc<dyn WebhookRepository>) -> Self {
+        Self {
+            repository,
+            allow_private_networks: false,
+        }
+    }
+
+    /// Accepts endpoints on loopback, private and other non-public addresses.
+    pub fn with_private_networks(mut self, allow: bool) -> Self {
+        self.allow_private_networks = allow;
+        self
+    }
+
+    /// Registers `url` for `event_types`. The returned subscription carries its signing
+    /// secret, which only the creation response exposes. URLs with an IP-literal host
+    /// outside the public internet are refused here; host names are checked on delivery.
+    #[tracing::instrument(skip(self, event_types), fields(tenant_id = %tenant_id))]
+    pub async fn subscribe(
+        &self,
+        tenant_id: TenantId,
+        url: &str,
+        event_types: Vec<WebhookEventType>,
+    ) -> Result<WebhookSubscription, WebhookServiceError> {
+        let url = url.trim();
+        let parsed = Url::parse(url).map_err(|_| {
+            WebhookServiceError::InvalidRequest("url must be an absolute http(s) URL".to_string())
+        })?;
+        check_target(&parsed, None, self.allow_private_networks).map_err(|blocked| {
+            WebhookServiceError::InvalidRequest(format!("url is not allowed: {blocked}"))
+        })?;
+        let mut event_types = event_types;
+        event_types.sort_by_key(|t| t.as_str());
+        event_types.dedup();
+        if event_types.is_empty() {
+            return Err(WebhookServiceError::InvalidRequest(
+                "at least one event type is required".to_string(),
+            ));
+        }
+
+        let subscription = WebhookSubscription::generate(tenant_id, url, event_types);
+        self.repository
+            .create_subscription(&subscription)
+            .await
+            .map_err(WebhookServiceError::Repository)?;
+        tracing::info!(subscription_id = %subscription.id, "Webhook subscription created");
+        Ok(subscription)
+    }
+
+    pub async fn list(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, WebhookServiceError> {
+        self.repository
+            .list_subscriptions(tenant_id)
+            .await
+            .map_err(WebhookServiceError::Repository)
+    }
+
+    #[tracing::instrument(skip(self), fields(subscription_id = %id, tenant_id = %tenant_id))]
+    pub async fn unsubscribe(
+        &self,
+        tenant_id: &TenantId,
+        id: WebhookSubscriptionId,
+    ) -> Result<(), WebhookServiceError> {
+        self.find(tenant_id, id).await?;
+        if !self
+            .repository
+            .delete_subscription(id)
+            .await
+            .map_err(WebhookServiceError::Repository)?
+        {
+            return Err(WebhookServiceError::NotFound(id));
+        }
+        tracing::info!("Webhook subscription deleted");
+        Ok(())
+    }
+
+    /// Most recent deliveries of one subscription, newest first.
+    pub async fn deliveries(
+        &self,
+        tenant_id: &TenantId,
+        id: WebhookSubscriptionId,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, WebhookServiceError> {
+        self.find(tenant_id, id).await?;
+        self.repository
+            .list_deliveries(id, limit)
+            .await
+            .map_err(WebhookServiceError::Repository)
+    }
+
+    /// Subscriptions of other tenants are indistinguishable from missing ones.
+    async fn find(
+        &self,
+        tenant_id: &TenantId,
+        id: WebhookSubscriptionId,
+    ) -> Result<WebhookSubscription, WebhookServiceError> {
+        match self
+            .repository
+            .get_subscription(id)
+            .await
+            .map_err(WebhookServiceError::Repository)?
+        {
+            Some(subscription) if subscription.tenant_id == *tenant_id => Ok(subscription),
+            _ => Err(WebhookServiceError::NotFound(id)),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WebhookServiceError {
+    #[error("invalid webhook request: {0}")]
+    InvalidRequest(String),
+    #[error("webhook subscription not found: {0}")]
+    NotFound(WebhookSubscriptionId),
+    #[error("repository: {0}")]
+    Repository(RepositoryError),
+}
@@ -0,0 +1,135 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::TenantId;
+
+const SECRET_PREFIX: &str = "sk-";
+/// Characters of the secret kept in clear so operators can tell keys apart.
+const DISPLAY_PREFIX_LEN: usize = 11;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApiKeyId(Uuid);
+
+impl ApiKeyId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for ApiKeyId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for ApiKeyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Capability granted to an API key. `Admin` implies every other scope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiScope {
+    Ingest,
+    Query,
+    Agent,
+    Admin,
+}
+
+impl ApiScope {
+    pub const ALL: [ApiScope; 4] = [Self::Ingest, Self::Query, Self::Agent, Self::Admin];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Ingest => "ingest",
+            Self::Query => "query",
+            Self::Agent => "agent",
+            Self::Admin => "admin",
+        }
+    }
+}
+
+impl FromStr for ApiScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ingest" => Ok(Self::Ingest),
+            "query" => Ok(Self::Query),
+            "agent" => Ok(Self::Agent),
+            "admin" => Ok(Self::Admin),
+            _ => Err(format!("Invalid API scope: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for ApiScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Persisted API key. Only the SHA-256 of the secret is stored; the secret itself is
+/// shown once, when the key is minted.
+#[derive(Debug, Clone)]
+pub struct ApiKey {
+    pub id: ApiKeyId,
+    pub name: String,
+    pub tenant_id: TenantId,
+    pub scopes: Vec<ApiScope>,
+    /// Leading characters of the secret, safe to display.
+    pub key_prefix: String,
+    pub key_hash: String,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKey {
+    /// Mints a new key, returning it together with its plaintext secret.
+    pub fn generate(name: &str, tenant_id: TenantId, scopes: Vec<ApiScope>) -> (Self, String) {
+        let secret = format!(
+            "{SECRET_PREFIX}{}{}",
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        );
+        let key = Self {
+            id: ApiKeyId::new(),
+            name: name.to_string(),
+            tenant_id,
+            scopes,
+            key_prefix: secret[..DISPLAY_PREFIX_LEN].to_string(),
+            key_hash: Self::hash_secret(&secret),
+            created_at: Utc::now(),
+            revoked_at: None,
+        };
+        (key, secret)
+    }
+
+    /// Hex-encoded SHA-256 of a presented secret. Secrets are high-entropy random values,
+    /// so an unsalted fast hash is sufficient for lookup.
+    pub fn hash_secret(secret: &str) -> String {
+        Sha256::digest(secret.as_bytes())
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
@@ -0,0 +1,162 @@
+use super::TranscriptSegment;
+
+impl TranscriptSegment {
+    /// Parses an SRT or WebVTT caption file into one segment per cue.
+    ///
+    /// Cue identifiers, WebVTT headers, `NOTE`/`STYLE`/`REGION` blocks and cue settings
+    /// are ignored. Markup (`<i>`, `<v Speaker>`, inline timestamps, `{\an8}`) is stripped
+    /// and the lines of a cue are joined with spaces. Consecutive cues with the same text,
+    /// as rolling auto-generated captions repeat them, become one segment.
+    pub fn parse_captions(captions: &str) -> Result<Vec<Self>, String> {
+        let captions = captions.trim_start_matches('\u{feff}');
+        let mut segments: Vec<Self> = Vec::new();
+        let mut lines = captions.lines().enumerate().peekable();
+
+        while let Some((index, line)) = lines.next() {
+            let Some((start, rest)) = line.split_once("-->") else {
+                continue;
+            };
+            let end = rest.split_whitespace().next().unwrap_or_default();
+            let parse = |value: &str| {
+                parse_caption_timestamp(value.trim())
+                    .ok_or_else(|| format!("line {}: invalid timestamp {:?}", index + 1, value))
+            };
+            let (start_time, end_time) = (parse(start)?, parse(end)?);
+
+            let mut text_lines = Vec::new();
+            while let Some((_, text)) = lines.next_if(|(_, l)| !l.trim().is_empty()) {
+                text_lines.push(strip_caption_markup(text));
+            }
+            let text = text_lines
+                .iter()
+                .map(|l| l.trim())
+                .filter(|l| !l.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            if text.is_empty() {
+                continue;
+            }
+
+            match segments.last_mut() {
+                Some(last) if last.text == text => last.end_time = last.end_time.max(end_time),
+                _ => segments.push(Self::new(text, start_time, end_time)),
+            }
+        }
+
+        if segments.is_empty() {
+            return Err("no caption cues found".to_string());
+        }
+        Ok(segments)
+    }
+
+    /// Renders segments as an SRT file, numbering cues from 1.
+    pub fn to_srt(segments: &[Self]) -> String {
+        let mut srt = String::new();
+        for (index, segment) in segments.iter().enumerate() {
+            srt.push_str(&format!(
+                "{}\n{} --> {}\n{}\n\n",
+                index + 1,
+                format_caption_timestamp(segment.start_time, ','),
+                format_caption_timestamp(segment.end_time, ','),
+                cue_text(&segment.text),
+            ));
+        }
+        srt
+    }
+
+    /// Renders segments as a WebVTT file. `&`, `<` and `>` in the text are escaped.
+    pub fn to_vtt(segments: &[Self]) -> String {
+        let mut vtt = String::from("WEBVTT\n\n");
+        for segment in segments {
+            let text = cue_text(&segment.text)
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;");
+            vtt.push_str(&format!(
+                "{} --> {}\n{}\n\n",
+                format_caption_timestamp(segment.start_time, '.'),
+                format_caption_timestamp(segment.end_time, '.'),
+                text,
+            ));
+        }
+        vtt
+    }
+
+    /// Renders segments as plain text, one line per segment.
+    pub fn to_plain_text(segments: &[Self]) -> String {
+        segments
+            .iter()
+            .map(|s| cue_text(&s.text))
+            .filter(|t| !t.is_empty())
+            .map(|t| t + "\n")
+            .collect()
+    }
+}
+
+/// Cue text on a single line, so it cannot end the cue or read as a timing line.
+fn cue_text(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("-->", "->")
+}
+
+/// `HH:MM:SS<separator>mmm`, the timestamp form shared by SRT (`,`) and WebVTT (`.`).
+fn format_caption_timestamp(seconds: f32, separator: char) -> String {
+    let millis = (seconds.max(0.0) as f64 * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}{}{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        separator,
+        millis % 1000
+    )
+}
+
+/// Seconds of an SRT (`01:02:03,456`) or WebVTT (`01:02:03.456`, `02:03.456`) timestamp.
+fn parse_caption_timestamp(value: &str) -> Option<f32> {
+    let (clock, millis) = value.split_once([',', '.'])?;
+    if millis.len() != 3 || !millis.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let parts = clock
+        .split(':')
+        .map(|p| p.parse::<u32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    let seconds = match parts.as_slice() {
+        [h, m, s] if *m < 60 && *s < 60 => h * 3600 + m * 60 + s,
+        [m, s] if *m < 60 && *s < 60 => m * 60 + s,
+        _ => return None,
+    };
+    Some(seconds as f32 + millis.parse::<u32>().ok()? as f32 / 1000.0)
+}
+
+/// Cue text without tags, SSA override blocks and the common character references.
+fn strip_caption_markup(line: &str) -> String {
+    let mut text = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '<' => {
+                for c in chars.by_ref() {
+                    if c == '>' {
+                        break;
+                    }
+                }
+            }
+            '{' if chars.as_str().starts_with('\\') => {
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        break;
+                    }
+                }
+            }
+            c => text.push(c),
+        }
+    }
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
+use super::code_span::CodeSpan;
 use super::document_metadata::DocumentMetadata;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -9,12 +10,21 @@ pub struct Chunk {
     pub id: ChunkId,
     pub text: String,
     pub document_id: DocumentId,
+    /// First page the chunk's text came from.
     pub page: Option<u32>,
+    /// Last page the chunk's text came from; differs from `page` when the chunk spans a
+    /// page break.
+    pub end_page: Option<u32>,
     pub offset: usize,
     pub metadata: Option<Arc<DocumentMetadata>>,
     /// Start time in seconds of the first transcript segment that contributed to this chunk.
     /// `None` for non-media sources (PDF, plain text).
     pub start_time: Option<f32>,
+    /// Targets of the links in the chunk's text, for markdown and HTML sources. The link
+    /// markup itself is reduced to its label.
+    pub links: Vec<String>,
+    /// Path, language, symbol and line range for chunks of source files.
+    pub code: Option<CodeSpan>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -71,9 +81,12 @@ impl Chunk {
             text,
             document_id,
             page,
+            end_page: page,
             offset,
             metadata: None,
             start_time: None,
+            links: Vec::new(),
+            code: None,
         }
     }
 
@@ -90,9 +103,12 @@ impl Chunk {
             text,
             document_id,
             page,
+            end_page: page,
             offset,
             metadata: Some(metadata),
             start_time: None,
+            links: Vec::new(),
+            code: None,
         }
     }
 
@@ -102,16 +118,53 @@ impl Chunk {
         self
     }
 
+    /// Builder-style method to record the link targets found in the chunk.
+    pub fn with_links(mut self, links: Vec<String>) -> Self {
+        self.links = links;
+        self
+    }
+
+    /// Builder-style method to record where in a source file the chunk comes from.
+    pub fn with_code_span(mut self, code: CodeSpan) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    /// Builder-style method to record the pages the chunk covers.
+    pub fn with_pages(mut self, first: u32, last: u32) -> Self {
+        self.page = Some(first);
+        self.end_page = Some(last.max(first));
+        self
+    }
+
+    /// `"3"` for a single page, `"3-4"` for a chunk spanning a page break.
+    pub fn page_label(&self) -> Option<String> {
+        let first = self.page?;
+        Some(match self.end_page {
+            Some(last) if last > first => format!("{first}-{last}"),
+            _ => first.to_string(),
+        })
+    }
+
     /// Returns an embedding-ready string that includes document context when available.
     ///
-    /// Enriching embeddings with title and page/time guides the model toward semantically
-    /// distinct vectors for identical text from different documents.
+    /// Enriching embeddings with title and page/time — symbol and lines for source code —
+    /// guides the model toward semantically distinct vectors for identical text from
+    /// different documents.
     pub fn as_contextual_string(&self) -> String {
-        match &self.metadata {
-            Some(meta) => {
-                let location_label = match (self.page, self.start_time) {
+        match (&self.metadata, &self.code) {
+            (Some(meta), Some(code)) => format!(
+                "Title: {}\nSymbol: {}\nLines: {}-{}\nContent: {}",
+                meta.title,
+                code.symbol.as_deref().unwrap_or("N/A"),
+                code.start_line,
+                code.end_line,
+                self.text
+            ),
+            (Some(meta), None) => {
+                let location_label = match (self.page_label(), self.start_time) {
                     (_, Some(t)) => format!("{:.1}s", t),
-                    (Some(p), None) => p.to_string(),
+                    (Some(pages), None) => pages,
                     (None, None) => "N/A".to_string(),
                 };
                 format!(
@@ -119,7 +172,7 @@ impl Chunk {
                     meta.title, location_label, self.text
                 )
             }
-            None => self.text.clone(),
+            (None, _) => self.text.clone(),
         }
     }
 }
@@ -0,0 +1,97 @@
+/// Programming language of a source file, detected from its extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceLanguage {
+    Rust,
+    Python,
+    JavaScript,
+    TypeScript,
+    Go,
+    Java,
+    Kotlin,
+    CSharp,
+    C,
+    Cpp,
+}
+
+impl SourceLanguage {
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (_, extension) = path.rsplit_once('.')?;
+        if extension.contains('/') {
+            return None;
+        }
+        match extension.to_ascii_lowercase().as_str() {
+            "rs" => Some(Self::Rust),
+            "py" | "pyi" => Some(Self::Python),
+            "js" | "jsx" | "mjs" | "cjs" => Some(Self::JavaScript),
+            "ts" | "tsx" | "mts" | "cts" => Some(Self::TypeScript),
+            "go" => Some(Self::Go),
+            "java" => Some(Self::Java),
+            "kt" | "kts" => Some(Self::Kotlin),
+            "cs" => Some(Self::CSharp),
+            "c" | "h" => Some(Self::C),
+            "cc" | "cpp" | "cxx" | "hh" | "hpp" | "hxx" => Some(Self::Cpp),
+            _ => None,
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "rust" => Some(Self::Rust),
+            "python" => Some(Self::Python),
+            "javascript" => Some(Self::JavaScript),
+            "typescript" => Some(Self::TypeScript),
+            "go" => Some(Self::Go),
+            "java" => Some(Self::Java),
+            "kotlin" => Some(Self::Kotlin),
+            "csharp" => Some(Self::CSharp),
+            "c" => Some(Self::C),
+            "cpp" => Some(Self::Cpp),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Rust => "rust",
+            Self::Python => "python",
+            Self::JavaScript => "javascript",
+            Self::TypeScript => "typescript",
+            Self::Go => "go",
+            Self::Java => "java",
+            Self::Kotlin => "kotlin",
+            Self::CSharp => "csharp",
+            Self::C => "c",
+            Self::Cpp => "cpp",
+        }
+    }
+
+    /// Separator between a container and its members in qualified symbol names.
+    pub fn scope_separator(&self) -> &'static str {
+        match self {
+            Self::Rust | Self::Cpp => "::",
+            _ => ".",
+        }
+    }
+}
+
+/// Location of a chunk within a source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CodeSpan {
+    /// Document filename: the repository name followed by the path inside the checkout.
+    pub path: String,
+    pub language: SourceLanguage,
+    /// Qualified name of the function, impl or class the chunk belongs to; `None` for
+    /// module-level code such as imports.
+    pub symbol: Option<String>,
+    /// First line of the chunk, 1-based.
+    pub start_line: u32,
+    /// Last line of the chunk, inclusive.
+    pub end_line: u32,
+}
+
+impl CodeSpan {
+    /// `"src/lib.rs:10-42"`.
+    pub fn location(&self) -> String {
+        format!("{}:{}-{}", self.path, self.start_line, self.end_line)
+    }
+}
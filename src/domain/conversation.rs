@@ -1,4 +1,4 @@
-use super::{ConversationId, Message};
+use super::{ConversationId, Message, TenantId};
 use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
@@ -6,6 +6,7 @@ pub struct Conversation {
     pub id: ConversationId,
     pub title: Option<String>,
     pub messages: Vec<Message>,
+    pub tenant_id: TenantId,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -17,8 +18,14 @@ impl Conversation {
             id: ConversationId::new(),
             title,
             messages: Vec::new(),
+            tenant_id: TenantId::default(),
             created_at: now,
             updated_at: now,
         }
     }
+
+    pub fn with_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = tenant_id;
+        self
+    }
 }
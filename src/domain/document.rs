@@ -14,6 +14,15 @@ pub enum ContentType {
     Audio,
     Video,
     Text,
+    Markdown,
+    Html,
+    Docx,
+    Pptx,
+    Xlsx,
+    /// Source code; the language comes from the filename's extension.
+    Code,
+    /// SRT or WebVTT captions, ingested as timed transcript segments.
+    Subtitles,
 }
 
 impl ContentType {
@@ -23,6 +32,19 @@ impl ContentType {
             m if m.starts_with("audio/") => Some(Self::Audio),
             m if m.starts_with("video/") => Some(Self::Video),
             "text/plain" => Some(Self::Text),
+            "text/markdown" | "text/x-markdown" => Some(Self::Markdown),
+            "text/html" | "application/xhtml+xml" => Some(Self::Html),
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document" => {
+                Some(Self::Docx)
+            }
+            "application/vnd.openxmlformats-officedocument.presentationml.presentation" => {
+                Some(Self::Pptx)
+            }
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => Some(Self::Xlsx),
+            "text/x-source" => Some(Self::Code),
+            "text/vtt" | "application/x-subrip" | "application/srt" | "text/srt" => {
+                Some(Self::Subtitles)
+            }
             _ => None,
         }
     }
@@ -33,8 +55,26 @@ impl ContentType {
             Self::Audio => "audio/mpeg",
             Self::Video => "video/mp4",
             Self::Text => "text/plain",
+            Self::Markdown => "text/markdown",
+            Self::Html => "text/html",
+            Self::Docx => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            Self::Pptx => {
+                "application/vnd.openxmlformats-officedocument.presentationml.presentation"
+            }
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            Self::Code => "text/x-source",
+            Self::Subtitles => "text/vtt",
         }
     }
+
+    /// Whether the loader for this type emits markdown, which the markdown-aware splitter
+    /// chunks along headings and tables.
+    pub fn is_markdown_source(&self) -> bool {
+        matches!(
+            self,
+            Self::Pdf | Self::Markdown | Self::Html | Self::Docx | Self::Pptx | Self::Xlsx
+        )
+    }
 }
 
 impl Document {
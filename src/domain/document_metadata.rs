@@ -1,4 +1,12 @@
-use super::{ContentType, Document};
+use std::collections::BTreeMap;
+
+use super::{ContentType, Document, TenantId};
+
+const MAX_TEXT_LEN: usize = 512;
+const MAX_URL_LEN: usize = 2048;
+const MAX_TAGS: usize = 32;
+const MAX_ATTRIBUTES: usize = 32;
+const MAX_ATTRIBUTE_KEY_LEN: usize = 64;
 
 /// Document-level context shared (Arc) across all chunks from the same source.
 ///
@@ -8,6 +16,13 @@ pub struct DocumentMetadata {
     pub title: String,
     pub content_type: ContentType,
     pub source_url: Option<String>,
+    pub author: Option<String>,
+    /// Free-form labels usable as search filters.
+    pub tags: Vec<String>,
+    /// Caller-supplied key/value tags, returned with every source.
+    pub attributes: BTreeMap<String, String>,
+    /// Owning tenant; written to the chunk payload and enforced on every search.
+    pub tenant_id: TenantId,
 }
 
 impl DocumentMetadata {
@@ -18,7 +33,121 @@ impl DocumentMetadata {
             title,
             content_type: doc.content_type,
             source_url,
+            author: None,
+            tags: Vec::new(),
+            attributes: BTreeMap::new(),
+            tenant_id: TenantId::default(),
+        }
+    }
+
+    pub fn with_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = tenant_id;
+        self
+    }
+
+    /// Applies what the caller supplied at ingestion; a given title replaces the one
+    /// derived from the filename.
+    pub fn with_source(mut self, source: &SourceMetadata) -> Self {
+        if let Some(title) = &source.title {
+            self.title = title.clone();
+        }
+        if source.source_url.is_some() {
+            self.source_url = source.source_url.clone();
+        }
+        self.author = source.author.clone();
+        self.tags = source.tags.clone();
+        self.attributes = source.attributes.clone();
+        self
+    }
+}
+
+/// Description of a document's origin supplied by the caller at ingestion time.
+///
+/// Stored with the catalog entry so re-ingestion keeps it, and copied into every chunk
+/// through [`DocumentMetadata`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourceMetadata {
+    /// Original location, e.g. a YouTube watch URL; media sources get timestamped links.
+    pub source_url: Option<String>,
+    /// Display title; defaults to the filename without its extension.
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub tags: Vec<String>,
+    pub attributes: BTreeMap<String, String>,
+}
+
+impl SourceMetadata {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Trims every value, drops blank ones and duplicate tags, and rejects values a
+    /// search payload should not carry.
+    pub fn normalized(self) -> Result<Self, String> {
+        let source_url = non_blank(self.source_url);
+        if let Some(url) = &source_url {
+            let has_host = url
+                .strip_prefix("https://")
+                .or_else(|| url.strip_prefix("http://"))
+                .is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'));
+            if !has_host || url.len() > MAX_URL_LEN {
+                return Err(format!(
+                    "source_url must be an absolute http(s) URL of at most {MAX_URL_LEN} characters"
+                ));
+            }
+        }
+        let title = bounded("title", non_blank(self.title))?;
+        let author = bounded("author", non_blank(self.author))?;
+
+        let mut tags: Vec<String> = Vec::new();
+        for tag in self.tags {
+            if let Some(tag) = bounded("tag", non_blank(Some(tag)))?
+                && !tags.contains(&tag)
+            {
+                tags.push(tag);
+            }
+        }
+        if tags.len() > MAX_TAGS {
+            return Err(format!("at most {MAX_TAGS} tags are allowed"));
+        }
+
+        let mut attributes = BTreeMap::new();
+        for (key, value) in self.attributes {
+            let key = key.trim();
+            if key.is_empty() || key.len() > MAX_ATTRIBUTE_KEY_LEN {
+                return Err(format!(
+                    "attribute keys must be 1 to {MAX_ATTRIBUTE_KEY_LEN} characters"
+                ));
+            }
+            let value = bounded("attribute value", Some(value.trim().to_string()))?;
+            attributes.insert(key.to_string(), value.unwrap_or_default());
+        }
+        if attributes.len() > MAX_ATTRIBUTES {
+            return Err(format!("at most {MAX_ATTRIBUTES} attributes are allowed"));
+        }
+
+        Ok(Self {
+            source_url,
+            title,
+            author,
+            tags,
+            attributes,
+        })
+    }
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+}
+
+fn bounded(field: &str, value: Option<String>) -> Result<Option<String>, String> {
+    match value {
+        Some(v) if v.chars().count() > MAX_TEXT_LEN => {
+            Err(format!("{field} must be at most {MAX_TEXT_LEN} characters"))
         }
+        other => Ok(other),
     }
 }
 
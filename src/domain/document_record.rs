@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+
+use super::{ContentType, Document, DocumentId, SourceMetadata, StoragePath, TenantId};
+
+/// Catalog entry for an ingested document.
+///
+/// Persisted alongside (not inside) the vector store so documents can be listed, removed
+/// and re-ingested without scanning Qdrant payloads. `ingested_at` stays `None` until the
+/// first successful ingestion completes.
+#[derive(Debug, Clone)]
+pub struct DocumentRecord {
+    pub id: DocumentId,
+    pub filename: String,
+    pub content_type: ContentType,
+    pub size_bytes: u64,
+    pub content_hash: Option<String>,
+    pub chunk_count: usize,
+    /// Caller-supplied origin, title and tags, re-applied on every re-ingestion.
+    pub source: SourceMetadata,
+    pub storage_path: StoragePath,
+    /// `true` when the source file was uploaded through the API and is owned by the
+    /// service; referenced files belong to the caller and are never deleted.
+    pub staged_upload: bool,
+    /// SRT or WebVTT file that replaces transcription of an audio or video document.
+    pub captions_path: Option<StoragePath>,
+    pub tenant_id: TenantId,
+    pub created_at: DateTime<Utc>,
+    pub ingested_at: Option<DateTime<Utc>>,
+}
+
+impl DocumentRecord {
+    pub fn new(document: &Document, storage_path: StoragePath, staged_upload: bool) -> Self {
+        Self {
+            id: document.id,
+            filename: document.filename.clone(),
+            content_type: document.content_type,
+            size_bytes: document.size_bytes,
+            content_hash: None,
+            chunk_count: 0,
+            source: SourceMetadata::default(),
+            storage_path,
+            staged_upload,
+            captions_path: None,
+            tenant_id: TenantId::default(),
+            created_at: Utc::now(),
+            ingested_at: None,
+        }
+    }
+
+    pub fn with_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = tenant_id;
+        self
+    }
+
+    pub fn with_source(mut self, source: SourceMetadata) -> Self {
+        self.source = source;
+        self
+    }
+
+    pub fn with_captions(mut self, captions_path: Option<StoragePath>) -> Self {
+        self.captions_path = captions_path;
+        self
+    }
+
+    /// Where a transcript corrected through the API is staged as this document's captions.
+    /// Unlike referenced caption files, it belongs to the document and goes with it.
+    pub fn corrected_transcript_path(&self) -> StoragePath {
+        StoragePath::new(&self.id, "transcript.vtt")
+    }
+
+    /// Hex-encoded SHA-256 of the raw source bytes.
+    pub fn hash_content(data: &[u8]) -> String {
+        let mut hasher = ContentHasher::new();
+        hasher.update(data);
+        hasher.finish()
+    }
+
+    /// Content hash of a media file ingested from a caption file: changing either one
+    /// makes the document differ from its ingested version.
+    pub fn hash_captioned(media_hash: &str, captions: &[u8]) -> String {
+        let mut hasher = ContentHasher::new();
+        hasher.update(media_hash.as_bytes());
+        hasher.update(b"+captions:");
+        hasher.update(Self::hash_content(captions).as_bytes());
+        hasher.finish()
+    }
+
+    pub fn to_document(&self) -> Document {
+        Document {
+            id: self.id,
+            filename: self.filename.clone(),
+            content_type: self.content_type,
+            size_bytes: self.size_bytes,
+        }
+    }
+}
+
+/// Incremental form of `DocumentRecord::hash_content` for sources that are streamed
+/// (multipart uploads) rather than held in memory.
+#[derive(Debug, Clone, Default)]
+pub struct ContentHasher(Sha256);
+
+impl ContentHasher {
+    pub fn new() -> Self {
+        Self(Sha256::new())
+    }
+
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    pub fn finish(self) -> String {
+        format!("{:x}", self.0.finalize())
+    }
+}
@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::TenantId;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct EvalEventId(Uuid);
 
@@ -56,6 +58,9 @@ impl EvalOperationType {
 pub struct EvalSource {
     pub text: String,
     pub page: Option<u32>,
+    /// Last page of the source; differs from `page` when it spans a page break.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_page: Option<u32>,
     pub score: f32,
 }
 
@@ -99,6 +104,12 @@ pub struct EvalEvent {
     /// Full trace of tool calls and reflection, populated for `AgenticRun` events.
     #[serde(default)]
     pub agentic_trace: Option<AgenticTrace>,
+    /// Tenant whose request produced the event.
+    #[serde(default)]
+    pub tenant_id: TenantId,
+    /// Subject of the authenticated principal, when the event came from an API request.
+    #[serde(default)]
+    pub principal: Option<String>,
 }
 
 impl EvalEvent {
@@ -120,6 +131,8 @@ impl EvalEvent {
             operation_type: EvalOperationType::Query,
             correlation_id,
             agentic_trace: None,
+            tenant_id: TenantId::default(),
+            principal: None,
         }
     }
 
@@ -168,9 +181,21 @@ impl EvalEvent {
             operation_type,
             correlation_id,
             agentic_trace: None,
+            tenant_id: TenantId::default(),
+            principal: None,
         }
     }
 
+    pub fn with_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = tenant_id;
+        self
+    }
+
+    pub fn with_principal(mut self, principal: Option<String>) -> Self {
+        self.principal = principal;
+        self
+    }
+
     pub fn context_text(&self) -> String {
         self.retrieved_sources
             .iter()
@@ -0,0 +1,49 @@
+use super::{EvalResult, EvalRunItem};
+
+/// Version of the [`EvalRunReport`](super::EvalRunReport) layout. Version 1 reports only
+/// carry `failed` and `failed_count`; version 2 adds `below_threshold`, `errored_count` and
+/// `below_threshold_count` alongside them, so reports of both versions stay comparable.
+pub const EVAL_REPORT_SCHEMA_VERSION: u32 = 2;
+
+/// Schema version assumed for reports written before the field existed.
+pub(super) fn schema_version_1() -> u32 {
+    1
+}
+
+impl EvalRunItem {
+    /// Item for a question that was answered and scored. `failed` mirrors
+    /// `below_threshold`, as in version 1 reports.
+    pub fn scored(result: &EvalResult) -> Self {
+        Self {
+            event_id: Some(result.eval_event_id),
+            question: result.question.clone(),
+            generated_answer: result.generated_answer.clone(),
+            context_recall: result.context_recall,
+            faithfulness: Some(result.faithfulness),
+            correctness: result.correctness,
+            answer_relevancy: result.answer_relevancy,
+            context_precision: result.context_precision,
+            failed: result.below_threshold,
+            below_threshold: result.below_threshold,
+            error: None,
+        }
+    }
+
+    /// Item for a question that could not be answered or scored. Version 1 reports counted
+    /// these as failed, so `failed` stays set while `below_threshold` does not.
+    pub fn errored(question: &str, error: String) -> Self {
+        Self {
+            event_id: None,
+            question: question.to_string(),
+            generated_answer: String::new(),
+            context_recall: None,
+            faithfulness: None,
+            correctness: None,
+            answer_relevancy: None,
+            context_precision: None,
+            failed: true,
+            below_threshold: false,
+            error: Some(error),
+        }
+    }
+}
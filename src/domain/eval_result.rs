@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::EvalEventId;
+use super::{EvalEventId, EvalRunId};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct EvalResultId(Uuid);
@@ -43,6 +43,7 @@ impl std::fmt::Display for EvalResultId {
 /// Query and AgenticRun events; `None` for ingestion events.
 ///
 /// `below_threshold` is pre-computed at save time so dashboards can filter cheaply.
+/// `run_id` is set only for offline golden-dataset runs; passive (outbox) results leave it `None`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EvalResult {
     pub id: EvalResultId,
@@ -57,6 +58,8 @@ pub struct EvalResult {
     pub correctness: Option<f32>,
     pub below_threshold: bool,
     pub computed_at: DateTime<Utc>,
+    #[serde(default)]
+    pub run_id: Option<EvalRunId>,
 }
 
 impl EvalResult {
@@ -86,6 +89,13 @@ impl EvalResult {
             correctness,
             below_threshold: faithfulness < faithfulness_threshold,
             computed_at: Utc::now(),
+            run_id: None,
         }
     }
+
+    /// Tags the result with the offline evaluation run that produced it.
+    pub fn with_run_id(mut self, run_id: EvalRunId) -> Self {
+        self.run_id = Some(run_id);
+        self
+    }
 }
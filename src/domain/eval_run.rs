@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::EvalEventId;
+use super::eval_report_schema::{EVAL_REPORT_SCHEMA_VERSION, schema_version_1};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct EvalRunId(Uuid);
+
+impl EvalRunId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for EvalRunId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Display for EvalRunId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Per-question outcome of an offline golden-dataset run.
+///
+/// Metric fields are `None` when the question could not be answered or scored; `error`
+/// then carries the reason. `below_threshold` is only set for questions that were scored,
+/// so a broken pipeline or judge is never reported as a low score. `failed` keeps its
+/// version 1 meaning: below the threshold or errored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvalRunItem {
+    pub event_id: Option<EvalEventId>,
+    pub question: String,
+    pub generated_answer: String,
+    pub context_recall: Option<f32>,
+    pub faithfulness: Option<f32>,
+    pub correctness: Option<f32>,
+    #[serde(default)]
+    pub answer_relevancy: Option<f32>,
+    #[serde(default)]
+    pub context_precision: Option<f32>,
+    pub failed: bool,
+    #[serde(default)]
+    pub below_threshold: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Summary report written at the end of an offline golden-dataset run.
+///
+/// Means are computed over items that produced the metric; `None` when no item did.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvalRunReport {
+    /// [`EVAL_REPORT_SCHEMA_VERSION`] when written; 1 for reports that predate the field.
+    #[serde(default = "schema_version_1")]
+    pub schema_version: u32,
+    pub run_id: EvalRunId,
+    pub judge_model: String,
+    pub total_evaluated: usize,
+    pub mean_faithfulness: Option<f32>,
+    pub mean_context_recall: Option<f32>,
+    pub mean_correctness: Option<f32>,
+    #[serde(default)]
+    pub mean_answer_relevancy: Option<f32>,
+    #[serde(default)]
+    pub mean_context_precision: Option<f32>,
+    /// Questions below the threshold or errored: `below_threshold_count + errored_count`.
+    pub failed_count: usize,
+    /// Scored questions whose faithfulness fell below the threshold.
+    #[serde(default)]
+    pub below_threshold_count: usize,
+    /// Questions that could not be answered or scored.
+    #[serde(default)]
+    pub errored_count: usize,
+    pub results: Vec<EvalRunItem>,
+}
+
+impl EvalRunReport {
+    pub fn from_items(run_id: EvalRunId, judge_model: &str, results: Vec<EvalRunItem>) -> Self {
+        Self {
+            schema_version: EVAL_REPORT_SCHEMA_VERSION,
+            run_id,
+            judge_model: judge_model.to_string(),
+            total_evaluated: results.len(),
+            mean_faithfulness: mean_of(&results, |r| r.faithfulness),
+            mean_context_recall: mean_of(&results, |r| r.context_recall),
+            mean_correctness: mean_of(&results, |r| r.correctness),
+            mean_answer_relevancy: mean_of(&results, |r| r.answer_relevancy),
+            mean_context_precision: mean_of(&results, |r| r.context_precision),
+            failed_count: results.iter().filter(|r| r.failed).count(),
+            below_threshold_count: results.iter().filter(|r| r.below_threshold).count(),
+            errored_count: results.iter().filter(|r| r.error.is_some()).count(),
+            results,
+        }
+    }
+}
+
+fn mean_of(items: &[EvalRunItem], metric: impl Fn(&EvalRunItem) -> Option<f32>) -> Option<f32> {
+    let values: Vec<f32> = items.iter().filter_map(metric).collect();
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f32>() / values.len() as f32)
+}
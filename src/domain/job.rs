@@ -1,4 +1,4 @@
-use super::{DocumentId, JobId, JobStatus};
+use super::{Document, DocumentId, JobId, JobStatus, SourceMetadata, StoragePath, TenantId};
 use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone)]
@@ -8,10 +8,129 @@ pub struct Job {
     pub status: JobStatus,
     pub job_type: String,
     pub error_message: Option<String>,
+    pub tenant_id: TenantId,
+    /// What the worker runs; `None` for jobs created before the queue was persisted.
+    pub payload: Option<JobPayload>,
+    /// Number of times a worker has claimed the job.
+    pub attempts: u32,
+    /// Worker currently holding the job, while it is in flight.
+    pub lease_owner: Option<String>,
+    /// When the holder's lease runs out unless renewed; an expired lease means the worker died.
+    pub lease_expires_at: Option<DateTime<Utc>>,
+    /// Earliest time a queued retry may be claimed; `None` means immediately.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    /// Set through the API; the worker running the job stops at its next checkpoint.
+    pub cancel_requested: bool,
+    pub progress: JobProgress,
+    /// URLs a web crawl, or files a repository sync, could not ingest in its latest attempt.
+    pub fetch_failures: Vec<FetchFailure>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// Counters a worker reports while it runs a job, reset on every attempt.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JobProgress {
+    pub pages_processed: u32,
+    pub segments_transcribed: u32,
+    pub chunks_total: u32,
+    pub chunks_embedded: u32,
+    /// Pages a web crawl has fetched and submitted for ingestion.
+    pub urls_ingested: u32,
+    /// Files a repository sync submitted because they are new or changed.
+    pub files_ingested: u32,
+    /// Files a repository sync left alone because their content is already ingested.
+    pub files_unchanged: u32,
+    /// Documents a repository sync deleted because their file is gone from the checkout.
+    pub files_removed: u32,
+}
+
+/// Work a queued job describes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobPayload {
+    Ingestion(IngestionPayload),
+    Crawl(CrawlRequest),
+    RepositorySync(RepositorySyncRequest),
+}
+
+impl From<IngestionPayload> for JobPayload {
+    fn from(payload: IngestionPayload) -> Self {
+        Self::Ingestion(payload)
+    }
+}
+
+impl From<CrawlRequest> for JobPayload {
+    fn from(request: CrawlRequest) -> Self {
+        Self::Crawl(request)
+    }
+}
+
+impl From<RepositorySyncRequest> for JobPayload {
+    fn from(request: RepositorySyncRequest) -> Self {
+        Self::RepositorySync(request)
+    }
+}
+
+/// Everything an ingestion worker needs to rebuild a run from the job row alone.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngestionPayload {
+    pub document: Document,
+    pub storage_path: StoragePath,
+    /// Re-ingestion of a cataloged document, whose previous transcript is dropped when the
+    /// new version has none. Stale chunks are pruned on every run, only after the new ones
+    /// are upserted, so the document never drops out of search mid-replacement and a
+    /// failed run leaves the previous version searchable.
+    pub replace_existing: bool,
+    /// Subject of the principal that submitted the document, if any.
+    pub principal: Option<String>,
+    pub source: SourceMetadata,
+    /// Caption file of an audio or video document, used instead of transcribing it.
+    pub captions: Option<StoragePath>,
+}
+
+/// A web ingestion: the page at `url`, or every page a sitemap at `url` lists, plus the
+/// pages their links lead to up to `max_depth`. Each page becomes a document of its own,
+/// ingested through a regular ingestion job.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrawlRequest {
+    pub url: String,
+    /// Treat `url` as a sitemap (or sitemap index) rather than a page.
+    pub sitemap: bool,
+    /// Link hops followed from the start pages; `0` ingests only the start pages.
+    pub max_depth: u32,
+    /// Upper bound on pages submitted for ingestion.
+    pub max_pages: u32,
+    /// Hosts links may lead to, subdomains included; empty means the host of `url`.
+    pub allowed_domains: Vec<String>,
+    /// Subject of the principal that submitted the crawl, if any.
+    pub principal: Option<String>,
+    /// Applied to every page; the source URL and a missing title come from the page.
+    pub source: SourceMetadata,
+}
+
+/// A sync of a local git checkout. Every source file not excluded by `.gitignore` becomes a
+/// document named `<name>/<path>`; files whose content is already ingested are skipped and
+/// documents whose file is gone are deleted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RepositorySyncRequest {
+    /// Canonical path of the checkout.
+    pub path: String,
+    /// Prefix of every document filename; identifies the repository across syncs.
+    pub name: String,
+    /// Subject of the principal that submitted the sync, if any.
+    pub principal: Option<String>,
+    /// Applied to every file.
+    pub source: SourceMetadata,
+}
+
+/// A URL a crawl, or a file a repository sync, skipped or could not ingest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FetchFailure {
+    /// The URL, or the document filename for repository files.
+    pub url: String,
+    pub error: String,
+}
+
 impl Job {
     pub fn new(document_id: Option<DocumentId>, job_type: String) -> Self {
         let now = Utc::now();
@@ -21,8 +140,34 @@ impl Job {
             status: JobStatus::Queued,
             job_type,
             error_message: None,
+            tenant_id: TenantId::default(),
+            payload: None,
+            attempts: 0,
+            lease_owner: None,
+            lease_expires_at: None,
+            next_attempt_at: None,
+            cancel_requested: false,
+            progress: JobProgress::default(),
+            fetch_failures: Vec::new(),
             created_at: now,
             updated_at: now,
         }
     }
+
+    pub fn with_tenant(mut self, tenant_id: TenantId) -> Self {
+        self.tenant_id = tenant_id;
+        self
+    }
+
+    pub fn with_payload(mut self, payload: impl Into<JobPayload>) -> Self {
+        self.payload = Some(payload.into());
+        self
+    }
+
+    pub fn ingestion_payload(&self) -> Option<&IngestionPayload> {
+        match &self.payload {
+            Some(JobPayload::Ingestion(payload)) => Some(payload),
+            _ => None,
+        }
+    }
 }
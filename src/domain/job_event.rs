@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+
+use super::{JobId, JobProgress, JobStatus, TenantId};
+
+/// A change to a job as it happens, for clients following ingestion live.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobEvent {
+    pub job_id: JobId,
+    pub tenant_id: TenantId,
+    pub kind: JobEventKind,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobEventKind {
+    StatusChanged {
+        status: JobStatus,
+        error_message: Option<String>,
+    },
+    Progress(JobProgress),
+}
+
+impl JobEvent {
+    pub fn status_changed(
+        job_id: JobId,
+        tenant_id: TenantId,
+        status: JobStatus,
+        error_message: Option<String>,
+    ) -> Self {
+        Self::new(
+            job_id,
+            tenant_id,
+            JobEventKind::StatusChanged {
+                status,
+                error_message,
+            },
+        )
+    }
+
+    pub fn progress(job_id: JobId, tenant_id: TenantId, progress: JobProgress) -> Self {
+        Self::new(job_id, tenant_id, JobEventKind::Progress(progress))
+    }
+
+    fn new(job_id: JobId, tenant_id: TenantId, kind: JobEventKind) -> Self {
+        Self {
+            job_id,
+            tenant_id,
+            kind,
+            occurred_at: Utc::now(),
+        }
+    }
+}
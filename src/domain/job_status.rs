@@ -10,9 +10,30 @@ pub enum JobStatus {
     Embedding,
     Completed,
     Failed,
+    Cancelled,
 }
 
 impl JobStatus {
+    /// Statuses a worker moves a claimed job through before it completes or fails.
+    pub const IN_FLIGHT: [JobStatus; 4] = [
+        JobStatus::Processing,
+        JobStatus::MediaExtraction,
+        JobStatus::Transcribing,
+        JobStatus::Embedding,
+    ];
+
+    pub fn is_in_flight(&self) -> bool {
+        Self::IN_FLIGHT.contains(self)
+    }
+
+    /// Statuses no worker moves a job out of; only an explicit retry re-queues it.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Completed | JobStatus::Failed | JobStatus::Cancelled
+        )
+    }
+
     pub fn as_str(&self) -> &'static str {
         match self {
             JobStatus::Queued => "QUEUED",
@@ -22,6 +43,7 @@ impl JobStatus {
             JobStatus::Embedding => "EMBEDDING",
             JobStatus::Completed => "COMPLETED",
             JobStatus::Failed => "FAILED",
+            JobStatus::Cancelled => "CANCELLED",
         }
     }
 }
@@ -38,6 +60,7 @@ impl FromStr for JobStatus {
             "EMBEDDING" => Ok(JobStatus::Embedding),
             "COMPLETED" => Ok(JobStatus::Completed),
             "FAILED" => Ok(JobStatus::Failed),
+            "CANCELLED" => Ok(JobStatus::Cancelled),
             _ => Err(format!("Invalid job status: {}", s)),
         }
     }
@@ -1,41 +1,72 @@
+mod api_key;
+mod captions;
 mod chunk;
+mod code_span;
 mod conversation;
 mod conversation_id;
 mod document;
 mod document_metadata;
+mod document_record;
 mod embedding;
 mod eval_entry;
 mod eval_event;
 mod eval_outbox;
+mod eval_report_schema;
 mod eval_result;
+mod eval_run;
 mod job;
+mod job_event;
 mod job_id;
 mod job_status;
 mod message;
 mod message_id;
 mod message_role;
+mod page_segment;
+mod principal;
+mod robots;
 mod storage_path;
+mod tenant_id;
 mod tool_call;
 mod transcript_segment;
+mod transcript_word;
+mod webhook;
 
+pub use api_key::{ApiKey, ApiKeyId, ApiScope};
 pub use chunk::{Chunk, ChunkId, DocumentId};
+pub use code_span::{CodeSpan, SourceLanguage};
 pub use conversation::Conversation;
 pub use conversation_id::ConversationId;
 pub use document::{ContentType, Document};
-pub use document_metadata::DocumentMetadata;
+pub use document_metadata::{DocumentMetadata, SourceMetadata};
+pub use document_record::{ContentHasher, DocumentRecord};
 pub use embedding::{Embedding, SparseEmbedding};
 pub use eval_entry::EvalEntry;
 pub use eval_event::{
     AgenticTrace, EvalEvent, EvalEventId, EvalOperationType, EvalSource, ToolCallTrace,
 };
 pub use eval_outbox::{EvalOutboxEntry, EvalOutboxStatus};
+pub use eval_report_schema::EVAL_REPORT_SCHEMA_VERSION;
 pub use eval_result::{EvalResult, EvalResultId};
-pub use job::Job;
+pub use eval_run::{EvalRunId, EvalRunItem, EvalRunReport};
+pub use job::{
+    CrawlRequest, FetchFailure, IngestionPayload, Job, JobPayload, JobProgress,
+    RepositorySyncRequest,
+};
+pub use job_event::{JobEvent, JobEventKind};
 pub use job_id::JobId;
 pub use job_status::JobStatus;
 pub use message::Message;
 pub use message_id::MessageId;
 pub use message_role::MessageRole;
+pub use page_segment::{BoundingRegion, PageSegment};
+pub use principal::Principal;
+pub use robots::RobotsRules;
 pub use storage_path::StoragePath;
+pub use tenant_id::TenantId;
 pub use tool_call::{ToolCall, ToolCallId, ToolName, ToolResult};
-pub use transcript_segment::TranscriptSegment;
+pub use transcript_segment::{Transcript, TranscriptSegment};
+pub use transcript_word::TranscriptWord;
+pub use webhook::{
+    ClaimedWebhookDelivery, WebhookDelivery, WebhookDeliveryStatus, WebhookEvent, WebhookEventType,
+    WebhookSubscription, WebhookSubscriptionId,
+};
@@ -0,0 +1,42 @@
+/// Text extracted from one page of a document.
+///
+/// Paginated formats (PDF) yield one segment per page with a 1-based `page`; sources
+/// without pages (plain text) yield a single segment with `page: None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageSegment {
+    pub text: String,
+    pub page: Option<u32>,
+    /// Regions of the page the text was read from, when the extractor reports layout.
+    pub regions: Vec<BoundingRegion>,
+}
+
+/// A polygon on a page as flat `[x1, y1, x2, y2, ...]` coordinates, in the unit the
+/// extractor reports (inches for Azure Document Intelligence PDF results).
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundingRegion {
+    pub polygon: Vec<f32>,
+}
+
+impl PageSegment {
+    pub fn new(page: u32, text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            page: Some(page),
+            regions: Vec::new(),
+        }
+    }
+
+    /// A segment for a source without pages.
+    pub fn unpaged(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            page: None,
+            regions: Vec::new(),
+        }
+    }
+
+    pub fn with_regions(mut self, regions: Vec<BoundingRegion>) -> Self {
+        self.regions = regions;
+        self
+    }
+}
@@ -0,0 +1,49 @@
+use std::fmt;
+
+use super::{ApiKey, ApiScope, TenantId};
+
+/// The authenticated caller of a request.
+///
+/// `subject` identifies the caller in logs, spans and eval events (`api_key:<id>`,
+/// `static:<hash prefix>` or `anonymous`) and never contains secret material.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Principal {
+    pub subject: String,
+    pub tenant_id: TenantId,
+    pub scopes: Vec<ApiScope>,
+    /// May manage API keys of every tenant. Only the operator's static keys can.
+    pub all_tenants: bool,
+}
+
+impl Principal {
+    /// Caller of an instance running without authentication; holds every scope.
+    pub fn anonymous(tenant_id: TenantId) -> Self {
+        Self {
+            subject: "anonymous".to_string(),
+            tenant_id,
+            scopes: ApiScope::ALL.to_vec(),
+            all_tenants: false,
+        }
+    }
+
+    pub fn from_api_key(key: &ApiKey) -> Self {
+        Self {
+            subject: format!("api_key:{}", key.id),
+            tenant_id: key.tenant_id.clone(),
+            scopes: key.scopes.clone(),
+            all_tenants: false,
+        }
+    }
+
+    pub fn has_scope(&self, scope: ApiScope) -> bool {
+        self.scopes
+            .iter()
+            .any(|s| *s == scope || *s == ApiScope::Admin)
+    }
+}
+
+impl fmt::Display for Principal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.subject)
+    }
+}
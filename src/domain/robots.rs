@@ -0,0 +1,127 @@
+/// Access rules a site's `robots.txt` sets for one crawler.
+///
+/// Follows RFC 9309: the groups naming the crawler's product token apply, or the `*` group
+/// when none does; the longest matching `allow`/`disallow` pattern decides, with `allow`
+/// winning ties. Patterns support `*` and a trailing `$`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RobotsRules {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rule {
+    allow: bool,
+    pattern: String,
+}
+
+#[derive(Default)]
+struct Group {
+    agents: Vec<String>,
+    rules: Vec<Rule>,
+}
+
+impl RobotsRules {
+    /// Rules that allow everything, used when a site has no `robots.txt`.
+    pub fn allow_all() -> Self {
+        Self::default()
+    }
+
+    /// Rules that allow nothing, used while a site's `robots.txt` is unreachable.
+    pub fn disallow_all() -> Self {
+        Self {
+            rules: vec![Rule {
+                allow: false,
+                pattern: "/".to_string(),
+            }],
+        }
+    }
+
+    pub fn parse(robots_txt: &str, user_agent: &str) -> Self {
+        let mut groups: Vec<Group> = Vec::new();
+        let mut in_agent_lines = false;
+
+        for line in robots_txt.lines() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = value.trim();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "user-agent" => {
+                    if !in_agent_lines {
+                        groups.push(Group::default());
+                        in_agent_lines = true;
+                    }
+                    if let Some(group) = groups.last_mut() {
+                        group.agents.push(value.to_ascii_lowercase());
+                    }
+                }
+                field @ ("allow" | "disallow") => {
+                    in_agent_lines = false;
+                    // An empty pattern matches nothing, so it places no restriction.
+                    if let (Some(group), false) = (groups.last_mut(), value.is_empty()) {
+                        group.rules.push(Rule {
+                            allow: field == "allow",
+                            pattern: value.to_string(),
+                        });
+                    }
+                }
+                _ => in_agent_lines = false,
+            }
+        }
+
+        let token = user_agent.to_ascii_lowercase();
+        let agent = if groups.iter().any(|g| g.agents.contains(&token)) {
+            token.as_str()
+        } else {
+            "*"
+        };
+        let rules = groups
+            .into_iter()
+            .filter(|g| g.agents.iter().any(|a| a == agent))
+            .flat_map(|g| g.rules)
+            .collect();
+        Self { rules }
+    }
+
+    /// Whether the crawler may fetch `path`, the URL's path and query.
+    pub fn allows(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|rule| pattern_matches(&rule.pattern, path))
+            .max_by_key(|rule| (rule.pattern.len(), rule.allow))
+            .is_none_or(|rule| rule.allow)
+    }
+}
+
+/// Prefix match of `path` against a pattern where `*` matches any run of characters and a
+/// trailing `$` anchors the end.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(p) => (p, true),
+        None => (pattern, false),
+    };
+    let pattern = pattern.as_bytes();
+    let path = path.as_bytes();
+
+    // positions[i]: whether the pattern consumed so far can end at path[..i].
+    let mut positions = vec![false; path.len() + 1];
+    positions[0] = true;
+    for &p in pattern {
+        if p == b'*' {
+            for i in 1..=path.len() {
+                positions[i] |= positions[i - 1];
+            }
+        } else {
+            for i in (1..=path.len()).rev() {
+                positions[i] = positions[i - 1] && path[i - 1] == p;
+            }
+            positions[0] = false;
+        }
+    }
+    if anchored {
+        positions[path.len()]
+    } else {
+        positions.iter().any(|&reachable| reachable)
+    }
+}
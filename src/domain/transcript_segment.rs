@@ -1,3 +1,27 @@
+use super::TranscriptWord;
+
+/// Output of a transcription engine for one recording.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Transcript {
+    pub segments: Vec<TranscriptSegment>,
+    /// ISO 639-1 code of the spoken language, when configured or detected.
+    pub language: Option<String>,
+}
+
+impl Transcript {
+    pub fn new(segments: Vec<TranscriptSegment>) -> Self {
+        Self {
+            segments,
+            language: None,
+        }
+    }
+
+    pub fn with_language(mut self, language: Option<String>) -> Self {
+        self.language = language;
+        self
+    }
+}
+
 /// A timed speech segment produced by a transcription engine.
 ///
 /// Maps 1-to-1 with a Whisper output segment (or equivalent). The `start_time`
@@ -7,6 +31,8 @@ pub struct TranscriptSegment {
     pub text: String,
     pub start_time: f32,
     pub end_time: f32,
+    /// Timed words of the segment, in order; empty when the engine did not time words.
+    pub words: Vec<TranscriptWord>,
 }
 
 impl TranscriptSegment {
@@ -15,9 +41,15 @@ impl TranscriptSegment {
             text: text.into(),
             start_time,
             end_time,
+            words: Vec::new(),
         }
     }
 
+    pub fn with_words(mut self, words: Vec<TranscriptWord>) -> Self {
+        self.words = words;
+        self
+    }
+
     /// Returns the full plain text across all segments joined by a single space.
     pub fn merge_text(segments: &[TranscriptSegment]) -> String {
         segments
@@ -27,4 +59,29 @@ impl TranscriptSegment {
             .collect::<Vec<_>>()
             .join(" ")
     }
+
+    /// Checks that segments form a usable transcript: at least one segment, each with text
+    /// and finite, non-negative times that do not run backwards.
+    pub fn validate_transcript(segments: &[Self]) -> Result<(), String> {
+        if segments.is_empty() {
+            return Err("transcript has no segments".to_string());
+        }
+        for (index, segment) in segments.iter().enumerate() {
+            let position = index + 1;
+            if segment.text.trim().is_empty() {
+                return Err(format!("segment {position}: text is empty"));
+            }
+            let times_valid = segment.start_time.is_finite()
+                && segment.end_time.is_finite()
+                && segment.start_time >= 0.0
+                && segment.end_time >= segment.start_time;
+            if !times_valid {
+                return Err(format!(
+                    "segment {position}: invalid time range {}..{}",
+                    segment.start_time, segment.end_time
+                ));
+            }
+        }
+        Ok(())
+    }
 }
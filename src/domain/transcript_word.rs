@@ -0,0 +1,46 @@
+use super::TranscriptSegment;
+
+/// A word of a [`TranscriptSegment`] with its own timing, in seconds from the start of
+/// the media file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptWord {
+    pub text: String,
+    pub start_time: f32,
+    pub end_time: f32,
+}
+
+impl TranscriptWord {
+    pub fn new(text: impl Into<String>, start_time: f32, end_time: f32) -> Self {
+        Self {
+            text: text.into(),
+            start_time,
+            end_time,
+        }
+    }
+}
+
+impl TranscriptSegment {
+    /// When the segment's speech begins: its first word when words are timed, otherwise
+    /// the segment start.
+    pub fn speech_start(&self) -> f32 {
+        self.words
+            .first()
+            .map_or(self.start_time, |word| word.start_time)
+    }
+
+    /// Hands each word to the segment it was spoken in, for engines that report words
+    /// separately from segments. A word belongs to the last segment starting at or before
+    /// its midpoint.
+    pub fn attach_words(segments: &mut [Self], words: Vec<TranscriptWord>) {
+        let mut index = 0;
+        for word in words {
+            let midpoint = (word.start_time + word.end_time) / 2.0;
+            while index + 1 < segments.len() && segments[index + 1].start_time <= midpoint {
+                index += 1;
+            }
+            if let Some(segment) = segments.get_mut(index) {
+                segment.words.push(word);
+            }
+        }
+    }
+}
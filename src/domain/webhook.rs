@@ -0,0 +1,215 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::TenantId;
+
+const SECRET_PREFIX: &str = "whsec_";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WebhookSubscriptionId(Uuid);
+
+impl WebhookSubscriptionId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for WebhookSubscriptionId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for WebhookSubscriptionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Lifecycle event a webhook subscription can listen to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WebhookEventType {
+    #[serde(rename = "document.ingested")]
+    DocumentIngested,
+    #[serde(rename = "job.failed")]
+    JobFailed,
+    #[serde(rename = "eval.below_threshold")]
+    EvalBelowThreshold,
+}
+
+impl WebhookEventType {
+    pub const ALL: [WebhookEventType; 3] = [
+        Self::DocumentIngested,
+        Self::JobFailed,
+        Self::EvalBelowThreshold,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DocumentIngested => "document.ingested",
+            Self::JobFailed => "job.failed",
+            Self::EvalBelowThreshold => "eval.below_threshold",
+        }
+    }
+}
+
+impl FromStr for WebhookEventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|event_type| event_type.as_str() == s)
+            .ok_or_else(|| format!("Invalid webhook event type: {}", s))
+    }
+}
+
+impl fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Endpoint of one tenant that receives signed POSTs for the events it subscribed to.
+#[derive(Debug, Clone)]
+pub struct WebhookSubscription {
+    pub id: WebhookSubscriptionId,
+    pub tenant_id: TenantId,
+    pub url: String,
+    pub event_types: Vec<WebhookEventType>,
+    /// HMAC-SHA256 key for the `X-Sandakan-Signature` header.
+    pub secret: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WebhookSubscription {
+    /// Creates a subscription with a freshly generated signing secret.
+    pub fn generate(tenant_id: TenantId, url: &str, event_types: Vec<WebhookEventType>) -> Self {
+        Self {
+            id: WebhookSubscriptionId::new(),
+            tenant_id,
+            url: url.to_string(),
+            event_types,
+            secret: format!(
+                "{SECRET_PREFIX}{}{}",
+                Uuid::new_v4().simple(),
+                Uuid::new_v4().simple()
+            ),
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn subscribes_to(&self, event_type: WebhookEventType) -> bool {
+        self.event_types.contains(&event_type)
+    }
+}
+
+/// Something that happened to a tenant's documents, jobs or evaluations, fanned out to every
+/// subscription of that tenant listening to `event_type`.
+#[derive(Debug, Clone)]
+pub struct WebhookEvent {
+    /// Shared by all deliveries of the event, so receivers can deduplicate retries.
+    pub id: Uuid,
+    pub tenant_id: TenantId,
+    pub event_type: WebhookEventType,
+    pub occurred_at: DateTime<Utc>,
+    pub data: serde_json::Value,
+}
+
+impl WebhookEvent {
+    pub fn new(tenant_id: TenantId, event_type: WebhookEventType, data: serde_json::Value) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            tenant_id,
+            event_type,
+            occurred_at: Utc::now(),
+            data,
+        }
+    }
+
+    /// JSON body POSTed to subscribers.
+    pub fn payload(&self) -> serde_json::Value {
+        serde_json::json!({
+            "id": self.id,
+            "type": self.event_type.as_str(),
+            "created_at": self.occurred_at.to_rfc3339(),
+            "data": self.data,
+        })
+    }
+}
+
+/// Status lifecycle for webhook deliveries: Pending → Delivered | Failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+impl fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WebhookDeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "delivered" => Ok(Self::Delivered),
+            "failed" => Ok(Self::Failed),
+            other => Err(format!("unknown webhook delivery status: {other}")),
+        }
+    }
+}
+
+/// One event queued for one subscription; doubles as the delivery log entry.
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub subscription_id: WebhookSubscriptionId,
+    pub event_id: Uuid,
+    pub event_type: WebhookEventType,
+    pub payload: serde_json::Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: u32,
+    pub next_attempt_at: DateTime<Utc>,
+    /// HTTP status of the last attempt, when the endpoint answered.
+    pub response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// A due delivery claimed by a dispatcher, with the endpoint and key to sign it with.
+#[derive(Debug, Clone)]
+pub struct ClaimedWebhookDelivery {
+    pub delivery: WebhookDelivery,
+    pub url: String,
+    pub secret: String,
+}
@@ -1,8 +1,11 @@
 use std::io::Read;
+use std::path::Path;
+use std::process::ChildStdout;
 
+use ffmpeg_sidecar::child::FfmpegChild;
 use ffmpeg_sidecar::command::FfmpegCommand;
 
-use crate::application::ports::{AudioDecoder, AudioDecoderError, TranscriptionError};
+use crate::application::ports::{AudioDecoder, AudioDecoderError, PcmReader, TranscriptionError};
 
 pub fn check_ffmpeg_binary() -> Result<(), TranscriptionError> {
     let mut child = FfmpegCommand::new().arg("-version").spawn().map_err(|e| {
@@ -95,4 +98,119 @@ impl AudioDecoder for FfmpegAudioDecoder {
 
         Ok(pcm)
     }
+
+    fn open(&self, path: &Path) -> Result<Box<dyn PcmReader>, AudioDecoderError> {
+        // Only errors go to stderr: nothing drains it while PCM is read, so info-level
+        // progress lines would fill the pipe and stall ffmpeg on long recordings.
+        let mut child = FfmpegCommand::new()
+            .args(["-nostats", "-loglevel", "error", "-y", "-i"])
+            .arg(path)
+            .args(["-vn", "-ar", "16000", "-ac", "1", "-f", "s16le", "pipe:1"])
+            .spawn()
+            .map_err(|e| {
+                AudioDecoderError::DecodingFailed(format!("ffmpeg spawn failed: {}", e))
+            })?;
+
+        let stdout = child.take_stdout().ok_or_else(|| {
+            AudioDecoderError::DecodingFailed("ffmpeg stdout unavailable".to_string())
+        })?;
+
+        Ok(Box::new(FfmpegPcmReader {
+            child,
+            stdout,
+            bytes: Vec::new(),
+            samples_read: 0,
+            finished: false,
+        }))
+    }
+}
+
+/// PCM read straight from a running ffmpeg's stdout.
+struct FfmpegPcmReader {
+    child: FfmpegChild,
+    stdout: ChildStdout,
+    bytes: Vec<u8>,
+    samples_read: u64,
+    finished: bool,
+}
+
+impl FfmpegPcmReader {
+    fn finish(&mut self) -> Result<(), AudioDecoderError> {
+        self.finished = true;
+        let status = self
+            .child
+            .wait()
+            .map_err(|e| AudioDecoderError::DecodingFailed(format!("ffmpeg wait: {}", e)))?;
+
+        if !status.success() {
+            let mut stderr = String::new();
+            if let Some(mut pipe) = self.child.take_stderr() {
+                let _ = pipe.read_to_string(&mut stderr);
+            }
+            return Err(AudioDecoderError::DecodingFailed(format!(
+                "ffmpeg exited with non-zero status during audio extraction: {}",
+                stderr.trim()
+            )));
+        }
+        if self.samples_read == 0 {
+            return Err(AudioDecoderError::DecodingFailed(
+                "no audio samples decoded: ffmpeg produced no output".to_string(),
+            ));
+        }
+
+        tracing::debug!(
+            samples = self.samples_read,
+            duration_secs = self.samples_read as f32 / 16_000.0,
+            "Audio streamed as 16kHz mono PCM via ffmpeg-sidecar"
+        );
+        Ok(())
+    }
+}
+
+impl PcmReader for FfmpegPcmReader {
+    fn read(&mut self, buffer: &mut [f32]) -> Result<usize, AudioDecoderError> {
+        if self.finished {
+            return Ok(0);
+        }
+
+        self.bytes.resize(buffer.len() * 2, 0);
+        let mut filled = 0;
+        while filled < self.bytes.len() {
+            match self.stdout.read(&mut self.bytes[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => {
+                    return Err(AudioDecoderError::DecodingFailed(format!(
+                        "reading ffmpeg stdout: {}",
+                        e
+                    )));
+                }
+            }
+        }
+
+        let samples = filled / 2;
+        for (sample, b) in buffer
+            .iter_mut()
+            .zip(self.bytes[..samples * 2].chunks_exact(2))
+        {
+            *sample = i16::from_le_bytes([b[0], b[1]]) as f32 / i16::MAX as f32;
+        }
+        self.samples_read += samples as u64;
+
+        if samples == 0 {
+            self.finish()?;
+        }
+        Ok(samples)
+    }
+}
+
+impl Drop for FfmpegPcmReader {
+    fn drop(&mut self) {
+        // A reader abandoned mid-stream (a cancelled job) must not leave ffmpeg running.
+        if !self.finished {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
 }
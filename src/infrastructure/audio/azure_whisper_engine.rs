@@ -2,29 +2,38 @@ use async_trait::async_trait;
 use reqwest::multipart;
 use serde::Deserialize;
 
-use crate::application::ports::{TranscriptionEngine, TranscriptionError};
-use crate::domain::TranscriptSegment;
+use super::whisper_language::whisper_language_code;
+use crate::application::ports::{TranscriptionEngine, TranscriptionError, TranscriptionOptions};
+use crate::domain::{Transcript, TranscriptSegment, TranscriptWord};
 
 pub struct AzureWhisperEngine {
     client: reqwest::Client,
-    endpoint: String,
+    deployment_url: String,
+    api_version: String,
     api_key: String,
+    options: TranscriptionOptions,
 }
 
 impl AzureWhisperEngine {
     pub fn new(base_url: &str, deployment: &str, api_key: &str, api_version: &str) -> Self {
-        let endpoint = format!(
-            "{}/openai/deployments/{}/audio/transcriptions?api-version={}",
+        let deployment_url = format!(
+            "{}/openai/deployments/{}",
             base_url.trim_end_matches('/'),
             deployment,
-            api_version,
         );
         Self {
             client: reqwest::Client::new(),
-            endpoint,
+            deployment_url,
+            api_version: api_version.to_string(),
             api_key: api_key.to_string(),
+            options: TranscriptionOptions::default(),
         }
     }
+
+    pub fn with_options(mut self, options: TranscriptionOptions) -> Self {
+        self.options = options;
+        self
+    }
 }
 
 #[derive(Deserialize)]
@@ -34,31 +43,61 @@ struct AzureWhisperSegment {
     text: String,
 }
 
+#[derive(Deserialize)]
+struct AzureWhisperWord {
+    word: String,
+    start: f32,
+    end: f32,
+}
+
 #[derive(Deserialize)]
 struct AzureVerboseResponse {
     segments: Vec<AzureWhisperSegment>,
+    /// Detected language by name, e.g. `"english"`.
+    #[serde(default)]
+    language: Option<String>,
+    /// Present when word timestamps were requested.
+    #[serde(default)]
+    words: Vec<AzureWhisperWord>,
 }
 
 #[async_trait]
 impl TranscriptionEngine for AzureWhisperEngine {
-    async fn transcribe(
-        &self,
-        audio_data: &[u8],
-    ) -> Result<Vec<TranscriptSegment>, TranscriptionError> {
+    async fn transcribe(&self, audio_data: &[u8]) -> Result<Transcript, TranscriptionError> {
+        // Translations take no language and time only segments.
+        let task = if self.options.translate {
+            "translations"
+        } else {
+            "transcriptions"
+        };
+        let endpoint = format!(
+            "{}/audio/{}?api-version={}",
+            self.deployment_url, task, self.api_version
+        );
+
         let file_part = multipart::Part::bytes(audio_data.to_vec())
             .file_name("audio.wav")
             .mime_str("audio/wav")
             .map_err(|e| TranscriptionError::ApiRequestFailed(format!("mime: {}", e)))?;
 
-        let form = multipart::Form::new()
-            .text("response_format", "verbose_json")
-            .part("file", file_part);
+        let mut form = multipart::Form::new().text("response_format", "verbose_json");
+        if !self.options.translate {
+            if let Some(language) = &self.options.language {
+                form = form.text("language", language.clone());
+            }
+            if self.options.word_timestamps {
+                form = form
+                    .text("timestamp_granularities[]", "segment")
+                    .text("timestamp_granularities[]", "word");
+            }
+        }
+        let form = form.part("file", file_part);
 
-        tracing::debug!(endpoint = %self.endpoint, "Sending audio to Azure OpenAI Whisper");
+        tracing::debug!(endpoint = %endpoint, "Sending audio to Azure OpenAI Whisper");
 
         let response = self
             .client
-            .post(&self.endpoint)
+            .post(&endpoint)
             .header("api-key", &self.api_key)
             .multipart(form)
             .send()
@@ -82,17 +121,32 @@ impl TranscriptionEngine for AzureWhisperEngine {
             .await
             .map_err(|e| TranscriptionError::ApiRequestFailed(format!("parse response: {}", e)))?;
 
-        let segments: Vec<TranscriptSegment> = result
+        let mut segments: Vec<TranscriptSegment> = result
             .segments
             .into_iter()
             .map(|s| TranscriptSegment::new(s.text.trim().to_string(), s.start, s.end))
             .collect();
+        TranscriptSegment::attach_words(
+            &mut segments,
+            result
+                .words
+                .into_iter()
+                .map(|w| TranscriptWord::new(w.word.trim(), w.start, w.end))
+                .collect(),
+        );
+        let language = result
+            .language
+            .as_deref()
+            .and_then(whisper_language_code)
+            .map(str::to_string)
+            .or_else(|| self.options.language.clone());
 
         tracing::info!(
             segment_count = segments.len(),
+            language = language.as_deref(),
             "Azure OpenAI Whisper transcription completed"
         );
 
-        Ok(segments)
+        Ok(Transcript::new(segments).with_language(language))
     }
 }
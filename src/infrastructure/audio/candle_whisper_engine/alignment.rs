@@ -0,0 +1,193 @@
+use std::path::Path;
+
+use candle_core::{DType, Device, IndexOp, Tensor};
+use candle_nn::{Embedding, Module, VarBuilder};
+use candle_transformers::models::whisper::Config;
+
+use super::LoadedWhisper;
+use super::decoder_layer::DecoderLayer;
+use super::decoding::Phrase;
+use super::word_timing::{SECONDS_PER_FRAME, split_words, splits_on_spaces, token_frames};
+use crate::application::ports::TranscriptionError;
+use crate::domain::TranscriptWord;
+
+/// Times decoded words from the decoder's cross-attention, as openai-whisper does.
+///
+/// Candle's decoder keeps its attention private, so the layers up to the last alignment
+/// head are loaded a second time and run once over each window's decoded tokens.
+pub(super) struct WordAligner {
+    token_embedding: Embedding,
+    positional_embedding: Tensor,
+    layers: Vec<DecoderLayer>,
+    /// (layer, head) pairs whose cross-attention follows the speech.
+    heads: Vec<(usize, usize)>,
+    n_head: usize,
+}
+
+impl WordAligner {
+    /// Loads the decoder from `weights`, taking the alignment heads from the model's
+    /// `generation_config.json`. Without one, every head of the upper half of the decoder
+    /// is used, openai-whisper's default.
+    pub(super) fn load(
+        weights: &Path,
+        generation_config: Option<&Path>,
+        config: &Config,
+        dtype: DType,
+        device: &Device,
+    ) -> Result<Self, TranscriptionError> {
+        let failed = |e: candle_core::Error| {
+            TranscriptionError::ModelLoadFailed(format!("alignment decoder: {}", e))
+        };
+        let heads = alignment_heads(generation_config, config);
+        let layer_count = heads.iter().map(|(layer, _)| layer + 1).max().unwrap_or(0);
+        let n_state = config.d_model;
+
+        // SAFETY: safetensors files are memory-mapped read-only
+        let vb = unsafe { VarBuilder::from_mmaped_safetensors(&[weights], dtype, device) }
+            .map_err(failed)?
+            .pp("model.decoder");
+        let layers = (0..layer_count)
+            .map(|i| DecoderLayer::load(n_state, vb.pp(format!("layers.{i}"))))
+            .collect::<candle_core::Result<Vec<_>>>()
+            .map_err(failed)?;
+
+        Ok(Self {
+            token_embedding: candle_nn::embedding(
+                config.vocab_size,
+                n_state,
+                vb.pp("embed_tokens"),
+            )
+            .map_err(failed)?,
+            positional_embedding: vb
+                .get(
+                    (config.max_target_positions, n_state),
+                    "embed_positions.weight",
+                )
+                .map_err(failed)?,
+            layers,
+            heads,
+            n_head: config.decoder_attention_heads,
+        })
+    }
+
+    /// Times the words of a window's phrases, in seconds from the start of the window.
+    ///
+    /// `prompt` is the start-of-transcript sequence ending in `<|notimestamps|>`; the
+    /// phrases' text tokens follow it and `<|endoftext|>` closes them, as when aligning in
+    /// Whisper.
+    pub(super) fn time_words(
+        &self,
+        whisper: &LoadedWhisper,
+        audio_features: &Tensor,
+        prompt: &[u32],
+        phrases: &[Phrase],
+        duration: f32,
+        language: Option<&str>,
+    ) -> Result<Vec<Vec<TranscriptWord>>, TranscriptionError> {
+        let mut timed: Vec<Vec<TranscriptWord>> = phrases.iter().map(|_| Vec::new()).collect();
+        let text: Vec<u32> = phrases
+            .iter()
+            .flat_map(|p| p.tokens.iter().copied())
+            .collect();
+        if text.is_empty() {
+            return Ok(timed);
+        }
+
+        let mut tokens = prompt.to_vec();
+        tokens.extend(&text);
+        tokens.push(whisper.tokens.eot);
+        let logits = self
+            .cross_attention(&tokens, audio_features, duration)
+            .map_err(|e| TranscriptionError::TranscriptionFailed(format!("alignment: {}", e)))?;
+        // The row of `<|notimestamps|>` predicts the first text token, the last text
+        // token's row predicts `eot`: one row per text token plus the end.
+        let first = prompt.len() - 1;
+        let rows: Vec<Vec<Vec<f32>>> = logits
+            .into_iter()
+            .map(|head| head[first..first + text.len() + 1].to_vec())
+            .collect();
+        let frames = token_frames(&rows);
+
+        let phrase_ends: Vec<usize> = phrases
+            .iter()
+            .scan(0, |end, phrase| {
+                *end += phrase.tokens.len();
+                Some(*end)
+            })
+            .collect();
+        let mut start = 0;
+        for (word, token_count) in
+            split_words(&whisper.tokenizer, &text, splits_on_spaces(language))?
+        {
+            let end = start + token_count;
+            let phrase = phrase_ends.partition_point(|&phrase_end| phrase_end <= start);
+            if let Some(words) = timed.get_mut(phrase) {
+                words.push(TranscriptWord::new(
+                    word,
+                    (frames[start] as f32 * SECONDS_PER_FRAME).min(duration),
+                    (frames[end] as f32 * SECONDS_PER_FRAME).min(duration),
+                ));
+            }
+            start = end;
+        }
+        Ok(timed)
+    }
+
+    /// Cross-attention logits of the alignment heads over the window's speech frames, as
+    /// (head, token, frame).
+    fn cross_attention(
+        &self,
+        tokens: &[u32],
+        audio_features: &Tensor,
+        duration: f32,
+    ) -> candle_core::Result<Vec<Vec<Vec<f32>>>> {
+        let device = audio_features.device();
+        let len = tokens.len();
+        let input = Tensor::new(tokens, device)?.unsqueeze(0)?;
+        let mut x = self
+            .token_embedding
+            .forward(&input)?
+            .broadcast_add(&self.positional_embedding.narrow(0, 0, len)?)?;
+        let mask: Vec<f32> = (0..len)
+            .flat_map(|i| (0..len).map(move |j| if j > i { f32::NEG_INFINITY } else { 0.0 }))
+            .collect();
+        let mask = Tensor::from_vec(mask, (len, len), device)?.to_dtype(x.dtype())?;
+
+        let mut heads = Vec::with_capacity(self.heads.len());
+        for (index, layer) in self.layers.iter().enumerate() {
+            let (next, logits) = layer.forward(&x, audio_features, &mask, self.n_head)?;
+            for (_, head) in self.heads.iter().filter(|(layer, _)| *layer == index) {
+                heads.push(logits.i((0, *head))?);
+            }
+            x = next;
+        }
+
+        let available = audio_features.dim(1)?;
+        let frames = ((duration / SECONDS_PER_FRAME).ceil() as usize).clamp(1, available);
+        Tensor::stack(&heads, 0)?
+            .narrow(2, 0, frames)?
+            .to_dtype(DType::F32)?
+            .to_vec3()
+    }
+}
+
+fn alignment_heads(generation_config: Option<&Path>, config: &Config) -> Vec<(usize, usize)> {
+    let configured: Vec<(usize, usize)> = generation_config
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|json| serde_json::from_str::<serde_json::Value>(&json).ok())
+        .and_then(|json| {
+            serde_json::from_value::<Vec<(usize, usize)>>(json["alignment_heads"].clone()).ok()
+        })
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|&(layer, head)| {
+            layer < config.decoder_layers && head < config.decoder_attention_heads
+        })
+        .collect();
+    if !configured.is_empty() {
+        return configured;
+    }
+    (config.decoder_layers / 2..config.decoder_layers)
+        .flat_map(|layer| (0..config.decoder_attention_heads).map(move |head| (layer, head)))
+        .collect()
+}
@@ -0,0 +1,236 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use candle_core::Tensor;
+use candle_transformers::models::whisper as m;
+use tokio::sync::mpsc;
+
+use super::alignment::WordAligner;
+use super::decoding::{decode_phrases, detect_language};
+use super::{CandleWhisperEngine, LoadedWhisper};
+use crate::application::ports::{
+    PcmReader, TranscriptionError, TranscriptionObserver, TranscriptionOptions,
+};
+use crate::domain::{Transcript, TranscriptSegment, TranscriptWord};
+use crate::infrastructure::audio::voice_activity::{SpeechWindows, VoiceActivityOptions};
+
+impl LoadedWhisper {
+    /// Apply the ASR correction dictionary to a decoded text segment.
+    /// Matching is case-insensitive on word boundaries; replacement uses the configured casing.
+    /// Corrections are pre-sorted longest-first so multi-word phrases win over substrings.
+    pub(super) fn apply_corrections(&self, text: &str) -> String {
+        if self.asr_corrections.is_empty() {
+            return text.to_string();
+        }
+
+        let lower = text.to_lowercase();
+        let bytes = lower.as_bytes();
+        let len = bytes.len();
+
+        // Collect (start, end, replacement) for every word-boundary match.
+        let mut matches: Vec<(usize, usize, &str)> = Vec::new();
+        for (artifact, correction) in &self.asr_corrections {
+            let art = artifact.as_bytes();
+            let art_len = art.len();
+            let mut pos = 0;
+            while pos + art_len <= len {
+                if bytes[pos..pos + art_len] == *art {
+                    let before_ok = pos == 0 || !bytes[pos - 1].is_ascii_alphabetic();
+                    let after_ok =
+                        pos + art_len == len || !bytes[pos + art_len].is_ascii_alphabetic();
+                    if before_ok && after_ok {
+                        matches.push((pos, pos + art_len, correction.as_str()));
+                        pos += art_len;
+                        continue;
+                    }
+                }
+                pos += 1;
+            }
+        }
+
+        if matches.is_empty() {
+            return text.to_string();
+        }
+
+        // Sort by start position; skip overlapping matches.
+        matches.sort_by_key(|&(start, _, _)| start);
+        let mut out = String::with_capacity(text.len());
+        let mut last_end = 0usize;
+        for (start, end, replacement) in matches {
+            if start < last_end {
+                continue;
+            }
+            out.push_str(&text[last_end..start]);
+            out.push_str(replacement);
+            last_end = end;
+        }
+        out.push_str(&text[last_end..]);
+        out
+    }
+
+    pub(super) fn mel_tensor(&self, samples: &[f32]) -> Result<Tensor, TranscriptionError> {
+        let mel_data = m::audio::pcm_to_mel(&self.config, samples, &self.mel_filters);
+        let n_mel = self.config.num_mel_bins;
+        let n_frames = mel_data.len() / n_mel;
+        let n_frames_clamped = n_frames.min(m::N_FRAMES);
+
+        let dtype = CandleWhisperEngine::select_dtype(&self.device);
+        Tensor::from_vec(mel_data, (1, n_mel, n_frames), &self.device)
+            .map_err(|e| TranscriptionError::TranscriptionFailed(format!("mel tensor: {}", e)))?
+            .narrow(2, 0, n_frames_clamped)
+            .map_err(|e| TranscriptionError::TranscriptionFailed(format!("mel narrow: {}", e)))?
+            .to_dtype(dtype)
+            .map_err(|e| TranscriptionError::TranscriptionFailed(format!("mel tensor cast: {}", e)))
+    }
+}
+
+/// One transcription on a blocking thread, with the channel back to its caller.
+pub(super) struct BlockingRun {
+    pub(super) whisper: Arc<LoadedWhisper>,
+    pub(super) options: TranscriptionOptions,
+    pub(super) aligner: Option<Arc<WordAligner>>,
+    pub(super) segments: mpsc::UnboundedSender<TranscriptSegment>,
+    pub(super) cancelled: Arc<AtomicBool>,
+}
+
+/// Tells the blocking thread to stop once the future awaiting it is gone, e.g. when the
+/// job is cancelled or loses its lease.
+pub(super) struct CancelOnDrop(pub(super) Arc<AtomicBool>);
+
+impl Drop for CancelOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+impl BlockingRun {
+    /// Transcribes the speech windows of `reader` one at a time, so at most one 30-second
+    /// window of PCM and its mel spectrogram are held in memory.
+    ///
+    /// Without a configured language, it is detected on the first window and kept for the
+    /// rest. With word timestamps, Whisper's timestamp tokens split each window into
+    /// phrases, and the aligner times their words.
+    pub(super) fn transcribe_pcm(
+        &self,
+        reader: &mut dyn PcmReader,
+    ) -> Result<Transcript, TranscriptionError> {
+        let options = VoiceActivityOptions {
+            max_window_samples: m::N_SAMPLES,
+            ..VoiceActivityOptions::default()
+        };
+        let timestamps = self.aligner.is_some();
+        let mut language = self.options.language.clone();
+
+        let whisper = &*self.whisper;
+        let mut model = whisper.model.lock().map_err(|_| {
+            TranscriptionError::TranscriptionFailed("model poisoned by an earlier panic".into())
+        })?;
+        let mut segments: Vec<TranscriptSegment> = Vec::new();
+
+        for window in SpeechWindows::new(reader, options) {
+            if self.cancelled.load(Ordering::Relaxed) {
+                return Err(TranscriptionError::TranscriptionFailed(
+                    "cancelled".to_string(),
+                ));
+            }
+            let mut window =
+                window.map_err(|e| TranscriptionError::DecodingFailed(e.to_string()))?;
+            let duration = window.end_time - window.start_time;
+            window.samples.resize(m::N_SAMPLES, 0.0);
+
+            let mel_tensor = whisper.mel_tensor(&window.samples)?;
+            let audio_features = model
+                .encoder
+                .forward(&mel_tensor, true)
+                .map_err(|e| TranscriptionError::TranscriptionFailed(format!("encoder: {}", e)))?;
+
+            if language.is_none() && !whisper.tokens.languages.is_empty() {
+                language = detect_language(
+                    &mut model,
+                    &whisper.device,
+                    &whisper.tokens,
+                    &audio_features,
+                )?
+                .map(str::to_string);
+                tracing::info!(language = language.as_deref(), "Detected spoken language");
+            }
+            let prompt =
+                whisper
+                    .tokens
+                    .prompt(language.as_deref(), self.options.translate, timestamps);
+            let phrases = decode_phrases(
+                &mut model,
+                &whisper.tokenizer,
+                &whisper.device,
+                &whisper.tokens,
+                &audio_features,
+                prompt,
+            )?;
+
+            let mut words = match &self.aligner {
+                Some(aligner) => aligner.time_words(
+                    whisper,
+                    &audio_features,
+                    &whisper
+                        .tokens
+                        .prompt(language.as_deref(), self.options.translate, false),
+                    &phrases,
+                    duration,
+                    language.as_deref(),
+                )?,
+                None => Vec::new(),
+            }
+            .into_iter();
+
+            tracing::debug!(
+                start_time = window.start_time,
+                end_time = window.end_time,
+                phrases = phrases.len(),
+                "Transcribed speech window"
+            );
+
+            for phrase in phrases {
+                let words: Vec<TranscriptWord> = words
+                    .next()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|word| {
+                        TranscriptWord::new(
+                            whisper.apply_corrections(&word.text),
+                            window.start_time + word.start_time,
+                            window.start_time + word.end_time,
+                        )
+                    })
+                    .collect();
+                let text = whisper.apply_corrections(&phrase.text);
+                if text.is_empty() {
+                    continue;
+                }
+                let start_time = window.start_time + phrase.start.min(duration);
+                let end_time = window.start_time + phrase.end.min(duration);
+                let segment = TranscriptSegment::new(text, start_time, end_time).with_words(words);
+                if self.segments.send(segment.clone()).is_err() {
+                    // The caller is gone; finishing the recording would be wasted work.
+                    return Err(TranscriptionError::TranscriptionFailed(
+                        "cancelled".to_string(),
+                    ));
+                }
+                segments.push(segment);
+            }
+        }
+
+        tracing::info!(
+            segment_count = segments.len(),
+            language = language.as_deref(),
+            "Audio transcription completed"
+        );
+
+        Ok(Transcript::new(segments).with_language(language))
+    }
+}
+
+pub(super) struct Unobserved;
+
+impl TranscriptionObserver for Unobserved {
+    fn segment_transcribed(&self, _segment: &TranscriptSegment) {}
+}
@@ -0,0 +1,100 @@
+use candle_core::Tensor;
+use candle_nn::{LayerNorm, Linear, Module, VarBuilder};
+
+/// Self- or cross-attention of one decoder layer.
+struct Attention {
+    query: Linear,
+    key: Linear,
+    value: Linear,
+    out: Linear,
+}
+
+impl Attention {
+    fn load(n_state: usize, vb: VarBuilder) -> candle_core::Result<Self> {
+        Ok(Self {
+            query: candle_nn::linear(n_state, n_state, vb.pp("q_proj"))?,
+            key: candle_nn::linear_no_bias(n_state, n_state, vb.pp("k_proj"))?,
+            value: candle_nn::linear(n_state, n_state, vb.pp("v_proj"))?,
+            out: candle_nn::linear(n_state, n_state, vb.pp("out_proj"))?,
+        })
+    }
+
+    /// Attends `x` to `source`, returning the output and the pre-softmax logits as
+    /// (batch, head, query, key), scaled the way Whisper scales them.
+    fn forward(
+        &self,
+        x: &Tensor,
+        source: &Tensor,
+        mask: Option<&Tensor>,
+        n_head: usize,
+    ) -> candle_core::Result<(Tensor, Tensor)> {
+        let split = |t: Tensor| -> candle_core::Result<Tensor> {
+            let (batch, len, n_state) = t.dims3()?;
+            t.reshape((batch, len, n_head, n_state / n_head))?
+                .transpose(1, 2)
+        };
+        let (_, _, n_state) = x.dims3()?;
+        let scale = ((n_state / n_head) as f64).powf(-0.25);
+        let q = (split(self.query.forward(x)?)? * scale)?;
+        let k = (split(self.key.forward(source)?)?.transpose(2, 3)? * scale)?;
+        let v = split(self.value.forward(source)?)?.contiguous()?;
+
+        let mut logits = q.matmul(&k)?;
+        if let Some(mask) = mask {
+            logits = logits.broadcast_add(mask)?;
+        }
+        let weights = candle_nn::ops::softmax_last_dim(&logits)?;
+        let output = weights.matmul(&v)?.transpose(1, 2)?.flatten_from(2)?;
+        Ok((self.out.forward(&output)?, logits))
+    }
+}
+
+pub(super) struct DecoderLayer {
+    self_attn: Attention,
+    self_attn_ln: LayerNorm,
+    cross_attn: Attention,
+    cross_attn_ln: LayerNorm,
+    fc1: Linear,
+    fc2: Linear,
+    final_ln: LayerNorm,
+}
+
+impl DecoderLayer {
+    pub(super) fn load(n_state: usize, vb: VarBuilder) -> candle_core::Result<Self> {
+        Ok(Self {
+            self_attn: Attention::load(n_state, vb.pp("self_attn"))?,
+            self_attn_ln: candle_nn::layer_norm(n_state, 1e-5, vb.pp("self_attn_layer_norm"))?,
+            cross_attn: Attention::load(n_state, vb.pp("encoder_attn"))?,
+            cross_attn_ln: candle_nn::layer_norm(n_state, 1e-5, vb.pp("encoder_attn_layer_norm"))?,
+            fc1: candle_nn::linear(n_state, n_state * 4, vb.pp("fc1"))?,
+            fc2: candle_nn::linear(n_state * 4, n_state, vb.pp("fc2"))?,
+            final_ln: candle_nn::layer_norm(n_state, 1e-5, vb.pp("final_layer_norm"))?,
+        })
+    }
+
+    /// The layer's output and its cross-attention logits.
+    pub(super) fn forward(
+        &self,
+        x: &Tensor,
+        audio_features: &Tensor,
+        mask: &Tensor,
+        n_head: usize,
+    ) -> candle_core::Result<(Tensor, Tensor)> {
+        let normed = self.self_attn_ln.forward(x)?;
+        let (attn, _) = self
+            .self_attn
+            .forward(&normed, &normed, Some(mask), n_head)?;
+        let x = (x + attn)?;
+        let (cross, logits) = self.cross_attn.forward(
+            &self.cross_attn_ln.forward(&x)?,
+            audio_features,
+            None,
+            n_head,
+        )?;
+        let x = (x + cross)?;
+        let mlp = self
+            .fc2
+            .forward(&self.fc1.forward(&self.final_ln.forward(&x)?)?.gelu()?)?;
+        Ok(((x + mlp)?, logits))
+    }
+}
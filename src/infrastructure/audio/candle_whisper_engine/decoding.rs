@@ -0,0 +1,228 @@
+use candle_core::{DType, Device, Tensor};
+use candle_transformers::models::whisper::{self as m, Config};
+use tokenizers::Tokenizer;
+
+use crate::application::ports::TranscriptionError;
+use crate::infrastructure::audio::whisper_language::WHISPER_LANGUAGES;
+
+/// Seconds between consecutive Whisper timestamp tokens.
+const TIMESTAMP_STEP: f32 = 0.02;
+const MAX_DECODED_TOKENS: usize = 224;
+
+/// Special tokens of the loaded tokenizer that make up the decoder prompt.
+pub(super) struct PromptTokens {
+    pub(super) sot: u32,
+    pub(super) transcribe: u32,
+    pub(super) translate: Option<u32>,
+    pub(super) no_timestamps: u32,
+    pub(super) eot: u32,
+    /// Language tokens by Whisper code; empty for English-only models.
+    pub(super) languages: Vec<(&'static str, u32)>,
+}
+
+impl PromptTokens {
+    pub(super) fn load(tokenizer: &Tokenizer) -> Result<Self, TranscriptionError> {
+        let required = |token: &str| {
+            tokenizer.token_to_id(token).ok_or_else(|| {
+                TranscriptionError::ModelLoadFailed(format!("token not found: {}", token))
+            })
+        };
+        Ok(Self {
+            sot: required(m::SOT_TOKEN)?,
+            transcribe: required(m::TRANSCRIBE_TOKEN)?,
+            translate: tokenizer.token_to_id(m::TRANSLATE_TOKEN),
+            no_timestamps: required(m::NO_TIMESTAMPS_TOKEN)?,
+            eot: required(m::EOT_TOKEN)?,
+            languages: WHISPER_LANGUAGES
+                .iter()
+                .filter_map(|(code, _)| {
+                    tokenizer
+                        .token_to_id(&format!("<|{code}|>"))
+                        .map(|id| (*code, id))
+                })
+                .collect(),
+        })
+    }
+
+    /// Timestamp tokens follow `<|notimestamps|>`, starting at `<|0.00|>`.
+    fn timestamp_begin(&self) -> u32 {
+        self.no_timestamps + 1
+    }
+
+    pub(super) fn prompt(
+        &self,
+        language: Option<&str>,
+        translate: bool,
+        timestamps: bool,
+    ) -> Vec<u32> {
+        let mut prompt = vec![self.sot];
+        if let Some(id) = language.and_then(|language| {
+            self.languages
+                .iter()
+                .find(|(code, _)| *code == language)
+                .map(|(_, id)| *id)
+        }) {
+            prompt.push(id);
+        }
+        match self.translate {
+            Some(translate_token) if translate && !self.languages.is_empty() => {
+                prompt.push(translate_token)
+            }
+            _ => prompt.push(self.transcribe),
+        }
+        if !timestamps {
+            prompt.push(self.no_timestamps);
+        }
+        prompt
+    }
+}
+
+/// Decoded text between two timestamps, in seconds from the start of the window. Without
+/// timestamps a window decodes to one phrase ending at infinity.
+pub(super) struct Phrase {
+    pub(super) text: String,
+    /// The text tokens the phrase was decoded from, for word alignment.
+    pub(super) tokens: Vec<u32>,
+    pub(super) start: f32,
+    pub(super) end: f32,
+}
+
+/// Picks the most likely language token after `<|startoftranscript|>`.
+pub(super) fn detect_language(
+    model: &mut m::model::Whisper,
+    device: &Device,
+    tokens: &PromptTokens,
+    audio_features: &Tensor,
+) -> Result<Option<&'static str>, TranscriptionError> {
+    let failed = |e: candle_core::Error| {
+        TranscriptionError::TranscriptionFailed(format!("language detection: {}", e))
+    };
+    let input = Tensor::new(&[tokens.sot], device)
+        .and_then(|t| t.unsqueeze(0))
+        .map_err(failed)?;
+    let output = model
+        .decoder
+        .forward(&input, audio_features, true)
+        .map_err(failed)?;
+    let logits: Vec<f32> = model
+        .decoder
+        .final_linear(&output)
+        .and_then(|l| l.squeeze(0))
+        .and_then(|l| l.get(0))
+        .and_then(|l| l.to_dtype(DType::F32))
+        .and_then(|l| l.to_vec1())
+        .map_err(failed)?;
+    model.reset_kv_cache();
+
+    Ok(tokens
+        .languages
+        .iter()
+        .max_by(|a, b| logits[a.1 as usize].total_cmp(&logits[b.1 as usize]))
+        .map(|(code, _)| *code))
+}
+
+/// Greedy decoding of one window, split into phrases at timestamp tokens.
+pub(super) fn decode_phrases(
+    model: &mut m::model::Whisper,
+    tokenizer: &Tokenizer,
+    device: &Device,
+    tokens: &PromptTokens,
+    audio_features: &Tensor,
+    prompt: Vec<u32>,
+) -> Result<Vec<Phrase>, TranscriptionError> {
+    let failed = |e: candle_core::Error| TranscriptionError::TranscriptionFailed(e.to_string());
+    let timestamp_begin = tokens.timestamp_begin();
+    let decode_text = |ids: &[u32]| {
+        tokenizer
+            .decode(ids, true)
+            .map(|text| text.trim().to_string())
+            .map_err(|e| TranscriptionError::TranscriptionFailed(format!("detokenize: {}", e)))
+    };
+
+    let mut sequence = prompt;
+    let mut phrases = Vec::new();
+    let mut phrase_tokens: Vec<u32> = Vec::new();
+    let mut phrase_start = 0.0f32;
+
+    for step in 0..MAX_DECODED_TOKENS {
+        let token_tensor = Tensor::new(sequence.as_slice(), device)
+            .and_then(|t| t.unsqueeze(0))
+            .map_err(failed)?;
+
+        let decoder_output = model
+            .decoder
+            .forward(&token_tensor, audio_features, step == 0)
+            .map_err(|e| TranscriptionError::TranscriptionFailed(format!("decoder: {}", e)))?;
+
+        let logits = model
+            .decoder
+            .final_linear(&decoder_output)
+            .map_err(|e| TranscriptionError::TranscriptionFailed(format!("linear: {}", e)))?;
+
+        // logits shape: [1, seq_len, vocab_size] — squeeze batch then get last token
+        let logits = logits.squeeze(0).map_err(failed)?;
+        let seq_len = logits.dim(0).map_err(failed)?;
+        let next_token = logits
+            .get(seq_len - 1)
+            .and_then(|l| l.argmax(0))
+            .and_then(|t| t.to_scalar::<u32>())
+            .map_err(failed)?;
+
+        if next_token == tokens.eot {
+            break;
+        }
+        sequence.push(next_token);
+
+        if next_token >= timestamp_begin {
+            let time = (next_token - timestamp_begin) as f32 * TIMESTAMP_STEP;
+            let text = decode_text(&phrase_tokens)?;
+            let tokens = std::mem::take(&mut phrase_tokens);
+            if !text.is_empty() {
+                phrases.push(Phrase {
+                    text,
+                    tokens,
+                    start: phrase_start,
+                    end: time.max(phrase_start),
+                });
+            }
+            phrase_start = time;
+        } else if next_token < tokens.eot {
+            phrase_tokens.push(next_token);
+        }
+    }
+
+    model.reset_kv_cache();
+
+    let text = decode_text(&phrase_tokens)?;
+    if !text.is_empty() {
+        phrases.push(Phrase {
+            text,
+            tokens: phrase_tokens,
+            start: phrase_start,
+            end: f32::INFINITY,
+        });
+    }
+    Ok(phrases)
+}
+
+pub(super) fn read_mel_filters(
+    bytes: &[u8],
+    config: &Config,
+) -> Result<Vec<f32>, TranscriptionError> {
+    let expected_len = config.num_mel_bins * (m::N_FFT / 2 + 1);
+    if bytes.len() < expected_len * 4 {
+        return Err(TranscriptionError::ModelLoadFailed(format!(
+            "mel filters file too small: {} bytes, expected at least {}",
+            bytes.len(),
+            expected_len * 4
+        )));
+    }
+
+    let filters: Vec<f32> = bytes
+        .chunks_exact(4)
+        .take(expected_len)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect();
+
+    Ok(filters)
+}
@@ -0,0 +1,233 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicBool;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use candle_core::{DType, Device};
+use candle_nn::VarBuilder;
+use candle_transformers::models::whisper::{self as m, Config};
+use hf_hub::api::sync::Api;
+use hf_hub::{Repo, RepoType};
+use tokenizers::Tokenizer;
+use tokio::sync::mpsc;
+
+use crate::application::ports::{
+    AudioDecoder, TranscriptionEngine, TranscriptionError, TranscriptionObserver,
+    TranscriptionOptions,
+};
+use crate::domain::Transcript;
+use crate::infrastructure::audio::voice_activity::PcmSliceReader;
+
+mod alignment;
+mod blocking_run;
+mod decoder_layer;
+mod decoding;
+mod word_timing;
+
+use alignment::WordAligner;
+use blocking_run::{BlockingRun, CancelOnDrop, Unobserved};
+use decoding::{PromptTokens, read_mel_filters};
+
+pub struct CandleWhisperEngine {
+    whisper: Arc<LoadedWhisper>,
+    options: TranscriptionOptions,
+    decoder: Arc<dyn AudioDecoder>,
+    weights_path: PathBuf,
+    /// Carries the model's alignment heads, when the repository has one.
+    generation_config_path: Option<PathBuf>,
+    /// Loaded with word timestamps on.
+    aligner: Option<Arc<WordAligner>>,
+}
+
+/// The loaded model and everything decoding needs, shared with the blocking threads that
+/// run transcriptions.
+struct LoadedWhisper {
+    model: Mutex<m::model::Whisper>,
+    tokenizer: Tokenizer,
+    tokens: PromptTokens,
+    config: Config,
+    device: Device,
+    mel_filters: Vec<f32>,
+    /// Lowercased-key map of ASR artifact → correct term, applied post-decode.
+    asr_corrections: Vec<(String, String)>,
+}
+
+impl CandleWhisperEngine {
+    pub fn new(
+        model_id: &str,
+        decoder: Arc<dyn AudioDecoder>,
+        asr_corrections: HashMap<String, String>,
+    ) -> Result<Self, TranscriptionError> {
+        let device = Device::new_metal(0).unwrap_or(Device::Cpu);
+
+        tracing::info!(
+            device = ?device,
+            model = model_id,
+            "Initializing Candle Whisper transcription engine"
+        );
+
+        let api = Api::new().map_err(|e| TranscriptionError::ModelLoadFailed(e.to_string()))?;
+        let repo = api.repo(Repo::new(model_id.to_string(), RepoType::Model));
+
+        let config_path = repo
+            .get("config.json")
+            .map_err(|e| TranscriptionError::ModelLoadFailed(format!("config.json: {}", e)))?;
+        let tokenizer_path = repo
+            .get("tokenizer.json")
+            .map_err(|e| TranscriptionError::ModelLoadFailed(format!("tokenizer.json: {}", e)))?;
+        let weights_path = repo.get("model.safetensors").map_err(|e| {
+            TranscriptionError::ModelLoadFailed(format!("model.safetensors: {}", e))
+        })?;
+        let generation_config_path = repo.get("generation_config.json").ok();
+
+        let mel_repo = api.repo(Repo::new(
+            "FL33TW00D-HF/whisper-base".to_string(),
+            RepoType::Model,
+        ));
+        let mel_bytes_path = mel_repo
+            .get("melfilters.bytes")
+            .map_err(|e| TranscriptionError::ModelLoadFailed(format!("melfilters.bytes: {}", e)))?;
+
+        let config_contents = std::fs::read_to_string(&config_path)
+            .map_err(|e| TranscriptionError::ModelLoadFailed(format!("read config: {}", e)))?;
+        let config: Config = serde_json::from_str(&config_contents)
+            .map_err(|e| TranscriptionError::ModelLoadFailed(format!("parse config: {}", e)))?;
+
+        let tokenizer = Tokenizer::from_file(&tokenizer_path)
+            .map_err(|e| TranscriptionError::ModelLoadFailed(format!("tokenizer: {}", e)))?;
+        let tokens = PromptTokens::load(&tokenizer)?;
+
+        let mel_bytes = std::fs::read(&mel_bytes_path)
+            .map_err(|e| TranscriptionError::ModelLoadFailed(format!("mel filters: {}", e)))?;
+        let mel_filters = read_mel_filters(&mel_bytes, &config)?;
+
+        let dtype = Self::select_dtype(&device);
+
+        // SAFETY: safetensors files are memory-mapped read-only
+        let vb = unsafe {
+            VarBuilder::from_mmaped_safetensors(&[&weights_path], dtype, &device)
+                .map_err(|e| TranscriptionError::ModelLoadFailed(format!("weights: {}", e)))?
+        };
+
+        let model = m::model::Whisper::load(&vb, config.clone())
+            .map_err(|e| TranscriptionError::ModelLoadFailed(format!("model: {}", e)))?;
+
+        tracing::info!("Candle Whisper engine loaded successfully");
+
+        // Sort longest artifact first so multi-word phrases match before their substrings.
+        let mut corrections: Vec<(String, String)> = asr_corrections
+            .into_iter()
+            .map(|(k, v)| (k.to_lowercase(), v))
+            .collect();
+        corrections.sort_by_key(|c| std::cmp::Reverse(c.0.len()));
+
+        Ok(Self {
+            whisper: Arc::new(LoadedWhisper {
+                model: Mutex::new(model),
+                tokenizer,
+                tokens,
+                config,
+                device,
+                mel_filters,
+                asr_corrections: corrections,
+            }),
+            options: TranscriptionOptions::default(),
+            decoder,
+            weights_path,
+            generation_config_path,
+            aligner: None,
+        })
+    }
+
+    /// Language, translation and word timing. Translation needs a multilingual model; an
+    /// English-only model transcribes as is. Word timing loads the decoder a second time to
+    /// read its cross-attention, and fails like [`Self::new`] when it cannot.
+    pub fn with_options(
+        mut self,
+        options: TranscriptionOptions,
+    ) -> Result<Self, TranscriptionError> {
+        if options.translate && self.whisper.tokens.languages.is_empty() {
+            tracing::warn!("English-only Whisper model cannot translate; transcribing instead");
+        }
+        self.aligner = if options.word_timestamps {
+            Some(Arc::new(WordAligner::load(
+                &self.weights_path,
+                self.generation_config_path.as_deref(),
+                &self.whisper.config,
+                Self::select_dtype(&self.whisper.device),
+                &self.whisper.device,
+            )?))
+        } else {
+            None
+        };
+        self.options = options;
+        Ok(self)
+    }
+
+    pub fn select_dtype(_device: &Device) -> DType {
+        DType::F32
+    }
+
+    /// Runs `work` on a blocking thread, since decoding audio and Whisper inference would
+    /// otherwise hold a runtime worker for the whole recording. Segments come back over a
+    /// channel as each window is decoded; dropping the returned future stops the thread
+    /// before its next window.
+    async fn run_blocking<F>(
+        &self,
+        observer: &dyn TranscriptionObserver,
+        work: F,
+    ) -> Result<Transcript, TranscriptionError>
+    where
+        F: FnOnce(&BlockingRun) -> Result<Transcript, TranscriptionError> + Send + 'static,
+    {
+        let (segments, mut transcribed) = mpsc::unbounded_channel();
+        let cancelled = CancelOnDrop(Arc::new(AtomicBool::new(false)));
+        let run = BlockingRun {
+            whisper: Arc::clone(&self.whisper),
+            options: self.options.clone(),
+            aligner: self.aligner.clone(),
+            segments,
+            cancelled: Arc::clone(&cancelled.0),
+        };
+        let handle = tokio::task::spawn_blocking(move || work(&run));
+
+        while let Some(segment) = transcribed.recv().await {
+            observer.segment_transcribed(&segment);
+        }
+        handle.await.map_err(|e| {
+            TranscriptionError::TranscriptionFailed(format!("transcription thread: {}", e))
+        })?
+    }
+}
+
+#[async_trait]
+impl TranscriptionEngine for CandleWhisperEngine {
+    async fn transcribe(&self, audio_data: &[u8]) -> Result<Transcript, TranscriptionError> {
+        let decoder = Arc::clone(&self.decoder);
+        let audio_data = audio_data.to_vec();
+        self.run_blocking(&Unobserved, move |run| {
+            let pcm = decoder
+                .decode(&audio_data)
+                .map_err(|e| TranscriptionError::DecodingFailed(e.to_string()))?;
+            run.transcribe_pcm(&mut PcmSliceReader::new(&pcm))
+        })
+        .await
+    }
+
+    async fn transcribe_file(
+        &self,
+        path: &Path,
+        observer: &dyn TranscriptionObserver,
+    ) -> Result<Transcript, TranscriptionError> {
+        let decoder = Arc::clone(&self.decoder);
+        let path = PathBuf::from(path);
+        self.run_blocking(observer, move |run| {
+            let mut reader = decoder
+                .open(&path)
+                .map_err(|e| TranscriptionError::DecodingFailed(e.to_string()))?;
+            run.transcribe_pcm(reader.as_mut())
+        })
+        .await
+    }
+}
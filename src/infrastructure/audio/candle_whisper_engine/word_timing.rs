@@ -0,0 +1,201 @@
+use tokenizers::Tokenizer;
+
+use crate::application::ports::TranscriptionError;
+
+/// Seconds per encoder frame: 1500 frames cover Whisper's 30-second window.
+pub(super) const SECONDS_PER_FRAME: f32 = 0.02;
+/// Width of the median filter smoothing the attention over frames.
+const MEDIAN_FILTER_WIDTH: usize = 7;
+/// Punctuation joined to the word after it.
+const LEADING_PUNCTUATION: &str = "\"'“¿([{-";
+/// Punctuation joined to the word before it.
+const TRAILING_PUNCTUATION: &str = "\"'.。,，!！?？:：”)]}、";
+/// Languages written without spaces, whose words are split per character instead.
+const UNSPACED_LANGUAGES: [&str; 6] = ["zh", "ja", "th", "lo", "my", "yue"];
+
+pub(super) fn splits_on_spaces(language: Option<&str>) -> bool {
+    !language.is_some_and(|language| UNSPACED_LANGUAGES.contains(&language))
+}
+
+/// The frame each token row starts at, from cross-attention logits as (head, token, frame).
+///
+/// Each head's attention is normalised per frame across tokens and smoothed over frames;
+/// dynamic time warping then finds the monotonic path through the heads' mean.
+pub(super) fn token_frames(logits: &[Vec<Vec<f32>>]) -> Vec<usize> {
+    let rows = logits.first().map_or(0, Vec::len);
+    let frames = logits
+        .first()
+        .and_then(|head| head.first())
+        .map_or(0, Vec::len);
+    let mut matrix = vec![vec![0.0f32; frames]; rows];
+
+    for head in logits {
+        let mut weights: Vec<Vec<f32>> = head.iter().map(|row| softmax(row)).collect();
+        for frame in 0..frames {
+            let mean = weights.iter().map(|row| row[frame]).sum::<f32>() / rows as f32;
+            let variance = weights
+                .iter()
+                .map(|row| (row[frame] - mean).powi(2))
+                .sum::<f32>()
+                / rows as f32;
+            let std = variance.sqrt().max(f32::EPSILON);
+            for row in &mut weights {
+                row[frame] = (row[frame] - mean) / std;
+            }
+        }
+        for (total, row) in matrix.iter_mut().zip(&weights) {
+            for (total, value) in total.iter_mut().zip(median_filter(row)) {
+                *total -= value / logits.len() as f32;
+            }
+        }
+    }
+
+    let path = dtw(&matrix);
+    let mut starts = Vec::with_capacity(rows);
+    for (i, &(row, frame)) in path.iter().enumerate() {
+        if i == 0 || path[i - 1].0 != row {
+            starts.push(frame);
+        }
+    }
+    starts
+}
+
+fn softmax(row: &[f32]) -> Vec<f32> {
+    let max = row.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let exp: Vec<f32> = row.iter().map(|v| (v - max).exp()).collect();
+    let sum: f32 = exp.iter().sum();
+    exp.into_iter().map(|v| v / sum).collect()
+}
+
+/// Median over a sliding window, reflecting the row at its edges.
+fn median_filter(row: &[f32]) -> Vec<f32> {
+    let pad = MEDIAN_FILTER_WIDTH / 2;
+    if row.len() <= pad {
+        return row.to_vec();
+    }
+    let reflect = |i: isize| -> f32 {
+        let last = row.len() as isize - 1;
+        let i = if i < 0 {
+            -i
+        } else if i > last {
+            2 * last - i
+        } else {
+            i
+        };
+        row[i as usize]
+    };
+    (0..row.len() as isize)
+        .map(|center| {
+            let mut window: Vec<f32> = (center - pad as isize..=center + pad as isize)
+                .map(reflect)
+                .collect();
+            window.sort_by(f32::total_cmp);
+            window[pad]
+        })
+        .collect()
+}
+
+/// Cheapest monotonic path from the first cell to the last, as (row, column) pairs.
+fn dtw(cost: &[Vec<f32>]) -> Vec<(usize, usize)> {
+    let rows = cost.len();
+    let columns = cost.first().map_or(0, Vec::len);
+    let mut total = vec![vec![f32::INFINITY; columns + 1]; rows + 1];
+    let mut trace = vec![vec![0u8; columns + 1]; rows + 1];
+    total[0][0] = 0.0;
+
+    for j in 1..=columns {
+        for i in 1..=rows {
+            let (diagonal, up, left) = (total[i - 1][j - 1], total[i - 1][j], total[i][j - 1]);
+            let (best, step) = if diagonal < up && diagonal < left {
+                (diagonal, 0)
+            } else if up < diagonal && up < left {
+                (up, 1)
+            } else {
+                (left, 2)
+            };
+            total[i][j] = cost[i - 1][j - 1] + best;
+            trace[i][j] = step;
+        }
+    }
+
+    let (mut i, mut j) = (rows, columns);
+    let mut path = Vec::with_capacity(rows + columns);
+    while i > 0 && j > 0 {
+        path.push((i - 1, j - 1));
+        match trace[i][j] {
+            0 => (i, j) = (i - 1, j - 1),
+            1 => i -= 1,
+            _ => j -= 1,
+        }
+    }
+    path.reverse();
+    path
+}
+
+/// Groups text tokens into words with their token counts. Tokens that decode to part of a
+/// character stay together; in spaced languages a word starts at a leading space, and
+/// punctuation is joined to the word it belongs to.
+pub(super) fn split_words(
+    tokenizer: &Tokenizer,
+    tokens: &[u32],
+    split_on_spaces: bool,
+) -> Result<Vec<(String, usize)>, TranscriptionError> {
+    let decode = |ids: &[u32]| {
+        tokenizer
+            .decode(ids, true)
+            .map_err(|e| TranscriptionError::TranscriptionFailed(format!("detokenize: {}", e)))
+    };
+
+    let mut pieces: Vec<(String, usize)> = Vec::new();
+    let mut pending = 0;
+    for (index, _) in tokens.iter().enumerate() {
+        pending += 1;
+        let text = decode(&tokens[index + 1 - pending..=index])?;
+        if !text.contains('\u{fffd}') || index + 1 == tokens.len() {
+            pieces.push((text, pending));
+            pending = 0;
+        }
+    }
+    if !split_on_spaces {
+        return Ok(pieces
+            .into_iter()
+            .map(|(piece, count)| (piece.trim().to_string(), count))
+            .collect());
+    }
+
+    let mut words: Vec<(String, usize)> = Vec::new();
+    let mut leading: Option<(String, usize)> = None;
+    for (text, count) in pieces {
+        let trimmed = text.trim();
+        let only = |set: &str| !trimmed.is_empty() && trimmed.chars().all(|c| set.contains(c));
+        let spaced = text.starts_with(' ');
+        if only(LEADING_PUNCTUATION) && (spaced || words.is_empty() || leading.is_some()) {
+            let (held, held_count) = leading.take().unwrap_or_default();
+            leading = Some((held + trimmed, held_count + count));
+            continue;
+        }
+        if leading.is_none()
+            && let Some(last) = words.last_mut()
+        {
+            if only(TRAILING_PUNCTUATION) {
+                last.0.push_str(trimmed);
+                last.1 += count;
+                continue;
+            }
+            if !spaced {
+                last.0.push_str(&text);
+                last.1 += count;
+                continue;
+            }
+        }
+        let (held, held_count) = leading.take().unwrap_or_default();
+        words.push((format!("{held}{}", text.trim_start()), held_count + count));
+    }
+    if let Some(held) = leading {
+        words.push(held);
+    }
+    Ok(words
+        .into_iter()
+        .map(|(word, count)| (word.trim().to_string(), count))
+        .collect())
+}
@@ -3,9 +3,13 @@ mod azure_whisper_engine;
 mod candle_whisper_engine;
 mod openai_whisper_engine;
 mod transcription_engine_factory;
+pub mod voice_activity;
+mod whisper_language;
 
 pub use audio_decoder::{FfmpegAudioDecoder, check_ffmpeg_binary};
 pub use azure_whisper_engine::AzureWhisperEngine;
 pub use candle_whisper_engine::CandleWhisperEngine;
 pub use openai_whisper_engine::OpenAiWhisperEngine;
 pub use transcription_engine_factory::{TranscriptionEngineFactory, TranscriptionProvider};
+pub use voice_activity::{PcmSliceReader, SpeechWindow, SpeechWindows, VoiceActivityOptions};
+pub use whisper_language::{WHISPER_LANGUAGES, whisper_language_code};
@@ -2,14 +2,16 @@ use async_trait::async_trait;
 use reqwest::multipart;
 use serde::Deserialize;
 
-use crate::application::ports::{TranscriptionEngine, TranscriptionError};
-use crate::domain::TranscriptSegment;
+use super::whisper_language::whisper_language_code;
+use crate::application::ports::{TranscriptionEngine, TranscriptionError, TranscriptionOptions};
+use crate::domain::{Transcript, TranscriptSegment, TranscriptWord};
 
 pub struct OpenAiWhisperEngine {
     client: reqwest::Client,
     api_key: String,
     base_url: String,
     model: String,
+    options: TranscriptionOptions,
 }
 
 impl OpenAiWhisperEngine {
@@ -19,8 +21,14 @@ impl OpenAiWhisperEngine {
             api_key,
             base_url: base_url.unwrap_or_else(|| "https://api.openai.com/v1".to_string()),
             model: model.unwrap_or_else(|| "whisper-1".to_string()),
+            options: TranscriptionOptions::default(),
         }
     }
+
+    pub fn with_options(mut self, options: TranscriptionOptions) -> Self {
+        self.options = options;
+        self
+    }
 }
 
 #[derive(Deserialize)]
@@ -30,30 +38,56 @@ struct WhisperSegment {
     text: String,
 }
 
+#[derive(Deserialize)]
+struct WhisperWord {
+    word: String,
+    start: f32,
+    end: f32,
+}
+
 #[derive(Deserialize)]
 struct VerboseJsonResponse {
     segments: Vec<WhisperSegment>,
+    /// Detected language by name, e.g. `"english"`.
+    #[serde(default)]
+    language: Option<String>,
+    /// Present when word timestamps were requested.
+    #[serde(default)]
+    words: Vec<WhisperWord>,
 }
 
 #[async_trait]
 impl TranscriptionEngine for OpenAiWhisperEngine {
-    async fn transcribe(
-        &self,
-        audio_data: &[u8],
-    ) -> Result<Vec<TranscriptSegment>, TranscriptionError> {
-        let url = format!("{}/audio/transcriptions", self.base_url);
+    async fn transcribe(&self, audio_data: &[u8]) -> Result<Transcript, TranscriptionError> {
+        // Translations take no language and time only segments.
+        let task = if self.options.translate {
+            "translations"
+        } else {
+            "transcriptions"
+        };
+        let url = format!("{}/audio/{}", self.base_url, task);
 
         let file_part = multipart::Part::bytes(audio_data.to_vec())
             .file_name("audio.wav")
             .mime_str("audio/wav")
             .map_err(|e| TranscriptionError::ApiRequestFailed(format!("mime: {}", e)))?;
 
-        let form = multipart::Form::new()
+        let mut form = multipart::Form::new()
             .text("model", self.model.clone())
-            .text("response_format", "verbose_json")
-            .part("file", file_part);
+            .text("response_format", "verbose_json");
+        if !self.options.translate {
+            if let Some(language) = &self.options.language {
+                form = form.text("language", language.clone());
+            }
+            if self.options.word_timestamps {
+                form = form
+                    .text("timestamp_granularities[]", "segment")
+                    .text("timestamp_granularities[]", "word");
+            }
+        }
+        let form = form.part("file", file_part);
 
-        tracing::debug!(model = %self.model, "Sending audio to OpenAI Whisper API");
+        tracing::debug!(model = %self.model, task, "Sending audio to OpenAI Whisper API");
 
         let response = self
             .client
@@ -81,17 +115,32 @@ impl TranscriptionEngine for OpenAiWhisperEngine {
             .await
             .map_err(|e| TranscriptionError::ApiRequestFailed(format!("parse response: {}", e)))?;
 
-        let segments: Vec<TranscriptSegment> = result
+        let mut segments: Vec<TranscriptSegment> = result
             .segments
             .into_iter()
             .map(|s| TranscriptSegment::new(s.text.trim().to_string(), s.start, s.end))
             .collect();
+        TranscriptSegment::attach_words(
+            &mut segments,
+            result
+                .words
+                .into_iter()
+                .map(|w| TranscriptWord::new(w.word.trim(), w.start, w.end))
+                .collect(),
+        );
+        let language = result
+            .language
+            .as_deref()
+            .and_then(whisper_language_code)
+            .map(str::to_string)
+            .or_else(|| self.options.language.clone());
 
         tracing::info!(
             segment_count = segments.len(),
+            language = language.as_deref(),
             "OpenAI Whisper transcription completed"
         );
 
-        Ok(segments)
+        Ok(Transcript::new(segments).with_language(language))
     }
 }
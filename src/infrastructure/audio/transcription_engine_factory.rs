@@ -1,11 +1,14 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::application::ports::{AudioDecoder, TranscriptionEngine, TranscriptionError};
+use crate::application::ports::{
+    AudioDecoder, TranscriptionEngine, TranscriptionError, TranscriptionOptions,
+};
 
 use super::azure_whisper_engine::AzureWhisperEngine;
 use super::candle_whisper_engine::CandleWhisperEngine;
 use super::openai_whisper_engine::OpenAiWhisperEngine;
+use super::whisper_language::whisper_language_code;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TranscriptionProvider {
@@ -27,7 +30,23 @@ impl TranscriptionEngineFactory {
         azure_api_version: Option<String>,
         decoder: Option<Arc<dyn AudioDecoder>>,
         asr_corrections: HashMap<String, String>,
+        mut options: TranscriptionOptions,
     ) -> Result<Arc<dyn TranscriptionEngine>, TranscriptionError> {
+        options.language = match options.language.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(language) if language.eq_ignore_ascii_case("auto") => None,
+            Some(language) => Some(
+                whisper_language_code(language)
+                    .ok_or_else(|| {
+                        TranscriptionError::ModelLoadFailed(format!(
+                            "unsupported transcription language: {}",
+                            language
+                        ))
+                    })?
+                    .to_string(),
+            ),
+        };
+
         match provider {
             TranscriptionProvider::Local => {
                 let decoder = decoder.ok_or_else(|| {
@@ -35,7 +54,8 @@ impl TranscriptionEngineFactory {
                         "AudioDecoder is required for the Local transcription provider".to_string(),
                     )
                 })?;
-                let engine = CandleWhisperEngine::new(model, decoder, asr_corrections)?;
+                let engine = CandleWhisperEngine::new(model, decoder, asr_corrections)?
+                    .with_options(options)?;
                 Ok(Arc::new(engine))
             }
             TranscriptionProvider::OpenAi => {
@@ -44,7 +64,8 @@ impl TranscriptionEngineFactory {
                         "API key required for OpenAI Whisper".to_string(),
                     )
                 })?;
-                let engine = OpenAiWhisperEngine::new(key, base_url, Some(model.to_string()));
+                let engine = OpenAiWhisperEngine::new(key, base_url, Some(model.to_string()))
+                    .with_options(options);
                 Ok(Arc::new(engine))
             }
             TranscriptionProvider::Azure => {
@@ -69,7 +90,8 @@ impl TranscriptionEngineFactory {
                     .as_deref()
                     .unwrap_or("2024-02-01")
                     .to_string();
-                let engine = AzureWhisperEngine::new(&base, &deployment, &key, &api_version);
+                let engine = AzureWhisperEngine::new(&base, &deployment, &key, &api_version)
+                    .with_options(options);
                 Ok(Arc::new(engine))
             }
         }
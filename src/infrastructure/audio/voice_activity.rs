@@ -0,0 +1,232 @@
+use std::collections::VecDeque;
+
+use crate::application::ports::{AudioDecoderError, PcmReader};
+
+/// Sample rate of the PCM every decoder produces.
+pub const SAMPLE_RATE: usize = 16_000;
+
+/// Tuning of the energy-based voice activity detection in [`SpeechWindows`].
+#[derive(Debug, Clone)]
+pub struct VoiceActivityOptions {
+    /// RMS level of a 30 ms frame under which it counts as silence.
+    pub silence_threshold: f32,
+    /// A pause at least this long (in seconds) closes the current window.
+    pub min_silence_secs: f32,
+    /// Silence (in seconds) kept before and after the speech of each window.
+    pub padding_secs: f32,
+    /// Windows with less speech than this (in seconds) are dropped as noise.
+    pub min_speech_secs: f32,
+    /// Longest window in samples. Speech running past it is cut at its latest short pause.
+    pub max_window_samples: usize,
+}
+
+impl Default for VoiceActivityOptions {
+    fn default() -> Self {
+        Self {
+            silence_threshold: 0.01,
+            min_silence_secs: 0.6,
+            padding_secs: 0.2,
+            min_speech_secs: 0.2,
+            max_window_samples: 30 * SAMPLE_RATE,
+        }
+    }
+}
+
+/// A stretch of speech and where it sits in the recording.
+#[derive(Debug, Clone)]
+pub struct SpeechWindow {
+    pub start_time: f32,
+    pub end_time: f32,
+    pub samples: Vec<f32>,
+}
+
+const FRAME_SAMPLES: usize = SAMPLE_RATE * 30 / 1000;
+/// Pauses at least this long are candidate cut points when a window runs full.
+const SHORT_PAUSE_SAMPLES: usize = SAMPLE_RATE * 150 / 1000;
+
+/// Cuts a [`PcmReader`] into speech windows, skipping silence.
+///
+/// Frames are classified by their RMS level. A window opens at the first speech frame and
+/// closes at the next pause of `min_silence_secs`, so window boundaries fall between
+/// utterances; a window reaching `max_window_samples` is cut at its latest short pause, or
+/// hard when there was none. Only the open window and a frame are held in memory, however
+/// long the recording.
+pub struct SpeechWindows<'a> {
+    reader: &'a mut dyn PcmReader,
+    options: VoiceActivityOptions,
+    frame: Vec<f32>,
+    /// Global index of the next sample to be read.
+    position: u64,
+    /// Global index of `window[0]`.
+    window_start: u64,
+    window: Vec<f32>,
+    speech_samples: usize,
+    trailing_silence: usize,
+    last_pause: Option<usize>,
+    /// Most recent silence, prepended to the next window as padding.
+    pre_roll: VecDeque<f32>,
+    ended: bool,
+}
+
+impl<'a> SpeechWindows<'a> {
+    pub fn new(reader: &'a mut dyn PcmReader, options: VoiceActivityOptions) -> Self {
+        Self {
+            reader,
+            options,
+            frame: vec![0.0; FRAME_SAMPLES],
+            position: 0,
+            window_start: 0,
+            window: Vec::new(),
+            speech_samples: 0,
+            trailing_silence: 0,
+            last_pause: None,
+            pre_roll: VecDeque::new(),
+            ended: false,
+        }
+    }
+
+    fn padding_samples(&self) -> usize {
+        (self.options.padding_secs * SAMPLE_RATE as f32) as usize
+    }
+
+    fn min_silence_samples(&self) -> usize {
+        (self.options.min_silence_secs * SAMPLE_RATE as f32) as usize
+    }
+
+    /// Takes `window[..len]` out as a finished window; the rest stays open.
+    fn take(&mut self, len: usize) -> Option<SpeechWindow> {
+        let rest = self.window.split_off(len);
+        let samples = std::mem::replace(&mut self.window, rest);
+        let start = self.window_start;
+        self.window_start += len as u64;
+        let speech_samples = std::mem::take(&mut self.speech_samples);
+        self.last_pause = None;
+
+        let min_speech = (self.options.min_speech_secs * SAMPLE_RATE as f32) as usize;
+        if speech_samples < min_speech {
+            return None;
+        }
+        Some(SpeechWindow {
+            start_time: start as f32 / SAMPLE_RATE as f32,
+            end_time: (start + samples.len() as u64) as f32 / SAMPLE_RATE as f32,
+            samples,
+        })
+    }
+
+    /// Closes the open window after its trailing silence, keeping `padding` of it.
+    fn close(&mut self) -> Option<SpeechWindow> {
+        let speech_end = self.window.len() - self.trailing_silence;
+        let end = (speech_end + self.padding_samples()).min(self.window.len());
+        let window = self.take(end);
+
+        let padding = self.padding_samples();
+        let tail = std::mem::take(&mut self.window);
+        self.pre_roll = tail[tail.len().saturating_sub(padding)..]
+            .iter()
+            .copied()
+            .collect();
+        self.trailing_silence = 0;
+        window
+    }
+
+    /// Feeds one frame; returns a window if it completed one.
+    fn push_frame(&mut self, len: usize) -> Option<SpeechWindow> {
+        let frame = &self.frame[..len];
+        let rms = (frame.iter().map(|s| s * s).sum::<f32>() / len as f32).sqrt();
+        let is_speech = rms >= self.options.silence_threshold;
+        let frame_start = self.position;
+        self.position += len as u64;
+
+        if self.window.is_empty() {
+            if !is_speech {
+                self.pre_roll.extend(frame.iter().copied());
+                let padding = self.padding_samples();
+                while self.pre_roll.len() > padding {
+                    self.pre_roll.pop_front();
+                }
+                return None;
+            }
+            self.window_start = frame_start - self.pre_roll.len() as u64;
+            self.window.extend(self.pre_roll.drain(..));
+            self.trailing_silence = 0;
+        }
+
+        self.window.extend_from_slice(&self.frame[..len]);
+        if is_speech {
+            self.speech_samples += len;
+            self.trailing_silence = 0;
+        } else {
+            self.trailing_silence += len;
+            if self.trailing_silence >= SHORT_PAUSE_SAMPLES {
+                self.last_pause = Some(self.window.len() - self.trailing_silence / 2);
+            }
+            if self.trailing_silence >= self.min_silence_samples() {
+                return self.close();
+            }
+        }
+
+        let max = self.options.max_window_samples;
+        if self.window.len() >= max {
+            let cut = self
+                .last_pause
+                .filter(|pause| *pause >= max / 2 && *pause <= max)
+                .unwrap_or(max);
+            // The carried-over audio is counted as speech so it is never dropped as noise.
+            let carried = self.window.len() - cut;
+            let window = self.take(cut);
+            self.speech_samples = carried;
+            self.trailing_silence = self.trailing_silence.min(carried);
+            return window;
+        }
+        None
+    }
+}
+
+impl Iterator for SpeechWindows<'_> {
+    type Item = Result<SpeechWindow, AudioDecoderError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.ended {
+            let len = match self.reader.read(&mut self.frame) {
+                Ok(len) => len,
+                Err(e) => {
+                    self.ended = true;
+                    return Some(Err(e));
+                }
+            };
+            if len == 0 {
+                self.ended = true;
+                if !self.window.is_empty() {
+                    if let Some(window) = self.close() {
+                        return Some(Ok(window));
+                    }
+                }
+                break;
+            }
+            if let Some(window) = self.push_frame(len) {
+                return Some(Ok(window));
+            }
+        }
+        None
+    }
+}
+
+/// Reads PCM already held in memory.
+pub struct PcmSliceReader<'a> {
+    samples: &'a [f32],
+}
+
+impl<'a> PcmSliceReader<'a> {
+    pub fn new(samples: &'a [f32]) -> Self {
+        Self { samples }
+    }
+}
+
+impl PcmReader for PcmSliceReader<'_> {
+    fn read(&mut self, buffer: &mut [f32]) -> Result<usize, AudioDecoderError> {
+        let len = buffer.len().min(self.samples.len());
+        buffer[..len].copy_from_slice(&self.samples[..len]);
+        self.samples = &self.samples[len..];
+        Ok(len)
+    }
+}
@@ -1,6 +1,7 @@
 mod embeder;
 mod mock_llm_client;
 mod openai_client;
+mod reranker;
 mod streaming_client;
 
 pub use embeder::LocalCandleEmbedder;
@@ -10,4 +11,5 @@ pub use embeder::{EmbedderFactory, EmbedderFactoryError};
 
 pub use mock_llm_client::MockLlmClient;
 pub use openai_client::OpenAiClient;
+pub use reranker::{HttpReranker, LocalCandleReranker, RerankerFactory, RerankerFactoryError};
 pub use streaming_client::{StreamingLlmClient, create_streaming_llm_client};
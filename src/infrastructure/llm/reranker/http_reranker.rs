@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::{Reranker, RerankerError};
+
+/// Client for the `/rerank` endpoint shared by LM Studio, llama.cpp server, vLLM, TEI,
+/// Infinity, Jina and Cohere-compatible gateways.
+pub struct HttpReranker {
+    client: Client,
+    endpoint: String,
+    api_key: Option<String>,
+    model: String,
+}
+
+#[derive(Serialize)]
+struct RerankRequest<'a> {
+    model: &'a str,
+    query: &'a str,
+    documents: &'a [&'a str],
+    top_n: usize,
+}
+
+#[derive(Deserialize)]
+struct RerankResponse {
+    results: Vec<RerankResult>,
+}
+
+#[derive(Deserialize)]
+struct RerankResult {
+    index: usize,
+    #[serde(alias = "score")]
+    relevance_score: f32,
+}
+
+impl HttpReranker {
+    /// `base_url` is the API root, e.g. `http://localhost:1234/v1`.
+    pub fn new(base_url: &str, api_key: Option<String>, model: String) -> Self {
+        Self {
+            client: Client::new(),
+            endpoint: format!("{}/rerank", base_url.trim_end_matches('/')),
+            api_key: api_key.filter(|k| !k.is_empty()),
+            model,
+        }
+    }
+}
+
+#[async_trait]
+impl Reranker for HttpReranker {
+    async fn rerank(&self, query: &str, documents: &[&str]) -> Result<Vec<f32>, RerankerError> {
+        if documents.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let body = RerankRequest {
+            model: &self.model,
+            query,
+            documents,
+            top_n: documents.len(),
+        };
+
+        let mut request = self.client.post(&self.endpoint).json(&body);
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| RerankerError::ApiRequestFailed(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(RerankerError::ApiRequestFailed(format!(
+                "HTTP {}: {}",
+                status, body
+            )));
+        }
+
+        let parsed: RerankResponse = response
+            .json()
+            .await
+            .map_err(|e| RerankerError::InvalidResponse(e.to_string()))?;
+
+        // Results come back sorted by relevance; restore input order.
+        let mut scores = vec![None; documents.len()];
+        for result in parsed.results {
+            let slot = scores.get_mut(result.index).ok_or_else(|| {
+                RerankerError::InvalidResponse(format!(
+                    "result index {} out of range",
+                    result.index
+                ))
+            })?;
+            *slot = Some(result.relevance_score);
+        }
+
+        scores
+            .into_iter()
+            .enumerate()
+            .map(|(i, score)| {
+                score.ok_or_else(|| {
+                    RerankerError::InvalidResponse(format!("no score for document {}", i))
+                })
+            })
+            .collect()
+    }
+}
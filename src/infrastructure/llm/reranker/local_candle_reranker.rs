@@ -0,0 +1,163 @@
+use async_trait::async_trait;
+use candle_core::{DType, Device, Tensor};
+use candle_nn::{Linear, Module, VarBuilder, linear};
+use candle_transformers::models::bert::{BertModel, Config as BertConfig};
+use hf_hub::api::sync::Api;
+use hf_hub::{Repo, RepoType};
+use tokenizers::Tokenizer;
+
+use crate::application::ports::{Reranker, RerankerError};
+
+/// Pairs scored per forward pass; bounds peak memory for long candidate lists.
+const BATCH_SIZE: usize = 16;
+
+/// BERT cross-encoder (`BertForSequenceClassification` with a single logit), e.g.
+/// `cross-encoder/ms-marco-MiniLM-L-6-v2`. Scores are sigmoid-squashed into `0..1`.
+pub struct LocalCandleReranker {
+    model: BertModel,
+    pooler: Linear,
+    classifier: Linear,
+    tokenizer: Tokenizer,
+    device: Device,
+}
+
+impl LocalCandleReranker {
+    pub fn new(model_id: &str) -> Result<Self, RerankerError> {
+        let device = Device::new_metal(0).unwrap_or(Device::Cpu);
+
+        tracing::info!(
+            device = ?device,
+            model = model_id,
+            "Initializing local Candle cross-encoder"
+        );
+
+        let api = Api::new().map_err(|e| RerankerError::ModelLoadFailed(e.to_string()))?;
+        let repo = api.repo(Repo::new(model_id.to_string(), RepoType::Model));
+
+        let config_path = repo
+            .get("config.json")
+            .map_err(|e| RerankerError::ModelLoadFailed(format!("config.json: {}", e)))?;
+        let tokenizer_path = repo
+            .get("tokenizer.json")
+            .map_err(|e| RerankerError::ModelLoadFailed(format!("tokenizer.json: {}", e)))?;
+        let weights_path = repo
+            .get("model.safetensors")
+            .map_err(|e| RerankerError::ModelLoadFailed(format!("model.safetensors: {}", e)))?;
+
+        let config_contents = std::fs::read_to_string(&config_path)
+            .map_err(|e| RerankerError::ModelLoadFailed(format!("read config: {}", e)))?;
+        let config: BertConfig = serde_json::from_str(&config_contents)
+            .map_err(|e| RerankerError::ModelLoadFailed(format!("parse config: {}", e)))?;
+
+        let mut tokenizer = Tokenizer::from_file(&tokenizer_path)
+            .map_err(|e| RerankerError::ModelLoadFailed(format!("tokenizer: {}", e)))?;
+        tokenizer
+            .with_truncation(Some(tokenizers::TruncationParams {
+                max_length: config.max_position_embeddings,
+                ..Default::default()
+            }))
+            .map_err(|e| RerankerError::ModelLoadFailed(format!("truncation config: {}", e)))?;
+
+        // F32 for the same reason as `LocalCandleEmbedder::select_dtype`.
+        // SAFETY: safetensors files are memory-mapped read-only
+        let vb = unsafe {
+            VarBuilder::from_mmaped_safetensors(&[weights_path], DType::F32, &device)
+                .map_err(|e| RerankerError::ModelLoadFailed(format!("weights: {}", e)))?
+        };
+
+        let model = BertModel::load(vb.pp("bert"), &config)
+            .map_err(|e| RerankerError::ModelLoadFailed(format!("model: {}", e)))?;
+        let pooler = linear(
+            config.hidden_size,
+            config.hidden_size,
+            vb.pp("bert.pooler.dense"),
+        )
+        .map_err(|e| RerankerError::ModelLoadFailed(format!("pooler: {}", e)))?;
+        let classifier = linear(config.hidden_size, 1, vb.pp("classifier"))
+            .map_err(|e| RerankerError::ModelLoadFailed(format!("classifier: {}", e)))?;
+
+        tracing::info!("Local Candle cross-encoder loaded successfully");
+
+        Ok(Self {
+            model,
+            pooler,
+            classifier,
+            tokenizer,
+            device,
+        })
+    }
+
+    fn score_batch(&self, query: &str, documents: &[&str]) -> Result<Vec<f32>, RerankerError> {
+        let pairs: Vec<(&str, &str)> = documents.iter().map(|d| (query, *d)).collect();
+        let encodings = self
+            .tokenizer
+            .encode_batch(pairs, true)
+            .map_err(|e| RerankerError::InferenceFailed(format!("tokenization: {}", e)))?;
+
+        let max_len = encodings
+            .iter()
+            .map(|e| e.get_ids().len())
+            .max()
+            .unwrap_or(0);
+
+        let mut all_input_ids = Vec::with_capacity(documents.len() * max_len);
+        let mut all_type_ids = Vec::with_capacity(documents.len() * max_len);
+        let mut all_attention_mask = Vec::with_capacity(documents.len() * max_len);
+
+        for encoding in &encodings {
+            let pad_len = max_len - encoding.get_ids().len();
+
+            all_input_ids.extend_from_slice(encoding.get_ids());
+            all_input_ids.extend(std::iter::repeat_n(0u32, pad_len));
+
+            all_type_ids.extend_from_slice(encoding.get_type_ids());
+            all_type_ids.extend(std::iter::repeat_n(0u32, pad_len));
+
+            all_attention_mask.extend_from_slice(encoding.get_attention_mask());
+            all_attention_mask.extend(std::iter::repeat_n(0u32, pad_len));
+        }
+
+        let shape = (documents.len(), max_len);
+        let input_ids = Tensor::from_vec(all_input_ids, shape, &self.device)
+            .map_err(|e| RerankerError::InferenceFailed(e.to_string()))?;
+        let token_type_ids = Tensor::from_vec(all_type_ids, shape, &self.device)
+            .map_err(|e| RerankerError::InferenceFailed(e.to_string()))?;
+        let attention_mask = Tensor::from_vec(all_attention_mask, shape, &self.device)
+            .map_err(|e| RerankerError::InferenceFailed(e.to_string()))?;
+
+        // [CLS] hidden state → pooler (dense + tanh) → single relevance logit.
+        let logits = self
+            .model
+            .forward(&input_ids, &token_type_ids, Some(&attention_mask))
+            .and_then(|hidden| hidden.narrow(1, 0, 1)?.squeeze(1))
+            .and_then(|cls| self.pooler.forward(&cls)?.tanh())
+            .and_then(|pooled| self.classifier.forward(&pooled))
+            .and_then(|logits| logits.squeeze(1)?.to_dtype(DType::F32))
+            .map_err(|e| RerankerError::InferenceFailed(e.to_string()))?;
+
+        let logits: Vec<f32> = logits
+            .to_vec1()
+            .map_err(|e| RerankerError::InferenceFailed(e.to_string()))?;
+
+        Ok(logits.into_iter().map(sigmoid).collect())
+    }
+}
+
+fn sigmoid(x: f32) -> f32 {
+    if x.is_finite() {
+        1.0 / (1.0 + (-x).exp())
+    } else {
+        0.0
+    }
+}
+
+#[async_trait]
+impl Reranker for LocalCandleReranker {
+    async fn rerank(&self, query: &str, documents: &[&str]) -> Result<Vec<f32>, RerankerError> {
+        let mut scores = Vec::with_capacity(documents.len());
+        for batch in documents.chunks(BATCH_SIZE) {
+            scores.extend(self.score_batch(query, batch)?);
+        }
+        Ok(scores)
+    }
+}
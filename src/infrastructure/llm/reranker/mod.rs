@@ -0,0 +1,7 @@
+pub mod http_reranker;
+pub mod local_candle_reranker;
+pub mod reranker_factory;
+
+pub use http_reranker::HttpReranker;
+pub use local_candle_reranker::LocalCandleReranker;
+pub use reranker_factory::{RerankerFactory, RerankerFactoryError};
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::application::ports::Reranker;
+use crate::presentation::config::RerankProvider;
+
+use super::{HttpReranker, LocalCandleReranker};
+
+pub struct RerankerFactory;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RerankerFactoryError {
+    #[error("missing base URL: HTTP reranker requires rerank.base_url")]
+    MissingBaseUrl,
+    #[error("model initialization failed: {0}")]
+    InitializationFailed(String),
+}
+
+impl RerankerFactory {
+    pub fn create(
+        provider: RerankProvider,
+        model: String,
+        base_url: Option<String>,
+        api_key: Option<String>,
+    ) -> Result<Arc<dyn Reranker>, RerankerFactoryError> {
+        match provider {
+            RerankProvider::Local => {
+                tracing::info!(model = %model, "Loading local Candle reranker");
+                let reranker = LocalCandleReranker::new(&model)
+                    .map_err(|e| RerankerFactoryError::InitializationFailed(e.to_string()))?;
+                Ok(Arc::new(reranker))
+            }
+            RerankProvider::Http => {
+                let base_url = base_url
+                    .filter(|u| !u.is_empty())
+                    .ok_or(RerankerFactoryError::MissingBaseUrl)?;
+                tracing::info!(model = %model, base_url = %base_url, "Using HTTP reranker");
+                Ok(Arc::new(HttpReranker::new(&base_url, api_key, model)))
+            }
+        }
+    }
+}
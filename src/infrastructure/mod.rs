@@ -3,6 +3,9 @@ pub mod llm;
 pub mod mcp;
 pub mod observability;
 pub mod persistence;
+pub mod source_tree;
 pub mod storage;
 pub mod text_processing;
 pub mod tools;
+pub mod web;
+pub mod webhooks;
@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use crate::application::ports::EvalEventError;
+use crate::domain::EvalEntry;
+
+/// Reads a golden dataset (one `EvalEntry` JSON object per line) for offline evaluation.
+///
+/// Blank lines are skipped; a malformed line fails the whole load with its 1-based line
+/// number so a broken dataset never silently shrinks the run.
+pub async fn load_ground_truth(path: &Path) -> Result<Vec<EvalEntry>, EvalEventError> {
+    let content = tokio::fs::read_to_string(path).await?;
+    parse_ground_truth(&content)
+}
+
+pub fn parse_ground_truth(content: &str) -> Result<Vec<EvalEntry>, EvalEventError> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str::<EvalEntry>(line.trim())
+                .map_err(|e| EvalEventError::Serialization(format!("line {}: {}", index + 1, e)))
+        })
+        .collect()
+}
@@ -3,7 +3,11 @@
 //!   record() creates parent dirs and appends one JSON line. list(limit) reads all lines,
 //!   sample(n) uses Fisher-Yates partial shuffle with a time-seeded rng. Returns empty vec
 //!   (not error) when the file does not yet exist. Useful for offline/CLI evaluation runs.
+//! - ground_truth_loader -> Parses the golden dataset JSONL (`collections/eval/ground_truth.jsonl`)
+//!   into `EvalEntry` rows for the offline `EvalRunner`. Fails with the line number on bad JSON.
 
+mod ground_truth_loader;
 mod jsonl_eval_event_repository;
 
+pub use ground_truth_loader::{load_ground_truth, parse_ground_truth};
 pub use jsonl_eval_event_repository::JsonlEvalEventRepository;
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+use crate::application::ports::JobEventBus;
+use crate::domain::JobEvent;
+
+/// Events buffered per subscriber before a slow one starts missing them.
+const DEFAULT_CAPACITY: usize = 256;
+
+pub struct BroadcastJobEventBus {
+    sender: broadcast::Sender<JobEvent>,
+}
+
+impl BroadcastJobEventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity.max(1));
+        Self { sender }
+    }
+
+    pub(super) fn send(&self, event: JobEvent) {
+        // An error only means nobody is subscribed right now.
+        let _ = self.sender.send(event);
+    }
+}
+
+impl Default for BroadcastJobEventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+#[async_trait]
+impl JobEventBus for BroadcastJobEventBus {
+    async fn publish(&self, event: &JobEvent) {
+        self.send(event.clone());
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<JobEvent> {
+        self.sender.subscribe()
+    }
+}
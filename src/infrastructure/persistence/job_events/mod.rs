@@ -0,0 +1,11 @@
+//! JobEventBus adapters.
+//! - broadcast_job_event_bus -> In-process tokio broadcast; events reach only subscribers
+//!   in the publishing process. Used in tests and as the fan-out behind the Postgres bus.
+//! - pg_job_event_bus        -> Postgres LISTEN/NOTIFY, so events published by worker
+//!   processes reach SSE subscribers of every API server on the same database.
+
+mod broadcast_job_event_bus;
+mod pg_job_event_bus;
+
+pub use broadcast_job_event_bus::BroadcastJobEventBus;
+pub use pg_job_event_bus::PgJobEventBus;
@@ -0,0 +1,218 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use sqlx::postgres::PgListener;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use super::BroadcastJobEventBus;
+use crate::application::ports::JobEventBus;
+use crate::domain::{JobEvent, JobEventKind, JobId, JobProgress, JobStatus, TenantId};
+
+const CHANNEL: &str = "job_events";
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+/// Bytes of a failure message carried in a notification. Postgres rejects payloads of
+/// 8000 bytes or more; even if JSON escaping grows every byte sixfold, a message of this
+/// size leaves room for the rest of the event. The full message stays on the job row.
+const MAX_ERROR_MESSAGE_BYTES: usize = 1000;
+
+/// Publishes with `pg_notify` and relays notifications from a single `LISTEN` connection to
+/// local subscribers. Notifications sent while the listener is reconnecting are lost.
+pub struct PgJobEventBus {
+    pool: PgPool,
+    local: Arc<BroadcastJobEventBus>,
+}
+
+impl PgJobEventBus {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            local: Arc::new(BroadcastJobEventBus::default()),
+        }
+    }
+
+    /// Starts relaying notifications to [`JobEventBus::subscribe`]rs. Processes that only
+    /// publish, such as standalone workers, need not call it.
+    pub fn spawn_listener(&self) -> tokio::task::JoinHandle<()> {
+        let pool = self.pool.clone();
+        let local = Arc::clone(&self.local);
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = relay(&pool, &local).await {
+                    tracing::warn!(error = %e, "Job event listener disconnected; reconnecting");
+                }
+                tokio::time::sleep(RECONNECT_DELAY).await;
+            }
+        })
+    }
+}
+
+async fn relay(pool: &PgPool, local: &BroadcastJobEventBus) -> Result<(), sqlx::Error> {
+    let mut listener = PgListener::connect_with(pool).await?;
+    listener.listen(CHANNEL).await?;
+    tracing::info!(channel = CHANNEL, "Listening for job events");
+
+    loop {
+        let notification = listener.recv().await?;
+        match serde_json::from_str::<JobEventJson>(notification.payload())
+            .map_err(|e| e.to_string())
+            .and_then(JobEvent::try_from)
+        {
+            Ok(event) => local.send(event),
+            Err(e) => tracing::warn!(error = %e, "Ignoring malformed job event"),
+        }
+    }
+}
+
+#[async_trait]
+impl JobEventBus for PgJobEventBus {
+    async fn publish(&self, event: &JobEvent) {
+        let payload = match serde_json::to_string(&JobEventJson::from(event)) {
+            Ok(payload) => payload,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to serialize job event");
+                return;
+            }
+        };
+
+        if let Err(e) = sqlx::query_scalar!(
+            r#"SELECT 1 AS "sent!" FROM pg_notify($1, $2)"#,
+            CHANNEL,
+            payload
+        )
+        .fetch_one(&self.pool)
+        .await
+        {
+            tracing::warn!(error = %e, "Failed to publish job event");
+        }
+    }
+
+    fn subscribe(&self) -> broadcast::Receiver<JobEvent> {
+        self.local.subscribe()
+    }
+}
+
+/// NOTIFY payload shape of [`JobEvent`].
+#[derive(Serialize, Deserialize)]
+struct JobEventJson {
+    job_id: Uuid,
+    tenant_id: String,
+    occurred_at: DateTime<Utc>,
+    #[serde(flatten)]
+    kind: KindJson,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum KindJson {
+    Status {
+        status: String,
+        error_message: Option<String>,
+    },
+    Progress {
+        pages_processed: u32,
+        segments_transcribed: u32,
+        chunks_total: u32,
+        chunks_embedded: u32,
+        #[serde(default)]
+        urls_ingested: u32,
+        #[serde(default)]
+        files_ingested: u32,
+        #[serde(default)]
+        files_unchanged: u32,
+        #[serde(default)]
+        files_removed: u32,
+    },
+}
+
+impl From<&JobEvent> for JobEventJson {
+    fn from(event: &JobEvent) -> Self {
+        let kind = match &event.kind {
+            JobEventKind::StatusChanged {
+                status,
+                error_message,
+            } => KindJson::Status {
+                status: status.as_str().to_string(),
+                error_message: error_message.as_deref().map(truncate_error_message),
+            },
+            JobEventKind::Progress(progress) => KindJson::Progress {
+                pages_processed: progress.pages_processed,
+                segments_transcribed: progress.segments_transcribed,
+                chunks_total: progress.chunks_total,
+                chunks_embedded: progress.chunks_embedded,
+                urls_ingested: progress.urls_ingested,
+                files_ingested: progress.files_ingested,
+                files_unchanged: progress.files_unchanged,
+                files_removed: progress.files_removed,
+            },
+        };
+        Self {
+            job_id: event.job_id.as_uuid(),
+            tenant_id: event.tenant_id.as_str().to_string(),
+            occurred_at: event.occurred_at,
+            kind,
+        }
+    }
+}
+
+impl TryFrom<JobEventJson> for JobEvent {
+    type Error = String;
+
+    fn try_from(json: JobEventJson) -> Result<Self, Self::Error> {
+        let tenant_id = TenantId::parse(&json.tenant_id)
+            .ok_or_else(|| format!("invalid tenant id: {}", json.tenant_id))?;
+        let kind = match json.kind {
+            KindJson::Status {
+                status,
+                error_message,
+            } => JobEventKind::StatusChanged {
+                status: status.parse::<JobStatus>()?,
+                error_message,
+            },
+            KindJson::Progress {
+                pages_processed,
+                segments_transcribed,
+                chunks_total,
+                chunks_embedded,
+                urls_ingested,
+                files_ingested,
+                files_unchanged,
+                files_removed,
+            } => JobEventKind::Progress(JobProgress {
+                pages_processed,
+                segments_transcribed,
+                chunks_total,
+                chunks_embedded,
+                urls_ingested,
+                files_ingested,
+                files_unchanged,
+                files_removed,
+            }),
+        };
+        Ok(Self {
+            job_id: JobId::from_uuid(json.job_id),
+            tenant_id,
+            kind,
+            occurred_at: json.occurred_at,
+        })
+    }
+}
+
+/// `message` cut to [`MAX_ERROR_MESSAGE_BYTES`] at a character boundary, marked with `…`
+/// when cut.
+fn truncate_error_message(message: &str) -> String {
+    if message.len() <= MAX_ERROR_MESSAGE_BYTES {
+        return message.to_string();
+    }
+    let boundary = message
+        .char_indices()
+        .map(|(pos, ch)| pos + ch.len_utf8())
+        .take_while(|&end| end <= MAX_ERROR_MESSAGE_BYTES)
+        .last()
+        .unwrap_or(0);
+    format!("{}…", &message[..boundary])
+}
@@ -1,20 +1,30 @@
 mod eval_event;
+mod job_events;
 mod pg_pool;
 mod repositories;
 mod vector_store;
 
-pub use eval_event::JsonlEvalEventRepository;
+pub use eval_event::{JsonlEvalEventRepository, load_ground_truth, parse_ground_truth};
+pub use job_events::{BroadcastJobEventBus, PgJobEventBus};
 
+pub use repositories::MockApiKeyRepository;
 pub use repositories::MockConversationRepository;
+pub use repositories::MockDocumentRepository;
 pub use repositories::MockEvalEventRepository;
 pub use repositories::MockEvalOutboxRepository;
 pub use repositories::MockEvalResultRepository;
 pub use repositories::MockJobRepository;
+pub use repositories::MockTranscriptRepository;
+pub use repositories::MockWebhookRepository;
+pub use repositories::PgApiKeyRepository;
 pub use repositories::PgConversationRepository;
+pub use repositories::PgDocumentRepository;
 pub use repositories::PgEvalEventRepository;
 pub use repositories::PgEvalOutboxRepository;
 pub use repositories::PgEvalResultRepository;
 pub use repositories::PgJobRepository;
+pub use repositories::PgTranscriptRepository;
+pub use repositories::PgWebhookRepository;
 
 pub use pg_pool::create_pool;
 
@@ -1,12 +1,18 @@
+use std::time::Duration;
+
 use uuid::Uuid;
 
 use crate::application::ports::{
-    ConversationRepository, EvalEventError, EvalEventRepository, EvalOutboxError,
-    EvalOutboxRepository, EvalResultError, EvalResultRepository, JobRepository, RepositoryError,
+    ApiKeyRepository, ConversationRepository, DocumentRepository, EvalEventError,
+    EvalEventRepository, EvalOutboxError, EvalOutboxRepository, EvalResultError,
+    EvalResultRepository, JobFilter, JobRepository, LeaseRenewal, RepositoryError,
+    TranscriptRepository, WebhookRepository,
 };
 use crate::domain::{
-    Conversation, ConversationId, EvalEvent, EvalEventId, EvalOutboxEntry, EvalResult, Job, JobId,
-    JobStatus, Message,
+    ApiKey, ApiKeyId, ClaimedWebhookDelivery, Conversation, ConversationId, DocumentId,
+    DocumentRecord, EvalEvent, EvalEventId, EvalOutboxEntry, EvalResult, FetchFailure, Job, JobId,
+    JobProgress, JobStatus, Message, TenantId, Transcript, WebhookDelivery, WebhookEvent,
+    WebhookSubscription, WebhookSubscriptionId,
 };
 
 pub struct MockConversationRepository;
@@ -27,6 +33,13 @@ impl ConversationRepository for MockConversationRepository {
         Ok(None)
     }
 
+    async fn get_conversation_tenant(
+        &self,
+        _id: ConversationId,
+    ) -> Result<Option<TenantId>, RepositoryError> {
+        Ok(None)
+    }
+
     async fn append_message(&self, _message: &Message) -> Result<(), RepositoryError> {
         Ok(())
     }
@@ -61,9 +74,154 @@ impl JobRepository for MockJobRepository {
         Ok(())
     }
 
+    async fn update_leased_status(
+        &self,
+        _id: JobId,
+        _worker_id: &str,
+        _status: JobStatus,
+        _error_message: Option<&str>,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
     async fn list_by_status(&self, _status: JobStatus) -> Result<Vec<Job>, RepositoryError> {
         Ok(vec![])
     }
+
+    async fn list(
+        &self,
+        _filter: &JobFilter,
+        _limit: usize,
+        _offset: usize,
+    ) -> Result<Vec<Job>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn find_latest_by_document(
+        &self,
+        _document_id: DocumentId,
+    ) -> Result<Option<Job>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn claim_next(
+        &self,
+        _worker_id: &str,
+        _lease: Duration,
+    ) -> Result<Option<Job>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn renew_lease(
+        &self,
+        _id: JobId,
+        _worker_id: &str,
+        _lease: Duration,
+    ) -> Result<LeaseRenewal, RepositoryError> {
+        Ok(LeaseRenewal::Lost)
+    }
+
+    async fn release_expired(&self, _id: JobId) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    async fn schedule_retry(
+        &self,
+        _id: JobId,
+        _worker_id: &str,
+        _delay: Duration,
+        _error_message: &str,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    async fn update_progress(
+        &self,
+        _id: JobId,
+        _worker_id: &str,
+        _progress: &JobProgress,
+    ) -> Result<bool, RepositoryError> {
+        Ok(true)
+    }
+
+    async fn record_fetch_failure(
+        &self,
+        _id: JobId,
+        _failure: &FetchFailure,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn request_cancel(&self, _id: JobId) -> Result<Option<JobStatus>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn retry(&self, _id: JobId) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+}
+
+pub struct MockDocumentRepository;
+
+#[async_trait::async_trait]
+impl DocumentRepository for MockDocumentRepository {
+    async fn create(&self, _document: &DocumentRecord) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn get_by_id(&self, _id: DocumentId) -> Result<Option<DocumentRecord>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn find_by_content_hash(
+        &self,
+        _tenant_id: &TenantId,
+        _content_hash: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn find_by_filename(
+        &self,
+        _tenant_id: &TenantId,
+        _filename: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn update_source(&self, _document: &DocumentRecord) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        _tenant_id: &TenantId,
+        _limit: usize,
+        _offset: usize,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn list_by_filename_prefix(
+        &self,
+        _tenant_id: &TenantId,
+        _prefix: &str,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn mark_ingested(
+        &self,
+        _id: DocumentId,
+        _chunk_count: usize,
+        _content_hash: &str,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn delete(&self, _id: DocumentId) -> Result<(), RepositoryError> {
+        Ok(())
+    }
 }
 
 pub struct MockEvalEventRepository;
@@ -119,3 +277,132 @@ impl EvalResultRepository for MockEvalResultRepository {
         Ok(())
     }
 }
+
+pub struct MockApiKeyRepository;
+
+#[async_trait::async_trait]
+impl ApiKeyRepository for MockApiKeyRepository {
+    async fn create(&self, _key: &ApiKey) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn find_active_by_hash(
+        &self,
+        _key_hash: &str,
+    ) -> Result<Option<ApiKey>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn list(&self, _tenant_id: Option<&TenantId>) -> Result<Vec<ApiKey>, RepositoryError> {
+        Ok(Vec::new())
+    }
+
+    async fn revoke(
+        &self,
+        _id: ApiKeyId,
+        _tenant_id: Option<&TenantId>,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+}
+
+pub struct MockWebhookRepository;
+
+#[async_trait::async_trait]
+impl WebhookRepository for MockWebhookRepository {
+    async fn create_subscription(
+        &self,
+        _subscription: &WebhookSubscription,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn list_subscriptions(
+        &self,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_subscription(
+        &self,
+        _id: WebhookSubscriptionId,
+    ) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn delete_subscription(
+        &self,
+        _id: WebhookSubscriptionId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    async fn enqueue(&self, _event: &WebhookEvent) -> Result<usize, RepositoryError> {
+        Ok(0)
+    }
+
+    async fn claim_due(
+        &self,
+        _batch_size: usize,
+        _lease: Duration,
+    ) -> Result<Vec<ClaimedWebhookDelivery>, RepositoryError> {
+        Ok(Vec::new())
+    }
+
+    async fn mark_delivered(
+        &self,
+        _id: Uuid,
+        _response_status: u16,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn schedule_retry(
+        &self,
+        _id: Uuid,
+        _delay: Duration,
+        _error: &str,
+        _response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        _id: Uuid,
+        _error: &str,
+        _response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn list_deliveries(
+        &self,
+        _subscription_id: WebhookSubscriptionId,
+        _limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        Ok(Vec::new())
+    }
+}
+
+pub struct MockTranscriptRepository;
+
+#[async_trait::async_trait]
+impl TranscriptRepository for MockTranscriptRepository {
+    async fn save(
+        &self,
+        _document_id: DocumentId,
+        _transcript: &Transcript,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn get(&self, _document_id: DocumentId) -> Result<Option<Transcript>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn delete(&self, _document_id: DocumentId) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
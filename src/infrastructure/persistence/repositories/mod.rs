@@ -1,9 +1,15 @@
 //! @AI: repositories module routing map
-//! - mock_repository            -> In-memory stubs: MockConversationRepository, MockJobRepository,
-//!   MockEvalEventRepository, MockEvalOutboxRepository, MockEvalResultRepository.
+//! - mock_repository            -> In-memory stubs: MockApiKeyRepository,
+//!   MockConversationRepository, MockJobRepository, MockDocumentRepository,
+//!   MockEvalEventRepository, MockEvalOutboxRepository,
+//!   MockEvalResultRepository, MockTranscriptRepository, MockWebhookRepository.
 //!   Used in offline unit/integration tests only.
+//! - pg_api_key_repository      -> PostgreSQL adapter for ApiKeyRepository port.
+//!   Stores SHA-256 key hashes only; revocation sets revoked_at (rows are never deleted).
 //! - pg_conversation_repository -> PostgreSQL adapter for ConversationRepository port.
 //!   Persists conversations and messages; get_messages returns oldest-first.
+//! - pg_document_repository     -> PostgreSQL adapter for DocumentRepository port.
+//!   Document catalog (one row per DocumentId); chunk_count/content_hash set on completion.
 //! - pg_eval_event_repository   -> PostgreSQL adapter for EvalEventRepository port.
 //!   Stores eval events as rows with JSONB retrieved_sources. sample() uses ORDER BY RANDOM().
 //! - pg_eval_outbox_repository  -> PostgreSQL adapter for EvalOutboxRepository port.
@@ -13,22 +19,53 @@
 //!   INSERT with ON CONFLICT (eval_event_id) DO NOTHING for idempotent worker retry safety.
 //!   UNIQUE(eval_event_id) enforces one result per event.
 //! - pg_job_repository          -> PostgreSQL adapter for JobRepository port.
-//!   Tracks ingestion job lifecycle (QUEUED → PROCESSING → DONE/FAILED).
+//!   Tracks ingestion job lifecycle (QUEUED → PROCESSING → DONE/FAILED) and is the durable
+//!   ingestion queue: JSONB payload, claim_next with FOR UPDATE SKIP LOCKED, renewable
+//!   leases, release_expired for jobs whose worker died.
+//! - pg_transcript_repository   -> PostgreSQL adapter for TranscriptRepository port.
+//!   One JSONB row of segments per document, removed with the document by ON DELETE CASCADE.
+//! - pg_webhook_repository      -> PostgreSQL adapter for WebhookRepository port.
+//!   Subscriptions plus the webhook_deliveries outbox: enqueue fans an event out with one
+//!   INSERT ... SELECT, claim_due pushes next_attempt_at out by a lease instead of flipping
+//!   status, so a dispatcher crash only delays a delivery. Rows double as the delivery log.
+//!
+//! Documents, jobs, conversations and eval events carry a `tenant_id` column; rows written
+//! before tenancy existed default to the `default` tenant.
 
 mod mock_repository;
+mod pg_api_key_repository;
 mod pg_conversation_repository;
+mod pg_document_repository;
 mod pg_eval_event_repository;
 mod pg_eval_outbox_repository;
 mod pg_eval_result_repository;
 mod pg_job_repository;
+mod pg_transcript_repository;
+mod pg_webhook_repository;
 
+pub use mock_repository::MockApiKeyRepository;
 pub use mock_repository::MockConversationRepository;
+pub use mock_repository::MockDocumentRepository;
 pub use mock_repository::MockEvalEventRepository;
 pub use mock_repository::MockEvalOutboxRepository;
 pub use mock_repository::MockEvalResultRepository;
 pub use mock_repository::MockJobRepository;
+pub use mock_repository::MockTranscriptRepository;
+pub use mock_repository::MockWebhookRepository;
+pub use pg_api_key_repository::PgApiKeyRepository;
 pub use pg_conversation_repository::PgConversationRepository;
+pub use pg_document_repository::PgDocumentRepository;
 pub use pg_eval_event_repository::PgEvalEventRepository;
 pub use pg_eval_outbox_repository::PgEvalOutboxRepository;
 pub use pg_eval_result_repository::PgEvalResultRepository;
 pub use pg_job_repository::PgJobRepository;
+pub use pg_transcript_repository::PgTranscriptRepository;
+pub use pg_webhook_repository::PgWebhookRepository;
+
+use crate::application::ports::RepositoryError;
+use crate::domain::TenantId;
+
+fn parse_tenant_id(raw: &str) -> Result<TenantId, RepositoryError> {
+    TenantId::parse(raw)
+        .ok_or_else(|| RepositoryError::QueryFailed(format!("invalid tenant id: {raw}")))
+}
@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::parse_tenant_id;
+use crate::application::ports::{ApiKeyRepository, RepositoryError};
+use crate::domain::{ApiKey, ApiKeyId, ApiScope, TenantId};
+
+pub struct PgApiKeyRepository {
+    pool: PgPool,
+}
+
+impl PgApiKeyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+struct ApiKeyRow {
+    id: Uuid,
+    name: String,
+    tenant_id: String,
+    scopes: Vec<String>,
+    key_prefix: String,
+    key_hash: String,
+    created_at: DateTime<Utc>,
+    revoked_at: Option<DateTime<Utc>>,
+}
+
+impl ApiKeyRow {
+    fn into_key(self) -> Result<ApiKey, RepositoryError> {
+        let scopes = self
+            .scopes
+            .iter()
+            .map(|s| s.parse::<ApiScope>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(RepositoryError::QueryFailed)?;
+
+        Ok(ApiKey {
+            id: ApiKeyId::from_uuid(self.id),
+            name: self.name,
+            tenant_id: parse_tenant_id(&self.tenant_id)?,
+            scopes,
+            key_prefix: self.key_prefix,
+            key_hash: self.key_hash,
+            created_at: self.created_at,
+            revoked_at: self.revoked_at,
+        })
+    }
+}
+
+#[async_trait]
+impl ApiKeyRepository for PgApiKeyRepository {
+    #[instrument(skip(self, key), fields(api_key_id = %key.id, tenant_id = %key.tenant_id))]
+    async fn create(&self, key: &ApiKey) -> Result<(), RepositoryError> {
+        let scopes: Vec<String> = key.scopes.iter().map(|s| s.as_str().to_string()).collect();
+
+        sqlx::query!(
+            r#"
+            INSERT INTO api_keys
+                (id, name, tenant_id, scopes, key_prefix, key_hash, created_at, revoked_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            key.id.as_uuid(),
+            key.name,
+            key.tenant_id.as_str(),
+            &scopes,
+            key.key_prefix,
+            key.key_hash,
+            key.created_at,
+            key.revoked_at
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| match &e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => {
+                RepositoryError::ConstraintViolation(db.message().to_string())
+            }
+            _ => RepositoryError::QueryFailed(e.to_string()),
+        })?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, key_hash))]
+    async fn find_active_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        let row = sqlx::query_as!(
+            ApiKeyRow,
+            r#"
+            SELECT id, name, tenant_id, scopes, key_prefix, key_hash, created_at, revoked_at
+            FROM api_keys
+            WHERE key_hash = $1 AND revoked_at IS NULL
+            "#,
+            key_hash
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+        row.map(ApiKeyRow::into_key).transpose()
+    }
+
+    #[instrument(skip(self), fields(tenant_id = ?tenant_id.map(TenantId::as_str)))]
+    async fn list(&self, tenant_id: Option<&TenantId>) -> Result<Vec<ApiKey>, RepositoryError> {
+        let rows = sqlx::query_as!(
+            ApiKeyRow,
+            r#"
+            SELECT id, name, tenant_id, scopes, key_prefix, key_hash, created_at, revoked_at
+            FROM api_keys
+            WHERE $1::TEXT IS NULL OR tenant_id = $1
+            ORDER BY created_at DESC
+            "#,
+            tenant_id.map(TenantId::as_str)
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+        rows.into_iter().map(ApiKeyRow::into_key).collect()
+    }
+
+    #[instrument(skip(self), fields(api_key_id = %id))]
+    async fn revoke(
+        &self,
+        id: ApiKeyId,
+        tenant_id: Option<&TenantId>,
+    ) -> Result<bool, RepositoryError> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE api_keys
+            SET revoked_at = NOW()
+            WHERE id = $1 AND revoked_at IS NULL AND ($2::TEXT IS NULL OR tenant_id = $2)
+            "#,
+            id.as_uuid(),
+            tenant_id.map(TenantId::as_str)
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -3,9 +3,10 @@ use chrono::Utc;
 use sqlx::PgPool;
 use tracing::instrument;
 
+use super::parse_tenant_id;
 use crate::application::ports::{ConversationRepository, RepositoryError};
 use crate::domain::{
-    Conversation, ConversationId, Message, MessageId, MessageRole, ToolCallId, ToolName,
+    Conversation, ConversationId, Message, MessageId, MessageRole, TenantId, ToolCallId, ToolName,
 };
 
 pub struct PgConversationRepository {
@@ -29,12 +30,13 @@ impl ConversationRepository for PgConversationRepository {
 
         sqlx::query!(
             r#"
-            INSERT INTO conversations (id, title, created_at, updated_at)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO conversations (id, title, tenant_id, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
             ON CONFLICT (id) DO NOTHING
             "#,
             conversation_id,
             conversation.title,
+            conversation.tenant_id.as_str(),
             conversation.created_at,
             conversation.updated_at
         )
@@ -54,7 +56,7 @@ impl ConversationRepository for PgConversationRepository {
 
         let row = sqlx::query!(
             r#"
-            SELECT id, title, created_at, updated_at
+            SELECT id, title, tenant_id, created_at, updated_at
             FROM conversations
             WHERE id = $1
             "#,
@@ -72,6 +74,7 @@ impl ConversationRepository for PgConversationRepository {
                     id: ConversationId::from_uuid(r.id),
                     title: r.title,
                     messages,
+                    tenant_id: parse_tenant_id(&r.tenant_id)?,
                     created_at: r.created_at,
                     updated_at: r.updated_at,
                 }))
@@ -80,6 +83,24 @@ impl ConversationRepository for PgConversationRepository {
         }
     }
 
+    #[instrument(skip(self), fields(conversation_id = %id.as_uuid()))]
+    async fn get_conversation_tenant(
+        &self,
+        id: ConversationId,
+    ) -> Result<Option<TenantId>, RepositoryError> {
+        let conversation_id = id.as_uuid();
+
+        let tenant_id = sqlx::query_scalar!(
+            "SELECT tenant_id FROM conversations WHERE id = $1",
+            conversation_id
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+        tenant_id.as_deref().map(parse_tenant_id).transpose()
+    }
+
     #[instrument(skip(self, message), fields(message_id = %message.id.as_uuid(), conversation_id = %message.conversation_id.as_uuid()))]
     async fn append_message(&self, message: &Message) -> Result<(), RepositoryError> {
         let message_id = message.id.as_uuid();
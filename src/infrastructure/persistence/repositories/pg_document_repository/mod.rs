@@ -0,0 +1,85 @@
+//! @AI: pg_document_repository routing map
+//! - query    -> reads and listings of catalog rows, scoped by tenant.
+//! - mutation -> inserts, source updates, ingestion marks and deletes.
+//! - rows     -> DocumentRow and its mapping to DocumentRecord.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::application::ports::{DocumentRepository, RepositoryError};
+use crate::domain::{DocumentId, DocumentRecord, TenantId};
+
+mod mutation;
+mod query;
+mod rows;
+
+pub struct PgDocumentRepository {
+    pool: PgPool,
+}
+
+impl PgDocumentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DocumentRepository for PgDocumentRepository {
+    async fn create(&self, document: &DocumentRecord) -> Result<(), RepositoryError> {
+        mutation::create(&self.pool, document).await
+    }
+
+    async fn get_by_id(&self, id: DocumentId) -> Result<Option<DocumentRecord>, RepositoryError> {
+        query::get_by_id(&self.pool, id).await
+    }
+
+    async fn find_by_content_hash(
+        &self,
+        tenant_id: &TenantId,
+        content_hash: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError> {
+        query::find_by_content_hash(&self.pool, tenant_id, content_hash).await
+    }
+
+    async fn find_by_filename(
+        &self,
+        tenant_id: &TenantId,
+        filename: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError> {
+        query::find_by_filename(&self.pool, tenant_id, filename).await
+    }
+
+    async fn update_source(&self, document: &DocumentRecord) -> Result<(), RepositoryError> {
+        mutation::update_source(&self.pool, document).await
+    }
+
+    async fn list(
+        &self,
+        tenant_id: &TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError> {
+        query::list(&self.pool, tenant_id, limit, offset).await
+    }
+
+    async fn list_by_filename_prefix(
+        &self,
+        tenant_id: &TenantId,
+        prefix: &str,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError> {
+        query::list_by_filename_prefix(&self.pool, tenant_id, prefix).await
+    }
+
+    async fn mark_ingested(
+        &self,
+        id: DocumentId,
+        chunk_count: usize,
+        content_hash: &str,
+    ) -> Result<(), RepositoryError> {
+        mutation::mark_ingested(&self.pool, id, chunk_count, content_hash).await
+    }
+
+    async fn delete(&self, id: DocumentId) -> Result<(), RepositoryError> {
+        mutation::delete(&self.pool, id).await
+    }
+}
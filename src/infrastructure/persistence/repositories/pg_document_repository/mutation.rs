@@ -0,0 +1,151 @@
+use chrono::Utc;
+use sqlx::PgPool;
+use tracing::instrument;
+
+use crate::application::ports::RepositoryError;
+use crate::domain::{DocumentId, DocumentRecord, SourceMetadata, StoragePath};
+
+fn map_write_error(error: sqlx::Error) -> RepositoryError {
+    match &error {
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            RepositoryError::ConstraintViolation(db.message().to_string())
+        }
+        _ => RepositoryError::QueryFailed(error.to_string()),
+    }
+}
+
+fn attributes_json(source: &SourceMetadata) -> Result<serde_json::Value, RepositoryError> {
+    serde_json::to_value(&source.attributes)
+        .map_err(|e| RepositoryError::QueryFailed(format!("attributes serialization: {e}")))
+}
+
+#[instrument(skip(pool, document), fields(document_id = %document.id.as_uuid()))]
+pub(super) async fn create(
+    pool: &PgPool,
+    document: &DocumentRecord,
+) -> Result<(), RepositoryError> {
+    let id = document.id.as_uuid();
+    let content_type = document.content_type.as_mime();
+    let size_bytes = document.size_bytes as i64;
+    let chunk_count = document.chunk_count as i32;
+    let attributes = attributes_json(&document.source)?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO documents
+            (id, filename, content_type, size_bytes, content_hash, chunk_count,
+             source_url, title, author, tags, attributes,
+             storage_path, staged_upload, captions_path, tenant_id, created_at, ingested_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+        "#,
+        id,
+        document.filename,
+        content_type,
+        size_bytes,
+        document.content_hash,
+        chunk_count,
+        document.source.source_url,
+        document.source.title,
+        document.source.author,
+        &document.source.tags,
+        attributes,
+        document.storage_path.as_str(),
+        document.staged_upload,
+        document.captions_path.as_ref().map(StoragePath::as_str),
+        document.tenant_id.as_str(),
+        document.created_at,
+        document.ingested_at
+    )
+    .execute(pool)
+    .await
+    .map_err(map_write_error)?;
+
+    Ok(())
+}
+
+#[instrument(skip(pool, document), fields(document_id = %document.id.as_uuid()))]
+pub(super) async fn update_source(
+    pool: &PgPool,
+    document: &DocumentRecord,
+) -> Result<(), RepositoryError> {
+    let id = document.id.as_uuid();
+    let content_type = document.content_type.as_mime();
+    let size_bytes = document.size_bytes as i64;
+    let attributes = attributes_json(&document.source)?;
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE documents
+        SET filename = $1, content_type = $2, size_bytes = $3, content_hash = $4,
+            storage_path = $5, staged_upload = $6, captions_path = $7,
+            source_url = $8, title = $9, author = $10, tags = $11, attributes = $12
+        WHERE id = $13
+        "#,
+        document.filename,
+        content_type,
+        size_bytes,
+        document.content_hash,
+        document.storage_path.as_str(),
+        document.staged_upload,
+        document.captions_path.as_ref().map(StoragePath::as_str),
+        document.source.source_url,
+        document.source.title,
+        document.source.author,
+        &document.source.tags,
+        attributes,
+        id
+    )
+    .execute(pool)
+    .await
+    .map_err(map_write_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(RepositoryError::NotFound(id.to_string()));
+    }
+
+    Ok(())
+}
+
+#[instrument(skip(pool, content_hash), fields(document_id = %id.as_uuid()))]
+pub(super) async fn mark_ingested(
+    pool: &PgPool,
+    id: DocumentId,
+    chunk_count: usize,
+    content_hash: &str,
+) -> Result<(), RepositoryError> {
+    let document_id = id.as_uuid();
+    let now = Utc::now();
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE documents
+        SET chunk_count = $1, content_hash = $2, ingested_at = $3
+        WHERE id = $4
+        "#,
+        chunk_count as i32,
+        content_hash,
+        now,
+        document_id
+    )
+    .execute(pool)
+    .await
+    .map_err(map_write_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err(RepositoryError::NotFound(document_id.to_string()));
+    }
+
+    Ok(())
+}
+
+#[instrument(skip(pool), fields(document_id = %id.as_uuid()))]
+pub(super) async fn delete(pool: &PgPool, id: DocumentId) -> Result<(), RepositoryError> {
+    let document_id = id.as_uuid();
+
+    sqlx::query!("DELETE FROM documents WHERE id = $1", document_id)
+        .execute(pool)
+        .await
+        .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(())
+}
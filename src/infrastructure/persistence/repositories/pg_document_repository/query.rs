@@ -0,0 +1,143 @@
+use sqlx::PgPool;
+use tracing::instrument;
+
+use super::rows::DocumentRow;
+use crate::application::ports::RepositoryError;
+use crate::domain::{DocumentId, DocumentRecord, TenantId};
+
+#[instrument(skip(pool), fields(document_id = %id.as_uuid()))]
+pub(super) async fn get_by_id(
+    pool: &PgPool,
+    id: DocumentId,
+) -> Result<Option<DocumentRecord>, RepositoryError> {
+    let document_id = id.as_uuid();
+
+    let row = sqlx::query_as!(
+        DocumentRow,
+        r#"
+        SELECT id, filename, content_type, size_bytes, content_hash, chunk_count,
+               source_url, title, author, tags, attributes,
+               storage_path, staged_upload, captions_path, tenant_id, created_at,
+               ingested_at
+        FROM documents
+        WHERE id = $1
+        "#,
+        document_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    row.map(DocumentRow::into_record).transpose()
+}
+
+#[instrument(skip(pool, content_hash), fields(tenant_id = %tenant_id))]
+pub(super) async fn find_by_content_hash(
+    pool: &PgPool,
+    tenant_id: &TenantId,
+    content_hash: &str,
+) -> Result<Option<DocumentRecord>, RepositoryError> {
+    let row = sqlx::query_as!(
+        DocumentRow,
+        r#"
+        SELECT id, filename, content_type, size_bytes, content_hash, chunk_count,
+               source_url, title, author, tags, attributes,
+               storage_path, staged_upload, captions_path, tenant_id, created_at,
+               ingested_at
+        FROM documents
+        WHERE tenant_id = $1 AND content_hash = $2
+        "#,
+        tenant_id.as_str(),
+        content_hash
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    row.map(DocumentRow::into_record).transpose()
+}
+
+#[instrument(skip(pool), fields(tenant_id = %tenant_id))]
+pub(super) async fn find_by_filename(
+    pool: &PgPool,
+    tenant_id: &TenantId,
+    filename: &str,
+) -> Result<Option<DocumentRecord>, RepositoryError> {
+    let row = sqlx::query_as!(
+        DocumentRow,
+        r#"
+        SELECT id, filename, content_type, size_bytes, content_hash, chunk_count,
+               source_url, title, author, tags, attributes,
+               storage_path, staged_upload, captions_path, tenant_id, created_at,
+               ingested_at
+        FROM documents
+        WHERE tenant_id = $1 AND filename = $2
+        ORDER BY created_at DESC
+        LIMIT 1
+        "#,
+        tenant_id.as_str(),
+        filename
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    row.map(DocumentRow::into_record).transpose()
+}
+
+#[instrument(skip(pool), fields(tenant_id = %tenant_id))]
+pub(super) async fn list(
+    pool: &PgPool,
+    tenant_id: &TenantId,
+    limit: usize,
+    offset: usize,
+) -> Result<Vec<DocumentRecord>, RepositoryError> {
+    let rows = sqlx::query_as!(
+        DocumentRow,
+        r#"
+        SELECT id, filename, content_type, size_bytes, content_hash, chunk_count,
+               source_url, title, author, tags, attributes,
+               storage_path, staged_upload, captions_path, tenant_id, created_at,
+               ingested_at
+        FROM documents
+        WHERE tenant_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2 OFFSET $3
+        "#,
+        tenant_id.as_str(),
+        limit as i64,
+        offset as i64
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    rows.into_iter().map(DocumentRow::into_record).collect()
+}
+
+#[instrument(skip(pool), fields(tenant_id = %tenant_id))]
+pub(super) async fn list_by_filename_prefix(
+    pool: &PgPool,
+    tenant_id: &TenantId,
+    prefix: &str,
+) -> Result<Vec<DocumentRecord>, RepositoryError> {
+    let rows = sqlx::query_as!(
+        DocumentRow,
+        r#"
+        SELECT id, filename, content_type, size_bytes, content_hash, chunk_count,
+               source_url, title, author, tags, attributes,
+               storage_path, staged_upload, captions_path, tenant_id, created_at,
+               ingested_at
+        FROM documents
+        WHERE tenant_id = $1 AND starts_with(filename, $2)
+        ORDER BY created_at
+        "#,
+        tenant_id.as_str(),
+        prefix
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    rows.into_iter().map(DocumentRow::into_record).collect()
+}
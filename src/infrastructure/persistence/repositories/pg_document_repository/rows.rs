@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::super::parse_tenant_id;
+use crate::application::ports::RepositoryError;
+use crate::domain::{ContentType, DocumentId, DocumentRecord, SourceMetadata, StoragePath};
+
+pub(super) struct DocumentRow {
+    pub(super) id: Uuid,
+    pub(super) filename: String,
+    pub(super) content_type: String,
+    pub(super) size_bytes: i64,
+    pub(super) content_hash: Option<String>,
+    pub(super) chunk_count: i32,
+    pub(super) source_url: Option<String>,
+    pub(super) title: Option<String>,
+    pub(super) author: Option<String>,
+    pub(super) tags: Vec<String>,
+    pub(super) attributes: serde_json::Value,
+    pub(super) storage_path: String,
+    pub(super) staged_upload: bool,
+    pub(super) captions_path: Option<String>,
+    pub(super) tenant_id: String,
+    pub(super) created_at: DateTime<Utc>,
+    pub(super) ingested_at: Option<DateTime<Utc>>,
+}
+
+impl DocumentRow {
+    pub(super) fn into_record(self) -> Result<DocumentRecord, RepositoryError> {
+        let content_type = ContentType::from_mime(&self.content_type).ok_or_else(|| {
+            RepositoryError::QueryFailed(format!("unknown content type: {}", self.content_type))
+        })?;
+        let tenant_id = parse_tenant_id(&self.tenant_id)?;
+        let attributes = serde_json::from_value(self.attributes)
+            .map_err(|e| RepositoryError::QueryFailed(format!("invalid attributes: {e}")))?;
+
+        Ok(DocumentRecord {
+            id: DocumentId::from_uuid(self.id),
+            filename: self.filename,
+            content_type,
+            size_bytes: self.size_bytes as u64,
+            content_hash: self.content_hash,
+            chunk_count: self.chunk_count as usize,
+            source: SourceMetadata {
+                source_url: self.source_url,
+                title: self.title,
+                author: self.author,
+                tags: self.tags,
+                attributes,
+            },
+            storage_path: StoragePath::from_raw(self.storage_path),
+            staged_upload: self.staged_upload,
+            captions_path: self.captions_path.map(StoragePath::from_raw),
+            tenant_id,
+            created_at: self.created_at,
+            ingested_at: self.ingested_at,
+        })
+    }
+}
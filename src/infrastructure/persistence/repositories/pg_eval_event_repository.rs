@@ -5,7 +5,9 @@ use tracing::instrument;
 use uuid::Uuid;
 
 use crate::application::ports::{EvalEventError, EvalEventRepository};
-use crate::domain::{AgenticTrace, EvalEvent, EvalEventId, EvalOperationType, EvalSource};
+use crate::domain::{
+    AgenticTrace, EvalEvent, EvalEventId, EvalOperationType, EvalSource, TenantId,
+};
 
 pub struct PgEvalEventRepository {
     pool: PgPool,
@@ -27,6 +29,8 @@ struct EvalEventRow {
     operation_type: String,
     correlation_id: Option<String>,
     agentic_trace: Option<serde_json::Value>,
+    tenant_id: String,
+    principal: Option<String>,
 }
 
 fn parse_operation_type(s: &str) -> EvalOperationType {
@@ -47,6 +51,9 @@ fn row_to_event(r: EvalEventRow) -> Result<EvalEvent, EvalEventError> {
         .map(serde_json::from_value)
         .transpose()
         .map_err(|e: serde_json::Error| EvalEventError::Serialization(e.to_string()))?;
+    let tenant_id = TenantId::parse(&r.tenant_id).ok_or_else(|| {
+        EvalEventError::Serialization(format!("invalid tenant id: {}", r.tenant_id))
+    })?;
     Ok(EvalEvent {
         id: EvalEventId::from_uuid(r.id),
         timestamp: r.timestamp,
@@ -57,6 +64,8 @@ fn row_to_event(r: EvalEventRow) -> Result<EvalEvent, EvalEventError> {
         operation_type: parse_operation_type(&r.operation_type),
         correlation_id: r.correlation_id,
         agentic_trace,
+        tenant_id,
+        principal: r.principal,
     })
 }
 
@@ -76,8 +85,8 @@ impl EvalEventRepository for PgEvalEventRepository {
 
         sqlx::query!(
             r#"
-            INSERT INTO eval_events (id, timestamp, question, generated_answer, retrieved_sources, model_config, operation_type, correlation_id, agentic_trace)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            INSERT INTO eval_events (id, timestamp, question, generated_answer, retrieved_sources, model_config, operation_type, correlation_id, agentic_trace, tenant_id, principal)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             ON CONFLICT (id) DO NOTHING
             "#,
             id,
@@ -88,7 +97,9 @@ impl EvalEventRepository for PgEvalEventRepository {
             event.model_config,
             event.operation_type.as_str(),
             event.correlation_id.as_deref(),
-            agentic_trace
+            agentic_trace,
+            event.tenant_id.as_str(),
+            event.principal.as_deref()
         )
         .execute(&self.pool)
         .await
@@ -103,7 +114,7 @@ impl EvalEventRepository for PgEvalEventRepository {
         let row = sqlx::query_as!(
             EvalEventRow,
             r#"
-            SELECT id, timestamp, question, generated_answer, retrieved_sources, model_config, operation_type, correlation_id, agentic_trace
+            SELECT id, timestamp, question, generated_answer, retrieved_sources, model_config, operation_type, correlation_id, agentic_trace, tenant_id, principal
             FROM eval_events
             WHERE id = $1
             "#,
@@ -121,7 +132,7 @@ impl EvalEventRepository for PgEvalEventRepository {
         let rows = sqlx::query_as!(
             EvalEventRow,
             r#"
-            SELECT id, timestamp, question, generated_answer, retrieved_sources, model_config, operation_type, correlation_id, agentic_trace
+            SELECT id, timestamp, question, generated_answer, retrieved_sources, model_config, operation_type, correlation_id, agentic_trace, tenant_id, principal
             FROM eval_events
             ORDER BY timestamp DESC
             "#
@@ -143,7 +154,7 @@ impl EvalEventRepository for PgEvalEventRepository {
         let rows = sqlx::query_as!(
             EvalEventRow,
             r#"
-            SELECT id, timestamp, question, generated_answer, retrieved_sources, model_config, operation_type, correlation_id, agentic_trace
+            SELECT id, timestamp, question, generated_answer, retrieved_sources, model_config, operation_type, correlation_id, agentic_trace, tenant_id, principal
             FROM eval_events
             ORDER BY RANDOM()
             LIMIT $1
@@ -21,14 +21,15 @@ impl EvalResultRepository for PgEvalResultRepository {
     async fn save(&self, result: &EvalResult) -> Result<(), EvalResultError> {
         let id = result.id.as_uuid();
         let eval_event_id = result.eval_event_id.as_uuid();
+        let run_id = result.run_id.map(|r| r.as_uuid());
 
         sqlx::query!(
             r#"
             INSERT INTO eval_results
                 (id, eval_event_id, question, generated_answer, eval_description,
                  faithfulness, answer_relevancy, context_precision,
-                 context_recall, correctness, below_threshold, computed_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+                 context_recall, correctness, below_threshold, computed_at, run_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
             ON CONFLICT (eval_event_id) DO NOTHING
             "#,
             id,
@@ -43,6 +44,7 @@ impl EvalResultRepository for PgEvalResultRepository {
             result.correctness,
             result.below_threshold,
             result.computed_at,
+            run_id,
         )
         .execute(&self.pool)
         .await
@@ -0,0 +1,211 @@
+use chrono::Utc;
+use sqlx::PgPool;
+use tracing::instrument;
+
+use super::payload::payload_to_json;
+use super::rows::{FetchFailureJson, JobRow, row_to_job};
+use crate::application::ports::{JobFilter, RepositoryError};
+use crate::domain::{DocumentId, FetchFailure, Job, JobId, JobStatus};
+
+#[instrument(skip(pool, job), fields(job_id = %job.id.as_uuid()))]
+pub(super) async fn create(pool: &PgPool, job: &Job) -> Result<(), RepositoryError> {
+    let job_id = job.id.as_uuid();
+    let document_id = job.document_id.map(|id| id.as_uuid());
+    let status = job.status.as_str();
+    let payload = job.payload.as_ref().map(payload_to_json).transpose()?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO jobs
+            (id, document_id, status, job_type, error_message, tenant_id, payload,
+             created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        "#,
+        job_id,
+        document_id,
+        status,
+        job.job_type,
+        job.error_message,
+        job.tenant_id.as_str(),
+        payload,
+        job.created_at,
+        job.updated_at
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(())
+}
+
+#[instrument(skip(pool), fields(job_id = %id.as_uuid()))]
+pub(super) async fn get_by_id(pool: &PgPool, id: JobId) -> Result<Option<Job>, RepositoryError> {
+    let job_id = id.as_uuid();
+
+    let row = sqlx::query_as!(
+        JobRow,
+        r#"
+        SELECT id, document_id, status, job_type, error_message, tenant_id, payload,
+               attempts, lease_owner, lease_expires_at, next_attempt_at, cancel_requested,
+               progress, fetch_failures, created_at, updated_at
+        FROM jobs
+        WHERE id = $1
+        "#,
+        job_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    row.map(row_to_job).transpose()
+}
+
+#[instrument(skip(pool, error_message), fields(job_id = %id.as_uuid(), status = %status))]
+pub(super) async fn update_status(
+    pool: &PgPool,
+    id: JobId,
+    status: JobStatus,
+    error_message: Option<&str>,
+) -> Result<(), RepositoryError> {
+    let job_id = id.as_uuid();
+    let status_str = status.as_str();
+    let now = Utc::now();
+
+    sqlx::query!(
+        r#"
+        UPDATE jobs
+        SET status = $1, error_message = $2, updated_at = $3
+        WHERE id = $4
+        "#,
+        status_str,
+        error_message,
+        now,
+        job_id
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(())
+}
+
+#[instrument(skip(pool), fields(status = %status))]
+pub(super) async fn list_by_status(
+    pool: &PgPool,
+    status: JobStatus,
+) -> Result<Vec<Job>, RepositoryError> {
+    let status_str = status.as_str();
+
+    let rows = sqlx::query_as!(
+        JobRow,
+        r#"
+        SELECT id, document_id, status, job_type, error_message, tenant_id, payload,
+               attempts, lease_owner, lease_expires_at, next_attempt_at, cancel_requested,
+               progress, fetch_failures, created_at, updated_at
+        FROM jobs
+        WHERE status = $1
+        ORDER BY created_at DESC
+        "#,
+        status_str
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    rows.into_iter().map(row_to_job).collect()
+}
+
+#[instrument(skip(pool, filter), fields(tenant_id = %filter.tenant_id))]
+pub(super) async fn list(
+    pool: &PgPool,
+    filter: &JobFilter,
+    limit: usize,
+    offset: usize,
+) -> Result<Vec<Job>, RepositoryError> {
+    let status = filter.status.map(|s| s.as_str());
+
+    let rows = sqlx::query_as!(
+        JobRow,
+        r#"
+        SELECT id, document_id, status, job_type, error_message, tenant_id, payload,
+               attempts, lease_owner, lease_expires_at, next_attempt_at, cancel_requested,
+               progress, fetch_failures, created_at, updated_at
+        FROM jobs
+        WHERE tenant_id = $1
+          AND ($2::TEXT IS NULL OR status = $2)
+          AND ($3::TEXT IS NULL OR job_type = $3)
+          AND ($4::TIMESTAMPTZ IS NULL OR created_at >= $4)
+          AND ($5::TIMESTAMPTZ IS NULL OR created_at <= $5)
+        ORDER BY created_at DESC
+        LIMIT $6 OFFSET $7
+        "#,
+        filter.tenant_id.as_str(),
+        status,
+        filter.job_type.as_deref(),
+        filter.created_after,
+        filter.created_before,
+        limit as i64,
+        offset as i64
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    rows.into_iter().map(row_to_job).collect()
+}
+
+#[instrument(skip(pool), fields(document_id = %document_id.as_uuid()))]
+pub(super) async fn find_latest_by_document(
+    pool: &PgPool,
+    document_id: DocumentId,
+) -> Result<Option<Job>, RepositoryError> {
+    let document_uuid = document_id.as_uuid();
+
+    let row = sqlx::query_as!(
+        JobRow,
+        r#"
+        SELECT id, document_id, status, job_type, error_message, tenant_id, payload,
+               attempts, lease_owner, lease_expires_at, next_attempt_at, cancel_requested,
+               progress, fetch_failures, created_at, updated_at
+        FROM jobs
+        WHERE document_id = $1
+        ORDER BY created_at DESC
+        LIMIT 1
+        "#,
+        document_uuid
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    row.map(row_to_job).transpose()
+}
+
+#[instrument(skip(pool, failure), fields(job_id = %id.as_uuid(), url = %failure.url))]
+pub(super) async fn record_fetch_failure(
+    pool: &PgPool,
+    id: JobId,
+    failure: &FetchFailure,
+) -> Result<(), RepositoryError> {
+    let job_id = id.as_uuid();
+    let entry = serde_json::to_value([FetchFailureJson {
+        url: failure.url.clone(),
+        error: failure.error.clone(),
+    }])
+    .map_err(|e| RepositoryError::QueryFailed(format!("fetch failure serialization: {e}")))?;
+
+    sqlx::query!(
+        r#"
+        UPDATE jobs
+        SET fetch_failures = fetch_failures || $2::jsonb, updated_at = NOW()
+        WHERE id = $1
+        "#,
+        job_id,
+        entry
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tracing::instrument;
+
+use super::rows::{JobRow, ProgressJson, in_flight_statuses, row_to_job};
+use crate::application::ports::{LeaseRenewal, RepositoryError};
+use crate::domain::{Job, JobId, JobProgress, JobStatus};
+
+#[instrument(skip(pool))]
+pub(super) async fn claim_next(
+    pool: &PgPool,
+    worker_id: &str,
+    lease: Duration,
+) -> Result<Option<Job>, RepositoryError> {
+    let row = sqlx::query_as!(
+        JobRow,
+        r#"
+        UPDATE jobs
+        SET status = 'PROCESSING',
+            attempts = attempts + 1,
+            lease_owner = $1,
+            lease_expires_at = NOW() + make_interval(secs => $2),
+            next_attempt_at = NULL,
+            progress = '{}'::jsonb,
+            fetch_failures = '[]'::jsonb,
+            updated_at = NOW()
+        WHERE id = (
+            SELECT id FROM jobs
+            WHERE status = 'QUEUED' AND payload IS NOT NULL
+              AND (next_attempt_at IS NULL OR next_attempt_at <= NOW())
+            ORDER BY created_at
+            LIMIT 1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING id, document_id, status, job_type, error_message, tenant_id, payload,
+                  attempts, lease_owner, lease_expires_at, next_attempt_at,
+                  cancel_requested, progress, fetch_failures, created_at, updated_at
+        "#,
+        worker_id,
+        lease.as_secs_f64()
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    row.map(row_to_job).transpose()
+}
+
+#[instrument(skip(pool), fields(job_id = %id.as_uuid()))]
+pub(super) async fn renew_lease(
+    pool: &PgPool,
+    id: JobId,
+    worker_id: &str,
+    lease: Duration,
+) -> Result<LeaseRenewal, RepositoryError> {
+    let job_id = id.as_uuid();
+    let in_flight = in_flight_statuses();
+
+    let cancel_requested = sqlx::query_scalar!(
+        r#"
+        UPDATE jobs
+        SET lease_expires_at = NOW() + make_interval(secs => $3)
+        WHERE id = $1 AND lease_owner = $2 AND status = ANY($4)
+        RETURNING cancel_requested
+        "#,
+        job_id,
+        worker_id,
+        lease.as_secs_f64(),
+        &in_flight
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(match cancel_requested {
+        Some(true) => LeaseRenewal::CancelRequested,
+        Some(false) => LeaseRenewal::Renewed,
+        None => LeaseRenewal::Lost,
+    })
+}
+
+#[instrument(skip(pool), fields(job_id = %id.as_uuid()))]
+pub(super) async fn release_expired(pool: &PgPool, id: JobId) -> Result<bool, RepositoryError> {
+    let job_id = id.as_uuid();
+    let in_flight = in_flight_statuses();
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE jobs
+        SET status = 'QUEUED', lease_owner = NULL, lease_expires_at = NULL,
+            updated_at = NOW()
+        WHERE id = $1
+          AND status = ANY($2)
+          AND (lease_expires_at IS NULL OR lease_expires_at < NOW())
+        "#,
+        job_id,
+        &in_flight
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(result.rows_affected() == 1)
+}
+
+#[instrument(skip(pool, error_message), fields(job_id = %id.as_uuid(), status = %status))]
+pub(super) async fn update_leased_status(
+    pool: &PgPool,
+    id: JobId,
+    worker_id: &str,
+    status: JobStatus,
+    error_message: Option<&str>,
+) -> Result<bool, RepositoryError> {
+    let job_id = id.as_uuid();
+    let status_str = status.as_str();
+    let in_flight = in_flight_statuses();
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE jobs
+        SET status = $1, error_message = $2, updated_at = NOW()
+        WHERE id = $3 AND lease_owner = $4 AND status = ANY($5)
+        "#,
+        status_str,
+        error_message,
+        job_id,
+        worker_id,
+        &in_flight
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(result.rows_affected() == 1)
+}
+
+#[instrument(skip(pool, progress), fields(job_id = %id.as_uuid()))]
+pub(super) async fn update_progress(
+    pool: &PgPool,
+    id: JobId,
+    worker_id: &str,
+    progress: &JobProgress,
+) -> Result<bool, RepositoryError> {
+    let job_id = id.as_uuid();
+    let progress = serde_json::to_value(ProgressJson::from(progress))
+        .map_err(|e| RepositoryError::QueryFailed(format!("progress serialization: {e}")))?;
+    let in_flight = in_flight_statuses();
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE jobs
+        SET progress = $2, updated_at = NOW()
+        WHERE id = $1 AND lease_owner = $3 AND status = ANY($4)
+        "#,
+        job_id,
+        progress,
+        worker_id,
+        &in_flight
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(result.rows_affected() == 1)
+}
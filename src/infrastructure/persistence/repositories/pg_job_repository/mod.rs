@@ -0,0 +1,137 @@
+//! @AI: pg_job_repository routing map
+//! - crud    -> inserts, reads, listings, unleased status writes and fetch failures.
+//! - lease   -> claim_next, renew_lease, release_expired and the lease-conditional
+//!   status and progress writes.
+//! - retry   -> schedule_retry, request_cancel and the manual retry of a settled job.
+//! - rows    -> JobRow and its mapping to Job, with the progress and fetch-failure JSONB.
+//! - payload -> JSONB shapes of ingestion, crawl and repository sync payloads.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+
+use crate::application::ports::{JobFilter, JobRepository, LeaseRenewal, RepositoryError};
+use crate::domain::{DocumentId, FetchFailure, Job, JobId, JobProgress, JobStatus};
+
+mod crud;
+mod lease;
+mod payload;
+mod retry;
+mod rows;
+
+pub struct PgJobRepository {
+    pool: PgPool,
+}
+
+impl PgJobRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl JobRepository for PgJobRepository {
+    async fn create(&self, job: &Job) -> Result<(), RepositoryError> {
+        crud::create(&self.pool, job).await
+    }
+
+    async fn get_by_id(&self, id: JobId) -> Result<Option<Job>, RepositoryError> {
+        crud::get_by_id(&self.pool, id).await
+    }
+
+    async fn update_status(
+        &self,
+        id: JobId,
+        status: JobStatus,
+        error_message: Option<&str>,
+    ) -> Result<(), RepositoryError> {
+        crud::update_status(&self.pool, id, status, error_message).await
+    }
+
+    async fn update_leased_status(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        status: JobStatus,
+        error_message: Option<&str>,
+    ) -> Result<bool, RepositoryError> {
+        lease::update_leased_status(&self.pool, id, worker_id, status, error_message).await
+    }
+
+    async fn list_by_status(&self, status: JobStatus) -> Result<Vec<Job>, RepositoryError> {
+        crud::list_by_status(&self.pool, status).await
+    }
+
+    async fn list(
+        &self,
+        filter: &JobFilter,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Job>, RepositoryError> {
+        crud::list(&self.pool, filter, limit, offset).await
+    }
+
+    async fn find_latest_by_document(
+        &self,
+        document_id: DocumentId,
+    ) -> Result<Option<Job>, RepositoryError> {
+        crud::find_latest_by_document(&self.pool, document_id).await
+    }
+
+    async fn claim_next(
+        &self,
+        worker_id: &str,
+        lease: Duration,
+    ) -> Result<Option<Job>, RepositoryError> {
+        lease::claim_next(&self.pool, worker_id, lease).await
+    }
+
+    async fn renew_lease(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        lease: Duration,
+    ) -> Result<LeaseRenewal, RepositoryError> {
+        lease::renew_lease(&self.pool, id, worker_id, lease).await
+    }
+
+    async fn release_expired(&self, id: JobId) -> Result<bool, RepositoryError> {
+        lease::release_expired(&self.pool, id).await
+    }
+
+    async fn schedule_retry(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        delay: Duration,
+        error_message: &str,
+    ) -> Result<bool, RepositoryError> {
+        retry::schedule_retry(&self.pool, id, worker_id, delay, error_message).await
+    }
+
+    async fn update_progress(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        progress: &JobProgress,
+    ) -> Result<bool, RepositoryError> {
+        lease::update_progress(&self.pool, id, worker_id, progress).await
+    }
+
+    async fn record_fetch_failure(
+        &self,
+        id: JobId,
+        failure: &FetchFailure,
+    ) -> Result<(), RepositoryError> {
+        crud::record_fetch_failure(&self.pool, id, failure).await
+    }
+
+    async fn request_cancel(&self, id: JobId) -> Result<Option<JobStatus>, RepositoryError> {
+        retry::request_cancel(&self.pool, id).await
+    }
+
+    async fn retry(&self, id: JobId) -> Result<bool, RepositoryError> {
+        retry::retry(&self.pool, id).await
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::ports::RepositoryError;
+use crate::domain::{
+    ContentType, CrawlRequest, Document, DocumentId, IngestionPayload, JobPayload,
+    RepositorySyncRequest, SourceMetadata, StoragePath,
+};
+
+/// JSONB shape of [`IngestionPayload`].
+#[derive(Serialize, Deserialize)]
+struct PayloadJson {
+    document_id: Uuid,
+    filename: String,
+    content_type: String,
+    size_bytes: u64,
+    storage_path: String,
+    replace_existing: bool,
+    principal: Option<String>,
+    /// Absent from jobs queued before source metadata was accepted.
+    #[serde(default)]
+    source: SourceJson,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    captions: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct SourceJson {
+    source_url: Option<String>,
+    title: Option<String>,
+    author: Option<String>,
+    tags: Vec<String>,
+    attributes: BTreeMap<String, String>,
+}
+
+impl From<&SourceMetadata> for SourceJson {
+    fn from(source: &SourceMetadata) -> Self {
+        Self {
+            source_url: source.source_url.clone(),
+            title: source.title.clone(),
+            author: source.author.clone(),
+            tags: source.tags.clone(),
+            attributes: source.attributes.clone(),
+        }
+    }
+}
+
+impl From<SourceJson> for SourceMetadata {
+    fn from(json: SourceJson) -> Self {
+        Self {
+            source_url: json.source_url,
+            title: json.title,
+            author: json.author,
+            tags: json.tags,
+            attributes: json.attributes,
+        }
+    }
+}
+
+impl From<&IngestionPayload> for PayloadJson {
+    fn from(payload: &IngestionPayload) -> Self {
+        Self {
+            document_id: payload.document.id.as_uuid(),
+            filename: payload.document.filename.clone(),
+            content_type: payload.document.content_type.as_mime().to_string(),
+            size_bytes: payload.document.size_bytes,
+            storage_path: payload.storage_path.to_string(),
+            replace_existing: payload.replace_existing,
+            principal: payload.principal.clone(),
+            source: SourceJson::from(&payload.source),
+            captions: payload.captions.as_ref().map(ToString::to_string),
+        }
+    }
+}
+
+impl TryFrom<PayloadJson> for IngestionPayload {
+    type Error = RepositoryError;
+
+    fn try_from(json: PayloadJson) -> Result<Self, Self::Error> {
+        let content_type = ContentType::from_mime(&json.content_type).ok_or_else(|| {
+            RepositoryError::QueryFailed(format!(
+                "invalid payload content type: {}",
+                json.content_type
+            ))
+        })?;
+        Ok(Self {
+            document: Document {
+                id: DocumentId::from_uuid(json.document_id),
+                filename: json.filename,
+                content_type,
+                size_bytes: json.size_bytes,
+            },
+            storage_path: StoragePath::from_raw(json.storage_path),
+            replace_existing: json.replace_existing,
+            principal: json.principal,
+            source: json.source.into(),
+            captions: json.captions.map(StoragePath::from_raw),
+        })
+    }
+}
+
+const CRAWL_KIND: &str = "crawl";
+
+/// JSONB shape of [`CrawlRequest`]. Ingestion payloads predate it and carry no `kind`.
+#[derive(Serialize, Deserialize)]
+struct CrawlJson {
+    kind: String,
+    url: String,
+    sitemap: bool,
+    max_depth: u32,
+    max_pages: u32,
+    allowed_domains: Vec<String>,
+    principal: Option<String>,
+    source: SourceJson,
+}
+
+impl From<&CrawlRequest> for CrawlJson {
+    fn from(request: &CrawlRequest) -> Self {
+        Self {
+            kind: CRAWL_KIND.to_string(),
+            url: request.url.clone(),
+            sitemap: request.sitemap,
+            max_depth: request.max_depth,
+            max_pages: request.max_pages,
+            allowed_domains: request.allowed_domains.clone(),
+            principal: request.principal.clone(),
+            source: SourceJson::from(&request.source),
+        }
+    }
+}
+
+impl From<CrawlJson> for CrawlRequest {
+    fn from(json: CrawlJson) -> Self {
+        Self {
+            url: json.url,
+            sitemap: json.sitemap,
+            max_depth: json.max_depth,
+            max_pages: json.max_pages,
+            allowed_domains: json.allowed_domains,
+            principal: json.principal,
+            source: json.source.into(),
+        }
+    }
+}
+
+const REPOSITORY_SYNC_KIND: &str = "repository_sync";
+
+/// JSONB shape of [`RepositorySyncRequest`].
+#[derive(Serialize, Deserialize)]
+struct RepositorySyncJson {
+    kind: String,
+    path: String,
+    name: String,
+    principal: Option<String>,
+    source: SourceJson,
+}
+
+impl From<&RepositorySyncRequest> for RepositorySyncJson {
+    fn from(request: &RepositorySyncRequest) -> Self {
+        Self {
+            kind: REPOSITORY_SYNC_KIND.to_string(),
+            path: request.path.clone(),
+            name: request.name.clone(),
+            principal: request.principal.clone(),
+            source: SourceJson::from(&request.source),
+        }
+    }
+}
+
+impl From<RepositorySyncJson> for RepositorySyncRequest {
+    fn from(json: RepositorySyncJson) -> Self {
+        Self {
+            path: json.path,
+            name: json.name,
+            principal: json.principal,
+            source: json.source.into(),
+        }
+    }
+}
+
+pub(super) fn payload_to_json(payload: &JobPayload) -> Result<serde_json::Value, RepositoryError> {
+    match payload {
+        JobPayload::Ingestion(payload) => serde_json::to_value(PayloadJson::from(payload)),
+        JobPayload::Crawl(request) => serde_json::to_value(CrawlJson::from(request)),
+        JobPayload::RepositorySync(request) => {
+            serde_json::to_value(RepositorySyncJson::from(request))
+        }
+    }
+    .map_err(|e| RepositoryError::QueryFailed(format!("payload serialization: {e}")))
+}
+
+pub(super) fn payload_from_json(value: serde_json::Value) -> Result<JobPayload, RepositoryError> {
+    let parse_error = |e: serde_json::Error| RepositoryError::QueryFailed(format!("payload: {e}"));
+    match value.get("kind").and_then(serde_json::Value::as_str) {
+        Some(CRAWL_KIND) => {
+            let json = serde_json::from_value::<CrawlJson>(value).map_err(parse_error)?;
+            return Ok(JobPayload::Crawl(json.into()));
+        }
+        Some(REPOSITORY_SYNC_KIND) => {
+            let json = serde_json::from_value::<RepositorySyncJson>(value).map_err(parse_error)?;
+            return Ok(JobPayload::RepositorySync(json.into()));
+        }
+        _ => {}
+    }
+    let json = serde_json::from_value::<PayloadJson>(value).map_err(parse_error)?;
+    IngestionPayload::try_from(json).map(JobPayload::Ingestion)
+}
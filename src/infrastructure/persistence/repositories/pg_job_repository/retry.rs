@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tracing::instrument;
+
+use super::rows::in_flight_statuses;
+use crate::application::ports::{JOB_CANCELLED, RepositoryError};
+use crate::domain::{JobId, JobStatus};
+
+#[instrument(skip(pool, error_message), fields(job_id = %id.as_uuid()))]
+pub(super) async fn schedule_retry(
+    pool: &PgPool,
+    id: JobId,
+    worker_id: &str,
+    delay: Duration,
+    error_message: &str,
+) -> Result<bool, RepositoryError> {
+    let job_id = id.as_uuid();
+    let in_flight = in_flight_statuses();
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE jobs
+        SET status = 'QUEUED', error_message = $2,
+            next_attempt_at = NOW() + make_interval(secs => $3),
+            lease_owner = NULL, lease_expires_at = NULL, updated_at = NOW()
+        WHERE id = $1 AND lease_owner = $4 AND status = ANY($5) AND NOT cancel_requested
+        "#,
+        job_id,
+        error_message,
+        delay.as_secs_f64(),
+        worker_id,
+        &in_flight
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(result.rows_affected() == 1)
+}
+
+#[instrument(skip(pool), fields(job_id = %id.as_uuid()))]
+pub(super) async fn request_cancel(
+    pool: &PgPool,
+    id: JobId,
+) -> Result<Option<JobStatus>, RepositoryError> {
+    let job_id = id.as_uuid();
+
+    let status = sqlx::query_scalar!(
+        r#"
+        UPDATE jobs
+        SET cancel_requested = TRUE,
+            status = CASE WHEN status = 'QUEUED' THEN 'CANCELLED' ELSE status END,
+            error_message = CASE WHEN status = 'QUEUED' THEN $2 ELSE error_message END,
+            updated_at = NOW()
+        WHERE id = $1 AND status NOT IN ('COMPLETED', 'FAILED', 'CANCELLED')
+        RETURNING status
+        "#,
+        job_id,
+        JOB_CANCELLED
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    status
+        .map(|s| s.parse::<JobStatus>().map_err(RepositoryError::QueryFailed))
+        .transpose()
+}
+
+#[instrument(skip(pool), fields(job_id = %id.as_uuid()))]
+pub(super) async fn retry(pool: &PgPool, id: JobId) -> Result<bool, RepositoryError> {
+    let job_id = id.as_uuid();
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE jobs
+        SET status = 'QUEUED', attempts = 0, error_message = NULL, next_attempt_at = NULL,
+            cancel_requested = FALSE, progress = '{}'::jsonb, fetch_failures = '[]'::jsonb,
+            lease_owner = NULL, lease_expires_at = NULL, updated_at = NOW()
+        WHERE id = $1 AND status IN ('FAILED', 'CANCELLED') AND payload IS NOT NULL
+        "#,
+        job_id
+    )
+    .execute(pool)
+    .await
+    .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+    Ok(result.rows_affected() == 1)
+}
@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::super::parse_tenant_id;
+use super::payload::payload_from_json;
+use crate::application::ports::RepositoryError;
+use crate::domain::{DocumentId, FetchFailure, Job, JobId, JobProgress, JobStatus};
+
+pub(super) struct JobRow {
+    pub(super) id: Uuid,
+    pub(super) document_id: Option<Uuid>,
+    pub(super) status: String,
+    pub(super) job_type: String,
+    pub(super) error_message: Option<String>,
+    pub(super) tenant_id: String,
+    pub(super) payload: Option<serde_json::Value>,
+    pub(super) attempts: i32,
+    pub(super) lease_owner: Option<String>,
+    pub(super) lease_expires_at: Option<DateTime<Utc>>,
+    pub(super) next_attempt_at: Option<DateTime<Utc>>,
+    pub(super) cancel_requested: bool,
+    pub(super) progress: serde_json::Value,
+    pub(super) fetch_failures: serde_json::Value,
+    pub(super) created_at: DateTime<Utc>,
+    pub(super) updated_at: DateTime<Utc>,
+}
+
+/// JSONB shape of [`JobProgress`]; missing counters read as zero.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub(super) struct ProgressJson {
+    pages_processed: u32,
+    segments_transcribed: u32,
+    chunks_total: u32,
+    chunks_embedded: u32,
+    urls_ingested: u32,
+    files_ingested: u32,
+    files_unchanged: u32,
+    files_removed: u32,
+}
+
+impl From<&JobProgress> for ProgressJson {
+    fn from(progress: &JobProgress) -> Self {
+        Self {
+            pages_processed: progress.pages_processed,
+            segments_transcribed: progress.segments_transcribed,
+            chunks_total: progress.chunks_total,
+            chunks_embedded: progress.chunks_embedded,
+            urls_ingested: progress.urls_ingested,
+            files_ingested: progress.files_ingested,
+            files_unchanged: progress.files_unchanged,
+            files_removed: progress.files_removed,
+        }
+    }
+}
+
+impl From<ProgressJson> for JobProgress {
+    fn from(json: ProgressJson) -> Self {
+        Self {
+            pages_processed: json.pages_processed,
+            segments_transcribed: json.segments_transcribed,
+            chunks_total: json.chunks_total,
+            chunks_embedded: json.chunks_embedded,
+            urls_ingested: json.urls_ingested,
+            files_ingested: json.files_ingested,
+            files_unchanged: json.files_unchanged,
+            files_removed: json.files_removed,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct FetchFailureJson {
+    pub(super) url: String,
+    pub(super) error: String,
+}
+
+pub(super) fn row_to_job(r: JobRow) -> Result<Job, RepositoryError> {
+    let status = r
+        .status
+        .parse::<JobStatus>()
+        .map_err(RepositoryError::QueryFailed)?;
+    let payload = r.payload.map(payload_from_json).transpose()?;
+    let progress = serde_json::from_value::<ProgressJson>(r.progress)
+        .map_err(|e| RepositoryError::QueryFailed(format!("progress: {e}")))?;
+    let fetch_failures = serde_json::from_value::<Vec<FetchFailureJson>>(r.fetch_failures)
+        .map_err(|e| RepositoryError::QueryFailed(format!("fetch failures: {e}")))?
+        .into_iter()
+        .map(|f| FetchFailure {
+            url: f.url,
+            error: f.error,
+        })
+        .collect();
+
+    Ok(Job {
+        id: JobId::from_uuid(r.id),
+        document_id: r.document_id.map(DocumentId::from_uuid),
+        status,
+        job_type: r.job_type,
+        error_message: r.error_message,
+        tenant_id: parse_tenant_id(&r.tenant_id)?,
+        payload,
+        attempts: r.attempts.max(0) as u32,
+        lease_owner: r.lease_owner,
+        lease_expires_at: r.lease_expires_at,
+        next_attempt_at: r.next_attempt_at,
+        cancel_requested: r.cancel_requested,
+        progress: progress.into(),
+        fetch_failures,
+        created_at: r.created_at,
+        updated_at: r.updated_at,
+    })
+}
+
+pub(super) fn in_flight_statuses() -> Vec<String> {
+    JobStatus::IN_FLIGHT
+        .iter()
+        .map(|s| s.as_str().to_string())
+        .collect()
+}
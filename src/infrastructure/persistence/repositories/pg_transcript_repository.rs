@@ -0,0 +1,138 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tracing::instrument;
+
+use crate::application::ports::{RepositoryError, TranscriptRepository};
+use crate::domain::{DocumentId, Transcript, TranscriptSegment, TranscriptWord};
+
+pub struct PgTranscriptRepository {
+    pool: PgPool,
+}
+
+impl PgTranscriptRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// JSONB shape of one [`TranscriptSegment`].
+#[derive(Serialize, Deserialize)]
+struct SegmentJson {
+    start_time: f32,
+    end_time: f32,
+    text: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    words: Vec<WordJson>,
+}
+
+/// JSONB shape of one [`TranscriptWord`].
+#[derive(Serialize, Deserialize)]
+struct WordJson {
+    start_time: f32,
+    end_time: f32,
+    text: String,
+}
+
+impl From<&TranscriptSegment> for SegmentJson {
+    fn from(segment: &TranscriptSegment) -> Self {
+        Self {
+            start_time: segment.start_time,
+            end_time: segment.end_time,
+            text: segment.text.clone(),
+            words: segment
+                .words
+                .iter()
+                .map(|word| WordJson {
+                    start_time: word.start_time,
+                    end_time: word.end_time,
+                    text: word.text.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<SegmentJson> for TranscriptSegment {
+    fn from(segment: SegmentJson) -> Self {
+        Self::new(segment.text, segment.start_time, segment.end_time).with_words(
+            segment
+                .words
+                .into_iter()
+                .map(|word| TranscriptWord::new(word.text, word.start_time, word.end_time))
+                .collect(),
+        )
+    }
+}
+
+#[async_trait]
+impl TranscriptRepository for PgTranscriptRepository {
+    #[instrument(skip(self, transcript), fields(document_id = %document_id.as_uuid(), segments = transcript.segments.len()))]
+    async fn save(
+        &self,
+        document_id: DocumentId,
+        transcript: &Transcript,
+    ) -> Result<(), RepositoryError> {
+        let segments = serde_json::to_value(
+            transcript
+                .segments
+                .iter()
+                .map(SegmentJson::from)
+                .collect::<Vec<_>>(),
+        )
+        .map_err(|e| RepositoryError::QueryFailed(format!("segment serialization: {e}")))?;
+
+        sqlx::query!(
+            r#"
+            INSERT INTO document_transcripts (document_id, segments, language, updated_at)
+            VALUES ($1, $2, $3, NOW())
+            ON CONFLICT (document_id)
+            DO UPDATE SET segments = EXCLUDED.segments,
+                          language = COALESCE(EXCLUDED.language, document_transcripts.language),
+                          updated_at = EXCLUDED.updated_at
+            "#,
+            document_id.as_uuid(),
+            segments,
+            transcript.language.as_deref()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(document_id = %document_id.as_uuid()))]
+    async fn get(&self, document_id: DocumentId) -> Result<Option<Transcript>, RepositoryError> {
+        let row = sqlx::query!(
+            "SELECT segments, language FROM document_transcripts WHERE document_id = $1",
+            document_id.as_uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+        row.map(|row| {
+            serde_json::from_value::<Vec<SegmentJson>>(row.segments)
+                .map(|segments| {
+                    Transcript::new(segments.into_iter().map(TranscriptSegment::from).collect())
+                        .with_language(row.language)
+                })
+                .map_err(|e| RepositoryError::QueryFailed(format!("invalid segments: {e}")))
+        })
+        .transpose()
+    }
+
+    #[instrument(skip(self), fields(document_id = %document_id.as_uuid()))]
+    async fn delete(&self, document_id: DocumentId) -> Result<(), RepositoryError> {
+        sqlx::query!(
+            "DELETE FROM document_transcripts WHERE document_id = $1",
+            document_id.as_uuid()
+        )
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryFailed(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+//! @AI: pg_webhook_repository routing map
+//! - subscriptions -> create, list, get and delete of a tenant's webhook subscriptions.
+//! - outbox        -> delivery fan-out, lease-based claims, retry scheduling and settlement.
+//! - rows          -> subscription, delivery and claimed-delivery rows and their mapping.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::application::ports::{RepositoryError, WebhookRepository};
+use crate::domain::{
+    ClaimedWebhookDelivery, TenantId, WebhookDelivery, WebhookEvent, WebhookSubscription,
+    WebhookSubscriptionId,
+};
+
+mod outbox;
+mod rows;
+mod subscriptions;
+
+pub struct PgWebhookRepository {
+    pool: PgPool,
+}
+
+impl PgWebhookRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn query_failed(e: sqlx::Error) -> RepositoryError {
+    RepositoryError::QueryFailed(e.to_string())
+}
+
+#[async_trait]
+impl WebhookRepository for PgWebhookRepository {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), RepositoryError> {
+        subscriptions::create_subscription(&self.pool, subscription).await
+    }
+
+    async fn list_subscriptions(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        subscriptions::list_subscriptions(&self.pool, tenant_id).await
+    }
+
+    async fn get_subscription(
+        &self,
+        id: WebhookSubscriptionId,
+    ) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        subscriptions::get_subscription(&self.pool, id).await
+    }
+
+    async fn delete_subscription(
+        &self,
+        id: WebhookSubscriptionId,
+    ) -> Result<bool, RepositoryError> {
+        subscriptions::delete_subscription(&self.pool, id).await
+    }
+
+    async fn enqueue(&self, event: &WebhookEvent) -> Result<usize, RepositoryError> {
+        outbox::enqueue(&self.pool, event).await
+    }
+
+    async fn claim_due(
+        &self,
+        batch_size: usize,
+        lease: Duration,
+    ) -> Result<Vec<ClaimedWebhookDelivery>, RepositoryError> {
+        outbox::claim_due(&self.pool, batch_size, lease).await
+    }
+
+    async fn mark_delivered(&self, id: Uuid, response_status: u16) -> Result<(), RepositoryError> {
+        outbox::mark_delivered(&self.pool, id, response_status).await
+    }
+
+    async fn schedule_retry(
+        &self,
+        id: Uuid,
+        delay: Duration,
+        error: &str,
+        response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        outbox::schedule_retry(&self.pool, id, delay, error, response_status).await
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        outbox::mark_failed(&self.pool, id, error, response_status).await
+    }
+
+    async fn list_deliveries(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        outbox::list_deliveries(&self.pool, subscription_id, limit).await
+    }
+}
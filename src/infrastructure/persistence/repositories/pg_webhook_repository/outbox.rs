@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::query_failed;
+use super::rows::{ClaimedRow, DeliveryRow};
+use crate::application::ports::RepositoryError;
+use crate::domain::{ClaimedWebhookDelivery, WebhookDelivery, WebhookEvent, WebhookSubscriptionId};
+
+#[instrument(skip(pool, event), fields(event_id = %event.id, event_type = %event.event_type, tenant_id = %event.tenant_id))]
+pub(super) async fn enqueue(pool: &PgPool, event: &WebhookEvent) -> Result<usize, RepositoryError> {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO webhook_deliveries (subscription_id, event_id, event_type, payload)
+        SELECT id, $2, $3, $4
+        FROM webhook_subscriptions
+        WHERE tenant_id = $1 AND $3 = ANY(event_types)
+        "#,
+        event.tenant_id.as_str(),
+        event.id,
+        event.event_type.as_str(),
+        event.payload()
+    )
+    .execute(pool)
+    .await
+    .map_err(query_failed)?;
+
+    Ok(result.rows_affected() as usize)
+}
+
+#[instrument(skip(pool))]
+pub(super) async fn claim_due(
+    pool: &PgPool,
+    batch_size: usize,
+    lease: Duration,
+) -> Result<Vec<ClaimedWebhookDelivery>, RepositoryError> {
+    let rows = sqlx::query_as!(
+        ClaimedRow,
+        r#"
+        UPDATE webhook_deliveries AS d
+        SET attempts = d.attempts + 1,
+            next_attempt_at = NOW() + make_interval(secs => $2),
+            updated_at = NOW()
+        FROM webhook_subscriptions AS s
+        WHERE s.id = d.subscription_id
+          AND d.id IN (
+            SELECT id FROM webhook_deliveries
+            WHERE status = 'pending' AND next_attempt_at <= NOW()
+            ORDER BY next_attempt_at
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+          )
+        RETURNING d.id, d.subscription_id, d.event_id, d.event_type, d.payload, d.status,
+                  d.attempts, d.next_attempt_at, d.response_status, d.last_error,
+                  d.created_at, d.delivered_at, s.url, s.secret
+        "#,
+        batch_size as i64,
+        lease.as_secs_f64()
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(query_failed)?;
+
+    rows.into_iter().map(ClaimedRow::into_claimed).collect()
+}
+
+#[instrument(skip(pool), fields(delivery_id = %id))]
+pub(super) async fn mark_delivered(
+    pool: &PgPool,
+    id: Uuid,
+    response_status: u16,
+) -> Result<(), RepositoryError> {
+    sqlx::query!(
+        r#"
+        UPDATE webhook_deliveries
+        SET status = 'delivered', response_status = $2, last_error = NULL,
+            delivered_at = NOW(), updated_at = NOW()
+        WHERE id = $1
+        "#,
+        id,
+        response_status as i32
+    )
+    .execute(pool)
+    .await
+    .map_err(query_failed)?;
+    Ok(())
+}
+
+#[instrument(skip(pool, error), fields(delivery_id = %id))]
+pub(super) async fn schedule_retry(
+    pool: &PgPool,
+    id: Uuid,
+    delay: Duration,
+    error: &str,
+    response_status: Option<u16>,
+) -> Result<(), RepositoryError> {
+    sqlx::query!(
+        r#"
+        UPDATE webhook_deliveries
+        SET next_attempt_at = NOW() + make_interval(secs => $2),
+            last_error = $3, response_status = $4, updated_at = NOW()
+        WHERE id = $1
+        "#,
+        id,
+        delay.as_secs_f64(),
+        error,
+        response_status.map(i32::from)
+    )
+    .execute(pool)
+    .await
+    .map_err(query_failed)?;
+    Ok(())
+}
+
+#[instrument(skip(pool, error), fields(delivery_id = %id))]
+pub(super) async fn mark_failed(
+    pool: &PgPool,
+    id: Uuid,
+    error: &str,
+    response_status: Option<u16>,
+) -> Result<(), RepositoryError> {
+    sqlx::query!(
+        r#"
+        UPDATE webhook_deliveries
+        SET status = 'failed', last_error = $2, response_status = $3, updated_at = NOW()
+        WHERE id = $1
+        "#,
+        id,
+        error,
+        response_status.map(i32::from)
+    )
+    .execute(pool)
+    .await
+    .map_err(query_failed)?;
+    Ok(())
+}
+
+#[instrument(skip(pool), fields(subscription_id = %subscription_id))]
+pub(super) async fn list_deliveries(
+    pool: &PgPool,
+    subscription_id: WebhookSubscriptionId,
+    limit: usize,
+) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+    let rows = sqlx::query_as!(
+        DeliveryRow,
+        r#"
+        SELECT id, subscription_id, event_id, event_type, payload, status, attempts,
+               next_attempt_at, response_status, last_error, created_at, delivered_at
+        FROM webhook_deliveries
+        WHERE subscription_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+        subscription_id.as_uuid(),
+        limit as i64
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(query_failed)?;
+
+    rows.into_iter().map(DeliveryRow::into_delivery).collect()
+}
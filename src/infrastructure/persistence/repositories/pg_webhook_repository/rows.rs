@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use super::super::parse_tenant_id;
+use crate::application::ports::RepositoryError;
+use crate::domain::{
+    ClaimedWebhookDelivery, WebhookDelivery, WebhookDeliveryStatus, WebhookEventType,
+    WebhookSubscription, WebhookSubscriptionId,
+};
+
+pub(super) struct SubscriptionRow {
+    pub(super) id: Uuid,
+    pub(super) tenant_id: String,
+    pub(super) url: String,
+    pub(super) event_types: Vec<String>,
+    pub(super) secret: String,
+    pub(super) created_at: DateTime<Utc>,
+}
+
+impl SubscriptionRow {
+    pub(super) fn into_subscription(self) -> Result<WebhookSubscription, RepositoryError> {
+        Ok(WebhookSubscription {
+            id: WebhookSubscriptionId::from_uuid(self.id),
+            tenant_id: parse_tenant_id(&self.tenant_id)?,
+            url: self.url,
+            event_types: parse_event_types(&self.event_types)?,
+            secret: self.secret,
+            created_at: self.created_at,
+        })
+    }
+}
+
+pub(super) struct DeliveryRow {
+    pub(super) id: Uuid,
+    pub(super) subscription_id: Uuid,
+    pub(super) event_id: Uuid,
+    pub(super) event_type: String,
+    pub(super) payload: serde_json::Value,
+    pub(super) status: String,
+    pub(super) attempts: i32,
+    pub(super) next_attempt_at: DateTime<Utc>,
+    pub(super) response_status: Option<i32>,
+    pub(super) last_error: Option<String>,
+    pub(super) created_at: DateTime<Utc>,
+    pub(super) delivered_at: Option<DateTime<Utc>>,
+}
+
+impl DeliveryRow {
+    pub(super) fn into_delivery(self) -> Result<WebhookDelivery, RepositoryError> {
+        Ok(WebhookDelivery {
+            id: self.id,
+            subscription_id: WebhookSubscriptionId::from_uuid(self.subscription_id),
+            event_id: self.event_id,
+            event_type: self
+                .event_type
+                .parse()
+                .map_err(RepositoryError::QueryFailed)?,
+            payload: self.payload,
+            status: self
+                .status
+                .parse::<WebhookDeliveryStatus>()
+                .map_err(RepositoryError::QueryFailed)?,
+            attempts: self.attempts.max(0) as u32,
+            next_attempt_at: self.next_attempt_at,
+            response_status: self.response_status.map(|s| s as u16),
+            last_error: self.last_error,
+            created_at: self.created_at,
+            delivered_at: self.delivered_at,
+        })
+    }
+}
+
+pub(super) struct ClaimedRow {
+    pub(super) id: Uuid,
+    pub(super) subscription_id: Uuid,
+    pub(super) event_id: Uuid,
+    pub(super) event_type: String,
+    pub(super) payload: serde_json::Value,
+    pub(super) status: String,
+    pub(super) attempts: i32,
+    pub(super) next_attempt_at: DateTime<Utc>,
+    pub(super) response_status: Option<i32>,
+    pub(super) last_error: Option<String>,
+    pub(super) created_at: DateTime<Utc>,
+    pub(super) delivered_at: Option<DateTime<Utc>>,
+    pub(super) url: String,
+    pub(super) secret: String,
+}
+
+impl ClaimedRow {
+    pub(super) fn into_claimed(self) -> Result<ClaimedWebhookDelivery, RepositoryError> {
+        let delivery = DeliveryRow {
+            id: self.id,
+            subscription_id: self.subscription_id,
+            event_id: self.event_id,
+            event_type: self.event_type,
+            payload: self.payload,
+            status: self.status,
+            attempts: self.attempts,
+            next_attempt_at: self.next_attempt_at,
+            response_status: self.response_status,
+            last_error: self.last_error,
+            created_at: self.created_at,
+            delivered_at: self.delivered_at,
+        }
+        .into_delivery()?;
+        Ok(ClaimedWebhookDelivery {
+            delivery,
+            url: self.url,
+            secret: self.secret,
+        })
+    }
+}
+
+fn parse_event_types(raw: &[String]) -> Result<Vec<WebhookEventType>, RepositoryError> {
+    raw.iter()
+        .map(|s| s.parse::<WebhookEventType>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(RepositoryError::QueryFailed)
+}
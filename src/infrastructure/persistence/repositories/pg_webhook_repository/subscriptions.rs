@@ -0,0 +1,98 @@
+use sqlx::PgPool;
+use tracing::instrument;
+
+use super::query_failed;
+use super::rows::SubscriptionRow;
+use crate::application::ports::RepositoryError;
+use crate::domain::{TenantId, WebhookSubscription, WebhookSubscriptionId};
+
+#[instrument(skip(pool, subscription), fields(subscription_id = %subscription.id, tenant_id = %subscription.tenant_id))]
+pub(super) async fn create_subscription(
+    pool: &PgPool,
+    subscription: &WebhookSubscription,
+) -> Result<(), RepositoryError> {
+    let event_types: Vec<String> = subscription
+        .event_types
+        .iter()
+        .map(|t| t.as_str().to_string())
+        .collect();
+
+    sqlx::query!(
+        r#"
+        INSERT INTO webhook_subscriptions (id, tenant_id, url, event_types, secret, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        "#,
+        subscription.id.as_uuid(),
+        subscription.tenant_id.as_str(),
+        subscription.url,
+        &event_types,
+        subscription.secret,
+        subscription.created_at
+    )
+    .execute(pool)
+    .await
+    .map_err(query_failed)?;
+
+    Ok(())
+}
+
+#[instrument(skip(pool), fields(tenant_id = %tenant_id))]
+pub(super) async fn list_subscriptions(
+    pool: &PgPool,
+    tenant_id: &TenantId,
+) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+    let rows = sqlx::query_as!(
+        SubscriptionRow,
+        r#"
+        SELECT id, tenant_id, url, event_types, secret, created_at
+        FROM webhook_subscriptions
+        WHERE tenant_id = $1
+        ORDER BY created_at DESC
+        "#,
+        tenant_id.as_str()
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(query_failed)?;
+
+    rows.into_iter()
+        .map(SubscriptionRow::into_subscription)
+        .collect()
+}
+
+#[instrument(skip(pool), fields(subscription_id = %id))]
+pub(super) async fn get_subscription(
+    pool: &PgPool,
+    id: WebhookSubscriptionId,
+) -> Result<Option<WebhookSubscription>, RepositoryError> {
+    let row = sqlx::query_as!(
+        SubscriptionRow,
+        r#"
+        SELECT id, tenant_id, url, event_types, secret, created_at
+        FROM webhook_subscriptions
+        WHERE id = $1
+        "#,
+        id.as_uuid()
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(query_failed)?;
+
+    row.map(SubscriptionRow::into_subscription).transpose()
+}
+
+#[instrument(skip(pool), fields(subscription_id = %id))]
+pub(super) async fn delete_subscription(
+    pool: &PgPool,
+    id: WebhookSubscriptionId,
+) -> Result<bool, RepositoryError> {
+    let result = sqlx::query!(
+        "DELETE FROM webhook_subscriptions WHERE id = $1",
+        id.as_uuid()
+    )
+    .execute(pool)
+    .await
+    .map_err(query_failed)?;
+
+    Ok(result.rows_affected() > 0)
+}
@@ -1,4 +1,6 @@
-use crate::application::ports::{CollectionConfig, SearchResult, VectorStore, VectorStoreError};
+use crate::application::ports::{
+    CollectionConfig, SearchFilter, SearchResult, VectorStore, VectorStoreError,
+};
 use crate::domain::{Chunk, ChunkId, DocumentId, Embedding};
 
 pub struct MockVectorStore;
@@ -31,6 +33,7 @@ impl VectorStore for MockVectorStore {
         &self,
         _embedding: &Embedding,
         _top_k: usize,
+        _filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         Ok(vec![SearchResult {
             chunk: Chunk::new(
@@ -46,6 +49,13 @@ impl VectorStore for MockVectorStore {
     async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
         Ok(())
     }
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
 }
 
 pub struct MockVectorStoreLowScore;
@@ -83,6 +93,7 @@ impl VectorStore for MockVectorStoreLowScore {
         &self,
         _embedding: &Embedding,
         _top_k: usize,
+        _filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         Ok(vec![SearchResult {
             chunk: Chunk::new("test chunk".to_string(), DocumentId::new(), Some(1), 0),
@@ -93,4 +104,11 @@ impl VectorStore for MockVectorStoreLowScore {
     async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
         Ok(())
     }
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
 }
@@ -1,11 +1,14 @@
 // @AI-BYPASS-LENGTH
 use async_trait::async_trait;
+use chrono::Utc;
 use qdrant_client::Qdrant;
 use qdrant_client::qdrant::{
-    CreateCollectionBuilder, CreateFieldIndexCollectionBuilder, DeletePointsBuilder, Distance,
-    FieldType, Fusion, NamedVectors, PointId, PointStruct, PointsIdsList, PrefetchQueryBuilder,
-    Query, QueryPointsBuilder, ScoredPoint, SearchPointsBuilder, SparseVectorParamsBuilder,
-    UpsertPointsBuilder, Vector, VectorInput, VectorParamsBuilder, VectorsConfig,
+    Condition, CreateCollectionBuilder, CreateFieldIndexCollectionBuilder, DeletePointsBuilder,
+    Distance, FieldType, Filter, Fusion, Modifier, NamedVectors, PointId, PointStruct,
+    PointsIdsList, PrefetchQueryBuilder, Query, QueryPointsBuilder, Range, ScoredPoint,
+    SearchPointsBuilder, SparseVectorParamsBuilder, SparseVectorsConfigBuilder,
+    UpdateCollectionBuilder, UpsertPointsBuilder, Vector, VectorInput, VectorParamsBuilder,
+    VectorsConfig,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -13,10 +16,12 @@ use tracing::{info, instrument};
 use uuid::Uuid;
 
 use crate::application::ports::{
-    CollectionConfig, DistanceMetric, PayloadFieldType, SearchResult, VectorStore, VectorStoreError,
+    CollectionConfig, DistanceMetric, PayloadFieldType, PayloadIndex, SearchFilter, SearchResult,
+    VectorStore, VectorStoreError,
 };
 use crate::domain::{
-    Chunk, ChunkId, ContentType, DocumentId, DocumentMetadata, Embedding, SparseEmbedding,
+    Chunk, ChunkId, CodeSpan, ContentType, DocumentId, DocumentMetadata, Embedding, SourceLanguage,
+    SparseEmbedding, TenantId,
 };
 
 pub struct QdrantAdapter {
@@ -51,6 +56,17 @@ impl QdrantAdapter {
         }
     }
 
+    /// The `sparse` vector holds BM25 term weights; Qdrant supplies the IDF factor from its
+    /// own corpus statistics at query time.
+    fn sparse_vectors_config() -> SparseVectorsConfigBuilder {
+        let mut sparse = SparseVectorsConfigBuilder::default();
+        sparse.add_named_vector_params(
+            "sparse",
+            SparseVectorParamsBuilder::default().modifier(Modifier::Idf),
+        );
+        sparse
+    }
+
     fn map_field_type(field_type: &PayloadFieldType) -> FieldType {
         match field_type {
             PayloadFieldType::Keyword => FieldType::Keyword,
@@ -60,7 +76,25 @@ impl QdrantAdapter {
         }
     }
 
-    fn build_payload(chunk: &Chunk) -> HashMap<String, serde_json::Value> {
+    async fn create_payload_index(&self, index: &PayloadIndex) -> Result<(), VectorStoreError> {
+        self.client
+            .create_field_index(CreateFieldIndexCollectionBuilder::new(
+                &self.collection_name,
+                &index.field_name,
+                Self::map_field_type(&index.field_type),
+            ))
+            .await
+            .map_err(|e| VectorStoreError::PayloadIndexFailed(e.to_string()))?;
+
+        info!(
+            collection = %self.collection_name,
+            field = %index.field_name,
+            "payload_index_applied"
+        );
+        Ok(())
+    }
+
+    fn build_payload(chunk: &Chunk, ingested_at: i64) -> HashMap<String, serde_json::Value> {
         let mut payload: HashMap<String, serde_json::Value> = HashMap::new();
         payload.insert(
             "document_id".to_string(),
@@ -77,10 +111,21 @@ impl QdrantAdapter {
                 .map(|p| serde_json::Value::Number(p.into()))
                 .unwrap_or(serde_json::Value::Null),
         );
+        payload.insert(
+            "end_page".to_string(),
+            chunk
+                .end_page
+                .map(|p| serde_json::Value::Number(p.into()))
+                .unwrap_or(serde_json::Value::Null),
+        );
         payload.insert(
             "offset".to_string(),
             serde_json::Value::Number((chunk.offset as u64).into()),
         );
+        payload.insert(
+            "ingested_at".to_string(),
+            serde_json::Value::Number(ingested_at.into()),
+        );
 
         if let Some(meta) = &chunk.metadata {
             payload.insert(
@@ -91,12 +136,45 @@ impl QdrantAdapter {
                 "content_type".to_string(),
                 serde_json::Value::String(meta.content_type.as_mime().to_string()),
             );
+            payload.insert(
+                "tenant_id".to_string(),
+                serde_json::Value::String(meta.tenant_id.as_str().to_string()),
+            );
             if let Some(url) = &meta.source_url {
                 payload.insert(
                     "source_url".to_string(),
                     serde_json::Value::String(url.clone()),
                 );
             }
+            if let Some(author) = &meta.author {
+                payload.insert(
+                    "author".to_string(),
+                    serde_json::Value::String(author.clone()),
+                );
+            }
+            if !meta.tags.is_empty() {
+                payload.insert("tags".to_string(), serde_json::json!(meta.tags));
+            }
+            if !meta.attributes.is_empty() {
+                payload.insert("attributes".to_string(), serde_json::json!(meta.attributes));
+            }
+        }
+
+        if !chunk.links.is_empty() {
+            payload.insert("links".to_string(), serde_json::json!(chunk.links));
+        }
+
+        if let Some(code) = &chunk.code {
+            payload.insert("path".to_string(), serde_json::json!(code.path));
+            payload.insert(
+                "language".to_string(),
+                serde_json::json!(code.language.as_str()),
+            );
+            if let Some(symbol) = &code.symbol {
+                payload.insert("symbol".to_string(), serde_json::json!(symbol));
+            }
+            payload.insert("start_line".to_string(), serde_json::json!(code.start_line));
+            payload.insert("end_line".to_string(), serde_json::json!(code.end_line));
         }
 
         if let Some(start_time) = chunk.start_time {
@@ -112,6 +190,58 @@ impl QdrantAdapter {
         payload
     }
 
+    /// Translates the backend-neutral filter into Qdrant conditions.
+    ///
+    /// The tenant condition is always present. Chunks written before tenancy carry no
+    /// `tenant_id` payload and are treated as belonging to the default tenant.
+    pub fn build_filter(filter: &SearchFilter) -> Filter {
+        let tenant = filter.tenant_id.as_str().to_string();
+        let tenant_condition = if filter.tenant_id.is_default() {
+            Condition::from(Filter::should([
+                Condition::matches("tenant_id", tenant),
+                Condition::is_empty("tenant_id"),
+            ]))
+        } else {
+            Condition::matches("tenant_id", tenant)
+        };
+
+        let mut conditions = vec![tenant_condition];
+        if !filter.document_ids.is_empty() {
+            let ids: Vec<String> = filter
+                .document_ids
+                .iter()
+                .map(|id| id.as_uuid().to_string())
+                .collect();
+            conditions.push(Condition::matches("document_id", ids));
+        }
+        if !filter.content_types.is_empty() {
+            let mimes: Vec<String> = filter
+                .content_types
+                .iter()
+                .map(|ct| ct.as_mime().to_string())
+                .collect();
+            conditions.push(Condition::matches("content_type", mimes));
+        }
+        if !filter.titles.is_empty() {
+            conditions.push(Condition::matches("title", filter.titles.clone()));
+        }
+        if !filter.tags.is_empty() {
+            conditions.push(Condition::matches("tags", filter.tags.clone()));
+        }
+        if filter.ingested_after.is_some() || filter.ingested_before.is_some() {
+            conditions.push(Condition::range(
+                "ingested_at",
+                Range {
+                    gte: filter.ingested_after.map(|t| t.timestamp() as f64),
+                    lte: filter.ingested_before.map(|t| t.timestamp() as f64),
+                    ..Default::default()
+                },
+            ));
+        }
+
+        Filter::must(conditions)
+    }
+
     fn map_scored_point(point: ScoredPoint) -> Option<SearchResult> {
         let payload = point.payload;
 
@@ -131,6 +261,12 @@ impl QdrantAdapter {
             .get("page")
             .and_then(|v| v.as_integer())
             .map(|v| v as u32);
+        // Chunks written before page ranges only carry `page`.
+        let end_page = payload
+            .get("end_page")
+            .and_then(|v| v.as_integer())
+            .map(|v| v as u32)
+            .or(page);
         let offset = payload.get("offset")?.as_integer()? as usize;
 
         let metadata = payload.get("title").and_then(|v| v.as_str()).map(|title| {
@@ -143,10 +279,44 @@ impl QdrantAdapter {
                 .get("source_url")
                 .and_then(|v| v.as_str())
                 .map(|s| s.to_string());
+            let tags = payload
+                .get("tags")
+                .and_then(|v| v.as_list())
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let author = payload
+                .get("author")
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            let attributes = payload
+                .get("attributes")
+                .and_then(|v| v.as_struct())
+                .map(|object| {
+                    object
+                        .fields
+                        .iter()
+                        .filter_map(|(key, v)| v.as_str().map(|s| (key.clone(), s.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let tenant_id = payload
+                .get("tenant_id")
+                .and_then(|v| v.as_str())
+                .and_then(|s| TenantId::parse(s.as_str()))
+                .unwrap_or_default();
             Arc::new(DocumentMetadata {
                 title: title.to_string(),
                 content_type,
                 source_url,
+                author,
+                tags,
+                attributes,
+                tenant_id,
             })
         });
 
@@ -155,14 +325,30 @@ impl QdrantAdapter {
             .and_then(|v| v.as_double())
             .map(|v| v as f32);
 
+        let links = payload
+            .get("links")
+            .and_then(|v| v.as_list())
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let code = Self::parse_code_span(&payload);
+
         let chunk = Chunk {
             id: ChunkId::from_uuid(chunk_id),
             text,
             document_id: DocumentId::from_uuid(document_id),
             page,
+            end_page,
             offset,
             metadata,
             start_time,
+            links,
+            code,
         };
 
         if point.score.is_nan() {
@@ -176,6 +362,27 @@ impl QdrantAdapter {
         })
     }
 
+    /// Source-file location of a chunk written by the code splitter.
+    fn parse_code_span(
+        payload: &HashMap<String, qdrant_client::qdrant::Value>,
+    ) -> Option<CodeSpan> {
+        let path = payload.get("path")?.as_str()?.to_string();
+        let language = SourceLanguage::parse(payload.get("language")?.as_str()?)?;
+        let symbol = payload
+            .get("symbol")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+        let start_line = payload.get("start_line")?.as_integer()? as u32;
+        let end_line = payload.get("end_line")?.as_integer()? as u32;
+        Some(CodeSpan {
+            path,
+            language,
+            symbol,
+            start_line,
+            end_line,
+        })
+    }
+
     fn log_nan_filtered(total: usize, valid: usize) {
         if valid < total {
             tracing::warn!(
@@ -227,7 +434,7 @@ impl VectorStore for QdrantAdapter {
         }
 
         let builder = if config.hybrid {
-            use qdrant_client::qdrant::{SparseVectorsConfigBuilder, VectorsConfigBuilder};
+            use qdrant_client::qdrant::VectorsConfigBuilder;
 
             let mut vectors = VectorsConfigBuilder::default();
             vectors.add_named_vector_params(
@@ -238,12 +445,9 @@ impl VectorStore for QdrantAdapter {
                 ),
             );
 
-            let mut sparse = SparseVectorsConfigBuilder::default();
-            sparse.add_named_vector_params("sparse", SparseVectorParamsBuilder::default());
-
             CreateCollectionBuilder::new(&self.collection_name)
                 .vectors_config(vectors)
-                .sparse_vectors_config(sparse)
+                .sparse_vectors_config(Self::sparse_vectors_config())
         } else {
             let vectors_config = VectorsConfig::from(VectorParamsBuilder::new(
                 config.vector_dimensions,
@@ -260,20 +464,7 @@ impl VectorStore for QdrantAdapter {
         info!(collection = %self.collection_name, hybrid = config.hybrid, "collection_created");
 
         for index in &config.payload_indexes {
-            self.client
-                .create_field_index(CreateFieldIndexCollectionBuilder::new(
-                    &self.collection_name,
-                    &index.field_name,
-                    Self::map_field_type(&index.field_type),
-                ))
-                .await
-                .map_err(|e| VectorStoreError::PayloadIndexFailed(e.to_string()))?;
-
-            info!(
-                collection = %self.collection_name,
-                field = %index.field_name,
-                "payload_index_applied"
-            );
+            self.create_payload_index(index).await?;
         }
 
         Ok(true)
@@ -345,6 +536,64 @@ impl VectorStore for QdrantAdapter {
         Ok(is_hybrid)
     }
 
+    #[instrument(skip(self), fields(collection = %self.collection_name))]
+    async fn ensure_sparse_idf(&self) -> Result<bool, VectorStoreError> {
+        let collection_info = self
+            .client
+            .collection_info(&self.collection_name)
+            .await
+            .map_err(|e| VectorStoreError::ConnectionFailed(e.to_string()))?;
+
+        let has_idf = collection_info
+            .result
+            .and_then(|r| r.config)
+            .and_then(|c| c.params)
+            .and_then(|p| p.sparse_vectors_config)
+            .and_then(|sv| sv.map.get("sparse").and_then(|params| params.modifier))
+            .is_some_and(|modifier| modifier == Modifier::Idf as i32);
+
+        if has_idf {
+            return Ok(false);
+        }
+
+        self.client
+            .update_collection(
+                UpdateCollectionBuilder::new(&self.collection_name)
+                    .sparse_vectors_config(Self::sparse_vectors_config()),
+            )
+            .await
+            .map_err(|e| VectorStoreError::CollectionCreationFailed(e.to_string()))?;
+
+        info!(collection = %self.collection_name, "sparse_idf_enabled");
+        Ok(true)
+    }
+
+    #[instrument(skip(self, indexes), fields(collection = %self.collection_name))]
+    async fn ensure_payload_indexes(
+        &self,
+        indexes: &[PayloadIndex],
+    ) -> Result<Vec<String>, VectorStoreError> {
+        let collection_info = self
+            .client
+            .collection_info(&self.collection_name)
+            .await
+            .map_err(|e| VectorStoreError::ConnectionFailed(e.to_string()))?;
+        let schema = collection_info
+            .result
+            .map(|r| r.payload_schema)
+            .unwrap_or_default();
+
+        let mut created = Vec::new();
+        for index in indexes
+            .iter()
+            .filter(|index| !schema.contains_key(&index.field_name))
+        {
+            self.create_payload_index(index).await?;
+            created.push(index.field_name.clone());
+        }
+        Ok(created)
+    }
+
     #[instrument(skip(self), fields(collection = %self.collection_name))]
     async fn delete_collection(&self) -> Result<(), VectorStoreError> {
         if !self.collection_exists().await? {
@@ -374,11 +623,12 @@ impl VectorStore for QdrantAdapter {
 
         Self::log_bad_embeddings(embeddings);
 
+        let ingested_at = Utc::now().timestamp();
         let points: Vec<PointStruct> = chunks
             .iter()
             .zip(embeddings.iter())
             .map(|(chunk, embedding)| {
-                let payload = Self::build_payload(chunk);
+                let payload = Self::build_payload(chunk, ingested_at);
                 PointStruct::new(
                     PointId::from(chunk.id.as_uuid().to_string()),
                     embedding.values.clone(),
@@ -411,12 +661,13 @@ impl VectorStore for QdrantAdapter {
 
         Self::log_bad_embeddings(dense);
 
+        let ingested_at = Utc::now().timestamp();
         let points: Vec<PointStruct> = chunks
             .iter()
             .zip(dense.iter())
             .zip(sparse.iter())
             .map(|((chunk, d_emb), s_emb)| {
-                let payload = Self::build_payload(chunk);
+                let payload = Self::build_payload(chunk, ingested_at);
 
                 let named = NamedVectors::default()
                     .add_vector("dense", Vector::new_dense(d_emb.values.clone()))
@@ -442,24 +693,26 @@ impl VectorStore for QdrantAdapter {
         Ok(())
     }
 
-    #[instrument(skip(self, embedding), fields(collection = %self.collection_name, top_k = top_k))]
+    #[instrument(skip(self, embedding, filter), fields(collection = %self.collection_name, top_k = top_k, tenant_id = %filter.tenant_id, filtered = !filter.is_empty()))]
     async fn search(
         &self,
         embedding: &Embedding,
         top_k: usize,
+        filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         Self::log_bad_query_embedding(embedding);
 
+        let request = SearchPointsBuilder::new(
+            &self.collection_name,
+            embedding.values.clone(),
+            top_k as u64,
+        )
+        .with_payload(true)
+        .filter(Self::build_filter(filter));
+
         let search_result = self
             .client
-            .search_points(
-                SearchPointsBuilder::new(
-                    &self.collection_name,
-                    embedding.values.clone(),
-                    top_k as u64,
-                )
-                .with_payload(true),
-            )
+            .search_points(request)
             .await
             .map_err(|e| VectorStoreError::SearchFailed(e.to_string()))?;
 
@@ -474,23 +727,29 @@ impl VectorStore for QdrantAdapter {
         Ok(results)
     }
 
-    #[instrument(skip(self, dense, sparse), fields(collection = %self.collection_name, top_k = top_k))]
+    #[instrument(skip(self, dense, sparse, filter), fields(collection = %self.collection_name, top_k = top_k, tenant_id = %filter.tenant_id, filtered = !filter.is_empty()))]
     async fn search_hybrid(
         &self,
         dense: &Embedding,
         sparse: &SparseEmbedding,
         top_k: usize,
+        filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         Self::log_bad_query_embedding(dense);
 
         let prefetch_limit = (top_k as u64).saturating_mul(2).max(10);
 
+        // Filter each prefetch so both candidate lists are drawn from the matching subset
+        // before fusion, rather than fusing top-k of the whole collection and discarding.
+        let qdrant_filter = Self::build_filter(filter);
+
         let dense_prefetch = PrefetchQueryBuilder::default()
             .query(Query::new_nearest(VectorInput::new_dense(
                 dense.values.clone(),
             )))
             .using("dense")
-            .limit(prefetch_limit);
+            .limit(prefetch_limit)
+            .filter(qdrant_filter.clone());
 
         let sparse_prefetch = PrefetchQueryBuilder::default()
             .query(Query::new_nearest(VectorInput::new_sparse(
@@ -498,7 +757,8 @@ impl VectorStore for QdrantAdapter {
                 sparse.values.clone(),
             )))
             .using("sparse")
-            .limit(prefetch_limit);
+            .limit(prefetch_limit)
+            .filter(qdrant_filter);
 
         let response = self
             .client
@@ -542,4 +802,35 @@ impl VectorStore for QdrantAdapter {
         info!(collection = %self.collection_name, count = chunk_ids.len(), "points_deleted");
         Ok(())
     }
+
+    #[instrument(skip(self, keep), fields(collection = %self.collection_name, document_id = %document_id.as_uuid(), keep = keep.len()))]
+    async fn delete_by_document(
+        &self,
+        document_id: DocumentId,
+        keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        let mut filter = Filter::must([Condition::matches(
+            "document_id",
+            document_id.as_uuid().to_string(),
+        )]);
+        if !keep.is_empty() {
+            let kept_ids: Vec<PointId> = keep
+                .iter()
+                .map(|id| PointId::from(id.as_uuid().to_string()))
+                .collect();
+            filter.must_not.push(Condition::has_id(kept_ids));
+        }
+
+        self.client
+            .delete_points(
+                DeletePointsBuilder::new(&self.collection_name)
+                    .points(filter)
+                    .wait(true),
+            )
+            .await
+            .map_err(|e| VectorStoreError::DeleteFailed(e.to_string()))?;
+
+        info!(collection = %self.collection_name, "document_points_deleted");
+        Ok(())
+    }
 }
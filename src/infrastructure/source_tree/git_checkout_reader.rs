@@ -0,0 +1,98 @@
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use ignore::WalkBuilder;
+
+use crate::application::ports::{SourceFile, SourceTreeError, SourceTreeReader};
+
+/// Walks a local git checkout with the same ignore rules git applies: `.gitignore` files at
+/// every level, `.git/info/exclude` and the global excludes file.
+#[derive(Debug, Default, Clone)]
+pub struct GitCheckoutReader;
+
+impl GitCheckoutReader {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> SourceTreeError {
+    match error.kind() {
+        ErrorKind::NotFound => SourceTreeError::NotFound(path.display().to_string()),
+        _ => SourceTreeError::Io(format!("{}: {}", path.display(), error)),
+    }
+}
+
+#[async_trait]
+impl SourceTreeReader for GitCheckoutReader {
+    async fn resolve(&self, root: &Path) -> Result<PathBuf, SourceTreeError> {
+        let canonical = tokio::fs::canonicalize(root)
+            .await
+            .map_err(|e| io_error(root, e))?;
+        // `.git` is a directory in a regular clone and a file in a worktree or submodule.
+        if tokio::fs::symlink_metadata(canonical.join(".git"))
+            .await
+            .is_err()
+        {
+            return Err(SourceTreeError::NotACheckout(
+                canonical.display().to_string(),
+            ));
+        }
+        Ok(canonical)
+    }
+
+    async fn list_files(&self, root: &Path) -> Result<Vec<SourceFile>, SourceTreeError> {
+        let root = root.to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            let walker = WalkBuilder::new(&root)
+                .hidden(true)
+                .git_ignore(true)
+                .git_exclude(true)
+                .git_global(true)
+                .require_git(false)
+                .follow_links(false)
+                .build();
+
+            let mut files = Vec::new();
+            for entry in walker {
+                let entry = entry.map_err(|e| SourceTreeError::Io(e.to_string()))?;
+                if !entry.file_type().is_some_and(|t| t.is_file()) {
+                    continue;
+                }
+                let Ok(relative) = entry.path().strip_prefix(&root) else {
+                    continue;
+                };
+                let Some(relative) = relative.to_str() else {
+                    tracing::warn!(path = %entry.path().display(), "Skipping non UTF-8 path");
+                    continue;
+                };
+                let size_bytes = entry
+                    .metadata()
+                    .map_err(|e| SourceTreeError::Io(e.to_string()))?
+                    .len();
+                files.push(SourceFile {
+                    path: relative.replace('\\', "/"),
+                    size_bytes,
+                });
+            }
+            files.sort_by(|a, b| a.path.cmp(&b.path));
+            Ok(files)
+        })
+        .await
+        .map_err(|e| SourceTreeError::Io(e.to_string()))?
+    }
+
+    async fn read(&self, root: &Path, path: &str) -> Result<Vec<u8>, SourceTreeError> {
+        let joined = root.join(path);
+        let canonical = tokio::fs::canonicalize(&joined)
+            .await
+            .map_err(|e| io_error(&joined, e))?;
+        if !canonical.starts_with(root) {
+            return Err(SourceTreeError::OutsideCheckout(path.to_string()));
+        }
+        tokio::fs::read(&canonical)
+            .await
+            .map_err(|e| io_error(&canonical, e))
+    }
+}
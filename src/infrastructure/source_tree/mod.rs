@@ -0,0 +1,5 @@
+//! Read access to local source checkouts for repository ingestion.
+
+mod git_checkout_reader;
+
+pub use git_checkout_reader::GitCheckoutReader;
@@ -83,6 +83,23 @@ impl StagingStore for LocalStagingStore {
         Ok(bytes.to_vec())
     }
 
+    async fn fetch_stream(
+        &self,
+        path: &StoragePath,
+    ) -> Result<BoxStream<'static, Result<Bytes, StagingStoreError>>, StagingStoreError> {
+        let store_path = StorePath::from(path.as_str());
+        let result = self
+            .inner
+            .get(&store_path)
+            .await
+            .map_err(|e| StagingStoreError::NotFound(e.to_string()))?;
+
+        Ok(result
+            .into_stream()
+            .map(|chunk| chunk.map_err(|e| StagingStoreError::DownloadFailed(e.to_string())))
+            .boxed())
+    }
+
     async fn delete(&self, path: &StoragePath) -> Result<(), StagingStoreError> {
         let store_path = StorePath::from(path.as_str());
         self.inner
@@ -0,0 +1,154 @@
+mod stop_words;
+mod ukrainian_stemmer;
+
+use std::collections::HashSet;
+
+use rust_stemmers::{Algorithm, Stemmer};
+use unicode_segmentation::UnicodeSegmentation;
+use whatlang::{Detector, Lang};
+
+/// Languages with a dedicated analyzer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnalyzerLanguage {
+    English,
+    German,
+    Ukrainian,
+}
+
+impl AnalyzerLanguage {
+    fn whatlang(self) -> Lang {
+        match self {
+            Self::English => Lang::Eng,
+            Self::German => Lang::Deu,
+            Self::Ukrainian => Lang::Ukr,
+        }
+    }
+
+    fn stop_words(self) -> &'static [&'static str] {
+        match self {
+            Self::English => stop_words::ENGLISH,
+            Self::German => stop_words::GERMAN,
+            Self::Ukrainian => stop_words::UKRAINIAN,
+        }
+    }
+}
+
+/// Tokenizer, stop-word filter and optional stemmer for one language.
+struct LanguageAnalyzer {
+    language: AnalyzerLanguage,
+    stop_words: HashSet<&'static str>,
+    stemmer: Option<Stemmer>,
+    stemming: bool,
+}
+
+impl LanguageAnalyzer {
+    fn new(language: AnalyzerLanguage, stemming: bool) -> Self {
+        let stemmer = match language {
+            AnalyzerLanguage::English if stemming => Some(Stemmer::create(Algorithm::English)),
+            AnalyzerLanguage::German if stemming => Some(Stemmer::create(Algorithm::German)),
+            _ => None,
+        };
+        Self {
+            language,
+            stop_words: language.stop_words().iter().copied().collect(),
+            stemmer,
+            stemming,
+        }
+    }
+
+    fn analyze(&self, text: &str) -> Vec<String> {
+        text.unicode_words()
+            .map(str::to_lowercase)
+            .filter(|w| w.chars().count() > 1 && !self.stop_words.contains(w.as_str()))
+            .map(|w| self.stem(w))
+            .collect()
+    }
+
+    fn stem(&self, word: String) -> String {
+        match (&self.stemmer, self.language) {
+            (Some(stemmer), _) => stemmer.stem(&word).into_owned(),
+            (None, AnalyzerLanguage::Ukrainian) if self.stemming => ukrainian_stemmer::stem(&word),
+            (None, _) => word,
+        }
+    }
+}
+
+/// Turns text into index terms for lexical search.
+///
+/// Configured with one language, every text goes through that language's analyzer. With
+/// several, each document is analyzed in its detected language (the first configured
+/// language when detection is inconclusive), while a query — usually too short to detect
+/// reliably — is analyzed in every configured language and the terms are merged, so it
+/// matches whichever analyzer produced the document terms.
+pub struct TextAnalyzer {
+    analyzers: Vec<LanguageAnalyzer>,
+    detector: Option<Detector>,
+}
+
+impl TextAnalyzer {
+    /// `languages` must not be empty; duplicates are ignored.
+    pub fn new(languages: &[AnalyzerLanguage], stemming: bool) -> Self {
+        let mut unique: Vec<AnalyzerLanguage> = Vec::new();
+        for language in languages {
+            if !unique.contains(language) {
+                unique.push(*language);
+            }
+        }
+        if unique.is_empty() {
+            unique.push(AnalyzerLanguage::English);
+        }
+
+        let detector = (unique.len() > 1)
+            .then(|| Detector::with_allowlist(unique.iter().map(|l| l.whatlang()).collect()));
+
+        Self {
+            analyzers: unique
+                .into_iter()
+                .map(|l| LanguageAnalyzer::new(l, stemming))
+                .collect(),
+            detector,
+        }
+    }
+
+    /// Language a document will be analyzed in.
+    pub fn detect_language(&self, text: &str) -> AnalyzerLanguage {
+        self.document_analyzer(text).language
+    }
+
+    pub fn analyze_document(&self, text: &str) -> Vec<String> {
+        self.document_analyzer(text).analyze(text)
+    }
+
+    /// Distinct query terms across every configured language.
+    pub fn analyze_query(&self, text: &str) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.analyzers
+            .iter()
+            .flat_map(|a| a.analyze(text))
+            .filter(|term| seen.insert(term.clone()))
+            .collect()
+    }
+
+    fn document_analyzer(&self, text: &str) -> &LanguageAnalyzer {
+        let fallback = &self.analyzers[0];
+        let Some(detector) = &self.detector else {
+            return fallback;
+        };
+
+        detector
+            .detect(text)
+            .filter(|info| info.is_reliable())
+            .and_then(|info| {
+                self.analyzers
+                    .iter()
+                    .find(|a| a.language.whatlang() == info.lang())
+            })
+            .unwrap_or(fallback)
+    }
+}
+
+impl Default for TextAnalyzer {
+    fn default() -> Self {
+        Self::new(&[AnalyzerLanguage::English], true)
+    }
+}
@@ -0,0 +1,148 @@
+pub(super) const ENGLISH: &[&str] = &[
+    "a", "an", "the", "is", "are", "was", "were", "be", "been", "being", "have", "has", "had",
+    "do", "does", "did", "will", "would", "could", "should", "may", "might", "shall", "can",
+    "need", "dare", "ought", "in", "on", "at", "to", "for", "of", "with", "by", "from", "as",
+    "into", "through", "during", "before", "after", "above", "below", "and", "or", "but", "if",
+    "then", "that", "this", "it", "its", "not", "no", "nor", "so", "yet", "both", "either",
+    "neither",
+];
+
+pub(super) const GERMAN: &[&str] = &[
+    "der", "die", "das", "den", "dem", "des", "ein", "eine", "einer", "eines", "einem", "einen",
+    "und", "oder", "aber", "doch", "sondern", "denn", "wenn", "als", "ob", "dass", "weil", "da",
+    "wie", "wo", "was", "wer", "ist", "sind", "war", "waren", "bin", "bist", "sein", "seid",
+    "gewesen", "hat", "haben", "hatte", "hatten", "habe", "hast", "wird", "werden", "wurde",
+    "wurden", "worden", "kann", "können", "konnte", "muss", "müssen", "soll", "sollen", "will",
+    "wollen", "darf", "mag", "in", "im", "an", "am", "auf", "aus", "bei", "mit", "nach", "von",
+    "vom", "zu", "zum", "zur", "für", "über", "unter", "vor", "hinter", "neben", "zwischen",
+    "durch", "gegen", "ohne", "um", "bis", "seit", "ich", "du", "er", "sie", "es", "wir", "ihr",
+    "mich", "mir", "dich", "dir", "ihn", "ihm", "uns", "euch", "ihnen", "sich", "mein", "dein",
+    "unser", "euer", "dies", "diese", "dieser", "dieses", "diesem", "diesen", "jener", "nicht",
+    "kein", "keine", "keinen", "keinem", "keiner", "auch", "noch", "nur", "schon", "sehr", "so",
+    "dann", "hier", "dort", "man", "alle", "allem", "allen", "aller", "alles",
+];
+
+pub(super) const UKRAINIAN: &[&str] = &[
+    "і",
+    "й",
+    "та",
+    "або",
+    "але",
+    "а",
+    "що",
+    "як",
+    "це",
+    "цей",
+    "ця",
+    "ці",
+    "цього",
+    "цієї",
+    "цих",
+    "той",
+    "те",
+    "ті",
+    "того",
+    "тієї",
+    "тих",
+    "в",
+    "у",
+    "на",
+    "до",
+    "з",
+    "із",
+    "зі",
+    "від",
+    "для",
+    "по",
+    "при",
+    "про",
+    "під",
+    "над",
+    "між",
+    "через",
+    "без",
+    "після",
+    "перед",
+    "за",
+    "не",
+    "ні",
+    "так",
+    "же",
+    "ж",
+    "би",
+    "б",
+    "чи",
+    "лише",
+    "тільки",
+    "вже",
+    "ще",
+    "також",
+    "теж",
+    "є",
+    "був",
+    "була",
+    "було",
+    "були",
+    "бути",
+    "буде",
+    "будуть",
+    "я",
+    "ти",
+    "він",
+    "вона",
+    "воно",
+    "ми",
+    "ви",
+    "вони",
+    "мене",
+    "мені",
+    "тебе",
+    "тобі",
+    "його",
+    "йому",
+    "її",
+    "їй",
+    "нас",
+    "нам",
+    "вас",
+    "вам",
+    "їх",
+    "їм",
+    "себе",
+    "собі",
+    "свій",
+    "своя",
+    "своє",
+    "свої",
+    "мій",
+    "моя",
+    "моє",
+    "мої",
+    "наш",
+    "ваш",
+    "який",
+    "яка",
+    "яке",
+    "які",
+    "якого",
+    "якої",
+    "яких",
+    "де",
+    "коли",
+    "тому",
+    "тоді",
+    "тут",
+    "там",
+    "дуже",
+    "може",
+    "можна",
+    "треба",
+    "якщо",
+    "щоб",
+    "бо",
+    "адже",
+    "все",
+    "всі",
+    "весь",
+    "вся",
+];
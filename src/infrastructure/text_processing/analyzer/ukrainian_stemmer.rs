@@ -0,0 +1,111 @@
+//! Light suffix-stripping stemmer for Ukrainian.
+//!
+//! Snowball ships no Ukrainian algorithm, so this follows the structure of the Snowball
+//! Russian stemmer (RV region, perfective gerund → reflexive → adjectival / verb / noun
+//! endings, then `и`/`ь` cleanup) with Ukrainian inflections. It favours conflating
+//! inflected forms of the same word over linguistic precision.
+
+const VOWELS: &[char] = &['а', 'е', 'є', 'и', 'і', 'ї', 'о', 'у', 'ю', 'я'];
+
+const PERFECTIVE_GERUND: &[&str] = &["вшись", "вши"];
+
+const REFLEXIVE: &[&str] = &["ся", "сь", "си"];
+
+const ADJECTIVAL: &[&str] = &[
+    "ими", "іми", "ого", "ому", "ій", "ий", "ої", "ою", "ім", "им", "их", "іх", "ая", "яя", "ее",
+    "ие", "а", "е", "є", "я", "і",
+];
+
+const PARTICIPLE: &[&str] = &["ан", "ян", "ен", "ін", "ит", "ут", "юч", "уч", "ач", "яч"];
+
+const VERB: &[&str] = &[
+    "ували",
+    "ювали",
+    "ування",
+    "ювання",
+    "уватися",
+    "ювати",
+    "увати",
+    "ати",
+    "яти",
+    "іти",
+    "ити",
+    "ать",
+    "ять",
+    "али",
+    "яли",
+    "ила",
+    "или",
+    "іла",
+    "ав",
+    "ив",
+    "ів",
+    "ла",
+    "ли",
+    "ло",
+    "уть",
+    "ють",
+    "емо",
+    "ємо",
+    "имо",
+    "ете",
+    "єте",
+    "ите",
+    "еш",
+    "єш",
+    "иш",
+    "ить",
+    "іть",
+    "ме",
+    "ть",
+    "ю",
+    "у",
+];
+
+const NOUN: &[&str] = &[
+    "ями", "ами", "ові", "еві", "єві", "ах", "ях", "ам", "ям", "ом", "ем", "єм", "ою", "ею", "єю",
+    "ів", "їв", "ей", "ий", "ій", "а", "я", "о", "е", "є", "и", "і", "ї", "у", "ю", "й", "ь",
+];
+
+/// Stems a lowercase Ukrainian word. Words without a vowel are returned unchanged.
+pub(super) fn stem(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    let Some(first_vowel) = chars.iter().position(|c| VOWELS.contains(c)) else {
+        return word.to_string();
+    };
+    let prefix: String = chars[..=first_vowel].iter().collect();
+    let mut rv: String = chars[first_vowel + 1..].iter().collect();
+
+    if !strip_longest(&mut rv, PERFECTIVE_GERUND) {
+        strip_longest(&mut rv, REFLEXIVE);
+        if strip_longest(&mut rv, ADJECTIVAL) {
+            strip_longest(&mut rv, PARTICIPLE);
+        } else if !strip_longest(&mut rv, VERB) {
+            strip_longest(&mut rv, NOUN);
+        }
+    }
+
+    strip_longest(&mut rv, &["и", "і"]);
+    if rv.ends_with("нн") {
+        rv.pop();
+    }
+    strip_longest(&mut rv, &["ь"]);
+
+    prefix + &rv
+}
+
+/// Removes the longest matching suffix, keeping at least one character of the region.
+fn strip_longest(region: &mut String, suffixes: &[&str]) -> bool {
+    let longest = suffixes
+        .iter()
+        .filter(|s| region.ends_with(*s) && region.len() > s.len())
+        .max_by_key(|s| s.len());
+
+    match longest {
+        Some(suffix) => {
+            region.truncate(region.len() - suffix.len());
+            true
+        }
+        None => false,
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -6,7 +7,7 @@ use reqwest::Client;
 use serde::Deserialize;
 
 use crate::application::ports::{FileLoader, FileLoaderError};
-use crate::domain::{ContentType, Document};
+use crate::domain::{BoundingRegion, ContentType, Document, PageSegment};
 
 pub const POLL_TIMEOUT: Duration = Duration::from_secs(300);
 pub const INITIAL_BACKOFF: Duration = Duration::from_secs(2);
@@ -37,7 +38,7 @@ impl AzureDocIntelAdapter {
         let body = serde_json::json!({ "base64Source": b64 });
 
         let url = format!(
-            "{}/documentintelligence/documentModels/prebuilt-layout:analyze?api-version={}&outputContentFormat=markdown",
+            "{}/documentintelligence/documentModels/prebuilt-layout:analyze?api-version={}&outputContentFormat=markdown&stringIndexType=unicodeCodePoint",
             self.endpoint, API_VERSION
         );
 
@@ -73,7 +74,10 @@ impl AzureDocIntelAdapter {
         Ok(operation_url)
     }
 
-    async fn poll_until_complete(&self, operation_url: &str) -> Result<String, FileLoaderError> {
+    async fn poll_until_complete(
+        &self,
+        operation_url: &str,
+    ) -> Result<Option<AnalyzeResult>, FileLoaderError> {
         let poll_future = async {
             let mut backoff = INITIAL_BACKOFF;
 
@@ -112,10 +116,7 @@ impl AzureDocIntelAdapter {
                 })?;
 
                 match result.status.as_str() {
-                    "succeeded" => {
-                        let content = result.analyze_result.map(|r| r.content).unwrap_or_default();
-                        return Ok(content);
-                    }
+                    "succeeded" => return Ok(result.analyze_result),
                     "failed" => {
                         return Err(FileLoaderError::ExtractionFailed(
                             "Azure Document Intelligence analysis failed".to_string(),
@@ -148,11 +149,11 @@ impl FileLoader for AzureDocIntelAdapter {
             filename = %document.filename
         )
     )]
-    async fn extract_text(
+    async fn extract_pages(
         &self,
         data: &[u8],
         document: &Document,
-    ) -> Result<String, FileLoaderError> {
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
         if document.content_type != ContentType::Pdf {
             return Err(FileLoaderError::UnsupportedContentType(
                 document.content_type.as_mime().to_string(),
@@ -160,13 +161,17 @@ impl FileLoader for AzureDocIntelAdapter {
         }
 
         let operation_url = self.submit(data).await?;
-        let markdown = self.poll_until_complete(&operation_url).await?;
+        let pages = self
+            .poll_until_complete(&operation_url)
+            .await?
+            .map(AnalyzeResult::into_pages)
+            .unwrap_or_default();
 
-        if markdown.trim().is_empty() {
+        if pages.iter().all(|p| p.text.trim().is_empty()) {
             return Err(FileLoaderError::NoTextFound(document.filename.clone()));
         }
 
-        Ok(markdown)
+        Ok(pages)
     }
 }
 
@@ -180,4 +185,81 @@ pub struct AnalyzeResponse {
 #[derive(Deserialize)]
 pub struct AnalyzeResult {
     pub content: String,
+    #[serde(default)]
+    pub pages: Vec<AnalyzedPage>,
+    #[serde(default)]
+    pub paragraphs: Vec<AnalyzedParagraph>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzedPage {
+    pub page_number: u32,
+    #[serde(default)]
+    pub spans: Vec<TextSpan>,
+}
+
+/// Range of `AnalyzeResult::content`, in Unicode code points.
+#[derive(Deserialize)]
+pub struct TextSpan {
+    pub offset: usize,
+    pub length: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzedParagraph {
+    #[serde(default)]
+    pub bounding_regions: Vec<AnalyzedRegion>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyzedRegion {
+    pub page_number: u32,
+    #[serde(default)]
+    pub polygon: Vec<f32>,
+}
+
+impl AnalyzeResult {
+    /// Cuts `content` into one segment per page along the page spans, attaching the
+    /// paragraph bounding regions of each page. Results without page information yield
+    /// the whole content as a single unpaged segment.
+    pub fn into_pages(self) -> Vec<PageSegment> {
+        if self.pages.is_empty() {
+            return vec![PageSegment::unpaged(self.content)];
+        }
+
+        let mut regions: HashMap<u32, Vec<BoundingRegion>> = HashMap::new();
+        for region in self.paragraphs.into_iter().flat_map(|p| p.bounding_regions) {
+            regions
+                .entry(region.page_number)
+                .or_default()
+                .push(BoundingRegion {
+                    polygon: region.polygon,
+                });
+        }
+
+        let chars: Vec<char> = self.content.chars().collect();
+        self.pages
+            .into_iter()
+            .filter_map(|page| {
+                let text = page
+                    .spans
+                    .iter()
+                    .map(|span| {
+                        let start = span.offset.min(chars.len());
+                        let end = (span.offset + span.length).min(chars.len());
+                        chars[start..end].iter().collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let text = text.trim();
+                (!text.is_empty()).then(|| {
+                    PageSegment::new(page.page_number, text)
+                        .with_regions(regions.remove(&page.page_number).unwrap_or_default())
+                })
+            })
+            .collect()
+    }
 }
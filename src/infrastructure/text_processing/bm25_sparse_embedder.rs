@@ -1,32 +1,63 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
-use unicode_segmentation::UnicodeSegmentation;
 
 use crate::application::ports::{EmbedderError, SparseEmbedder};
 use crate::domain::SparseEmbedding;
 
-const STOP_WORDS: &[&str] = &[
-    "a", "an", "the", "is", "are", "was", "were", "be", "been", "being", "have", "has", "had",
-    "do", "does", "did", "will", "would", "could", "should", "may", "might", "shall", "can",
-    "need", "dare", "ought", "in", "on", "at", "to", "for", "of", "with", "by", "from", "as",
-    "into", "through", "during", "before", "after", "above", "below", "and", "or", "but", "if",
-    "then", "that", "this", "it", "its", "not", "no", "nor", "so", "yet", "both", "either",
-    "neither",
-];
+use super::TextAnalyzer;
 
-pub struct Bm25SparseEmbedder;
+/// BM25 term-frequency saturation parameters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bm25Params {
+    pub k1: f32,
+    pub b: f32,
+    /// Expected chunk length in tokens (after stop-word removal), standing in for BM25's
+    /// `avgdl`. It is a fixed approximation, not the mean over the indexed chunks: weights
+    /// are computed once at ingestion and never revisited as the corpus grows, so this
+    /// should roughly match the chunking settings, and changing it only affects chunks
+    /// embedded afterwards.
+    pub avg_doc_length: f32,
+}
+
+impl Default for Bm25Params {
+    fn default() -> Self {
+        Self {
+            k1: 1.2,
+            b: 0.75,
+            avg_doc_length: 256.0,
+        }
+    }
+}
+
+/// Hashed-vocabulary BM25.
+///
+/// Documents carry the saturated, length-normalized term frequency
+/// `tf * (k1 + 1) / (tf + k1 * (1 - b + b * dl / avgdl))`; queries carry `1.0` per distinct
+/// term. The IDF factor is corpus-wide and is applied by the vector store at query time
+/// (Qdrant's `idf` modifier on the sparse vector), so the dot product of the two is the
+/// BM25 score. Terms come from the [`TextAnalyzer`], which must stay the same between
+/// ingestion and querying.
+pub struct Bm25SparseEmbedder {
+    params: Bm25Params,
+    analyzer: TextAnalyzer,
+}
 
 impl Bm25SparseEmbedder {
     pub fn new() -> Self {
-        Self
+        Self::with_params(Bm25Params::default())
+    }
+
+    pub fn with_params(params: Bm25Params) -> Self {
+        Self {
+            params,
+            analyzer: TextAnalyzer::default(),
+        }
     }
 
-    fn tokenize(text: &str) -> Vec<String> {
-        text.unicode_words()
-            .map(|w| w.to_lowercase())
-            .filter(|w| w.len() > 1 && !STOP_WORDS.contains(&w.as_str()))
-            .collect()
+    pub fn with_analyzer(mut self, analyzer: TextAnalyzer) -> Self {
+        self.analyzer = analyzer;
+        self
     }
 
     fn fnv1a(token: &str) -> u32 {
@@ -37,21 +68,43 @@ impl Bm25SparseEmbedder {
         })
     }
 
-    fn compute_sparse(text: &str) -> SparseEmbedding {
-        let tokens = Self::tokenize(text);
+    fn term_counts(tokens: &[String]) -> HashMap<u32, u32> {
+        let mut counts: HashMap<u32, u32> = HashMap::new();
+        for token in tokens {
+            *counts.entry(Self::fnv1a(token)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    fn compute_document(&self, text: &str) -> SparseEmbedding {
+        let tokens = self.analyzer.analyze_document(text);
         if tokens.is_empty() {
             return SparseEmbedding::new(vec![]);
         }
 
-        let total = tokens.len() as f32;
-        let mut counts: HashMap<u32, u32> = HashMap::new();
-        for token in &tokens {
-            *counts.entry(Self::fnv1a(token)).or_insert(0) += 1;
-        }
+        let Bm25Params {
+            k1,
+            b,
+            avg_doc_length,
+        } = self.params;
+        let length_norm = 1.0 - b + b * tokens.len() as f32 / avg_doc_length.max(1.0);
 
-        let pairs: Vec<(u32, f32)> = counts
+        let pairs: Vec<(u32, f32)> = Self::term_counts(&tokens)
             .into_iter()
-            .map(|(idx, count)| (idx, count as f32 / total))
+            .map(|(idx, count)| {
+                let tf = count as f32;
+                (idx, tf * (k1 + 1.0) / (tf + k1 * length_norm))
+            })
+            .collect();
+
+        SparseEmbedding::new(pairs)
+    }
+
+    fn compute_query(&self, text: &str) -> SparseEmbedding {
+        let tokens = self.analyzer.analyze_query(text);
+        let pairs: Vec<(u32, f32)> = Self::term_counts(&tokens)
+            .into_keys()
+            .map(|idx| (idx, 1.0))
             .collect();
 
         SparseEmbedding::new(pairs)
@@ -66,14 +119,14 @@ impl Default for Bm25SparseEmbedder {
 
 #[async_trait]
 impl SparseEmbedder for Bm25SparseEmbedder {
-    async fn embed_sparse(&self, text: &str) -> Result<SparseEmbedding, EmbedderError> {
-        Ok(Self::compute_sparse(text))
+    async fn embed_sparse_query(&self, query: &str) -> Result<SparseEmbedding, EmbedderError> {
+        Ok(self.compute_query(query))
     }
 
     async fn embed_sparse_batch(
         &self,
         texts: &[&str],
     ) -> Result<Vec<SparseEmbedding>, EmbedderError> {
-        Ok(texts.iter().map(|t| Self::compute_sparse(t)).collect())
+        Ok(texts.iter().map(|t| self.compute_document(t)).collect())
     }
 }
@@ -0,0 +1,177 @@
+use regex::Regex;
+
+use crate::application::ports::TextSplitterError;
+use crate::domain::SourceLanguage;
+
+/// A declaration pattern. The last capture is the name; an earlier capture (a Go method's
+/// receiver type) qualifies it.
+pub(super) struct Declaration {
+    pub(super) pattern: Regex,
+    pub(super) container: bool,
+}
+
+/// Pattern sources, each flagged when it declares a container.
+type Patterns = Vec<(&'static str, bool)>;
+
+/// Declaration patterns and block structure of one language.
+pub(super) struct Grammar {
+    /// Patterns matched at the top level and inside containers.
+    pub(super) declarations: Vec<Declaration>,
+    /// Patterns matched only inside containers (methods without a keyword).
+    pub(super) members: Vec<Declaration>,
+    /// Blocks are delimited by indentation rather than braces.
+    pub(super) indented: bool,
+    /// `'` delimits strings rather than character literals.
+    pub(super) single_quote_strings: bool,
+    /// Line prefixes of comments, attributes and decorators that belong to the declaration
+    /// below them.
+    pub(super) preamble: &'static [&'static str],
+}
+
+impl Grammar {
+    pub(super) fn for_language(language: SourceLanguage) -> Result<Self, TextSplitterError> {
+        const C_PREAMBLE: &[&str] = &["//", "/*", "*"];
+        const ANNOTATED_PREAMBLE: &[&str] = &["//", "/*", "*", "@"];
+        const MODIFIERS: &str = r"(?:(?:public|private|protected|internal|static|final|abstract|sealed|open|data|partial|inner|enum|annotation|value|readonly|unsafe|new)\s+)*";
+
+        let (declarations, members, preamble): (Patterns, Patterns, _) = match language {
+            SourceLanguage::Rust => (
+                vec![
+                    (
+                        r#"^(?:pub(?:\([^)]*\))?\s+)?(?:(?:default|const|async|unsafe|extern(?:\s+"[^"]*")?)\s+)*fn\s+(\w+)"#,
+                        false,
+                    ),
+                    (
+                        r"^(?:unsafe\s+)?impl\b(?:\s*<.*?>)?\s+(?:.*?\bfor\s+)?(?:&|dyn\s+)?([A-Za-z_][\w:]*)",
+                        true,
+                    ),
+                    (
+                        r"^(?:pub(?:\([^)]*\))?\s+)?(?:unsafe\s+)?(?:struct|enum|union|trait|mod)\s+(\w+)",
+                        true,
+                    ),
+                    (r"^macro_rules!\s*(\w+)", false),
+                ],
+                vec![],
+                &["//", "/*", "*", "#["][..],
+            ),
+            SourceLanguage::Python => (
+                vec![
+                    (r"^(?:async\s+)?def\s+(\w+)", false),
+                    (r"^class\s+(\w+)", true),
+                ],
+                vec![],
+                &["#", "@"][..],
+            ),
+            SourceLanguage::JavaScript | SourceLanguage::TypeScript => (
+                vec![
+                    (
+                        r"^(?:export\s+)?(?:default\s+)?(?:async\s+)?function\b\s*\*?\s*(\w+)",
+                        false,
+                    ),
+                    (
+                        r"^(?:export\s+)?(?:default\s+)?(?:declare\s+)?(?:abstract\s+)?class\s+(\w+)",
+                        true,
+                    ),
+                    (
+                        r"^(?:export\s+)?(?:declare\s+)?(?:const\s+)?(?:interface|enum|namespace|module)\s+(\w+)",
+                        true,
+                    ),
+                    (
+                        r"^(?:export\s+)?(?:const|let|var)\s+(\w+)\s*(?::[^=]*)?=\s*(?:async\s+)?(?:function\b|\([^)]*\)\s*(?::[^=]*)?=>|\w+\s*=>)",
+                        false,
+                    ),
+                ],
+                vec![(
+                    r"^(?:(?:public|private|protected|static|async|readonly|override|abstract|get|set)\s+)*\*?#?(\w+)\s*(?:<[^>]*>)?\s*\(",
+                    false,
+                )],
+                ANNOTATED_PREAMBLE,
+            ),
+            SourceLanguage::Go => (
+                vec![
+                    (
+                        r"^func\s+\(\s*(?:\w+\s+)?\*?(\w+)(?:\[[^\]]*\])?\s*\)\s*(\w+)",
+                        false,
+                    ),
+                    (r"^func\s+(\w+)", false),
+                    (r"^type\s+(\w+)", true),
+                ],
+                vec![],
+                C_PREAMBLE,
+            ),
+            SourceLanguage::Java | SourceLanguage::CSharp => (
+                vec![(
+                    leak(format!(
+                        r"^{MODIFIERS}(?:class|interface|enum|record|struct|namespace)\s+(\w+)"
+                    )),
+                    true,
+                )],
+                vec![
+                    (
+                        r"^(?:(?:public|private|protected|internal|static|final|abstract|synchronized|native|default|override|virtual|async|sealed|extern|unsafe|new|partial)\s+)*(?:<[^>]+>\s+)?[\w<>\[\],.?]+\s+(\w+)\s*\(",
+                        false,
+                    ),
+                    (
+                        r"^(?:public|private|protected|internal)\s+(\w+)\s*\(",
+                        false,
+                    ),
+                ],
+                &["//", "/*", "*", "@", "["][..],
+            ),
+            SourceLanguage::Kotlin => (
+                vec![
+                    (
+                        leak(format!(r"^{MODIFIERS}(?:class|interface|object)\s+(\w+)")),
+                        true,
+                    ),
+                    (
+                        r"^(?:(?:public|private|protected|internal|override|open|abstract|suspend|inline|operator|infix|tailrec|external|actual|expect)\s+)*fun\s+(?:<[^>]*>\s*)?(?:[\w.]+\.)?(\w+)",
+                        false,
+                    ),
+                ],
+                vec![],
+                ANNOTATED_PREAMBLE,
+            ),
+            SourceLanguage::C | SourceLanguage::Cpp => (
+                vec![
+                    (
+                        r"^(?:template\s*<.*>\s*)?(?:class|struct|union|namespace|enum(?:\s+class)?)\s+(\w+)[^;]*$",
+                        true,
+                    ),
+                    (
+                        r"^(?:template\s*<.*>\s*)?(?:[\w:<>,*&~]+[\s*&]+)+([A-Za-z_~][\w:~]*)\s*\([^;]*$",
+                        false,
+                    ),
+                ],
+                vec![],
+                C_PREAMBLE,
+            ),
+        };
+
+        let compile = |patterns: Patterns| {
+            patterns
+                .into_iter()
+                .map(|(pattern, container)| {
+                    Regex::new(pattern)
+                        .map(|pattern| Declaration { pattern, container })
+                        .map_err(|e| TextSplitterError::SplittingFailed(e.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            declarations: compile(declarations)?,
+            members: compile(members)?,
+            indented: language == SourceLanguage::Python,
+            single_quote_strings: matches!(
+                language,
+                SourceLanguage::Python | SourceLanguage::JavaScript | SourceLanguage::TypeScript
+            ),
+            preamble,
+        })
+    }
+}
+
+/// Patterns assembled at construction live for the program's lifetime.
+fn leak(pattern: String) -> &'static str {
+    Box::leak(pattern.into_boxed_str())
+}
@@ -0,0 +1,148 @@
+use std::ops::Range;
+
+use super::source::Source;
+
+/// Counts braces outside strings and comments, carrying block comments across lines.
+pub(super) struct Lexer {
+    single_quote_strings: bool,
+    pub(super) in_block_comment: bool,
+}
+
+pub(super) struct LineScan {
+    pub(super) opens: usize,
+    pub(super) closes: usize,
+    /// The last code character on the line is `;`.
+    pub(super) ends_statement: bool,
+}
+
+impl Lexer {
+    pub(super) fn new(single_quote_strings: bool) -> Self {
+        Self {
+            single_quote_strings,
+            in_block_comment: false,
+        }
+    }
+
+    pub(super) fn scan(&mut self, line: &str) -> LineScan {
+        let chars: Vec<char> = line.chars().collect();
+        let mut scan = LineScan {
+            opens: 0,
+            closes: 0,
+            ends_statement: false,
+        };
+        let mut last = None;
+        let mut i = 0;
+        while i < chars.len() {
+            let next = chars.get(i + 1).copied();
+            if self.in_block_comment {
+                if chars[i] == '*' && next == Some('/') {
+                    self.in_block_comment = false;
+                    i += 1;
+                }
+                i += 1;
+                continue;
+            }
+            match chars[i] {
+                '/' if next == Some('/') => break,
+                '/' if next == Some('*') => {
+                    self.in_block_comment = true;
+                    i += 2;
+                    continue;
+                }
+                quote @ ('"' | '`') => i = skip_string(&chars, i, quote),
+                '\'' if self.single_quote_strings => i = skip_string(&chars, i, '\''),
+                // A character literal; a lone quote is a Rust lifetime.
+                '\'' => {
+                    if next == Some('\\') {
+                        if let Some(close) = chars[i + 2..].iter().take(10).position(|&c| c == '\'')
+                        {
+                            i += close + 2;
+                        }
+                    } else if chars.get(i + 2) == Some(&'\'') {
+                        i += 2;
+                    }
+                }
+                '{' => scan.opens += 1,
+                '}' => scan.closes += 1,
+                _ => {}
+            }
+            if !chars[i.min(chars.len() - 1)].is_whitespace() {
+                last = Some(chars[i.min(chars.len() - 1)]);
+            }
+            i += 1;
+        }
+        scan.ends_statement = last == Some(';');
+        scan
+    }
+}
+
+/// Index of the quote closing the string opened at `start`, or the last index of the line.
+fn skip_string(chars: &[char], start: usize, quote: char) -> usize {
+    let mut i = start + 1;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            c if c == quote => return i,
+            _ => {}
+        }
+        i += 1;
+    }
+    chars.len() - 1
+}
+
+/// Whether each line of `range` starts inside a Python triple-quoted string.
+pub(super) fn triple_quoted_lines(source: &Source<'_>, range: Range<usize>) -> Vec<bool> {
+    let mut open: Option<&str> = None;
+    range
+        .map(|line| {
+            let starts_inside = open.is_some();
+            let mut rest = source.line(line);
+            loop {
+                let delimiter = match open {
+                    Some(delimiter) => Some(delimiter),
+                    None => match (rest.find("\"\"\""), rest.find("'''")) {
+                        (Some(a), Some(b)) => Some(if a < b { "\"\"\"" } else { "'''" }),
+                        (Some(_), None) => Some("\"\"\""),
+                        (None, Some(_)) => Some("'''"),
+                        (None, None) => None,
+                    },
+                };
+                let Some(delimiter) = delimiter else { break };
+                let Some(at) = rest.find(delimiter) else {
+                    break;
+                };
+                rest = &rest[at + 3..];
+                open = match open {
+                    Some(_) => None,
+                    None => Some(delimiter),
+                };
+            }
+            starts_inside
+        })
+        .collect()
+}
+
+/// Opening minus closing brackets on a line, ignoring string contents and comments.
+pub(super) fn bracket_balance(line: &str) -> i64 {
+    let chars: Vec<char> = line.chars().collect();
+    let mut balance = 0;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '#' => break,
+            quote @ ('"' | '\'') => i = skip_string(&chars, i, quote),
+            '(' | '[' | '{' => balance += 1,
+            ')' | ']' | '}' => balance -= 1,
+            _ => {}
+        }
+        i += 1;
+    }
+    balance
+}
+
+pub(super) fn indentation(line: &str) -> usize {
+    line.chars()
+        .take_while(|c| c.is_whitespace())
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum()
+}
@@ -0,0 +1,213 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use tiktoken_rs::{CoreBPE, cl100k_base};
+
+use crate::application::ports::{CodeSplitter, TextSplitterError};
+use crate::domain::{Chunk, CodeSpan, DocumentId, DocumentMetadata, SourceLanguage};
+
+mod grammar;
+mod lexer;
+mod outline;
+mod source;
+
+use grammar::Grammar;
+use source::{Block, Segment, Source};
+
+/// Splits source files along syntactic boundaries without a parser.
+///
+/// Top-level declarations (functions, impls, classes, interfaces, modules) are located with
+/// per-language patterns; their extent follows brace nesting, or indentation for Python,
+/// with string literals and comments skipped. Doc comments, attributes and decorators
+/// directly above a declaration belong to it. A declaration that fits the token budget is
+/// one chunk; a larger container (impl, class) is split into its members, and anything
+/// still too large — or code between declarations — is cut into line windows. Every chunk
+/// records the qualified symbol it belongs to (`Parser::parse`, `Service.handle`) and its
+/// 1-based line range.
+pub struct SyntacticCodeSplitter {
+    max_tokens: usize,
+    tokenizer: CoreBPE,
+    grammars: HashMap<SourceLanguage, Grammar>,
+}
+
+impl SyntacticCodeSplitter {
+    pub fn new(max_tokens: usize) -> Result<Self, TextSplitterError> {
+        let tokenizer = cl100k_base().map_err(|e| {
+            TextSplitterError::TokenizationFailed(format!("Failed to load tokenizer: {}", e))
+        })?;
+        let grammars = [
+            SourceLanguage::Rust,
+            SourceLanguage::Python,
+            SourceLanguage::JavaScript,
+            SourceLanguage::TypeScript,
+            SourceLanguage::Go,
+            SourceLanguage::Java,
+            SourceLanguage::Kotlin,
+            SourceLanguage::CSharp,
+            SourceLanguage::C,
+            SourceLanguage::Cpp,
+        ]
+        .into_iter()
+        .map(|language| Grammar::for_language(language).map(|g| (language, g)))
+        .collect::<Result<_, _>>()?;
+        Ok(Self {
+            max_tokens: max_tokens.max(1),
+            tokenizer,
+            grammars,
+        })
+    }
+
+    /// Splits `range` into chunk-sized segments, descending into containers that do not
+    /// fit. Code outside any declaration is attributed to `scope`.
+    fn segment(
+        &self,
+        source: &Source<'_>,
+        range: Range<usize>,
+        blocks: &[Block],
+        scope: Option<&str>,
+        separator: &str,
+        out: &mut Vec<Segment>,
+    ) {
+        let mut cursor = range.start;
+        for block in blocks {
+            if cursor < block.lines.start {
+                out.push(Segment::new(cursor..block.lines.start, scope));
+            }
+            let symbol = match scope {
+                Some(scope) => format!("{scope}{separator}{}", block.name),
+                None => block.name.clone(),
+            };
+            if !source.fits(block.lines.clone(), self.max_tokens) && !block.children.is_empty() {
+                self.segment(
+                    source,
+                    block.lines.clone(),
+                    &block.children,
+                    Some(&symbol),
+                    separator,
+                    out,
+                );
+            } else {
+                out.push(Segment::new(block.lines.clone(), Some(&symbol)));
+            }
+            cursor = block.lines.end;
+        }
+        if cursor < range.end {
+            out.push(Segment::new(cursor..range.end, scope));
+        }
+    }
+
+    /// Joins neighbouring segments of the same symbol that fit together, drops segments
+    /// without any identifier (stray closing braces) and cuts oversized ones into windows.
+    fn finalize(&self, source: &Source<'_>, segments: Vec<Segment>) -> Vec<Segment> {
+        let mut merged: Vec<Segment> = Vec::new();
+        for segment in segments {
+            let Some(segment) = source.trim(segment) else {
+                continue;
+            };
+            if let Some(last) = merged.last_mut()
+                && last.symbol == segment.symbol
+                && source.fits(last.lines.start..segment.lines.end, self.max_tokens)
+            {
+                last.lines.end = segment.lines.end;
+                continue;
+            }
+            merged.push(segment);
+        }
+
+        let mut out = Vec::new();
+        for segment in merged {
+            if source.fits(segment.lines.clone(), self.max_tokens) {
+                out.push(segment);
+                continue;
+            }
+            let mut start = segment.lines.start;
+            let mut tokens = 0;
+            for line in segment.lines.clone() {
+                let line_tokens = source.tokens[line];
+                if line > start && tokens + line_tokens > self.max_tokens {
+                    out.extend(source.trim(Segment {
+                        lines: start..line,
+                        symbol: segment.symbol.clone(),
+                    }));
+                    start = line;
+                    tokens = 0;
+                }
+                tokens += line_tokens;
+            }
+            out.extend(source.trim(Segment {
+                lines: start..segment.lines.end,
+                symbol: segment.symbol,
+            }));
+        }
+        out
+    }
+}
+
+#[async_trait]
+impl CodeSplitter for SyntacticCodeSplitter {
+    async fn split_code(
+        &self,
+        source: &str,
+        path: &str,
+        document_id: DocumentId,
+        metadata: Option<Arc<DocumentMetadata>>,
+    ) -> Result<Vec<Chunk>, TextSplitterError> {
+        let language = SourceLanguage::from_path(path).ok_or_else(|| {
+            TextSplitterError::SplittingFailed(format!("unsupported source language: {path}"))
+        })?;
+        let grammar = &self.grammars[&language];
+        let source = Source::new(source, &self.tokenizer);
+        let all = 0..source.lines.len();
+
+        let blocks = if grammar.indented {
+            grammar.outline_indented(&source, all.clone())
+        } else {
+            grammar.outline_braced(&source, all.clone(), false)
+        };
+        let mut segments = Vec::new();
+        self.segment(
+            &source,
+            all,
+            &blocks,
+            None,
+            language.scope_separator(),
+            &mut segments,
+        );
+
+        let chunks: Vec<Chunk> = self
+            .finalize(&source, segments)
+            .into_iter()
+            .map(|segment| {
+                let (offset, text) = source.text(segment.lines.clone());
+                let chunk = match &metadata {
+                    Some(meta) => Chunk::with_metadata(
+                        text.to_string(),
+                        document_id,
+                        None,
+                        offset,
+                        Arc::clone(meta),
+                    ),
+                    None => Chunk::new(text.to_string(), document_id, None, offset),
+                };
+                chunk.with_code_span(CodeSpan {
+                    path: path.to_string(),
+                    language,
+                    symbol: segment.symbol,
+                    start_line: segment.lines.start as u32 + 1,
+                    end_line: segment.lines.end as u32,
+                })
+            })
+            .collect();
+
+        tracing::debug!(
+            path,
+            language = language.as_str(),
+            declarations = blocks.len(),
+            chunks = chunks.len(),
+            "Source file split"
+        );
+        Ok(chunks)
+    }
+}
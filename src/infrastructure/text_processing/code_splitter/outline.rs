@@ -0,0 +1,178 @@
+use std::ops::Range;
+
+use super::grammar::Grammar;
+use super::lexer::{Lexer, bracket_balance, indentation, triple_quoted_lines};
+use super::source::{Block, Source};
+
+/// Names the member patterns can pick up from control-flow statements.
+const KEYWORDS: &[&str] = &[
+    "if", "else", "for", "while", "do", "switch", "case", "catch", "return", "new", "delete",
+    "throw", "sizeof", "typeof", "await", "yield", "match", "using", "lock", "foreach",
+];
+
+impl Grammar {
+    /// Name of the declaration starting at `line`, and whether it is a container.
+    fn declaration(&self, line: &str, nested: bool) -> Option<(String, bool)> {
+        let line = line.trim_start();
+        let patterns = self
+            .declarations
+            .iter()
+            .chain(self.members.iter().filter(|_| nested));
+        patterns.into_iter().find_map(|declaration| {
+            let captures = declaration.pattern.captures(line)?;
+            let names: Vec<&str> = captures
+                .iter()
+                .skip(1)
+                .flatten()
+                .map(|m| m.as_str())
+                .collect();
+            let name = names.last()?;
+            if KEYWORDS.contains(name) {
+                return None;
+            }
+            Some((names.join("."), declaration.container))
+        })
+    }
+
+    /// First line of the comments and attributes directly above `line`, not reaching
+    /// back before `floor`.
+    fn preamble_start(&self, source: &Source<'_>, line: usize, floor: usize) -> usize {
+        let mut start = line;
+        while start > floor {
+            let above = source.line(start - 1).trim_start();
+            if above.is_empty() || !self.preamble.iter().any(|p| above.starts_with(p)) {
+                break;
+            }
+            start -= 1;
+        }
+        start
+    }
+
+    /// Declarations at brace depth zero within `range`.
+    pub(super) fn outline_braced(
+        &self,
+        source: &Source<'_>,
+        range: Range<usize>,
+        nested: bool,
+    ) -> Vec<Block> {
+        let mut blocks: Vec<Block> = Vec::new();
+        let mut lexer = Lexer::new(self.single_quote_strings);
+        let mut depth = 0i64;
+        let mut line = range.start;
+
+        while line < range.end {
+            let declaration = (depth == 0 && !lexer.in_block_comment)
+                .then(|| self.declaration(source.line(line), nested))
+                .flatten();
+            let Some((name, container)) = declaration else {
+                let scan = lexer.scan(source.line(line));
+                depth = (depth + scan.opens as i64 - scan.closes as i64).max(0);
+                line += 1;
+                continue;
+            };
+
+            // The declaration runs until its braces balance, or — without a body — until
+            // the statement ends or a blank line follows its signature.
+            let mut end = line;
+            let mut block_depth = 0i64;
+            let mut body_start = None;
+            while end < range.end {
+                let scan = lexer.scan(source.line(end));
+                block_depth += scan.opens as i64 - scan.closes as i64;
+                if scan.opens > 0 && body_start.is_none() {
+                    body_start = Some(end + 1);
+                }
+                end += 1;
+                match body_start {
+                    Some(_) if block_depth <= 0 => break,
+                    None if scan.ends_statement || (end < range.end && source.is_blank(end)) => {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+
+            let floor = blocks.last().map_or(range.start, |b| b.lines.end);
+            let children = match body_start {
+                Some(body_start) if container && body_start < end => {
+                    self.outline_braced(source, body_start..end - 1, true)
+                }
+                _ => Vec::new(),
+            };
+            blocks.push(Block {
+                lines: self.preamble_start(source, line, floor)..end,
+                name,
+                children,
+            });
+            line = end;
+        }
+        blocks
+    }
+
+    /// Declarations at the indentation of the first line of `range`.
+    pub(super) fn outline_indented(&self, source: &Source<'_>, range: Range<usize>) -> Vec<Block> {
+        let in_string = triple_quoted_lines(source, range.clone());
+        let code_line = |line: usize| !in_string[line - range.start] && !source.is_blank(line);
+        let Some(level) = range
+            .clone()
+            .find(|&line| code_line(line))
+            .map(|line| indentation(source.line(line)))
+        else {
+            return Vec::new();
+        };
+
+        let mut blocks: Vec<Block> = Vec::new();
+        let mut line = range.start;
+        while line < range.end {
+            let declaration = (code_line(line) && indentation(source.line(line)) == level)
+                .then(|| self.declaration(source.line(line), false))
+                .flatten();
+            let Some((name, container)) = declaration else {
+                line += 1;
+                continue;
+            };
+
+            // The signature may span lines until its brackets close.
+            let mut header_end = line;
+            let mut brackets = 0i64;
+            while header_end < range.end {
+                brackets += bracket_balance(source.line(header_end));
+                header_end += 1;
+                if brackets <= 0 {
+                    break;
+                }
+            }
+            let mut end = header_end;
+            while end < range.end
+                && (!code_line(end)
+                    || indentation(source.line(end)) > level
+                    || source.line(end).trim_start().starts_with(['#']))
+            {
+                end += 1;
+            }
+            // Comments and blank lines before the next declaration belong to it.
+            while end > header_end
+                && (source.is_blank(end - 1)
+                    || (!in_string[end - 1 - range.start]
+                        && indentation(source.line(end - 1)) <= level
+                        && source.line(end - 1).trim_start().starts_with('#')))
+            {
+                end -= 1;
+            }
+
+            let floor = blocks.last().map_or(range.start, |b| b.lines.end);
+            let children = if container && header_end < end {
+                self.outline_indented(source, header_end..end)
+            } else {
+                Vec::new()
+            };
+            blocks.push(Block {
+                lines: self.preamble_start(source, line, floor)..end,
+                name,
+                children,
+            });
+            line = end.max(line + 1);
+        }
+        blocks
+    }
+}
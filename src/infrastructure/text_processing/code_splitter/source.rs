@@ -0,0 +1,86 @@
+use std::ops::Range;
+
+use tiktoken_rs::CoreBPE;
+
+/// A run of lines and the symbol it belongs to.
+pub(super) struct Segment {
+    pub(super) lines: Range<usize>,
+    pub(super) symbol: Option<String>,
+}
+
+impl Segment {
+    pub(super) fn new(lines: Range<usize>, symbol: Option<&str>) -> Self {
+        Self {
+            lines,
+            symbol: symbol.map(str::to_string),
+        }
+    }
+}
+
+/// A declaration: its lines (preamble included), name and, for containers, its members.
+pub(super) struct Block {
+    pub(super) lines: Range<usize>,
+    pub(super) name: String,
+    pub(super) children: Vec<Block>,
+}
+
+/// The file's lines with their byte offsets and token counts.
+pub(super) struct Source<'a> {
+    pub(super) text: &'a str,
+    pub(super) lines: Vec<(usize, &'a str)>,
+    pub(super) tokens: Vec<usize>,
+}
+
+impl<'a> Source<'a> {
+    pub(super) fn new(text: &'a str, tokenizer: &CoreBPE) -> Self {
+        let mut lines = Vec::new();
+        let mut offset = 0;
+        for line in text.split_inclusive('\n') {
+            lines.push((offset, line.trim_end_matches(['\n', '\r'])));
+            offset += line.len();
+        }
+        let tokens = lines
+            .iter()
+            .map(|(_, line)| tokenizer.encode_ordinary(line).len() + 1)
+            .collect();
+        Self {
+            text,
+            lines,
+            tokens,
+        }
+    }
+
+    pub(super) fn line(&self, index: usize) -> &'a str {
+        self.lines[index].1
+    }
+
+    pub(super) fn is_blank(&self, index: usize) -> bool {
+        self.line(index).trim().is_empty()
+    }
+
+    pub(super) fn fits(&self, lines: Range<usize>, max_tokens: usize) -> bool {
+        self.tokens[lines].iter().sum::<usize>() <= max_tokens
+    }
+
+    /// Byte offset and text of `lines`.
+    pub(super) fn text(&self, lines: Range<usize>) -> (usize, &'a str) {
+        let start = self.lines[lines.start].0;
+        let (last_offset, last) = self.lines[lines.end - 1];
+        (start, &self.text[start..last_offset + last.len()])
+    }
+
+    /// Drops surrounding blank lines; `None` when nothing but punctuation remains.
+    pub(super) fn trim(&self, mut segment: Segment) -> Option<Segment> {
+        while segment.lines.start < segment.lines.end && self.is_blank(segment.lines.start) {
+            segment.lines.start += 1;
+        }
+        while segment.lines.end > segment.lines.start && self.is_blank(segment.lines.end - 1) {
+            segment.lines.end -= 1;
+        }
+        segment
+            .lines
+            .clone()
+            .any(|i| self.line(i).chars().any(char::is_alphanumeric))
+            .then_some(segment)
+    }
+}
@@ -4,7 +4,7 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::application::ports::{FileLoader, FileLoaderError};
-use crate::domain::{ContentType, Document};
+use crate::domain::{ContentType, Document, PageSegment};
 
 pub struct CompositeFileLoader {
     adapters: HashMap<ContentType, Arc<dyn FileLoader>>,
@@ -20,15 +20,15 @@ impl CompositeFileLoader {
 
 #[async_trait]
 impl FileLoader for CompositeFileLoader {
-    async fn extract_text(
+    async fn extract_pages(
         &self,
         data: &[u8],
         document: &Document,
-    ) -> Result<String, FileLoaderError> {
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
         let adapter = self.adapters.get(&document.content_type).ok_or_else(|| {
             FileLoaderError::UnsupportedContentType(document.content_type.as_mime().to_string())
         })?;
 
-        adapter.extract_text(data, document).await
+        adapter.extract_pages(data, document).await
     }
 }
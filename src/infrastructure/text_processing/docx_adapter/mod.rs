@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+use crate::application::ports::{FileLoader, FileLoaderError};
+use crate::domain::{ContentType, Document, PageSegment};
+
+use super::markdown_render::{collapse_whitespace, markdown_table};
+use super::ooxml::{OoxmlPackage, TableStack, attr, xml_error};
+
+mod styles;
+
+use styles::{Numbering, ParagraphStyle, outline_heading_level, parse_styles};
+
+const DOCUMENT_PART: &str = "word/document.xml";
+const STYLES_PART: &str = "word/styles.xml";
+const NUMBERING_PART: &str = "word/numbering.xml";
+
+/// Elements whose content duplicates or predates the visible text: fallback renderings of
+/// text boxes, tracked formatting changes and moved-away runs.
+const SKIPPED_ELEMENTS: &[&[u8]] = &[b"Fallback", b"pPrChange", b"rPrChange", b"moveFrom"];
+
+/// Converts Word documents to markdown: heading styles become `#` headings, numbered and
+/// bulleted paragraphs become list items and tables become markdown tables. The result is
+/// one unpaged segment, as DOCX carries no reliable page layout.
+pub struct DocxAdapter;
+
+#[async_trait]
+impl FileLoader for DocxAdapter {
+    async fn extract_pages(
+        &self,
+        data: &[u8],
+        document: &Document,
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
+        if document.content_type != ContentType::Docx {
+            return Err(FileLoaderError::UnsupportedContentType(
+                document.content_type.as_mime().to_string(),
+            ));
+        }
+
+        let mut package = OoxmlPackage::open(data)?;
+        let styles = match package.read_part(STYLES_PART)? {
+            Some(xml) => parse_styles(&xml)?,
+            None => HashMap::new(),
+        };
+        let numbering = match package.read_part(NUMBERING_PART)? {
+            Some(xml) => Numbering::parse(&xml)?,
+            None => Numbering::default(),
+        };
+        let body = package.require_part(DOCUMENT_PART)?;
+
+        let markdown = render_document(&body, &styles, &numbering)?;
+        if markdown.is_empty() {
+            return Err(FileLoaderError::NoTextFound(document.filename.clone()));
+        }
+        tracing::debug!(chars = markdown.len(), "DOCX converted to markdown");
+        Ok(vec![PageSegment::unpaged(markdown)])
+    }
+}
+
+#[derive(Default)]
+struct Paragraph {
+    text: String,
+    style: Option<String>,
+    num_id: Option<String>,
+    ilvl: Option<usize>,
+    heading_level: Option<usize>,
+}
+
+/// Joins markdown blocks with blank lines, keeping consecutive list items together.
+#[derive(Default)]
+struct MarkdownWriter {
+    markdown: String,
+    after_list_item: bool,
+}
+
+impl MarkdownWriter {
+    fn push(&mut self, block: &str, list_item: bool) {
+        if block.is_empty() {
+            return;
+        }
+        if !self.markdown.is_empty() {
+            self.markdown
+                .push_str(if list_item && self.after_list_item {
+                    "\n"
+                } else {
+                    "\n\n"
+                });
+        }
+        self.markdown.push_str(block);
+        self.after_list_item = list_item;
+    }
+}
+
+fn render_document(
+    xml: &str,
+    styles: &HashMap<String, ParagraphStyle>,
+    numbering: &Numbering,
+) -> Result<String, FileLoaderError> {
+    let mut reader = Reader::from_str(xml);
+    let mut writer = MarkdownWriter::default();
+    // Text boxes nest paragraphs inside a run of their anchor paragraph.
+    let mut paragraphs: Vec<Paragraph> = Vec::new();
+    let mut tables = TableStack::default();
+    let mut in_properties = false;
+    let mut in_text = false;
+
+    loop {
+        match reader.read_event().map_err(xml_error(DOCUMENT_PART))? {
+            Event::Start(e) => match e.local_name().as_ref() {
+                name if SKIPPED_ELEMENTS.contains(&name) => {
+                    reader
+                        .read_to_end(e.name())
+                        .map_err(xml_error(DOCUMENT_PART))?;
+                }
+                b"p" => paragraphs.push(Paragraph::default()),
+                b"pPr" => in_properties = true,
+                b"t" => in_text = true,
+                name => tables.start(name),
+            },
+            Event::Empty(e) => {
+                let Some(paragraph) = paragraphs.last_mut() else {
+                    continue;
+                };
+                match (in_properties, e.local_name().as_ref()) {
+                    (true, b"pStyle") => paragraph.style = attr(&e, b"val"),
+                    (true, b"numId") => paragraph.num_id = attr(&e, b"val"),
+                    (true, b"ilvl") => {
+                        paragraph.ilvl = attr(&e, b"val").and_then(|v| v.parse().ok())
+                    }
+                    (true, b"outlineLvl") => paragraph.heading_level = outline_heading_level(&e),
+                    (false, b"tab" | b"br" | b"cr") => paragraph.text.push(' '),
+                    _ => {}
+                }
+            }
+            Event::Text(t) if in_text => {
+                if let Some(paragraph) = paragraphs.last_mut() {
+                    let text = t.unescape().map_err(xml_error(DOCUMENT_PART))?;
+                    paragraph.text.push_str(&text);
+                }
+            }
+            Event::End(e) => match e.local_name().as_ref() {
+                b"pPr" => in_properties = false,
+                b"t" => in_text = false,
+                b"p" => {
+                    if let Some(paragraph) = paragraphs.pop() {
+                        let text = collapse_whitespace(&paragraph.text);
+                        if tables.is_open() {
+                            tables.push_text(&text);
+                        } else {
+                            let (block, list_item) =
+                                render_paragraph(&paragraph, text, styles, numbering);
+                            writer.push(&block, list_item);
+                        }
+                    }
+                }
+                name => {
+                    if let Some(rows) = tables.end(name) {
+                        writer.push(&markdown_table(&rows), false);
+                    }
+                }
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(writer.markdown)
+}
+
+/// Renders a top-level paragraph, returning the block and whether it is a list item.
+fn render_paragraph(
+    paragraph: &Paragraph,
+    text: String,
+    styles: &HashMap<String, ParagraphStyle>,
+    numbering: &Numbering,
+) -> (String, bool) {
+    if text.is_empty() {
+        return (text, false);
+    }
+    let style = paragraph
+        .style
+        .as_ref()
+        .and_then(|id| styles.get(id))
+        .cloned()
+        .unwrap_or_default();
+
+    if let Some(level) = paragraph.heading_level.or(style.heading_level) {
+        return (format!("{} {}", "#".repeat(level.clamp(1, 6)), text), false);
+    }
+
+    // numId 0 explicitly removes numbering inherited from the style.
+    match paragraph.num_id.clone().or(style.num_id) {
+        Some(num_id) if num_id != "0" => {
+            let level = paragraph.ilvl.or(style.ilvl).unwrap_or(0);
+            let marker = if numbering.is_ordered(&num_id, level) {
+                "1."
+            } else {
+                "-"
+            };
+            (format!("{}{} {}", "  ".repeat(level), marker, text), true)
+        }
+        _ => (text, false),
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+use super::super::ooxml::{attr, xml_error};
+use super::{NUMBERING_PART, STYLES_PART};
+use crate::application::ports::FileLoaderError;
+
+/// Heading and list properties a paragraph style contributes.
+#[derive(Default, Clone)]
+pub(super) struct ParagraphStyle {
+    pub(super) heading_level: Option<usize>,
+    pub(super) num_id: Option<String>,
+    pub(super) ilvl: Option<usize>,
+}
+
+/// Heading levels come from the built-in style names (`heading 1`, `Title`), which stay
+/// English in localized documents, or from an explicit outline level.
+pub(super) fn parse_styles(xml: &str) -> Result<HashMap<String, ParagraphStyle>, FileLoaderError> {
+    let mut styles = HashMap::new();
+    let mut reader = Reader::from_str(xml);
+    let mut current: Option<(String, ParagraphStyle)> = None;
+
+    loop {
+        match reader.read_event().map_err(xml_error(STYLES_PART))? {
+            Event::Start(e) if e.local_name().as_ref() == b"style" => {
+                current = attr(&e, b"styleId").map(|id| (id, ParagraphStyle::default()));
+            }
+            Event::Start(e) | Event::Empty(e) => {
+                let Some((_, style)) = current.as_mut() else {
+                    continue;
+                };
+                match e.local_name().as_ref() {
+                    b"name" => {
+                        let name = attr(&e, b"val").unwrap_or_default().to_lowercase();
+                        if name == "title" {
+                            style.heading_level = Some(1);
+                        } else if let Some(level) = name
+                            .strip_prefix("heading ")
+                            .and_then(|n| n.parse::<usize>().ok())
+                        {
+                            style.heading_level = Some(level);
+                        }
+                    }
+                    b"outlineLvl" if style.heading_level.is_none() => {
+                        style.heading_level = outline_heading_level(&e);
+                    }
+                    b"numId" => style.num_id = attr(&e, b"val"),
+                    b"ilvl" => style.ilvl = attr(&e, b"val").and_then(|v| v.parse().ok()),
+                    _ => {}
+                }
+            }
+            Event::End(e) if e.local_name().as_ref() == b"style" => {
+                if let Some((id, style)) = current.take() {
+                    styles.insert(id, style);
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(styles)
+}
+
+/// Outline levels 0–8 mark headings 1–9; level 9 is body text.
+pub(super) fn outline_heading_level(element: &quick_xml::events::BytesStart<'_>) -> Option<usize> {
+    attr(element, b"val")
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|&level| level < 9)
+        .map(|level| level + 1)
+}
+
+/// Which list levels are ordered, resolved from `w:num` instances to their abstract
+/// definitions.
+#[derive(Default)]
+pub(super) struct Numbering {
+    abstract_of: HashMap<String, String>,
+    ordered: HashMap<(String, usize), bool>,
+}
+
+impl Numbering {
+    pub(super) fn parse(xml: &str) -> Result<Self, FileLoaderError> {
+        let mut numbering = Self::default();
+        let mut reader = Reader::from_str(xml);
+        let mut abstract_id: Option<String> = None;
+        let mut num_id: Option<String> = None;
+        let mut level: Option<usize> = None;
+
+        loop {
+            match reader.read_event().map_err(xml_error(NUMBERING_PART))? {
+                Event::Start(e) | Event::Empty(e) => match e.local_name().as_ref() {
+                    b"abstractNum" => abstract_id = attr(&e, b"abstractNumId"),
+                    b"num" => num_id = attr(&e, b"numId"),
+                    b"abstractNumId" => {
+                        if let (Some(num), Some(target)) = (&num_id, attr(&e, b"val")) {
+                            numbering.abstract_of.insert(num.clone(), target);
+                        }
+                    }
+                    b"lvl" => level = attr(&e, b"ilvl").and_then(|v| v.parse().ok()),
+                    b"numFmt" => {
+                        if let (Some(id), Some(lvl)) = (&abstract_id, level) {
+                            let format = attr(&e, b"val").unwrap_or_default();
+                            numbering.ordered.insert(
+                                (id.clone(), lvl),
+                                !matches!(format.as_str(), "bullet" | "none"),
+                            );
+                        }
+                    }
+                    _ => {}
+                },
+                Event::End(e) => match e.local_name().as_ref() {
+                    b"abstractNum" => abstract_id = None,
+                    b"num" => num_id = None,
+                    b"lvl" => level = None,
+                    _ => {}
+                },
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(numbering)
+    }
+
+    pub(super) fn is_ordered(&self, num_id: &str, level: usize) -> bool {
+        self.abstract_of
+            .get(num_id)
+            .and_then(|id| self.ordered.get(&(id.clone(), level)))
+            .copied()
+            .unwrap_or(false)
+    }
+}
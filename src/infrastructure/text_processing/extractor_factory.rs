@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
 use crate::application::ports::FileLoader;
-use crate::presentation::config::{ExtractorProvider, PdfExtractionSettings};
+use crate::presentation::config::{ExtractorProvider, PdfExtractionSettings, PdfFallbackProvider};
 
 use super::azure_doc_intel_adapter::AzureDocIntelAdapter;
+use super::hybrid_pdf_adapter::{HybridPdfAdapter, PageRecognizer};
 use super::lm_studio_vlm_pdf_adapter::LmStudioVlmPdfAdapter;
 use super::local_vlm_pdf_adapter::LocalVlmPdfAdapter;
 
@@ -28,35 +29,8 @@ impl ExtractorFactory {
         settings: &PdfExtractionSettings,
     ) -> Result<Arc<dyn FileLoader>, ExtractorFactoryError> {
         match settings.provider {
-            ExtractorProvider::LocalVlm => {
-                let model = settings
-                    .vlm_model
-                    .as_deref()
-                    .unwrap_or("vikhyatk/moondream1");
-                let revision = settings
-                    .vlm_revision
-                    .as_deref()
-                    .or(Some("f6e9da68e8f1b78b8f3ee10905d56826db7a5802"));
-                tracing::info!(model, "Loading local VLM PDF adapter");
-                let adapter = LocalVlmPdfAdapter::new(model, revision)
-                    .map_err(|e| ExtractorFactoryError::InitializationFailed(e.to_string()))?;
-                Ok(Arc::new(adapter))
-            }
-            ExtractorProvider::LmStudio => {
-                let base_url = settings
-                    .vlm_base_url
-                    .as_deref()
-                    .ok_or(ExtractorFactoryError::MissingVlmBaseUrl)?;
-                let model = settings
-                    .vlm_model
-                    .as_deref()
-                    .ok_or(ExtractorFactoryError::MissingVlmModel)?;
-                let api_key = settings.vlm_api_key.as_deref().unwrap_or("lm-studio");
-                tracing::info!(model, base_url, "Loading LM Studio VLM PDF adapter");
-                Ok(Arc::new(LmStudioVlmPdfAdapter::new(
-                    base_url, model, api_key,
-                )))
-            }
+            ExtractorProvider::LocalVlm => Ok(Arc::new(Self::local_vlm(settings)?)),
+            ExtractorProvider::LmStudio => Ok(Arc::new(Self::lm_studio(settings)?)),
             ExtractorProvider::Azure => {
                 let endpoint = settings
                     .azure_endpoint
@@ -69,6 +43,54 @@ impl ExtractorFactory {
                 tracing::info!("Loading Azure Document Intelligence PDF adapter");
                 Ok(Arc::new(AzureDocIntelAdapter::new(endpoint, key)))
             }
+            ExtractorProvider::Hybrid => {
+                let fallback: Option<Arc<dyn PageRecognizer>> = match settings.fallback_provider {
+                    PdfFallbackProvider::LocalVlm => Some(Arc::new(Self::local_vlm(settings)?)),
+                    PdfFallbackProvider::LmStudio => Some(Arc::new(Self::lm_studio(settings)?)),
+                    PdfFallbackProvider::None => None,
+                };
+                tracing::info!(
+                    fallback = ?settings.fallback_provider,
+                    min_page_chars = settings.min_page_chars,
+                    "Loading hybrid text-layer PDF adapter"
+                );
+                Ok(Arc::new(HybridPdfAdapter::new(
+                    fallback,
+                    settings.min_page_chars,
+                )))
+            }
         }
     }
+
+    fn local_vlm(
+        settings: &PdfExtractionSettings,
+    ) -> Result<LocalVlmPdfAdapter, ExtractorFactoryError> {
+        let model = settings
+            .vlm_model
+            .as_deref()
+            .unwrap_or("vikhyatk/moondream1");
+        let revision = settings
+            .vlm_revision
+            .as_deref()
+            .or(Some("f6e9da68e8f1b78b8f3ee10905d56826db7a5802"));
+        tracing::info!(model, "Loading local VLM PDF adapter");
+        LocalVlmPdfAdapter::new(model, revision)
+            .map_err(|e| ExtractorFactoryError::InitializationFailed(e.to_string()))
+    }
+
+    fn lm_studio(
+        settings: &PdfExtractionSettings,
+    ) -> Result<LmStudioVlmPdfAdapter, ExtractorFactoryError> {
+        let base_url = settings
+            .vlm_base_url
+            .as_deref()
+            .ok_or(ExtractorFactoryError::MissingVlmBaseUrl)?;
+        let model = settings
+            .vlm_model
+            .as_deref()
+            .ok_or(ExtractorFactoryError::MissingVlmModel)?;
+        let api_key = settings.vlm_api_key.as_deref().unwrap_or("lm-studio");
+        tracing::info!(model, base_url, "Loading LM Studio VLM PDF adapter");
+        Ok(LmStudioVlmPdfAdapter::new(base_url, model, api_key))
+    }
 }
@@ -0,0 +1,96 @@
+use scraper::{ElementRef, Node};
+
+use super::super::markdown_render::{collapse_whitespace, markdown_table};
+use super::markdown::Converter;
+
+impl Converter {
+    pub(super) fn list_lines(&self, list: ElementRef<'_>, depth: usize) -> Vec<String> {
+        let ordered = list.value().name() == "ol";
+        let indent = "  ".repeat(depth);
+        let mut lines = Vec::new();
+        for item in list
+            .child_elements()
+            .filter(|e| e.value().name() == "li" && !self.is_boilerplate(*e))
+        {
+            let mut content = self.nested();
+            let mut sublists = Vec::new();
+            for child in item.children() {
+                match child.value() {
+                    Node::Text(text) => content.inline.push_str(text),
+                    Node::Element(e) if matches!(e.name(), "ul" | "ol") => {
+                        sublists.extend(ElementRef::wrap(child));
+                    }
+                    Node::Element(_) => {
+                        if let Some(child) = ElementRef::wrap(child) {
+                            content.render_element(child);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            let text = collapse_whitespace(&content.finish().join(" "));
+            if !text.is_empty() {
+                let marker = if ordered { "1." } else { "-" };
+                lines.push(format!("{}{} {}", indent, marker, text));
+            }
+            for sublist in sublists {
+                lines.extend(self.list_lines(sublist, depth + 1));
+            }
+        }
+        lines
+    }
+
+    /// Data tables become markdown tables; single-column layout tables are rendered as
+    /// ordinary blocks.
+    pub(super) fn render_table(&mut self, table: ElementRef<'_>) {
+        let rows: Vec<ElementRef<'_>> = table
+            .child_elements()
+            .flat_map(|child| match child.value().name() {
+                "tr" => vec![child],
+                "thead" | "tbody" | "tfoot" => child
+                    .child_elements()
+                    .filter(|e| e.value().name() == "tr")
+                    .collect(),
+                _ => Vec::new(),
+            })
+            .collect();
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                row.child_elements()
+                    .filter(|c| matches!(c.value().name(), "td" | "th"))
+                    .map(|c| self.inline_text(c))
+                    .collect()
+            })
+            .collect();
+
+        if cells.iter().all(|row| row.len() < 2) {
+            self.flush();
+            self.render_children(table);
+            self.flush();
+        } else {
+            self.push_block(markdown_table(&cells));
+        }
+    }
+}
+
+/// Fenced code block with the language taken from a `language-*` or `lang-*` class on the
+/// `<pre>` or its `<code>`.
+pub(super) fn code_block(pre: ElementRef<'_>) -> String {
+    let code = pre.text().collect::<String>();
+    let code = code.trim_matches('\n');
+    if code.trim().is_empty() {
+        return String::new();
+    }
+    let language = std::iter::once(pre)
+        .chain(pre.child_elements().filter(|e| e.value().name() == "code"))
+        .flat_map(|e| e.value().classes())
+        .find_map(|class| {
+            class
+                .strip_prefix("language-")
+                .or_else(|| class.strip_prefix("lang-"))
+        })
+        .unwrap_or("");
+    let fence = if code.contains("```") { "~~~" } else { "```" };
+    format!("{fence}{language}\n{code}\n{fence}")
+}
@@ -0,0 +1,174 @@
+use reqwest::Url;
+use scraper::{ElementRef, Node};
+
+use super::super::markdown_render::collapse_whitespace;
+use super::blocks::code_block;
+
+/// Elements that start a new block; anything else is rendered inline.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "html",
+    "body",
+    "main",
+    "article",
+    "section",
+    "div",
+    "p",
+    "header",
+    "footer",
+    "address",
+    "figure",
+    "figcaption",
+    "details",
+    "summary",
+    "dl",
+    "dt",
+    "dd",
+    "center",
+];
+
+/// Walks the DOM, collecting finished markdown blocks and the inline text of the block
+/// being built.
+pub(super) struct Converter {
+    base: Option<Url>,
+    /// Whether `<header>` and `<footer>` belong to the page rather than the content.
+    pub(super) strip_page_chrome: bool,
+    blocks: Vec<String>,
+    pub(super) inline: String,
+}
+
+impl Converter {
+    pub(super) fn new(base: Option<Url>, strip_page_chrome: bool) -> Self {
+        Self {
+            base,
+            strip_page_chrome,
+            blocks: Vec::new(),
+            inline: String::new(),
+        }
+    }
+
+    pub(super) fn nested(&self) -> Self {
+        Self {
+            base: self.base.clone(),
+            strip_page_chrome: self.strip_page_chrome,
+            blocks: Vec::new(),
+            inline: String::new(),
+        }
+    }
+
+    pub(super) fn finish(mut self) -> Vec<String> {
+        self.flush();
+        self.blocks
+    }
+
+    pub(super) fn flush(&mut self) {
+        let text = collapse_whitespace(&std::mem::take(&mut self.inline));
+        if !text.is_empty() {
+            self.blocks.push(text);
+        }
+    }
+
+    pub(super) fn push_block(&mut self, block: String) {
+        self.flush();
+        if !block.is_empty() {
+            self.blocks.push(block);
+        }
+    }
+
+    pub(super) fn render_children(&mut self, element: ElementRef<'_>) {
+        for child in element.children() {
+            match child.value() {
+                Node::Text(text) => self.inline.push_str(text),
+                Node::Element(_) => {
+                    if let Some(child) = ElementRef::wrap(child) {
+                        self.render_element(child);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    pub(super) fn render_element(&mut self, element: ElementRef<'_>) {
+        let name = element.value().name();
+        if self.is_boilerplate(element) {
+            return;
+        }
+
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                let level = usize::from(name.as_bytes()[1] - b'0');
+                let text = self.inline_text(element);
+                if !text.is_empty() {
+                    self.push_block(format!("{} {}", "#".repeat(level), text));
+                }
+            }
+            "ul" | "ol" => {
+                let lines = self.list_lines(element, 0);
+                self.push_block(lines.join("\n"));
+            }
+            "pre" => self.push_block(code_block(element)),
+            "table" => self.render_table(element),
+            "blockquote" => {
+                let mut quote = self.nested();
+                quote.render_children(element);
+                let quoted = quote
+                    .finish()
+                    .iter()
+                    .flat_map(|block| block.lines().map(|line| format!("> {}", line)))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                self.push_block(quoted);
+            }
+            "hr" => self.flush(),
+            "br" => self.inline.push(' '),
+            "a" => {
+                let label = self.inline_text(element);
+                match element
+                    .value()
+                    .attr("href")
+                    .and_then(|h| self.link_target(h))
+                {
+                    Some(target) if !label.is_empty() => {
+                        self.inline.push_str(&format!("[{}]({})", label, target))
+                    }
+                    _ => self.inline.push_str(&label),
+                }
+            }
+            "code" | "kbd" | "samp" => {
+                let code = collapse_whitespace(&element.text().collect::<String>());
+                if !code.is_empty() {
+                    self.inline.push_str(&format!("`{}`", code));
+                }
+            }
+            name if BLOCK_ELEMENTS.contains(&name) => {
+                self.flush();
+                self.render_children(element);
+                self.flush();
+            }
+            _ => self.render_children(element),
+        }
+    }
+
+    /// The element's content on a single line.
+    pub(super) fn inline_text(&self, element: ElementRef<'_>) -> String {
+        let mut nested = self.nested();
+        nested.render_children(element);
+        collapse_whitespace(&nested.finish().join(" "))
+    }
+
+    /// Absolute target of a link, or `None` for scripts and same-page anchors.
+    fn link_target(&self, href: &str) -> Option<String> {
+        let href = href.trim();
+        if href.is_empty()
+            || href.starts_with('#')
+            || href.to_ascii_lowercase().starts_with("javascript:")
+        {
+            return None;
+        }
+        match &self.base {
+            Some(base) => base.join(href).ok().map(String::from),
+            None => Some(href.to_string()),
+        }
+        .map(|target| target.replace(' ', "%20"))
+    }
+}
@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use reqwest::Url;
+use scraper::Html;
+
+use crate::application::ports::{FileLoader, FileLoaderError};
+use crate::domain::{ContentType, Document, PageSegment};
+
+use super::markdown_render::collapse_whitespace;
+
+mod blocks;
+mod markdown;
+mod readability;
+
+use markdown::Converter;
+use readability::main_content;
+
+/// Converts HTML pages to markdown. The main content is located through `<main>`,
+/// `<article>` or `role="main"`, falling back to `<body>` without its header and footer;
+/// navigation, scripts, forms and other boilerplate are dropped. Headings, lists, tables
+/// and preformatted code become their markdown equivalents, and links are kept as
+/// `[label](target)` with targets resolved against `<base>`, the canonical URL or — for
+/// crawled pages — the page URL, so the markdown splitter can record them on each chunk.
+pub struct HtmlAdapter;
+
+#[async_trait]
+impl FileLoader for HtmlAdapter {
+    async fn extract_pages(
+        &self,
+        data: &[u8],
+        document: &Document,
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
+        if document.content_type != ContentType::Html {
+            return Err(FileLoaderError::UnsupportedContentType(
+                document.content_type.as_mime().to_string(),
+            ));
+        }
+
+        // Crawled pages are named by their URL, which resolves links lacking a `<base>`.
+        let page_url = Url::parse(&document.filename).ok();
+        let markdown = html_to_markdown(&String::from_utf8_lossy(data), page_url);
+        if markdown.is_empty() {
+            return Err(FileLoaderError::NoTextFound(document.filename.clone()));
+        }
+        tracing::debug!(chars = markdown.len(), "HTML converted to markdown");
+        Ok(vec![PageSegment::unpaged(markdown)])
+    }
+}
+
+fn html_to_markdown(html: &str, page_url: Option<Url>) -> String {
+    let html = Html::parse_document(html);
+    let root = html.root_element();
+    let find = |name: &str| {
+        root.descendent_elements()
+            .find(|e| e.value().name() == name)
+    };
+
+    let base = find("base")
+        .and_then(|e| e.value().attr("href"))
+        .or_else(|| {
+            root.descendent_elements()
+                .find(|e| e.value().name() == "link" && e.value().attr("rel") == Some("canonical"))
+                .and_then(|e| e.value().attr("href"))
+        })
+        .and_then(|href| match &page_url {
+            Some(page_url) => page_url.join(href.trim()).ok(),
+            None => Url::parse(href.trim()).ok(),
+        })
+        .or(page_url);
+    let title = find("title")
+        .map(|e| collapse_whitespace(&e.text().collect::<String>()))
+        .unwrap_or_default();
+
+    let content = main_content(root);
+    let mut converter = Converter::new(base, content.is_none());
+    converter.render_children(content.or_else(|| find("body")).unwrap_or(root));
+    let mut blocks = converter.finish();
+
+    if !title.is_empty() && !blocks.iter().any(|b| b.starts_with("# ")) {
+        blocks.insert(0, format!("# {}", title));
+    }
+    blocks.join("\n\n")
+}
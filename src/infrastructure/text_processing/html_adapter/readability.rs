@@ -0,0 +1,73 @@
+use scraper::ElementRef;
+
+use super::markdown::Converter;
+
+/// Elements that never hold readable content.
+const SKIPPED_ELEMENTS: &[&str] = &[
+    "script", "style", "noscript", "template", "head", "nav", "aside", "form", "button", "select",
+    "textarea", "input", "iframe", "object", "embed", "svg", "canvas", "video", "audio", "picture",
+    "img", "dialog",
+];
+
+/// ARIA landmarks of site chrome rather than page content.
+const BOILERPLATE_ROLES: &[&str] = &[
+    "navigation",
+    "banner",
+    "contentinfo",
+    "complementary",
+    "search",
+    "dialog",
+];
+
+/// Class and id tokens (split on `-` and `_`) that mark navigation, consent banners and
+/// sharing widgets.
+const BOILERPLATE_TOKENS: &[&str] = &[
+    "nav",
+    "navbar",
+    "navigation",
+    "menu",
+    "breadcrumb",
+    "breadcrumbs",
+    "sidebar",
+    "cookie",
+    "cookies",
+    "consent",
+    "advert",
+    "advertisement",
+    "share",
+    "social",
+];
+
+/// The page's main content: `<main>`, `<article>` or the `role="main"` element.
+pub(super) fn main_content(root: ElementRef<'_>) -> Option<ElementRef<'_>> {
+    let find = |name: &str| {
+        root.descendent_elements()
+            .find(|e| e.value().name() == name)
+    };
+    find("main").or_else(|| find("article")).or_else(|| {
+        root.descendent_elements()
+            .find(|e| e.value().attr("role") == Some("main"))
+    })
+}
+
+impl Converter {
+    pub(super) fn is_boilerplate(&self, element: ElementRef<'_>) -> bool {
+        let value = element.value();
+        let name = value.name();
+        if SKIPPED_ELEMENTS.contains(&name)
+            || (self.strip_page_chrome && matches!(name, "header" | "footer"))
+            || value.attr("hidden").is_some()
+            || value.attr("aria-hidden") == Some("true")
+            || value
+                .attr("role")
+                .is_some_and(|role| BOILERPLATE_ROLES.contains(&role))
+        {
+            return true;
+        }
+        value
+            .classes()
+            .chain(value.id())
+            .flat_map(|name| name.split(['-', '_']))
+            .any(|token| BOILERPLATE_TOKENS.contains(&token.to_ascii_lowercase().as_str()))
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::application::ports::{FileLoader, FileLoaderError};
+use crate::domain::{ContentType, Document, PageSegment};
+
+use super::local_vlm_pdf_adapter::{EXTRACTION_TIMEOUT, MAX_PAGES_DUE_TO_RAM_USAGE};
+use super::pdf_rasterizer::rasterize_selected_pages;
+use super::pdf_text_layer::{extract_text_layer, layout_markdown};
+use super::text_sanitizer::sanitize_extracted_text;
+
+/// Turns a single rasterized PDF page into Markdown.
+#[async_trait]
+pub trait PageRecognizer: Send + Sync {
+    async fn recognize_page(
+        &self,
+        png_bytes: &[u8],
+        page_index: usize,
+    ) -> Result<String, FileLoaderError>;
+}
+
+/// Reads the embedded PDF text layer and sends only pages with too little text (scans,
+/// image-only slides) to a VLM. A page the VLM cannot read keeps its text layer, so one
+/// bad page never fails the document.
+pub struct HybridPdfAdapter {
+    fallback: Option<Arc<dyn PageRecognizer>>,
+    min_page_chars: usize,
+}
+
+impl HybridPdfAdapter {
+    /// `fallback` of `None` extracts the text layer only.
+    pub fn new(fallback: Option<Arc<dyn PageRecognizer>>, min_page_chars: usize) -> Self {
+        Self {
+            fallback,
+            min_page_chars,
+        }
+    }
+
+    fn is_low_text(&self, text: &str) -> bool {
+        text.chars().filter(|c| c.is_alphanumeric()).count() < self.min_page_chars
+    }
+
+    async fn blocking<T, F>(task: F, what: &str) -> Result<T, FileLoaderError>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> Result<T, FileLoaderError> + Send + 'static,
+    {
+        let panic_message = format!("OOM or panic during PDF {what}");
+        tokio::time::timeout(
+            EXTRACTION_TIMEOUT,
+            tokio::task::spawn_blocking(move || {
+                std::panic::catch_unwind(std::panic::AssertUnwindSafe(task))
+                    .unwrap_or_else(|_| Err(FileLoaderError::ExtractionFailed(panic_message)))
+            }),
+        )
+        .await
+        .map_err(|_| FileLoaderError::ExtractionFailed(format!("PDF {what} timed out")))?
+        .map_err(|e| FileLoaderError::ExtractionFailed(format!("task join error: {e}")))?
+    }
+}
+
+#[async_trait]
+impl FileLoader for HybridPdfAdapter {
+    #[tracing::instrument(
+        skip(self, data),
+        fields(
+            document_id = %document.id.as_uuid(),
+            filename = %document.filename,
+            fallback_pages
+        )
+    )]
+    async fn extract_pages(
+        &self,
+        data: &[u8],
+        document: &Document,
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
+        if document.content_type != ContentType::Pdf {
+            return Err(FileLoaderError::UnsupportedContentType(
+                document.content_type.as_mime().to_string(),
+            ));
+        }
+
+        let data_owned = data.to_vec();
+        let text_layer =
+            Self::blocking(move || extract_text_layer(&data_owned), "text extraction").await?;
+
+        let mut page_texts: Vec<String> = text_layer
+            .iter()
+            .map(|lines| sanitize_extracted_text(&layout_markdown(lines)))
+            .collect();
+
+        let low_text: Vec<usize> = page_texts
+            .iter()
+            .enumerate()
+            .filter(|(_, text)| self.is_low_text(text))
+            .map(|(index, _)| index)
+            .collect();
+        tracing::Span::current().record("fallback_pages", low_text.len());
+
+        if let Some(fallback) = self.fallback.as_ref().filter(|_| !low_text.is_empty()) {
+            let mut low_text = low_text;
+            if low_text.len() > MAX_PAGES_DUE_TO_RAM_USAGE {
+                tracing::warn!(
+                    skipped_pages = low_text.len() - MAX_PAGES_DUE_TO_RAM_USAGE,
+                    "Too many low-text pages to rasterize; the rest keep their text layer"
+                );
+                low_text.truncate(MAX_PAGES_DUE_TO_RAM_USAGE);
+            }
+            tracing::info!(
+                page_count = page_texts.len(),
+                fallback_pages = low_text.len(),
+                "Text layer extracted, running VLM on low-text pages"
+            );
+
+            let data_owned = data.to_vec();
+            let indices = low_text.clone();
+            let png_buffers = Self::blocking(
+                move || rasterize_selected_pages(&data_owned, &indices),
+                "rasterization",
+            )
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Rasterization failed; low-text pages keep their text layer");
+                Vec::new()
+            });
+
+            for (index, png_bytes) in low_text.into_iter().zip(png_buffers) {
+                let recognized = match fallback.recognize_page(&png_bytes, index).await {
+                    Ok(recognized) => sanitize_extracted_text(&recognized),
+                    Err(e) => {
+                        tracing::warn!(page = index + 1, error = %e, "VLM fallback failed; page keeps its text layer");
+                        continue;
+                    }
+                };
+                if recognized.trim().len() > page_texts[index].trim().len() {
+                    page_texts[index] = recognized;
+                }
+            }
+        }
+
+        let pages: Vec<PageSegment> = page_texts
+            .into_iter()
+            .enumerate()
+            .filter(|(_, text)| !text.trim().is_empty())
+            .map(|(index, text)| PageSegment::new(index as u32 + 1, text))
+            .collect();
+
+        if pages.is_empty() {
+            return Err(FileLoaderError::NoTextFound(document.filename.clone()));
+        }
+
+        Ok(pages)
+    }
+}
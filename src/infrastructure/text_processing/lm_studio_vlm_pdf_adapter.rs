@@ -6,8 +6,9 @@ use reqwest::Client;
 use serde::Deserialize;
 
 use crate::application::ports::{FileLoader, FileLoaderError};
-use crate::domain::{ContentType, Document};
+use crate::domain::{ContentType, Document, PageSegment};
 
+use super::hybrid_pdf_adapter::PageRecognizer;
 use super::local_vlm_pdf_adapter::{EXTRACTION_TIMEOUT, OCR_PROMPT};
 use super::pdf_rasterizer::rasterize_pages;
 use super::text_sanitizer::sanitize_extracted_text;
@@ -132,6 +133,17 @@ struct ChatMessage {
     content: Option<String>,
 }
 
+#[async_trait]
+impl PageRecognizer for LmStudioVlmPdfAdapter {
+    async fn recognize_page(
+        &self,
+        png_bytes: &[u8],
+        page_index: usize,
+    ) -> Result<String, FileLoaderError> {
+        self.infer_page_markdown(png_bytes, page_index).await
+    }
+}
+
 #[async_trait]
 impl FileLoader for LmStudioVlmPdfAdapter {
     #[tracing::instrument(
@@ -141,11 +153,11 @@ impl FileLoader for LmStudioVlmPdfAdapter {
             filename = %document.filename
         )
     )]
-    async fn extract_text(
+    async fn extract_pages(
         &self,
         data: &[u8],
         document: &Document,
-    ) -> Result<String, FileLoaderError> {
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
         if document.content_type != ContentType::Pdf {
             return Err(FileLoaderError::UnsupportedContentType(
                 document.content_type.as_mime().to_string(),
@@ -178,21 +190,24 @@ impl FileLoader for LmStudioVlmPdfAdapter {
             "PDF rasterization complete, starting LM Studio VLM inference"
         );
 
-        let mut page_texts: Vec<String> = Vec::with_capacity(png_buffers.len());
+        let mut pages: Vec<PageSegment> = Vec::with_capacity(png_buffers.len());
 
         for (index, png_bytes) in png_buffers.iter().enumerate() {
             tracing::debug!(page = index, "Page converted to markdown");
 
             let page_text = self.infer_page_markdown(png_bytes, index).await?;
             if !page_text.trim().is_empty() {
-                page_texts.push(sanitize_extracted_text(&page_text));
+                pages.push(PageSegment::new(
+                    index as u32 + 1,
+                    sanitize_extracted_text(&page_text),
+                ));
             }
         }
 
-        if page_texts.is_empty() {
+        if pages.is_empty() {
             return Err(FileLoaderError::NoTextFound(filename));
         }
 
-        Ok(page_texts.join("\n\n"))
+        Ok(pages)
     }
 }
@@ -15,8 +15,9 @@ use tokenizers::Tokenizer;
 use tokio::sync::Mutex;
 
 use crate::application::ports::{FileLoader, FileLoaderError};
-use crate::domain::{ContentType, Document};
+use crate::domain::{ContentType, Document, PageSegment};
 
+use super::hybrid_pdf_adapter::PageRecognizer;
 use super::pdf_rasterizer::rasterize_pages;
 use super::text_sanitizer::sanitize_extracted_text;
 
@@ -315,6 +316,17 @@ fn run_page_inference(
     Ok(final_text.replace("<END>", "").trim().to_string())
 }
 
+#[async_trait]
+impl PageRecognizer for LocalVlmPdfAdapter {
+    async fn recognize_page(
+        &self,
+        png_bytes: &[u8],
+        page_index: usize,
+    ) -> Result<String, FileLoaderError> {
+        self.infer_page_markdown(png_bytes, page_index).await
+    }
+}
+
 #[async_trait]
 impl FileLoader for LocalVlmPdfAdapter {
     #[tracing::instrument(
@@ -324,11 +336,11 @@ impl FileLoader for LocalVlmPdfAdapter {
             filename = %document.filename
         )
     )]
-    async fn extract_text(
+    async fn extract_pages(
         &self,
         data: &[u8],
         document: &Document,
-    ) -> Result<String, FileLoaderError> {
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
         if document.content_type != ContentType::Pdf {
             return Err(FileLoaderError::UnsupportedContentType(
                 document.content_type.as_mime().to_string(),
@@ -361,21 +373,24 @@ impl FileLoader for LocalVlmPdfAdapter {
             "PDF rasterization complete, starting VLM inference"
         );
 
-        let mut page_texts: Vec<String> = Vec::with_capacity(png_buffers.len());
+        let mut pages: Vec<PageSegment> = Vec::with_capacity(png_buffers.len());
 
         for (index, png_bytes) in png_buffers.iter().enumerate() {
             tracing::info!("Page: {index} infer to markdown");
 
             let page_text = self.infer_page_markdown(png_bytes, index).await?;
             if !page_text.trim().is_empty() {
-                page_texts.push(sanitize_extracted_text(&page_text));
+                pages.push(PageSegment::new(
+                    index as u32 + 1,
+                    sanitize_extracted_text(&page_text),
+                ));
             }
         }
 
-        if page_texts.is_empty() {
+        if pages.is_empty() {
             return Err(FileLoaderError::NoTextFound(filename));
         }
 
-        Ok(page_texts.join("\n\n"))
+        Ok(pages)
     }
 }
@@ -0,0 +1,38 @@
+/// Collapses runs of whitespace into single spaces and trims the ends.
+pub(super) fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Renders rows as a GitHub-flavoured markdown table with the first row as header.
+/// Rows are padded to the widest one; fully empty rows are dropped. Returns an empty
+/// string when no row has content.
+pub(super) fn markdown_table(rows: &[Vec<String>]) -> String {
+    let rows: Vec<&Vec<String>> = rows
+        .iter()
+        .filter(|r| r.iter().any(|c| !c.trim().is_empty()))
+        .collect();
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    if width == 0 {
+        return String::new();
+    }
+
+    let render_row = |row: &Vec<String>| {
+        let mut line = String::from("|");
+        for i in 0..width {
+            line.push(' ');
+            line.push_str(&escape_cell(row.get(i).map(String::as_str).unwrap_or("")));
+            line.push_str(" |");
+        }
+        line
+    };
+
+    let mut lines = Vec::with_capacity(rows.len() + 1);
+    lines.push(render_row(rows[0]));
+    lines.push(format!("|{}", " --- |".repeat(width)));
+    lines.extend(rows[1..].iter().map(|r| render_row(r)));
+    lines.join("\n")
+}
+
+fn escape_cell(text: &str) -> String {
+    collapse_whitespace(text).replace('|', "\\|")
+}
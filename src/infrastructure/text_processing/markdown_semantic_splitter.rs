@@ -4,9 +4,10 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::application::ports::{TextSplitter, TextSplitterError};
-use crate::domain::{Chunk, DocumentId, DocumentMetadata, TranscriptSegment};
+use crate::domain::{Chunk, DocumentId, DocumentMetadata, PageSegment, TranscriptSegment};
 
 use super::SemanticSplitter;
+use super::page_map::PageMap;
 
 static ABBREVIATIONS: &[&str] = &[
     "e.g", "i.e", "vs", "etc", "fig", "dr", "mr", "mrs", "prof", "st", "no", "vol", "dept",
@@ -35,14 +36,21 @@ enum MarkdownBlock {
     Prose(String),
 }
 
-/// A section groups a header with its content blocks.
+/// A section groups a header with its content blocks, each tagged with its source page.
 struct Section {
     header: Option<String>,
-    blocks: Vec<MarkdownBlock>,
+    blocks: Vec<(Option<u32>, MarkdownBlock)>,
 }
 
-/// Groups parsed blocks into sections: each header starts a new section.
-fn group_into_sections(blocks: Vec<MarkdownBlock>) -> Vec<Section> {
+/// Chunk text plus the first and last page of the content it holds.
+struct SectionChunk {
+    text: String,
+    pages: Option<(u32, u32)>,
+}
+
+/// Groups parsed blocks into sections: each header starts a new section. Sections carry
+/// over page breaks, so a heading applies until the next heading on any later page.
+fn group_into_sections(blocks: Vec<(Option<u32>, MarkdownBlock)>) -> Vec<Section> {
     let mut sections: Vec<Section> = Vec::new();
     let mut current = Section {
         header: None,
@@ -50,7 +58,7 @@ fn group_into_sections(blocks: Vec<MarkdownBlock>) -> Vec<Section> {
     };
     let mut ancestor_stack: Vec<(usize, String)> = Vec::new();
 
-    for block in blocks {
+    for (page, block) in blocks {
         match block {
             MarkdownBlock::Header(level, text) => {
                 // Only push if the section has content; header-only sections are
@@ -76,7 +84,7 @@ fn group_into_sections(blocks: Vec<MarkdownBlock>) -> Vec<Section> {
                 };
             }
             other => {
-                current.blocks.push(other);
+                current.blocks.push((page, other));
             }
         }
     }
@@ -247,6 +255,101 @@ fn is_abbreviation_dot(text: &str, dot_pos: usize) -> bool {
     ABBREVIATIONS.contains(&lower.as_str())
 }
 
+/// Reduces inline links `[label](target)` to their label and returns the distinct targets
+/// in order of appearance. Image alt text is kept without recording the image, same-page
+/// `#anchor` links are not targets, and code spans and fences are left untouched.
+fn extract_links(text: &str) -> (String, Vec<String>) {
+    let mut output = String::with_capacity(text.len());
+    let mut links: Vec<String> = Vec::new();
+    let mut in_fence = false;
+
+    for (index, line) in text.split('\n').enumerate() {
+        if index > 0 {
+            output.push('\n');
+        }
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        }
+        if in_fence || trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            output.push_str(line);
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(pos) = rest.find(['[', '`']) {
+            let (before, from) = rest.split_at(pos);
+            output.push_str(before);
+            if let Some(code) = from.strip_prefix('`') {
+                let end = code.find('`').map_or(from.len(), |e| e + 2);
+                output.push_str(&from[..end]);
+                rest = &from[end..];
+                continue;
+            }
+            match parse_inline_link(from) {
+                Some((label, target, consumed)) => {
+                    let is_image = output.ends_with('!');
+                    if is_image {
+                        output.pop();
+                    }
+                    output.push_str(label);
+                    if !is_image && !target.starts_with('#') && !links.iter().any(|l| l == target) {
+                        links.push(target.to_string());
+                    }
+                    rest = &from[consumed..];
+                }
+                None => {
+                    output.push('[');
+                    rest = &from[1..];
+                }
+            }
+        }
+        output.push_str(rest);
+    }
+
+    (output, links)
+}
+
+/// Parses `[label](target "title")` at the start of `text`, returning the label, the
+/// target and the number of bytes consumed.
+fn parse_inline_link(text: &str) -> Option<(&str, &str, usize)> {
+    let close = text.find(']')?;
+    let label = &text[1..close];
+    if label.contains('[') || !text[close + 1..].starts_with('(') {
+        return None;
+    }
+    let body_start = close + 2;
+    let body = &text[body_start..];
+
+    let (target, after_target) = if let Some(angled) = body.strip_prefix('<') {
+        let end = angled.find('>')?;
+        (&angled[..end], end + 2)
+    } else {
+        let mut depth = 0usize;
+        let end = body
+            .char_indices()
+            .find(|&(_, c)| match c {
+                '(' => {
+                    depth += 1;
+                    false
+                }
+                ')' if depth > 0 => {
+                    depth -= 1;
+                    false
+                }
+                ')' => true,
+                c => c.is_whitespace(),
+            })
+            .map(|(i, _)| i)?;
+        (&body[..end], end)
+    };
+    let close_paren = body[after_target..].find(')')? + after_target;
+    if target.is_empty() {
+        return None;
+    }
+    Some((label, target, body_start + close_paren + 1))
+}
+
 /// Collects text units from a block for token-budgeted chunking.
 ///
 /// Prose: kept as a single atomic string (paragraph atomicity).
@@ -293,30 +396,60 @@ impl TextSplitter for MarkdownSemanticSplitter {
         document_id: DocumentId,
         metadata: Option<Arc<DocumentMetadata>>,
     ) -> Result<Vec<Chunk>, TextSplitterError> {
-        let blocks = parse_markdown_blocks(text);
+        self.split_pages(&[PageSegment::unpaged(text)], document_id, metadata)
+            .await
+    }
+
+    async fn split_pages(
+        &self,
+        pages: &[PageSegment],
+        document_id: DocumentId,
+        metadata: Option<Arc<DocumentMetadata>>,
+    ) -> Result<Vec<Chunk>, TextSplitterError> {
+        // Blocks are parsed page by page so each one knows its page; the merged text is
+        // only used for offsets.
+        let (text, _) = PageMap::merge(pages);
+        let blocks = pages
+            .iter()
+            .flat_map(|segment| {
+                parse_markdown_blocks(&segment.text)
+                    .into_iter()
+                    .map(|block| (segment.page, block))
+            })
+            .collect();
         let sections = group_into_sections(blocks);
 
         if sections.is_empty() {
             return Ok(Vec::new());
         }
 
-        let chunk_texts = self.build_section_chunks(&sections);
+        let section_chunks = self.build_section_chunks(&sections);
 
-        let mut all_chunks = Vec::with_capacity(chunk_texts.len());
+        let mut all_chunks = Vec::with_capacity(section_chunks.len());
         let mut search_from = 0usize;
 
-        for chunk_text in chunk_texts {
+        for SectionChunk {
+            text: chunk_text,
+            pages: chunk_pages,
+        } in section_chunks
+        {
             let first_word = chunk_text.split_whitespace().next().unwrap_or(&chunk_text);
             let offset = text[search_from..]
                 .find(first_word)
                 .map(|rel| search_from + rel)
                 .unwrap_or(search_from);
 
+            let (chunk_text, links) = extract_links(&chunk_text);
             let chunk = match &metadata {
                 Some(meta) => {
                     Chunk::with_metadata(chunk_text, document_id, None, offset, Arc::clone(meta))
                 }
                 None => Chunk::new(chunk_text, document_id, None, offset),
+            }
+            .with_links(links);
+            let chunk = match chunk_pages {
+                Some((first, last)) => chunk.with_pages(first, last),
+                None => chunk,
             };
             all_chunks.push(chunk);
             search_from = offset;
@@ -337,16 +470,23 @@ impl TextSplitter for MarkdownSemanticSplitter {
 }
 
 impl MarkdownSemanticSplitter {
-    /// Builds chunk text strings from sections with header propagation and hard boundaries.
-    fn build_section_chunks(&self, sections: &[Section]) -> Vec<String> {
-        let mut chunks: Vec<String> = Vec::new();
+    /// Builds chunk texts from sections with header propagation and hard boundaries.
+    ///
+    /// Page ranges count only content blocks; a propagated header does not pull its
+    /// page into a continuation chunk.
+    fn build_section_chunks(&self, sections: &[Section]) -> Vec<SectionChunk> {
+        let mut chunks: Vec<SectionChunk> = Vec::new();
         let mut current_text = String::new();
         let mut current_tokens: usize = 0;
+        let mut current_pages: Option<(u32, u32)> = None;
 
         for section in sections {
             // Hard boundary: finalize any in-progress chunk when a new header section starts
             if section.header.is_some() && !current_text.is_empty() {
-                chunks.push(std::mem::take(&mut current_text));
+                chunks.push(SectionChunk {
+                    text: std::mem::take(&mut current_text),
+                    pages: current_pages.take(),
+                });
                 current_tokens = 0;
             }
 
@@ -363,7 +503,7 @@ impl MarkdownSemanticSplitter {
                 current_tokens = header_tokens;
             }
 
-            for block in &section.blocks {
+            for (page, block) in &section.blocks {
                 let units = block_to_units(block, &self.inner, self.max_tokens);
 
                 for unit in units {
@@ -373,7 +513,10 @@ impl MarkdownSemanticSplitter {
                     if current_tokens + separator_tokens + unit_tokens > self.max_tokens
                         && !current_text.is_empty()
                     {
-                        chunks.push(std::mem::take(&mut current_text));
+                        chunks.push(SectionChunk {
+                            text: std::mem::take(&mut current_text),
+                            pages: current_pages.take(),
+                        });
                         current_tokens = 0;
 
                         // Header propagation: continuation chunks get the header prefix
@@ -389,12 +532,21 @@ impl MarkdownSemanticSplitter {
                     }
                     current_text.push_str(&unit);
                     current_tokens += unit_tokens;
+                    if let Some(page) = *page {
+                        current_pages = Some(match current_pages {
+                            Some((first, last)) => (first.min(page), last.max(page)),
+                            None => (page, page),
+                        });
+                    }
                 }
             }
         }
 
         if !current_text.is_empty() {
-            chunks.push(current_text);
+            chunks.push(SectionChunk {
+                text: current_text,
+                pages: current_pages,
+            });
         }
 
         chunks
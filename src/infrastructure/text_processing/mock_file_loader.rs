@@ -1,12 +1,17 @@
 use crate::application::ports::{FileLoader, FileLoaderError};
-use crate::domain::Document;
+use crate::domain::{Document, PageSegment};
 
 pub struct MockFileLoader;
 
 #[async_trait::async_trait]
 impl FileLoader for MockFileLoader {
-    async fn extract_text(&self, data: &[u8], _doc: &Document) -> Result<String, FileLoaderError> {
+    async fn extract_pages(
+        &self,
+        data: &[u8],
+        _doc: &Document,
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
         String::from_utf8(data.to_vec())
+            .map(|text| vec![PageSegment::unpaged(text)])
             .map_err(|e| FileLoaderError::ExtractionFailed(e.to_string()))
     }
 }
@@ -1,35 +1,55 @@
+mod analyzer;
 mod azure_doc_intel_adapter;
 mod bm25_sparse_embedder;
+mod code_splitter;
 mod composite_file_loader;
+mod docx_adapter;
 mod extractor_factory;
+mod html_adapter;
+mod hybrid_pdf_adapter;
 mod lm_studio_vlm_pdf_adapter;
 mod local_vlm_pdf_adapter;
+mod markdown_render;
 mod markdown_semantic_splitter;
 mod mock_file_loader;
+mod ooxml;
+mod page_map;
 mod pdf_rasterizer;
+mod pdf_text_layer;
 mod plain_text_adapter;
+mod pptx_adapter;
 mod recursive_character_splitter;
 mod semantic_splitter;
 mod text_sanitizer;
 mod text_splitter_factory;
+mod xlsx_adapter;
 
+pub use analyzer::{AnalyzerLanguage, TextAnalyzer};
 pub use azure_doc_intel_adapter::AnalyzeResponse;
 pub use azure_doc_intel_adapter::AnalyzeResult;
 pub use azure_doc_intel_adapter::AzureDocIntelAdapter;
+pub use azure_doc_intel_adapter::{AnalyzedPage, AnalyzedParagraph, AnalyzedRegion, TextSpan};
 
-pub use bm25_sparse_embedder::Bm25SparseEmbedder;
+pub use bm25_sparse_embedder::{Bm25Params, Bm25SparseEmbedder};
+pub use code_splitter::SyntacticCodeSplitter;
 pub use composite_file_loader::CompositeFileLoader;
+pub use docx_adapter::DocxAdapter;
 pub use extractor_factory::{ExtractorFactory, ExtractorFactoryError};
+pub use html_adapter::HtmlAdapter;
+pub use hybrid_pdf_adapter::{HybridPdfAdapter, PageRecognizer};
 pub use lm_studio_vlm_pdf_adapter::LmStudioVlmPdfAdapter;
 pub use local_vlm_pdf_adapter::LocalVlmPdfAdapter;
 pub use local_vlm_pdf_adapter::parse_shard_names;
 pub use markdown_semantic_splitter::MarkdownSemanticSplitter;
 pub use mock_file_loader::MockFileLoader;
+pub use pdf_text_layer::{TextLine, layout_markdown};
 pub use plain_text_adapter::PlainTextAdapter;
+pub use pptx_adapter::PptxAdapter;
 pub use recursive_character_splitter::RecursiveCharacterSplitter;
 pub use semantic_splitter::SemanticSplitter;
 pub use text_sanitizer::sanitize_extracted_text;
 pub use text_splitter_factory::{TextSplitterFactory, TextSplitters};
+pub use xlsx_adapter::XlsxAdapter;
 
 pub use local_vlm_pdf_adapter::EXTRACTION_TIMEOUT;
 pub use local_vlm_pdf_adapter::MAX_PAGES_DUE_TO_RAM_USAGE;
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use zip::ZipArchive;
+use zip::result::ZipError;
+
+use crate::application::ports::FileLoaderError;
+
+mod table;
+mod xml;
+
+pub(super) use table::TableStack;
+pub(super) use xml::{attr, collect_elements, relationship_id, xml_error};
+
+/// Upper bound on a single decompressed part, guarding against zip bombs.
+const MAX_PART_BYTES: u64 = 64 * 1024 * 1024;
+
+/// An opened Office Open XML package (the zip archive of XML parts behind DOCX, PPTX and
+/// XLSX files).
+pub(super) struct OoxmlPackage<'a> {
+    archive: ZipArchive<Cursor<&'a [u8]>>,
+}
+
+impl<'a> OoxmlPackage<'a> {
+    pub(super) fn open(data: &'a [u8]) -> Result<Self, FileLoaderError> {
+        ZipArchive::new(Cursor::new(data))
+            .map(|archive| Self { archive })
+            .map_err(|e| FileLoaderError::ExtractionFailed(format!("not an OOXML package: {}", e)))
+    }
+
+    /// Reads a part as UTF-8, or `None` when the package lacks it.
+    pub(super) fn read_part(&mut self, path: &str) -> Result<Option<String>, FileLoaderError> {
+        let file = match self.archive.by_name(path) {
+            Ok(file) => file,
+            Err(ZipError::FileNotFound) => return Ok(None),
+            Err(e) => {
+                return Err(FileLoaderError::ExtractionFailed(format!(
+                    "failed to open part {}: {}",
+                    path, e
+                )));
+            }
+        };
+        let mut xml = String::new();
+        file.take(MAX_PART_BYTES + 1)
+            .read_to_string(&mut xml)
+            .map_err(|e| {
+                FileLoaderError::ExtractionFailed(format!("failed to read part {}: {}", path, e))
+            })?;
+        if xml.len() as u64 > MAX_PART_BYTES {
+            return Err(FileLoaderError::ExtractionFailed(format!(
+                "part {} exceeds {} bytes",
+                path, MAX_PART_BYTES
+            )));
+        }
+        Ok(Some(xml))
+    }
+
+    /// Reads a part the format requires, failing when it is missing.
+    pub(super) fn require_part(&mut self, path: &str) -> Result<String, FileLoaderError> {
+        self.read_part(path)?.ok_or_else(|| {
+            FileLoaderError::ExtractionFailed(format!("package has no {} part", path))
+        })
+    }
+
+    /// Internal relationships of `part`, as relationship id to (type, absolute part path).
+    pub(super) fn relationships(
+        &mut self,
+        part: &str,
+    ) -> Result<HashMap<String, Relationship>, FileLoaderError> {
+        let (dir, name) = part.rsplit_once('/').unwrap_or(("", part));
+        let rels_path = if dir.is_empty() {
+            format!("_rels/{}.rels", name)
+        } else {
+            format!("{}/_rels/{}.rels", dir, name)
+        };
+        let Some(xml) = self.read_part(&rels_path)? else {
+            return Ok(HashMap::new());
+        };
+
+        let mut relationships = HashMap::new();
+        let mut reader = Reader::from_str(&xml);
+        loop {
+            match reader.read_event().map_err(xml_error(&rels_path))? {
+                Event::Start(e) | Event::Empty(e) if e.local_name().as_ref() == b"Relationship" => {
+                    if attr(&e, b"TargetMode").as_deref() == Some("External") {
+                        continue;
+                    }
+                    if let (Some(id), Some(target)) = (attr(&e, b"Id"), attr(&e, b"Target")) {
+                        relationships.insert(
+                            id,
+                            Relationship {
+                                kind: attr(&e, b"Type").unwrap_or_default(),
+                                target: resolve_target(dir, &target),
+                            },
+                        );
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+        Ok(relationships)
+    }
+}
+
+pub(super) struct Relationship {
+    /// Relationship type URI, e.g. `.../relationships/notesSlide`.
+    pub(super) kind: String,
+    /// Absolute path of the target part inside the package.
+    pub(super) target: String,
+}
+
+/// Resolves a relationship target against the directory of its source part.
+fn resolve_target(base_dir: &str, target: &str) -> String {
+    let mut segments: Vec<&str> = if target.starts_with('/') {
+        Vec::new()
+    } else {
+        base_dir.split('/').filter(|s| !s.is_empty()).collect()
+    };
+    for segment in target.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            s => segments.push(s),
+        }
+    }
+    segments.join("/")
+}
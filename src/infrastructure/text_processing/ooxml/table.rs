@@ -0,0 +1,101 @@
+/// Collects the cells of one DrawingML or WordprocessingML table.
+#[derive(Default)]
+struct TableBuilder {
+    rows: Vec<Vec<String>>,
+    row: Vec<String>,
+    cell: String,
+}
+
+impl TableBuilder {
+    fn start_row(&mut self) {
+        self.row.clear();
+    }
+
+    fn end_row(&mut self) {
+        self.rows.push(std::mem::take(&mut self.row));
+    }
+
+    fn start_cell(&mut self) {
+        self.cell.clear();
+    }
+
+    fn end_cell(&mut self) {
+        self.row.push(std::mem::take(&mut self.cell));
+    }
+
+    /// Appends a paragraph of the current cell.
+    fn push_text(&mut self, text: &str) {
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        if !self.cell.is_empty() {
+            self.cell.push(' ');
+        }
+        self.cell.push_str(text);
+    }
+
+    /// The table's text on one line, for flattening into an enclosing table.
+    fn into_text(self) -> String {
+        self.rows
+            .into_iter()
+            .flatten()
+            .filter(|c| !c.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn into_rows(self) -> Vec<Vec<String>> {
+        self.rows
+    }
+}
+
+/// The tables open while a part's XML is streamed, innermost last. A nested table is
+/// flattened into the cell that holds it.
+#[derive(Default)]
+pub(crate) struct TableStack {
+    tables: Vec<TableBuilder>,
+}
+
+impl TableStack {
+    /// Opens a table, row or cell for a `tbl`, `tr` or `tc` start tag; other tags are
+    /// ignored.
+    pub(crate) fn start(&mut self, local_name: &[u8]) {
+        match (local_name, self.tables.last_mut()) {
+            (b"tbl", _) => self.tables.push(TableBuilder::default()),
+            (b"tr", Some(table)) => table.start_row(),
+            (b"tc", Some(table)) => table.start_cell(),
+            _ => {}
+        }
+    }
+
+    /// Closes a table, row or cell for a `tbl`, `tr` or `tc` end tag, returning the rows
+    /// of a finished top-level table.
+    pub(crate) fn end(&mut self, local_name: &[u8]) -> Option<Vec<Vec<String>>> {
+        match (local_name, self.tables.last_mut()) {
+            (b"tc", Some(table)) => table.end_cell(),
+            (b"tr", Some(table)) => table.end_row(),
+            (b"tbl", Some(_)) => {
+                let table = self.tables.pop()?;
+                match self.tables.last_mut() {
+                    Some(outer) => outer.push_text(&table.into_text()),
+                    None => return Some(table.into_rows()),
+                }
+            }
+            _ => {}
+        }
+        None
+    }
+
+    /// Whether the streamed text lies inside a table.
+    pub(crate) fn is_open(&self) -> bool {
+        !self.tables.is_empty()
+    }
+
+    /// Appends a paragraph to the current cell of the innermost table.
+    pub(crate) fn push_text(&mut self, text: &str) {
+        if let Some(table) = self.tables.last_mut() {
+            table.push_text(text);
+        }
+    }
+}
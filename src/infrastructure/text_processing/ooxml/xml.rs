@@ -0,0 +1,51 @@
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+
+use crate::application::ports::FileLoaderError;
+
+/// Value of the attribute with the given local name, ignoring its namespace prefix.
+pub(crate) fn attr(element: &BytesStart<'_>, local_name: &[u8]) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|a| a.key.local_name().as_ref() == local_name)
+        .and_then(|a| a.unescape_value().ok())
+        .map(|v| v.into_owned())
+}
+
+/// The `r:id` of an element: an `id` attribute in the relationships namespace, as opposed
+/// to a plain `id`.
+pub(crate) fn relationship_id(element: &BytesStart<'_>) -> Option<String> {
+    element
+        .attributes()
+        .flatten()
+        .find(|a| a.key.prefix().is_some() && a.key.local_name().as_ref() == b"id")
+        .and_then(|a| a.unescape_value().ok())
+        .map(|v| v.into_owned())
+}
+
+pub(crate) fn xml_error(part: &str) -> impl Fn(quick_xml::Error) -> FileLoaderError + '_ {
+    move |e| FileLoaderError::ExtractionFailed(format!("malformed XML in {}: {}", part, e))
+}
+
+/// Maps every `local_name` element of `part`, in document order, skipping those `map`
+/// rejects.
+pub(crate) fn collect_elements<T>(
+    xml: &str,
+    part: &str,
+    local_name: &[u8],
+    mut map: impl FnMut(&BytesStart<'_>) -> Option<T>,
+) -> Result<Vec<T>, FileLoaderError> {
+    let mut reader = Reader::from_str(xml);
+    let mut items = Vec::new();
+    loop {
+        match reader.read_event().map_err(xml_error(part))? {
+            Event::Start(e) | Event::Empty(e) if e.local_name().as_ref() == local_name => {
+                items.extend(map(&e));
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(items)
+}
@@ -0,0 +1,46 @@
+use crate::domain::{Chunk, PageSegment};
+
+/// Byte positions at which each page starts in the text produced by [`PageMap::merge`],
+/// so splitters working on the merged text can map a chunk back to its pages.
+pub(super) struct PageMap {
+    starts: Vec<(usize, Option<u32>)>,
+}
+
+impl PageMap {
+    /// Joins non-blank pages with a paragraph break. A single segment is returned verbatim,
+    /// so offsets of unpaged text are unchanged.
+    pub(super) fn merge(pages: &[PageSegment]) -> (String, Self) {
+        let mut text = String::new();
+        let mut starts = Vec::with_capacity(pages.len());
+
+        for segment in pages.iter().filter(|p| !p.text.trim().is_empty()) {
+            if !text.is_empty() {
+                text.push_str("\n\n");
+            }
+            starts.push((text.len(), segment.page));
+            text.push_str(&segment.text);
+        }
+
+        (text, Self { starts })
+    }
+
+    /// First and last page overlapping the byte range `start..end`, or `None` when the
+    /// range falls in unpaged text.
+    pub(super) fn span(&self, start: usize, end: usize) -> Option<(u32, u32)> {
+        let page_at = |pos: usize| {
+            let index = self.starts.partition_point(|(s, _)| *s <= pos);
+            self.starts.get(index.checked_sub(1)?)?.1
+        };
+        let first = page_at(start)?;
+        let last = page_at(end.saturating_sub(1).max(start)).unwrap_or(first);
+        Some((first, last))
+    }
+
+    /// Stamps `chunk` with the pages covered by `start..end`.
+    pub(super) fn apply(&self, chunk: Chunk, start: usize, end: usize) -> Chunk {
+        match self.span(start, end) {
+            Some((first, last)) => chunk.with_pages(first, last),
+            None => chunk,
+        }
+    }
+}
@@ -5,49 +5,72 @@ use crate::application::ports::FileLoaderError;
 
 use super::local_vlm_pdf_adapter::{MAX_PAGES_DUE_TO_RAM_USAGE, RENDER_DPI};
 
-pub(super) fn rasterize_pages(data: &[u8]) -> Result<Vec<Vec<u8>>, FileLoaderError> {
-    let pdfium = Pdfium::new(
-        Pdfium::bind_to_system_library()
-            .map_err(|e| FileLoaderError::ExtractionFailed(format!("pdfium bind failed: {e}")))?,
-    );
+pub(super) fn bind_pdfium() -> Result<Pdfium, FileLoaderError> {
+    Ok(Pdfium::new(Pdfium::bind_to_system_library().map_err(
+        |e| FileLoaderError::ExtractionFailed(format!("pdfium bind failed: {e}")),
+    )?))
+}
 
-    let doc = pdfium
+pub(super) fn open_document<'a>(
+    pdfium: &'a Pdfium,
+    data: &'a [u8],
+) -> Result<PdfDocument<'a>, FileLoaderError> {
+    pdfium
         .load_pdf_from_byte_slice(data, None)
-        .map_err(|e| FileLoaderError::ExtractionFailed(format!("pdfium open failed: {e}")))?;
+        .map_err(|e| FileLoaderError::ExtractionFailed(format!("pdfium open failed: {e}")))
+}
+
+pub(super) fn rasterize_pages(data: &[u8]) -> Result<Vec<Vec<u8>>, FileLoaderError> {
+    let pdfium = bind_pdfium()?;
+    let doc = open_document(&pdfium, data)?;
 
     let page_count = doc.pages().len() as usize;
     let pages_to_render = page_count.min(MAX_PAGES_DUE_TO_RAM_USAGE);
 
-    let mut png_buffers: Vec<Vec<u8>> = Vec::with_capacity(pages_to_render);
+    (0..pages_to_render)
+        .map(|index| render_page(&doc, index))
+        .collect()
+}
+
+/// Renders only the given zero-based page indices, in the order given.
+pub(super) fn rasterize_selected_pages(
+    data: &[u8],
+    indices: &[usize],
+) -> Result<Vec<Vec<u8>>, FileLoaderError> {
+    let pdfium = bind_pdfium()?;
+    let doc = open_document(&pdfium, data)?;
+
+    indices
+        .iter()
+        .map(|&index| render_page(&doc, index))
+        .collect()
+}
+
+fn render_page(doc: &PdfDocument<'_>, index: usize) -> Result<Vec<u8>, FileLoaderError> {
+    let page = doc.pages().get(index as u16).map_err(|e| {
+        FileLoaderError::ExtractionFailed(format!("page {index} access failed: {e}"))
+    })?;
+
+    let width = (page.width().value * RENDER_DPI / 72.0) as i32;
+    let height = (page.height().value * RENDER_DPI / 72.0) as i32;
+
+    let bitmap = page
+        .render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(width)
+                .set_target_height(height),
+        )
+        .map_err(|e| {
+            FileLoaderError::ExtractionFailed(format!("render page {index} failed: {e}"))
+        })?;
 
-    for index in 0..pages_to_render {
-        let page = doc.pages().get(index as u16).map_err(|e| {
-            FileLoaderError::ExtractionFailed(format!("page {index} access failed: {e}"))
+    let dynamic_image = bitmap.as_image();
+    let mut png_bytes: Vec<u8> = Vec::new();
+    dynamic_image
+        .write_to(&mut std::io::Cursor::new(&mut png_bytes), ImageFormat::Png)
+        .map_err(|e| {
+            FileLoaderError::ExtractionFailed(format!("PNG encode page {index} failed: {e}"))
         })?;
 
-        let width = (page.width().value * RENDER_DPI / 72.0) as i32;
-        let height = (page.height().value * RENDER_DPI / 72.0) as i32;
-
-        let bitmap = page
-            .render_with_config(
-                &PdfRenderConfig::new()
-                    .set_target_width(width)
-                    .set_target_height(height),
-            )
-            .map_err(|e| {
-                FileLoaderError::ExtractionFailed(format!("render page {index} failed: {e}"))
-            })?;
-
-        let dynamic_image = bitmap.as_image();
-        let mut png_bytes: Vec<u8> = Vec::new();
-        dynamic_image
-            .write_to(&mut std::io::Cursor::new(&mut png_bytes), ImageFormat::Png)
-            .map_err(|e| {
-                FileLoaderError::ExtractionFailed(format!("PNG encode page {index} failed: {e}"))
-            })?;
-
-        png_buffers.push(png_bytes);
-    }
-
-    Ok(png_buffers)
+    Ok(png_bytes)
 }
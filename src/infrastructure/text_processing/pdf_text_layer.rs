@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use pdfium_render::prelude::*;
+
+use crate::application::ports::FileLoaderError;
+
+use super::pdf_rasterizer::{bind_pdfium, open_document};
+
+/// Lines at least this much larger than the body text become `#` headings.
+const TITLE_SCALE: f32 = 1.5;
+/// Lines at least this much larger than the body text become `##` headings.
+const HEADING_SCALE: f32 = 1.2;
+/// Longer lines are treated as body text even when set in a large font.
+const MAX_HEADING_CHARS: usize = 120;
+
+/// One line of a PDF text layer with its average font size in points.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextLine {
+    pub text: String,
+    pub font_size: f32,
+}
+
+impl TextLine {
+    pub fn new(text: impl Into<String>, font_size: f32) -> Self {
+        Self {
+            text: text.into(),
+            font_size,
+        }
+    }
+}
+
+/// Reads the embedded text of every page as lines in content-stream order, which pdfium
+/// keeps close to reading order. Unlike rasterization, this holds no page images, so no
+/// page limit applies.
+pub(super) fn extract_text_layer(data: &[u8]) -> Result<Vec<Vec<TextLine>>, FileLoaderError> {
+    let pdfium = bind_pdfium()?;
+    let doc = open_document(&pdfium, data)?;
+
+    let page_count = doc.pages().len() as usize;
+    let mut pages = Vec::with_capacity(page_count);
+
+    for index in 0..page_count {
+        let page = doc.pages().get(index as u16).map_err(|e| {
+            FileLoaderError::ExtractionFailed(format!("page {index} access failed: {e}"))
+        })?;
+        let text = page.text().map_err(|e| {
+            FileLoaderError::ExtractionFailed(format!("page {index} text layer failed: {e}"))
+        })?;
+        pages.push(collect_lines(&text));
+    }
+
+    Ok(pages)
+}
+
+fn collect_lines(text: &PdfPageText<'_>) -> Vec<TextLine> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut size_sum = 0.0_f32;
+    let mut sized_chars = 0_u32;
+
+    let mut flush = |current: &mut String, size_sum: &mut f32, sized_chars: &mut u32| {
+        if !current.trim().is_empty() {
+            let font_size = if *sized_chars > 0 {
+                *size_sum / *sized_chars as f32
+            } else {
+                0.0
+            };
+            lines.push(TextLine::new(current.trim(), font_size));
+        }
+        current.clear();
+        *size_sum = 0.0;
+        *sized_chars = 0;
+    };
+
+    for ch in text.chars().iter() {
+        let Some(c) = ch.unicode_char() else {
+            continue;
+        };
+        if c == '\n' || c == '\r' {
+            flush(&mut current, &mut size_sum, &mut sized_chars);
+            continue;
+        }
+        if !c.is_whitespace() {
+            size_sum += ch.scaled_font_size().value;
+            sized_chars += 1;
+        }
+        current.push(c);
+    }
+    flush(&mut current, &mut size_sum, &mut sized_chars);
+
+    lines
+}
+
+/// Renders text-layer lines as Markdown, promoting short lines set noticeably larger than
+/// the body text to headings. Consecutive heading lines of the same level are joined, so
+/// titles wrapped across lines stay one heading.
+pub fn layout_markdown(lines: &[TextLine]) -> String {
+    let body_size = body_font_size(lines);
+    let mut blocks: Vec<(usize, String)> = Vec::new();
+
+    for line in lines {
+        let text = line.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let level = heading_level(line.font_size, body_size, text);
+        match blocks.last_mut() {
+            Some((last_level, block)) if *last_level == level => {
+                block.push(if level > 0 { ' ' } else { '\n' });
+                block.push_str(text);
+            }
+            _ => blocks.push((level, text.to_string())),
+        }
+    }
+
+    blocks
+        .into_iter()
+        .map(|(level, block)| match level {
+            0 => block,
+            _ => format!("{} {block}", "#".repeat(level)),
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Font size covering the most characters, rounded to half a point.
+fn body_font_size(lines: &[TextLine]) -> Option<f32> {
+    let mut coverage: HashMap<i32, usize> = HashMap::new();
+    for line in lines.iter().filter(|l| l.font_size > 0.0) {
+        *coverage
+            .entry((line.font_size * 2.0).round() as i32)
+            .or_default() += line.text.chars().count();
+    }
+    coverage
+        .into_iter()
+        .max_by_key(|(size, count)| (*count, -size))
+        .map(|(size, _)| size as f32 / 2.0)
+}
+
+fn heading_level(font_size: f32, body_size: Option<f32>, text: &str) -> usize {
+    let Some(body_size) = body_size else {
+        return 0;
+    };
+    if text.chars().count() > MAX_HEADING_CHARS {
+        return 0;
+    }
+    if font_size >= body_size * TITLE_SCALE {
+        1
+    } else if font_size >= body_size * HEADING_SCALE {
+        2
+    } else {
+        0
+    }
+}
@@ -1,24 +1,28 @@
 use async_trait::async_trait;
 
 use crate::application::ports::{FileLoader, FileLoaderError};
-use crate::domain::{ContentType, Document};
+use crate::domain::{ContentType, Document, PageSegment};
 
 pub struct PlainTextAdapter;
 
 #[async_trait]
 impl FileLoader for PlainTextAdapter {
-    async fn extract_text(
+    async fn extract_pages(
         &self,
         data: &[u8],
         document: &Document,
-    ) -> Result<String, FileLoaderError> {
-        if document.content_type != ContentType::Text {
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
+        if !matches!(
+            document.content_type,
+            ContentType::Text | ContentType::Markdown
+        ) {
             return Err(FileLoaderError::UnsupportedContentType(
                 document.content_type.as_mime().to_string(),
             ));
         }
 
         String::from_utf8(data.to_vec())
+            .map(|text| vec![PageSegment::unpaged(text)])
             .map_err(|e| FileLoaderError::ExtractionFailed(e.to_string()))
     }
 }
@@ -0,0 +1,225 @@
+use async_trait::async_trait;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+use crate::application::ports::{FileLoader, FileLoaderError};
+use crate::domain::{ContentType, Document, PageSegment};
+
+use super::markdown_render::{collapse_whitespace, markdown_table};
+use super::ooxml::{OoxmlPackage, TableStack, attr, collect_elements, relationship_id, xml_error};
+
+const PRESENTATION_PART: &str = "ppt/presentation.xml";
+const NOTES_RELATIONSHIP: &str = "/notesSlide";
+
+/// Placeholders carrying slide furniture rather than content.
+const FURNITURE_PLACEHOLDERS: &[&str] = &["sldNum", "dt", "ftr", "hdr", "sldImg"];
+
+/// Converts PowerPoint decks to markdown, one segment per slide numbered by its position in
+/// the deck. Each slide becomes a `# Slide N: <title>` section holding its text shapes and
+/// tables, followed by a `## Speaker notes` subsection when the slide has notes.
+pub struct PptxAdapter;
+
+#[async_trait]
+impl FileLoader for PptxAdapter {
+    async fn extract_pages(
+        &self,
+        data: &[u8],
+        document: &Document,
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
+        if document.content_type != ContentType::Pptx {
+            return Err(FileLoaderError::UnsupportedContentType(
+                document.content_type.as_mime().to_string(),
+            ));
+        }
+
+        let mut package = OoxmlPackage::open(data)?;
+        let presentation = package.require_part(PRESENTATION_PART)?;
+        let relationships = package.relationships(PRESENTATION_PART)?;
+
+        let mut segments = Vec::new();
+        for (index, slide_rel) in slide_order(&presentation)?.iter().enumerate() {
+            let Some(slide_part) = relationships.get(slide_rel).map(|r| r.target.clone()) else {
+                tracing::warn!(relationship = %slide_rel, "Slide relationship missing; skipping");
+                continue;
+            };
+            let Some(slide_xml) = package.read_part(&slide_part)? else {
+                tracing::warn!(part = %slide_part, "Slide part missing; skipping");
+                continue;
+            };
+            let notes_part = package
+                .relationships(&slide_part)?
+                .into_values()
+                .find(|r| r.kind.ends_with(NOTES_RELATIONSHIP))
+                .map(|r| r.target);
+            let notes_xml = match notes_part {
+                Some(part) => package.read_part(&part)?.map(|xml| (part, xml)),
+                None => None,
+            };
+
+            let slide_number = index as u32 + 1;
+            let slide = parse_shapes(&slide_xml, &slide_part)?;
+            let notes = match &notes_xml {
+                Some((part, xml)) => parse_shapes(xml, part)?,
+                None => Vec::new(),
+            };
+            segments.push(PageSegment::new(
+                slide_number,
+                render_slide(slide_number, &slide, &notes),
+            ));
+        }
+
+        if segments.is_empty() {
+            return Err(FileLoaderError::NoTextFound(document.filename.clone()));
+        }
+        tracing::debug!(slides = segments.len(), "PPTX converted to markdown");
+        Ok(segments)
+    }
+}
+
+/// Relationship ids of the slides in presentation order.
+fn slide_order(xml: &str) -> Result<Vec<String>, FileLoaderError> {
+    collect_elements(xml, PRESENTATION_PART, b"sldId", relationship_id)
+}
+
+enum ShapeContent {
+    /// Paragraphs of a text shape, one per line, with the placeholder type when the shape
+    /// fills one (`title`, `body`, ...).
+    Text {
+        placeholder: Option<String>,
+        text: String,
+    },
+    Table(String),
+}
+
+impl ShapeContent {
+    fn is_title(&self) -> bool {
+        matches!(self, Self::Text { placeholder: Some(p), .. } if p == "title" || p == "ctrTitle")
+    }
+}
+
+#[derive(Default)]
+struct Shape {
+    placeholder: Option<String>,
+    paragraphs: Vec<String>,
+}
+
+/// Text shapes and tables of a slide or notes part, in document order.
+fn parse_shapes(xml: &str, part: &str) -> Result<Vec<ShapeContent>, FileLoaderError> {
+    let mut reader = Reader::from_str(xml);
+    let mut contents = Vec::new();
+    let mut shape: Option<Shape> = None;
+    let mut tables = TableStack::default();
+    let mut paragraph: Option<String> = None;
+    let mut in_text = false;
+
+    loop {
+        match reader.read_event().map_err(xml_error(part))? {
+            Event::Start(e) => match e.local_name().as_ref() {
+                // Alternate renderings repeat the text of the preferred choice.
+                b"Fallback" => {
+                    reader.read_to_end(e.name()).map_err(xml_error(part))?;
+                }
+                b"sp" => shape = Some(Shape::default()),
+                b"ph" => set_placeholder(&mut shape, &e),
+                b"p" => paragraph = Some(String::new()),
+                b"t" => in_text = true,
+                name => tables.start(name),
+            },
+            Event::Empty(e) => match e.local_name().as_ref() {
+                b"ph" => set_placeholder(&mut shape, &e),
+                b"br" => {
+                    if let Some(paragraph) = paragraph.as_mut() {
+                        paragraph.push(' ');
+                    }
+                }
+                _ => {}
+            },
+            Event::Text(t) if in_text => {
+                if let Some(paragraph) = paragraph.as_mut() {
+                    paragraph.push_str(&t.unescape().map_err(xml_error(part))?);
+                }
+            }
+            Event::End(e) => match e.local_name().as_ref() {
+                b"t" => in_text = false,
+                b"p" => {
+                    let text = collapse_whitespace(&paragraph.take().unwrap_or_default());
+                    if tables.is_open() {
+                        tables.push_text(&text);
+                    } else if let (Some(shape), false) = (shape.as_mut(), text.is_empty()) {
+                        shape.paragraphs.push(text);
+                    }
+                }
+                b"sp" => {
+                    if let Some(shape) = shape.take().filter(|s| !s.paragraphs.is_empty()) {
+                        contents.push(ShapeContent::Text {
+                            placeholder: shape.placeholder,
+                            text: shape.paragraphs.join("\n"),
+                        });
+                    }
+                }
+                name => {
+                    if let Some(rows) = tables.end(name) {
+                        contents.push(ShapeContent::Table(markdown_table(&rows)));
+                    }
+                }
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(contents)
+}
+
+/// A placeholder without a type is the generic content (`obj`) placeholder.
+fn set_placeholder(shape: &mut Option<Shape>, element: &quick_xml::events::BytesStart<'_>) {
+    if let Some(shape) = shape.as_mut() {
+        shape.placeholder = Some(attr(element, b"type").unwrap_or_else(|| "obj".to_string()));
+    }
+}
+
+fn render_slide(number: u32, slide: &[ShapeContent], notes: &[ShapeContent]) -> String {
+    let title = slide
+        .iter()
+        .filter(|c| c.is_title())
+        .filter_map(|c| match c {
+            ShapeContent::Text { text, .. } => Some(collapse_whitespace(text)),
+            ShapeContent::Table(_) => None,
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut markdown = if title.is_empty() {
+        format!("# Slide {}", number)
+    } else {
+        format!("# Slide {}: {}", number, title)
+    };
+    for content in slide.iter().filter(|c| !c.is_title()) {
+        if let Some(block) = content_block(content) {
+            markdown.push_str("\n\n");
+            markdown.push_str(block);
+        }
+    }
+
+    let notes: Vec<&str> = notes
+        .iter()
+        .filter(|c| matches!(c, ShapeContent::Text { placeholder: Some(p), .. } if p == "body"))
+        .filter_map(content_block)
+        .collect();
+    if !notes.is_empty() {
+        markdown.push_str("\n\n## Speaker notes\n\n");
+        markdown.push_str(&notes.join("\n\n"));
+    }
+    markdown
+}
+
+fn content_block(content: &ShapeContent) -> Option<&str> {
+    match content {
+        ShapeContent::Text {
+            placeholder: Some(p),
+            ..
+        } if FURNITURE_PLACEHOLDERS.contains(&p.as_str()) => None,
+        ShapeContent::Text { text, .. } => Some(text),
+        ShapeContent::Table(table) if !table.is_empty() => Some(table),
+        ShapeContent::Table(_) => None,
+    }
+}
@@ -3,7 +3,9 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::application::ports::{TextSplitter, TextSplitterError};
-use crate::domain::{Chunk, DocumentId, DocumentMetadata, TranscriptSegment};
+use crate::domain::{Chunk, DocumentId, DocumentMetadata, PageSegment, TranscriptSegment};
+
+use super::page_map::PageMap;
 
 pub struct RecursiveCharacterSplitter {
     chunk_size: usize,
@@ -60,6 +62,33 @@ impl TextSplitter for RecursiveCharacterSplitter {
         Ok(chunks)
     }
 
+    async fn split_pages(
+        &self,
+        pages: &[PageSegment],
+        document_id: DocumentId,
+        metadata: Option<Arc<DocumentMetadata>>,
+    ) -> Result<Vec<Chunk>, TextSplitterError> {
+        let (text, page_map) = PageMap::merge(pages);
+        let chunks = self.split(&text, document_id, metadata).await?;
+
+        // Chunk offsets count chars; the page map works in bytes.
+        let byte_at: Vec<usize> = text
+            .char_indices()
+            .map(|(pos, _)| pos)
+            .chain(std::iter::once(text.len()))
+            .collect();
+        let to_byte = |char_pos: usize| byte_at.get(char_pos).copied().unwrap_or(text.len());
+
+        Ok(chunks
+            .into_iter()
+            .map(|c| {
+                let start = to_byte(c.offset);
+                let end = to_byte(c.offset + c.text.chars().count());
+                page_map.apply(c, start, end)
+            })
+            .collect())
+    }
+
     /// Merges segment text and splits it using the character-based chunker.
     /// `start_time` is assigned to each chunk proportionally from the merged text offset.
     async fn split_segments(
@@ -73,7 +102,7 @@ impl TextSplitter for RecursiveCharacterSplitter {
 
         // Attach the start_time of the first segment to every chunk (character-level
         // splitter does not track segment boundaries, so this is a best-effort approximation).
-        let first_start = segments.first().map(|s| s.start_time);
+        let first_start = segments.first().map(TranscriptSegment::speech_start);
         Ok(chunks
             .into_iter()
             .map(|c| match first_start {
@@ -4,7 +4,16 @@ use async_trait::async_trait;
 use tiktoken_rs::{CoreBPE, cl100k_base};
 
 use crate::application::ports::{TextSplitter, TextSplitterError};
-use crate::domain::{Chunk, DocumentId, DocumentMetadata, TranscriptSegment};
+use crate::domain::{Chunk, DocumentId, DocumentMetadata, PageSegment, TranscriptSegment};
+
+use super::page_map::PageMap;
+
+/// Chunk text plus the inclusive range of sentence indices it was built from.
+pub(super) struct SentenceChunk {
+    pub(super) text: String,
+    pub(super) first: usize,
+    pub(super) last: usize,
+}
 
 pub struct SemanticSplitter {
     max_tokens: usize,
@@ -89,12 +98,12 @@ impl SemanticSplitter {
     pub(super) fn merge_sentences_into_chunks(
         &self,
         sentences: &[&str],
-    ) -> Result<Vec<String>, TextSplitterError> {
+    ) -> Result<Vec<SentenceChunk>, TextSplitterError> {
         if sentences.is_empty() {
             return Ok(Vec::new());
         }
 
-        let mut chunks: Vec<String> = Vec::new();
+        let mut chunks: Vec<SentenceChunk> = Vec::new();
         let mut idx = 0;
 
         while idx < sentences.len() {
@@ -104,7 +113,11 @@ impl SemanticSplitter {
             // Sentence alone overflows the limit — split it internally.
             if sentence_tokens > self.max_tokens {
                 let sub = self.split_oversized_sentence(sentence);
-                chunks.extend(sub);
+                chunks.extend(sub.into_iter().map(|text| SentenceChunk {
+                    text,
+                    first: idx,
+                    last: idx,
+                }));
                 idx += 1;
                 continue;
             }
@@ -138,7 +151,11 @@ impl SemanticSplitter {
             }
 
             let chunk_end = idx - 1; // inclusive last sentence index in this chunk
-            chunks.push(current);
+            chunks.push(SentenceChunk {
+                text: current,
+                first: chunk_start,
+                last: chunk_end,
+            });
 
             // Compute overlap: walk backwards from chunk_end until we accumulate
             // enough overlap tokens, then rewind idx to replay those sentences.
@@ -179,7 +196,7 @@ impl SemanticSplitter {
         let mut seg_idx = 0;
 
         while seg_idx < segments.len() {
-            let chunk_start_time = segments[seg_idx].start_time;
+            let chunk_start_time = segments[seg_idx].speech_start();
             let mut current_text = segments[seg_idx].text.trim().to_string();
             let mut current_tokens = self.count_tokens(&current_text);
             let chunk_start_idx = seg_idx;
@@ -251,6 +268,20 @@ impl TextSplitter for SemanticSplitter {
         document_id: DocumentId,
         metadata: Option<Arc<DocumentMetadata>>,
     ) -> Result<Vec<Chunk>, TextSplitterError> {
+        self.split_pages(&[PageSegment::unpaged(text)], document_id, metadata)
+            .await
+    }
+
+    async fn split_pages(
+        &self,
+        pages: &[PageSegment],
+        document_id: DocumentId,
+        metadata: Option<Arc<DocumentMetadata>>,
+    ) -> Result<Vec<Chunk>, TextSplitterError> {
+        // Pages are joined by paragraph breaks, so no sentence crosses a page boundary.
+        let (text, page_map) = PageMap::merge(pages);
+        let text = text.as_str();
+
         // Collect paragraphs while tracking their byte start positions in `text`
         // so offsets reflect the original document, not accumulated chunk lengths.
         let mut paragraphs: Vec<(usize, &str)> = Vec::new();
@@ -283,13 +314,18 @@ impl TextSplitter for SemanticSplitter {
         }
 
         let sentence_texts: Vec<&str> = all_sentences.iter().map(|(s, _)| *s).collect();
-        let chunk_texts = self.merge_sentences_into_chunks(&sentence_texts)?;
+        let sentence_chunks = self.merge_sentences_into_chunks(&sentence_texts)?;
 
         // Assign byte offsets by locating each chunk's first word in `text`.
-        let mut all_chunks = Vec::with_capacity(chunk_texts.len());
+        let mut all_chunks = Vec::with_capacity(sentence_chunks.len());
         let mut search_from = 0usize;
 
-        for chunk_text in chunk_texts {
+        for SentenceChunk {
+            text: chunk_text,
+            first,
+            last,
+        } in sentence_chunks
+        {
             let first_word = chunk_text.split_whitespace().next().unwrap_or(&chunk_text);
             let offset = text[search_from..]
                 .find(first_word)
@@ -306,7 +342,9 @@ impl TextSplitter for SemanticSplitter {
                 ),
                 None => Chunk::new(chunk_text.clone(), document_id, None, offset),
             };
-            all_chunks.push(chunk);
+            let (_, span_start) = all_sentences[first];
+            let (last_sentence, last_start) = all_sentences[last];
+            all_chunks.push(page_map.apply(chunk, span_start, last_start + last_sentence.len()));
             // Advance search cursor to avoid re-matching earlier text.
             search_from = offset;
         }
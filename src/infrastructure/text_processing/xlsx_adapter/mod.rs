@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+use crate::application::ports::{FileLoader, FileLoaderError};
+use crate::domain::{ContentType, Document, PageSegment};
+
+use super::markdown_render::markdown_table;
+use super::ooxml::{OoxmlPackage, attr, collect_elements, relationship_id, xml_error};
+
+mod sheet;
+
+use sheet::parse_rows;
+
+const WORKBOOK_PART: &str = "xl/workbook.xml";
+const SHARED_STRINGS_PART: &str = "xl/sharedStrings.xml";
+
+/// Data rows per rendered table. Long sheets are cut into several tables that each repeat
+/// the header row, so every chunk keeps its column names.
+const ROWS_PER_TABLE: usize = 50;
+
+/// Converts Excel workbooks to markdown, one segment per sheet numbered by its position in
+/// the workbook. Each sheet becomes a `# Sheet N: <name>` section with its used range as
+/// a markdown table whose first row is the header. Cells hold their cached values, so
+/// formulas show their last computed result.
+pub struct XlsxAdapter;
+
+#[async_trait]
+impl FileLoader for XlsxAdapter {
+    async fn extract_pages(
+        &self,
+        data: &[u8],
+        document: &Document,
+    ) -> Result<Vec<PageSegment>, FileLoaderError> {
+        if document.content_type != ContentType::Xlsx {
+            return Err(FileLoaderError::UnsupportedContentType(
+                document.content_type.as_mime().to_string(),
+            ));
+        }
+
+        let mut package = OoxmlPackage::open(data)?;
+        let workbook = package.require_part(WORKBOOK_PART)?;
+        let relationships = package.relationships(WORKBOOK_PART)?;
+        let shared_strings = match package.read_part(SHARED_STRINGS_PART)? {
+            Some(xml) => parse_shared_strings(&xml)?,
+            None => Vec::new(),
+        };
+
+        let mut segments = Vec::new();
+        for (index, (name, sheet_rel)) in sheet_order(&workbook)?.into_iter().enumerate() {
+            let Some(sheet_part) = relationships.get(&sheet_rel).map(|r| r.target.clone()) else {
+                tracing::warn!(sheet = %name, "Sheet relationship missing; skipping");
+                continue;
+            };
+            let Some(sheet_xml) = package.read_part(&sheet_part)? else {
+                tracing::warn!(sheet = %name, part = %sheet_part, "Sheet part missing; skipping");
+                continue;
+            };
+
+            let rows = parse_rows(&sheet_xml, &sheet_part, &shared_strings)?;
+            let tables = render_tables(&rows);
+            if tables.is_empty() {
+                continue;
+            }
+            let sheet_number = index as u32 + 1;
+            segments.push(PageSegment::new(
+                sheet_number,
+                format!("# Sheet {}: {}\n\n{}", sheet_number, name, tables),
+            ));
+        }
+
+        if segments.is_empty() {
+            return Err(FileLoaderError::NoTextFound(document.filename.clone()));
+        }
+        tracing::debug!(sheets = segments.len(), "XLSX converted to markdown");
+        Ok(segments)
+    }
+}
+
+/// Sheet names and relationship ids in workbook order.
+fn sheet_order(xml: &str) -> Result<Vec<(String, String)>, FileLoaderError> {
+    collect_elements(xml, WORKBOOK_PART, b"sheet", |e| {
+        relationship_id(e).map(|rel| (attr(e, b"name").unwrap_or_default(), rel))
+    })
+}
+
+/// The workbook's string table. Rich-text runs are concatenated; phonetic hints are left
+/// out.
+fn parse_shared_strings(xml: &str) -> Result<Vec<String>, FileLoaderError> {
+    let mut reader = Reader::from_str(xml);
+    let mut strings = Vec::new();
+    let mut current = String::new();
+    let mut in_text = false;
+    let mut in_phonetic = false;
+
+    loop {
+        match reader
+            .read_event()
+            .map_err(xml_error(SHARED_STRINGS_PART))?
+        {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"si" => current.clear(),
+                b"t" => in_text = !in_phonetic,
+                b"rPh" => in_phonetic = true,
+                _ => {}
+            },
+            Event::Empty(e) if e.local_name().as_ref() == b"si" => strings.push(String::new()),
+            Event::Text(t) if in_text => {
+                current.push_str(&t.unescape().map_err(xml_error(SHARED_STRINGS_PART))?);
+            }
+            Event::End(e) => match e.local_name().as_ref() {
+                b"si" => strings.push(std::mem::take(&mut current)),
+                b"t" => in_text = false,
+                b"rPh" => in_phonetic = false,
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(strings)
+}
+
+/// Renders the used range as markdown tables of at most [`ROWS_PER_TABLE`] data rows,
+/// dropping empty leading columns.
+fn render_tables(rows: &[Vec<String>]) -> String {
+    let Some(first_column) = rows
+        .iter()
+        .filter_map(|r| r.iter().position(|c| !c.trim().is_empty()))
+        .min()
+    else {
+        return String::new();
+    };
+    let rows: Vec<Vec<String>> = rows.iter().map(|r| r[first_column..].to_vec()).collect();
+
+    let (header, data) = rows.split_first().expect("rows are not empty");
+    if data.is_empty() {
+        return markdown_table(std::slice::from_ref(header));
+    }
+    data.chunks(ROWS_PER_TABLE)
+        .map(|chunk| {
+            let mut table = Vec::with_capacity(chunk.len() + 1);
+            table.push(header.clone());
+            table.extend_from_slice(chunk);
+            markdown_table(&table)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
@@ -0,0 +1,131 @@
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+use super::super::ooxml::{attr, xml_error};
+use crate::application::ports::FileLoaderError;
+
+/// Excel's last column, `XFD`. References beyond it only come from crafted files.
+const MAX_COLUMNS: usize = 16_384;
+/// Non-empty rows read from one sheet before the workbook is rejected.
+const MAX_ROWS_PER_SHEET: usize = 100_000;
+/// Cells read from one sheet before the workbook is rejected, counting the empty ones a
+/// row is padded with up to its last value.
+const MAX_CELLS_PER_SHEET: usize = 2_000_000;
+
+#[derive(Default)]
+struct Cell {
+    column: Option<usize>,
+    kind: Option<String>,
+    value: String,
+}
+
+/// Rows of a worksheet as dense cell vectors, placing each cell by its `A1`-style
+/// reference. Rows without any value are omitted. Sheets beyond [`MAX_COLUMNS`],
+/// [`MAX_ROWS_PER_SHEET`] or [`MAX_CELLS_PER_SHEET`] are rejected.
+pub(super) fn parse_rows(
+    xml: &str,
+    part: &str,
+    shared_strings: &[String],
+) -> Result<Vec<Vec<String>>, FileLoaderError> {
+    let mut reader = Reader::from_str(xml);
+    let mut rows = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut cells = 0usize;
+    let mut cell: Option<Cell> = None;
+    let mut in_value = false;
+    let mut in_phonetic = false;
+
+    loop {
+        match reader.read_event().map_err(xml_error(part))? {
+            Event::Start(e) => match e.local_name().as_ref() {
+                b"row" => row.clear(),
+                b"c" => {
+                    let column = match attr(&e, b"r") {
+                        Some(reference) => column_index(&reference, part)?,
+                        None => None,
+                    };
+                    cell = Some(Cell {
+                        column,
+                        kind: attr(&e, b"t"),
+                        value: String::new(),
+                    })
+                }
+                b"v" | b"t" => in_value = !in_phonetic,
+                b"rPh" => in_phonetic = true,
+                _ => {}
+            },
+            Event::Text(t) if in_value => {
+                if let Some(cell) = cell.as_mut() {
+                    cell.value.push_str(&t.unescape().map_err(xml_error(part))?);
+                }
+            }
+            Event::End(e) => match e.local_name().as_ref() {
+                b"v" | b"t" => in_value = false,
+                b"rPh" => in_phonetic = false,
+                b"c" => {
+                    if let Some(cell) = cell.take() {
+                        let column = cell.column.unwrap_or(row.len());
+                        if column >= MAX_COLUMNS {
+                            return Err(too_large(part, "columns", MAX_COLUMNS));
+                        }
+                        if row.len() <= column {
+                            if cells + column + 1 > MAX_CELLS_PER_SHEET {
+                                return Err(too_large(part, "cells", MAX_CELLS_PER_SHEET));
+                            }
+                            row.resize(column + 1, String::new());
+                        }
+                        row[column] = cell_text(cell, shared_strings);
+                    }
+                }
+                b"row" if row.iter().any(|c| !c.trim().is_empty()) => {
+                    if rows.len() == MAX_ROWS_PER_SHEET {
+                        return Err(too_large(part, "rows", MAX_ROWS_PER_SHEET));
+                    }
+                    cells += row.len();
+                    rows.push(std::mem::take(&mut row));
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(rows)
+}
+
+fn cell_text(cell: Cell, shared_strings: &[String]) -> String {
+    match cell.kind.as_deref() {
+        Some("s") => cell
+            .value
+            .trim()
+            .parse::<usize>()
+            .ok()
+            .and_then(|i| shared_strings.get(i))
+            .cloned()
+            .unwrap_or_default(),
+        Some("b") => match cell.value.trim() {
+            "1" => "TRUE".to_string(),
+            "0" => "FALSE".to_string(),
+            other => other.to_string(),
+        },
+        _ => cell.value,
+    }
+}
+
+/// Zero-based column of an `A1`-style cell reference, `None` without column letters.
+/// Columns past [`MAX_COLUMNS`] are an error.
+fn column_index(reference: &str, part: &str) -> Result<Option<usize>, FileLoaderError> {
+    let mut column = 0usize;
+    for b in reference.bytes().take_while(u8::is_ascii_alphabetic) {
+        column = column * 26 + (b.to_ascii_uppercase() - b'A') as usize + 1;
+        // Checked per letter, so a long run of letters cannot overflow.
+        if column > MAX_COLUMNS {
+            return Err(too_large(part, "columns", MAX_COLUMNS));
+        }
+    }
+    Ok(column.checked_sub(1))
+}
+
+fn too_large(part: &str, what: &str, limit: usize) -> FileLoaderError {
+    FileLoaderError::ExtractionFailed(format!("{part}: sheet exceeds {limit} {what}"))
+}
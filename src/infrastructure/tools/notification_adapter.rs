@@ -1,8 +1,10 @@
+use std::time::Duration;
+
 use async_trait::async_trait;
-use reqwest::Client;
 
-use crate::application::ports::{McpError, ToolSchema};
+use crate::application::ports::{McpError, ToolSchema, WebhookSendError};
 use crate::infrastructure::mcp::ToolHandler;
+use crate::infrastructure::webhooks::WebhookClient;
 
 pub enum NotificationFormat {
     Plain,
@@ -16,15 +18,13 @@ pub struct NotificationConfig {
 }
 
 pub struct NotificationAdapter {
-    client: Client,
+    client: WebhookClient,
     config: NotificationConfig,
 }
 
 impl NotificationAdapter {
     pub fn new(config: NotificationConfig) -> Result<Self, reqwest::Error> {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(config.timeout_secs))
-            .build()?;
+        let client = WebhookClient::new(Duration::from_secs(config.timeout_secs))?;
         Ok(Self { client, config })
     }
 
@@ -84,30 +84,17 @@ impl ToolHandler for NotificationAdapter {
 
         let title = arguments["title"].as_str().unwrap_or("");
 
-        let body = build_body(&self.config.format, title, message);
+        let body = serde_json::to_vec(&build_body(&self.config.format, title, message))
+            .map_err(|e| McpError::Serialization(e.to_string()))?;
 
-        let response = self
-            .client
-            .post(&self.config.webhook_url)
-            .header("Content-Type", "application/json")
-            .json(&body)
-            .send()
+        self.client
+            .post_json(&self.config.webhook_url, body, &[])
             .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    McpError::ExecutionFailed("webhook timeout".to_string())
-                } else {
-                    McpError::ExecutionFailed(e.to_string())
-                }
+            .map_err(|e| match e {
+                WebhookSendError::RequestFailed(message) => McpError::ExecutionFailed(message),
+                other => McpError::ExecutionFailed(other.to_string()),
             })?;
 
-        if !response.status().is_success() {
-            return Err(McpError::ExecutionFailed(format!(
-                "webhook HTTP {}",
-                response.status().as_u16()
-            )));
-        }
-
         Ok("Notification sent successfully.".to_string())
     }
 }
@@ -3,8 +3,9 @@ use std::sync::Arc;
 use async_trait::async_trait;
 
 use crate::application::ports::{
-    McpError, RagSourceCollector, RetrievalServicePort, SourceChunk, ToolSchema,
+    McpError, RagSourceCollector, RetrievalServicePort, SearchFilter, SourceChunk, ToolSchema,
 };
+use crate::application::services::current_tenant;
 use crate::domain::EvalSource;
 use crate::infrastructure::mcp::ToolHandler;
 
@@ -37,6 +38,34 @@ impl RagSearchAdapter {
                     "query": {
                         "type": "string",
                         "description": "The search query to execute against the knowledge base"
+                    },
+                    "document_ids": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Only search these documents (UUIDs)"
+                    },
+                    "content_types": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Only search these MIME types, e.g. application/pdf, audio/mpeg"
+                    },
+                    "titles": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Only search documents with these exact titles"
+                    },
+                    "tags": {
+                        "type": "array",
+                        "items": { "type": "string" },
+                        "description": "Only search documents carrying any of these tags"
+                    },
+                    "ingested_after": {
+                        "type": "string",
+                        "description": "RFC 3339 timestamp; only documents ingested at or after it"
+                    },
+                    "ingested_before": {
+                        "type": "string",
+                        "description": "RFC 3339 timestamp; only documents ingested at or before it"
                     }
                 },
                 "required": ["query"]
@@ -56,9 +85,15 @@ impl ToolHandler for RagSearchAdapter {
             .as_str()
             .ok_or_else(|| McpError::Serialization("missing 'query' argument".to_string()))?;
 
+        // Fail closed: without a tenant scope there is no safe set of documents to search.
+        let tenant_id = current_tenant().ok_or_else(|| {
+            McpError::ExecutionFailed("rag_search invoked outside a tenant scope".to_string())
+        })?;
+        let filter = parse_filter(arguments)?.with_tenant(tenant_id);
+
         let chunks = self
             .port
-            .search_chunks(query)
+            .search_chunks(query, &filter)
             .await
             .map_err(|e| McpError::ExecutionFailed(e.to_string()))?;
 
@@ -68,6 +103,7 @@ impl ToolHandler for RagSearchAdapter {
                 .map(|c| EvalSource {
                     text: c.text.clone(),
                     page: c.page,
+                    end_page: c.end_page,
                     score: c.score,
                 })
                 .collect();
@@ -78,6 +114,20 @@ impl ToolHandler for RagSearchAdapter {
     }
 }
 
+/// Every argument other than `query` is a filter field.
+fn parse_filter(arguments: &serde_json::Value) -> Result<SearchFilter, McpError> {
+    let mut filter_args = match arguments.as_object() {
+        Some(map) => map.clone(),
+        None => return Ok(SearchFilter::default()),
+    };
+    filter_args.remove("query");
+    // Models often send explicit nulls for optional parameters.
+    filter_args.retain(|_, v| !v.is_null());
+
+    SearchFilter::from_json(&serde_json::Value::Object(filter_args))
+        .map_err(|e| McpError::Serialization(e.to_string()))
+}
+
 /// Returns the longest prefix of `s` whose byte length does not exceed `max_bytes`,
 /// always slicing at a UTF-8 codepoint boundary to avoid a panic on multi-byte sequences.
 fn truncate_utf8(s: &str, max_bytes: usize) -> &str {
@@ -102,10 +152,17 @@ fn format_rag_response(chunks: &[SourceChunk]) -> String {
         .iter()
         .enumerate()
         .map(|(i, chunk)| {
-            let location_label = match (chunk.page, chunk.start_time) {
-                (_, Some(t)) => format!("{:.1}s", t),
-                (Some(p), None) => format!("Page {p}"),
-                (None, None) => "Page ?".to_string(),
+            let location_label = match (&chunk.code, chunk.page, chunk.end_page, chunk.start_time) {
+                (Some(code), ..) => match &code.symbol {
+                    Some(symbol) => format!("{} ({symbol})", code.location()),
+                    None => code.location(),
+                },
+                (None, _, _, Some(t)) => format!("{:.1}s", t),
+                (None, Some(first), Some(last), None) if last > first => {
+                    format!("Pages {first}-{last}")
+                }
+                (None, Some(p), _, None) => format!("Page {p}"),
+                (None, None, _, None) => "Page ?".to_string(),
             };
 
             let label = match &chunk.title {
@@ -115,13 +172,12 @@ fn format_rag_response(chunks: &[SourceChunk]) -> String {
 
             let text = truncate_utf8(&chunk.text, 800);
 
-            format!(
-                "{}. [{}, score: {:.2}]: {}",
-                i + 1,
-                label,
-                chunk.score,
-                text
-            )
+            let score_label = match chunk.rerank_score {
+                Some(rerank) => format!("score: {:.2}, rerank: {:.2}", chunk.score, rerank),
+                None => format!("score: {:.2}", chunk.score),
+            };
+
+            format!("{}. [{}, {}]: {}", i + 1, label, score_label, text)
         })
         .collect();
 
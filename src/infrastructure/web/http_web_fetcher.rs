@@ -0,0 +1,293 @@
+use std::collections::HashMap;
+use std::error::Error as _;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::{Client, Url, redirect};
+use scraper::{Html, Selector};
+
+use super::public_address::PublicAddressResolver;
+use crate::application::ports::{
+    BlockedTarget, WebFetchError, WebFetcher, WebResource, check_target,
+};
+
+const MAX_REDIRECTS: usize = 10;
+/// Longest page title kept, in characters.
+const MAX_TITLE_CHARS: usize = 256;
+/// Clients kept for distinct `allowed_domains` scopes before the cache is reset.
+const MAX_CACHED_CLIENTS: usize = 16;
+
+static BASE_SELECTOR: LazyLock<Selector> = LazyLock::new(|| selector("base[href]"));
+static TITLE_SELECTOR: LazyLock<Selector> = LazyLock::new(|| selector("title"));
+static LINK_SELECTOR: LazyLock<Selector> = LazyLock::new(|| selector("link[href]"));
+static ANCHOR_SELECTOR: LazyLock<Selector> = LazyLock::new(|| selector("a[href]"));
+
+fn selector(css: &str) -> Selector {
+    Selector::parse(css).expect("selector literals are valid CSS")
+}
+
+/// Fetches pages over HTTP(S) with reqwest, extracting title, canonical URL and links from
+/// HTML and the `<loc>` entries from XML sitemaps. Bodies above `max_response_bytes` are
+/// rejected without being read in full.
+///
+/// Only public addresses are contacted: IP-literal hosts are checked before the request,
+/// host names are filtered at resolution, and every redirect hop is re-checked for scheme,
+/// address and the caller's `allowed_domains`. [`Self::with_private_networks`] lifts the
+/// address restriction for intranet deployments.
+pub struct HttpWebFetcher {
+    user_agent: String,
+    timeout: Duration,
+    max_response_bytes: u64,
+    allow_private_networks: bool,
+    /// One client per `allowed_domains` scope, since the redirect policy is fixed at build.
+    clients: Mutex<HashMap<Vec<String>, Client>>,
+}
+
+impl HttpWebFetcher {
+    pub fn new(
+        user_agent: &str,
+        timeout: Duration,
+        max_response_bytes: u64,
+    ) -> Result<Self, reqwest::Error> {
+        let fetcher = Self {
+            user_agent: user_agent.to_string(),
+            timeout,
+            max_response_bytes,
+            allow_private_networks: false,
+            clients: Mutex::new(HashMap::new()),
+        };
+        // Surface TLS or configuration errors at startup rather than on the first fetch.
+        fetcher.client(&[])?;
+        Ok(fetcher)
+    }
+
+    /// Allows loopback, private and other non-public addresses.
+    pub fn with_private_networks(mut self, allow: bool) -> Self {
+        self.allow_private_networks = allow;
+        self.clients
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+        self
+    }
+
+    fn client(&self, allowed_domains: &[String]) -> Result<Client, reqwest::Error> {
+        let mut clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(client) = clients.get(allowed_domains) {
+            return Ok(client.clone());
+        }
+
+        let allow_private = self.allow_private_networks;
+        let scope = (!allowed_domains.is_empty()).then(|| allowed_domains.to_vec());
+        let policy = redirect::Policy::custom(move |attempt| {
+            if attempt.previous().len() > MAX_REDIRECTS {
+                return attempt.error(format!("more than {MAX_REDIRECTS} redirects"));
+            }
+            match check_target(attempt.url(), scope.as_deref(), allow_private) {
+                Ok(()) => attempt.follow(),
+                Err(blocked) => attempt.error(blocked),
+            }
+        });
+        let mut builder = Client::builder()
+            .user_agent(&self.user_agent)
+            .timeout(self.timeout)
+            .redirect(policy);
+        if !allow_private {
+            // A proxy would connect on our behalf, bypassing the resolver's address check.
+            builder = builder
+                .no_proxy()
+                .dns_resolver(Arc::new(PublicAddressResolver));
+        }
+        let client = builder.build()?;
+
+        if clients.len() >= MAX_CACHED_CLIENTS {
+            clients.clear();
+        }
+        clients.insert(allowed_domains.to_vec(), client.clone());
+        Ok(client)
+    }
+}
+
+#[async_trait]
+impl WebFetcher for HttpWebFetcher {
+    async fn fetch(
+        &self,
+        url: &str,
+        allowed_domains: &[String],
+    ) -> Result<WebResource, WebFetchError> {
+        let url = Url::parse(url).map_err(|e| WebFetchError::InvalidUrl(format!("{url}: {e}")))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(WebFetchError::InvalidUrl(format!(
+                "unsupported scheme: {}",
+                url.scheme()
+            )));
+        }
+        check_target(&url, None, self.allow_private_networks)
+            .map_err(|blocked| WebFetchError::Blocked(blocked.0))?;
+
+        let client = self
+            .client(allowed_domains)
+            .map_err(|e| WebFetchError::RequestFailed(e.to_string()))?;
+        let mut response = client.get(url).send().await.map_err(request_error)?;
+        if !response.status().is_success() {
+            return Err(WebFetchError::Status(response.status().as_u16()));
+        }
+        if response
+            .content_length()
+            .is_some_and(|len| len > self.max_response_bytes)
+        {
+            return Err(WebFetchError::TooLarge(self.max_response_bytes));
+        }
+
+        let final_url = response.url().clone();
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.split(';').next())
+            .map(|mime| mime.trim().to_ascii_lowercase())
+            .filter(|mime| !mime.is_empty());
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(request_error)? {
+            if (body.len() + chunk.len()) as u64 > self.max_response_bytes {
+                return Err(WebFetchError::TooLarge(self.max_response_bytes));
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        let mut resource = WebResource {
+            url: final_url.to_string(),
+            content_type,
+            ..WebResource::default()
+        };
+        match resource.content_type.as_deref() {
+            Some("text/html" | "application/xhtml+xml") => {
+                read_html(&mut resource, &final_url, &String::from_utf8_lossy(&body));
+            }
+            Some("application/xml" | "text/xml") => read_sitemap(&mut resource, &body),
+            _ => {}
+        }
+        resource.body = body;
+        Ok(resource)
+    }
+}
+
+fn request_error(e: reqwest::Error) -> WebFetchError {
+    let mut source = e.source();
+    while let Some(inner) = source {
+        if let Some(blocked) = inner.downcast_ref::<BlockedTarget>() {
+            return WebFetchError::Blocked(blocked.0.clone());
+        }
+        source = inner.source();
+    }
+    if e.is_timeout() {
+        WebFetchError::Timeout
+    } else {
+        WebFetchError::RequestFailed(e.to_string())
+    }
+}
+
+/// Fills in title, canonical URL and links, resolving relative targets against `<base>`
+/// or the page URL.
+fn read_html(resource: &mut WebResource, page_url: &Url, html: &str) {
+    let html = Html::parse_document(html);
+    let select = |selector: &Selector| html.select(selector).collect::<Vec<_>>();
+
+    let base = select(&BASE_SELECTOR)
+        .first()
+        .and_then(|e| e.value().attr("href"))
+        .and_then(|href| page_url.join(href.trim()).ok())
+        .unwrap_or_else(|| page_url.clone());
+    let resolve = |href: &str| {
+        let mut target = base.join(href.trim()).ok()?;
+        target.set_fragment(None);
+        matches!(target.scheme(), "http" | "https").then(|| target.to_string())
+    };
+
+    resource.title = select(&TITLE_SELECTOR)
+        .first()
+        .map(|e| e.text().collect::<Vec<_>>().join(" "))
+        .map(|title| {
+            title
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .chars()
+                .take(MAX_TITLE_CHARS)
+                .collect::<String>()
+        })
+        .filter(|title| !title.is_empty());
+    resource.canonical_url = select(&LINK_SELECTOR)
+        .into_iter()
+        .find(|e| {
+            e.value().attr("rel").is_some_and(|rel| {
+                rel.split_whitespace()
+                    .any(|r| r.eq_ignore_ascii_case("canonical"))
+            })
+        })
+        .and_then(|e| e.value().attr("href"))
+        .and_then(resolve);
+    for link in select(&ANCHOR_SELECTOR) {
+        let nofollow = link.value().attr("rel").is_some_and(|rel| {
+            rel.split_whitespace()
+                .any(|r| r.eq_ignore_ascii_case("nofollow"))
+        });
+        if let (false, Some(target)) = (nofollow, link.value().attr("href").and_then(resolve))
+            && !resource.links.contains(&target)
+        {
+            resource.links.push(target);
+        }
+    }
+}
+
+/// Collects the `<loc>` entries of a `<urlset>` or `<sitemapindex>`. Other XML documents
+/// yield no links; a malformed sitemap yields the entries read before the error.
+fn read_sitemap(resource: &mut WebResource, body: &[u8]) {
+    let mut reader = Reader::from_reader(body);
+    let mut buf = Vec::new();
+    let mut is_sitemap = false;
+    let mut in_loc = false;
+    let mut loc = String::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"sitemapindex" => {
+                    is_sitemap = true;
+                    resource.sitemap_index = true;
+                }
+                b"urlset" => is_sitemap = true,
+                b"loc" if is_sitemap => {
+                    in_loc = true;
+                    loc.clear();
+                }
+                _ => {}
+            },
+            Ok(Event::Text(t)) if in_loc => {
+                if let Ok(text) = t.unescape() {
+                    loc.push_str(&text);
+                }
+            }
+            Ok(Event::CData(t)) if in_loc => loc.push_str(&String::from_utf8_lossy(&t)),
+            Ok(Event::End(e)) if e.local_name().as_ref() == b"loc" && in_loc => {
+                in_loc = false;
+                let loc = loc.trim();
+                if !loc.is_empty() {
+                    resource.links.push(loc.to_string());
+                }
+            }
+            Ok(Event::Eof) => break,
+            Err(e) => {
+                tracing::warn!(url = %resource.url, error = %e, "Malformed sitemap");
+                break;
+            }
+            _ => {}
+        }
+        buf.clear();
+    }
+}
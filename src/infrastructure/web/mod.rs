@@ -0,0 +1,7 @@
+//! Outbound HTTP fetching of web pages and sitemaps for URL ingestion.
+
+mod http_web_fetcher;
+mod public_address;
+
+pub use http_web_fetcher::HttpWebFetcher;
+pub use public_address::PublicAddressResolver;
@@ -0,0 +1,32 @@
+use std::net::SocketAddr;
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+use crate::application::ports::{BlockedTarget, is_public_ip};
+
+/// Resolves host names with the system resolver and drops non-public addresses, so a name
+/// pointing at an internal service cannot be connected to, however it is reached: directly,
+/// through a redirect, or by re-resolving after the URL was checked.
+pub struct PublicAddressResolver;
+
+impl Resolve for PublicAddressResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            let public: Vec<SocketAddr> = addrs
+                .iter()
+                .copied()
+                .filter(|addr| is_public_ip(addr.ip()))
+                .collect();
+            if public.is_empty() && !addrs.is_empty() {
+                return Err(Box::new(BlockedTarget(format!(
+                    "{host} resolves to non-public addresses"
+                )))
+                    as Box<dyn std::error::Error + Send + Sync>);
+            }
+            Ok(Box::new(public.into_iter()) as Addrs)
+        })
+    }
+}
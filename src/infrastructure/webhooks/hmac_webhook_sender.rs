@@ -0,0 +1,61 @@
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use super::WebhookClient;
+use crate::application::ports::{WebhookSendError, WebhookSender};
+use crate::domain::ClaimedWebhookDelivery;
+
+pub const SIGNATURE_HEADER: &str = "X-Sandakan-Signature";
+pub const EVENT_HEADER: &str = "X-Sandakan-Event";
+pub const DELIVERY_HEADER: &str = "X-Sandakan-Delivery";
+
+/// Signs each delivery with its subscription secret and POSTs it through a [`WebhookClient`].
+pub struct HmacWebhookSender {
+    client: WebhookClient,
+}
+
+impl HmacWebhookSender {
+    pub fn new(client: WebhookClient) -> Self {
+        Self { client }
+    }
+}
+
+/// `t=<unix seconds>,v1=<hex HMAC-SHA256 of "<t>.<body>">`. Receivers recompute the MAC
+/// over the raw body and reject stale timestamps to stop replays.
+pub fn sign_payload(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    let digest: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    format!("t={timestamp},v1={digest}")
+}
+
+#[async_trait]
+impl WebhookSender for HmacWebhookSender {
+    async fn send(&self, claimed: &ClaimedWebhookDelivery) -> Result<u16, WebhookSendError> {
+        let delivery = &claimed.delivery;
+        let body = serde_json::to_vec(&delivery.payload)
+            .map_err(|e| WebhookSendError::RequestFailed(e.to_string()))?;
+        let signature = sign_payload(&claimed.secret, chrono::Utc::now().timestamp(), &body);
+
+        self.client
+            .post_json(
+                &claimed.url,
+                body,
+                &[
+                    (SIGNATURE_HEADER, signature),
+                    (EVENT_HEADER, delivery.event_type.as_str().to_string()),
+                    (DELIVERY_HEADER, delivery.id.to_string()),
+                ],
+            )
+            .await
+    }
+}
@@ -0,0 +1,10 @@
+//! Outbound HTTP webhooks: the shared JSON POST client (also behind the agent's
+//! `send_notification` tool) and the signed sender used by the `WebhookDispatcher`.
+
+mod hmac_webhook_sender;
+mod webhook_client;
+
+pub use hmac_webhook_sender::{
+    DELIVERY_HEADER, EVENT_HEADER, HmacWebhookSender, SIGNATURE_HEADER, sign_payload,
+};
+pub use webhook_client::WebhookClient;
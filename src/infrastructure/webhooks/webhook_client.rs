@@ -0,0 +1,82 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Client, Url, redirect};
+
+use crate::application::ports::{WebhookSendError, check_target};
+use crate::infrastructure::web::PublicAddressResolver;
+
+/// POSTs JSON bodies to webhook endpoints with a fixed timeout, treating any non-2xx
+/// answer as a rejection.
+pub struct WebhookClient {
+    client: Client,
+    /// Refuse IP-literal hosts outside the public internet before sending.
+    public_only: bool,
+}
+
+impl WebhookClient {
+    pub fn new(timeout: Duration) -> Result<Self, reqwest::Error> {
+        let client = Client::builder().timeout(timeout).build()?;
+        Ok(Self {
+            client,
+            public_only: false,
+        })
+    }
+
+    /// Client for endpoints registered by tenants. Redirects are never followed and, unless
+    /// `allow_private_networks` is set, only public addresses are connected to, with no
+    /// proxy in between, so a subscription cannot reach internal services.
+    pub fn for_tenant_endpoints(
+        timeout: Duration,
+        allow_private_networks: bool,
+    ) -> Result<Self, reqwest::Error> {
+        let mut builder = Client::builder()
+            .timeout(timeout)
+            .redirect(redirect::Policy::none());
+        if !allow_private_networks {
+            builder = builder
+                .no_proxy()
+                .dns_resolver(Arc::new(PublicAddressResolver));
+        }
+        Ok(Self {
+            client: builder.build()?,
+            public_only: !allow_private_networks,
+        })
+    }
+
+    /// Sends `body` as `application/json` and returns the response status.
+    pub async fn post_json(
+        &self,
+        url: &str,
+        body: Vec<u8>,
+        headers: &[(&str, String)],
+    ) -> Result<u16, WebhookSendError> {
+        if self.public_only {
+            let target =
+                Url::parse(url).map_err(|e| WebhookSendError::RequestFailed(e.to_string()))?;
+            check_target(&target, None, false)
+                .map_err(|blocked| WebhookSendError::RequestFailed(blocked.0))?;
+        }
+        let mut request = self
+            .client
+            .post(url)
+            .header("Content-Type", "application/json");
+        for (name, value) in headers {
+            request = request.header(*name, value);
+        }
+
+        let response = request.body(body).send().await.map_err(|e| {
+            if e.is_timeout() {
+                WebhookSendError::Timeout
+            } else {
+                WebhookSendError::RequestFailed(e.to_string())
+            }
+        })?;
+
+        let status = response.status().as_u16();
+        if !response.status().is_success() {
+            return Err(WebhookSendError::Rejected(status));
+        }
+        Ok(status)
+    }
+}
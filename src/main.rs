@@ -7,38 +7,46 @@ use config::Environment as EnvironmentSource;
 use config::{Config, File};
 use sqlx::PgPool;
 use tokio::net::TcpListener;
-use tokio::sync::mpsc;
+use tokio::sync::Notify;
 
 use sandakan::application::ports::McpClientPort;
 use sandakan::application::ports::RagSourceCollector;
 use sandakan::application::ports::RetrievalServicePort;
 use sandakan::application::ports::{
-    AudioDecoder, CollectionConfig, ConversationRepository, Embedder, EvalEventRepository,
-    EvalOutboxRepository, EvalResultRepository, FileLoader, JobRepository, LlmClient,
-    SparseEmbedder, StagingStore, TranscriptionEngine, VectorStore,
+    AudioDecoder, CollectionConfig, ConversationRepository, DocumentRepository, Embedder,
+    EvalEventRepository, EvalOutboxRepository, EvalResultRepository, FileLoader, JobEventBus,
+    JobRepository, LlmClient, Reranker, SparseEmbedder, StagingStore, TranscriptRepository,
+    TranscriptionEngine, TranscriptionOptions, VectorStore, WebhookRepository,
 };
 use sandakan::application::services::{
-    AgentService, AgentServicePort, EvalWorker, IngestionService, IngestionWorker, RetrievalService,
+    AgentService, AgentServicePort, ApiKeyService, DocumentService, EvalRunner, EvalTarget,
+    EvalWorker, IngestionQueueOptions, IngestionService, IngestionWorker, QueryTransformOptions,
+    RepositoryIngestionOptions, RepositoryIngestionService, RetrievalService, RetryPolicy,
+    UrlIngestionOptions, UrlIngestionService, WebhookDispatchOptions, WebhookDispatcher,
+    WebhookService,
 };
 use sandakan::domain::ContentType;
 use sandakan::infrastructure::audio::{
     FfmpegAudioDecoder, TranscriptionEngineFactory, TranscriptionProvider, check_ffmpeg_binary,
 };
 use sandakan::infrastructure::llm::{
-    EmbedderFactory, StreamingLlmClient, create_streaming_llm_client,
+    EmbedderFactory, RerankerFactory, StreamingLlmClient, create_streaming_llm_client,
 };
 use sandakan::infrastructure::mcp::{
     CompositeMcpClient, SseMcpClient, StandardMcpAdapter, StdioMcpClient, ToolHandler,
 };
 use sandakan::infrastructure::observability::{TracingConfig, init_tracing};
 use sandakan::infrastructure::persistence::{
-    PgConversationRepository, PgEvalEventRepository, PgEvalOutboxRepository,
-    PgEvalResultRepository, PgJobRepository, QdrantAdapter, create_pool,
+    PgApiKeyRepository, PgConversationRepository, PgDocumentRepository, PgEvalEventRepository,
+    PgEvalOutboxRepository, PgEvalResultRepository, PgJobEventBus, PgJobRepository,
+    PgTranscriptRepository, PgWebhookRepository, QdrantAdapter, create_pool, load_ground_truth,
 };
+use sandakan::infrastructure::source_tree::GitCheckoutReader;
 use sandakan::infrastructure::storage::StagingStoreFactory;
 use sandakan::infrastructure::text_processing::{
-    Bm25SparseEmbedder, CompositeFileLoader, ExtractorFactory, PlainTextAdapter,
-    TextSplitterFactory, TextSplitters,
+    AnalyzerLanguage, Bm25Params, Bm25SparseEmbedder, CompositeFileLoader, DocxAdapter,
+    ExtractorFactory, HtmlAdapter, PlainTextAdapter, PptxAdapter, SyntacticCodeSplitter,
+    TextAnalyzer, TextSplitterFactory, TextSplitters, XlsxAdapter,
 };
 use sandakan::infrastructure::tools::{
     GetFunctionSignaturesTool, InMemoryRagSourceCollector, LinkedInAdapter, LinkedInConfig,
@@ -46,13 +54,17 @@ use sandakan::infrastructure::tools::{
     NotificationFormat, RagSearchAdapter, ReadFileTool, SearchFilesTool, SemanticToolRegistry,
     StaticToolRegistry, WebSearchAdapter, WebSearchConfig, build_fs_tools,
 };
-use sandakan::presentation::config::ReflectionSettings;
+use sandakan::infrastructure::web::HttpWebFetcher;
+use sandakan::infrastructure::webhooks::{HmacWebhookSender, WebhookClient};
+use sandakan::presentation::config::{AnalyzerLanguageSetting, ReflectionSettings};
 use sandakan::presentation::config::{NotificationFormat as ConfigNotificationFormat, ToolConfig};
 use sandakan::presentation::{
     AppState, Environment, Settings, TranscriptionProviderSetting, create_router,
 };
 
-const INGESTION_CHANNEL_CAPACITY: usize = 64;
+const EVALUATE_COMMAND: &str = "evaluate";
+const EVALUATE_AGENT_FLAG: &str = "--agent";
+const WORKER_COMMAND: &str = "worker";
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -75,7 +87,8 @@ async fn main() -> anyhow::Result<()> {
 
     let pg_pool = init_database(&settings).await?;
 
-    let (job_repository, conversation_repository) = build_repositories(&pg_pool);
+    let (job_repository, conversation_repository, document_repository) =
+        build_repositories(&pg_pool);
     let file_loader = build_file_loader(&settings)?;
     let embedder = build_embedder(&settings)?;
     let llm_client = build_llm_client(&settings)?;
@@ -85,15 +98,39 @@ async fn main() -> anyhow::Result<()> {
     let staging_store = build_staging_store(&settings)?;
 
     let sparse_embedder: Option<Arc<dyn SparseEmbedder>> = if settings.qdrant.hybrid_search {
-        Some(Arc::new(Bm25SparseEmbedder::new()))
+        Some(build_sparse_embedder(&settings))
     } else {
         None
     };
 
-    let (eval_event_repo, eval_outbox_repo) = build_eval_repos(&settings, &pg_pool);
     let model_config = format!("{}/{}", settings.llm.provider, settings.llm.chat_model);
 
-    let retrieval_service = Arc::new(RetrievalService::new(
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some(EVALUATE_COMMAND) {
+        let target = if args.iter().any(|a| a == EVALUATE_AGENT_FLAG) {
+            EvalTarget::Agent
+        } else {
+            EvalTarget::Retrieval
+        };
+        let result = run_evaluation(
+            &settings,
+            target,
+            &pg_pool,
+            &embedder,
+            &llm_client,
+            &vector_store,
+            &conversation_repository,
+            sparse_embedder,
+            &model_config,
+        )
+        .await;
+        shutdown_tracing(otel_provider);
+        return result;
+    }
+
+    let (eval_event_repo, eval_outbox_repo) = build_eval_repos(&settings, &pg_pool);
+
+    let mut retrieval_service = RetrievalService::new(
         Arc::clone(&embedder),
         Arc::clone(&llm_client),
         Arc::clone(&vector_store),
@@ -106,7 +143,16 @@ async fn main() -> anyhow::Result<()> {
         settings.rag.similarity_threshold,
         settings.rag.max_context_tokens,
         settings.rag.fallback_message.clone(),
-    ));
+    );
+    retrieval_service = retrieval_service.with_query_transform(query_transform_options(&settings));
+    if let Some(reranker) = build_reranker(&settings)? {
+        retrieval_service = retrieval_service.with_reranker(
+            reranker,
+            settings.rerank.candidates,
+            settings.rerank.top_n,
+        );
+    }
+    let retrieval_service = Arc::new(retrieval_service);
 
     let ingestion_service = Arc::new(IngestionService::new(
         Arc::clone(&file_loader),
@@ -118,10 +164,46 @@ async fn main() -> anyhow::Result<()> {
         sparse_embedder.clone(),
     ));
 
-    let (ingestion_sender, ingestion_receiver) = mpsc::channel(INGESTION_CHANNEL_CAPACITY);
+    let ingestion_wakeup = Arc::new(Notify::new());
+    let job_events = Arc::new(PgJobEventBus::new(pg_pool.clone()));
+    let webhook_repository: Arc<dyn WebhookRepository> =
+        Arc::new(PgWebhookRepository::new(pg_pool.clone()));
+    let transcript_repository: Arc<dyn TranscriptRepository> =
+        Arc::new(PgTranscriptRepository::new(pg_pool.clone()));
+
+    // Built before the worker so worker processes can submit the pages their crawls fetch.
+    let document_service = Arc::new(
+        DocumentService::new(
+            Arc::clone(&document_repository),
+            Arc::clone(&job_repository),
+            Arc::clone(&vector_store),
+            Arc::clone(&staging_store),
+            Arc::clone(&transcript_repository),
+        )
+        .with_ingestion_wakeup(Arc::clone(&ingestion_wakeup)),
+    );
+    let url_ingestion_service = Arc::new(
+        build_url_ingestion_service(
+            &settings,
+            Arc::clone(&staging_store),
+            Arc::clone(&document_service),
+            Arc::clone(&job_repository),
+        )?
+        .with_job_events(Arc::clone(&job_events) as Arc<dyn JobEventBus>)
+        .with_ingestion_wakeup(Arc::clone(&ingestion_wakeup)),
+    );
+    let repository_ingestion_service = Arc::new(
+        build_repository_ingestion_service(
+            &settings,
+            Arc::clone(&staging_store),
+            Arc::clone(&document_service),
+            Arc::clone(&job_repository),
+        )
+        .with_job_events(Arc::clone(&job_events) as Arc<dyn JobEventBus>)
+        .with_ingestion_wakeup(Arc::clone(&ingestion_wakeup)),
+    );
 
     let mut ingestion_worker = IngestionWorker::new(
-        ingestion_receiver,
         Arc::clone(&file_loader),
         Arc::clone(&embedder),
         Arc::clone(&vector_store),
@@ -130,11 +212,32 @@ async fn main() -> anyhow::Result<()> {
         Arc::clone(&job_repository),
         transcription_engine,
         Arc::clone(&staging_store),
-    );
+    )
+    .with_document_repository(document_repository)
+    .with_transcript_repository(transcript_repository)
+    .with_url_ingestion(Arc::clone(&url_ingestion_service))
+    .with_repository_ingestion(Arc::clone(&repository_ingestion_service))
+    .with_code_splitter(Arc::new(SyntacticCodeSplitter::new(
+        settings.chunking.max_chunk_size,
+    )?))
+    .with_queue_options(ingestion_queue_options(&settings))
+    .with_wakeup(Arc::clone(&ingestion_wakeup))
+    .with_job_events(Arc::clone(&job_events) as Arc<dyn JobEventBus>)
+    .with_webhooks(Arc::clone(&webhook_repository));
     if let Some(sparse) = sparse_embedder {
         ingestion_worker = ingestion_worker.with_sparse_embedder(sparse);
     }
 
+    if args.first().map(String::as_str) == Some(WORKER_COMMAND) {
+        if let (Some(event_repo), Some(outbox_repo)) = (eval_event_repo, eval_outbox_repo) {
+            ingestion_worker = ingestion_worker.with_eval(event_repo, outbox_repo, &model_config);
+        }
+        tracing::info!("Running ingestion worker without the API server");
+        ingestion_worker.run().await;
+        shutdown_tracing(otel_provider);
+        return Ok(());
+    }
+
     let agent_eval_event_repo = eval_event_repo.clone();
     let agent_eval_outbox_repo = eval_outbox_repo.clone();
 
@@ -146,7 +249,19 @@ async fn main() -> anyhow::Result<()> {
         &llm_client,
         &model_config,
         &pg_pool,
+        &webhook_repository,
     );
+    if settings.webhooks.enabled {
+        let dispatcher = build_webhook_dispatcher(&settings, Arc::clone(&webhook_repository))?;
+        tokio::spawn(dispatcher.run());
+        tracing::info!("Webhook dispatcher spawned");
+    }
+
+    let rag_source_collector = if settings.eval.enabled {
+        build_rag_source_collector(&settings)
+    } else {
+        None
+    };
 
     let agent_service = build_agent_service(
         &settings,
@@ -156,17 +271,31 @@ async fn main() -> anyhow::Result<()> {
         &conversation_repository,
         agent_eval_event_repo,
         agent_eval_outbox_repo,
+        rag_source_collector,
     )
     .await?;
 
+    job_events.spawn_listener();
+
     let state = AppState {
         ingestion_service,
         retrieval_service,
+        document_service,
+        url_ingestion_service,
+        repository_ingestion_service,
         conversation_repository,
         job_repository,
-        ingestion_sender,
+        job_events,
         staging_store,
+        ingestion_wakeup,
         agent_service,
+        api_key_service: Arc::new(ApiKeyService::new(Arc::new(PgApiKeyRepository::new(
+            pg_pool.clone(),
+        )))),
+        webhook_service: Arc::new(
+            WebhookService::new(webhook_repository)
+                .with_private_networks(settings.webhooks.allow_private_networks),
+        ),
         settings: settings.clone(),
     };
 
@@ -178,13 +307,17 @@ async fn main() -> anyhow::Result<()> {
     let listener = TcpListener::bind(addr).await?;
     axum::serve(listener, router).await?;
 
+    shutdown_tracing(otel_provider);
+
+    Ok(())
+}
+
+fn shutdown_tracing(otel_provider: Option<opentelemetry_sdk::trace::SdkTracerProvider>) {
     if let Some(provider) = otel_provider {
         if let Err(e) = provider.shutdown() {
             tracing::warn!(error = %e, "OTel provider shutdown error");
         }
     }
-
-    Ok(())
 }
 
 fn load_settings() -> anyhow::Result<(Environment, Settings)> {
@@ -229,11 +362,17 @@ async fn init_database(settings: &Settings) -> anyhow::Result<PgPool> {
 
 fn build_repositories(
     pg_pool: &PgPool,
-) -> (Arc<dyn JobRepository>, Arc<dyn ConversationRepository>) {
+) -> (
+    Arc<dyn JobRepository>,
+    Arc<dyn ConversationRepository>,
+    Arc<dyn DocumentRepository>,
+) {
     let job_repository: Arc<dyn JobRepository> = Arc::new(PgJobRepository::new(pg_pool.clone()));
     let conversation_repository: Arc<dyn ConversationRepository> =
         Arc::new(PgConversationRepository::new(pg_pool.clone()));
-    (job_repository, conversation_repository)
+    let document_repository: Arc<dyn DocumentRepository> =
+        Arc::new(PgDocumentRepository::new(pg_pool.clone()));
+    (job_repository, conversation_repository, document_repository)
 }
 
 fn build_file_loader(settings: &Settings) -> anyhow::Result<Arc<CompositeFileLoader>> {
@@ -248,7 +387,12 @@ fn build_file_loader(settings: &Settings) -> anyhow::Result<Arc<CompositeFileLoa
     let text_adapter: Arc<dyn FileLoader> = Arc::new(PlainTextAdapter);
     Ok(Arc::new(CompositeFileLoader::new(vec![
         (ContentType::Pdf, pdf_adapter),
-        (ContentType::Text, text_adapter),
+        (ContentType::Text, Arc::clone(&text_adapter)),
+        (ContentType::Markdown, text_adapter),
+        (ContentType::Html, Arc::new(HtmlAdapter)),
+        (ContentType::Docx, Arc::new(DocxAdapter)),
+        (ContentType::Pptx, Arc::new(PptxAdapter)),
+        (ContentType::Xlsx, Arc::new(XlsxAdapter)),
     ])))
 }
 
@@ -262,6 +406,76 @@ fn build_embedder(settings: &Settings) -> anyhow::Result<Arc<dyn Embedder>> {
     Ok(embedder)
 }
 
+fn build_sparse_embedder(settings: &Settings) -> Arc<dyn SparseEmbedder> {
+    let bm25 = &settings.qdrant.bm25;
+    let languages: Vec<AnalyzerLanguage> = bm25
+        .languages
+        .iter()
+        .map(|language| match language {
+            AnalyzerLanguageSetting::English => AnalyzerLanguage::English,
+            AnalyzerLanguageSetting::German => AnalyzerLanguage::German,
+            AnalyzerLanguageSetting::Ukrainian => AnalyzerLanguage::Ukrainian,
+        })
+        .collect();
+    tracing::info!(languages = ?languages, stemming = bm25.stemming, "BM25 analyzer configured");
+
+    Arc::new(
+        Bm25SparseEmbedder::with_params(Bm25Params {
+            k1: bm25.k1,
+            b: bm25.b,
+            avg_doc_length: bm25.avg_doc_length,
+        })
+        .with_analyzer(TextAnalyzer::new(&languages, bm25.stemming)),
+    )
+}
+
+fn ingestion_queue_options(settings: &Settings) -> IngestionQueueOptions {
+    let ingestion = &settings.ingestion;
+    let host = env::var("HOSTNAME").unwrap_or_else(|_| "sandakan".to_string());
+    IngestionQueueOptions {
+        worker_id: format!("{host}-{}", std::process::id()),
+        concurrency: ingestion.workers,
+        poll_interval: std::time::Duration::from_millis(ingestion.poll_interval_ms),
+        lease: std::time::Duration::from_secs(ingestion.lease_secs),
+        retry: RetryPolicy {
+            max_attempts: ingestion.max_attempts,
+            base_delay: std::time::Duration::from_millis(ingestion.retry_base_delay_ms),
+            rate_limited_base_delay: std::time::Duration::from_millis(
+                ingestion.rate_limited_base_delay_ms,
+            ),
+            max_delay: std::time::Duration::from_millis(ingestion.retry_max_delay_ms),
+        },
+    }
+}
+
+fn query_transform_options(settings: &Settings) -> QueryTransformOptions {
+    let transform = &settings.rag.query_transform;
+    QueryTransformOptions {
+        condense: transform.condense,
+        history_messages: transform.history_messages,
+        paraphrases: transform.paraphrases,
+        hyde: transform.hyde,
+    }
+}
+
+fn build_reranker(settings: &Settings) -> anyhow::Result<Option<Arc<dyn Reranker>>> {
+    if !settings.rerank.enabled {
+        return Ok(None);
+    }
+    let reranker = RerankerFactory::create(
+        settings.rerank.provider,
+        settings.rerank.model.clone(),
+        settings.rerank.base_url.clone(),
+        settings.rerank.api_key.clone(),
+    )?;
+    tracing::info!(
+        candidates = settings.rerank.candidates,
+        top_n = settings.rerank.top_n,
+        "Reranking enabled"
+    );
+    Ok(Some(reranker))
+}
+
 fn build_llm_client(settings: &Settings) -> anyhow::Result<Arc<StreamingLlmClient>> {
     let client = Arc::new(
         create_streaming_llm_client(&settings.llm, settings.rag.system_prompt.clone())
@@ -328,6 +542,26 @@ async fn build_vector_store(settings: &Settings) -> anyhow::Result<Arc<QdrantAda
                     .expect("Failed to recreate collection with dense-only schema");
                 tracing::info!("Collection recreated with dense-only vectors");
             } else {
+                if is_hybrid {
+                    match vector_store.ensure_sparse_idf().await {
+                        Ok(true) => tracing::warn!(
+                            "Enabled IDF on the sparse vector; re-ingest documents to replace \
+                             pre-BM25 sparse weights"
+                        ),
+                        Ok(false) => {}
+                        Err(e) => tracing::warn!("Could not enable sparse IDF: {}", e),
+                    }
+                }
+                match vector_store
+                    .ensure_payload_indexes(&collection_config.payload_indexes)
+                    .await
+                {
+                    Ok(created) if !created.is_empty() => {
+                        tracing::info!(fields = ?created, "Created missing payload indexes")
+                    }
+                    Ok(_) => {}
+                    Err(e) => tracing::warn!("Could not create payload indexes: {}", e),
+                }
                 tracing::info!(
                     dimension = existing_size,
                     hybrid = is_hybrid,
@@ -393,6 +627,11 @@ fn build_transcription_engine(settings: &Settings) -> anyhow::Result<Arc<dyn Tra
         settings.extraction.audio.azure_api_version.clone(),
         audio_decoder,
         settings.extraction.audio.asr_corrections.clone(),
+        TranscriptionOptions {
+            language: settings.extraction.audio.language.clone(),
+            translate: settings.extraction.audio.translate,
+            word_timestamps: settings.extraction.audio.word_timestamps,
+        },
     )
     .expect("Failed to initialize transcription engine");
 
@@ -405,6 +644,66 @@ fn build_transcription_engine(settings: &Settings) -> anyhow::Result<Arc<dyn Tra
     Ok(engine)
 }
 
+fn build_url_ingestion_service(
+    settings: &Settings,
+    staging_store: Arc<dyn StagingStore>,
+    document_service: Arc<DocumentService<QdrantAdapter>>,
+    job_repository: Arc<dyn JobRepository>,
+) -> anyhow::Result<UrlIngestionService<QdrantAdapter>> {
+    let web = &settings.ingestion.web;
+    let fetcher = HttpWebFetcher::new(
+        &web.user_agent,
+        std::time::Duration::from_secs(web.timeout_secs),
+        web.max_response_bytes,
+    )?
+    .with_private_networks(web.allow_private_networks);
+    let options = UrlIngestionOptions {
+        robots_user_agent: web.robots_token.clone(),
+        max_depth: web.max_depth,
+        max_pages: web.max_pages,
+        ..UrlIngestionOptions::default()
+    };
+    Ok(UrlIngestionService::new(
+        Arc::new(fetcher),
+        staging_store,
+        document_service,
+        job_repository,
+    )
+    .with_options(options))
+}
+
+fn build_repository_ingestion_service(
+    settings: &Settings,
+    staging_store: Arc<dyn StagingStore>,
+    document_service: Arc<DocumentService<QdrantAdapter>>,
+    job_repository: Arc<dyn JobRepository>,
+) -> RepositoryIngestionService<QdrantAdapter> {
+    let repository = &settings.ingestion.repository;
+    let allowed_roots = repository
+        .allowed_roots
+        .iter()
+        .filter_map(|root| match std::fs::canonicalize(root) {
+            Ok(root) => Some(root),
+            Err(e) => {
+                tracing::warn!(root = %root, error = %e, "Skipping repository root");
+                None
+            }
+        })
+        .collect();
+    let options = RepositoryIngestionOptions {
+        allowed_roots,
+        max_files: repository.max_files,
+        max_file_bytes: repository.max_file_bytes,
+    };
+    RepositoryIngestionService::new(
+        Arc::new(GitCheckoutReader::new()),
+        staging_store,
+        document_service,
+        job_repository,
+    )
+    .with_options(options)
+}
+
 fn build_staging_store(settings: &Settings) -> anyhow::Result<Arc<dyn StagingStore>> {
     let store =
         StagingStoreFactory::create(&settings.storage).expect("Failed to initialize staging store");
@@ -436,6 +735,165 @@ fn build_eval_repos(
     }
 }
 
+fn build_eval_judge(
+    settings: &Settings,
+    llm_client: &Arc<StreamingLlmClient>,
+) -> Arc<dyn LlmClient> {
+    let judge_llm_settings = settings.eval.judge.as_ref().unwrap_or(&settings.llm);
+    match create_streaming_llm_client(judge_llm_settings, String::new()) {
+        Ok(c) => {
+            tracing::info!(
+                provider = %judge_llm_settings.provider,
+                model = %judge_llm_settings.chat_model,
+                dedicated_judge = settings.eval.judge.is_some(),
+                "Eval judge initialized"
+            );
+            Arc::new(c)
+        }
+        Err(e) => {
+            tracing::warn!(
+                error = %e,
+                "Failed to build dedicated eval judge; falling back to main LLM client"
+            );
+            llm_client.clone() as Arc<dyn LlmClient>
+        }
+    }
+}
+
+fn judge_model_label(settings: &Settings) -> String {
+    let judge_llm_settings = settings.eval.judge.as_ref().unwrap_or(&settings.llm);
+    format!(
+        "{}/{}",
+        judge_llm_settings.provider, judge_llm_settings.chat_model
+    )
+}
+
+fn build_rag_source_collector(settings: &Settings) -> Option<Arc<dyn RagSourceCollector>> {
+    let has_rag_search = settings
+        .agent
+        .tools
+        .iter()
+        .any(|t| matches!(t, ToolConfig::RagSearch));
+    if has_rag_search {
+        Some(Arc::new(InMemoryRagSourceCollector::new()))
+    } else {
+        None
+    }
+}
+
+/// Offline golden-dataset run (`sandakan evaluate [--agent]`).
+///
+/// Builds its own `RetrievalService`/`AgentService` without eval repositories so the
+/// replayed questions are not also enqueued for passive outbox scoring. Fails (non-zero
+/// exit) when the mean faithfulness falls below `eval.faithfulness_threshold`, which lets
+/// CI gate retrieval and chunking changes on regressions.
+#[allow(clippy::too_many_arguments)]
+async fn run_evaluation(
+    settings: &Settings,
+    target: EvalTarget,
+    pg_pool: &PgPool,
+    embedder: &Arc<dyn Embedder>,
+    llm_client: &Arc<StreamingLlmClient>,
+    vector_store: &Arc<QdrantAdapter>,
+    conversation_repository: &Arc<dyn ConversationRepository>,
+    sparse_embedder: Option<Arc<dyn SparseEmbedder>>,
+    model_config: &str,
+) -> anyhow::Result<()> {
+    let dataset_path = std::path::Path::new(&settings.eval.dataset_path);
+    let entries = load_ground_truth(dataset_path).await?;
+    tracing::info!(
+        dataset = %settings.eval.dataset_path,
+        entries = entries.len(),
+        target = ?target,
+        "Golden dataset loaded"
+    );
+
+    let mut retrieval_service = RetrievalService::new(
+        Arc::clone(embedder),
+        Arc::clone(llm_client),
+        Arc::clone(vector_store),
+        Arc::clone(conversation_repository),
+        None,
+        None,
+        sparse_embedder,
+        model_config.to_string(),
+        settings.rag.top_k,
+        settings.rag.similarity_threshold,
+        settings.rag.max_context_tokens,
+        settings.rag.fallback_message.clone(),
+    );
+    retrieval_service = retrieval_service.with_query_transform(query_transform_options(settings));
+    if let Some(reranker) = build_reranker(settings)? {
+        retrieval_service = retrieval_service.with_reranker(
+            reranker,
+            settings.rerank.candidates,
+            settings.rerank.top_n,
+        );
+    }
+    let retrieval_service = Arc::new(retrieval_service);
+
+    let mut runner = EvalRunner::new(
+        Arc::clone(&retrieval_service),
+        Arc::new(PgEvalEventRepository::new(pg_pool.clone())),
+        Arc::new(PgEvalResultRepository::new(pg_pool.clone())),
+        build_eval_judge(settings, llm_client),
+        Arc::clone(embedder),
+        settings.eval.faithfulness_threshold,
+        model_config,
+        &judge_model_label(settings),
+    );
+
+    if target == EvalTarget::Agent {
+        let rag_source_collector = build_rag_source_collector(settings);
+        let agent_service = build_agent_service(
+            settings,
+            llm_client,
+            embedder,
+            &retrieval_service,
+            conversation_repository,
+            None,
+            None,
+            rag_source_collector.clone(),
+        )
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("agent.enabled must be true to evaluate the agent"))?;
+        runner = runner.with_agent(agent_service, rag_source_collector);
+    }
+
+    let started_at = chrono::Utc::now();
+    let report = runner.run(&entries, target).await?;
+    let report_path = settings.eval.report_file(report.run_id, started_at);
+    if let Some(dir) = report_path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        tokio::fs::create_dir_all(dir).await?;
+    }
+    tokio::fs::write(&report_path, serde_json::to_string_pretty(&report)?).await?;
+    tracing::info!(
+        run_id = %report.run_id,
+        report_path = %report_path.display(),
+        below_threshold_count = report.below_threshold_count,
+        errored_count = report.errored_count,
+        "Evaluation report written"
+    );
+
+    if report.errored_count > 0 {
+        return Err(anyhow::anyhow!(
+            "{} of {} questions could not be scored; see {}",
+            report.errored_count,
+            report.total_evaluated,
+            report_path.display()
+        ));
+    }
+    match report.mean_faithfulness {
+        Some(mean) if mean < settings.eval.faithfulness_threshold => Err(anyhow::anyhow!(
+            "mean faithfulness {:.3} is below threshold {:.3}",
+            mean,
+            settings.eval.faithfulness_threshold
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 fn spawn_workers(
     settings: &Settings,
     ingestion_worker: IngestionWorker<CompositeFileLoader, QdrantAdapter>,
@@ -444,32 +902,14 @@ fn spawn_workers(
     llm_client: &Arc<StreamingLlmClient>,
     model_config: &str,
     pg_pool: &PgPool,
+    webhook_repository: &Arc<dyn WebhookRepository>,
 ) {
     let ingestion_worker =
         if let (Some(event_repo), Some(outbox_repo)) = (eval_event_repo, eval_outbox_repo) {
             let result_repo: Arc<dyn EvalResultRepository> =
                 Arc::new(PgEvalResultRepository::new(pg_pool.clone()));
 
-            let judge_llm_settings = settings.eval.judge.as_ref().unwrap_or(&settings.llm);
-            let judge: Arc<dyn LlmClient> =
-                match create_streaming_llm_client(judge_llm_settings, String::new()) {
-                    Ok(c) => {
-                        tracing::info!(
-                            provider = %judge_llm_settings.provider,
-                            model = %judge_llm_settings.chat_model,
-                            dedicated_judge = settings.eval.judge.is_some(),
-                            "Eval judge initialized"
-                        );
-                        Arc::new(c)
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            error = %e,
-                            "Failed to build dedicated eval judge; falling back to main LLM client"
-                        );
-                        llm_client.clone() as Arc<dyn LlmClient>
-                    }
-                };
+            let judge = build_eval_judge(settings, llm_client);
 
             let eval_worker = EvalWorker::new(
                 outbox_repo.clone(),
@@ -479,7 +919,8 @@ fn spawn_workers(
                 settings.eval.faithfulness_threshold,
                 std::time::Duration::from_secs(settings.eval.worker_poll_interval_secs),
                 settings.eval.worker_batch_size,
-            );
+            )
+            .with_webhooks(Arc::clone(webhook_repository));
             tokio::spawn(async move {
                 eval_worker.run().await;
             });
@@ -495,10 +936,43 @@ fn spawn_workers(
             ingestion_worker
         };
 
-    tokio::spawn(async move {
-        ingestion_worker.run().await;
-    });
-    tracing::info!("Ingestion worker spawned");
+    if settings.ingestion.embedded_workers {
+        tokio::spawn(async move {
+            ingestion_worker.run().await;
+        });
+        tracing::info!(
+            workers = settings.ingestion.workers,
+            "Ingestion worker spawned"
+        );
+    } else {
+        tracing::info!("Embedded ingestion workers disabled; run `sandakan worker` to ingest");
+    }
+}
+
+fn build_webhook_dispatcher(
+    settings: &Settings,
+    webhook_repository: Arc<dyn WebhookRepository>,
+) -> anyhow::Result<WebhookDispatcher> {
+    let webhooks = &settings.webhooks;
+    let client = WebhookClient::for_tenant_endpoints(
+        std::time::Duration::from_secs(webhooks.timeout_secs),
+        webhooks.allow_private_networks,
+    )?;
+    let defaults = WebhookDispatchOptions::default();
+    Ok(
+        WebhookDispatcher::new(webhook_repository, Arc::new(HmacWebhookSender::new(client)))
+            .with_options(WebhookDispatchOptions {
+                poll_interval: std::time::Duration::from_millis(webhooks.poll_interval_ms),
+                batch_size: webhooks.batch_size,
+                retry: RetryPolicy {
+                    max_attempts: webhooks.max_attempts,
+                    base_delay: std::time::Duration::from_millis(webhooks.retry_base_delay_ms),
+                    max_delay: std::time::Duration::from_millis(webhooks.retry_max_delay_ms),
+                    ..defaults.retry
+                },
+                ..defaults
+            }),
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -510,6 +984,7 @@ async fn build_agent_service(
     conversation_repository: &Arc<dyn ConversationRepository>,
     eval_event_repo: Option<Arc<dyn EvalEventRepository>>,
     eval_outbox_repo: Option<Arc<dyn EvalOutboxRepository>>,
+    rag_source_collector: Option<Arc<dyn RagSourceCollector>>,
 ) -> anyhow::Result<Option<Arc<dyn AgentServicePort>>> {
     if !settings.agent.enabled {
         tracing::info!("Agent feature disabled");
@@ -520,18 +995,6 @@ async fn build_agent_service(
     let mut schemas = Vec::new();
     let mut wire_clients: Vec<Arc<dyn McpClientPort>> = Vec::new();
 
-    let has_rag_search = settings
-        .agent
-        .tools
-        .iter()
-        .any(|t| matches!(t, ToolConfig::RagSearch));
-    let rag_source_collector: Option<Arc<dyn RagSourceCollector>> =
-        if has_rag_search && settings.eval.enabled {
-            Some(Arc::new(InMemoryRagSourceCollector::new()))
-        } else {
-            None
-        };
-
     for tool in &settings.agent.tools {
         match tool {
             ToolConfig::RagSearch => {
@@ -3,11 +3,14 @@ mod settings;
 
 pub use environment::Environment;
 pub use settings::{
-    AgentServiceConfig, AgentSettings, AudioExtractionSettings, ChatMode, ChunkingSettings,
-    ChunkingStrategy, DatabaseSettings, EmbeddingProvider, EmbeddingsSettings, EvalSettings,
-    ExtractionSettings, ExtractorProvider, FsConfig, LlmSettings, LoggingSettings, McpSseConfig,
-    McpStdioConfig, NotificationConfig, NotificationFormat, PdfExtractionSettings, QdrantSettings,
-    RagSettings, ReflectionSettings, ServerSettings, Settings, StorageProviderSetting,
-    StorageSettings, ToolConfig, TranscriptionProviderSetting, VideoExtractionSettings,
-    WebSearchConfig,
+    AgentServiceConfig, AgentSettings, AnalyzerLanguageSetting, AudioExtractionSettings,
+    AuthSettings, Bm25Settings, ChatMode, ChunkingSettings, ChunkingStrategy, DatabaseSettings,
+    EmbeddingProvider, EmbeddingsSettings, EvalSettings, ExtractionSettings, ExtractorProvider,
+    FsConfig, IngestionSettings, LlmSettings, LoggingSettings, McpSseConfig, McpStdioConfig,
+    NotificationConfig, NotificationFormat, PdfExtractionSettings, PdfFallbackProvider,
+    QdrantSettings, QueryTransformSettings, RagSettings, ReflectionSettings,
+    RepositoryIngestionSettings, RerankProvider, RerankSettings, ServerSettings, Settings,
+    StorageProviderSetting, StorageSettings, TenancySettings, ToolConfig,
+    TranscriptionProviderSetting, VideoExtractionSettings, WebIngestionSettings, WebSearchConfig,
+    WebhookSettings,
 };
@@ -0,0 +1,13 @@
+use serde::Deserialize;
+
+/// API key authentication.
+///
+/// When `enabled`, every non-public route requires `Authorization: Bearer <key>` (the scheme
+/// Open WebUI's OpenAI client already sends) resolved against the `api_keys` table or the
+/// static keys in [`super::TenancySettings`]. Disabled, requests run as an anonymous
+/// principal holding every scope, unless static keys are configured.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct AuthSettings {
+    #[serde(default)]
+    pub enabled: bool,
+}
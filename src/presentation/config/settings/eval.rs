@@ -1,6 +1,10 @@
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
 
 use super::LlmSettings;
+use crate::domain::EvalRunId;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct EvalSettings {
@@ -16,6 +20,15 @@ pub struct EvalSettings {
     /// When absent the main `llm` settings are used as the judge.
     #[serde(default)]
     pub judge: Option<LlmSettings>,
+    /// Golden dataset replayed by `sandakan evaluate` (one `EvalEntry` per JSONL line).
+    #[serde(default = "default_dataset_path")]
+    pub dataset_path: String,
+    /// Fixed file for the `sandakan evaluate` run report. When unset, each run writes its
+    /// own `evaluation_report-<timestamp>-<run_id>.json` under `report_dir`.
+    #[serde(default)]
+    pub report_path: Option<String>,
+    #[serde(default = "default_report_dir")]
+    pub report_dir: String,
 }
 
 fn default_faithfulness_threshold() -> f32 {
@@ -30,6 +43,29 @@ fn default_batch_size() -> usize {
     10
 }
 
+fn default_dataset_path() -> String {
+    "collections/eval/ground_truth.jsonl".to_string()
+}
+
+fn default_report_dir() -> String {
+    "reports/eval".to_string()
+}
+
+impl EvalSettings {
+    /// Report file for a run started at `started_at`, so runs never overwrite each other
+    /// unless `report_path` pins one.
+    pub fn report_file(&self, run_id: EvalRunId, started_at: DateTime<Utc>) -> PathBuf {
+        match &self.report_path {
+            Some(path) => PathBuf::from(path),
+            None => Path::new(&self.report_dir).join(format!(
+                "evaluation_report-{}-{}.json",
+                started_at.format("%Y%m%dT%H%M%SZ"),
+                run_id
+            )),
+        }
+    }
+}
+
 impl Default for EvalSettings {
     fn default() -> Self {
         Self {
@@ -38,6 +74,9 @@ impl Default for EvalSettings {
             worker_poll_interval_secs: default_poll_interval(),
             worker_batch_size: default_batch_size(),
             judge: None,
+            dataset_path: default_dataset_path(),
+            report_path: None,
+            report_dir: default_report_dir(),
         }
     }
 }
@@ -8,12 +8,32 @@ pub enum ExtractorProvider {
     LocalVlm,
     LmStudio,
     Azure,
+    /// Embedded text layer, with a VLM only for pages that have too little text.
+    Hybrid,
 }
 
 fn default_extractor_provider() -> ExtractorProvider {
     ExtractorProvider::LocalVlm
 }
 
+/// VLM used by the hybrid extractor for scanned or image-only pages.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PdfFallbackProvider {
+    LocalVlm,
+    LmStudio,
+    /// Text layer only; low-text pages are kept as extracted.
+    None,
+}
+
+fn default_pdf_fallback_provider() -> PdfFallbackProvider {
+    PdfFallbackProvider::LocalVlm
+}
+
+fn default_min_page_chars() -> usize {
+    50
+}
+
 #[derive(Debug, Clone, Copy, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum TranscriptionProviderSetting {
@@ -46,6 +66,11 @@ pub struct PdfExtractionSettings {
     pub azure_endpoint: Option<String>,
     #[serde(default)]
     pub azure_key: Option<String>,
+    #[serde(default = "default_pdf_fallback_provider")]
+    pub fallback_provider: PdfFallbackProvider,
+    /// Pages with fewer alphanumeric characters in their text layer go to the fallback VLM.
+    #[serde(default = "default_min_page_chars")]
+    pub min_page_chars: usize,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -67,6 +92,16 @@ pub struct AudioExtractionSettings {
     /// Keys are ASR artifacts (case-insensitive match), values are the correct terms.
     #[serde(default)]
     pub asr_corrections: HashMap<String, String>,
+    /// Spoken language as an ISO 639-1 code or English name. Unset or `auto` detects it
+    /// per recording.
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Translate speech into English instead of transcribing it as spoken.
+    #[serde(default)]
+    pub translate: bool,
+    /// Time each word, so transcript chunks start at their first word.
+    #[serde(default)]
+    pub word_timestamps: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
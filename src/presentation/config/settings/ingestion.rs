@@ -0,0 +1,182 @@
+use serde::Deserialize;
+
+/// Background ingestion workers consuming the job queue in Postgres.
+///
+/// Workers can run inside the server (`embedded_workers`) or as separate `worker` processes;
+/// any mix of them can share one database.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IngestionSettings {
+    /// Run ingestion workers inside the API server process.
+    #[serde(default = "default_embedded_workers")]
+    pub embedded_workers: bool,
+    /// Jobs processed concurrently per process.
+    #[serde(default = "default_workers")]
+    pub workers: usize,
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+    /// How long a claimed job stays reserved without a heartbeat before another worker may
+    /// take it over.
+    #[serde(default = "default_lease_secs")]
+    pub lease_secs: u64,
+    /// Attempts a job gets before it is marked failed, whether its worker died or it hit a
+    /// retryable error (rate limits, network failures).
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// Backoff after the first transient failure; doubles with each further attempt.
+    #[serde(default = "default_retry_base_delay_ms")]
+    pub retry_base_delay_ms: u64,
+    /// Backoff after the first rate-limited attempt; doubles with each further attempt.
+    #[serde(default = "default_rate_limited_base_delay_ms")]
+    pub rate_limited_base_delay_ms: u64,
+    #[serde(default = "default_retry_max_delay_ms")]
+    pub retry_max_delay_ms: u64,
+    #[serde(default)]
+    pub web: WebIngestionSettings,
+    #[serde(default)]
+    pub repository: RepositoryIngestionSettings,
+}
+
+/// Fetching of web pages for `POST /api/v1/ingest-url`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebIngestionSettings {
+    /// `User-Agent` header sent with every request.
+    #[serde(default = "default_user_agent")]
+    pub user_agent: String,
+    /// Product token matched against `User-agent` lines in robots.txt.
+    #[serde(default = "default_robots_token")]
+    pub robots_token: String,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Responses above this size are recorded as fetch failures.
+    #[serde(default = "default_max_response_bytes")]
+    pub max_response_bytes: u64,
+    /// Highest link depth a request may ask for.
+    #[serde(default = "default_max_depth")]
+    pub max_depth: u32,
+    /// Highest page count a request may ask for.
+    #[serde(default = "default_max_pages")]
+    pub max_pages: u32,
+    /// Lets requests reach loopback, private and link-local addresses. Leave off unless the
+    /// service only ingests intranet sites and cannot reach anything sensitive.
+    #[serde(default)]
+    pub allow_private_networks: bool,
+}
+
+/// Syncing of local git checkouts for `POST /api/v1/ingest-repository`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RepositoryIngestionSettings {
+    /// Directories checkouts must live under; empty disables repository ingestion.
+    #[serde(default)]
+    pub allowed_roots: Vec<String>,
+    /// Files a single sync ingests at most; the rest are skipped and summarized in one failure.
+    #[serde(default = "default_max_files")]
+    pub max_files: usize,
+    /// Files above this size are recorded as failures.
+    #[serde(default = "default_max_file_bytes")]
+    pub max_file_bytes: u64,
+}
+
+fn default_embedded_workers() -> bool {
+    true
+}
+
+fn default_workers() -> usize {
+    1
+}
+
+fn default_poll_interval_ms() -> u64 {
+    1000
+}
+
+fn default_lease_secs() -> u64 {
+    60
+}
+
+fn default_max_attempts() -> u32 {
+    3
+}
+
+fn default_retry_base_delay_ms() -> u64 {
+    2_000
+}
+
+fn default_rate_limited_base_delay_ms() -> u64 {
+    30_000
+}
+
+fn default_retry_max_delay_ms() -> u64 {
+    300_000
+}
+
+fn default_user_agent() -> String {
+    concat!("sandakan/", env!("CARGO_PKG_VERSION")).to_string()
+}
+
+fn default_robots_token() -> String {
+    "sandakan".to_string()
+}
+
+fn default_timeout_secs() -> u64 {
+    30
+}
+
+fn default_max_response_bytes() -> u64 {
+    20 * 1024 * 1024
+}
+
+fn default_max_depth() -> u32 {
+    3
+}
+
+fn default_max_pages() -> u32 {
+    200
+}
+
+fn default_max_files() -> usize {
+    5_000
+}
+
+fn default_max_file_bytes() -> u64 {
+    1024 * 1024
+}
+
+impl Default for IngestionSettings {
+    fn default() -> Self {
+        Self {
+            embedded_workers: default_embedded_workers(),
+            workers: default_workers(),
+            poll_interval_ms: default_poll_interval_ms(),
+            lease_secs: default_lease_secs(),
+            max_attempts: default_max_attempts(),
+            retry_base_delay_ms: default_retry_base_delay_ms(),
+            rate_limited_base_delay_ms: default_rate_limited_base_delay_ms(),
+            retry_max_delay_ms: default_retry_max_delay_ms(),
+            web: WebIngestionSettings::default(),
+            repository: RepositoryIngestionSettings::default(),
+        }
+    }
+}
+
+impl Default for WebIngestionSettings {
+    fn default() -> Self {
+        Self {
+            user_agent: default_user_agent(),
+            robots_token: default_robots_token(),
+            timeout_secs: default_timeout_secs(),
+            max_response_bytes: default_max_response_bytes(),
+            max_depth: default_max_depth(),
+            max_pages: default_max_pages(),
+            allow_private_networks: false,
+        }
+    }
+}
+
+impl Default for RepositoryIngestionSettings {
+    fn default() -> Self {
+        Self {
+            allowed_roots: Vec::new(),
+            max_files: default_max_files(),
+            max_file_bytes: default_max_file_bytes(),
+        }
+    }
+}
@@ -1,34 +1,44 @@
 mod agent;
+mod auth;
 mod chunking;
 mod database;
 mod embeddings;
 mod eval;
 mod extraction;
+mod ingestion;
 mod llm;
 mod logging;
 mod qdrant;
 mod rag;
+mod rerank;
 mod server;
 mod storage;
+mod tenancy;
+mod webhooks;
 
 pub use agent::{
     AgentServiceConfig, AgentSettings, ChatMode, FsConfig, McpSseConfig, McpStdioConfig,
     NotificationConfig, NotificationFormat, ReflectionSettings, ToolConfig, WebSearchConfig,
 };
+pub use auth::AuthSettings;
 pub use chunking::{ChunkingSettings, ChunkingStrategy};
 pub use database::DatabaseSettings;
 pub use embeddings::{EmbeddingProvider, EmbeddingsSettings};
 pub use eval::EvalSettings;
 pub use extraction::{
     AudioExtractionSettings, ExtractionSettings, ExtractorProvider, PdfExtractionSettings,
-    TranscriptionProviderSetting, VideoExtractionSettings,
+    PdfFallbackProvider, TranscriptionProviderSetting, VideoExtractionSettings,
 };
+pub use ingestion::{IngestionSettings, RepositoryIngestionSettings, WebIngestionSettings};
 pub use llm::LlmSettings;
 pub use logging::LoggingSettings;
-pub use qdrant::QdrantSettings;
-pub use rag::RagSettings;
+pub use qdrant::{AnalyzerLanguageSetting, Bm25Settings, QdrantSettings};
+pub use rag::{QueryTransformSettings, RagSettings};
+pub use rerank::{RerankProvider, RerankSettings};
 pub use server::ServerSettings;
 pub use storage::{StorageProviderSetting, StorageSettings};
+pub use tenancy::TenancySettings;
+pub use webhooks::WebhookSettings;
 
 use serde::Deserialize;
 
@@ -45,7 +55,17 @@ pub struct Settings {
     pub storage: StorageSettings,
     pub rag: RagSettings,
     #[serde(default)]
+    pub rerank: RerankSettings,
+    #[serde(default)]
+    pub ingestion: IngestionSettings,
+    #[serde(default)]
     pub eval: EvalSettings,
     #[serde(default)]
     pub agent: AgentSettings,
+    #[serde(default)]
+    pub tenancy: TenancySettings,
+    #[serde(default)]
+    pub auth: AuthSettings,
+    #[serde(default)]
+    pub webhooks: WebhookSettings,
 }
@@ -6,4 +6,68 @@ pub struct QdrantSettings {
     pub collection_name: String,
     #[serde(default)]
     pub hybrid_search: bool,
+    #[serde(default)]
+    pub bm25: Bm25Settings,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnalyzerLanguageSetting {
+    English,
+    German,
+    Ukrainian,
+}
+
+/// BM25 parameters for the sparse half of hybrid search. IDF comes from Qdrant.
+///
+/// Changing `languages` or `stemming` changes the indexed terms; re-ingest afterwards.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Bm25Settings {
+    #[serde(default = "default_k1")]
+    pub k1: f32,
+    #[serde(default = "default_b")]
+    pub b: f32,
+    /// Typical chunk length in tokens, used as BM25's average document length for
+    /// length normalization. This is a configured approximation rather than the mean
+    /// over the indexed chunks; keep it near the chunk size and re-ingest after changing it.
+    #[serde(default = "default_avg_doc_length")]
+    pub avg_doc_length: f32,
+    /// Corpus languages. With more than one, each chunk's language is detected and the
+    /// first entry is the fallback.
+    #[serde(default = "default_languages")]
+    pub languages: Vec<AnalyzerLanguageSetting>,
+    #[serde(default = "default_stemming")]
+    pub stemming: bool,
+}
+
+fn default_k1() -> f32 {
+    1.2
+}
+
+fn default_b() -> f32 {
+    0.75
+}
+
+fn default_avg_doc_length() -> f32 {
+    256.0
+}
+
+fn default_languages() -> Vec<AnalyzerLanguageSetting> {
+    vec![AnalyzerLanguageSetting::English]
+}
+
+fn default_stemming() -> bool {
+    true
+}
+
+impl Default for Bm25Settings {
+    fn default() -> Self {
+        Self {
+            k1: default_k1(),
+            b: default_b(),
+            avg_doc_length: default_avg_doc_length(),
+            languages: default_languages(),
+            stemming: default_stemming(),
+        }
+    }
 }
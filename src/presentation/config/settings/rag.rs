@@ -7,4 +7,39 @@ pub struct RagSettings {
     pub top_k: usize,
     pub system_prompt: String,
     pub fallback_message: String,
+    #[serde(default)]
+    pub query_transform: QueryTransformSettings,
+}
+
+/// LLM-driven query transformations applied before retrieval. Each adds an LLM round-trip
+/// per question; all are off by default.
+#[derive(Debug, Clone, Deserialize)]
+pub struct QueryTransformSettings {
+    /// Rewrite follow-up questions in a conversation into standalone questions.
+    #[serde(default)]
+    pub condense: bool,
+    /// Most recent conversation messages used for condensing.
+    #[serde(default = "default_history_messages")]
+    pub history_messages: usize,
+    /// Paraphrases searched alongside the question (multi-query); 0 disables.
+    #[serde(default)]
+    pub paraphrases: usize,
+    /// Also search with an LLM-written hypothetical answer (HyDE).
+    #[serde(default)]
+    pub hyde: bool,
+}
+
+fn default_history_messages() -> usize {
+    6
+}
+
+impl Default for QueryTransformSettings {
+    fn default() -> Self {
+        Self {
+            condense: false,
+            history_messages: default_history_messages(),
+            paraphrases: 0,
+            hyde: false,
+        }
+    }
 }
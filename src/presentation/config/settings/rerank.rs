@@ -0,0 +1,60 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RerankProvider {
+    /// Candle cross-encoder loaded from the Hugging Face Hub.
+    #[default]
+    Local,
+    /// OpenAI-style `POST {base_url}/rerank` (LM Studio, llama.cpp, vLLM, TEI, ...).
+    Http,
+}
+
+/// Optional cross-encoder stage between vector search and context building.
+///
+/// When enabled, retrieval fetches `candidates` chunks, rescores them against the query and
+/// keeps the best `top_n`, which replaces `rag.top_k` as the number of chunks in context.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RerankSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub provider: RerankProvider,
+    #[serde(default = "default_model")]
+    pub model: String,
+    /// API root for the `http` provider, e.g. `http://localhost:1234/v1`.
+    #[serde(default)]
+    pub base_url: Option<String>,
+    #[serde(default)]
+    pub api_key: Option<String>,
+    #[serde(default = "default_candidates")]
+    pub candidates: usize,
+    #[serde(default = "default_top_n")]
+    pub top_n: usize,
+}
+
+fn default_model() -> String {
+    "cross-encoder/ms-marco-MiniLM-L-6-v2".to_string()
+}
+
+fn default_candidates() -> usize {
+    50
+}
+
+fn default_top_n() -> usize {
+    5
+}
+
+impl Default for RerankSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: RerankProvider::default(),
+            model: default_model(),
+            base_url: None,
+            api_key: None,
+            candidates: default_candidates(),
+            top_n: default_top_n(),
+        }
+    }
+}
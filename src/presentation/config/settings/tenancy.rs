@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+/// How the tenant of each request is resolved.
+///
+/// Without authentication the tenant is read from `header` (falling back to the default
+/// tenant), which suits deployments behind a trusted gateway. Once any key is configured
+/// (here or, with [`super::AuthSettings::enabled`], in the database) every scoped request
+/// must present `Authorization: Bearer <key>` and the tenant is taken from the key; the
+/// header may then only repeat that same tenant.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TenancySettings {
+    #[serde(default = "default_header")]
+    pub header: String,
+    /// Static API key → tenant id. Static keys hold every scope, which makes them suitable
+    /// for bootstrapping the first admin key.
+    #[serde(default)]
+    pub api_keys: HashMap<String, String>,
+}
+
+fn default_header() -> String {
+    "x-tenant-id".to_string()
+}
+
+impl Default for TenancySettings {
+    fn default() -> Self {
+        Self {
+            header: default_header(),
+            api_keys: HashMap::new(),
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use serde::Deserialize;
+
+/// Delivery of outbound webhooks from the `webhook_deliveries` outbox.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookSettings {
+    /// Run the webhook dispatcher inside the API server process. Events are queued either way.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    #[serde(default = "default_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+    /// Deliveries sent concurrently per poll.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+    /// Attempts per delivery before it is marked failed.
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// Backoff after the first failed attempt; doubles with each further attempt.
+    #[serde(default = "default_retry_base_delay_ms")]
+    pub retry_base_delay_ms: u64,
+    #[serde(default = "default_retry_max_delay_ms")]
+    pub retry_max_delay_ms: u64,
+    /// Allow subscriptions and deliveries to loopback, private and other non-public
+    /// addresses. Only for deployments whose tenants are trusted with the internal network.
+    #[serde(default)]
+    pub allow_private_networks: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_poll_interval_ms() -> u64 {
+    1000
+}
+
+fn default_batch_size() -> usize {
+    20
+}
+
+fn default_timeout_secs() -> u64 {
+    10
+}
+
+fn default_max_attempts() -> u32 {
+    8
+}
+
+fn default_retry_base_delay_ms() -> u64 {
+    10_000
+}
+
+fn default_retry_max_delay_ms() -> u64 {
+    3_600_000
+}
+
+impl Default for WebhookSettings {
+    fn default() -> Self {
+        Self {
+            enabled: default_enabled(),
+            poll_interval_ms: default_poll_interval_ms(),
+            batch_size: default_batch_size(),
+            timeout_secs: default_timeout_secs(),
+            max_attempts: default_max_attempts(),
+            retry_base_delay_ms: default_retry_base_delay_ms(),
+            retry_max_delay_ms: default_retry_max_delay_ms(),
+            allow_private_networks: false,
+        }
+    }
+}
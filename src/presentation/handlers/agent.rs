@@ -10,8 +10,9 @@ use std::time::Duration;
 
 use crate::application::ports::{FileLoader, LlmClient, VectorStore};
 use crate::application::services::AgentChatRequest;
-use crate::domain::ConversationId;
+use crate::domain::{ConversationId, TenantId};
 use crate::infrastructure::observability::CorrelationId;
+use crate::presentation::handlers::conversation_scope::conversation_accessible;
 use crate::presentation::state::AppState;
 
 #[derive(Deserialize)]
@@ -21,12 +22,13 @@ pub struct AgentChatRequestBody {
 }
 
 #[tracing::instrument(
-    skip(state, correlation_id, body),
+    skip(state, correlation_id, tenant_id, body),
     fields(message_len = body.message.len())
 )]
 pub async fn agent_chat_handler<F, L, V>(
     State(state): State<AppState<F, L, V>>,
     Extension(correlation_id): Extension<CorrelationId>,
+    Extension(tenant_id): Extension<TenantId>,
     Json(body): Json<AgentChatRequestBody>,
 ) -> impl IntoResponse
 where
@@ -49,10 +51,24 @@ where
         .and_then(|s| uuid::Uuid::parse_str(s).ok())
         .map(ConversationId::from_uuid);
 
+    if let Some(conv_id) = conversation_id {
+        match conversation_accessible(state.conversation_repository.as_ref(), conv_id, &tenant_id)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => return StatusCode::NOT_FOUND.into_response(),
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to resolve conversation owner");
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        }
+    }
+
     let request = AgentChatRequest {
         conversation_id,
         user_message: body.message,
         correlation_id: Some(correlation_id.0),
+        tenant_id,
     };
 
     match service.chat(request).await {
@@ -0,0 +1,202 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::ports::{FileLoader, LlmClient, VectorStore};
+use crate::application::services::ApiKeyServiceError;
+use crate::domain::{ApiKey, ApiKeyId, ApiScope, Principal, TenantId};
+use crate::presentation::handlers::ingest::ErrorResponse;
+use crate::presentation::state::AppState;
+
+#[derive(Deserialize)]
+pub struct CreateApiKeyRequest {
+    pub name: String,
+    /// Defaults to the caller's tenant.
+    pub tenant_id: Option<String>,
+    pub scopes: Vec<ApiScope>,
+}
+
+#[derive(Deserialize)]
+pub struct ListApiKeysParams {
+    /// Defaults to the caller's tenant.
+    pub tenant_id: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct ApiKeyResponse {
+    pub id: String,
+    pub name: String,
+    pub tenant_id: String,
+    pub scopes: Vec<ApiScope>,
+    pub key_prefix: String,
+    pub created_at: String,
+    pub revoked_at: Option<String>,
+}
+
+impl From<ApiKey> for ApiKeyResponse {
+    fn from(key: ApiKey) -> Self {
+        Self {
+            id: key.id.as_uuid().to_string(),
+            name: key.name,
+            tenant_id: key.tenant_id.to_string(),
+            scopes: key.scopes,
+            key_prefix: key.key_prefix,
+            created_at: key.created_at.to_rfc3339(),
+            revoked_at: key.revoked_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CreatedApiKeyResponse {
+    #[serde(flatten)]
+    pub key: ApiKeyResponse,
+    /// Plaintext secret; returned only once.
+    pub secret: String,
+}
+
+#[derive(Serialize)]
+pub struct ApiKeyListResponse {
+    pub api_keys: Vec<ApiKeyResponse>,
+}
+
+#[tracing::instrument(skip(state, principal, body), fields(principal = %principal))]
+pub async fn create_api_key_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(principal): Extension<Principal>,
+    Json(body): Json<CreateApiKeyRequest>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let tenant_id = match managed_tenant(&principal, body.tenant_id.as_deref()) {
+        Ok(tenant_id) => tenant_id,
+        Err((status, error)) => return (status, Json(ErrorResponse { error })).into_response(),
+    };
+
+    match state
+        .api_key_service
+        .mint(&body.name, tenant_id, body.scopes)
+        .await
+    {
+        Ok((key, secret)) => (
+            StatusCode::CREATED,
+            Json(CreatedApiKeyResponse {
+                key: ApiKeyResponse::from(key),
+                secret,
+            }),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+#[tracing::instrument(skip(state, principal, params), fields(principal = %principal))]
+pub async fn list_api_keys_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(principal): Extension<Principal>,
+    Query(params): Query<ListApiKeysParams>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let tenant_id = match managed_tenant(&principal, params.tenant_id.as_deref()) {
+        Ok(tenant_id) => tenant_id,
+        Err((status, error)) => return (status, Json(ErrorResponse { error })).into_response(),
+    };
+
+    match state.api_key_service.list(Some(&tenant_id)).await {
+        Ok(keys) => (
+            StatusCode::OK,
+            Json(ApiKeyListResponse {
+                api_keys: keys.into_iter().map(ApiKeyResponse::from).collect(),
+            }),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+#[tracing::instrument(skip(state, principal), fields(principal = %principal))]
+pub async fn revoke_api_key_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(principal): Extension<Principal>,
+    Path(api_key_id): Path<String>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let Ok(uuid) = Uuid::parse_str(&api_key_id) else {
+        return bad_request(format!("Invalid API key ID: {}", api_key_id));
+    };
+
+    // Keys of other tenants are reported as missing rather than forbidden, so their ids
+    // cannot be probed.
+    let tenant_id = (!principal.all_tenants).then_some(&principal.tenant_id);
+    match state
+        .api_key_service
+        .revoke(ApiKeyId::from_uuid(uuid), tenant_id)
+        .await
+    {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+/// The tenant whose keys a request manages: the caller's own unless it names another, which
+/// only static keys may.
+fn managed_tenant(
+    principal: &Principal,
+    requested: Option<&str>,
+) -> Result<TenantId, (StatusCode, String)> {
+    let Some(raw) = requested else {
+        return Ok(principal.tenant_id.clone());
+    };
+    let tenant_id = TenantId::parse(raw).ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            format!("Invalid tenant id: {}", raw),
+        )
+    })?;
+    if tenant_id != principal.tenant_id && !principal.all_tenants {
+        return Err((
+            StatusCode::FORBIDDEN,
+            format!(
+                "API keys of tenant {} cannot be managed by this key",
+                tenant_id
+            ),
+        ));
+    }
+    Ok(tenant_id)
+}
+
+fn bad_request(error: String) -> Response {
+    (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response()
+}
+
+fn error_response(error: ApiKeyServiceError) -> Response {
+    let status = match &error {
+        ApiKeyServiceError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+        ApiKeyServiceError::NotFound(_) => StatusCode::NOT_FOUND,
+        ApiKeyServiceError::Repository(_) => {
+            tracing::error!(error = %error, "API key operation failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    (
+        status,
+        Json(ErrorResponse {
+            error: error.to_string(),
+        }),
+    )
+        .into_response()
+}
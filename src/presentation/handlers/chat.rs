@@ -8,11 +8,14 @@ use serde::Serialize;
 use std::convert::Infallible;
 use std::time::Duration;
 
-use crate::application::ports::{ConversationRepository, FileLoader, LlmClient, VectorStore};
+use crate::application::ports::{
+    ConversationRepository, FileLoader, LlmClient, SearchFilter, VectorStore,
+};
 use crate::application::services::AgentChatRequest;
-use crate::domain::{Conversation, ConversationId, Message, MessageRole};
+use crate::domain::{Conversation, ConversationId, Message, MessageRole, TenantId};
 use crate::infrastructure::observability::{CorrelationId, sanitize_prompt};
 use crate::presentation::config::ChatMode;
+use crate::presentation::handlers::conversation_scope::conversation_accessible;
 use crate::presentation::state::AppState;
 
 use super::openai_types::{ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse};
@@ -21,6 +24,7 @@ const AGENT_MODEL_ID: &str = "agent-pipeline";
 
 async fn resolve_conversation_id(
     chat_id: &str,
+    tenant_id: &TenantId,
     repo: &dyn ConversationRepository,
 ) -> Option<ConversationId> {
     let uuid = uuid::Uuid::parse_str(chat_id).ok()?;
@@ -28,13 +32,25 @@ async fn resolve_conversation_id(
 
     // create_conversation uses ON CONFLICT DO NOTHING, so this is safe to call
     // on every request — it's a no-op when the row already exists.
-    let mut conv = Conversation::new(None);
+    let mut conv = Conversation::new(None).with_tenant(tenant_id.clone());
     conv.id = conv_id;
     if let Err(e) = repo.create_conversation(&conv).await {
         tracing::warn!(error = %e, %chat_id, "Failed to ensure conversation exists");
         return None;
     }
-    Some(conv_id)
+
+    // A chat id owned by another tenant is served statelessly rather than leaking history.
+    match conversation_accessible(repo, conv_id, tenant_id).await {
+        Ok(true) => Some(conv_id),
+        Ok(false) => {
+            tracing::warn!(%chat_id, "Chat id belongs to another tenant; not persisting history");
+            None
+        }
+        Err(e) => {
+            tracing::warn!(error = %e, %chat_id, "Failed to resolve conversation owner");
+            None
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -48,6 +64,20 @@ pub struct ChatError {
     pub r#type: String,
 }
 
+fn invalid_filter(message: String) -> axum::response::Response {
+    tracing::warn!(error = %message, "Rejected chat completion filter");
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse {
+            error: ChatError {
+                message,
+                r#type: "invalid_request_error".to_string(),
+            },
+        }),
+    )
+        .into_response()
+}
+
 /// Returns `true` when the request should be handled by `AgentService`.
 ///
 /// Two triggers:
@@ -65,12 +95,13 @@ fn should_use_agent(
 }
 
 #[tracing::instrument(
-    skip(state, correlation_id, request),
+    skip(state, correlation_id, tenant_id, request),
     fields(model = %request.model, streaming = ?request.stream)
 )]
 pub async fn chat_completions_handler<F, L, V>(
     State(state): State<AppState<F, L, V>>,
     Extension(correlation_id): Extension<CorrelationId>,
+    Extension(tenant_id): Extension<TenantId>,
     headers: HeaderMap,
     Json(request): Json<ChatCompletionRequest>,
 ) -> impl IntoResponse
@@ -103,6 +134,12 @@ where
             .into_response();
     }
 
+    let filter = match request.filter.as_ref().map(SearchFilter::from_json) {
+        None => SearchFilter::for_tenant(tenant_id.clone()),
+        Some(Ok(filter)) => filter.with_tenant(tenant_id.clone()),
+        Some(Err(e)) => return invalid_filter(e.to_string()),
+    };
+
     // Resolve the stable conversation ID.
     // Open WebUI sends `chat_id` in the request body; the `X-OpenWebUI-Chat-Id`
     // header is accepted as a fallback for other clients.
@@ -113,7 +150,9 @@ where
             .map(str::to_owned)
     });
     let conversation_id = match raw_chat_id.as_deref() {
-        Some(id) => resolve_conversation_id(id, state.conversation_repository.as_ref()).await,
+        Some(id) => {
+            resolve_conversation_id(id, &tenant_id, state.conversation_repository.as_ref()).await
+        }
         None => None,
     };
 
@@ -125,6 +164,13 @@ where
     );
 
     if use_agent {
+        if !filter.is_empty() {
+            return invalid_filter(
+                "filter is not supported on the agent pipeline; the agent filters through rag_search arguments"
+                    .to_string(),
+            );
+        }
+
         let service = match agent_service {
             Some(s) => s,
             None => {
@@ -145,6 +191,7 @@ where
             conversation_id,
             user_message: user_message.clone(),
             correlation_id: Some(correlation_id.0),
+            tenant_id: tenant_id.clone(),
         };
 
         // Non-streaming: run the agent synchronously and return a single JSON response.
@@ -276,7 +323,7 @@ where
     } else if request.stream == Some(true) {
         match state
             .retrieval_service
-            .query_stream(&user_message, conversation_id)
+            .query_stream(&user_message, conversation_id, &filter)
             .await
         {
             Ok(streaming_response) => {
@@ -365,7 +412,12 @@ where
     } else {
         match state
             .retrieval_service
-            .query(&user_message, conversation_id, Some(correlation_id.0))
+            .query(
+                &user_message,
+                conversation_id,
+                Some(correlation_id.0),
+                &filter,
+            )
             .await
         {
             Ok(response) => {
@@ -0,0 +1,14 @@
+use crate::application::ports::{ConversationRepository, RepositoryError};
+use crate::domain::{ConversationId, TenantId};
+
+/// Whether `tenant_id` may read and append to `conversation_id`.
+///
+/// Unknown conversations are allowed: they are created on first use under the caller's tenant.
+pub(crate) async fn conversation_accessible(
+    repo: &dyn ConversationRepository,
+    conversation_id: ConversationId,
+    tenant_id: &TenantId,
+) -> Result<bool, RepositoryError> {
+    let owner = repo.get_conversation_tenant(conversation_id).await?;
+    Ok(owner.is_none_or(|owner| owner == *tenant_id))
+}
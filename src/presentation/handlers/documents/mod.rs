@@ -0,0 +1,162 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use uuid::Uuid;
+
+use crate::application::ports::{FileLoader, LlmClient, VectorStore};
+use crate::application::services::DocumentServiceError;
+use crate::domain::{DocumentId, TenantId};
+use crate::presentation::handlers::ingest::{ErrorResponse, IngestResponse};
+use crate::presentation::state::AppState;
+
+mod schema;
+mod transcript;
+
+pub use schema::{DocumentListResponse, DocumentResponse, ListDocumentsParams};
+pub use transcript::{correct_transcript_handler, get_transcript_handler};
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 200;
+
+#[tracing::instrument(skip(state, tenant_id, params))]
+pub async fn list_documents_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Query(params): Query<ListDocumentsParams>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = params.offset.unwrap_or(0);
+
+    match state.document_service.list(&tenant_id, limit, offset).await {
+        Ok(records) => (
+            StatusCode::OK,
+            Json(DocumentListResponse {
+                documents: records.into_iter().map(DocumentResponse::from).collect(),
+                limit,
+                offset,
+            }),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+#[tracing::instrument(skip(state, tenant_id))]
+pub async fn get_document_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(document_id): Path<String>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let Some(id) = parse_document_id(&document_id) else {
+        return invalid_document_id(&document_id);
+    };
+
+    match state.document_service.get(&tenant_id, id).await {
+        Ok(record) => (StatusCode::OK, Json(DocumentResponse::from(record))).into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+#[tracing::instrument(skip(state, tenant_id))]
+pub async fn delete_document_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(document_id): Path<String>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let Some(id) = parse_document_id(&document_id) else {
+        return invalid_document_id(&document_id);
+    };
+
+    match state.document_service.delete(&tenant_id, id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+#[tracing::instrument(skip(state, tenant_id))]
+pub async fn reingest_document_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(document_id): Path<String>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let Some(id) = parse_document_id(&document_id) else {
+        return invalid_document_id(&document_id);
+    };
+
+    match state.document_service.reingest(&tenant_id, id).await {
+        Ok(job_id) => (
+            StatusCode::ACCEPTED,
+            Json(IngestResponse {
+                document_id: id.as_uuid().to_string(),
+                job_id: job_id.as_uuid().to_string(),
+                message: "Document re-ingestion started".to_string(),
+                deduplicated: false,
+            }),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+fn parse_document_id(raw: &str) -> Option<DocumentId> {
+    Uuid::parse_str(raw).ok().map(DocumentId::from_uuid)
+}
+
+fn invalid_document_id(raw: &str) -> Response {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(ErrorResponse {
+            error: format!("Invalid document ID: {}", raw),
+        }),
+    )
+        .into_response()
+}
+
+pub(crate) fn error_response(error: DocumentServiceError) -> Response {
+    let status = match &error {
+        DocumentServiceError::NotFound(_) | DocumentServiceError::TranscriptNotFound(_) => {
+            StatusCode::NOT_FOUND
+        }
+        DocumentServiceError::SourceUnavailable(_) => StatusCode::CONFLICT,
+        DocumentServiceError::InvalidSource(_) | DocumentServiceError::InvalidTranscript(_) => {
+            StatusCode::BAD_REQUEST
+        }
+        DocumentServiceError::Repository(_)
+        | DocumentServiceError::VectorStore(_)
+        | DocumentServiceError::Staging(_) => {
+            tracing::error!(error = %error, "Document operation failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    (
+        status,
+        Json(ErrorResponse {
+            error: error.to_string(),
+        }),
+    )
+        .into_response()
+}
@@ -0,0 +1,116 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{DocumentRecord, TranscriptSegment, TranscriptWord};
+
+#[derive(Deserialize)]
+pub struct ListDocumentsParams {
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct DocumentResponse {
+    pub id: String,
+    pub filename: String,
+    pub content_type: String,
+    pub size_bytes: u64,
+    pub content_hash: Option<String>,
+    pub chunk_count: usize,
+    pub source_url: Option<String>,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    pub tags: Vec<String>,
+    pub attributes: BTreeMap<String, String>,
+    pub created_at: String,
+    pub ingested_at: Option<String>,
+}
+
+impl From<DocumentRecord> for DocumentResponse {
+    fn from(record: DocumentRecord) -> Self {
+        Self {
+            id: record.id.as_uuid().to_string(),
+            filename: record.filename,
+            content_type: record.content_type.as_mime().to_string(),
+            size_bytes: record.size_bytes,
+            content_hash: record.content_hash,
+            chunk_count: record.chunk_count,
+            source_url: record.source.source_url,
+            title: record.source.title,
+            author: record.source.author,
+            tags: record.source.tags,
+            attributes: record.source.attributes,
+            created_at: record.created_at.to_rfc3339(),
+            ingested_at: record.ingested_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DocumentListResponse {
+    pub documents: Vec<DocumentResponse>,
+    pub limit: usize,
+    pub offset: usize,
+}
+
+#[derive(Deserialize)]
+pub struct TranscriptParams {
+    /// `json` (default), `srt`, `vtt` or `txt`.
+    pub format: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TranscriptSegmentBody {
+    pub start_time: f32,
+    pub end_time: f32,
+    pub text: String,
+    /// Timed words, when the transcription engine timed them. Ignored on correction.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<TranscriptWordBody>,
+}
+
+impl From<TranscriptSegment> for TranscriptSegmentBody {
+    fn from(segment: TranscriptSegment) -> Self {
+        Self {
+            start_time: segment.start_time,
+            end_time: segment.end_time,
+            text: segment.text,
+            words: segment
+                .words
+                .into_iter()
+                .map(TranscriptWordBody::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct TranscriptWordBody {
+    pub start_time: f32,
+    pub end_time: f32,
+    pub text: String,
+}
+
+impl From<TranscriptWord> for TranscriptWordBody {
+    fn from(word: TranscriptWord) -> Self {
+        Self {
+            start_time: word.start_time,
+            end_time: word.end_time,
+            text: word.text,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct TranscriptResponse {
+    pub document_id: String,
+    /// ISO 639-1 code of the spoken language, when configured or detected.
+    pub language: Option<String>,
+    pub segments: Vec<TranscriptSegmentBody>,
+}
+
+#[derive(Deserialize)]
+pub struct TranscriptCorrectionRequest {
+    pub segments: Vec<TranscriptSegmentBody>,
+}
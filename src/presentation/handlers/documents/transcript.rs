@@ -0,0 +1,146 @@
+use axum::Json;
+use axum::body::Bytes;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::IntoResponse;
+
+use super::schema::{
+    TranscriptCorrectionRequest, TranscriptParams, TranscriptResponse, TranscriptSegmentBody,
+};
+use super::{error_response, invalid_document_id, parse_document_id};
+use crate::application::ports::{FileLoader, LlmClient, VectorStore};
+use crate::domain::{TenantId, TranscriptSegment};
+use crate::presentation::handlers::ingest::{ErrorResponse, IngestResponse};
+use crate::presentation::state::AppState;
+
+#[tracing::instrument(skip(state, tenant_id, params))]
+pub async fn get_transcript_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(document_id): Path<String>,
+    Query(params): Query<TranscriptParams>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let Some(id) = parse_document_id(&document_id) else {
+        return invalid_document_id(&document_id);
+    };
+
+    let transcript = match state.document_service.transcript(&tenant_id, id).await {
+        Ok(transcript) => transcript,
+        Err(e) => return error_response(e),
+    };
+    let segments = transcript.segments;
+
+    let (content_type, body) = match params.format.as_deref().unwrap_or("json") {
+        "json" => {
+            return (
+                StatusCode::OK,
+                Json(TranscriptResponse {
+                    document_id: id.as_uuid().to_string(),
+                    language: transcript.language,
+                    segments: segments
+                        .into_iter()
+                        .map(TranscriptSegmentBody::from)
+                        .collect(),
+                }),
+            )
+                .into_response();
+        }
+        "srt" => (
+            "application/x-subrip; charset=utf-8",
+            TranscriptSegment::to_srt(&segments),
+        ),
+        "vtt" => (
+            "text/vtt; charset=utf-8",
+            TranscriptSegment::to_vtt(&segments),
+        ),
+        "txt" => (
+            "text/plain; charset=utf-8",
+            TranscriptSegment::to_plain_text(&segments),
+        ),
+        other => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: format!("Unsupported transcript format: {other} (json, srt, vtt, txt)"),
+                }),
+            )
+                .into_response();
+        }
+    };
+    (StatusCode::OK, [(header::CONTENT_TYPE, content_type)], body).into_response()
+}
+
+/// Accepts corrected segments as JSON (`{"segments": [...]}`) or as an SRT or WebVTT body.
+#[tracing::instrument(skip(state, tenant_id, headers, body))]
+pub async fn correct_transcript_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(document_id): Path<String>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let Some(id) = parse_document_id(&document_id) else {
+        return invalid_document_id(&document_id);
+    };
+
+    let segments = match parse_transcript_body(&headers, &body) {
+        Ok(segments) => segments,
+        Err(error) => {
+            return (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response();
+        }
+    };
+
+    match state
+        .document_service
+        .correct_transcript(&tenant_id, id, &segments)
+        .await
+    {
+        Ok(job_id) => (
+            StatusCode::ACCEPTED,
+            Json(IngestResponse {
+                document_id: id.as_uuid().to_string(),
+                job_id: job_id.as_uuid().to_string(),
+                message: "Transcript correction started".to_string(),
+                deduplicated: false,
+            }),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+fn parse_transcript_body(
+    headers: &HeaderMap,
+    body: &[u8],
+) -> Result<Vec<TranscriptSegment>, String> {
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(';').next())
+        .map(|v| v.trim().to_ascii_lowercase())
+        .unwrap_or_default();
+
+    if content_type == "application/json" {
+        let request: TranscriptCorrectionRequest =
+            serde_json::from_slice(body).map_err(|e| format!("Invalid transcript JSON: {e}"))?;
+        return Ok(request
+            .segments
+            .into_iter()
+            .map(|s| TranscriptSegment::new(s.text, s.start_time, s.end_time))
+            .collect());
+    }
+
+    let text =
+        std::str::from_utf8(body).map_err(|e| format!("Transcript is not valid UTF-8: {e}"))?;
+    TranscriptSegment::parse_captions(text).map_err(|e| format!("Invalid captions: {e}"))
+}
@@ -1,12 +1,19 @@
+use std::collections::BTreeMap;
+
 use axum::Json;
-use axum::extract::{Multipart, State};
+use axum::extract::multipart::Field;
+use axum::extract::{Extension, Multipart, State};
 use axum::http::StatusCode;
-use axum::response::IntoResponse;
+use axum::response::{IntoResponse, Response};
 use serde::Serialize;
+use tokio::sync::oneshot;
 
 use crate::application::ports::{FileLoader, LlmClient, VectorStore};
-use crate::application::services::IngestionMessage;
-use crate::domain::{ContentType, DocumentId, Job, StoragePath};
+use crate::application::services::{IngestionSubmission, SubmissionOutcome};
+use crate::domain::{
+    ContentHasher, ContentType, Document, DocumentId, SourceMetadata, StoragePath, TenantId,
+};
+use crate::presentation::handlers::documents::error_response;
 use crate::presentation::state::AppState;
 
 #[derive(Serialize)]
@@ -14,6 +21,8 @@ pub struct IngestResponse {
     pub document_id: String,
     pub job_id: String,
     pub message: String,
+    /// True when identical content was already cataloged and no new job was started.
+    pub deduplicated: bool,
 }
 
 #[derive(Serialize)]
@@ -21,8 +30,11 @@ pub struct ErrorResponse {
     pub error: String,
 }
 
+/// Multipart upload of one file, optionally with source metadata fields (`source_url`,
+/// `title`, `author`, `tags`, `attributes`) before or after it.
 pub async fn ingest_handler<F, L, V>(
     State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
     mut multipart: Multipart,
 ) -> impl IntoResponse
 where
@@ -30,30 +42,90 @@ where
     L: LlmClient + 'static,
     V: VectorStore + 'static,
 {
-    let mut field = match multipart.next_field().await {
-        Ok(Some(f)) => f,
-        Ok(None) => {
-            tracing::warn!("Ingest request with no file");
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: "No file uploaded".to_string(),
-                }),
-            )
-                .into_response();
+    let mut source = SourceMetadata::default();
+    let mut upload: Option<StagedUpload> = None;
+
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(f)) => f,
+            Ok(None) => break,
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to read multipart");
+                discard_upload(&state, upload.as_ref()).await;
+                return bad_request(format!("Failed to read multipart: {}", e));
+            }
+        };
+
+        if field.file_name().is_some() {
+            if upload.is_some() {
+                discard_upload(&state, upload.as_ref()).await;
+                return bad_request("Only one file can be uploaded per request".to_string());
+            }
+            match stage_upload(&state, field).await {
+                Ok(staged) => upload = Some(staged),
+                Err(response) => return response,
+            }
+            continue;
         }
-        Err(e) => {
-            tracing::error!(error = %e, "Failed to read multipart");
-            return (
-                StatusCode::BAD_REQUEST,
-                Json(ErrorResponse {
-                    error: format!("Failed to read multipart: {}", e),
-                }),
-            )
-                .into_response();
+
+        let name = field.name().unwrap_or_default().to_string();
+        let value = match field.text().await {
+            Ok(value) => value,
+            Err(e) => {
+                discard_upload(&state, upload.as_ref()).await;
+                return bad_request(format!("Failed to read field '{}': {}", name, e));
+            }
+        };
+        if let Err(error) = apply_source_field(&mut source, &name, value) {
+            discard_upload(&state, upload.as_ref()).await;
+            return bad_request(error);
         }
+    }
+
+    let Some(upload) = upload else {
+        tracing::warn!("Ingest request with no file");
+        return bad_request("No file uploaded".to_string());
     };
 
+    // Uploaded files stay in staging after ingestion so the document can be re-ingested;
+    // they are removed together with the catalog entry.
+    match state
+        .document_service
+        .submit(
+            &tenant_id,
+            upload.document,
+            upload.storage_path.clone(),
+            true,
+            upload.content_hash,
+            source,
+        )
+        .await
+    {
+        Ok(submission) => submission_response(submission),
+        Err(e) => {
+            // Nothing was queued, so the staged file is an orphan.
+            let _ = state.staging_store.delete(&upload.storage_path).await;
+            error_response(e)
+        }
+    }
+}
+
+struct StagedUpload {
+    document: Document,
+    storage_path: StoragePath,
+    content_hash: String,
+}
+
+/// Streams the file part to the staging store without buffering, hashing on the way.
+async fn stage_upload<F, L, V>(
+    state: &AppState<F, L, V>,
+    mut field: Field<'_>,
+) -> Result<StagedUpload, Response>
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
     let filename = field.file_name().unwrap_or("unknown").to_string();
     let content_type_str = field
         .content_type()
@@ -63,10 +135,19 @@ where
     tracing::debug!(filename = %filename, content_type = %content_type_str, "Processing file upload");
 
     let content_type = match ContentType::from_mime(&content_type_str) {
-        Some(ct @ (ContentType::Text | ContentType::Pdf)) => ct,
+        Some(
+            ct @ (ContentType::Text
+            | ContentType::Markdown
+            | ContentType::Html
+            | ContentType::Pdf
+            | ContentType::Docx
+            | ContentType::Pptx
+            | ContentType::Xlsx
+            | ContentType::Subtitles),
+        ) => ct,
         Some(_) => {
             tracing::warn!(content_type = %content_type_str, "Content type not accepted for direct upload; use /ingest-reference instead");
-            return (
+            return Err((
                 StatusCode::UNSUPPORTED_MEDIA_TYPE,
                 Json(ErrorResponse {
                     error: format!(
@@ -75,30 +156,37 @@ where
                     ),
                 }),
             )
-                .into_response();
+                .into_response());
         }
         None => {
             tracing::warn!(content_type = %content_type_str, "Unsupported content type");
-            return (
+            return Err((
                 StatusCode::UNSUPPORTED_MEDIA_TYPE,
                 Json(ErrorResponse {
                     error: format!("Unsupported content type: {}", content_type_str),
                 }),
             )
-                .into_response();
+                .into_response());
         }
     };
 
     let doc_id = DocumentId::new();
     let storage_path = StoragePath::new(&doc_id, &filename);
 
-    // Stream multipart chunks directly to staging store without buffering
+    let (hash_sender, hash_receiver) = oneshot::channel();
     let byte_stream: futures::stream::BoxStream<'_, Result<bytes::Bytes, std::io::Error>> =
         Box::pin(async_stream::stream! {
+            let mut hasher = ContentHasher::new();
             loop {
                 match field.chunk().await {
-                    Ok(Some(bytes)) => yield Ok(bytes),
-                    Ok(None) => break,
+                    Ok(Some(bytes)) => {
+                        hasher.update(&bytes);
+                        yield Ok(bytes);
+                    }
+                    Ok(None) => {
+                        let _ = hash_sender.send(hasher.finish());
+                        break;
+                    }
                     Err(e) => {
                         yield Err(std::io::Error::other(e.to_string()));
                         break;
@@ -115,71 +203,106 @@ where
         Ok(size) => size,
         Err(e) => {
             tracing::error!(error = %e, "Failed to stage uploaded file");
-            return (
+            return Err((
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
                     error: format!("Upload staging failed: {}", e),
                 }),
             )
-                .into_response();
+                .into_response());
         }
     };
 
-    tracing::debug!(bytes = size_bytes, "File staged to storage");
-
-    let document = crate::domain::Document {
-        id: doc_id,
-        filename: filename.clone(),
-        content_type,
-        size_bytes,
-    };
-    let job = Job::new(Some(doc_id), "document_ingestion".to_string());
-    let job_id = job.id;
-
-    if let Err(e) = state.job_repository.create(&job).await {
-        tracing::error!(error = %e, "Failed to create job record");
+    let Ok(content_hash) = hash_receiver.await else {
+        tracing::error!("Upload stream ended without a content hash");
         let _ = state.staging_store.delete(&storage_path).await;
-        return (
+        return Err((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(ErrorResponse {
-                error: format!("Failed to create job: {}", e),
+                error: "Upload staging failed: incomplete upload".to_string(),
             }),
         )
-            .into_response();
-    }
-
-    let msg = IngestionMessage {
-        job_id,
-        document,
-        storage_path: storage_path.clone(),
-        delete_after_processing: true,
+            .into_response());
     };
 
-    if let Err(e) = state.ingestion_sender.send(msg).await {
-        tracing::error!(error = %e, "Failed to enqueue ingestion job");
-        let _ = state.staging_store.delete(&storage_path).await;
-        return (
-            StatusCode::SERVICE_UNAVAILABLE,
-            Json(ErrorResponse {
-                error: "Ingestion queue full or worker unavailable".to_string(),
-            }),
-        )
-            .into_response();
+    tracing::debug!(bytes = size_bytes, "File staged to storage");
+
+    Ok(StagedUpload {
+        document: Document {
+            id: doc_id,
+            filename,
+            content_type,
+            size_bytes,
+        },
+        storage_path,
+        content_hash,
+    })
+}
+
+/// Fills `source` from one text field; `tags` may repeat and holds comma-separated labels,
+/// `attributes` is a JSON object of strings.
+fn apply_source_field(
+    source: &mut SourceMetadata,
+    name: &str,
+    value: String,
+) -> Result<(), String> {
+    match name {
+        "source_url" => source.source_url = Some(value),
+        "title" => source.title = Some(value),
+        "author" => source.author = Some(value),
+        "tags" => source
+            .tags
+            .extend(value.split(',').map(|tag| tag.to_string())),
+        "attributes" => {
+            let attributes: BTreeMap<String, String> = serde_json::from_str(&value)
+                .map_err(|e| format!("attributes must be a JSON object of strings: {}", e))?;
+            source.attributes.extend(attributes);
+        }
+        other => return Err(format!("Unknown form field: {}", other)),
     }
+    Ok(())
+}
+
+async fn discard_upload<F, L, V>(state: &AppState<F, L, V>, upload: Option<&StagedUpload>)
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    if let Some(upload) = upload {
+        let _ = state.staging_store.delete(&upload.storage_path).await;
+    }
+}
+
+fn bad_request(error: String) -> Response {
+    (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response()
+}
+
+/// 200 with the existing ids for duplicate content, 202 when an ingestion job was queued.
+pub(crate) fn submission_response(submission: IngestionSubmission) -> Response {
+    let (status, message) = match submission.outcome {
+        SubmissionOutcome::Created => (StatusCode::ACCEPTED, "Document ingestion started"),
+        SubmissionOutcome::Replaced => (
+            StatusCode::ACCEPTED,
+            "Document content changed; re-ingestion started",
+        ),
+        SubmissionOutcome::Duplicate => (StatusCode::OK, "Document already ingested"),
+    };
 
     tracing::info!(
-        job_id = %job_id.as_uuid(),
-        document_id = %doc_id.as_uuid(),
-        filename = %filename,
-        "Document ingestion job enqueued"
+        job_id = %submission.job_id.as_uuid(),
+        document_id = %submission.document_id.as_uuid(),
+        outcome = ?submission.outcome,
+        "Document submission handled"
     );
 
     (
-        StatusCode::ACCEPTED,
+        status,
         Json(IngestResponse {
-            document_id: doc_id.as_uuid().to_string(),
-            job_id: job_id.as_uuid().to_string(),
-            message: "Document ingestion started".to_string(),
+            document_id: submission.document_id.as_uuid().to_string(),
+            job_id: submission.job_id.as_uuid().to_string(),
+            message: message.to_string(),
+            deduplicated: submission.outcome == SubmissionOutcome::Duplicate,
         }),
     )
         .into_response()
@@ -1,13 +1,18 @@
+use std::collections::BTreeMap;
+
 use axum::Json;
-use axum::extract::State;
+use axum::extract::{Extension, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use serde::Deserialize;
 
 use crate::application::ports::{FileLoader, LlmClient, StagingStoreError, VectorStore};
-use crate::application::services::IngestionMessage;
-use crate::domain::{ContentType, Document, DocumentId, Job, StoragePath};
-use crate::presentation::handlers::ingest::{ErrorResponse, IngestResponse};
+use crate::domain::{
+    ContentType, Document, DocumentId, DocumentRecord, SourceMetadata, StoragePath, TenantId,
+    TranscriptSegment,
+};
+use crate::presentation::handlers::documents::error_response;
+use crate::presentation::handlers::ingest::{ErrorResponse, submission_response};
 use crate::presentation::state::AppState;
 
 #[derive(Deserialize)]
@@ -15,10 +20,24 @@ pub struct IngestReferenceRequest {
     pub storage_path: String,
     pub filename: String,
     pub content_type: String,
+    /// Original location, e.g. the YouTube page of a downloaded video.
+    pub source_url: Option<String>,
+    /// Display title; defaults to the filename without its extension.
+    pub title: Option<String>,
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Arbitrary key/value tags returned with every source of the document.
+    #[serde(default)]
+    pub attributes: BTreeMap<String, String>,
+    /// Staged SRT or WebVTT file for an audio or video reference; its cues replace
+    /// transcription.
+    pub captions_storage_path: Option<String>,
 }
 
 pub async fn ingest_reference_handler<F, L, V>(
     State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
     Json(body): Json<IngestReferenceRequest>,
 ) -> impl IntoResponse
 where
@@ -40,6 +59,20 @@ where
         }
     };
 
+    let captions_path = body
+        .captions_storage_path
+        .as_deref()
+        .map(StoragePath::from_raw);
+    if captions_path.is_some() && !matches!(content_type, ContentType::Audio | ContentType::Video) {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "captions_storage_path is only accepted for audio and video".to_string(),
+            }),
+        )
+            .into_response();
+    }
+
     let storage_path = StoragePath::from_raw(&body.storage_path);
 
     let size_bytes = match state.staging_store.head(&storage_path).await {
@@ -66,60 +99,84 @@ where
         }
     };
 
-    let doc_id = DocumentId::new();
+    let content_hash = match state.staging_store.fetch(&storage_path).await {
+        Ok(data) => DocumentRecord::hash_content(&data),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to read referenced file for hashing");
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Storage error: {}", e),
+                }),
+            )
+                .into_response();
+        }
+    };
+
+    let content_hash = match &captions_path {
+        Some(path) => match state.staging_store.fetch(path).await {
+            Ok(captions) => {
+                if let Err(error) = std::str::from_utf8(&captions)
+                    .map_err(|e| format!("captions are not UTF-8: {e}"))
+                    .and_then(TranscriptSegment::parse_captions)
+                {
+                    return (StatusCode::BAD_REQUEST, Json(ErrorResponse { error }))
+                        .into_response();
+                }
+                DocumentRecord::hash_captioned(&content_hash, &captions)
+            }
+            Err(StagingStoreError::NotFound(_)) => {
+                tracing::warn!(path = %path, "Referenced captions not found in storage");
+                return (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse {
+                        error: format!("File not found in storage: {}", path),
+                    }),
+                )
+                    .into_response();
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to read referenced captions");
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: format!("Storage error: {}", e),
+                    }),
+                )
+                    .into_response();
+            }
+        },
+        None => content_hash,
+    };
+
     let document = Document {
-        id: doc_id,
-        filename: body.filename.clone(),
+        id: DocumentId::new(),
+        filename: body.filename,
         content_type,
         size_bytes,
     };
-    let job = Job::new(Some(doc_id), "document_ingestion".to_string());
-    let job_id = job.id;
-
-    if let Err(e) = state.job_repository.create(&job).await {
-        tracing::error!(error = %e, "Failed to create job record for reference ingestion");
-        return (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse {
-                error: format!("Failed to create job: {}", e),
-            }),
-        )
-            .into_response();
-    }
-
-    let msg = IngestionMessage {
-        job_id,
-        document,
-        storage_path,
-        delete_after_processing: false,
+    let source = SourceMetadata {
+        source_url: body.source_url,
+        title: body.title,
+        author: body.author,
+        tags: body.tags,
+        attributes: body.attributes,
     };
 
-    if let Err(e) = state.ingestion_sender.send(msg).await {
-        tracing::error!(error = %e, "Failed to enqueue reference ingestion job");
-        return (
-            StatusCode::SERVICE_UNAVAILABLE,
-            Json(ErrorResponse {
-                error: "Ingestion queue full or worker unavailable".to_string(),
-            }),
+    match state
+        .document_service
+        .submit_with_captions(
+            &tenant_id,
+            document,
+            storage_path,
+            false,
+            content_hash,
+            source,
+            captions_path,
         )
-            .into_response();
+        .await
+    {
+        Ok(submission) => submission_response(submission),
+        Err(e) => error_response(e),
     }
-
-    tracing::info!(
-        job_id = %job_id.as_uuid(),
-        document_id = %doc_id.as_uuid(),
-        filename = %body.filename,
-        storage_path = %body.storage_path,
-        "Reference ingestion job enqueued"
-    );
-
-    (
-        StatusCode::ACCEPTED,
-        Json(IngestResponse {
-            document_id: doc_id.as_uuid().to_string(),
-            job_id: job_id.as_uuid().to_string(),
-            message: "Document ingestion started".to_string(),
-        }),
-    )
-        .into_response()
 }
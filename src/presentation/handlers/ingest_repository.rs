@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+
+use axum::Json;
+use axum::extract::{Extension, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::{FileLoader, LlmClient, VectorStore};
+use crate::application::services::RepositoryIngestionError;
+use crate::domain::{RepositorySyncRequest, SourceMetadata, TenantId};
+use crate::presentation::handlers::ingest::ErrorResponse;
+use crate::presentation::state::AppState;
+
+#[derive(Deserialize)]
+pub struct IngestRepositoryRequest {
+    /// Local git checkout on the server, under one of the configured roots.
+    pub path: String,
+    /// Prefix of the document filenames; defaults to the checkout's directory name. Syncing
+    /// the same name again only touches files that changed.
+    pub name: Option<String>,
+    pub title: Option<String>,
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub attributes: BTreeMap<String, String>,
+}
+
+#[derive(Serialize)]
+pub struct IngestRepositoryResponse {
+    pub job_id: String,
+    pub message: String,
+}
+
+/// Queues a repository sync; each new or changed file becomes a document with its own
+/// ingestion job.
+pub async fn ingest_repository_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Json(body): Json<IngestRepositoryRequest>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let request = RepositorySyncRequest {
+        path: body.path,
+        name: body.name.unwrap_or_default(),
+        principal: None,
+        source: SourceMetadata {
+            source_url: None,
+            title: body.title,
+            author: body.author,
+            tags: body.tags,
+            attributes: body.attributes,
+        },
+    };
+
+    match state
+        .repository_ingestion_service
+        .submit(&tenant_id, request)
+        .await
+    {
+        Ok(job_id) => (
+            StatusCode::ACCEPTED,
+            Json(IngestRepositoryResponse {
+                job_id: job_id.as_uuid().to_string(),
+                message: "Repository sync started".to_string(),
+            }),
+        )
+            .into_response(),
+        Err(RepositoryIngestionError::InvalidRequest(error)) => {
+            (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response()
+        }
+        Err(RepositoryIngestionError::Conflict(error)) => {
+            (StatusCode::CONFLICT, Json(ErrorResponse { error })).into_response()
+        }
+        Err(e @ (RepositoryIngestionError::Forbidden(_) | RepositoryIngestionError::Disabled)) => (
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to queue repository sync");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+                .into_response()
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+
+use axum::Json;
+use axum::extract::{Extension, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::{FileLoader, LlmClient, VectorStore};
+use crate::application::services::UrlIngestionError;
+use crate::domain::{CrawlRequest, SourceMetadata, TenantId};
+use crate::presentation::handlers::ingest::ErrorResponse;
+use crate::presentation::state::AppState;
+
+#[derive(Deserialize)]
+pub struct IngestUrlRequest {
+    /// Page to ingest, or the sitemap to read when `sitemap` is set.
+    pub url: String,
+    /// Treat `url` as an XML sitemap (or sitemap index) and ingest every page it lists.
+    #[serde(default)]
+    pub sitemap: bool,
+    /// Link hops followed from the start pages; `0` ingests only the pages themselves.
+    #[serde(default)]
+    pub max_depth: u32,
+    /// Pages ingested at most; defaults to the configured ceiling.
+    pub max_pages: Option<u32>,
+    /// Hosts (and their subdomains) links may lead to; defaults to the host of `url`.
+    #[serde(default)]
+    pub allowed_domains: Vec<String>,
+    /// Display title for every page; defaults to each page's `<title>`.
+    pub title: Option<String>,
+    pub author: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub attributes: BTreeMap<String, String>,
+}
+
+#[derive(Serialize)]
+pub struct IngestUrlResponse {
+    pub job_id: String,
+    pub message: String,
+}
+
+/// Queues a crawl job; each page it ingests becomes a document with its own ingestion job.
+pub async fn ingest_url_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Json(body): Json<IngestUrlRequest>,
+) -> impl IntoResponse
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let service = &state.url_ingestion_service;
+    let request = CrawlRequest {
+        url: body.url,
+        sitemap: body.sitemap,
+        max_depth: body.max_depth,
+        max_pages: body.max_pages.unwrap_or(service.options().max_pages),
+        allowed_domains: body.allowed_domains,
+        principal: None,
+        source: SourceMetadata {
+            source_url: None,
+            title: body.title,
+            author: body.author,
+            tags: body.tags,
+            attributes: body.attributes,
+        },
+    };
+
+    match service.submit(&tenant_id, request).await {
+        Ok(job_id) => (
+            StatusCode::ACCEPTED,
+            Json(IngestUrlResponse {
+                job_id: job_id.as_uuid().to_string(),
+                message: "URL ingestion started".to_string(),
+            }),
+        )
+            .into_response(),
+        Err(UrlIngestionError::InvalidRequest(error)) => {
+            (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response()
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to queue URL ingestion");
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+                .into_response()
+        }
+    }
+}
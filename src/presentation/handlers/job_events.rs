@@ -0,0 +1,121 @@
+use std::convert::Infallible;
+use std::time::Duration;
+
+use axum::extract::{Extension, Query, State};
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast::error::RecvError;
+use uuid::Uuid;
+
+use crate::application::ports::{FileLoader, LlmClient, VectorStore};
+use crate::domain::{JobEvent, JobEventKind, JobId, TenantId};
+use crate::presentation::handlers::job_status::JobProgressResponse;
+use crate::presentation::state::AppState;
+
+#[derive(Deserialize)]
+pub struct JobEventsParams {
+    /// Follow a single job instead of every job of the tenant.
+    pub job_id: Option<String>,
+}
+
+#[derive(Serialize)]
+struct StatusEventData {
+    job_id: String,
+    status: &'static str,
+    error_message: Option<String>,
+    occurred_at: String,
+}
+
+#[derive(Serialize)]
+struct ProgressEventData {
+    job_id: String,
+    progress: JobProgressResponse,
+    occurred_at: String,
+}
+
+fn to_sse_event(event: JobEvent) -> Event {
+    let job_id = event.job_id.as_uuid().to_string();
+    let occurred_at = event.occurred_at.to_rfc3339();
+    let (name, data) = match event.kind {
+        JobEventKind::StatusChanged {
+            status,
+            error_message,
+        } => (
+            "status",
+            serde_json::to_string(&StatusEventData {
+                job_id,
+                status: status.as_str(),
+                error_message,
+                occurred_at,
+            }),
+        ),
+        JobEventKind::Progress(progress) => (
+            "progress",
+            serde_json::to_string(&ProgressEventData {
+                job_id,
+                progress: progress.into(),
+                occurred_at,
+            }),
+        ),
+    };
+    Event::default().event(name).data(data.unwrap_or_default())
+}
+
+/// Streams `status` and `progress` events for the caller's jobs as workers emit them. Only
+/// changes after the client connects are sent; fetch the job first for its current state.
+#[tracing::instrument(skip(state, tenant_id, params))]
+pub async fn job_events_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Query(params): Query<JobEventsParams>,
+) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let job_filter = match params.job_id.as_deref().map(Uuid::parse_str) {
+        None => None,
+        Some(Ok(uuid)) => Some(JobId::from_uuid(uuid)),
+        Some(Err(_)) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("Invalid job ID: {}", params.job_id.unwrap_or_default()),
+            )
+                .into_response();
+        }
+    };
+
+    let keep_alive_secs = state.settings.llm.sse_keep_alive_seconds;
+    let mut events = state.job_events.subscribe();
+
+    let sse_stream = async_stream::stream! {
+        loop {
+            match events.recv().await {
+                Ok(event) => {
+                    if event.tenant_id != tenant_id
+                        || job_filter.is_some_and(|id| id != event.job_id)
+                    {
+                        continue;
+                    }
+                    yield Ok::<_, Infallible>(to_sse_event(event));
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Job event subscriber lagged; events dropped");
+                    yield Ok(Event::default().event("lagged").data(skipped.to_string()));
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    };
+
+    Sse::new(sse_stream)
+        .keep_alive(
+            KeepAlive::new()
+                .interval(Duration::from_secs(keep_alive_secs))
+                .text("keep-alive"),
+        )
+        .into_response()
+}
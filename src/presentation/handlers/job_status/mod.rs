@@ -0,0 +1,225 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::application::ports::{FileLoader, JOB_CANCELLED, JobFilter, LlmClient, VectorStore};
+use crate::domain::{Job, JobEvent, JobId, JobStatus, TenantId};
+use crate::presentation::state::AppState;
+
+mod schema;
+
+pub use schema::{JobListResponse, JobProgressResponse, JobStatusResponse, ListJobsParams};
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 200;
+
+#[derive(Serialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+fn error_response(status: StatusCode, error: String) -> Response {
+    (status, Json(ErrorResponse { error })).into_response()
+}
+
+fn repository_failure(e: impl std::fmt::Display) -> Response {
+    tracing::error!(error = %e, "Job repository request failed");
+    error_response(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        format!("Failed to fetch job: {}", e),
+    )
+}
+
+/// Loads a job of the caller's tenant. Jobs owned by another tenant are indistinguishable
+/// from missing ones.
+async fn find_job<F, L, V>(
+    state: &AppState<F, L, V>,
+    tenant_id: &TenantId,
+    job_id: &str,
+) -> Result<Job, Response>
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let uuid = Uuid::parse_str(job_id).map_err(|_| {
+        error_response(
+            StatusCode::BAD_REQUEST,
+            format!("Invalid job ID: {}", job_id),
+        )
+    })?;
+
+    match state.job_repository.get_by_id(JobId::from_uuid(uuid)).await {
+        Ok(Some(job)) if job.tenant_id == *tenant_id => Ok(job),
+        Ok(_) => Err(error_response(
+            StatusCode::NOT_FOUND,
+            format!("Job not found: {}", job_id),
+        )),
+        Err(e) => Err(repository_failure(e)),
+    }
+}
+
+/// Reads the job back after a state change so the response reflects the stored row.
+async fn reload<F, L, V>(state: &AppState<F, L, V>, id: JobId, status: StatusCode) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    match state.job_repository.get_by_id(id).await {
+        Ok(Some(job)) => (status, Json(JobStatusResponse::from(job))).into_response(),
+        Ok(None) => error_response(
+            StatusCode::NOT_FOUND,
+            format!("Job not found: {}", id.as_uuid()),
+        ),
+        Err(e) => repository_failure(e),
+    }
+}
+
+#[tracing::instrument(skip(state, tenant_id, params))]
+pub async fn list_jobs_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Query(params): Query<ListJobsParams>,
+) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let status = match params.status.as_deref().map(str::parse::<JobStatus>) {
+        None => None,
+        Some(Ok(status)) => Some(status),
+        Some(Err(e)) => return error_response(StatusCode::BAD_REQUEST, e),
+    };
+    let filter = JobFilter {
+        status,
+        job_type: params.job_type,
+        created_after: params.created_after,
+        created_before: params.created_before,
+        ..JobFilter::for_tenant(tenant_id)
+    };
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = params.offset.unwrap_or(0);
+
+    match state.job_repository.list(&filter, limit, offset).await {
+        Ok(jobs) => (
+            StatusCode::OK,
+            Json(JobListResponse {
+                jobs: jobs.into_iter().map(JobStatusResponse::from).collect(),
+                limit,
+                offset,
+            }),
+        )
+            .into_response(),
+        Err(e) => repository_failure(e),
+    }
+}
+
+#[tracing::instrument(skip(state, tenant_id))]
+pub async fn job_status_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(job_id): Path<String>,
+) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    match find_job(&state, &tenant_id, &job_id).await {
+        Ok(job) => (StatusCode::OK, Json(JobStatusResponse::from(job))).into_response(),
+        Err(response) => response,
+    }
+}
+
+/// Cancels a queued job immediately; an in-flight job is flagged and stopped by its worker
+/// at the next checkpoint, so its status may still be in flight in the response.
+#[tracing::instrument(skip(state, tenant_id))]
+pub async fn cancel_job_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(job_id): Path<String>,
+) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let job = match find_job(&state, &tenant_id, &job_id).await {
+        Ok(job) => job,
+        Err(response) => return response,
+    };
+
+    match state.job_repository.request_cancel(job.id).await {
+        Ok(Some(status)) => {
+            // In-flight jobs announce CANCELLED once their worker has stopped them.
+            if status == JobStatus::Cancelled {
+                state
+                    .job_events
+                    .publish(&JobEvent::status_changed(
+                        job.id,
+                        tenant_id,
+                        status,
+                        Some(JOB_CANCELLED.to_string()),
+                    ))
+                    .await;
+            }
+            reload(&state, job.id, StatusCode::ACCEPTED).await
+        }
+        Ok(None) => error_response(
+            StatusCode::CONFLICT,
+            format!("Job {} has already finished", job_id),
+        ),
+        Err(e) => repository_failure(e),
+    }
+}
+
+/// Re-queues a failed or cancelled job with a fresh attempt budget.
+#[tracing::instrument(skip(state, tenant_id))]
+pub async fn retry_job_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(job_id): Path<String>,
+) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let job = match find_job(&state, &tenant_id, &job_id).await {
+        Ok(job) => job,
+        Err(response) => return response,
+    };
+
+    match state.job_repository.retry(job.id).await {
+        Ok(true) => {
+            tracing::info!(job_id = %job.id.as_uuid(), "Job re-queued on request");
+            state.ingestion_wakeup.notify_one();
+            state
+                .job_events
+                .publish(&JobEvent::status_changed(
+                    job.id,
+                    tenant_id,
+                    JobStatus::Queued,
+                    None,
+                ))
+                .await;
+            reload(&state, job.id, StatusCode::ACCEPTED).await
+        }
+        Ok(false) => error_response(
+            StatusCode::CONFLICT,
+            format!(
+                "Job {} is {} and cannot be retried; only failed or cancelled jobs can",
+                job_id, job.status
+            ),
+        ),
+        Err(e) => repository_failure(e),
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{FetchFailure, Job, JobProgress};
+
+#[derive(Deserialize)]
+pub struct ListJobsParams {
+    pub status: Option<String>,
+    pub job_type: Option<String>,
+    /// RFC 3339; inclusive.
+    pub created_after: Option<DateTime<Utc>>,
+    /// RFC 3339; inclusive.
+    pub created_before: Option<DateTime<Utc>>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct JobStatusResponse {
+    pub id: String,
+    pub status: String,
+    pub document_id: Option<String>,
+    pub job_type: String,
+    pub error_message: Option<String>,
+    pub attempts: u32,
+    /// When a queued retry becomes claimable.
+    pub next_attempt_at: Option<String>,
+    pub cancel_requested: bool,
+    pub progress: JobProgressResponse,
+    /// URLs a web crawl, or files a repository sync, could not ingest.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fetch_failures: Vec<FetchFailureResponse>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Serialize)]
+pub struct JobProgressResponse {
+    pub pages_processed: u32,
+    pub segments_transcribed: u32,
+    pub chunks_total: u32,
+    pub chunks_embedded: u32,
+    pub urls_ingested: u32,
+    pub files_ingested: u32,
+    pub files_unchanged: u32,
+    pub files_removed: u32,
+}
+
+#[derive(Serialize)]
+pub struct FetchFailureResponse {
+    pub url: String,
+    pub error: String,
+}
+
+impl From<FetchFailure> for FetchFailureResponse {
+    fn from(failure: FetchFailure) -> Self {
+        Self {
+            url: failure.url,
+            error: failure.error,
+        }
+    }
+}
+
+impl From<JobProgress> for JobProgressResponse {
+    fn from(progress: JobProgress) -> Self {
+        Self {
+            pages_processed: progress.pages_processed,
+            segments_transcribed: progress.segments_transcribed,
+            chunks_total: progress.chunks_total,
+            chunks_embedded: progress.chunks_embedded,
+            urls_ingested: progress.urls_ingested,
+            files_ingested: progress.files_ingested,
+            files_unchanged: progress.files_unchanged,
+            files_removed: progress.files_removed,
+        }
+    }
+}
+
+impl From<Job> for JobStatusResponse {
+    fn from(job: Job) -> Self {
+        Self {
+            id: job.id.as_uuid().to_string(),
+            status: job.status.as_str().to_string(),
+            document_id: job.document_id.map(|id| id.as_uuid().to_string()),
+            job_type: job.job_type,
+            error_message: job.error_message,
+            attempts: job.attempts,
+            next_attempt_at: job.next_attempt_at.map(|at| at.to_rfc3339()),
+            cancel_requested: job.cancel_requested,
+            progress: job.progress.into(),
+            fetch_failures: job.fetch_failures.into_iter().map(Into::into).collect(),
+            created_at: job.created_at.to_rfc3339(),
+            updated_at: job.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct JobListResponse {
+    pub jobs: Vec<JobStatusResponse>,
+    pub limit: usize,
+    pub offset: usize,
+}
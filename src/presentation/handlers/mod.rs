@@ -1,18 +1,39 @@
 mod agent;
+mod api_keys;
 mod chat;
+mod conversation_scope;
+mod documents;
 mod health;
 mod ingest;
 mod ingest_reference;
+mod ingest_repository;
+mod ingest_url;
+mod job_events;
 mod job_status;
 mod models;
 pub mod openai_types;
 mod query;
+mod webhooks;
 
 pub use agent::agent_chat_handler;
+pub use api_keys::{create_api_key_handler, list_api_keys_handler, revoke_api_key_handler};
 pub use chat::chat_completions_handler;
+pub use documents::{
+    correct_transcript_handler, delete_document_handler, get_document_handler,
+    get_transcript_handler, list_documents_handler, reingest_document_handler,
+};
 pub use health::health_handler;
 pub use ingest::ingest_handler;
 pub use ingest_reference::ingest_reference_handler;
-pub use job_status::job_status_handler;
+pub use ingest_repository::ingest_repository_handler;
+pub use ingest_url::ingest_url_handler;
+pub use job_events::job_events_handler;
+pub use job_status::{
+    cancel_job_handler, job_status_handler, list_jobs_handler, retry_job_handler,
+};
 pub use models::models_handler;
 pub use query::query_handler;
+pub use webhooks::{
+    create_webhook_handler, delete_webhook_handler, list_webhook_deliveries_handler,
+    list_webhooks_handler,
+};
@@ -12,6 +12,10 @@ pub struct ChatCompletionRequest {
     pub stream: Option<bool>,
     #[serde(default)]
     pub chat_id: Option<String>,
+    /// Sandakan extension: metadata filter for retrieval (`SearchFilter::from_json` shape).
+    /// Not supported on the agent pipeline, which filters via `rag_search` arguments.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub filter: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
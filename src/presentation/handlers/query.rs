@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use axum::Json;
 use axum::extract::{Extension, State};
 use axum::http::StatusCode;
@@ -5,15 +7,19 @@ use axum::response::IntoResponse;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::application::ports::{FileLoader, LlmClient, VectorStore};
-use crate::domain::ConversationId;
+use crate::application::ports::{FileLoader, LlmClient, SearchFilter, VectorStore};
+use crate::domain::{CodeSpan, ConversationId, TenantId};
 use crate::infrastructure::observability::{CorrelationId, sanitize_prompt};
+use crate::presentation::handlers::conversation_scope::conversation_accessible;
 use crate::presentation::state::AppState;
 
 #[derive(Deserialize)]
 pub struct QueryRequest {
     pub question: String,
     pub conversation_id: Option<String>,
+    /// Optional metadata filter; see `SearchFilter::from_json` for the accepted fields.
+    #[serde(default)]
+    pub filter: Option<serde_json::Value>,
 }
 
 #[derive(Serialize)]
@@ -26,6 +32,9 @@ pub struct QueryResponse {
 pub struct SourceChunk {
     pub text: String,
     pub page: Option<u32>,
+    /// Last page of the passage; differs from `page` when it spans a page break.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_page: Option<u32>,
     pub score: f32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
@@ -35,10 +44,48 @@ pub struct SourceChunk {
     pub source_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Key/value tags supplied when the document was ingested.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub attributes: BTreeMap<String, String>,
+    /// Targets of the links in the passage, for markdown and HTML sources.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<String>,
     /// Start time of the chunk within the media file in seconds.
     /// Present only for audio/video sources.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub start_time: Option<f32>,
+    /// Source-file location; present only for passages from ingested repositories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<CodeSpanResponse>,
+    /// Cross-encoder relevance score; present only when reranking is enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rerank_score: Option<f32>,
+}
+
+#[derive(Serialize)]
+pub struct CodeSpanResponse {
+    pub path: String,
+    pub language: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    pub start_line: u32,
+    pub end_line: u32,
+}
+
+impl From<CodeSpan> for CodeSpanResponse {
+    fn from(code: CodeSpan) -> Self {
+        Self {
+            path: code.path,
+            language: code.language.as_str().to_string(),
+            symbol: code.symbol,
+            start_line: code.start_line,
+            end_line: code.end_line,
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -46,10 +93,11 @@ pub struct ErrorResponse {
     pub error: String,
 }
 
-#[tracing::instrument(skip(state, correlation_id, request))]
+#[tracing::instrument(skip(state, correlation_id, tenant_id, request))]
 pub async fn query_handler<F, L, V>(
     State(state): State<AppState<F, L, V>>,
     Extension(correlation_id): Extension<CorrelationId>,
+    Extension(tenant_id): Extension<TenantId>,
     Json(request): Json<QueryRequest>,
 ) -> impl IntoResponse
 where
@@ -59,14 +107,61 @@ where
 {
     tracing::debug!(question = %sanitize_prompt(&request.question), "Processing query");
 
+    let filter = match request.filter.as_ref().map(SearchFilter::from_json) {
+        None => SearchFilter::for_tenant(tenant_id.clone()),
+        Some(Ok(filter)) => filter.with_tenant(tenant_id.clone()),
+        Some(Err(e)) => {
+            tracing::warn!(error = %e, "Rejected query filter");
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: e.to_string(),
+                }),
+            )
+                .into_response();
+        }
+    };
+
     let conversation_id = request
         .conversation_id
         .and_then(|id| Uuid::parse_str(&id).ok())
         .map(ConversationId::from_uuid);
 
+    if let Some(conv_id) = conversation_id {
+        match conversation_accessible(state.conversation_repository.as_ref(), conv_id, &tenant_id)
+            .await
+        {
+            Ok(true) => {}
+            Ok(false) => {
+                return (
+                    StatusCode::NOT_FOUND,
+                    Json(ErrorResponse {
+                        error: format!("Conversation not found: {}", conv_id.as_uuid()),
+                    }),
+                )
+                    .into_response();
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to resolve conversation owner");
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        error: format!("Query failed: {}", e),
+                    }),
+                )
+                    .into_response();
+            }
+        }
+    }
+
     match state
         .retrieval_service
-        .query(&request.question, conversation_id, Some(correlation_id.0))
+        .query(
+            &request.question,
+            conversation_id,
+            Some(correlation_id.0),
+            &filter,
+        )
         .await
     {
         Ok(response) => {
@@ -89,11 +184,18 @@ where
                     SourceChunk {
                         text: s.text,
                         page: s.page,
+                        end_page: s.end_page,
                         score: s.score,
                         title: s.title,
                         source_url: timestamped,
                         content_type: s.content_type,
+                        author: s.author,
+                        tags: s.tags,
+                        attributes: s.attributes,
+                        links: s.links,
                         start_time,
+                        code: s.code.map(Into::into),
+                        rerank_score: s.rerank_score,
                     }
                 })
                 .collect();
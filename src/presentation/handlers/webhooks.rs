@@ -0,0 +1,241 @@
+use axum::Json;
+use axum::extract::{Extension, Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::ports::{FileLoader, LlmClient, VectorStore};
+use crate::application::services::WebhookServiceError;
+use crate::domain::{
+    TenantId, WebhookDelivery, WebhookDeliveryStatus, WebhookEventType, WebhookSubscription,
+    WebhookSubscriptionId,
+};
+use crate::presentation::handlers::ingest::ErrorResponse;
+use crate::presentation::state::AppState;
+
+const DEFAULT_DELIVERY_LIMIT: usize = 50;
+const MAX_DELIVERY_LIMIT: usize = 200;
+
+#[derive(Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub event_types: Vec<WebhookEventType>,
+}
+
+#[derive(Deserialize)]
+pub struct ListDeliveriesParams {
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct WebhookResponse {
+    pub id: String,
+    pub url: String,
+    pub event_types: Vec<WebhookEventType>,
+    pub created_at: String,
+}
+
+impl From<WebhookSubscription> for WebhookResponse {
+    fn from(subscription: WebhookSubscription) -> Self {
+        Self {
+            id: subscription.id.as_uuid().to_string(),
+            url: subscription.url,
+            event_types: subscription.event_types,
+            created_at: subscription.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CreatedWebhookResponse {
+    #[serde(flatten)]
+    pub webhook: WebhookResponse,
+    /// HMAC-SHA256 signing secret; returned only once.
+    pub secret: String,
+}
+
+#[derive(Serialize)]
+pub struct WebhookListResponse {
+    pub webhooks: Vec<WebhookResponse>,
+}
+
+#[derive(Serialize)]
+pub struct WebhookDeliveryResponse {
+    pub id: String,
+    pub event_id: String,
+    pub event_type: WebhookEventType,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: u32,
+    /// When a pending delivery is attempted next.
+    pub next_attempt_at: Option<String>,
+    pub response_status: Option<u16>,
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub delivered_at: Option<String>,
+}
+
+impl From<WebhookDelivery> for WebhookDeliveryResponse {
+    fn from(delivery: WebhookDelivery) -> Self {
+        Self {
+            id: delivery.id.to_string(),
+            event_id: delivery.event_id.to_string(),
+            event_type: delivery.event_type,
+            status: delivery.status,
+            attempts: delivery.attempts,
+            next_attempt_at: (delivery.status == WebhookDeliveryStatus::Pending)
+                .then(|| delivery.next_attempt_at.to_rfc3339()),
+            response_status: delivery.response_status,
+            last_error: delivery.last_error,
+            created_at: delivery.created_at.to_rfc3339(),
+            delivered_at: delivery.delivered_at.map(|t| t.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct WebhookDeliveryListResponse {
+    pub deliveries: Vec<WebhookDeliveryResponse>,
+}
+
+#[tracing::instrument(skip(state, tenant_id, body))]
+pub async fn create_webhook_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Json(body): Json<CreateWebhookRequest>,
+) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    match state
+        .webhook_service
+        .subscribe(tenant_id, &body.url, body.event_types)
+        .await
+    {
+        Ok(subscription) => {
+            let secret = subscription.secret.clone();
+            (
+                StatusCode::CREATED,
+                Json(CreatedWebhookResponse {
+                    webhook: WebhookResponse::from(subscription),
+                    secret,
+                }),
+            )
+                .into_response()
+        }
+        Err(e) => error_response(e),
+    }
+}
+
+#[tracing::instrument(skip(state, tenant_id))]
+pub async fn list_webhooks_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    match state.webhook_service.list(&tenant_id).await {
+        Ok(subscriptions) => (
+            StatusCode::OK,
+            Json(WebhookListResponse {
+                webhooks: subscriptions
+                    .into_iter()
+                    .map(WebhookResponse::from)
+                    .collect(),
+            }),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+#[tracing::instrument(skip(state, tenant_id))]
+pub async fn delete_webhook_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(webhook_id): Path<String>,
+) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let Ok(uuid) = Uuid::parse_str(&webhook_id) else {
+        return bad_request(format!("Invalid webhook ID: {}", webhook_id));
+    };
+
+    match state
+        .webhook_service
+        .unsubscribe(&tenant_id, WebhookSubscriptionId::from_uuid(uuid))
+        .await
+    {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+#[tracing::instrument(skip(state, tenant_id, params))]
+pub async fn list_webhook_deliveries_handler<F, L, V>(
+    State(state): State<AppState<F, L, V>>,
+    Extension(tenant_id): Extension<TenantId>,
+    Path(webhook_id): Path<String>,
+    Query(params): Query<ListDeliveriesParams>,
+) -> Response
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    let Ok(uuid) = Uuid::parse_str(&webhook_id) else {
+        return bad_request(format!("Invalid webhook ID: {}", webhook_id));
+    };
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_DELIVERY_LIMIT)
+        .clamp(1, MAX_DELIVERY_LIMIT);
+
+    match state
+        .webhook_service
+        .deliveries(&tenant_id, WebhookSubscriptionId::from_uuid(uuid), limit)
+        .await
+    {
+        Ok(deliveries) => (
+            StatusCode::OK,
+            Json(WebhookDeliveryListResponse {
+                deliveries: deliveries
+                    .into_iter()
+                    .map(WebhookDeliveryResponse::from)
+                    .collect(),
+            }),
+        )
+            .into_response(),
+        Err(e) => error_response(e),
+    }
+}
+
+fn bad_request(error: String) -> Response {
+    (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })).into_response()
+}
+
+fn error_response(error: WebhookServiceError) -> Response {
+    let status = match &error {
+        WebhookServiceError::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+        WebhookServiceError::NotFound(_) => StatusCode::NOT_FOUND,
+        WebhookServiceError::Repository(_) => {
+            tracing::error!(error = %error, "Webhook operation failed");
+            StatusCode::INTERNAL_SERVER_ERROR
+        }
+    };
+    (
+        status,
+        Json(ErrorResponse {
+            error: error.to_string(),
+        }),
+    )
+        .into_response()
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use axum::Json;
+use axum::extract::{Request, State};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use tracing::Instrument;
+
+use crate::application::services::{ApiKeyService, run_as_principal};
+use crate::domain::{ApiKey, ApiScope, Principal, TenantId};
+use crate::presentation::config::{AuthSettings, TenancySettings};
+
+/// Length of the key-hash prefix identifying a static key in logs.
+const STATIC_SUBJECT_HASH_LEN: usize = 8;
+
+/// Resolves the [`Principal`] (and therefore the tenant) of a request.
+pub struct Authenticator {
+    header: String,
+    static_keys: HashMap<String, Principal>,
+    api_keys: Arc<ApiKeyService>,
+    require_api_key: bool,
+}
+
+impl Authenticator {
+    /// Static keys mapped to an invalid tenant id are dropped (and logged), so they are
+    /// rejected like any unknown key rather than silently falling back to the default tenant.
+    pub fn new(
+        auth: &AuthSettings,
+        tenancy: &TenancySettings,
+        api_keys: Arc<ApiKeyService>,
+    ) -> Self {
+        let static_keys = tenancy
+            .api_keys
+            .iter()
+            .filter_map(|(key, tenant)| match TenantId::parse(tenant) {
+                Some(tenant_id) => {
+                    let hash = ApiKey::hash_secret(key);
+                    let principal = Principal {
+                        subject: format!("static:{}", &hash[..STATIC_SUBJECT_HASH_LEN]),
+                        tenant_id,
+                        scopes: ApiScope::ALL.to_vec(),
+                        all_tenants: true,
+                    };
+                    Some((key.clone(), principal))
+                }
+                None => {
+                    tracing::error!(tenant = %tenant, "Ignoring API key mapped to an invalid tenant id");
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            header: tenancy.header.to_ascii_lowercase(),
+            static_keys,
+            api_keys,
+            require_api_key: auth.enabled || !tenancy.api_keys.is_empty(),
+        }
+    }
+
+    pub async fn authenticate(&self, headers: &HeaderMap) -> Result<Principal, AuthRejection> {
+        let requested = match headers.get(self.header.as_str()) {
+            Some(value) => {
+                let raw = value.to_str().map_err(|_| AuthRejection::InvalidTenant)?;
+                Some(TenantId::parse(raw.trim()).ok_or(AuthRejection::InvalidTenant)?)
+            }
+            None => None,
+        };
+
+        // Open WebUI always sends a bearer token; it is ignored while authentication is off.
+        if !self.require_api_key {
+            return Ok(Principal::anonymous(requested.unwrap_or_default()));
+        }
+
+        let key = headers
+            .get(header::AUTHORIZATION)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|k| !k.is_empty())
+            .ok_or(AuthRejection::MissingApiKey)?;
+
+        let principal = match self.static_keys.get(key) {
+            Some(principal) => principal.clone(),
+            None => self
+                .api_keys
+                .authenticate(key)
+                .await
+                .map_err(|e| {
+                    tracing::error!(error = %e, "API key lookup failed");
+                    AuthRejection::Unavailable
+                })?
+                .ok_or(AuthRejection::UnknownApiKey)?,
+        };
+
+        match requested {
+            Some(requested) if requested != principal.tenant_id => {
+                Err(AuthRejection::TenantMismatch)
+            }
+            _ => Ok(principal),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthRejection {
+    MissingApiKey,
+    UnknownApiKey,
+    InvalidTenant,
+    TenantMismatch,
+    MissingScope(ApiScope),
+    Unavailable,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+impl IntoResponse for AuthRejection {
+    fn into_response(self) -> Response {
+        let (status, error) = match self {
+            Self::MissingApiKey => (StatusCode::UNAUTHORIZED, "Missing bearer API key".into()),
+            Self::UnknownApiKey => (StatusCode::UNAUTHORIZED, "Unknown API key".into()),
+            Self::InvalidTenant => (StatusCode::BAD_REQUEST, "Invalid tenant id".into()),
+            Self::TenantMismatch => (
+                StatusCode::FORBIDDEN,
+                "API key is not authorized for the requested tenant".into(),
+            ),
+            Self::MissingScope(scope) => (
+                StatusCode::FORBIDDEN,
+                format!("API key lacks the '{}' scope", scope),
+            ),
+            Self::Unavailable => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Authentication temporarily unavailable".into(),
+            ),
+        };
+        (status, Json(ErrorResponse { error })).into_response()
+    }
+}
+
+/// Authenticates the request, attaching its [`Principal`] and [`TenantId`] to the request
+/// extensions and running the rest of the stack inside the principal's scope.
+pub async fn auth_middleware(
+    State(authenticator): State<Arc<Authenticator>>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let principal = match authenticator.authenticate(request.headers()).await {
+        Ok(principal) => principal,
+        Err(rejection) => {
+            tracing::warn!(reason = ?rejection, "Rejected request: authentication failed");
+            return rejection.into_response();
+        }
+    };
+
+    let span = tracing::info_span!(
+        "auth",
+        tenant_id = %principal.tenant_id,
+        principal = %principal.subject,
+    );
+    request.extensions_mut().insert(principal.tenant_id.clone());
+    request.extensions_mut().insert(principal.clone());
+    run_as_principal(principal, next.run(request))
+        .instrument(span)
+        .await
+}
+
+/// Rejects requests whose principal lacks `scope`. Must run inside [`auth_middleware`].
+pub async fn require_scope(
+    State(scope): State<ApiScope>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match request.extensions().get::<Principal>() {
+        Some(principal) if principal.has_scope(scope) => next.run(request).await,
+        Some(principal) => {
+            tracing::warn!(principal = %principal, scope = %scope, "Rejected request: missing scope");
+            AuthRejection::MissingScope(scope).into_response()
+        }
+        None => AuthRejection::MissingApiKey.into_response(),
+    }
+}
@@ -0,0 +1,3 @@
+mod auth;
+
+pub use auth::{AuthRejection, Authenticator, auth_middleware, require_scope};
@@ -1,11 +1,13 @@
 pub mod config;
 pub mod handlers;
+pub mod middleware;
 pub mod router;
 pub mod state;
 
 pub use config::{
     ChunkingStrategy, EmbeddingProvider, Environment, EvalSettings, ExtractorProvider, RagSettings,
-    Settings, StorageProviderSetting, StorageSettings, TranscriptionProviderSetting,
+    Settings, StorageProviderSetting, StorageSettings, TenancySettings,
+    TranscriptionProviderSetting,
 };
 pub use router::create_router;
 pub use state::AppState;
@@ -1,18 +1,27 @@
+use std::sync::Arc;
+
 use axum::Router;
 use axum::extract::DefaultBodyLimit;
 use axum::middleware;
 use axum::response::IntoResponse;
-use axum::routing::{get, post};
+use axum::routing::{delete, get, post};
 use tower_http::cors::{Any, CorsLayer};
 use tower_http::trace::{DefaultMakeSpan, DefaultOnResponse, TraceLayer};
 use tracing::Level;
 
 use crate::application::ports::{FileLoader, LlmClient, VectorStore};
+use crate::domain::ApiScope;
 use crate::infrastructure::observability::{correlation_id_middleware, request_id_middleware};
 use crate::presentation::handlers::{
-    agent_chat_handler, chat_completions_handler, health_handler, ingest_handler,
-    ingest_reference_handler, job_status_handler, models_handler, query_handler,
+    agent_chat_handler, cancel_job_handler, chat_completions_handler, correct_transcript_handler,
+    create_api_key_handler, create_webhook_handler, delete_document_handler,
+    delete_webhook_handler, get_document_handler, get_transcript_handler, health_handler,
+    ingest_handler, ingest_reference_handler, ingest_repository_handler, ingest_url_handler,
+    job_events_handler, job_status_handler, list_api_keys_handler, list_documents_handler,
+    list_jobs_handler, list_webhook_deliveries_handler, list_webhooks_handler, models_handler,
+    query_handler, reingest_document_handler, retry_job_handler, revoke_api_key_handler,
 };
+use crate::presentation::middleware::{Authenticator, auth_middleware, require_scope};
 use crate::presentation::state::AppState;
 
 pub fn create_router<F, L, V>(state: AppState<F, L, V>) -> Router
@@ -22,6 +31,11 @@ where
     V: VectorStore + 'static,
 {
     let max_upload_bytes = state.settings.storage.max_upload_size_bytes as usize;
+    let authenticator = Arc::new(Authenticator::new(
+        &state.settings.auth,
+        &state.settings.tenancy,
+        Arc::clone(&state.api_key_service),
+    ));
 
     let cors = CorsLayer::new()
         .allow_origin(Any)
@@ -32,8 +46,18 @@ where
         .make_span_with(DefaultMakeSpan::new().level(Level::INFO))
         .on_response(DefaultOnResponse::new().level(Level::INFO));
 
-    api_routes()
-        .merge(openai_compat_routes::<F, L, V>())
+    let authenticated = scoped(ingest_routes(), ApiScope::Ingest)
+        .merge(scoped(query_routes(), ApiScope::Query))
+        .merge(scoped(openai_compat_routes(), ApiScope::Query))
+        .merge(scoped(agent_routes(), ApiScope::Agent))
+        .merge(scoped(admin_routes(), ApiScope::Admin))
+        .route_layer(middleware::from_fn_with_state(
+            authenticator,
+            auth_middleware,
+        ));
+
+    public_routes()
+        .merge(authenticated)
         .layer(DefaultBodyLimit::max(max_upload_bytes))
         .layer(middleware::from_fn(correlation_id_middleware))
         .layer(middleware::from_fn(request_id_middleware))
@@ -42,8 +66,8 @@ where
         .with_state(state)
 }
 
-/// Core API routes (health, ingestion, query, jobs, agent).
-fn api_routes<F, L, V>() -> Router<AppState<F, L, V>>
+/// Unauthenticated, tenant-independent routes.
+fn public_routes<F, L, V>() -> Router<AppState<F, L, V>>
 where
     F: FileLoader + 'static,
     L: LlmClient + 'static,
@@ -52,14 +76,109 @@ where
     Router::new()
         .route("/openapi.json", get(serve_openapi_spec))
         .route("/health", get(health_handler))
+}
+
+/// Rejects requests to `router` whose principal lacks `scope`.
+fn scoped<S>(router: Router<S>, scope: ApiScope) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    router.route_layer(middleware::from_fn_with_state(scope, require_scope))
+}
+
+/// Ingestion, document catalog, job and webhook routes (`ingest` scope).
+fn ingest_routes<F, L, V>() -> Router<AppState<F, L, V>>
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    Router::new()
         .route("/api/v1/ingest", post(ingest_handler::<F, L, V>))
         .route(
             "/api/v1/ingest-reference",
             post(ingest_reference_handler::<F, L, V>),
         )
-        .route("/api/v1/query", post(query_handler::<F, L, V>))
+        .route("/api/v1/ingest-url", post(ingest_url_handler::<F, L, V>))
+        .route(
+            "/api/v1/ingest-repository",
+            post(ingest_repository_handler::<F, L, V>),
+        )
+        .route("/api/v1/documents", get(list_documents_handler::<F, L, V>))
+        .route(
+            "/api/v1/documents/{document_id}",
+            get(get_document_handler::<F, L, V>).delete(delete_document_handler::<F, L, V>),
+        )
+        .route(
+            "/api/v1/documents/{document_id}/reingest",
+            post(reingest_document_handler::<F, L, V>),
+        )
+        .route(
+            "/api/v1/documents/{document_id}/transcript",
+            get(get_transcript_handler::<F, L, V>).put(correct_transcript_handler::<F, L, V>),
+        )
+        .route("/api/v1/jobs", get(list_jobs_handler::<F, L, V>))
+        .route("/api/v1/jobs/events", get(job_events_handler::<F, L, V>))
         .route("/api/v1/jobs/{job_id}", get(job_status_handler::<F, L, V>))
-        .route("/api/v1/agent/chat", post(agent_chat_handler::<F, L, V>))
+        .route(
+            "/api/v1/jobs/{job_id}/cancel",
+            post(cancel_job_handler::<F, L, V>),
+        )
+        .route(
+            "/api/v1/jobs/{job_id}/retry",
+            post(retry_job_handler::<F, L, V>),
+        )
+        .route(
+            "/api/v1/webhooks",
+            get(list_webhooks_handler::<F, L, V>).post(create_webhook_handler::<F, L, V>),
+        )
+        .route(
+            "/api/v1/webhooks/{webhook_id}",
+            delete(delete_webhook_handler::<F, L, V>),
+        )
+        .route(
+            "/api/v1/webhooks/{webhook_id}/deliveries",
+            get(list_webhook_deliveries_handler::<F, L, V>),
+        )
+}
+
+/// RAG query route (`query` scope).
+fn query_routes<F, L, V>() -> Router<AppState<F, L, V>>
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    Router::new().route("/api/v1/query", post(query_handler::<F, L, V>))
+}
+
+/// Agentic chat route (`agent` scope).
+fn agent_routes<F, L, V>() -> Router<AppState<F, L, V>>
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    Router::new().route("/api/v1/agent/chat", post(agent_chat_handler::<F, L, V>))
+}
+
+/// API key management (`admin` scope). Admin keys manage the keys of their own tenant; only
+/// static keys reach other tenants.
+fn admin_routes<F, L, V>() -> Router<AppState<F, L, V>>
+where
+    F: FileLoader + 'static,
+    L: LlmClient + 'static,
+    V: VectorStore + 'static,
+{
+    Router::new()
+        .route(
+            "/api/v1/admin/api-keys",
+            get(list_api_keys_handler::<F, L, V>).post(create_api_key_handler::<F, L, V>),
+        )
+        .route(
+            "/api/v1/admin/api-keys/{api_key_id}",
+            delete(revoke_api_key_handler::<F, L, V>),
+        )
 }
 
 /// OpenAI-compatible routes (canonical `/v1/` paths + `/api/` aliases for Open WebUI).
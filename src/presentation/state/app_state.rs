@@ -1,12 +1,14 @@
 use std::sync::Arc;
 
-use tokio::sync::mpsc;
+use tokio::sync::Notify;
 
 use crate::application::ports::{
-    ConversationRepository, FileLoader, JobRepository, LlmClient, StagingStore, VectorStore,
+    ConversationRepository, FileLoader, JobEventBus, JobRepository, LlmClient, StagingStore,
+    VectorStore,
 };
 use crate::application::services::{
-    AgentServicePort, IngestionMessage, IngestionService, RetrievalService,
+    AgentServicePort, ApiKeyService, DocumentService, IngestionService, RepositoryIngestionService,
+    RetrievalService, UrlIngestionService, WebhookService,
 };
 use crate::presentation::config::Settings;
 
@@ -18,11 +20,18 @@ where
 {
     pub ingestion_service: Arc<IngestionService<F, V>>,
     pub retrieval_service: Arc<RetrievalService<L, V>>,
+    pub document_service: Arc<DocumentService<V>>,
+    pub url_ingestion_service: Arc<UrlIngestionService<V>>,
+    pub repository_ingestion_service: Arc<RepositoryIngestionService<V>>,
     pub conversation_repository: Arc<dyn ConversationRepository>,
     pub job_repository: Arc<dyn JobRepository>,
-    pub ingestion_sender: mpsc::Sender<IngestionMessage>,
+    pub job_events: Arc<dyn JobEventBus>,
     pub staging_store: Arc<dyn StagingStore>,
+    /// Signals an in-process ingestion worker that a job was queued.
+    pub ingestion_wakeup: Arc<Notify>,
     pub agent_service: Option<Arc<dyn AgentServicePort>>,
+    pub api_key_service: Arc<ApiKeyService>,
+    pub webhook_service: Arc<WebhookService>,
     pub settings: Settings,
 }
 
@@ -36,11 +45,17 @@ where
         Self {
             ingestion_service: Arc::clone(&self.ingestion_service),
             retrieval_service: Arc::clone(&self.retrieval_service),
+            document_service: Arc::clone(&self.document_service),
+            url_ingestion_service: Arc::clone(&self.url_ingestion_service),
+            repository_ingestion_service: Arc::clone(&self.repository_ingestion_service),
             conversation_repository: Arc::clone(&self.conversation_repository),
             job_repository: Arc::clone(&self.job_repository),
-            ingestion_sender: self.ingestion_sender.clone(),
+            job_events: Arc::clone(&self.job_events),
             staging_store: Arc::clone(&self.staging_store),
+            ingestion_wakeup: Arc::clone(&self.ingestion_wakeup),
             agent_service: self.agent_service.as_ref().map(Arc::clone),
+            api_key_service: Arc::clone(&self.api_key_service),
+            webhook_service: Arc::clone(&self.webhook_service),
             settings: self.settings.clone(),
         }
     }
@@ -0,0 +1,136 @@
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use sandakan::application::ports::{DocumentRepository, RepositoryError};
+use sandakan::domain::{DocumentId, DocumentRecord, TenantId};
+
+/// Document catalog kept in a vector.
+#[derive(Default)]
+pub struct InMemoryDocumentRepository {
+    pub records: Mutex<Vec<DocumentRecord>>,
+}
+
+impl InMemoryDocumentRepository {
+    /// Marks every record ingested, as the worker would after processing its job.
+    pub async fn ingest_all(&self) {
+        for record in self.records.lock().await.iter_mut() {
+            record.ingested_at = Some(Utc::now());
+        }
+    }
+
+    pub async fn filenames(&self) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .records
+            .lock()
+            .await
+            .iter()
+            .map(|r| r.filename.clone())
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+#[async_trait::async_trait]
+impl DocumentRepository for InMemoryDocumentRepository {
+    async fn create(&self, document: &DocumentRecord) -> Result<(), RepositoryError> {
+        self.records.lock().await.push(document.clone());
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: DocumentId) -> Result<Option<DocumentRecord>, RepositoryError> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .iter()
+            .find(|r| r.id == id)
+            .cloned())
+    }
+
+    async fn find_by_content_hash(
+        &self,
+        tenant_id: &TenantId,
+        content_hash: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .iter()
+            .find(|r| r.tenant_id == *tenant_id && r.content_hash.as_deref() == Some(content_hash))
+            .cloned())
+    }
+
+    async fn find_by_filename(
+        &self,
+        tenant_id: &TenantId,
+        filename: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .iter()
+            .rev()
+            .find(|r| r.tenant_id == *tenant_id && r.filename == filename)
+            .cloned())
+    }
+
+    async fn update_source(&self, document: &DocumentRecord) -> Result<(), RepositoryError> {
+        let mut records = self.records.lock().await;
+        let record = records
+            .iter_mut()
+            .find(|r| r.id == document.id)
+            .ok_or_else(|| RepositoryError::NotFound(document.id.as_uuid().to_string()))?;
+        *record = document.clone();
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        tenant_id: &TenantId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .iter()
+            .filter(|r| r.tenant_id == *tenant_id)
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_by_filename_prefix(
+        &self,
+        tenant_id: &TenantId,
+        prefix: &str,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError> {
+        Ok(self
+            .records
+            .lock()
+            .await
+            .iter()
+            .filter(|r| r.tenant_id == *tenant_id && r.filename.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+
+    async fn mark_ingested(
+        &self,
+        _id: DocumentId,
+        _chunk_count: usize,
+        _content_hash: &str,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn delete(&self, id: DocumentId) -> Result<(), RepositoryError> {
+        self.records.lock().await.retain(|r| r.id != id);
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+use std::time::Duration;
+
+use tokio::sync::Mutex;
+
+use sandakan::application::ports::{JobFilter, JobRepository, LeaseRenewal, RepositoryError};
+use sandakan::domain::{DocumentId, FetchFailure, Job, JobId, JobProgress, JobStatus};
+
+/// Job queue kept in a vector. Nothing is ever claimed, so leases and retries are refused.
+#[derive(Default)]
+pub struct InMemoryJobRepository {
+    pub jobs: Mutex<Vec<Job>>,
+}
+
+impl InMemoryJobRepository {
+    pub async fn job(&self, id: JobId) -> Job {
+        self.jobs
+            .lock()
+            .await
+            .iter()
+            .find(|j| j.id == id)
+            .cloned()
+            .expect("job exists")
+    }
+
+    pub async fn count_of_type(&self, job_type: &str) -> usize {
+        self.jobs
+            .lock()
+            .await
+            .iter()
+            .filter(|j| j.job_type == job_type)
+            .count()
+    }
+}
+
+#[async_trait::async_trait]
+impl JobRepository for InMemoryJobRepository {
+    async fn create(&self, job: &Job) -> Result<(), RepositoryError> {
+        self.jobs.lock().await.push(job.clone());
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: JobId) -> Result<Option<Job>, RepositoryError> {
+        Ok(self.jobs.lock().await.iter().find(|j| j.id == id).cloned())
+    }
+
+    async fn update_status(
+        &self,
+        id: JobId,
+        status: JobStatus,
+        error_message: Option<&str>,
+    ) -> Result<(), RepositoryError> {
+        if let Some(job) = self.jobs.lock().await.iter_mut().find(|j| j.id == id) {
+            job.status = status;
+            job.error_message = error_message.map(str::to_string);
+        }
+        Ok(())
+    }
+
+    async fn update_leased_status(
+        &self,
+        _id: JobId,
+        _worker_id: &str,
+        _status: JobStatus,
+        _error_message: Option<&str>,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    async fn list_by_status(&self, status: JobStatus) -> Result<Vec<Job>, RepositoryError> {
+        Ok(self
+            .jobs
+            .lock()
+            .await
+            .iter()
+            .filter(|j| j.status == status)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_latest_by_document(
+        &self,
+        document_id: DocumentId,
+    ) -> Result<Option<Job>, RepositoryError> {
+        Ok(self
+            .jobs
+            .lock()
+            .await
+            .iter()
+            .rev()
+            .find(|j| j.document_id == Some(document_id))
+            .cloned())
+    }
+
+    async fn list(
+        &self,
+        _filter: &JobFilter,
+        _limit: usize,
+        _offset: usize,
+    ) -> Result<Vec<Job>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn claim_next(
+        &self,
+        _worker_id: &str,
+        _lease: Duration,
+    ) -> Result<Option<Job>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn renew_lease(
+        &self,
+        _id: JobId,
+        _worker_id: &str,
+        _lease: Duration,
+    ) -> Result<LeaseRenewal, RepositoryError> {
+        Ok(LeaseRenewal::Lost)
+    }
+
+    async fn release_expired(&self, _id: JobId) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    async fn schedule_retry(
+        &self,
+        _id: JobId,
+        _worker_id: &str,
+        _delay: Duration,
+        _error_message: &str,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    async fn update_progress(
+        &self,
+        id: JobId,
+        _worker_id: &str,
+        progress: &JobProgress,
+    ) -> Result<bool, RepositoryError> {
+        if let Some(job) = self.jobs.lock().await.iter_mut().find(|j| j.id == id) {
+            job.progress = progress.clone();
+        }
+        Ok(true)
+    }
+
+    async fn request_cancel(&self, id: JobId) -> Result<Option<JobStatus>, RepositoryError> {
+        let mut jobs = self.jobs.lock().await;
+        let Some(job) = jobs
+            .iter_mut()
+            .find(|j| j.id == id && !j.status.is_terminal())
+        else {
+            return Ok(None);
+        };
+        job.cancel_requested = true;
+        if job.status == JobStatus::Queued {
+            job.status = JobStatus::Cancelled;
+        }
+        Ok(Some(job.status))
+    }
+
+    async fn retry(&self, _id: JobId) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    async fn record_fetch_failure(
+        &self,
+        id: JobId,
+        failure: &FetchFailure,
+    ) -> Result<(), RepositoryError> {
+        if let Some(job) = self.jobs.lock().await.iter_mut().find(|j| j.id == id) {
+            job.fetch_failures.push(failure.clone());
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,7 @@
+mod in_memory_document_repository;
+mod in_memory_job_repository;
 mod test_postgres;
 
+pub use in_memory_document_repository::InMemoryDocumentRepository;
+pub use in_memory_job_repository::InMemoryJobRepository;
 pub use test_postgres::TestPostgres;
@@ -4,22 +4,28 @@ use std::time::Duration;
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
 use futures::stream::StreamExt;
+use tokio::sync::Notify;
 use tower::ServiceExt;
 
 use sandakan::application::ports::{Embedder, LlmClient, TextSplitter};
-use sandakan::application::services::IngestionMessage;
-use sandakan::application::services::{IngestionService, RetrievalService};
+use sandakan::application::services::{
+    ApiKeyService, DocumentService, IngestionService, RepositoryIngestionService, RetrievalService,
+    UrlIngestionService, WebhookService,
+};
 use sandakan::infrastructure::llm::{MockEmbedder, create_streaming_llm_client};
 use sandakan::infrastructure::persistence::{
-    MockConversationRepository, MockJobRepository, MockVectorStore,
+    BroadcastJobEventBus, MockApiKeyRepository, MockConversationRepository, MockDocumentRepository,
+    MockJobRepository, MockTranscriptRepository, MockVectorStore, MockWebhookRepository,
 };
+use sandakan::infrastructure::source_tree::GitCheckoutReader;
 use sandakan::infrastructure::storage::MockStagingStore;
 use sandakan::infrastructure::text_processing::{MockFileLoader, RecursiveCharacterSplitter};
+use sandakan::infrastructure::web::HttpWebFetcher;
 use sandakan::presentation::config::{
     AudioExtractionSettings, ChunkingSettings, ChunkingStrategy, DatabaseSettings,
     EmbeddingProvider, EmbeddingsSettings, ExtractionSettings, LlmSettings, LoggingSettings,
-    PdfExtractionSettings, QdrantSettings, RagSettings, ServerSettings, StorageProviderSetting,
-    StorageSettings, TranscriptionProviderSetting, VideoExtractionSettings,
+    PdfExtractionSettings, PdfFallbackProvider, QdrantSettings, RagSettings, ServerSettings,
+    StorageProviderSetting, StorageSettings, TranscriptionProviderSetting, VideoExtractionSettings,
 };
 use sandakan::presentation::{AppState, Settings, create_router};
 
@@ -59,6 +65,7 @@ fn test_settings() -> Settings {
             url: "http://localhost:6334".to_string(),
             collection_name: "test".to_string(),
             hybrid_search: false,
+            bm25: sandakan::presentation::config::Bm25Settings::default(),
         },
         database: DatabaseSettings {
             url: "postgres://test".to_string(),
@@ -102,6 +109,8 @@ fn test_settings() -> Settings {
                 vlm_api_key: None,
                 azure_endpoint: None,
                 azure_key: None,
+                fallback_provider: PdfFallbackProvider::None,
+                min_page_chars: 50,
             },
             audio: AudioExtractionSettings {
                 enabled: true,
@@ -113,6 +122,9 @@ fn test_settings() -> Settings {
                 azure_key: None,
                 azure_api_version: None,
                 asr_corrections: Default::default(),
+                language: None,
+                translate: false,
+                word_timestamps: false,
             },
             video: VideoExtractionSettings {
                 enabled: true,
@@ -125,9 +137,15 @@ fn test_settings() -> Settings {
             top_k: 5,
             system_prompt: SYSTEM_PROMPT.to_string(),
             fallback_message: "I cannot answer this.".to_string(),
+            query_transform: sandakan::presentation::config::QueryTransformSettings::default(),
         },
         eval: sandakan::presentation::config::EvalSettings::default(),
         agent: sandakan::presentation::config::AgentSettings::default(),
+        tenancy: sandakan::presentation::config::TenancySettings::default(),
+        auth: sandakan::presentation::config::AuthSettings::default(),
+        webhooks: sandakan::presentation::config::WebhookSettings::default(),
+        rerank: sandakan::presentation::config::RerankSettings::default(),
+        ingestion: sandakan::presentation::config::IngestionSettings::default(),
     }
 }
 
@@ -170,17 +188,42 @@ fn create_ollama_test_app() -> axum::Router {
         "I cannot answer this.".to_string(),
     ));
 
-    let (ingestion_sender, _ingestion_receiver) =
-        tokio::sync::mpsc::channel::<IngestionMessage>(16);
+    let document_service = Arc::new(DocumentService::new(
+        Arc::new(MockDocumentRepository),
+        Arc::new(MockJobRepository),
+        Arc::clone(&vector_store),
+        Arc::new(MockStagingStore),
+        Arc::new(MockTranscriptRepository),
+    ));
+
+    let url_ingestion_service = Arc::new(UrlIngestionService::new(
+        Arc::new(HttpWebFetcher::new("sandakan-test", Duration::from_secs(5), 1024).unwrap()),
+        Arc::new(MockStagingStore),
+        Arc::clone(&document_service),
+        Arc::new(MockJobRepository),
+    ));
+
+    let repository_ingestion_service = Arc::new(RepositoryIngestionService::new(
+        Arc::new(GitCheckoutReader::new()),
+        Arc::new(MockStagingStore),
+        Arc::clone(&document_service),
+        Arc::new(MockJobRepository),
+    ));
 
     let state = AppState {
         ingestion_service,
         retrieval_service,
+        document_service,
+        url_ingestion_service,
+        repository_ingestion_service,
         conversation_repository: Arc::new(MockConversationRepository),
         job_repository: Arc::new(MockJobRepository),
-        ingestion_sender,
+        job_events: Arc::new(BroadcastJobEventBus::default()),
         staging_store: Arc::new(MockStagingStore),
+        ingestion_wakeup: Arc::new(Notify::new()),
         agent_service: None,
+        api_key_service: Arc::new(ApiKeyService::new(Arc::new(MockApiKeyRepository))),
+        webhook_service: Arc::new(WebhookService::new(Arc::new(MockWebhookRepository))),
         settings: test_settings(),
     };
 
@@ -1,3 +1,5 @@
 mod pg_container_test;
 mod pg_conversation_repository_test;
 mod pg_job_repository_test;
+mod pg_transcript_repository_test;
+mod pg_webhook_repository_test;
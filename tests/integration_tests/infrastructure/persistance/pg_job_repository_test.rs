@@ -1,5 +1,11 @@
-use sandakan::application::ports::JobRepository;
-use sandakan::domain::{DocumentId, Job, JobStatus};
+use std::time::Duration;
+
+use sandakan::application::ports::{JobFilter, JobRepository, LeaseRenewal};
+use sandakan::domain::{
+    ContentType, CrawlRequest, Document, DocumentId, FetchFailure, IngestionPayload, Job,
+    JobPayload, JobProgress, JobStatus, RepositorySyncRequest, SourceMetadata, StoragePath,
+    TenantId,
+};
 
 use crate::helpers::TestPostgres;
 
@@ -110,3 +116,487 @@ async fn given_nonexistent_job_id_when_retrieving_then_returns_none() {
 
     assert!(result.is_none());
 }
+
+fn queued_ingestion_job() -> Job {
+    let document = Document::new("report.pdf".to_string(), ContentType::Pdf, 1024);
+    let storage_path = StoragePath::new(&document.id, &document.filename);
+    Job::new(Some(document.id), "document_ingestion".to_string()).with_payload(IngestionPayload {
+        document,
+        storage_path,
+        replace_existing: true,
+        principal: Some("api_key:test".to_string()),
+        source: SourceMetadata {
+            source_url: Some("https://example.com/report".to_string()),
+            title: Some("Annual report".to_string()),
+            author: None,
+            tags: vec!["finance".to_string()],
+            attributes: [("year".to_string(), "2024".to_string())].into(),
+        },
+        captions: Some(StoragePath::from_raw("captions/report.vtt")),
+    })
+}
+
+#[tokio::test]
+async fn given_job_with_payload_when_retrieving_then_payload_round_trips() {
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+
+    test_pg.job_repository.create(&job).await.unwrap();
+    let retrieved = test_pg
+        .job_repository
+        .get_by_id(job.id)
+        .await
+        .unwrap()
+        .expect("Job not found");
+
+    assert_eq!(retrieved.payload, job.payload);
+    assert_eq!(retrieved.attempts, 0);
+}
+
+#[tokio::test]
+async fn given_queued_job_when_two_workers_claim_then_only_one_receives_it() {
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+    test_pg.job_repository.create(&job).await.unwrap();
+
+    let lease = Duration::from_secs(30);
+    let (first, second) = tokio::join!(
+        test_pg.job_repository.claim_next("worker-a", lease),
+        test_pg.job_repository.claim_next("worker-b", lease),
+    );
+    let claimed: Vec<Job> = [first.unwrap(), second.unwrap()]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    assert_eq!(claimed.len(), 1);
+    assert_eq!(claimed[0].id, job.id);
+    assert_eq!(claimed[0].status, JobStatus::Processing);
+    assert_eq!(claimed[0].attempts, 1);
+    assert!(claimed[0].lease_expires_at.is_some());
+}
+
+#[tokio::test]
+async fn given_job_without_payload_when_claiming_then_it_is_not_returned() {
+    let test_pg = TestPostgres::new().await;
+    let job = Job::new(None, "legacy_job".to_string());
+    test_pg.job_repository.create(&job).await.unwrap();
+
+    let claimed = test_pg
+        .job_repository
+        .claim_next("worker-a", Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    assert!(claimed.is_none());
+}
+
+#[tokio::test]
+async fn given_claimed_job_when_another_worker_renews_lease_then_renewal_is_refused() {
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+    test_pg.job_repository.create(&job).await.unwrap();
+    let lease = Duration::from_secs(30);
+    test_pg
+        .job_repository
+        .claim_next("worker-a", lease)
+        .await
+        .unwrap();
+
+    let by_owner = test_pg
+        .job_repository
+        .renew_lease(job.id, "worker-a", lease)
+        .await
+        .unwrap();
+    let by_other = test_pg
+        .job_repository
+        .renew_lease(job.id, "worker-b", lease)
+        .await
+        .unwrap();
+
+    assert_eq!(by_owner, LeaseRenewal::Renewed);
+    assert_eq!(by_other, LeaseRenewal::Lost);
+}
+
+#[tokio::test]
+async fn given_claimed_job_when_lease_expires_then_release_requeues_it() {
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+    test_pg.job_repository.create(&job).await.unwrap();
+    test_pg
+        .job_repository
+        .claim_next("worker-a", Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    let while_live = test_pg
+        .job_repository
+        .release_expired(job.id)
+        .await
+        .unwrap();
+    test_pg
+        .job_repository
+        .renew_lease(job.id, "worker-a", Duration::ZERO)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let after_expiry = test_pg
+        .job_repository
+        .release_expired(job.id)
+        .await
+        .unwrap();
+    let reclaimed = test_pg
+        .job_repository
+        .claim_next("worker-b", Duration::from_secs(30))
+        .await
+        .unwrap()
+        .expect("Job was not re-queued");
+
+    assert!(!while_live);
+    assert!(after_expiry);
+    assert_eq!(reclaimed.attempts, 2);
+    assert_eq!(reclaimed.lease_owner.as_deref(), Some("worker-b"));
+}
+
+#[tokio::test]
+async fn given_job_reclaimed_by_another_worker_when_stale_worker_settles_then_writes_are_rejected()
+{
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+    test_pg.job_repository.create(&job).await.unwrap();
+    test_pg
+        .job_repository
+        .claim_next("worker-a", Duration::from_secs(30))
+        .await
+        .unwrap();
+    test_pg
+        .job_repository
+        .renew_lease(job.id, "worker-a", Duration::ZERO)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    test_pg
+        .job_repository
+        .release_expired(job.id)
+        .await
+        .unwrap();
+    test_pg
+        .job_repository
+        .claim_next("worker-b", Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    let stale_completed = test_pg
+        .job_repository
+        .update_leased_status(job.id, "worker-a", JobStatus::Completed, None)
+        .await
+        .unwrap();
+    let stale_retry = test_pg
+        .job_repository
+        .schedule_retry(job.id, "worker-a", Duration::ZERO, "network error")
+        .await
+        .unwrap();
+    let stale_progress = test_pg
+        .job_repository
+        .update_progress(
+            job.id,
+            "worker-a",
+            &JobProgress {
+                chunks_embedded: 99,
+                ..JobProgress::default()
+            },
+        )
+        .await
+        .unwrap();
+    let owner_completed = test_pg
+        .job_repository
+        .update_leased_status(job.id, "worker-b", JobStatus::Completed, None)
+        .await
+        .unwrap();
+    let stored = test_pg
+        .job_repository
+        .get_by_id(job.id)
+        .await
+        .unwrap()
+        .unwrap();
+
+    assert!(!stale_completed);
+    assert!(!stale_retry);
+    assert!(!stale_progress);
+    assert_eq!(stored.progress.chunks_embedded, 0);
+    assert!(owner_completed);
+    assert_eq!(stored.status, JobStatus::Completed);
+    assert!(stored.error_message.is_none());
+}
+
+#[tokio::test]
+async fn given_retry_scheduled_with_backoff_when_claiming_then_job_waits_until_it_elapses() {
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+    test_pg.job_repository.create(&job).await.unwrap();
+    let lease = Duration::from_secs(30);
+    test_pg
+        .job_repository
+        .claim_next("worker-a", lease)
+        .await
+        .unwrap();
+
+    let scheduled = test_pg
+        .job_repository
+        .schedule_retry(
+            job.id,
+            "worker-a",
+            Duration::from_millis(200),
+            "embedding rate limited",
+        )
+        .await
+        .unwrap();
+    let during_backoff = test_pg
+        .job_repository
+        .claim_next("worker-b", lease)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(250)).await;
+    let after_backoff = test_pg
+        .job_repository
+        .claim_next("worker-b", lease)
+        .await
+        .unwrap()
+        .expect("Retry was not claimable");
+
+    assert!(scheduled);
+    assert!(during_backoff.is_none());
+    assert_eq!(after_backoff.attempts, 2);
+    assert_eq!(
+        after_backoff.error_message.as_deref(),
+        Some("embedding rate limited")
+    );
+}
+
+#[tokio::test]
+async fn given_queued_job_when_cancel_requested_then_it_is_cancelled_immediately() {
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+    test_pg.job_repository.create(&job).await.unwrap();
+
+    let status = test_pg.job_repository.request_cancel(job.id).await.unwrap();
+    let claimed = test_pg
+        .job_repository
+        .claim_next("worker-a", Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    assert_eq!(status, Some(JobStatus::Cancelled));
+    assert!(claimed.is_none());
+}
+
+#[tokio::test]
+async fn given_in_flight_job_when_cancel_requested_then_next_renewal_reports_it() {
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+    test_pg.job_repository.create(&job).await.unwrap();
+    let lease = Duration::from_secs(30);
+    test_pg
+        .job_repository
+        .claim_next("worker-a", lease)
+        .await
+        .unwrap();
+
+    let status = test_pg.job_repository.request_cancel(job.id).await.unwrap();
+    let renewal = test_pg
+        .job_repository
+        .renew_lease(job.id, "worker-a", lease)
+        .await
+        .unwrap();
+    let retry_scheduled = test_pg
+        .job_repository
+        .schedule_retry(job.id, "worker-a", Duration::ZERO, "network error")
+        .await
+        .unwrap();
+
+    assert_eq!(status, Some(JobStatus::Processing));
+    assert_eq!(renewal, LeaseRenewal::CancelRequested);
+    assert!(!retry_scheduled);
+}
+
+#[tokio::test]
+async fn given_failed_job_when_retried_then_it_is_requeued_with_fresh_attempts() {
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+    test_pg.job_repository.create(&job).await.unwrap();
+    let lease = Duration::from_secs(30);
+    test_pg
+        .job_repository
+        .claim_next("worker-a", lease)
+        .await
+        .unwrap();
+    test_pg
+        .job_repository
+        .update_status(job.id, JobStatus::Failed, Some("extraction failed"))
+        .await
+        .unwrap();
+
+    let retried = test_pg.job_repository.retry(job.id).await.unwrap();
+    let retried_again = test_pg.job_repository.retry(job.id).await.unwrap();
+    let stored = test_pg
+        .job_repository
+        .get_by_id(job.id)
+        .await
+        .unwrap()
+        .expect("Job not found");
+
+    assert!(retried);
+    assert!(!retried_again);
+    assert_eq!(stored.status, JobStatus::Queued);
+    assert_eq!(stored.attempts, 0);
+    assert!(stored.error_message.is_none());
+}
+
+#[tokio::test]
+async fn given_progress_update_when_retrieving_then_counters_round_trip() {
+    let test_pg = TestPostgres::new().await;
+    let job = queued_ingestion_job();
+    test_pg.job_repository.create(&job).await.unwrap();
+    let progress = JobProgress {
+        pages_processed: 12,
+        segments_transcribed: 0,
+        chunks_total: 40,
+        chunks_embedded: 32,
+        urls_ingested: 0,
+        files_ingested: 3,
+        files_unchanged: 7,
+        files_removed: 1,
+    };
+
+    test_pg
+        .job_repository
+        .claim_next("worker-a", Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    let recorded = test_pg
+        .job_repository
+        .update_progress(job.id, "worker-a", &progress)
+        .await
+        .unwrap();
+    let stored = test_pg
+        .job_repository
+        .get_by_id(job.id)
+        .await
+        .unwrap()
+        .expect("Job not found");
+
+    assert!(recorded);
+    assert_eq!(stored.progress, progress);
+}
+
+#[tokio::test]
+async fn given_crawl_job_with_fetch_failures_when_claiming_then_payload_round_trips_and_failures_reset()
+ {
+    let test_pg = TestPostgres::new().await;
+    let request = CrawlRequest {
+        url: "https://docs.example.com/".to_string(),
+        sitemap: false,
+        max_depth: 2,
+        max_pages: 50,
+        allowed_domains: vec!["example.com".to_string()],
+        principal: Some("key-1".to_string()),
+        source: SourceMetadata {
+            tags: vec!["docs".to_string()],
+            ..SourceMetadata::default()
+        },
+    };
+    let job = Job::new(None, "url_ingestion".to_string()).with_payload(request.clone());
+    test_pg.job_repository.create(&job).await.unwrap();
+    let failure = FetchFailure {
+        url: "https://docs.example.com/missing".to_string(),
+        error: "HTTP 404".to_string(),
+    };
+    test_pg
+        .job_repository
+        .record_fetch_failure(job.id, &failure)
+        .await
+        .unwrap();
+
+    let stored = test_pg
+        .job_repository
+        .get_by_id(job.id)
+        .await
+        .unwrap()
+        .expect("Job not found");
+    assert_eq!(stored.payload, Some(JobPayload::Crawl(request)));
+    assert_eq!(stored.fetch_failures, vec![failure]);
+
+    let claimed = test_pg
+        .job_repository
+        .claim_next("worker-0", Duration::from_secs(30))
+        .await
+        .unwrap()
+        .expect("crawl job is claimable");
+    assert_eq!(claimed.id, job.id);
+    assert!(claimed.fetch_failures.is_empty());
+}
+
+#[tokio::test]
+async fn given_repository_sync_job_when_retrieving_then_payload_round_trips() {
+    let test_pg = TestPostgres::new().await;
+    let request = RepositorySyncRequest {
+        path: "/srv/repos/widgets".to_string(),
+        name: "widgets".to_string(),
+        principal: Some("key-1".to_string()),
+        source: SourceMetadata {
+            tags: vec!["code".to_string()],
+            ..SourceMetadata::default()
+        },
+    };
+    let job = Job::new(None, "repository_sync".to_string()).with_payload(request.clone());
+    test_pg.job_repository.create(&job).await.unwrap();
+
+    let stored = test_pg
+        .job_repository
+        .get_by_id(job.id)
+        .await
+        .unwrap()
+        .expect("Job not found");
+
+    assert_eq!(stored.payload, Some(JobPayload::RepositorySync(request)));
+}
+
+#[tokio::test]
+async fn given_jobs_of_several_tenants_when_listing_with_filter_then_only_matching_jobs_are_paged()
+{
+    let test_pg = TestPostgres::new().await;
+    let tenant = TenantId::parse("acme").unwrap();
+
+    let first = Job::new(None, "ingest_text".to_string()).with_tenant(tenant.clone());
+    let second = Job::new(None, "ingest_text".to_string()).with_tenant(tenant.clone());
+    let audio = Job::new(None, "ingest_audio".to_string()).with_tenant(tenant.clone());
+    let foreign = Job::new(None, "ingest_text".to_string());
+    for job in [&first, &second, &audio, &foreign] {
+        test_pg.job_repository.create(job).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    test_pg
+        .job_repository
+        .update_status(first.id, JobStatus::Failed, Some("boom"))
+        .await
+        .unwrap();
+
+    let filter = JobFilter {
+        job_type: Some("ingest_text".to_string()),
+        ..JobFilter::for_tenant(tenant.clone())
+    };
+    let page = test_pg.job_repository.list(&filter, 1, 0).await.unwrap();
+    assert_eq!(page.len(), 1);
+    assert_eq!(page[0].id, second.id, "newest job comes first");
+    let next = test_pg.job_repository.list(&filter, 1, 1).await.unwrap();
+    assert_eq!(next[0].id, first.id);
+
+    let failed = JobFilter {
+        status: Some(JobStatus::Failed),
+        ..JobFilter::for_tenant(tenant)
+    };
+    let failed = test_pg.job_repository.list(&failed, 10, 0).await.unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].id, first.id);
+}
@@ -0,0 +1,74 @@
+use sandakan::application::ports::{DocumentRepository, TranscriptRepository};
+use sandakan::domain::{
+    ContentType, Document, DocumentRecord, StoragePath, Transcript, TranscriptSegment,
+};
+use sandakan::infrastructure::persistence::{PgDocumentRepository, PgTranscriptRepository};
+
+use crate::helpers::TestPostgres;
+
+async fn cataloged_recording(documents: &PgDocumentRepository) -> DocumentRecord {
+    let document = Document::new("lecture.mp4".to_string(), ContentType::Video, 4096);
+    let path = StoragePath::new(&document.id, &document.filename);
+    let record = DocumentRecord::new(&document, path, true);
+    documents
+        .create(&record)
+        .await
+        .expect("Failed to create document");
+    record
+}
+
+#[tokio::test]
+async fn given_saved_transcript_when_saved_again_then_latest_segments_are_returned() {
+    let test_pg = TestPostgres::new().await;
+    let documents = PgDocumentRepository::new(test_pg.pool.clone());
+    let transcripts = PgTranscriptRepository::new(test_pg.pool.clone());
+    let record = cataloged_recording(&documents).await;
+
+    transcripts
+        .save(
+            record.id,
+            &Transcript::new(vec![TranscriptSegment::new("Draft.", 0.0, 1.0)]),
+        )
+        .await
+        .expect("Failed to save transcript");
+    let corrected = Transcript::new(vec![
+        TranscriptSegment::new("Corrected.", 0.0, 1.5),
+        TranscriptSegment::new("Second line.", 1.5, 3.25),
+    ]);
+    transcripts
+        .save(record.id, &corrected)
+        .await
+        .expect("Failed to overwrite transcript");
+
+    let stored = transcripts
+        .get(record.id)
+        .await
+        .expect("Failed to read transcript");
+    assert_eq!(stored, Some(corrected));
+}
+
+#[tokio::test]
+async fn given_transcript_when_document_is_deleted_then_transcript_goes_with_it() {
+    let test_pg = TestPostgres::new().await;
+    let documents = PgDocumentRepository::new(test_pg.pool.clone());
+    let transcripts = PgTranscriptRepository::new(test_pg.pool.clone());
+    let record = cataloged_recording(&documents).await;
+    transcripts
+        .save(
+            record.id,
+            &Transcript::new(vec![TranscriptSegment::new("Hello.", 0.0, 1.0)]),
+        )
+        .await
+        .expect("Failed to save transcript");
+
+    documents
+        .delete(record.id)
+        .await
+        .expect("Failed to delete document");
+
+    let stored = transcripts
+        .get(record.id)
+        .await
+        .expect("Failed to read transcript");
+    assert_eq!(stored, None);
+}
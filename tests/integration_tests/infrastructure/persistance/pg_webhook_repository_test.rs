@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use sandakan::application::ports::WebhookRepository;
+use sandakan::domain::{
+    TenantId, WebhookDeliveryStatus, WebhookEvent, WebhookEventType, WebhookSubscription,
+};
+use sandakan::infrastructure::persistence::PgWebhookRepository;
+
+use crate::helpers::TestPostgres;
+
+async fn subscribe(
+    repository: &PgWebhookRepository,
+    tenant: &str,
+    event_types: Vec<WebhookEventType>,
+) -> WebhookSubscription {
+    let subscription = WebhookSubscription::generate(
+        TenantId::parse(tenant).unwrap(),
+        "https://hooks.example.com",
+        event_types,
+    );
+    repository
+        .create_subscription(&subscription)
+        .await
+        .expect("Failed to create subscription");
+    subscription
+}
+
+#[tokio::test]
+async fn given_subscriptions_when_enqueueing_event_then_only_matching_tenant_and_type_get_delivery()
+{
+    let test_pg = TestPostgres::new().await;
+    let repository = PgWebhookRepository::new(test_pg.pool.clone());
+    let matching = subscribe(&repository, "acme", vec![WebhookEventType::JobFailed]).await;
+    subscribe(
+        &repository,
+        "acme",
+        vec![WebhookEventType::DocumentIngested],
+    )
+    .await;
+    subscribe(&repository, "globex", vec![WebhookEventType::JobFailed]).await;
+
+    let event = WebhookEvent::new(
+        TenantId::parse("acme").unwrap(),
+        WebhookEventType::JobFailed,
+        serde_json::json!({ "job_id": "j-1" }),
+    );
+    let queued = repository.enqueue(&event).await.expect("Failed to enqueue");
+
+    assert_eq!(queued, 1);
+    let deliveries = repository
+        .list_deliveries(matching.id, 10)
+        .await
+        .expect("Failed to list deliveries");
+    assert_eq!(deliveries.len(), 1);
+    assert_eq!(deliveries[0].event_id, event.id);
+    assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Pending);
+    assert_eq!(deliveries[0].payload["data"]["job_id"], "j-1");
+}
+
+#[tokio::test]
+async fn given_due_delivery_when_claimed_then_it_is_leased_until_outcome_is_recorded() {
+    let test_pg = TestPostgres::new().await;
+    let repository = PgWebhookRepository::new(test_pg.pool.clone());
+    let subscription = subscribe(&repository, "acme", vec![WebhookEventType::JobFailed]).await;
+    let event = WebhookEvent::new(
+        TenantId::parse("acme").unwrap(),
+        WebhookEventType::JobFailed,
+        serde_json::json!({}),
+    );
+    repository.enqueue(&event).await.expect("Failed to enqueue");
+
+    let claimed = repository
+        .claim_due(10, Duration::from_secs(60))
+        .await
+        .expect("Failed to claim");
+    let reclaimed = repository
+        .claim_due(10, Duration::from_secs(60))
+        .await
+        .expect("Failed to claim");
+
+    assert_eq!(claimed.len(), 1);
+    assert!(reclaimed.is_empty());
+    assert_eq!(claimed[0].secret, subscription.secret);
+    assert_eq!(claimed[0].delivery.attempts, 1);
+
+    repository
+        .mark_delivered(claimed[0].delivery.id, 204)
+        .await
+        .expect("Failed to mark delivered");
+    let deliveries = repository
+        .list_deliveries(subscription.id, 10)
+        .await
+        .expect("Failed to list deliveries");
+    assert_eq!(deliveries[0].status, WebhookDeliveryStatus::Delivered);
+    assert_eq!(deliveries[0].response_status, Some(204));
+    assert!(deliveries[0].delivered_at.is_some());
+}
+
+#[tokio::test]
+async fn given_deleted_subscription_when_listing_deliveries_then_log_is_removed() {
+    let test_pg = TestPostgres::new().await;
+    let repository = PgWebhookRepository::new(test_pg.pool.clone());
+    let subscription = subscribe(&repository, "acme", vec![WebhookEventType::JobFailed]).await;
+    let event = WebhookEvent::new(
+        TenantId::parse("acme").unwrap(),
+        WebhookEventType::JobFailed,
+        serde_json::json!({}),
+    );
+    repository.enqueue(&event).await.expect("Failed to enqueue");
+
+    let deleted = repository
+        .delete_subscription(subscription.id)
+        .await
+        .expect("Failed to delete");
+
+    assert!(deleted);
+    assert!(
+        repository
+            .list_deliveries(subscription.id, 10)
+            .await
+            .expect("Failed to list deliveries")
+            .is_empty()
+    );
+}
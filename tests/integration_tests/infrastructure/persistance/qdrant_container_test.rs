@@ -115,6 +115,34 @@ async fn given_running_qdrant_container_when_creating_collection_then_collection
     assert!(exists, "Collection should exist after creation");
 }
 
+#[tokio::test]
+async fn given_collection_missing_payload_indexes_when_ensuring_indexes_then_only_missing_ones_are_created()
+ {
+    let test_qdrant = TestQdrant::new().await;
+    let full = CollectionConfig::new(384);
+    let mut legacy = full.clone();
+    legacy.payload_indexes.truncate(3);
+    test_qdrant
+        .adapter
+        .create_collection(&legacy)
+        .await
+        .expect("Failed to create collection");
+
+    let created = test_qdrant
+        .adapter
+        .ensure_payload_indexes(&full.payload_indexes)
+        .await
+        .expect("Failed to ensure payload indexes");
+    let again = test_qdrant
+        .adapter
+        .ensure_payload_indexes(&full.payload_indexes)
+        .await
+        .expect("Failed to ensure payload indexes");
+
+    assert_eq!(created, vec!["content_type", "title", "tags", "ingested_at"]);
+    assert!(again.is_empty());
+}
+
 #[tokio::test]
 async fn given_running_qdrant_container_when_ingestion_service_upserts_document_chunk_then_vector_count_increments_and_payload_matches()
  {
@@ -4,7 +4,7 @@ use axum::Router;
 use axum::body::Body;
 use axum::http::{HeaderValue, Request, Response, StatusCode};
 use sandakan::application::ports::{FileLoader, FileLoaderError};
-use sandakan::domain::{ContentType, Document};
+use sandakan::domain::{ContentType, Document, PageSegment};
 use sandakan::infrastructure::text_processing::AzureDocIntelAdapter;
 use tokio::net::TcpListener;
 
@@ -79,13 +79,13 @@ async fn given_valid_pdf_bytes_when_azure_adapter_used_then_returns_markdown() {
         data.len() as u64,
     );
 
-    let result = adapter.extract_text(data, &document).await;
+    let result = adapter.extract_pages(data, &document).await;
 
     assert!(result.is_ok(), "expected Ok but got: {:?}", result);
-    let text = result.unwrap();
+    let pages = result.unwrap();
     assert!(
-        text.contains("Report"),
-        "expected markdown content, got: {text}"
+        pages[0].text.contains("Report"),
+        "expected markdown content, got: {pages:?}"
     );
 
     let _ = shutdown_tx.send(());
@@ -100,7 +100,7 @@ async fn given_azure_returns_failed_status_when_polling_then_returns_extraction_
     let data = b"corrupt pdf";
     let document = Document::new("bad.pdf".to_string(), ContentType::Pdf, data.len() as u64);
 
-    let result = adapter.extract_text(data, &document).await;
+    let result = adapter.extract_pages(data, &document).await;
 
     assert!(
         matches!(result, Err(FileLoaderError::ExtractionFailed(_))),
@@ -120,7 +120,7 @@ async fn given_azure_returns_empty_content_when_polling_then_returns_no_text_fou
     let data = b"blank pdf";
     let document = Document::new("blank.pdf".to_string(), ContentType::Pdf, data.len() as u64);
 
-    let result = adapter.extract_text(data, &document).await;
+    let result = adapter.extract_pages(data, &document).await;
 
     assert!(
         matches!(result, Err(FileLoaderError::NoTextFound(_))),
@@ -130,3 +130,37 @@ async fn given_azure_returns_empty_content_when_polling_then_returns_no_text_fou
 
     let _ = shutdown_tx.send(());
 }
+
+#[tokio::test]
+async fn given_multi_page_result_when_extracting_then_returns_one_segment_per_page() {
+    let response_body = r##"{"status":"succeeded","analyzeResult":{
+        "content":"# Intro\nFirst page.\n<!-- PageBreak -->\nZweite Seite über Ärzte.",
+        "pages":[
+            {"pageNumber":1,"spans":[{"offset":0,"length":19}]},
+            {"pageNumber":2,"spans":[{"offset":39,"length":24}]}
+        ],
+        "paragraphs":[
+            {"boundingRegions":[{"pageNumber":2,"polygon":[1.0,1.0,7.5,1.0,7.5,1.4,1.0,1.4]}]}
+        ]
+    }}"##;
+    let (base_url, shutdown_tx) = start_mock_azure_server(response_body).await;
+
+    let adapter = AzureDocIntelAdapter::new(&base_url, "test-api-key");
+    let data = b"fake pdf bytes";
+    let document = Document::new(
+        "report.pdf".to_string(),
+        ContentType::Pdf,
+        data.len() as u64,
+    );
+
+    let pages = adapter.extract_pages(data, &document).await.unwrap();
+
+    assert_eq!(pages.len(), 2);
+    assert_eq!(pages[0], PageSegment::new(1, "# Intro\nFirst page."));
+    assert_eq!(pages[1].page, Some(2));
+    assert_eq!(pages[1].text, "Zweite Seite über Ärzte.");
+    assert_eq!(pages[1].regions.len(), 1);
+    assert_eq!(pages[1].regions[0].polygon.len(), 8);
+
+    let _ = shutdown_tx.send(());
+}
@@ -53,7 +53,7 @@ async fn given_non_pdf_content_type_when_extracting_text_then_returns_unsupporte
     let adapter = LmStudioVlmPdfAdapter::new("http://localhost:1234", "test-model", "test-key");
     let document = Document::new("file.txt".to_string(), ContentType::Text, 10);
 
-    let result = adapter.extract_text(b"hello", &document).await;
+    let result = adapter.extract_pages(b"hello", &document).await;
 
     assert!(
         matches!(result, Err(FileLoaderError::UnsupportedContentType(_))),
@@ -77,11 +77,13 @@ async fn given_valid_pdf_when_lm_studio_returns_content_then_returns_extracted_t
         data.len() as u64,
     );
 
-    let result = adapter.extract_text(&data, &document).await;
+    let result = adapter.extract_pages(&data, &document).await;
 
     assert!(result.is_ok(), "expected Ok but got: {:?}", result);
+    let pages = result.unwrap();
+    assert_eq!(pages[0].page, Some(1));
     assert!(
-        result.unwrap().contains("Invoice"),
+        pages[0].text.contains("Invoice"),
         "expected extracted text to contain page content"
     );
 
@@ -101,7 +103,7 @@ async fn given_valid_pdf_when_lm_studio_returns_error_status_then_returns_extrac
         data.len() as u64,
     );
 
-    let result = adapter.extract_text(&data, &document).await;
+    let result = adapter.extract_pages(&data, &document).await;
 
     assert!(
         matches!(result, Err(FileLoaderError::ExtractionFailed(_))),
@@ -121,7 +123,7 @@ async fn given_valid_pdf_when_lm_studio_returns_empty_content_then_returns_no_te
     let data = std::fs::read(sample_pdf_path()).expect("sample.pdf fixture missing");
     let document = Document::new("blank.pdf".to_string(), ContentType::Pdf, data.len() as u64);
 
-    let result = adapter.extract_text(&data, &document).await;
+    let result = adapter.extract_pages(&data, &document).await;
 
     assert!(
         matches!(result, Err(FileLoaderError::NoTextFound(_))),
@@ -141,7 +143,7 @@ async fn given_valid_pdf_when_lm_studio_returns_null_content_then_returns_no_tex
     let data = std::fs::read(sample_pdf_path()).expect("sample.pdf fixture missing");
     let document = Document::new("blank.pdf".to_string(), ContentType::Pdf, data.len() as u64);
 
-    let result = adapter.extract_text(&data, &document).await;
+    let result = adapter.extract_pages(&data, &document).await;
 
     assert!(
         matches!(result, Err(FileLoaderError::NoTextFound(_))),
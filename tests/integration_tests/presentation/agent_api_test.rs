@@ -1,22 +1,28 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
+use tokio::sync::Notify;
 use tower::ServiceExt;
 
 use sandakan::application::ports::{Embedder, TextSplitter};
 use sandakan::application::services::{
     AgentChatRequest, AgentChatResponse, AgentError, AgentProgressEvent, AgentServicePort,
-    IngestionMessage, IngestionService, RetrievalService,
+    ApiKeyService, DocumentService, IngestionService, RepositoryIngestionService, RetrievalService,
+    UrlIngestionService, WebhookService,
 };
 use sandakan::domain::ConversationId;
 use sandakan::infrastructure::llm::{MockEmbedder, MockLlmClient};
 use sandakan::infrastructure::persistence::{
-    MockConversationRepository, MockJobRepository, MockVectorStore,
+    MockApiKeyRepository, MockConversationRepository, MockDocumentRepository, MockJobRepository,
+    MockTranscriptRepository, MockVectorStore, MockWebhookRepository,
 };
+use sandakan::infrastructure::source_tree::GitCheckoutReader;
 use sandakan::infrastructure::storage::MockStagingStore;
 use sandakan::infrastructure::text_processing::MockFileLoader;
-use sandakan::presentation::config::{AgentSettings, EvalSettings};
+use sandakan::infrastructure::web::HttpWebFetcher;
+use sandakan::presentation::config::{AgentSettings, EvalSettings, TenancySettings};
 use sandakan::presentation::{AppState, Settings, create_router};
 
 const TEST_CHUNK_SIZE: usize = 512;
@@ -58,8 +64,9 @@ fn test_settings() -> Settings {
     use sandakan::presentation::config::{
         AudioExtractionSettings, ChunkingSettings, ChunkingStrategy, DatabaseSettings,
         EmbeddingProvider, EmbeddingsSettings, ExtractionSettings, LlmSettings, LoggingSettings,
-        PdfExtractionSettings, QdrantSettings, RagSettings, ServerSettings, StorageProviderSetting,
-        StorageSettings, TranscriptionProviderSetting, VideoExtractionSettings,
+        PdfExtractionSettings, PdfFallbackProvider, QdrantSettings, RagSettings, ServerSettings,
+        StorageProviderSetting, StorageSettings, TranscriptionProviderSetting,
+        VideoExtractionSettings,
     };
 
     Settings {
@@ -71,6 +78,7 @@ fn test_settings() -> Settings {
             url: "http://localhost:6334".to_string(),
             collection_name: "test".to_string(),
             hybrid_search: false,
+            bm25: sandakan::presentation::config::Bm25Settings::default(),
         },
         database: DatabaseSettings {
             url: "postgres://test".to_string(),
@@ -123,6 +131,8 @@ fn test_settings() -> Settings {
                 vlm_api_key: None,
                 azure_endpoint: None,
                 azure_key: None,
+                fallback_provider: PdfFallbackProvider::None,
+                min_page_chars: 50,
             },
             audio: AudioExtractionSettings {
                 enabled: true,
@@ -134,6 +144,9 @@ fn test_settings() -> Settings {
                 azure_key: None,
                 azure_api_version: None,
                 asr_corrections: Default::default(),
+                language: None,
+                translate: false,
+                word_timestamps: false,
             },
             video: VideoExtractionSettings {
                 enabled: true,
@@ -146,18 +159,18 @@ fn test_settings() -> Settings {
             top_k: TEST_TOP_K,
             system_prompt: "test prompt".to_string(),
             fallback_message: TEST_FALLBACK_MESSAGE.to_string(),
+            query_transform: sandakan::presentation::config::QueryTransformSettings::default(),
         },
         eval: EvalSettings::default(),
         agent: AgentSettings::default(),
+        tenancy: TenancySettings::default(),
+        auth: sandakan::presentation::config::AuthSettings::default(),
+        webhooks: sandakan::presentation::config::WebhookSettings::default(),
+        rerank: sandakan::presentation::config::RerankSettings::default(),
+        ingestion: sandakan::presentation::config::IngestionSettings::default(),
     }
 }
 
-fn create_ingestion_sender() -> tokio::sync::mpsc::Sender<IngestionMessage> {
-    let (sender, mut receiver) = tokio::sync::mpsc::channel(16);
-    tokio::spawn(async move { while receiver.recv().await.is_some() {} });
-    sender
-}
-
 fn create_test_app_with_agent(agent_service: Option<Arc<dyn AgentServicePort>>) -> axum::Router {
     use sandakan::infrastructure::text_processing::RecursiveCharacterSplitter;
 
@@ -199,14 +212,44 @@ fn create_test_app_with_agent(agent_service: Option<Arc<dyn AgentServicePort>>)
         TEST_FALLBACK_MESSAGE.to_string(),
     ));
 
+    let document_service = Arc::new(DocumentService::new(
+        Arc::new(MockDocumentRepository),
+        Arc::new(MockJobRepository),
+        Arc::clone(&vector_store),
+        Arc::new(MockStagingStore),
+        Arc::new(MockTranscriptRepository),
+    ));
+
+    let url_ingestion_service = Arc::new(UrlIngestionService::new(
+        Arc::new(HttpWebFetcher::new("sandakan-test", Duration::from_secs(5), 1024).unwrap()),
+        Arc::new(MockStagingStore),
+        Arc::clone(&document_service),
+        Arc::new(MockJobRepository),
+    ));
+
+    let repository_ingestion_service = Arc::new(RepositoryIngestionService::new(
+        Arc::new(GitCheckoutReader::new()),
+        Arc::new(MockStagingStore),
+        Arc::clone(&document_service),
+        Arc::new(MockJobRepository),
+    ));
+
     let state = AppState {
         ingestion_service,
         retrieval_service,
+        document_service,
+        url_ingestion_service,
+        repository_ingestion_service,
         conversation_repository: Arc::new(MockConversationRepository),
         job_repository: Arc::new(MockJobRepository),
-        ingestion_sender: create_ingestion_sender(),
+        job_events: Arc::new(
+            sandakan::infrastructure::persistence::BroadcastJobEventBus::default(),
+        ),
         staging_store: Arc::new(MockStagingStore),
+        ingestion_wakeup: Arc::new(Notify::new()),
         agent_service,
+        api_key_service: Arc::new(ApiKeyService::new(Arc::new(MockApiKeyRepository))),
+        webhook_service: Arc::new(WebhookService::new(Arc::new(MockWebhookRepository))),
         settings: test_settings(),
     };
 
@@ -1,22 +1,34 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use axum::body::Body;
 use axum::http::{Request, StatusCode};
+use tokio::sync::Notify;
 use tower::ServiceExt;
 
-use sandakan::application::ports::{Embedder, TextSplitter};
-use sandakan::application::services::{IngestionMessage, IngestionService, RetrievalService};
+use sandakan::application::ports::{
+    ApiKeyRepository, Embedder, JobEventBus, RepositoryError, TextSplitter,
+};
+use sandakan::application::services::{
+    ApiKeyService, DocumentService, IngestionService, RepositoryIngestionService, RetrievalService,
+    UrlIngestionService, WebhookService,
+};
+use sandakan::domain::{ApiKey, ApiKeyId, ApiScope, JobEvent, JobId, JobStatus, TenantId};
 use sandakan::infrastructure::llm::{MockEmbedder, MockLlmClient};
 use sandakan::infrastructure::persistence::{
-    MockConversationRepository, MockJobRepository, MockVectorStore, MockVectorStoreLowScore,
+    BroadcastJobEventBus, MockApiKeyRepository, MockConversationRepository, MockDocumentRepository,
+    MockJobRepository, MockTranscriptRepository, MockVectorStore, MockVectorStoreLowScore,
+    MockWebhookRepository,
 };
+use sandakan::infrastructure::source_tree::GitCheckoutReader;
 use sandakan::infrastructure::storage::MockStagingStore;
 use sandakan::infrastructure::text_processing::MockFileLoader;
+use sandakan::infrastructure::web::HttpWebFetcher;
 use sandakan::presentation::config::{
     AudioExtractionSettings, ChunkingSettings, ChunkingStrategy, DatabaseSettings,
     EmbeddingProvider, EmbeddingsSettings, ExtractionSettings, LlmSettings, LoggingSettings,
-    PdfExtractionSettings, QdrantSettings, RagSettings, ServerSettings, StorageProviderSetting,
-    StorageSettings, TranscriptionProviderSetting, VideoExtractionSettings,
+    PdfExtractionSettings, PdfFallbackProvider, QdrantSettings, RagSettings, ServerSettings,
+    StorageProviderSetting, StorageSettings, TranscriptionProviderSetting, VideoExtractionSettings,
 };
 use sandakan::presentation::{AppState, Settings, create_router};
 
@@ -37,6 +49,7 @@ fn test_settings() -> Settings {
             url: "http://localhost:6334".to_string(),
             collection_name: "test".to_string(),
             hybrid_search: false,
+            bm25: sandakan::presentation::config::Bm25Settings::default(),
         },
         database: DatabaseSettings {
             url: "postgres://test".to_string(),
@@ -89,6 +102,8 @@ fn test_settings() -> Settings {
                 vlm_api_key: None,
                 azure_endpoint: None,
                 azure_key: None,
+                fallback_provider: PdfFallbackProvider::None,
+                min_page_chars: 50,
             },
             audio: AudioExtractionSettings {
                 enabled: true,
@@ -100,6 +115,9 @@ fn test_settings() -> Settings {
                 azure_key: None,
                 azure_api_version: None,
                 asr_corrections: Default::default(),
+                language: None,
+                translate: false,
+                word_timestamps: false,
             },
             video: VideoExtractionSettings {
                 enabled: true,
@@ -112,19 +130,46 @@ fn test_settings() -> Settings {
             top_k: TEST_TOP_K,
             system_prompt: "test prompt".to_string(),
             fallback_message: TEST_FALLBACK_MESSAGE.to_string(),
+            query_transform: sandakan::presentation::config::QueryTransformSettings::default(),
         },
         eval: sandakan::presentation::config::EvalSettings::default(),
         agent: sandakan::presentation::config::AgentSettings::default(),
+        tenancy: sandakan::presentation::config::TenancySettings::default(),
+        auth: sandakan::presentation::config::AuthSettings::default(),
+        webhooks: sandakan::presentation::config::WebhookSettings::default(),
+        rerank: sandakan::presentation::config::RerankSettings::default(),
+        ingestion: sandakan::presentation::config::IngestionSettings::default(),
     }
 }
 
-fn create_ingestion_sender() -> tokio::sync::mpsc::Sender<IngestionMessage> {
-    let (sender, mut receiver) = tokio::sync::mpsc::channel(16);
-    tokio::spawn(async move { while receiver.recv().await.is_some() {} });
-    sender
+fn create_test_app() -> axum::Router {
+    create_test_app_with_settings(test_settings())
 }
 
-fn create_test_app() -> axum::Router {
+fn create_test_app_with_settings(settings: Settings) -> axum::Router {
+    create_test_app_with_api_keys(settings, Arc::new(MockApiKeyRepository))
+}
+
+fn create_test_app_with_api_keys(
+    settings: Settings,
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+) -> axum::Router {
+    build_test_app(
+        settings,
+        api_key_repository,
+        Arc::new(BroadcastJobEventBus::default()),
+    )
+}
+
+fn create_test_app_with_job_events(job_events: Arc<dyn JobEventBus>) -> axum::Router {
+    build_test_app(test_settings(), Arc::new(MockApiKeyRepository), job_events)
+}
+
+fn build_test_app(
+    settings: Settings,
+    api_key_repository: Arc<dyn ApiKeyRepository>,
+    job_events: Arc<dyn JobEventBus>,
+) -> axum::Router {
     use sandakan::infrastructure::text_processing::RecursiveCharacterSplitter;
 
     let file_loader = Arc::new(MockFileLoader);
@@ -165,15 +210,43 @@ fn create_test_app() -> axum::Router {
         TEST_FALLBACK_MESSAGE.to_string(),
     ));
 
+    let document_service = Arc::new(DocumentService::new(
+        Arc::new(MockDocumentRepository),
+        Arc::new(MockJobRepository),
+        Arc::clone(&vector_store),
+        Arc::new(MockStagingStore),
+        Arc::new(MockTranscriptRepository),
+    ));
+
+    let url_ingestion_service = Arc::new(UrlIngestionService::new(
+        Arc::new(HttpWebFetcher::new("sandakan-test", Duration::from_secs(5), 1024).unwrap()),
+        Arc::new(MockStagingStore),
+        Arc::clone(&document_service),
+        Arc::new(MockJobRepository),
+    ));
+
+    let repository_ingestion_service = Arc::new(RepositoryIngestionService::new(
+        Arc::new(GitCheckoutReader::new()),
+        Arc::new(MockStagingStore),
+        Arc::clone(&document_service),
+        Arc::new(MockJobRepository),
+    ));
+
     let state = AppState {
         ingestion_service,
         retrieval_service,
+        document_service,
+        url_ingestion_service,
+        repository_ingestion_service,
         conversation_repository: Arc::new(MockConversationRepository),
         job_repository: Arc::new(MockJobRepository),
-        ingestion_sender: create_ingestion_sender(),
+        job_events,
         staging_store: Arc::new(MockStagingStore),
+        ingestion_wakeup: Arc::new(Notify::new()),
         agent_service: None,
-        settings: test_settings(),
+        api_key_service: Arc::new(ApiKeyService::new(api_key_repository)),
+        webhook_service: Arc::new(WebhookService::new(Arc::new(MockWebhookRepository))),
+        settings,
     };
 
     create_router(state)
@@ -196,6 +269,106 @@ async fn given_running_server_when_health_check_then_returns_ok() {
     assert_eq!(response.status(), StatusCode::OK);
 }
 
+#[tokio::test]
+async fn given_empty_catalog_when_listing_documents_then_returns_empty_page() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/documents?limit=10")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["documents"].as_array().unwrap().len(), 0);
+    assert_eq!(json["limit"], 10);
+}
+
+#[tokio::test]
+async fn given_invalid_document_id_when_getting_document_then_returns_bad_request() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/documents/not-a-uuid")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn given_unknown_document_when_deleting_then_returns_not_found() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("DELETE")
+                .uri(format!("/api/v1/documents/{}", uuid::Uuid::new_v4()))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn given_unknown_document_when_getting_transcript_then_returns_not_found() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/api/v1/documents/{}/transcript?format=srt",
+                    uuid::Uuid::new_v4()
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn given_unparseable_captions_when_correcting_transcript_then_returns_bad_request() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("PUT")
+                .uri(format!(
+                    "/api/v1/documents/{}/transcript",
+                    uuid::Uuid::new_v4()
+                ))
+                .header("content-type", "text/vtt")
+                .body(Body::from("WEBVTT\n\nno cues here\n"))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn given_valid_question_when_query_endpoint_then_returns_answer() {
     let app = create_test_app();
@@ -234,6 +407,48 @@ async fn given_missing_body_when_query_endpoint_then_returns_bad_request() {
     assert_eq!(response.status(), StatusCode::BAD_REQUEST);
 }
 
+#[tokio::test]
+async fn given_metadata_filter_when_query_endpoint_then_returns_answer() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/query")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"question": "What is RAG?", "filter": {"content_types": ["application/pdf"], "ingested_after": "2025-01-01T00:00:00Z"}}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn given_invalid_metadata_filter_when_query_endpoint_then_returns_bad_request() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/query")
+                .header("content-type", "application/json")
+                .body(Body::from(
+                    r#"{"question": "What is RAG?", "filter": {"document_ids": ["not-a-uuid"]}}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn given_openwebui_when_requesting_models_then_returns_model_list() {
     let app = create_test_app();
@@ -398,14 +613,42 @@ async fn given_low_similarity_when_chat_completions_then_returns_fallback() {
         TEST_FALLBACK_MESSAGE.to_string(),
     ));
 
+    let document_service = Arc::new(DocumentService::new(
+        Arc::new(MockDocumentRepository),
+        Arc::new(MockJobRepository),
+        Arc::clone(&vector_store),
+        Arc::new(MockStagingStore),
+        Arc::new(MockTranscriptRepository),
+    ));
+
+    let url_ingestion_service = Arc::new(UrlIngestionService::new(
+        Arc::new(HttpWebFetcher::new("sandakan-test", Duration::from_secs(5), 1024).unwrap()),
+        Arc::new(MockStagingStore),
+        Arc::clone(&document_service),
+        Arc::new(MockJobRepository),
+    ));
+
+    let repository_ingestion_service = Arc::new(RepositoryIngestionService::new(
+        Arc::new(GitCheckoutReader::new()),
+        Arc::new(MockStagingStore),
+        Arc::clone(&document_service),
+        Arc::new(MockJobRepository),
+    ));
+
     let state = AppState {
         ingestion_service,
         retrieval_service,
+        document_service,
+        url_ingestion_service,
+        repository_ingestion_service,
         conversation_repository: Arc::new(MockConversationRepository),
         job_repository: Arc::new(MockJobRepository),
-        ingestion_sender: create_ingestion_sender(),
+        job_events: Arc::new(BroadcastJobEventBus::default()),
         staging_store: Arc::new(MockStagingStore),
+        ingestion_wakeup: Arc::new(Notify::new()),
         agent_service: None,
+        api_key_service: Arc::new(ApiKeyService::new(Arc::new(MockApiKeyRepository))),
+        webhook_service: Arc::new(WebhookService::new(Arc::new(MockWebhookRepository))),
         settings: test_settings(),
     };
 
@@ -475,6 +718,132 @@ async fn given_nonexistent_job_when_job_status_then_returns_not_found() {
     assert_eq!(response.status(), StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn given_no_jobs_when_listing_jobs_then_returns_empty_page() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/jobs?status=FAILED&created_after=2026-01-01T00:00:00Z&limit=10")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["jobs"], serde_json::json!([]));
+    assert_eq!(json["limit"], 10);
+}
+
+#[tokio::test]
+async fn given_unknown_status_when_listing_jobs_then_returns_bad_request() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/jobs?status=SLEEPING")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn given_event_stream_when_jobs_change_then_streams_only_own_tenant_events() {
+    use futures::StreamExt;
+
+    let job_events = Arc::new(BroadcastJobEventBus::default());
+    let app = create_test_app_with_job_events(job_events.clone());
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/jobs/events")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let own_job = JobId::new();
+    let other_tenant = TenantId::parse("other-tenant").unwrap();
+    job_events
+        .publish(&JobEvent::status_changed(
+            JobId::new(),
+            other_tenant,
+            JobStatus::Embedding,
+            None,
+        ))
+        .await;
+    job_events
+        .publish(&JobEvent::status_changed(
+            own_job,
+            TenantId::default(),
+            JobStatus::Transcribing,
+            None,
+        ))
+        .await;
+
+    let mut body = response.into_body().into_data_stream();
+    let frame = tokio::time::timeout(std::time::Duration::from_secs(2), body.next())
+        .await
+        .expect("no event streamed")
+        .unwrap()
+        .unwrap();
+    let frame = String::from_utf8(frame.to_vec()).unwrap();
+
+    assert!(frame.starts_with("event: status\n"));
+    assert!(frame.contains(&own_job.as_uuid().to_string()));
+    assert!(frame.contains("TRANSCRIBING"));
+}
+
+#[tokio::test]
+async fn given_nonexistent_job_when_cancelling_then_returns_not_found() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/jobs/00000000-0000-0000-0000-000000000000/cancel")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn given_invalid_uuid_when_retrying_job_then_returns_bad_request() {
+    let app = create_test_app();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/jobs/not-a-uuid/retry")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn given_valid_reference_when_ingesting_then_returns_accepted() {
     let app = create_test_app();
@@ -562,3 +931,496 @@ async fn given_unsupported_content_type_when_ingesting_reference_then_returns_41
 
     assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
 }
+
+fn create_keyed_test_app() -> axum::Router {
+    let mut settings = test_settings();
+    settings
+        .tenancy
+        .api_keys
+        .insert("acme-key".to_string(), "acme".to_string());
+    create_test_app_with_settings(settings)
+}
+
+async fn get_documents(app: axum::Router, headers: &[(&str, &str)]) -> StatusCode {
+    let mut request = Request::builder().uri("/api/v1/documents");
+    for (name, value) in headers {
+        request = request.header(*name, *value);
+    }
+    app.oneshot(request.body(Body::empty()).unwrap())
+        .await
+        .unwrap()
+        .status()
+}
+
+#[tokio::test]
+async fn given_invalid_tenant_header_when_listing_documents_then_returns_bad_request() {
+    let status = get_documents(create_test_app(), &[("x-tenant-id", "acme corp")]).await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn given_tenant_header_without_api_keys_when_listing_documents_then_returns_ok() {
+    let status = get_documents(create_test_app(), &[("x-tenant-id", "acme")]).await;
+
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn given_api_keys_configured_when_request_has_no_key_then_returns_unauthorized() {
+    let status = get_documents(create_keyed_test_app(), &[]).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn given_api_keys_configured_when_request_has_unknown_key_then_returns_unauthorized() {
+    let status = get_documents(
+        create_keyed_test_app(),
+        &[("authorization", "Bearer wrong-key")],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn given_valid_key_when_requesting_another_tenant_then_returns_forbidden() {
+    let status = get_documents(
+        create_keyed_test_app(),
+        &[
+            ("authorization", "Bearer acme-key"),
+            ("x-tenant-id", "globex"),
+        ],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn given_valid_key_when_listing_documents_then_returns_ok() {
+    let status = get_documents(
+        create_keyed_test_app(),
+        &[
+            ("authorization", "Bearer acme-key"),
+            ("x-tenant-id", "acme"),
+        ],
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn given_api_keys_configured_when_health_check_then_does_not_require_key() {
+    let response = create_keyed_test_app()
+        .oneshot(
+            Request::builder()
+                .uri("/health")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// Resolves exactly one stored key, as the Postgres repository would.
+struct SingleKeyRepository {
+    key: ApiKey,
+}
+
+#[async_trait::async_trait]
+impl ApiKeyRepository for SingleKeyRepository {
+    async fn create(&self, _key: &ApiKey) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn find_active_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        Ok((self.key.key_hash == key_hash).then(|| self.key.clone()))
+    }
+
+    async fn list(&self, _tenant_id: Option<&TenantId>) -> Result<Vec<ApiKey>, RepositoryError> {
+        Ok(vec![self.key.clone()])
+    }
+
+    async fn revoke(
+        &self,
+        _id: ApiKeyId,
+        _tenant_id: Option<&TenantId>,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+}
+
+/// Auth-enabled app holding a single stored key with `scopes`; returns the key's secret.
+fn create_authenticated_test_app(scopes: Vec<ApiScope>) -> (axum::Router, String) {
+    let (key, secret) = ApiKey::generate("test", TenantId::default(), scopes);
+    let mut settings = test_settings();
+    settings.auth.enabled = true;
+    let app = create_test_app_with_api_keys(settings, Arc::new(SingleKeyRepository { key }));
+    (app, format!("Bearer {secret}"))
+}
+
+#[tokio::test]
+async fn given_auth_enabled_when_request_has_no_key_then_returns_unauthorized() {
+    let (app, _) = create_authenticated_test_app(vec![ApiScope::Ingest]);
+
+    let status = get_documents(app, &[]).await;
+
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn given_ingest_scoped_key_when_listing_documents_then_returns_ok() {
+    let (app, bearer) = create_authenticated_test_app(vec![ApiScope::Ingest]);
+
+    let status = get_documents(app, &[("authorization", &bearer)]).await;
+
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn given_query_scoped_key_when_listing_documents_then_returns_forbidden() {
+    let (app, bearer) = create_authenticated_test_app(vec![ApiScope::Query]);
+
+    let status = get_documents(app, &[("authorization", &bearer)]).await;
+
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn given_query_scoped_key_when_querying_then_returns_answer() {
+    let (app, bearer) = create_authenticated_test_app(vec![ApiScope::Query]);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/query")
+                .header("content-type", "application/json")
+                .header("authorization", bearer)
+                .body(Body::from(r#"{"question": "What is RAG?"}"#))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn given_query_scoped_key_when_listing_api_keys_then_returns_forbidden() {
+    let (app, bearer) = create_authenticated_test_app(vec![ApiScope::Query]);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/admin/api-keys")
+                .header("authorization", bearer)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn given_admin_key_when_listing_api_keys_then_hashes_are_not_exposed() {
+    let (app, bearer) = create_authenticated_test_app(vec![ApiScope::Admin]);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/admin/api-keys")
+                .header("authorization", bearer)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    let key = &json["api_keys"][0];
+    assert_eq!(key["scopes"], serde_json::json!(["admin"]));
+    assert!(key.get("key_hash").is_none());
+    assert!(key.get("secret").is_none());
+}
+
+#[tokio::test]
+async fn given_static_bootstrap_key_when_minting_api_key_then_returns_secret_once() {
+    let response = create_keyed_test_app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/admin/api-keys")
+                .header("content-type", "application/json")
+                .header("authorization", "Bearer acme-key")
+                .body(Body::from(
+                    r#"{"name": "open-webui", "scopes": ["query", "agent"]}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(json["secret"].as_str().unwrap().starts_with("sk-"));
+    assert_eq!(json["tenant_id"], "acme");
+    assert_eq!(json["scopes"], serde_json::json!(["agent", "query"]));
+}
+
+#[tokio::test]
+async fn given_tenant_admin_key_when_minting_for_another_tenant_then_returns_forbidden() {
+    let (app, bearer) = create_authenticated_test_app(vec![ApiScope::Admin]);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/admin/api-keys")
+                .header("content-type", "application/json")
+                .header("authorization", bearer)
+                .body(Body::from(
+                    r#"{"name": "escape", "tenant_id": "acme", "scopes": ["ingest"]}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn given_tenant_admin_key_when_listing_another_tenant_then_returns_forbidden() {
+    let (app, bearer) = create_authenticated_test_app(vec![ApiScope::Admin]);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/v1/admin/api-keys?tenant_id=acme")
+                .header("authorization", bearer)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn given_static_key_when_minting_for_another_tenant_then_returns_created() {
+    let response = create_keyed_test_app()
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/v1/admin/api-keys")
+                .header("content-type", "application/json")
+                .header("authorization", "Bearer acme-key")
+                .body(Body::from(
+                    r#"{"name": "physics", "tenant_id": "physics", "scopes": ["query"]}"#,
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(json["tenant_id"], "physics");
+}
+
+#[tokio::test]
+async fn given_auth_disabled_when_client_sends_bearer_token_then_token_is_ignored() {
+    let response = create_test_app()
+        .oneshot(
+            Request::builder()
+                .uri("/v1/models")
+                .header("authorization", "Bearer sk-anything")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+fn create_webhook_request(body: &'static str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/api/v1/webhooks")
+        .header("content-type", "application/json")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn given_valid_subscription_when_creating_webhook_then_returns_signing_secret() {
+    let response = create_test_app()
+        .oneshot(create_webhook_request(
+            r#"{"url": "https://hooks.example.com/sandakan", "event_types": ["job.failed", "document.ingested"]}"#,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(json["secret"].as_str().unwrap().starts_with("whsec_"));
+    assert_eq!(
+        json["event_types"],
+        serde_json::json!(["document.ingested", "job.failed"])
+    );
+}
+
+#[tokio::test]
+async fn given_relative_url_when_creating_webhook_then_returns_bad_request() {
+    let response = create_test_app()
+        .oneshot(create_webhook_request(
+            r#"{"url": "/hooks", "event_types": ["job.failed"]}"#,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn given_unknown_event_type_when_creating_webhook_then_returns_unprocessable_entity() {
+    let response = create_test_app()
+        .oneshot(create_webhook_request(
+            r#"{"url": "https://hooks.example.com", "event_types": ["document.deleted"]}"#,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn given_unknown_webhook_when_listing_deliveries_then_returns_not_found() {
+    let response = create_test_app()
+        .oneshot(
+            Request::builder()
+                .uri(format!(
+                    "/api/v1/webhooks/{}/deliveries",
+                    uuid::Uuid::new_v4()
+                ))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+fn multipart_ingest_request(fields: &[(&str, &str)]) -> Request<Body> {
+    let boundary = "sandakan-test-boundary";
+    let mut body = String::new();
+    for (name, value) in fields {
+        body.push_str(&format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+        ));
+    }
+    body.push_str(&format!(
+        "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"notes.txt\"\r\n\
+         Content-Type: text/plain\r\n\r\nSome notes.\r\n--{boundary}--\r\n"
+    ));
+    Request::builder()
+        .method("POST")
+        .uri("/api/v1/ingest")
+        .header(
+            "content-type",
+            format!("multipart/form-data; boundary={boundary}"),
+        )
+        .body(Body::from(body))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn given_unknown_form_field_when_ingesting_then_returns_bad_request() {
+    let response = create_test_app()
+        .oneshot(multipart_ingest_request(&[(
+            "sorce_url",
+            "https://example.com",
+        )]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert!(json["error"].as_str().unwrap().contains("sorce_url"));
+}
+
+#[tokio::test]
+async fn given_attributes_that_are_not_a_string_map_when_ingesting_then_returns_bad_request() {
+    let response = create_test_app()
+        .oneshot(multipart_ingest_request(&[
+            ("title", "Notes"),
+            ("attributes", r#"{"year": 2024}"#),
+        ]))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+fn ingest_reference_request(body: &'static str) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/api/v1/ingest-reference")
+        .header("content-type", "application/json")
+        .body(Body::from(body))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn given_reference_with_source_metadata_when_ingesting_then_returns_accepted() {
+    let response = create_test_app()
+        .oneshot(ingest_reference_request(
+            r#"{"storage_path": "some/path/video.mp4", "filename": "video.mp4", "content_type": "video/mp4",
+                "source_url": "https://www.youtube.com/watch?v=abc123", "title": "Entropy explained",
+                "author": "Prof. Lee", "tags": ["physics"], "attributes": {"course": "PHYS-201"}}"#,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::ACCEPTED);
+}
+
+#[tokio::test]
+async fn given_reference_with_relative_source_url_when_ingesting_then_returns_bad_request() {
+    let response = create_test_app()
+        .oneshot(ingest_reference_request(
+            r#"{"storage_path": "some/path/video.mp4", "filename": "video.mp4", "content_type": "video/mp4",
+                "source_url": "watch?v=abc123"}"#,
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
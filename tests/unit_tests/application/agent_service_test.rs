@@ -12,7 +12,8 @@ use sandakan::application::services::{
     AgentChatRequest, AgentError, AgentProgressEvent, AgentService, AgentServicePort,
 };
 use sandakan::domain::{
-    Conversation, ConversationId, EvalSource, Message, ToolCall, ToolCallId, ToolName, ToolResult,
+    Conversation, ConversationId, EvalSource, Message, TenantId, ToolCall, ToolCallId, ToolName,
+    ToolResult,
 };
 use sandakan::presentation::config::{AgentServiceConfig, ReflectionSettings};
 // ─── Mock: LLM returns ToolCalls on first call, Content on second ─────────────
@@ -180,6 +181,13 @@ impl ConversationRepository for MockConversationRepository {
         Ok(None)
     }
 
+    async fn get_conversation_tenant(
+        &self,
+        _id: ConversationId,
+    ) -> Result<Option<TenantId>, RepositoryError> {
+        Ok(None)
+    }
+
     async fn append_message(&self, _message: &Message) -> Result<(), RepositoryError> {
         Ok(())
     }
@@ -294,6 +302,7 @@ async fn given_llm_returns_tool_call_then_content_when_chatting_then_react_loop_
         conversation_id: None,
         user_message: "What is the news?".to_string(),
         correlation_id: None,
+        tenant_id: TenantId::default(),
     };
 
     let result = service.chat(request).await;
@@ -320,6 +329,7 @@ async fn given_llm_always_returns_tool_calls_when_chatting_then_returns_max_iter
         conversation_id: None,
         user_message: "Loop forever".to_string(),
         correlation_id: None,
+        tenant_id: TenantId::default(),
     };
 
     let result = service.chat(request).await;
@@ -349,6 +359,7 @@ async fn given_mcp_client_fails_when_fail_fast_true_then_agent_returns_hard_tool
             conversation_id: None,
             user_message: "Search something".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await;
     let err = result.err();
@@ -369,6 +380,7 @@ async fn given_llm_returns_content_immediately_when_chatting_then_no_progress_ev
         conversation_id: None,
         user_message: "Simple question".to_string(),
         correlation_id: None,
+        tenant_id: TenantId::default(),
     };
 
     let result = service.chat(request).await.unwrap();
@@ -485,6 +497,7 @@ async fn given_llm_returns_two_tool_calls_when_executing_then_both_results_appea
             conversation_id: None,
             user_message: "Do two things".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -541,6 +554,7 @@ async fn given_one_of_two_parallel_tool_calls_fails_when_fail_fast_true_then_age
             conversation_id: None,
             user_message: "Do two things, one will fail".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await;
 
@@ -588,6 +602,7 @@ impl McpClientPort for MockMcpRagSearch {
             self.collector.collect(vec![EvalSource {
                 text: "retrieved chunk text".to_string(),
                 page: Some(1),
+                end_page: Some(1),
                 score: 0.9,
             }]);
         }
@@ -668,6 +683,7 @@ async fn given_rag_tool_collects_sources_when_eval_enabled_then_eval_event_has_n
             conversation_id: None,
             user_message: "What does the KB say?".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -700,6 +716,7 @@ async fn given_no_rag_tool_invoked_when_eval_fires_then_eval_event_has_empty_sou
             conversation_id: None,
             user_message: "No RAG needed".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -723,6 +740,7 @@ async fn given_llm_returns_content_when_chatting_then_token_stream_yields_real_t
             conversation_id: None,
             user_message: "Stream me an answer".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -762,6 +780,7 @@ async fn given_tool_fails_with_execution_error_when_fail_fast_false_then_agent_c
             conversation_id: None,
             user_message: "Search something".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await;
 
@@ -864,6 +883,7 @@ async fn given_tool_exceeds_timeout_when_fail_fast_false_then_agent_sees_timeout
             conversation_id: None,
             user_message: "Call a slow tool".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await;
 
@@ -901,6 +921,7 @@ async fn given_tool_not_found_when_fail_fast_false_then_agent_still_returns_hard
             conversation_id: None,
             user_message: "Use missing tool".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await;
 
@@ -1028,6 +1049,7 @@ async fn given_one_of_two_parallel_tools_fails_when_fail_fast_false_then_other_r
             conversation_id: None,
             user_message: "Two parallel tools, one fails".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await;
 
@@ -1161,6 +1183,7 @@ async fn given_high_quality_answer_when_reflection_enabled_then_no_correction_pa
             conversation_id: None,
             user_message: "What is Rust?".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -1218,6 +1241,7 @@ async fn given_low_score_answer_when_reflection_enabled_then_correction_message_
             conversation_id: None,
             user_message: "Explain something complex".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -1272,6 +1296,7 @@ async fn given_correction_budget_zero_when_score_below_threshold_then_original_a
             conversation_id: None,
             user_message: "Budget is zero".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -1412,6 +1437,7 @@ async fn given_correction_budget_3_when_score_always_low_then_all_three_correcti
             conversation_id: None,
             user_message: "explain something".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -1460,6 +1486,7 @@ async fn given_correction_budget_5_when_second_correction_passes_threshold_then_
             conversation_id: None,
             user_message: "explain something".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -1564,6 +1591,7 @@ async fn given_reflection_disabled_when_agent_runs_then_critic_llm_not_called()
             conversation_id: None,
             user_message: "Simple question".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -1605,6 +1633,7 @@ async fn given_critic_returns_unparseable_response_when_reflecting_then_answer_r
             conversation_id: None,
             user_message: "What does the critic say?".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed even with unparseable critic response");
@@ -1717,6 +1746,7 @@ async fn given_tool_result_with_ascii_content_when_truncating_then_progress_even
             conversation_id: None,
             user_message: "trigger ascii tool".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("should not panic on ascii content");
@@ -1753,6 +1783,7 @@ async fn given_tool_result_with_multibyte_utf8_when_truncating_then_no_panic_and
             conversation_id: None,
             user_message: "trigger multibyte tool".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("must not panic on multibyte UTF-8 content");
@@ -1804,6 +1835,7 @@ async fn given_tool_result_with_emoji_content_when_truncating_then_no_panic_and_
             conversation_id: None,
             user_message: "trigger emoji tool".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("must not panic on emoji content");
@@ -1852,6 +1884,7 @@ async fn given_tool_result_shorter_than_limit_when_truncating_then_content_retur
             conversation_id: None,
             user_message: "trigger short tool".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("should succeed");
@@ -1938,6 +1971,7 @@ async fn given_configured_system_prompt_when_agent_chat_called_then_system_messa
             conversation_id: None,
             user_message: "hello".to_string(),
             correlation_id: None,
+            tenant_id: TenantId::default(),
         })
         .await
         .expect("chat should succeed");
@@ -0,0 +1,178 @@
+use std::sync::{Arc, RwLock};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sandakan::application::ports::{ApiKeyRepository, RepositoryError};
+use sandakan::application::services::{ApiKeyService, ApiKeyServiceError};
+use sandakan::domain::{ApiKey, ApiKeyId, ApiScope, TenantId};
+
+#[derive(Default)]
+struct InMemoryApiKeyRepository {
+    keys: RwLock<Vec<ApiKey>>,
+}
+
+#[async_trait]
+impl ApiKeyRepository for InMemoryApiKeyRepository {
+    async fn create(&self, key: &ApiKey) -> Result<(), RepositoryError> {
+        self.keys.write().unwrap().push(key.clone());
+        Ok(())
+    }
+
+    async fn find_active_by_hash(&self, key_hash: &str) -> Result<Option<ApiKey>, RepositoryError> {
+        Ok(self
+            .keys
+            .read()
+            .unwrap()
+            .iter()
+            .find(|k| k.key_hash == key_hash && !k.is_revoked())
+            .cloned())
+    }
+
+    async fn list(&self, tenant_id: Option<&TenantId>) -> Result<Vec<ApiKey>, RepositoryError> {
+        Ok(self
+            .keys
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|k| tenant_id.is_none_or(|t| k.tenant_id == *t))
+            .cloned()
+            .collect())
+    }
+
+    async fn revoke(
+        &self,
+        id: ApiKeyId,
+        tenant_id: Option<&TenantId>,
+    ) -> Result<bool, RepositoryError> {
+        let mut keys = self.keys.write().unwrap();
+        match keys
+            .iter_mut()
+            .find(|k| k.id == id && !k.is_revoked() && tenant_id.is_none_or(|t| k.tenant_id == *t))
+        {
+            Some(key) => {
+                key.revoked_at = Some(Utc::now());
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+fn service() -> (ApiKeyService, Arc<InMemoryApiKeyRepository>) {
+    let repository = Arc::new(InMemoryApiKeyRepository::default());
+    (
+        ApiKeyService::new(Arc::clone(&repository) as Arc<dyn ApiKeyRepository>),
+        repository,
+    )
+}
+
+fn acme() -> TenantId {
+    TenantId::parse("acme").unwrap()
+}
+
+#[tokio::test]
+async fn given_minted_key_when_authenticating_with_its_secret_then_returns_principal_of_key() {
+    let (service, _) = service();
+    let (key, secret) = service
+        .mint("ingest bot", acme(), vec![ApiScope::Ingest])
+        .await
+        .unwrap();
+
+    let principal = service.authenticate(&secret).await.unwrap().unwrap();
+
+    assert_eq!(principal.tenant_id, acme());
+    assert_eq!(principal.scopes, vec![ApiScope::Ingest]);
+    assert_eq!(principal.subject, format!("api_key:{}", key.id));
+}
+
+#[tokio::test]
+async fn given_minted_key_when_stored_then_secret_is_not_persisted() {
+    let (service, repository) = service();
+    let (_, secret) = service
+        .mint("reader", acme(), vec![ApiScope::Query])
+        .await
+        .unwrap();
+
+    let stored = repository.keys.read().unwrap();
+    assert_eq!(stored.len(), 1);
+    assert_ne!(stored[0].key_hash, secret);
+}
+
+#[tokio::test]
+async fn given_revoked_key_when_authenticating_then_returns_none() {
+    let (service, _) = service();
+    let (key, secret) = service
+        .mint("temp", acme(), vec![ApiScope::Query])
+        .await
+        .unwrap();
+
+    service.revoke(key.id, Some(&acme())).await.unwrap();
+
+    assert!(service.authenticate(&secret).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn given_unknown_secret_when_authenticating_then_returns_none() {
+    let (service, _) = service();
+
+    assert!(service.authenticate("sk-unknown").await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn given_unknown_key_when_revoking_then_returns_not_found() {
+    let (service, _) = service();
+
+    let result = service.revoke(ApiKeyId::new(), None).await;
+
+    assert!(matches!(result, Err(ApiKeyServiceError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn given_key_of_another_tenant_when_revoking_then_returns_not_found_and_key_stays_active() {
+    let (service, _) = service();
+    let (key, secret) = service
+        .mint("acme reader", acme(), vec![ApiScope::Query])
+        .await
+        .unwrap();
+    let other = TenantId::parse("globex").unwrap();
+
+    let result = service.revoke(key.id, Some(&other)).await;
+
+    assert!(matches!(result, Err(ApiKeyServiceError::NotFound(_))));
+    assert!(service.authenticate(&secret).await.unwrap().is_some());
+}
+
+#[tokio::test]
+async fn given_no_scopes_when_minting_then_rejects_request() {
+    let (service, repository) = service();
+
+    let result = service.mint("empty", acme(), Vec::new()).await;
+
+    assert!(matches!(result, Err(ApiKeyServiceError::InvalidRequest(_))));
+    assert!(repository.keys.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn given_blank_name_when_minting_then_rejects_request() {
+    let (service, _) = service();
+
+    let result = service.mint("  ", acme(), vec![ApiScope::Query]).await;
+
+    assert!(matches!(result, Err(ApiKeyServiceError::InvalidRequest(_))));
+}
+
+#[tokio::test]
+async fn given_duplicate_scopes_when_minting_then_scopes_are_deduplicated() {
+    let (service, _) = service();
+
+    let (key, _) = service
+        .mint(
+            "dup",
+            acme(),
+            vec![ApiScope::Query, ApiScope::Ingest, ApiScope::Query],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(key.scopes.len(), 2);
+}
@@ -0,0 +1,997 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tokio::sync::Mutex;
+
+use sandakan::application::ports::{
+    CollectionConfig, DocumentRepository, JobRepository, RepositoryError, SearchFilter,
+    SearchResult, StagingStore, StagingStoreError, TranscriptRepository, VectorStore,
+    VectorStoreError,
+};
+use sandakan::application::services::{
+    DocumentService, DocumentServiceError, SubmissionOutcome, run_as_principal,
+};
+use sandakan::domain::{
+    Chunk, ChunkId, ContentType, Document, DocumentId, DocumentRecord, Embedding, IngestionPayload,
+    JobId, JobStatus, Principal, SourceMetadata, StoragePath, TenantId, Transcript,
+    TranscriptSegment,
+};
+
+use crate::helpers::{InMemoryDocumentRepository, InMemoryJobRepository};
+
+// --- Hand-written mocks ---
+
+#[derive(Default)]
+struct TrackingVectorStore {
+    deleted_documents: Mutex<Vec<DocumentId>>,
+}
+
+#[async_trait::async_trait]
+impl VectorStore for TrackingVectorStore {
+    async fn create_collection(
+        &self,
+        _config: &CollectionConfig,
+    ) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn collection_exists(&self) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn get_collection_vector_size(&self) -> Result<Option<u64>, VectorStoreError> {
+        Ok(Some(384))
+    }
+
+    async fn delete_collection(&self) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn upsert(
+        &self,
+        _chunks: &[Chunk],
+        _embeddings: &[Embedding],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _embedding: &Embedding,
+        _top_k: usize,
+        _filter: &SearchFilter,
+    ) -> Result<Vec<SearchResult>, VectorStoreError> {
+        Ok(vec![])
+    }
+
+    async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn delete_by_document(
+        &self,
+        document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        self.deleted_documents.lock().await.push(document_id);
+        Ok(())
+    }
+}
+
+struct TrackingStagingStore {
+    source_exists: bool,
+    stored: Mutex<Vec<(String, Vec<u8>)>>,
+    deleted_paths: Mutex<Vec<String>>,
+}
+
+impl TrackingStagingStore {
+    fn new(source_exists: bool) -> Self {
+        Self {
+            source_exists,
+            stored: Mutex::new(vec![]),
+            deleted_paths: Mutex::new(vec![]),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl StagingStore for TrackingStagingStore {
+    async fn store(
+        &self,
+        path: &StoragePath,
+        mut stream: futures::stream::BoxStream<'_, Result<bytes::Bytes, std::io::Error>>,
+        _content_length: Option<u64>,
+    ) -> Result<u64, StagingStoreError> {
+        use futures::StreamExt;
+        let mut data = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            data.extend_from_slice(&chunk?);
+        }
+        let size = data.len() as u64;
+        self.stored.lock().await.push((path.to_string(), data));
+        Ok(size)
+    }
+
+    async fn fetch(&self, _path: &StoragePath) -> Result<Vec<u8>, StagingStoreError> {
+        Ok(vec![])
+    }
+
+    async fn delete(&self, path: &StoragePath) -> Result<(), StagingStoreError> {
+        self.deleted_paths.lock().await.push(path.to_string());
+        Ok(())
+    }
+
+    async fn head(&self, path: &StoragePath) -> Result<u64, StagingStoreError> {
+        if self.source_exists {
+            Ok(42)
+        } else {
+            Err(StagingStoreError::NotFound(path.to_string()))
+        }
+    }
+}
+
+#[derive(Default)]
+struct InMemoryTranscriptRepository {
+    transcripts: Mutex<Vec<(DocumentId, Transcript)>>,
+}
+
+#[async_trait::async_trait]
+impl TranscriptRepository for InMemoryTranscriptRepository {
+    async fn save(
+        &self,
+        document_id: DocumentId,
+        transcript: &Transcript,
+    ) -> Result<(), RepositoryError> {
+        let mut transcripts = self.transcripts.lock().await;
+        transcripts.retain(|(id, _)| *id != document_id);
+        transcripts.push((document_id, transcript.clone()));
+        Ok(())
+    }
+
+    async fn get(&self, document_id: DocumentId) -> Result<Option<Transcript>, RepositoryError> {
+        Ok(self
+            .transcripts
+            .lock()
+            .await
+            .iter()
+            .find(|(id, _)| *id == document_id)
+            .map(|(_, transcript)| transcript.clone()))
+    }
+
+    async fn delete(&self, document_id: DocumentId) -> Result<(), RepositoryError> {
+        self.transcripts
+            .lock()
+            .await
+            .retain(|(id, _)| *id != document_id);
+        Ok(())
+    }
+}
+
+struct Fixture {
+    service: DocumentService<TrackingVectorStore>,
+    documents: Arc<InMemoryDocumentRepository>,
+    jobs: Arc<InMemoryJobRepository>,
+    vector_store: Arc<TrackingVectorStore>,
+    staging_store: Arc<TrackingStagingStore>,
+    transcripts: Arc<InMemoryTranscriptRepository>,
+    dequeued: AtomicUsize,
+}
+
+/// A job as a worker would claim it from the queue.
+struct QueuedIngestion {
+    job_id: JobId,
+    tenant_id: TenantId,
+    payload: IngestionPayload,
+}
+
+impl Fixture {
+    /// Next job enqueued since the previous call, in submission order.
+    async fn next_queued(&self) -> Option<QueuedIngestion> {
+        let jobs = self.jobs.jobs.lock().await;
+        let job = jobs.get(self.dequeued.load(Ordering::SeqCst))?;
+        self.dequeued.fetch_add(1, Ordering::SeqCst);
+        Some(QueuedIngestion {
+            job_id: job.id,
+            tenant_id: job.tenant_id.clone(),
+            payload: job
+                .ingestion_payload()
+                .cloned()
+                .expect("queued job carries a payload"),
+        })
+    }
+}
+
+fn fixture(source_exists: bool) -> Fixture {
+    let documents = Arc::new(InMemoryDocumentRepository::default());
+    let jobs = Arc::new(InMemoryJobRepository::default());
+    let vector_store = Arc::new(TrackingVectorStore::default());
+    let staging_store = Arc::new(TrackingStagingStore::new(source_exists));
+    let transcripts = Arc::new(InMemoryTranscriptRepository::default());
+    let service = DocumentService::new(
+        Arc::clone(&documents) as Arc<dyn DocumentRepository>,
+        Arc::clone(&jobs) as Arc<dyn JobRepository>,
+        Arc::clone(&vector_store),
+        Arc::clone(&staging_store) as Arc<dyn StagingStore>,
+        Arc::clone(&transcripts) as Arc<dyn TranscriptRepository>,
+    );
+    Fixture {
+        service,
+        documents,
+        jobs,
+        vector_store,
+        staging_store,
+        transcripts,
+        dequeued: AtomicUsize::new(0),
+    }
+}
+
+fn sample_record(staged_upload: bool) -> DocumentRecord {
+    let document = Document::new("lecture.pdf".to_string(), ContentType::Pdf, 42);
+    let path = StoragePath::new(&document.id, &document.filename);
+    DocumentRecord::new(&document, path, staged_upload)
+}
+
+#[tokio::test]
+async fn given_uploaded_document_when_deleted_then_chunks_file_and_row_are_removed() {
+    let fx = fixture(true);
+    let record = sample_record(true);
+    fx.documents.create(&record).await.unwrap();
+
+    fx.service
+        .delete(&TenantId::default(), record.id)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *fx.vector_store.deleted_documents.lock().await,
+        vec![record.id]
+    );
+    assert_eq!(
+        *fx.staging_store.deleted_paths.lock().await,
+        vec![record.storage_path.to_string()]
+    );
+    assert!(fx.documents.records.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn given_document_with_queued_job_when_deleted_then_job_is_cancelled() {
+    let fx = fixture(true);
+    let record = sample_record(true);
+    fx.documents.create(&record).await.unwrap();
+    let job_id = fx
+        .service
+        .reingest(&TenantId::default(), record.id)
+        .await
+        .unwrap();
+
+    fx.service
+        .delete(&TenantId::default(), record.id)
+        .await
+        .unwrap();
+
+    let job = fx.jobs.get_by_id(job_id).await.unwrap().unwrap();
+    assert_eq!(job.status, JobStatus::Cancelled);
+    assert!(fx.documents.records.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn given_document_with_running_job_when_deleted_then_worker_is_asked_to_stop() {
+    let fx = fixture(true);
+    let record = sample_record(true);
+    fx.documents.create(&record).await.unwrap();
+    let job_id = fx
+        .service
+        .reingest(&TenantId::default(), record.id)
+        .await
+        .unwrap();
+    fx.jobs
+        .update_status(job_id, JobStatus::Embedding, None)
+        .await
+        .unwrap();
+
+    fx.service
+        .delete(&TenantId::default(), record.id)
+        .await
+        .unwrap();
+
+    let job = fx.jobs.get_by_id(job_id).await.unwrap().unwrap();
+    assert!(job.cancel_requested);
+    assert_eq!(job.status, JobStatus::Embedding);
+}
+
+#[tokio::test]
+async fn given_referenced_document_when_deleted_then_source_file_is_kept() {
+    let fx = fixture(true);
+    let record = sample_record(false);
+    fx.documents.create(&record).await.unwrap();
+
+    fx.service
+        .delete(&TenantId::default(), record.id)
+        .await
+        .unwrap();
+
+    assert_eq!(fx.vector_store.deleted_documents.lock().await.len(), 1);
+    assert!(fx.staging_store.deleted_paths.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn given_unknown_document_when_deleted_then_returns_not_found() {
+    let fx = fixture(true);
+
+    let result = fx
+        .service
+        .delete(&TenantId::default(), DocumentId::new())
+        .await;
+
+    assert!(matches!(result, Err(DocumentServiceError::NotFound(_))));
+    assert!(fx.vector_store.deleted_documents.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn given_cataloged_document_when_reingested_then_replacing_job_is_enqueued() {
+    let fx = fixture(true);
+    let record = sample_record(true);
+    fx.documents.create(&record).await.unwrap();
+
+    let job_id = fx
+        .service
+        .reingest(&TenantId::default(), record.id)
+        .await
+        .unwrap();
+
+    let msg = fx.next_queued().await.unwrap();
+    assert_eq!(msg.job_id, job_id);
+    assert_eq!(msg.payload.document.id, record.id);
+    assert_eq!(msg.payload.storage_path, record.storage_path);
+    assert!(msg.payload.replace_existing);
+}
+
+#[tokio::test]
+async fn given_missing_source_file_when_reingested_then_returns_source_unavailable() {
+    let fx = fixture(false);
+    let record = sample_record(true);
+    fx.documents.create(&record).await.unwrap();
+
+    let result = fx.service.reingest(&TenantId::default(), record.id).await;
+
+    assert!(matches!(
+        result,
+        Err(DocumentServiceError::SourceUnavailable(_))
+    ));
+}
+
+fn upload(filename: &str) -> (Document, StoragePath) {
+    let document = Document::new(filename.to_string(), ContentType::Pdf, 42);
+    let path = StoragePath::new(&document.id, &document.filename);
+    (document, path)
+}
+
+#[tokio::test]
+async fn given_new_content_when_submitted_then_document_is_created_and_job_enqueued() {
+    let fx = fixture(true);
+    let (document, path) = upload("lecture.pdf");
+    let document_id = document.id;
+
+    let submission = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            document,
+            path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(submission.outcome, SubmissionOutcome::Created);
+    assert_eq!(submission.document_id, document_id);
+    let records = fx.documents.records.lock().await;
+    assert_eq!(records[0].content_hash.as_deref(), Some("hash-a"));
+    let msg = fx.next_queued().await.unwrap();
+    assert_eq!(msg.job_id, submission.job_id);
+    assert!(!msg.payload.replace_existing);
+}
+
+#[tokio::test]
+async fn given_identical_content_when_resubmitted_then_existing_document_and_job_are_returned() {
+    let fx = fixture(true);
+    let (first, first_path) = upload("lecture.pdf");
+    let original = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            first,
+            first_path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+    fx.next_queued().await.unwrap();
+
+    let (second, second_path) = upload("lecture-copy.pdf");
+    let submission = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            second,
+            second_path.clone(),
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(submission.outcome, SubmissionOutcome::Duplicate);
+    assert_eq!(submission.document_id, original.document_id);
+    assert_eq!(submission.job_id, original.job_id);
+    assert_eq!(fx.documents.records.lock().await.len(), 1);
+    assert_eq!(
+        *fx.staging_store.deleted_paths.lock().await,
+        vec![second_path.to_string()]
+    );
+    assert!(fx.next_queued().await.is_none());
+}
+
+#[tokio::test]
+async fn given_identical_content_after_failed_job_when_resubmitted_then_ingestion_is_retried() {
+    let fx = fixture(true);
+    let (first, first_path) = upload("lecture.pdf");
+    let original = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            first,
+            first_path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+    fx.next_queued().await.unwrap();
+    fx.jobs
+        .update_status(original.job_id, JobStatus::Failed, Some("boom"))
+        .await
+        .unwrap();
+
+    let (second, second_path) = upload("lecture.pdf");
+    let submission = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            second,
+            second_path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(submission.outcome, SubmissionOutcome::Replaced);
+    assert_eq!(submission.document_id, original.document_id);
+    assert_ne!(submission.job_id, original.job_id);
+    assert!(fx.next_queued().await.unwrap().payload.replace_existing);
+}
+
+#[tokio::test]
+async fn given_identical_content_after_cancelled_job_when_resubmitted_then_ingestion_is_retried() {
+    let fx = fixture(true);
+    let (first, first_path) = upload("lecture.pdf");
+    let original = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            first,
+            first_path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+    fx.next_queued().await.unwrap();
+    fx.jobs.request_cancel(original.job_id).await.unwrap();
+
+    let (second, second_path) = upload("lecture.pdf");
+    let submission = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            second,
+            second_path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(submission.outcome, SubmissionOutcome::Replaced);
+    assert_eq!(submission.document_id, original.document_id);
+    assert_ne!(submission.job_id, original.job_id);
+    assert!(fx.next_queued().await.unwrap().payload.replace_existing);
+}
+
+#[tokio::test]
+async fn given_changed_content_under_same_filename_when_submitted_then_document_is_replaced() {
+    let fx = fixture(true);
+    let (first, first_path) = upload("lecture.pdf");
+    let original = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            first,
+            first_path.clone(),
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+    fx.next_queued().await.unwrap();
+
+    let (second, second_path) = upload("lecture.pdf");
+    let submission = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            second,
+            second_path.clone(),
+            true,
+            "hash-b".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(submission.outcome, SubmissionOutcome::Replaced);
+    assert_eq!(submission.document_id, original.document_id);
+    let records = fx.documents.records.lock().await;
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].content_hash.as_deref(), Some("hash-b"));
+    assert_eq!(records[0].storage_path, second_path);
+    assert_eq!(
+        *fx.staging_store.deleted_paths.lock().await,
+        vec![first_path.to_string()]
+    );
+    let msg = fx.next_queued().await.unwrap();
+    assert_eq!(msg.payload.document.id, original.document_id);
+    assert_eq!(msg.payload.storage_path, second_path);
+    assert!(msg.payload.replace_existing);
+}
+
+#[tokio::test]
+async fn given_referenced_source_when_duplicate_submitted_then_source_file_is_kept() {
+    let fx = fixture(true);
+    let (first, path) = upload("talk.mp4");
+    fx.service
+        .submit(
+            &TenantId::default(),
+            first,
+            path.clone(),
+            false,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+    fx.next_queued().await.unwrap();
+
+    let (second, _) = upload("talk.mp4");
+    let submission = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            second,
+            path,
+            false,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(submission.outcome, SubmissionOutcome::Duplicate);
+    assert!(fx.staging_store.deleted_paths.lock().await.is_empty());
+}
+
+fn acme() -> TenantId {
+    TenantId::parse("acme").unwrap()
+}
+
+#[tokio::test]
+async fn given_identical_content_in_another_tenant_when_submitted_then_separate_document_is_created()
+ {
+    let fx = fixture(true);
+    let (first, first_path) = upload("lecture.pdf");
+    let original = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            first,
+            first_path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+    fx.next_queued().await.unwrap();
+
+    let (second, second_path) = upload("lecture.pdf");
+    let submission = fx
+        .service
+        .submit(
+            &acme(),
+            second,
+            second_path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(submission.outcome, SubmissionOutcome::Created);
+    assert_ne!(submission.document_id, original.document_id);
+    let msg = fx.next_queued().await.unwrap();
+    assert_eq!(msg.tenant_id, acme());
+    let jobs = fx.jobs.jobs.lock().await;
+    assert_eq!(jobs.last().unwrap().tenant_id, acme());
+}
+
+#[tokio::test]
+async fn given_document_of_another_tenant_when_accessed_then_behaves_as_missing() {
+    let fx = fixture(true);
+    let record = sample_record(true).with_tenant(acme());
+    fx.documents.create(&record).await.unwrap();
+
+    let get = fx.service.get(&TenantId::default(), record.id).await;
+    let delete = fx.service.delete(&TenantId::default(), record.id).await;
+    let listed = fx.service.list(&TenantId::default(), 10, 0).await.unwrap();
+
+    assert!(matches!(get, Err(DocumentServiceError::NotFound(_))));
+    assert!(matches!(delete, Err(DocumentServiceError::NotFound(_))));
+    assert!(listed.is_empty());
+    assert!(fx.vector_store.deleted_documents.lock().await.is_empty());
+    assert_eq!(fx.documents.records.lock().await.len(), 1);
+}
+
+#[tokio::test]
+async fn given_authenticated_principal_when_submitting_then_message_carries_principal_subject() {
+    let fx = fixture(true);
+    let (document, path) = upload("lecture.pdf");
+
+    run_as_principal(
+        Principal::anonymous(TenantId::default()),
+        fx.service.submit(
+            &TenantId::default(),
+            document,
+            path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        ),
+    )
+    .await
+    .unwrap();
+
+    let msg = fx.next_queued().await.unwrap();
+    assert_eq!(msg.payload.principal.as_deref(), Some("anonymous"));
+}
+
+#[tokio::test]
+async fn given_no_principal_scope_when_submitting_then_message_has_no_principal() {
+    let fx = fixture(true);
+    let (document, path) = upload("lecture.pdf");
+
+    fx.service
+        .submit(
+            &TenantId::default(),
+            document,
+            path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+
+    let msg = fx.next_queued().await.unwrap();
+    assert!(msg.payload.principal.is_none());
+}
+
+fn lecture_source() -> SourceMetadata {
+    SourceMetadata {
+        source_url: Some(" https://www.youtube.com/watch?v=abc123 ".to_string()),
+        title: Some("Thermodynamics, lecture 3".to_string()),
+        author: Some("Prof. Lee".to_string()),
+        tags: vec![
+            "physics".to_string(),
+            " ".to_string(),
+            "physics".to_string(),
+        ],
+        attributes: [("course".to_string(), "PHYS-201".to_string())].into(),
+    }
+}
+
+#[tokio::test]
+async fn given_source_metadata_when_submitted_then_catalog_and_job_carry_normalized_metadata() {
+    let fx = fixture(true);
+    let (document, path) = upload("lecture.mp4");
+
+    fx.service
+        .submit(
+            &TenantId::default(),
+            document,
+            path,
+            false,
+            "hash-a".to_string(),
+            lecture_source(),
+        )
+        .await
+        .unwrap();
+
+    let expected = SourceMetadata {
+        source_url: Some("https://www.youtube.com/watch?v=abc123".to_string()),
+        tags: vec!["physics".to_string()],
+        ..lecture_source()
+    };
+    assert_eq!(fx.documents.records.lock().await[0].source, expected);
+    assert_eq!(fx.next_queued().await.unwrap().payload.source, expected);
+}
+
+#[tokio::test]
+async fn given_changed_content_without_metadata_when_submitted_then_stored_metadata_is_kept() {
+    let fx = fixture(true);
+    let (first, first_path) = upload("lecture.pdf");
+    fx.service
+        .submit(
+            &TenantId::default(),
+            first,
+            first_path,
+            true,
+            "hash-a".to_string(),
+            lecture_source(),
+        )
+        .await
+        .unwrap();
+    fx.next_queued().await.unwrap();
+
+    let (second, second_path) = upload("lecture.pdf");
+    fx.service
+        .submit(
+            &TenantId::default(),
+            second,
+            second_path,
+            true,
+            "hash-b".to_string(),
+            SourceMetadata::default(),
+        )
+        .await
+        .unwrap();
+
+    let msg = fx.next_queued().await.unwrap();
+    assert!(msg.payload.replace_existing);
+    assert_eq!(msg.payload.source.author.as_deref(), Some("Prof. Lee"));
+    assert_eq!(
+        fx.documents.records.lock().await[0].source.title.as_deref(),
+        Some("Thermodynamics, lecture 3")
+    );
+}
+
+#[tokio::test]
+async fn given_relative_source_url_when_submitted_then_rejected_before_cataloging() {
+    let fx = fixture(true);
+    let (document, path) = upload("lecture.pdf");
+
+    let result = fx
+        .service
+        .submit(
+            &TenantId::default(),
+            document,
+            path,
+            true,
+            "hash-a".to_string(),
+            SourceMetadata {
+                source_url: Some("/watch?v=abc123".to_string()),
+                ..SourceMetadata::default()
+            },
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DocumentServiceError::InvalidSource(_))
+    ));
+    assert!(fx.documents.records.lock().await.is_empty());
+    assert!(fx.next_queued().await.is_none());
+}
+
+#[tokio::test]
+async fn given_captions_when_submitted_then_catalog_and_reingest_jobs_carry_captions() {
+    let fx = fixture(true);
+    let (document, path) = upload("lecture.mp4");
+    let captions = StoragePath::from_raw("captions/lecture.vtt");
+
+    let submission = fx
+        .service
+        .submit_with_captions(
+            &TenantId::default(),
+            document,
+            path,
+            false,
+            "hash-a".to_string(),
+            SourceMetadata::default(),
+            Some(captions.clone()),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        fx.documents.records.lock().await[0].captions_path,
+        Some(captions.clone())
+    );
+    assert_eq!(
+        fx.next_queued().await.unwrap().payload.captions,
+        Some(captions.clone())
+    );
+
+    fx.service
+        .reingest(&TenantId::default(), submission.document_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        fx.next_queued().await.unwrap().payload.captions,
+        Some(captions)
+    );
+}
+
+fn recording() -> DocumentRecord {
+    let document = Document::new("lecture.mp4".to_string(), ContentType::Video, 4096);
+    let path = StoragePath::new(&document.id, &document.filename);
+    DocumentRecord::new(&document, path, true)
+}
+
+fn corrected_segments() -> Vec<TranscriptSegment> {
+    vec![
+        TranscriptSegment::new("Entropy always increases.", 0.0, 2.5),
+        TranscriptSegment::new("In a closed system, that is.", 2.5, 5.0),
+    ]
+}
+
+#[tokio::test]
+async fn given_stored_transcript_when_requested_then_segments_are_returned() {
+    let fx = fixture(true);
+    let record = recording();
+    fx.documents.create(&record).await.unwrap();
+    fx.transcripts
+        .save(
+            record.id,
+            &Transcript::new(corrected_segments()).with_language(Some("en".to_string())),
+        )
+        .await
+        .unwrap();
+
+    let transcript = fx
+        .service
+        .transcript(&TenantId::default(), record.id)
+        .await
+        .unwrap();
+
+    assert_eq!(transcript.segments, corrected_segments());
+    assert_eq!(transcript.language.as_deref(), Some("en"));
+    let other_tenant = fx.service.transcript(&acme(), record.id).await;
+    assert!(matches!(
+        other_tenant,
+        Err(DocumentServiceError::NotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn given_document_without_transcript_when_requested_then_transcript_not_found() {
+    let fx = fixture(true);
+    let record = sample_record(true);
+    fx.documents.create(&record).await.unwrap();
+
+    let result = fx.service.transcript(&TenantId::default(), record.id).await;
+
+    assert!(matches!(
+        result,
+        Err(DocumentServiceError::TranscriptNotFound(_))
+    ));
+}
+
+#[tokio::test]
+async fn given_corrected_transcript_when_submitted_then_it_is_staged_as_captions_and_reingested() {
+    let fx = fixture(true);
+    let record = recording();
+    fx.documents.create(&record).await.unwrap();
+
+    let job_id = fx
+        .service
+        .correct_transcript(&TenantId::default(), record.id, &corrected_segments())
+        .await
+        .unwrap();
+
+    let correction_path = record.corrected_transcript_path();
+    let stored = fx.staging_store.stored.lock().await;
+    assert_eq!(stored[0].0, correction_path.to_string());
+    let staged = TranscriptSegment::parse_captions(std::str::from_utf8(&stored[0].1).unwrap());
+    assert_eq!(staged.unwrap(), corrected_segments());
+    assert_eq!(
+        fx.documents.records.lock().await[0].captions_path,
+        Some(correction_path.clone())
+    );
+    let msg = fx.next_queued().await.unwrap();
+    assert_eq!(msg.job_id, job_id);
+    assert!(msg.payload.replace_existing);
+    assert_eq!(msg.payload.captions, Some(correction_path));
+}
+
+#[tokio::test]
+async fn given_pdf_document_when_correcting_transcript_then_rejected() {
+    let fx = fixture(true);
+    let record = sample_record(true);
+    fx.documents.create(&record).await.unwrap();
+
+    let result = fx
+        .service
+        .correct_transcript(&TenantId::default(), record.id, &corrected_segments())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DocumentServiceError::InvalidTranscript(_))
+    ));
+    assert!(fx.staging_store.stored.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn given_segments_running_backwards_when_correcting_transcript_then_rejected() {
+    let fx = fixture(true);
+    let record = recording();
+    fx.documents.create(&record).await.unwrap();
+
+    let result = fx
+        .service
+        .correct_transcript(
+            &TenantId::default(),
+            record.id,
+            &[TranscriptSegment::new("Backwards", 5.0, 1.0)],
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(DocumentServiceError::InvalidTranscript(_))
+    ));
+}
+
+#[tokio::test]
+async fn given_corrected_transcript_when_document_deleted_then_correction_file_is_removed() {
+    let fx = fixture(true);
+    let record = recording();
+    let correction_path = record.corrected_transcript_path();
+    let record = record.with_captions(Some(correction_path.clone()));
+    fx.documents.create(&record).await.unwrap();
+
+    fx.service
+        .delete(&TenantId::default(), record.id)
+        .await
+        .unwrap();
+
+    assert!(
+        fx.staging_store
+            .deleted_paths
+            .lock()
+            .await
+            .contains(&correction_path.to_string())
+    );
+}
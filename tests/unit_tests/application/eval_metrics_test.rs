@@ -14,6 +14,7 @@ fn make_source(page: Option<u32>) -> EvalSource {
     EvalSource {
         text: "chunk".to_string(),
         page,
+        end_page: page,
         score: 0.9,
     }
 }
@@ -124,6 +125,16 @@ fn given_no_pages_in_retrieved_chunks_when_computing_recall_then_returns_zero()
     assert!((recall - 0.0).abs() < f32::EPSILON);
 }
 
+#[test]
+fn given_chunk_spanning_pages_when_computing_recall_then_every_covered_page_counts() {
+    let sources = vec![EvalSource {
+        end_page: Some(5),
+        ..make_source(Some(3))
+    }];
+    let recall = compute_context_recall(Some(&[4, 5]), &sources);
+    assert!((recall - 1.0).abs() < f32::EPSILON);
+}
+
 // -- Answer Correctness -------------------------------------------------------
 
 #[tokio::test]
@@ -349,11 +360,13 @@ async fn given_judge_returns_valid_score_when_compute_chunk_quality_called_then_
         EvalSource {
             text: "Well-formed first chunk.".to_string(),
             page: Some(1),
+            end_page: Some(1),
             score: 0.0,
         },
         EvalSource {
             text: "Well-formed second chunk.".to_string(),
             page: Some(2),
+            end_page: Some(2),
             score: 0.0,
         },
     ];
@@ -371,6 +384,7 @@ async fn given_judge_returns_non_numeric_when_compute_chunk_quality_called_then_
     let samples = vec![EvalSource {
         text: "Some chunk text.".to_string(),
         page: None,
+        end_page: None,
         score: 0.0,
     }];
     let result = compute_chunk_quality(&judge, "doc.pdf", "ingestion_pdf", &samples).await;
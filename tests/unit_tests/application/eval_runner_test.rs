@@ -0,0 +1,360 @@
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use sandakan::application::ports::{
+    AgentMessage, CollectionConfig, ConversationRepository, Embedder, EmbedderError,
+    EvalEventError, EvalEventRepository, EvalResultError, EvalResultRepository, LlmClient,
+    LlmClientError, LlmToolResponse, RepositoryError, SearchFilter, SearchResult, ToolSchema,
+    VectorStore, VectorStoreError,
+};
+use sandakan::application::services::{EvalRunner, EvalRunnerError, EvalTarget, RetrievalService};
+use sandakan::domain::{
+    Chunk, ChunkId, Conversation, ConversationId, DocumentId, Embedding, EvalEntry, EvalEvent,
+    EvalEventId, EvalResult, Message, TenantId,
+};
+
+const TEST_FAITHFULNESS_THRESHOLD: f32 = 0.7;
+
+// --- Hand-written mocks ---
+
+struct MockEmbedder;
+
+#[async_trait::async_trait]
+impl Embedder for MockEmbedder {
+    async fn embed(&self, _text: &str) -> Result<Embedding, EmbedderError> {
+        Ok(Embedding::new(vec![0.1; 384]))
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Embedding>, EmbedderError> {
+        Ok(texts
+            .iter()
+            .map(|_| Embedding::new(vec![0.1; 384]))
+            .collect())
+    }
+}
+
+/// LLM that answers every prompt with a fixed string; doubles as the judge when the
+/// string parses as a score.
+struct FixedLlmClient(&'static str);
+
+#[async_trait::async_trait]
+impl LlmClient for FixedLlmClient {
+    async fn complete(&self, _prompt: &str, _context: &str) -> Result<String, LlmClientError> {
+        Ok(self.0.to_string())
+    }
+
+    async fn complete_stream(
+        &self,
+        _prompt: &str,
+        _context: &str,
+    ) -> Result<
+        std::pin::Pin<
+            Box<
+                dyn futures::stream::Stream<Item = Result<String, LlmClientError>> + Send + 'static,
+            >,
+        >,
+        LlmClientError,
+    > {
+        let answer = self.0.to_string();
+        Ok(Box::pin(futures::stream::once(async move { Ok(answer) })))
+    }
+
+    async fn complete_stream_with_messages(
+        &self,
+        _: &[AgentMessage],
+    ) -> Result<
+        std::pin::Pin<
+            Box<
+                dyn futures::stream::Stream<Item = Result<String, LlmClientError>> + Send + 'static,
+            >,
+        >,
+        LlmClientError,
+    > {
+        unimplemented!()
+    }
+
+    async fn complete_with_tools(
+        &self,
+        _messages: &[AgentMessage],
+        _tools: &[ToolSchema],
+    ) -> Result<LlmToolResponse, LlmClientError> {
+        unimplemented!()
+    }
+}
+
+struct MockVectorStore;
+
+#[async_trait::async_trait]
+impl VectorStore for MockVectorStore {
+    async fn create_collection(
+        &self,
+        _config: &CollectionConfig,
+    ) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn collection_exists(&self) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn get_collection_vector_size(&self) -> Result<Option<u64>, VectorStoreError> {
+        Ok(Some(384))
+    }
+
+    async fn delete_collection(&self) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn upsert(
+        &self,
+        _chunks: &[Chunk],
+        _embeddings: &[Embedding],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _embedding: &Embedding,
+        _top_k: usize,
+        _filter: &SearchFilter,
+    ) -> Result<Vec<SearchResult>, VectorStoreError> {
+        Ok(vec![SearchResult {
+            chunk: Chunk::new(
+                "Chunking splits documents.".to_string(),
+                DocumentId::new(),
+                Some(3),
+                0,
+            ),
+            score: 0.95,
+        }])
+    }
+
+    async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+}
+
+struct MockConversationRepository;
+
+#[async_trait::async_trait]
+impl ConversationRepository for MockConversationRepository {
+    async fn create_conversation(
+        &self,
+        _conversation: &Conversation,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn get_conversation(
+        &self,
+        _id: ConversationId,
+    ) -> Result<Option<Conversation>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn get_conversation_tenant(
+        &self,
+        _id: ConversationId,
+    ) -> Result<Option<TenantId>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn append_message(&self, _message: &Message) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn get_messages(
+        &self,
+        _conversation_id: ConversationId,
+        _limit: usize,
+    ) -> Result<Vec<Message>, RepositoryError> {
+        Ok(vec![])
+    }
+}
+
+#[derive(Default)]
+struct RecordingEventRepository {
+    events: Mutex<Vec<EvalEvent>>,
+}
+
+#[async_trait::async_trait]
+impl EvalEventRepository for RecordingEventRepository {
+    async fn record(&self, event: &EvalEvent) -> Result<(), EvalEventError> {
+        self.events.lock().await.push(event.clone());
+        Ok(())
+    }
+    async fn get(&self, _id: EvalEventId) -> Result<Option<EvalEvent>, EvalEventError> {
+        Ok(None)
+    }
+    async fn list(&self, _limit: Option<usize>) -> Result<Vec<EvalEvent>, EvalEventError> {
+        Ok(vec![])
+    }
+    async fn sample(&self, _n: usize) -> Result<Vec<EvalEvent>, EvalEventError> {
+        Ok(vec![])
+    }
+}
+
+#[derive(Default)]
+struct RecordingResultRepository {
+    results: Mutex<Vec<EvalResult>>,
+}
+
+#[async_trait::async_trait]
+impl EvalResultRepository for RecordingResultRepository {
+    async fn save(&self, result: &EvalResult) -> Result<(), EvalResultError> {
+        self.results.lock().await.push(result.clone());
+        Ok(())
+    }
+}
+
+fn golden_entries() -> Vec<EvalEntry> {
+    vec![
+        EvalEntry {
+            question: "What is chunking?".to_string(),
+            expected_answer: "Chunking splits documents.".to_string(),
+            expected_source_pages: Some(vec![3]),
+        },
+        EvalEntry {
+            question: "Where is chunking described?".to_string(),
+            expected_answer: "On pages three and four.".to_string(),
+            expected_source_pages: Some(vec![3, 4]),
+        },
+    ]
+}
+
+fn build_runner(
+    judge_reply: &'static str,
+    event_repository: Arc<RecordingEventRepository>,
+    result_repository: Arc<RecordingResultRepository>,
+) -> EvalRunner<FixedLlmClient, MockVectorStore> {
+    let embedder: Arc<dyn Embedder> = Arc::new(MockEmbedder);
+    let retrieval_service = Arc::new(RetrievalService::new(
+        Arc::clone(&embedder),
+        Arc::new(FixedLlmClient("Chunking splits documents.")),
+        Arc::new(MockVectorStore),
+        Arc::new(MockConversationRepository),
+        None,
+        None,
+        None,
+        "test/mock-model".to_string(),
+        5,
+        0.7,
+        3072,
+        "fallback".to_string(),
+    ));
+    EvalRunner::new(
+        retrieval_service,
+        event_repository,
+        result_repository,
+        Arc::new(FixedLlmClient(judge_reply)),
+        embedder,
+        TEST_FAITHFULNESS_THRESHOLD,
+        "test/mock-model",
+        "test/judge",
+    )
+}
+
+#[tokio::test]
+async fn given_golden_dataset_when_running_retrieval_eval_then_report_aggregates_scores() {
+    let events = Arc::new(RecordingEventRepository::default());
+    let results = Arc::new(RecordingResultRepository::default());
+    let runner = build_runner("0.9", Arc::clone(&events), Arc::clone(&results));
+
+    let report = runner
+        .run(&golden_entries(), EvalTarget::Retrieval)
+        .await
+        .unwrap();
+
+    assert_eq!(report.total_evaluated, 2);
+    assert_eq!(report.below_threshold_count, 0);
+    assert_eq!(report.errored_count, 0);
+    assert_eq!(report.judge_model, "test/judge");
+    assert!((report.mean_faithfulness.unwrap() - 0.9).abs() < 1e-6);
+    assert!((report.mean_context_recall.unwrap() - 0.75).abs() < 1e-6);
+    assert!((report.mean_correctness.unwrap() - 1.0).abs() < 1e-4);
+    assert_eq!(
+        report.results[0].generated_answer,
+        "Chunking splits documents."
+    );
+}
+
+#[tokio::test]
+async fn given_golden_dataset_when_running_eval_then_results_are_tagged_with_run_id() {
+    let events = Arc::new(RecordingEventRepository::default());
+    let results = Arc::new(RecordingResultRepository::default());
+    let runner = build_runner("0.9", Arc::clone(&events), Arc::clone(&results));
+
+    let report = runner
+        .run(&golden_entries(), EvalTarget::Retrieval)
+        .await
+        .unwrap();
+
+    let saved = results.results.lock().await;
+    assert_eq!(events.events.lock().await.len(), 2);
+    assert_eq!(saved.len(), 2);
+    assert!(saved.iter().all(|r| r.run_id == Some(report.run_id)));
+    assert_eq!(report.results[0].event_id, Some(saved[0].eval_event_id));
+}
+
+#[tokio::test]
+async fn given_low_judge_score_when_running_eval_then_items_are_marked_below_threshold() {
+    let runner = build_runner(
+        "0.2",
+        Arc::new(RecordingEventRepository::default()),
+        Arc::new(RecordingResultRepository::default()),
+    );
+
+    let report = runner
+        .run(&golden_entries(), EvalTarget::Retrieval)
+        .await
+        .unwrap();
+
+    assert_eq!(report.below_threshold_count, 2);
+    assert_eq!(report.errored_count, 0);
+    assert!(report.results.iter().all(|r| r.error.is_none()));
+}
+
+#[tokio::test]
+async fn given_unparseable_judge_reply_when_running_eval_then_entry_fails_without_aborting_run() {
+    let results = Arc::new(RecordingResultRepository::default());
+    let runner = build_runner(
+        "no score",
+        Arc::new(RecordingEventRepository::default()),
+        Arc::clone(&results),
+    );
+
+    let report = runner
+        .run(&golden_entries(), EvalTarget::Retrieval)
+        .await
+        .unwrap();
+
+    assert_eq!(report.total_evaluated, 2);
+    assert_eq!(report.errored_count, 2);
+    assert_eq!(report.below_threshold_count, 0);
+    assert!(report.mean_faithfulness.is_none());
+    assert!(report.results.iter().all(|r| r.error.is_some()));
+    assert!(results.results.lock().await.is_empty());
+}
+
+#[tokio::test]
+async fn given_no_agent_configured_when_running_agent_eval_then_returns_agent_unavailable() {
+    let runner = build_runner(
+        "0.9",
+        Arc::new(RecordingEventRepository::default()),
+        Arc::new(RecordingResultRepository::default()),
+    );
+
+    let result = runner.run(&golden_entries(), EvalTarget::Agent).await;
+
+    assert!(matches!(result, Err(EvalRunnerError::AgentUnavailable)));
+}
@@ -6,12 +6,14 @@ use uuid::Uuid;
 
 use sandakan::application::ports::{
     AgentMessage, EvalEventError, EvalEventRepository, EvalOutboxError, EvalOutboxRepository,
-    EvalResultError, EvalResultRepository, LlmClient, LlmClientError, LlmToolResponse, ToolSchema,
+    EvalResultError, EvalResultRepository, LlmClient, LlmClientError, LlmToolResponse,
+    RepositoryError, ToolSchema, WebhookRepository,
 };
 use sandakan::application::services::EvalWorker;
 use sandakan::domain::{
-    AgenticTrace, EvalEvent, EvalEventId, EvalOperationType, EvalOutboxEntry, EvalResult,
-    EvalSource, ToolCallTrace,
+    AgenticTrace, ClaimedWebhookDelivery, EvalEvent, EvalEventId, EvalOperationType,
+    EvalOutboxEntry, EvalResult, EvalSource, TenantId, ToolCallTrace, WebhookDelivery,
+    WebhookEvent, WebhookEventType, WebhookSubscription, WebhookSubscriptionId,
 };
 
 // --- Hand-written mocks ---
@@ -115,12 +117,15 @@ fn sample_eval_event(id: EvalEventId) -> EvalEvent {
         retrieved_sources: vec![EvalSource {
             text: "Chunking is the process of splitting documents into smaller pieces.".to_string(),
             page: Some(1),
+            end_page: Some(1),
             score: 0.92,
         }],
         model_config: "test/model".to_string(),
         operation_type: EvalOperationType::Query,
         correlation_id: None,
         agentic_trace: None,
+        tenant_id: TenantId::default(),
+        principal: None,
     }
 }
 
@@ -135,6 +140,8 @@ fn ingestion_eval_event(id: EvalEventId, chunk_count: usize) -> EvalEvent {
         operation_type: EvalOperationType::IngestionPdf,
         correlation_id: None,
         agentic_trace: None,
+        tenant_id: TenantId::default(),
+        principal: None,
     }
 }
 
@@ -152,16 +159,19 @@ fn ingestion_eval_event_with_samples(
             EvalSource {
                 text: "This is the first chunk of well-formed text.".to_string(),
                 page: Some(1),
+                end_page: Some(1),
                 score: 0.0,
             },
             EvalSource {
                 text: "This is the second chunk discussing another topic.".to_string(),
                 page: Some(1),
+                end_page: Some(1),
                 score: 0.0,
             },
             EvalSource {
                 text: "A third chunk covers the conclusion of the document.".to_string(),
                 page: Some(2),
+                end_page: Some(2),
                 score: 0.0,
             },
         ],
@@ -169,6 +179,8 @@ fn ingestion_eval_event_with_samples(
         operation_type,
         correlation_id: None,
         agentic_trace: None,
+        tenant_id: TenantId::default(),
+        principal: None,
     }
 }
 
@@ -181,12 +193,15 @@ fn agentic_eval_event(id: EvalEventId) -> EvalEvent {
         retrieved_sources: vec![EvalSource {
             text: "The agent has access to many tools.".to_string(),
             page: None,
+            end_page: None,
             score: 0.85,
         }],
         model_config: "test/model".to_string(),
         operation_type: EvalOperationType::AgenticRun,
         correlation_id: None,
         agentic_trace: None,
+        tenant_id: TenantId::default(),
+        principal: None,
     }
 }
 
@@ -352,6 +367,101 @@ impl EvalResultRepository for NoopResultRepository {
     }
 }
 
+/// Records the webhook events the worker queues.
+#[derive(Default)]
+struct RecordingWebhooks {
+    events: Mutex<Vec<WebhookEvent>>,
+}
+
+#[async_trait::async_trait]
+impl WebhookRepository for RecordingWebhooks {
+    async fn create_subscription(
+        &self,
+        _subscription: &WebhookSubscription,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+    async fn list_subscriptions(
+        &self,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        Ok(Vec::new())
+    }
+    async fn get_subscription(
+        &self,
+        _id: WebhookSubscriptionId,
+    ) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        Ok(None)
+    }
+    async fn delete_subscription(
+        &self,
+        _id: WebhookSubscriptionId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+    async fn enqueue(&self, event: &WebhookEvent) -> Result<usize, RepositoryError> {
+        self.events.lock().await.push(event.clone());
+        Ok(1)
+    }
+    async fn claim_due(
+        &self,
+        _batch_size: usize,
+        _lease: Duration,
+    ) -> Result<Vec<ClaimedWebhookDelivery>, RepositoryError> {
+        Ok(Vec::new())
+    }
+    async fn mark_delivered(
+        &self,
+        _id: Uuid,
+        _response_status: u16,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+    async fn schedule_retry(
+        &self,
+        _id: Uuid,
+        _delay: Duration,
+        _error: &str,
+        _response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+    async fn mark_failed(
+        &self,
+        _id: Uuid,
+        _error: &str,
+        _response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+    async fn list_deliveries(
+        &self,
+        _subscription_id: WebhookSubscriptionId,
+        _limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        Ok(Vec::new())
+    }
+}
+
+fn worker_with_webhooks(
+    event: EvalEvent,
+    threshold: f32,
+    webhooks: &Arc<RecordingWebhooks>,
+) -> EvalWorker {
+    let entry = EvalOutboxEntry::new(event.id);
+    EvalWorker::new(
+        Arc::new(TrackingOutboxRepository::with_entries(vec![entry]))
+            as Arc<dyn EvalOutboxRepository>,
+        Arc::new(SingleEventRepository { event }) as Arc<dyn EvalEventRepository>,
+        Arc::new(NoopResultRepository) as Arc<dyn EvalResultRepository>,
+        Arc::new(HighFaithfulnessJudge) as Arc<dyn LlmClient>,
+        threshold,
+        Duration::from_secs(60),
+        10,
+    )
+    .with_webhooks(Arc::clone(webhooks) as Arc<dyn WebhookRepository>)
+}
+
 // --- Tests ---
 
 #[tokio::test]
@@ -632,6 +742,7 @@ fn agentic_eval_event_with_trace(id: EvalEventId) -> EvalEvent {
         retrieved_sources: vec![EvalSource {
             text: "AI is a broad field.".to_string(),
             page: None,
+            end_page: None,
             score: 0.9,
         }],
         model_config: "test/model".to_string(),
@@ -648,6 +759,8 @@ fn agentic_eval_event_with_trace(id: EvalEventId) -> EvalEvent {
             reflection_score: Some(0.88),
             reflection_issues: vec![],
         }),
+        tenant_id: TenantId::default(),
+        principal: None,
     }
 }
 
@@ -668,6 +781,8 @@ fn agentic_eval_event_with_empty_trace(id: EvalEventId) -> EvalEvent {
             reflection_score: None,
             reflection_issues: vec![],
         }),
+        tenant_id: TenantId::default(),
+        principal: None,
     }
 }
 
@@ -866,3 +981,33 @@ async fn given_ingestion_mp4_with_chunk_samples_when_worker_processes_then_quali
     assert_eq!(saved.len(), 1);
     assert!((saved[0].faithfulness - 0.95).abs() < 0.001);
 }
+
+#[tokio::test]
+async fn given_score_below_threshold_when_worker_processes_then_below_threshold_webhook_is_queued()
+{
+    let event_id = EvalEventId::new();
+    let webhooks = Arc::new(RecordingWebhooks::default());
+    let worker = worker_with_webhooks(sample_eval_event(event_id), 0.99, &webhooks);
+
+    worker.process_batch().await.expect("should process batch");
+
+    let events = webhooks.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, WebhookEventType::EvalBelowThreshold);
+    assert_eq!(
+        events[0].data["eval_event_id"],
+        event_id.as_uuid().to_string().as_str()
+    );
+    assert_eq!(events[0].data["operation_type"], "query");
+    assert!((events[0].data["faithfulness"].as_f64().unwrap() - 0.95).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn given_score_above_threshold_when_worker_processes_then_no_webhook_is_queued() {
+    let webhooks = Arc::new(RecordingWebhooks::default());
+    let worker = worker_with_webhooks(sample_eval_event(EvalEventId::new()), 0.7, &webhooks);
+
+    worker.process_batch().await.expect("should process batch");
+
+    assert!(webhooks.events.lock().await.is_empty());
+}
@@ -0,0 +1,1445 @@
+use std::path::Path;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use chrono::Utc;
+use tokio::sync::Mutex;
+
+use sandakan::application::ports::{
+    CollectionConfig, DocumentRepository, Embedder, EmbedderError, JOB_CANCELLED, JobEventBus,
+    JobFilter, JobRepository, LeaseRenewal, RepositoryError, SearchFilter, SearchResult,
+    StagingStore, StagingStoreError, TranscriptRepository, TranscriptionEngine, TranscriptionError,
+    TranscriptionObserver, VectorStore, VectorStoreError, WebhookRepository,
+};
+use sandakan::application::services::{IngestionQueueOptions, IngestionWorker, RetryPolicy};
+use sandakan::domain::{
+    Chunk, ChunkId, ClaimedWebhookDelivery, ContentType, CrawlRequest, Document, DocumentId,
+    DocumentRecord, Embedding, FetchFailure, IngestionPayload, Job, JobEventKind, JobId,
+    JobPayload, JobProgress, JobStatus, RepositorySyncRequest, SourceMetadata, StoragePath,
+    TenantId, Transcript, TranscriptSegment, WebhookDelivery, WebhookEvent, WebhookEventType,
+    WebhookSubscription, WebhookSubscriptionId,
+};
+use sandakan::infrastructure::llm::MockEmbedder;
+use sandakan::infrastructure::persistence::{BroadcastJobEventBus, MockVectorStore};
+use sandakan::infrastructure::text_processing::{MockFileLoader, RecursiveCharacterSplitter};
+
+// --- Hand-written mocks ---
+
+/// In-memory queue with the claim/lease semantics of the Postgres adapter.
+#[derive(Default)]
+struct InMemoryJobQueue {
+    jobs: Mutex<Vec<Job>>,
+}
+
+impl InMemoryJobQueue {
+    async fn status_of(&self, id: JobId) -> Option<JobStatus> {
+        self.jobs
+            .lock()
+            .await
+            .iter()
+            .find(|j| j.id == id)
+            .map(|j| j.status)
+    }
+
+    async fn job(&self, id: JobId) -> Option<Job> {
+        self.jobs.lock().await.iter().find(|j| j.id == id).cloned()
+    }
+
+    /// Moves an in-flight job to `worker_id`, as if its lease expired and another worker
+    /// claimed it.
+    async fn hand_over(&self, id: JobId, worker_id: &str) {
+        if let Some(job) = self.jobs.lock().await.iter_mut().find(|j| j.id == id) {
+            job.lease_owner = Some(worker_id.to_string());
+            job.lease_expires_at = Some(Utc::now() + chrono::Duration::seconds(60));
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl JobRepository for InMemoryJobQueue {
+    async fn create(&self, job: &Job) -> Result<(), RepositoryError> {
+        self.jobs.lock().await.push(job.clone());
+        Ok(())
+    }
+
+    async fn get_by_id(&self, id: JobId) -> Result<Option<Job>, RepositoryError> {
+        Ok(self.job(id).await)
+    }
+
+    async fn update_status(
+        &self,
+        id: JobId,
+        status: JobStatus,
+        error_message: Option<&str>,
+    ) -> Result<(), RepositoryError> {
+        if let Some(job) = self.jobs.lock().await.iter_mut().find(|j| j.id == id) {
+            job.status = status;
+            job.error_message = error_message.map(str::to_string);
+        }
+        Ok(())
+    }
+
+    async fn update_leased_status(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        status: JobStatus,
+        error_message: Option<&str>,
+    ) -> Result<bool, RepositoryError> {
+        let mut jobs = self.jobs.lock().await;
+        match jobs.iter_mut().find(|j| {
+            j.id == id && j.status.is_in_flight() && j.lease_owner.as_deref() == Some(worker_id)
+        }) {
+            Some(job) => {
+                job.status = status;
+                job.error_message = error_message.map(str::to_string);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn list_by_status(&self, status: JobStatus) -> Result<Vec<Job>, RepositoryError> {
+        Ok(self
+            .jobs
+            .lock()
+            .await
+            .iter()
+            .filter(|j| j.status == status)
+            .cloned()
+            .collect())
+    }
+
+    async fn find_latest_by_document(
+        &self,
+        document_id: DocumentId,
+    ) -> Result<Option<Job>, RepositoryError> {
+        Ok(self
+            .jobs
+            .lock()
+            .await
+            .iter()
+            .rev()
+            .find(|j| j.document_id == Some(document_id))
+            .cloned())
+    }
+
+    async fn list(
+        &self,
+        filter: &JobFilter,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Job>, RepositoryError> {
+        Ok(self
+            .jobs
+            .lock()
+            .await
+            .iter()
+            .rev()
+            .filter(|j| j.tenant_id == filter.tenant_id)
+            .filter(|j| filter.status.is_none_or(|status| j.status == status))
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn claim_next(
+        &self,
+        worker_id: &str,
+        lease: Duration,
+    ) -> Result<Option<Job>, RepositoryError> {
+        let now = Utc::now();
+        let mut jobs = self.jobs.lock().await;
+        let Some(job) = jobs.iter_mut().find(|j| {
+            j.status == JobStatus::Queued
+                && j.payload.is_some()
+                && j.next_attempt_at.is_none_or(|at| at <= now)
+        }) else {
+            return Ok(None);
+        };
+        job.status = JobStatus::Processing;
+        job.attempts += 1;
+        job.lease_owner = Some(worker_id.to_string());
+        job.lease_expires_at = Some(now + lease);
+        job.next_attempt_at = None;
+        job.progress = JobProgress::default();
+        job.fetch_failures.clear();
+        Ok(Some(job.clone()))
+    }
+
+    async fn renew_lease(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        lease: Duration,
+    ) -> Result<LeaseRenewal, RepositoryError> {
+        let mut jobs = self.jobs.lock().await;
+        match jobs.iter_mut().find(|j| {
+            j.id == id && j.status.is_in_flight() && j.lease_owner.as_deref() == Some(worker_id)
+        }) {
+            Some(job) => {
+                job.lease_expires_at = Some(Utc::now() + lease);
+                Ok(if job.cancel_requested {
+                    LeaseRenewal::CancelRequested
+                } else {
+                    LeaseRenewal::Renewed
+                })
+            }
+            None => Ok(LeaseRenewal::Lost),
+        }
+    }
+
+    async fn release_expired(&self, id: JobId) -> Result<bool, RepositoryError> {
+        let now = Utc::now();
+        let mut jobs = self.jobs.lock().await;
+        match jobs.iter_mut().find(|j| {
+            j.id == id
+                && j.status.is_in_flight()
+                && j.lease_expires_at.is_none_or(|expiry| expiry < now)
+        }) {
+            Some(job) => {
+                job.status = JobStatus::Queued;
+                job.lease_owner = None;
+                job.lease_expires_at = None;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn schedule_retry(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        delay: Duration,
+        error_message: &str,
+    ) -> Result<bool, RepositoryError> {
+        let mut jobs = self.jobs.lock().await;
+        match jobs.iter_mut().find(|j| {
+            j.id == id
+                && j.status.is_in_flight()
+                && j.lease_owner.as_deref() == Some(worker_id)
+                && !j.cancel_requested
+        }) {
+            Some(job) => {
+                job.status = JobStatus::Queued;
+                job.error_message = Some(error_message.to_string());
+                job.next_attempt_at = Some(Utc::now() + delay);
+                job.lease_owner = None;
+                job.lease_expires_at = None;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn update_progress(
+        &self,
+        id: JobId,
+        worker_id: &str,
+        progress: &JobProgress,
+    ) -> Result<bool, RepositoryError> {
+        let mut jobs = self.jobs.lock().await;
+        match jobs.iter_mut().find(|j| {
+            j.id == id && j.status.is_in_flight() && j.lease_owner.as_deref() == Some(worker_id)
+        }) {
+            Some(job) => {
+                job.progress = progress.clone();
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    async fn request_cancel(&self, id: JobId) -> Result<Option<JobStatus>, RepositoryError> {
+        let mut jobs = self.jobs.lock().await;
+        let Some(job) = jobs
+            .iter_mut()
+            .find(|j| j.id == id && !j.status.is_terminal())
+        else {
+            return Ok(None);
+        };
+        job.cancel_requested = true;
+        if job.status == JobStatus::Queued {
+            job.status = JobStatus::Cancelled;
+            job.error_message = Some(JOB_CANCELLED.to_string());
+        }
+        Ok(Some(job.status))
+    }
+
+    async fn retry(&self, id: JobId) -> Result<bool, RepositoryError> {
+        let mut jobs = self.jobs.lock().await;
+        let Some(job) = jobs.iter_mut().find(|j| {
+            j.id == id
+                && matches!(j.status, JobStatus::Failed | JobStatus::Cancelled)
+                && j.payload.is_some()
+        }) else {
+            return Ok(false);
+        };
+        job.status = JobStatus::Queued;
+        job.attempts = 0;
+        job.error_message = None;
+        job.next_attempt_at = None;
+        job.cancel_requested = false;
+        job.progress = JobProgress::default();
+        job.fetch_failures.clear();
+        Ok(true)
+    }
+
+    async fn record_fetch_failure(
+        &self,
+        id: JobId,
+        failure: &FetchFailure,
+    ) -> Result<(), RepositoryError> {
+        if let Some(job) = self.jobs.lock().await.iter_mut().find(|j| j.id == id) {
+            job.fetch_failures.push(failure.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Catalog whose document is deleted while its job runs: `record` is what lookups see, and
+/// recording the ingestion always finds the row gone.
+struct VanishingCatalog {
+    record: Option<DocumentRecord>,
+}
+
+#[async_trait::async_trait]
+impl DocumentRepository for VanishingCatalog {
+    async fn create(&self, _document: &DocumentRecord) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn get_by_id(&self, _id: DocumentId) -> Result<Option<DocumentRecord>, RepositoryError> {
+        Ok(self.record.clone())
+    }
+
+    async fn find_by_content_hash(
+        &self,
+        _tenant_id: &TenantId,
+        _content_hash: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn find_by_filename(
+        &self,
+        _tenant_id: &TenantId,
+        _filename: &str,
+    ) -> Result<Option<DocumentRecord>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn update_source(&self, _document: &DocumentRecord) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn list(
+        &self,
+        _tenant_id: &TenantId,
+        _limit: usize,
+        _offset: usize,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn list_by_filename_prefix(
+        &self,
+        _tenant_id: &TenantId,
+        _prefix: &str,
+    ) -> Result<Vec<DocumentRecord>, RepositoryError> {
+        Ok(vec![])
+    }
+
+    async fn mark_ingested(
+        &self,
+        id: DocumentId,
+        _chunk_count: usize,
+        _content_hash: &str,
+    ) -> Result<(), RepositoryError> {
+        Err(RepositoryError::NotFound(id.as_uuid().to_string()))
+    }
+
+    async fn delete(&self, _id: DocumentId) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+}
+
+/// Serves a caption file for `.srt` paths and a line of text for anything else.
+struct TextStagingStore;
+
+const CAPTIONS_SRT: &str = "1\n00:00:01,000 --> 00:00:04,000\nWelcome to the talk.\n\n2\n00:00:04,500 --> 00:00:09,000\nToday: restart-safe ingestion.\n";
+
+#[async_trait::async_trait]
+impl StagingStore for TextStagingStore {
+    async fn store(
+        &self,
+        _path: &StoragePath,
+        _stream: futures::stream::BoxStream<'_, Result<bytes::Bytes, std::io::Error>>,
+        _content_length: Option<u64>,
+    ) -> Result<u64, StagingStoreError> {
+        Ok(0)
+    }
+
+    async fn fetch(&self, path: &StoragePath) -> Result<Vec<u8>, StagingStoreError> {
+        if path.as_str().ends_with(".srt") {
+            return Ok(CAPTIONS_SRT.as_bytes().to_vec());
+        }
+        Ok(b"Restart-safe ingestion keeps every queued document.".to_vec())
+    }
+
+    async fn delete(&self, _path: &StoragePath) -> Result<(), StagingStoreError> {
+        Ok(())
+    }
+
+    async fn head(&self, _path: &StoragePath) -> Result<u64, StagingStoreError> {
+        Ok(0)
+    }
+}
+
+struct UnusedTranscriptionEngine;
+
+#[async_trait::async_trait]
+impl TranscriptionEngine for UnusedTranscriptionEngine {
+    async fn transcribe(&self, _audio_data: &[u8]) -> Result<Transcript, TranscriptionError> {
+        Ok(Transcript::default())
+    }
+}
+
+/// Never finishes within a test, standing in for a long transcription.
+struct StalledTranscriptionEngine;
+
+#[async_trait::async_trait]
+impl TranscriptionEngine for StalledTranscriptionEngine {
+    async fn transcribe(&self, _audio_data: &[u8]) -> Result<Transcript, TranscriptionError> {
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        Ok(Transcript::default())
+    }
+}
+
+/// Transcribes until the attempt is abandoned, recording that it was stopped.
+#[derive(Default)]
+struct AbandonableTranscriptionEngine {
+    stopped: Arc<AtomicBool>,
+}
+
+struct SetOnDrop(Arc<AtomicBool>);
+
+impl Drop for SetOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[async_trait::async_trait]
+impl TranscriptionEngine for AbandonableTranscriptionEngine {
+    async fn transcribe(&self, _audio_data: &[u8]) -> Result<Transcript, TranscriptionError> {
+        let _stopped = SetOnDrop(Arc::clone(&self.stopped));
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        Ok(Transcript::default())
+    }
+}
+
+/// Reports a few segments of a long recording, then keeps transcribing past the test.
+struct TricklingTranscriptionEngine;
+
+#[async_trait::async_trait]
+impl TranscriptionEngine for TricklingTranscriptionEngine {
+    async fn transcribe(&self, _audio_data: &[u8]) -> Result<Transcript, TranscriptionError> {
+        unreachable!("media is transcribed from the spooled file")
+    }
+
+    async fn transcribe_file(
+        &self,
+        path: &Path,
+        observer: &dyn TranscriptionObserver,
+    ) -> Result<Transcript, TranscriptionError> {
+        assert!(path.exists(), "media must be spooled before transcription");
+        for i in 0..3 {
+            let start = i as f32 * 30.0;
+            observer.segment_transcribed(&TranscriptSegment::new(
+                format!("window {i}"),
+                start,
+                start + 30.0,
+            ));
+            tokio::task::yield_now().await;
+        }
+        tokio::time::sleep(Duration::from_secs(60)).await;
+        Ok(Transcript::default())
+    }
+}
+
+/// Fails the first `failures` calls with the error built by `error`, then embeds.
+struct FailingEmbedder {
+    failures: u32,
+    calls: std::sync::atomic::AtomicU32,
+    error: fn() -> EmbedderError,
+}
+
+impl FailingEmbedder {
+    fn new(failures: u32, error: fn() -> EmbedderError) -> Self {
+        Self {
+            failures,
+            calls: std::sync::atomic::AtomicU32::new(0),
+            error,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Embedder for FailingEmbedder {
+    async fn embed(&self, text: &str) -> Result<Embedding, EmbedderError> {
+        MockEmbedder.embed(text).await
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Embedding>, EmbedderError> {
+        let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        if call < self.failures {
+            return Err((self.error)());
+        }
+        MockEmbedder.embed_batch(texts).await
+    }
+}
+
+/// Records the webhook events the worker queues.
+#[derive(Default)]
+struct RecordingWebhooks {
+    events: Mutex<Vec<WebhookEvent>>,
+}
+
+#[async_trait::async_trait]
+impl WebhookRepository for RecordingWebhooks {
+    async fn create_subscription(
+        &self,
+        _subscription: &WebhookSubscription,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn list_subscriptions(
+        &self,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        Ok(Vec::new())
+    }
+
+    async fn get_subscription(
+        &self,
+        _id: WebhookSubscriptionId,
+    ) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn delete_subscription(
+        &self,
+        _id: WebhookSubscriptionId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    async fn enqueue(&self, event: &WebhookEvent) -> Result<usize, RepositoryError> {
+        self.events.lock().await.push(event.clone());
+        Ok(1)
+    }
+
+    async fn claim_due(
+        &self,
+        _batch_size: usize,
+        _lease: Duration,
+    ) -> Result<Vec<ClaimedWebhookDelivery>, RepositoryError> {
+        Ok(Vec::new())
+    }
+
+    async fn mark_delivered(
+        &self,
+        _id: uuid::Uuid,
+        _response_status: u16,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn schedule_retry(
+        &self,
+        _id: uuid::Uuid,
+        _delay: Duration,
+        _error: &str,
+        _response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        _id: uuid::Uuid,
+        _error: &str,
+        _response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn list_deliveries(
+        &self,
+        _subscription_id: WebhookSubscriptionId,
+        _limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        Ok(Vec::new())
+    }
+}
+
+/// Keeps the chunks the worker upserts, so tests can count what a document left behind.
+#[derive(Default)]
+struct ChunkStore {
+    chunks: Mutex<Vec<Chunk>>,
+}
+
+impl ChunkStore {
+    async fn count_for(&self, document_id: DocumentId) -> usize {
+        self.chunks
+            .lock()
+            .await
+            .iter()
+            .filter(|c| c.document_id == document_id)
+            .count()
+    }
+}
+
+#[async_trait::async_trait]
+impl VectorStore for ChunkStore {
+    async fn create_collection(
+        &self,
+        _config: &CollectionConfig,
+    ) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn collection_exists(&self) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn get_collection_vector_size(&self) -> Result<Option<u64>, VectorStoreError> {
+        Ok(Some(384))
+    }
+
+    async fn delete_collection(&self) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn upsert(
+        &self,
+        chunks: &[Chunk],
+        _embeddings: &[Embedding],
+    ) -> Result<(), VectorStoreError> {
+        self.chunks.lock().await.extend_from_slice(chunks);
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _embedding: &Embedding,
+        _top_k: usize,
+        _filter: &SearchFilter,
+    ) -> Result<Vec<SearchResult>, VectorStoreError> {
+        Ok(Vec::new())
+    }
+
+    async fn delete(&self, chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
+        self.chunks
+            .lock()
+            .await
+            .retain(|c| !chunk_ids.contains(&c.id));
+        Ok(())
+    }
+
+    async fn delete_by_document(
+        &self,
+        document_id: DocumentId,
+        keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        self.chunks
+            .lock()
+            .await
+            .retain(|c| c.document_id != document_id || keep.contains(&c.id));
+        Ok(())
+    }
+}
+
+// --- Helpers ---
+
+/// Records the transcripts the worker saves and the documents whose transcript it drops.
+#[derive(Default)]
+struct RecordingTranscripts {
+    saved: Mutex<Vec<(DocumentId, Transcript)>>,
+    deleted: Mutex<Vec<DocumentId>>,
+}
+
+#[async_trait::async_trait]
+impl TranscriptRepository for RecordingTranscripts {
+    async fn save(
+        &self,
+        document_id: DocumentId,
+        transcript: &Transcript,
+    ) -> Result<(), RepositoryError> {
+        self.saved
+            .lock()
+            .await
+            .push((document_id, transcript.clone()));
+        Ok(())
+    }
+
+    async fn get(&self, _document_id: DocumentId) -> Result<Option<Transcript>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn delete(&self, document_id: DocumentId) -> Result<(), RepositoryError> {
+        self.deleted.lock().await.push(document_id);
+        Ok(())
+    }
+}
+
+fn text_job() -> Job {
+    let document = Document::new("notes.txt".to_string(), ContentType::Text, 52);
+    let storage_path = StoragePath::new(&document.id, &document.filename);
+    Job::new(Some(document.id), "document_ingestion".to_string()).with_payload(IngestionPayload {
+        document,
+        storage_path,
+        replace_existing: false,
+        principal: None,
+        source: SourceMetadata::default(),
+        captions: None,
+    })
+}
+
+fn in_flight(mut job: Job, attempts: u32, lease_expires_in: Option<chrono::Duration>) -> Job {
+    job.status = JobStatus::Embedding;
+    job.attempts = attempts;
+    job.lease_owner = Some("crashed-worker-0".to_string());
+    job.lease_expires_at = lease_expires_in.map(|d| Utc::now() + d);
+    job
+}
+
+fn audio_job() -> Job {
+    let document = Document::new("meeting.mp3".to_string(), ContentType::Audio, 52);
+    let storage_path = StoragePath::new(&document.id, &document.filename);
+    Job::new(Some(document.id), "document_ingestion".to_string()).with_payload(IngestionPayload {
+        document,
+        storage_path,
+        replace_existing: false,
+        principal: None,
+        source: SourceMetadata::default(),
+        captions: None,
+    })
+}
+
+fn spawn_worker(queue: &Arc<InMemoryJobQueue>) -> tokio::task::JoinHandle<()> {
+    spawn_worker_with(
+        queue,
+        Arc::new(MockEmbedder),
+        Arc::new(UnusedTranscriptionEngine),
+    )
+}
+
+fn spawn_worker_with(
+    queue: &Arc<InMemoryJobQueue>,
+    embedder: Arc<dyn Embedder>,
+    transcription_engine: Arc<dyn TranscriptionEngine>,
+) -> tokio::task::JoinHandle<()> {
+    spawn_worker_publishing(
+        queue,
+        embedder,
+        transcription_engine,
+        Arc::new(BroadcastJobEventBus::default()),
+    )
+}
+
+fn spawn_worker_publishing(
+    queue: &Arc<InMemoryJobQueue>,
+    embedder: Arc<dyn Embedder>,
+    transcription_engine: Arc<dyn TranscriptionEngine>,
+    job_events: Arc<dyn JobEventBus>,
+) -> tokio::task::JoinHandle<()> {
+    let worker = build_worker(queue, embedder, transcription_engine).with_job_events(job_events);
+    tokio::spawn(worker.run())
+}
+
+fn build_worker(
+    queue: &Arc<InMemoryJobQueue>,
+    embedder: Arc<dyn Embedder>,
+    transcription_engine: Arc<dyn TranscriptionEngine>,
+) -> IngestionWorker<MockFileLoader, MockVectorStore> {
+    build_worker_storing(
+        queue,
+        embedder,
+        transcription_engine,
+        Arc::new(MockVectorStore),
+    )
+}
+
+fn build_worker_storing<V: VectorStore + 'static>(
+    queue: &Arc<InMemoryJobQueue>,
+    embedder: Arc<dyn Embedder>,
+    transcription_engine: Arc<dyn TranscriptionEngine>,
+    vector_store: Arc<V>,
+) -> IngestionWorker<MockFileLoader, V> {
+    let splitter = Arc::new(RecursiveCharacterSplitter::new(500, 50));
+    IngestionWorker::new(
+        Arc::new(MockFileLoader),
+        embedder,
+        vector_store,
+        splitter.clone(),
+        splitter,
+        Arc::clone(queue) as Arc<dyn JobRepository>,
+        transcription_engine,
+        Arc::new(TextStagingStore) as Arc<dyn StagingStore>,
+    )
+    .with_queue_options(IngestionQueueOptions {
+        worker_id: "test-worker".to_string(),
+        concurrency: 2,
+        poll_interval: Duration::from_millis(10),
+        lease: Duration::from_millis(150),
+        retry: RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(20),
+            rate_limited_base_delay: Duration::from_millis(40),
+            max_delay: Duration::from_secs(1),
+        },
+    })
+}
+
+async fn wait_for_status(queue: &InMemoryJobQueue, id: JobId, expected: JobStatus) -> bool {
+    for _ in 0..200 {
+        if queue.status_of(id).await == Some(expected) {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    false
+}
+
+// --- Tests ---
+
+#[tokio::test]
+async fn given_queued_job_when_worker_runs_then_job_is_claimed_and_completed() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = text_job();
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker(&queue);
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed);
+    let stored = queue.job(job.id).await.unwrap();
+    assert_eq!(stored.attempts, 1);
+    assert_eq!(stored.lease_owner.as_deref(), Some("test-worker-0"));
+}
+
+#[tokio::test]
+async fn given_job_abandoned_by_crashed_worker_when_worker_starts_then_job_is_requeued_and_completed()
+ {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = in_flight(text_job(), 1, Some(chrono::Duration::seconds(-5)));
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker(&queue);
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed);
+    assert_eq!(queue.job(job.id).await.unwrap().attempts, 2);
+}
+
+#[tokio::test]
+async fn given_chunks_upserted_by_an_earlier_attempt_when_job_is_reclaimed_then_they_are_pruned() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = in_flight(text_job(), 1, Some(chrono::Duration::seconds(-5)));
+    let document_id = job.document_id.unwrap();
+    queue.create(&job).await.unwrap();
+    let store = Arc::new(ChunkStore::default());
+    store
+        .upsert(
+            &[Chunk::new(
+                "Upserted by the crashed attempt.".to_string(),
+                document_id,
+                None,
+                0,
+            )],
+            &[],
+        )
+        .await
+        .unwrap();
+
+    let worker = tokio::spawn(
+        build_worker_storing(
+            &queue,
+            Arc::new(MockEmbedder),
+            Arc::new(UnusedTranscriptionEngine),
+            Arc::clone(&store),
+        )
+        .run(),
+    );
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed);
+    let progress = queue.job(job.id).await.unwrap().progress;
+    assert_eq!(
+        store.count_for(document_id).await,
+        progress.chunks_embedded as usize
+    );
+    assert!(
+        store
+            .chunks
+            .lock()
+            .await
+            .iter()
+            .all(|c| c.text != "Upserted by the crashed attempt.")
+    );
+}
+
+#[tokio::test]
+async fn given_job_with_live_lease_when_worker_starts_then_job_is_left_to_its_owner() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = in_flight(text_job(), 1, Some(chrono::Duration::seconds(30)));
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker(&queue);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    worker.abort();
+
+    let stored = queue.job(job.id).await.unwrap();
+    assert_eq!(stored.status, JobStatus::Embedding);
+    assert_eq!(stored.lease_owner.as_deref(), Some("crashed-worker-0"));
+}
+
+#[tokio::test]
+async fn given_stale_job_that_exhausted_attempts_when_worker_starts_then_job_is_failed() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = in_flight(text_job(), 3, Some(chrono::Duration::seconds(-5)));
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker(&queue);
+    let failed = wait_for_status(&queue, job.id, JobStatus::Failed).await;
+    worker.abort();
+
+    assert!(failed);
+    assert_eq!(queue.job(job.id).await.unwrap().attempts, 3);
+}
+
+#[tokio::test]
+async fn given_in_flight_job_without_payload_when_worker_starts_then_job_is_failed() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let mut job = in_flight(text_job(), 0, None);
+    job.payload = None;
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker(&queue);
+    let failed = wait_for_status(&queue, job.id, JobStatus::Failed).await;
+    worker.abort();
+
+    assert!(failed);
+    assert!(
+        queue
+            .job(job.id)
+            .await
+            .unwrap()
+            .error_message
+            .unwrap()
+            .contains("resubmit")
+    );
+}
+
+#[tokio::test]
+async fn given_completed_text_job_when_reading_job_then_progress_counts_pages_and_embedded_chunks()
+{
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = text_job();
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker(&queue);
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed);
+    let progress = queue.job(job.id).await.unwrap().progress;
+    assert_eq!(progress.pages_processed, 1);
+    assert!(progress.chunks_total > 0);
+    assert_eq!(progress.chunks_embedded, progress.chunks_total);
+}
+
+#[tokio::test]
+async fn given_rate_limited_embedder_when_job_runs_then_job_is_retried_and_completed() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = text_job();
+    queue.create(&job).await.unwrap();
+
+    let embedder = Arc::new(FailingEmbedder::new(1, || EmbedderError::RateLimited));
+    let worker = spawn_worker_with(&queue, embedder, Arc::new(UnusedTranscriptionEngine));
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed);
+    assert_eq!(queue.job(job.id).await.unwrap().attempts, 2);
+}
+
+#[tokio::test]
+async fn given_persistent_network_error_when_job_runs_then_job_fails_after_max_attempts() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = text_job();
+    queue.create(&job).await.unwrap();
+
+    let embedder = Arc::new(FailingEmbedder::new(u32::MAX, || {
+        EmbedderError::ApiRequestFailed("connection reset".to_string())
+    }));
+    let worker = spawn_worker_with(&queue, embedder, Arc::new(UnusedTranscriptionEngine));
+    let failed = wait_for_status(&queue, job.id, JobStatus::Failed).await;
+    worker.abort();
+
+    assert!(failed);
+    let stored = queue.job(job.id).await.unwrap();
+    assert_eq!(stored.attempts, 3);
+    assert!(stored.error_message.unwrap().contains("connection reset"));
+}
+
+#[tokio::test]
+async fn given_permanent_error_when_job_runs_then_job_fails_without_retry() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = text_job();
+    queue.create(&job).await.unwrap();
+
+    let embedder = Arc::new(FailingEmbedder::new(u32::MAX, || {
+        EmbedderError::InvalidResponse("dimension mismatch".to_string())
+    }));
+    let worker = spawn_worker_with(&queue, embedder, Arc::new(UnusedTranscriptionEngine));
+    let failed = wait_for_status(&queue, job.id, JobStatus::Failed).await;
+    worker.abort();
+
+    assert!(failed);
+    assert_eq!(queue.job(job.id).await.unwrap().attempts, 1);
+}
+
+#[tokio::test]
+async fn given_long_transcription_when_cancel_is_requested_then_job_is_cancelled() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = audio_job();
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker_with(
+        &queue,
+        Arc::new(MockEmbedder),
+        Arc::new(StalledTranscriptionEngine),
+    );
+    assert!(wait_for_status(&queue, job.id, JobStatus::Transcribing).await);
+    let status = queue.request_cancel(job.id).await.unwrap();
+    let cancelled = wait_for_status(&queue, job.id, JobStatus::Cancelled).await;
+    worker.abort();
+
+    assert_eq!(status, Some(JobStatus::Transcribing));
+    assert!(cancelled);
+    assert_eq!(
+        queue.job(job.id).await.unwrap().error_message.as_deref(),
+        Some(JOB_CANCELLED)
+    );
+}
+
+#[tokio::test]
+async fn given_lease_lost_mid_transcription_when_heartbeat_runs_then_attempt_stops_without_settling()
+ {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = audio_job();
+    queue.create(&job).await.unwrap();
+    let engine = Arc::new(AbandonableTranscriptionEngine::default());
+    let stopped = Arc::clone(&engine.stopped);
+
+    let worker = spawn_worker_with(&queue, Arc::new(MockEmbedder), engine);
+    assert!(wait_for_status(&queue, job.id, JobStatus::Transcribing).await);
+    queue.hand_over(job.id, "other-worker-0").await;
+    let mut abandoned = false;
+    for _ in 0..200 {
+        if stopped.load(Ordering::SeqCst) {
+            abandoned = true;
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    worker.abort();
+
+    assert!(abandoned);
+    let stored = queue.job(job.id).await.unwrap();
+    assert_eq!(stored.status, JobStatus::Transcribing);
+    assert_eq!(stored.lease_owner.as_deref(), Some("other-worker-0"));
+    assert!(stored.error_message.is_none());
+}
+
+#[tokio::test]
+async fn given_long_transcription_when_segments_are_produced_then_progress_is_reported_before_it_ends()
+ {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = audio_job();
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker_with(
+        &queue,
+        Arc::new(MockEmbedder),
+        Arc::new(TricklingTranscriptionEngine),
+    );
+    let mut transcribed = 0;
+    for _ in 0..200 {
+        transcribed = queue
+            .job(job.id)
+            .await
+            .unwrap()
+            .progress
+            .segments_transcribed;
+        if transcribed == 3 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    let status = queue.status_of(job.id).await;
+    queue.request_cancel(job.id).await.unwrap();
+    let cancelled = wait_for_status(&queue, job.id, JobStatus::Cancelled).await;
+    worker.abort();
+
+    assert_eq!(transcribed, 3);
+    assert_eq!(status, Some(JobStatus::Transcribing));
+    assert!(cancelled);
+}
+
+#[tokio::test]
+async fn given_subscriber_when_job_runs_then_status_transitions_and_progress_are_published() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = text_job();
+    queue.create(&job).await.unwrap();
+    let job_events = Arc::new(BroadcastJobEventBus::default());
+    let mut events = job_events.subscribe();
+
+    let worker = spawn_worker_publishing(
+        &queue,
+        Arc::new(MockEmbedder),
+        Arc::new(UnusedTranscriptionEngine),
+        job_events,
+    );
+    let mut statuses = Vec::new();
+    let mut last_progress = None;
+    while statuses.last() != Some(&JobStatus::Completed) {
+        let event = tokio::time::timeout(Duration::from_secs(2), events.recv())
+            .await
+            .expect("worker stopped publishing")
+            .unwrap();
+        assert_eq!(event.job_id, job.id);
+        match event.kind {
+            JobEventKind::StatusChanged { status, .. } => statuses.push(status),
+            JobEventKind::Progress(progress) => last_progress = Some(progress),
+        }
+    }
+    worker.abort();
+
+    assert_eq!(
+        statuses,
+        vec![
+            JobStatus::Processing,
+            JobStatus::Embedding,
+            JobStatus::Completed
+        ]
+    );
+    let progress = last_progress.expect("no progress published");
+    assert_eq!(progress.chunks_embedded, progress.chunks_total);
+}
+
+#[tokio::test]
+async fn given_webhooks_when_job_completes_then_document_ingested_event_is_queued() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = text_job();
+    queue.create(&job).await.unwrap();
+    let webhooks = Arc::new(RecordingWebhooks::default());
+
+    let worker = tokio::spawn(
+        build_worker(
+            &queue,
+            Arc::new(MockEmbedder),
+            Arc::new(UnusedTranscriptionEngine),
+        )
+        .with_webhooks(Arc::clone(&webhooks) as Arc<dyn WebhookRepository>)
+        .run(),
+    );
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed);
+    let events = webhooks.events.lock().await;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].event_type, WebhookEventType::DocumentIngested);
+    assert_eq!(events[0].tenant_id, job.tenant_id);
+    let document_id = job.document_id.unwrap().as_uuid().to_string();
+    assert_eq!(events[0].data["document_id"], document_id.as_str());
+    assert_eq!(events[0].data["filename"], "notes.txt");
+    assert!(events[0].data["chunk_count"].as_u64().unwrap() > 0);
+}
+
+#[tokio::test]
+async fn given_webhooks_when_job_fails_for_good_then_job_failed_event_is_queued_once() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = text_job();
+    queue.create(&job).await.unwrap();
+    let webhooks = Arc::new(RecordingWebhooks::default());
+    let embedder = Arc::new(FailingEmbedder::new(u32::MAX, || {
+        EmbedderError::ApiRequestFailed("connection reset".to_string())
+    }));
+
+    let worker = tokio::spawn(
+        build_worker(&queue, embedder, Arc::new(UnusedTranscriptionEngine))
+            .with_webhooks(Arc::clone(&webhooks) as Arc<dyn WebhookRepository>)
+            .run(),
+    );
+    let failed = wait_for_status(&queue, job.id, JobStatus::Failed).await;
+    worker.abort();
+
+    assert!(failed);
+    let events = webhooks.events.lock().await;
+    assert_eq!(events.len(), 1, "retried attempts must not notify");
+    assert_eq!(events[0].event_type, WebhookEventType::JobFailed);
+    assert_eq!(events[0].data["attempts"], 3);
+    assert!(
+        events[0].data["error"]
+            .as_str()
+            .unwrap()
+            .contains("connection reset")
+    );
+}
+
+#[tokio::test]
+async fn given_crawl_job_without_url_ingestion_when_worker_runs_then_job_fails() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = Job::new(None, "url_ingestion".to_string()).with_payload(CrawlRequest {
+        url: "https://example.com/".to_string(),
+        sitemap: false,
+        max_depth: 0,
+        max_pages: 1,
+        allowed_domains: vec!["example.com".to_string()],
+        principal: None,
+        source: SourceMetadata::default(),
+    });
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker(&queue);
+    let failed = wait_for_status(&queue, job.id, JobStatus::Failed).await;
+    worker.abort();
+
+    assert!(failed);
+    assert!(
+        queue
+            .job(job.id)
+            .await
+            .unwrap()
+            .error_message
+            .is_some_and(|e| e.contains("not configured"))
+    );
+}
+
+#[tokio::test]
+async fn given_repository_sync_without_repository_ingestion_when_worker_runs_then_job_fails() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let job = Job::new(None, "repository_sync".to_string()).with_payload(RepositorySyncRequest {
+        path: "/srv/repos/widgets".to_string(),
+        name: "widgets".to_string(),
+        principal: None,
+        source: SourceMetadata::default(),
+    });
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker(&queue);
+    let failed = wait_for_status(&queue, job.id, JobStatus::Failed).await;
+    worker.abort();
+
+    assert!(failed);
+    assert!(
+        queue
+            .job(job.id)
+            .await
+            .unwrap()
+            .error_message
+            .is_some_and(|e| e.contains("not configured"))
+    );
+}
+
+#[tokio::test]
+async fn given_subtitle_document_when_worker_runs_then_cues_become_segments() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let document = Document::new("talk.srt".to_string(), ContentType::Subtitles, 120);
+    let storage_path = StoragePath::new(&document.id, &document.filename);
+    let job = Job::new(Some(document.id), "document_ingestion".to_string()).with_payload(
+        IngestionPayload {
+            document,
+            storage_path,
+            replace_existing: false,
+            principal: None,
+            source: SourceMetadata::default(),
+            captions: None,
+        },
+    );
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker(&queue);
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed);
+    assert_eq!(
+        queue
+            .job(job.id)
+            .await
+            .unwrap()
+            .progress
+            .segments_transcribed,
+        2
+    );
+}
+
+#[tokio::test]
+async fn given_audio_with_captions_when_worker_runs_then_transcription_is_skipped() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let mut job = audio_job();
+    if let Some(JobPayload::Ingestion(payload)) = job.payload.as_mut() {
+        payload.captions = Some(StoragePath::from_raw("captions/meeting.srt"));
+    }
+    queue.create(&job).await.unwrap();
+
+    let worker = spawn_worker_with(
+        &queue,
+        Arc::new(MockEmbedder),
+        Arc::new(StalledTranscriptionEngine),
+    );
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed, "stalled transcription engine must not be called");
+    assert_eq!(
+        queue
+            .job(job.id)
+            .await
+            .unwrap()
+            .progress
+            .segments_transcribed,
+        2
+    );
+}
+
+#[tokio::test]
+async fn given_transcript_repository_when_captioned_audio_completes_then_transcript_is_saved() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let mut job = audio_job();
+    if let Some(JobPayload::Ingestion(payload)) = job.payload.as_mut() {
+        payload.captions = Some(StoragePath::from_raw("captions/meeting.srt"));
+    }
+    queue.create(&job).await.unwrap();
+    let transcripts = Arc::new(RecordingTranscripts::default());
+
+    let worker = tokio::spawn(
+        build_worker(
+            &queue,
+            Arc::new(MockEmbedder),
+            Arc::new(UnusedTranscriptionEngine),
+        )
+        .with_transcript_repository(Arc::clone(&transcripts) as Arc<dyn TranscriptRepository>)
+        .run(),
+    );
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed);
+    let saved = transcripts.saved.lock().await;
+    assert_eq!(saved.len(), 1);
+    assert_eq!(saved[0].0, job.document_id.unwrap());
+    assert_eq!(saved[0].1.language, None);
+    assert_eq!(
+        saved[0].1.segments,
+        vec![
+            TranscriptSegment::new("Welcome to the talk.", 1.0, 4.0),
+            TranscriptSegment::new("Today: restart-safe ingestion.", 4.5, 9.0),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn given_document_replaced_by_text_when_job_completes_then_stale_transcript_is_dropped() {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    let mut job = text_job();
+    if let Some(JobPayload::Ingestion(payload)) = job.payload.as_mut() {
+        payload.replace_existing = true;
+    }
+    queue.create(&job).await.unwrap();
+    let transcripts = Arc::new(RecordingTranscripts::default());
+
+    let worker = tokio::spawn(
+        build_worker(
+            &queue,
+            Arc::new(MockEmbedder),
+            Arc::new(UnusedTranscriptionEngine),
+        )
+        .with_transcript_repository(Arc::clone(&transcripts) as Arc<dyn TranscriptRepository>)
+        .run(),
+    );
+    let completed = wait_for_status(&queue, job.id, JobStatus::Completed).await;
+    worker.abort();
+
+    assert!(completed);
+    assert!(transcripts.saved.lock().await.is_empty());
+    assert_eq!(
+        *transcripts.deleted.lock().await,
+        vec![job.document_id.unwrap()]
+    );
+}
+
+async fn run_against_catalog(job: &Job, catalog: VanishingCatalog) -> Option<Job> {
+    let queue = Arc::new(InMemoryJobQueue::default());
+    queue.create(job).await.unwrap();
+
+    let worker = build_worker(
+        &queue,
+        Arc::new(MockEmbedder),
+        Arc::new(UnusedTranscriptionEngine),
+    )
+    .with_document_repository(Arc::new(catalog) as Arc<dyn DocumentRepository>);
+    let handle = tokio::spawn(worker.run());
+    let cancelled = wait_for_status(&queue, job.id, JobStatus::Cancelled).await;
+    handle.abort();
+
+    assert!(cancelled);
+    queue.job(job.id).await
+}
+
+#[tokio::test]
+async fn given_document_deleted_before_chunks_are_written_when_job_runs_then_job_is_cancelled() {
+    let job = text_job();
+
+    let settled = run_against_catalog(&job, VanishingCatalog { record: None }).await;
+
+    assert_eq!(
+        settled.unwrap().error_message.as_deref(),
+        Some("document deleted during ingestion")
+    );
+}
+
+#[tokio::test]
+async fn given_document_deleted_after_chunks_are_written_when_job_completes_then_job_is_cancelled()
+{
+    let job = text_job();
+    let payload = job.ingestion_payload().cloned().unwrap();
+    let record = DocumentRecord::new(&payload.document, payload.storage_path, true);
+
+    let settled = run_against_catalog(
+        &job,
+        VanishingCatalog {
+            record: Some(record),
+        },
+    )
+    .await;
+
+    assert_eq!(
+        settled.unwrap().error_message.as_deref(),
+        Some("document deleted during ingestion")
+    );
+}
@@ -1,6 +1,17 @@
 mod agent_service_test;
+mod api_key_service_test;
+mod document_service_test;
 mod eval_metrics_test;
+mod eval_runner_test;
 mod eval_worker_test;
+mod ingestion_worker_test;
+mod query_transform_test;
+mod repository_ingestion_service_test;
 mod retrieval_service_test;
+mod retry_policy_test;
+mod search_filter_test;
 mod timestamp_citation_test;
 mod token_counter_test;
+mod url_ingestion_service_test;
+mod webhook_dispatcher_test;
+mod webhook_service_test;
@@ -0,0 +1,484 @@
+use std::sync::{Arc, Mutex};
+
+use sandakan::application::ports::{
+    AgentMessage, CollectionConfig, ConversationRepository, Embedder, EmbedderError, LlmClient,
+    LlmClientError, LlmTokenStream, LlmToolResponse, RepositoryError, SearchFilter, SearchResult,
+    ToolSchema, VectorStore, VectorStoreError,
+};
+use sandakan::application::services::{QueryTransformOptions, RetrievalService};
+use sandakan::domain::{
+    Chunk, ChunkId, Conversation, ConversationId, DocumentId, Embedding, Message, MessageRole,
+    TenantId,
+};
+
+const TOP_K: usize = 5;
+const SIMILARITY_THRESHOLD: f32 = 0.5;
+const MAX_CONTEXT_TOKENS: usize = 3072;
+const FALLBACK: &str = "No answer.";
+
+const STANDALONE_QUESTION: &str = "How much does the Model X cost?";
+const HYDE_PASSAGE: &str = "The Model X costs 999 euros.";
+
+// ─── Mocks ───────────────────────────────────────────────────────────────────
+
+/// Answers transformation prompts with canned text and records every prompt it sees.
+#[derive(Default)]
+struct ScriptedLlmClient {
+    fail_transforms: bool,
+    prompts: Mutex<Vec<String>>,
+}
+
+impl ScriptedLlmClient {
+    fn failing() -> Self {
+        Self {
+            fail_transforms: true,
+            ..Self::default()
+        }
+    }
+
+    fn answer_prompts(&self) -> Vec<String> {
+        self.prompts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|p| p.starts_with("ANSWER:"))
+            .map(|p| p.trim_start_matches("ANSWER:").to_string())
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmClient for ScriptedLlmClient {
+    async fn complete(&self, prompt: &str, context: &str) -> Result<String, LlmClientError> {
+        if !context.is_empty() {
+            self.prompts
+                .lock()
+                .unwrap()
+                .push(format!("ANSWER:{}", prompt));
+            return Ok("Mock answer".to_string());
+        }
+        self.prompts.lock().unwrap().push(prompt.to_string());
+
+        if self.fail_transforms {
+            return Err(LlmClientError::ApiRequestFailed("offline".to_string()));
+        }
+        if prompt.contains("standalone question") {
+            Ok(STANDALONE_QUESTION.to_string())
+        } else if prompt.contains("different search queries") {
+            Ok("1. Model X price\n2. \"Model X cost in euros\"\n3. surplus line".to_string())
+        } else if prompt.contains("passage") {
+            Ok(HYDE_PASSAGE.to_string())
+        } else {
+            Ok(String::new())
+        }
+    }
+
+    async fn complete_stream(
+        &self,
+        _prompt: &str,
+        _context: &str,
+    ) -> Result<LlmTokenStream, LlmClientError> {
+        Ok(Box::pin(futures::stream::once(async {
+            Ok("Mock answer".to_string())
+        })))
+    }
+
+    async fn complete_stream_with_messages(
+        &self,
+        _messages: &[AgentMessage],
+    ) -> Result<LlmTokenStream, LlmClientError> {
+        Ok(Box::pin(futures::stream::once(async {
+            Ok("Mock answer".to_string())
+        })))
+    }
+
+    async fn complete_with_tools(
+        &self,
+        _messages: &[AgentMessage],
+        _tools: &[ToolSchema],
+    ) -> Result<LlmToolResponse, LlmClientError> {
+        Ok(LlmToolResponse::Content("Mock answer".to_string()))
+    }
+}
+
+/// Encodes the call order in the embedding so the vector store can tell searches apart.
+#[derive(Default)]
+struct RecordingEmbedder {
+    texts: Mutex<Vec<String>>,
+}
+
+impl RecordingEmbedder {
+    fn texts(&self) -> Vec<String> {
+        self.texts.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl Embedder for RecordingEmbedder {
+    async fn embed(&self, text: &str) -> Result<Embedding, EmbedderError> {
+        let mut texts = self.texts.lock().unwrap();
+        texts.push(text.to_string());
+        Ok(Embedding::new(vec![(texts.len() - 1) as f32; 4]))
+    }
+
+    async fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Embedding>, EmbedderError> {
+        let mut embeddings = Vec::new();
+        for text in texts {
+            embeddings.push(self.embed(text).await?);
+        }
+        Ok(embeddings)
+    }
+}
+
+/// The first search returns `[a, b]`; every later search returns `[c, b]`.
+struct TwoListVectorStore {
+    a: Chunk,
+    b: Chunk,
+    c: Chunk,
+}
+
+impl TwoListVectorStore {
+    fn new() -> Self {
+        let chunk = |text: &str| Chunk::new(text.to_string(), DocumentId::new(), None, 0);
+        Self {
+            a: chunk("chunk-a"),
+            b: chunk("chunk-b"),
+            c: chunk("chunk-c"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl VectorStore for TwoListVectorStore {
+    async fn create_collection(
+        &self,
+        _config: &CollectionConfig,
+    ) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn collection_exists(&self) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn get_collection_vector_size(&self) -> Result<Option<u64>, VectorStoreError> {
+        Ok(Some(4))
+    }
+
+    async fn delete_collection(&self) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn upsert(
+        &self,
+        _chunks: &[Chunk],
+        _embeddings: &[Embedding],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        embedding: &Embedding,
+        _top_k: usize,
+        _filter: &SearchFilter,
+    ) -> Result<Vec<SearchResult>, VectorStoreError> {
+        let hit = |chunk: &Chunk, score: f32| SearchResult {
+            chunk: chunk.clone(),
+            score,
+        };
+        if embedding.values[0] == 0.0 {
+            Ok(vec![hit(&self.a, 0.9), hit(&self.b, 0.8)])
+        } else {
+            Ok(vec![hit(&self.c, 0.85), hit(&self.b, 0.95)])
+        }
+    }
+
+    async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+}
+
+struct HistoryRepository {
+    history: Vec<Message>,
+}
+
+#[async_trait::async_trait]
+impl ConversationRepository for HistoryRepository {
+    async fn create_conversation(
+        &self,
+        _conversation: &Conversation,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn get_conversation(
+        &self,
+        _id: ConversationId,
+    ) -> Result<Option<Conversation>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn get_conversation_tenant(
+        &self,
+        _id: ConversationId,
+    ) -> Result<Option<TenantId>, RepositoryError> {
+        Ok(None)
+    }
+
+    async fn append_message(&self, _message: &Message) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn get_messages(
+        &self,
+        _conversation_id: ConversationId,
+        limit: usize,
+    ) -> Result<Vec<Message>, RepositoryError> {
+        let skip = self.history.len().saturating_sub(limit);
+        Ok(self.history[skip..].to_vec())
+    }
+}
+
+fn history(conversation_id: ConversationId) -> Vec<Message> {
+    vec![
+        Message::new(
+            conversation_id,
+            MessageRole::User,
+            "Tell me about the Model X.".to_string(),
+        ),
+        Message::new(
+            conversation_id,
+            MessageRole::Assistant,
+            "The Model X is our flagship scooter.".to_string(),
+        ),
+    ]
+}
+
+fn service(
+    embedder: Arc<RecordingEmbedder>,
+    llm_client: Arc<ScriptedLlmClient>,
+    history: Vec<Message>,
+    options: QueryTransformOptions,
+) -> RetrievalService<ScriptedLlmClient, TwoListVectorStore> {
+    RetrievalService::new(
+        embedder,
+        llm_client,
+        Arc::new(TwoListVectorStore::new()),
+        Arc::new(HistoryRepository { history }),
+        None,
+        None,
+        None,
+        "test/mock-model".to_string(),
+        TOP_K,
+        SIMILARITY_THRESHOLD,
+        MAX_CONTEXT_TOKENS,
+        FALLBACK.to_string(),
+    )
+    .with_query_transform(options)
+}
+
+// ─── Condensing ──────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn given_condense_enabled_when_querying_follow_up_then_searches_and_answers_standalone_question()
+ {
+    let conversation_id = ConversationId::new();
+    let embedder = Arc::new(RecordingEmbedder::default());
+    let llm_client = Arc::new(ScriptedLlmClient::default());
+    let service = service(
+        Arc::clone(&embedder),
+        Arc::clone(&llm_client),
+        history(conversation_id),
+        QueryTransformOptions {
+            condense: true,
+            history_messages: 6,
+            ..QueryTransformOptions::default()
+        },
+    );
+
+    service
+        .query(
+            "How much is it?",
+            Some(conversation_id),
+            None,
+            &SearchFilter::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(embedder.texts(), vec![STANDALONE_QUESTION]);
+    assert_eq!(llm_client.answer_prompts(), vec![STANDALONE_QUESTION]);
+    let prompts = llm_client.prompts.lock().unwrap();
+    assert!(prompts[0].contains("The Model X is our flagship scooter."));
+}
+
+#[tokio::test]
+async fn given_condense_enabled_without_conversation_when_querying_then_question_is_used_verbatim()
+{
+    let embedder = Arc::new(RecordingEmbedder::default());
+    let llm_client = Arc::new(ScriptedLlmClient::default());
+    let service = service(
+        Arc::clone(&embedder),
+        Arc::clone(&llm_client),
+        Vec::new(),
+        QueryTransformOptions {
+            condense: true,
+            history_messages: 6,
+            ..QueryTransformOptions::default()
+        },
+    );
+
+    service
+        .query("How much is it?", None, None, &SearchFilter::default())
+        .await
+        .unwrap();
+
+    assert_eq!(embedder.texts(), vec!["How much is it?"]);
+    assert_eq!(llm_client.answer_prompts(), vec!["How much is it?"]);
+}
+
+#[tokio::test]
+async fn given_condense_enabled_and_empty_history_when_querying_then_no_rewrite_is_requested() {
+    let embedder = Arc::new(RecordingEmbedder::default());
+    let llm_client = Arc::new(ScriptedLlmClient::default());
+    let service = service(
+        Arc::clone(&embedder),
+        Arc::clone(&llm_client),
+        Vec::new(),
+        QueryTransformOptions {
+            condense: true,
+            history_messages: 6,
+            ..QueryTransformOptions::default()
+        },
+    );
+
+    service
+        .query(
+            "How much is it?",
+            Some(ConversationId::new()),
+            None,
+            &SearchFilter::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(embedder.texts(), vec!["How much is it?"]);
+    assert_eq!(llm_client.prompts.lock().unwrap().len(), 1);
+}
+
+// ─── Multi-query and HyDE ────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn given_paraphrases_enabled_when_searching_then_each_paraphrase_is_searched() {
+    let embedder = Arc::new(RecordingEmbedder::default());
+    let service = service(
+        Arc::clone(&embedder),
+        Arc::new(ScriptedLlmClient::default()),
+        Vec::new(),
+        QueryTransformOptions {
+            paraphrases: 2,
+            ..QueryTransformOptions::default()
+        },
+    );
+
+    service
+        .search_chunks("What does the Model X cost?", &SearchFilter::default())
+        .await
+        .unwrap();
+
+    let mut texts = embedder.texts();
+    texts.sort();
+    assert_eq!(
+        texts,
+        vec![
+            "Model X cost in euros",
+            "Model X price",
+            "What does the Model X cost?"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn given_hyde_enabled_when_searching_then_hypothetical_passage_is_searched() {
+    let embedder = Arc::new(RecordingEmbedder::default());
+    let service = service(
+        Arc::clone(&embedder),
+        Arc::new(ScriptedLlmClient::default()),
+        Vec::new(),
+        QueryTransformOptions {
+            hyde: true,
+            ..QueryTransformOptions::default()
+        },
+    );
+
+    service
+        .search_chunks("What does the Model X cost?", &SearchFilter::default())
+        .await
+        .unwrap();
+
+    let texts = embedder.texts();
+    assert_eq!(texts.len(), 2);
+    assert!(texts.contains(&HYDE_PASSAGE.to_string()));
+}
+
+#[tokio::test]
+async fn given_several_searches_when_fusing_then_chunk_found_by_all_ranks_first_with_best_score() {
+    let service = service(
+        Arc::new(RecordingEmbedder::default()),
+        Arc::new(ScriptedLlmClient::default()),
+        Vec::new(),
+        QueryTransformOptions {
+            hyde: true,
+            ..QueryTransformOptions::default()
+        },
+    );
+
+    let chunks = service
+        .search_chunks("What does the Model X cost?", &SearchFilter::default())
+        .await
+        .unwrap();
+
+    let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+    assert_eq!(texts.len(), 3);
+    assert_eq!(texts[0], "chunk-b");
+    assert!((chunks[0].score - 0.95).abs() < f32::EPSILON);
+}
+
+#[tokio::test]
+async fn given_transform_llm_failures_when_searching_then_falls_back_to_original_question() {
+    let conversation_id = ConversationId::new();
+    let embedder = Arc::new(RecordingEmbedder::default());
+    let service = service(
+        Arc::clone(&embedder),
+        Arc::new(ScriptedLlmClient::failing()),
+        history(conversation_id),
+        QueryTransformOptions {
+            condense: true,
+            history_messages: 6,
+            paraphrases: 3,
+            hyde: true,
+        },
+    );
+
+    let result = service
+        .query(
+            "How much is it?",
+            Some(conversation_id),
+            None,
+            &SearchFilter::default(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(embedder.texts(), vec!["How much is it?"]);
+    assert_eq!(result.sources.len(), 2);
+}
@@ -0,0 +1,381 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use tokio::sync::watch;
+
+use sandakan::application::ports::{
+    DocumentRepository, JobRepository, SourceFile, SourceTreeError, SourceTreeReader,
+};
+use sandakan::application::services::{
+    DocumentService, REPOSITORY_ROOT_ATTRIBUTE, RepositoryIngestionError,
+    RepositoryIngestionOptions, RepositoryIngestionService, SyncOutcome,
+};
+use sandakan::domain::{
+    ContentType, Document, DocumentRecord, Job, JobPayload, RepositorySyncRequest, SourceMetadata,
+    StoragePath, TenantId,
+};
+use sandakan::infrastructure::persistence::{MockTranscriptRepository, MockVectorStore};
+use sandakan::infrastructure::storage::MockStagingStore;
+
+use crate::helpers::{InMemoryDocumentRepository, InMemoryJobRepository};
+
+const ROOT: &str = "/srv/repos";
+const CHECKOUT: &str = "/srv/repos/widgets";
+
+// --- Hand-written mocks ---
+
+/// A checkout held in memory; paths outside [`ROOT`] resolve as not found.
+#[derive(Default)]
+struct InMemorySourceTree {
+    files: std::sync::Mutex<BTreeMap<String, Vec<u8>>>,
+}
+
+impl InMemorySourceTree {
+    fn write(&self, path: &str, contents: &str) {
+        self.files
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), contents.as_bytes().to_vec());
+    }
+
+    fn remove(&self, path: &str) {
+        self.files.lock().unwrap().remove(path);
+    }
+}
+
+#[async_trait::async_trait]
+impl SourceTreeReader for InMemorySourceTree {
+    async fn resolve(&self, root: &Path) -> Result<PathBuf, SourceTreeError> {
+        if root.starts_with("/srv") || root.starts_with("/etc") {
+            Ok(root.to_path_buf())
+        } else {
+            Err(SourceTreeError::NotFound(root.display().to_string()))
+        }
+    }
+
+    async fn list_files(&self, _root: &Path) -> Result<Vec<SourceFile>, SourceTreeError> {
+        Ok(self
+            .files
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(path, data)| SourceFile {
+                path: path.clone(),
+                size_bytes: data.len() as u64,
+            })
+            .collect())
+    }
+
+    async fn read(&self, _root: &Path, path: &str) -> Result<Vec<u8>, SourceTreeError> {
+        self.files
+            .lock()
+            .unwrap()
+            .get(path)
+            .cloned()
+            .ok_or_else(|| SourceTreeError::NotFound(path.to_string()))
+    }
+}
+
+// --- Fixture ---
+
+struct Fixture {
+    tree: Arc<InMemorySourceTree>,
+    documents: Arc<InMemoryDocumentRepository>,
+    jobs: Arc<InMemoryJobRepository>,
+    service: RepositoryIngestionService<MockVectorStore>,
+}
+
+impl Fixture {
+    fn new(options: RepositoryIngestionOptions) -> Self {
+        let tree = Arc::new(InMemorySourceTree::default());
+        let documents = Arc::new(InMemoryDocumentRepository::default());
+        let jobs = Arc::new(InMemoryJobRepository::default());
+        let document_service = Arc::new(DocumentService::new(
+            Arc::clone(&documents) as Arc<dyn DocumentRepository>,
+            Arc::clone(&jobs) as Arc<dyn JobRepository>,
+            Arc::new(MockVectorStore),
+            Arc::new(MockStagingStore),
+            Arc::new(MockTranscriptRepository),
+        ));
+        let service = RepositoryIngestionService::new(
+            Arc::clone(&tree) as Arc<dyn SourceTreeReader>,
+            Arc::new(MockStagingStore),
+            document_service,
+            Arc::clone(&jobs) as Arc<dyn JobRepository>,
+        )
+        .with_options(options);
+        Self {
+            tree,
+            documents,
+            jobs,
+            service,
+        }
+    }
+
+    fn enabled() -> Self {
+        Self::new(RepositoryIngestionOptions {
+            allowed_roots: vec![PathBuf::from(ROOT)],
+            ..RepositoryIngestionOptions::default()
+        })
+    }
+
+    /// Queues a sync of [`CHECKOUT`] and runs it as a worker would after claiming it.
+    async fn sync(&self) -> (Result<SyncOutcome, RepositoryIngestionError>, Job) {
+        let job_id = self
+            .service
+            .submit(&tenant(), sync_request(CHECKOUT))
+            .await
+            .unwrap();
+        let job = self.jobs.job(job_id).await;
+        let Some(JobPayload::RepositorySync(request)) = job.payload.clone() else {
+            panic!("sync job carries its request");
+        };
+        let (_cancel, cancelled) = watch::channel(false);
+        let result = self.service.sync(&job, &request, cancelled).await;
+        (result, self.jobs.job(job_id).await)
+    }
+}
+
+fn tenant() -> TenantId {
+    TenantId::parse("acme").unwrap()
+}
+
+fn sync_request(path: &str) -> RepositorySyncRequest {
+    RepositorySyncRequest {
+        path: path.to_string(),
+        name: String::new(),
+        principal: None,
+        source: SourceMetadata::default(),
+    }
+}
+
+fn outcome(ingested: u32, unchanged: u32, removed: u32, failed: u32) -> SyncOutcome {
+    SyncOutcome {
+        ingested,
+        unchanged,
+        removed,
+        failed,
+    }
+}
+
+// --- Tests ---
+
+#[tokio::test]
+async fn given_checkout_under_root_when_submitting_then_job_is_queued_with_directory_name() {
+    let fixture = Fixture::enabled();
+
+    let job_id = fixture
+        .service
+        .submit(&tenant(), sync_request(CHECKOUT))
+        .await
+        .unwrap();
+
+    let job = fixture.jobs.job(job_id).await;
+    assert_eq!(job.job_type, "repository_sync");
+    let Some(JobPayload::RepositorySync(request)) = job.payload else {
+        panic!("expected a repository sync payload");
+    };
+    assert_eq!(request.path, CHECKOUT);
+    assert_eq!(request.name, "widgets");
+}
+
+#[tokio::test]
+async fn given_checkout_outside_roots_when_submitting_then_forbidden() {
+    let fixture = Fixture::enabled();
+
+    let result = fixture
+        .service
+        .submit(&tenant(), sync_request("/etc/secrets"))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(RepositoryIngestionError::Forbidden(_))
+    ));
+}
+
+#[tokio::test]
+async fn given_no_allowed_roots_when_submitting_then_disabled() {
+    let fixture = Fixture::new(RepositoryIngestionOptions::default());
+
+    let result = fixture
+        .service
+        .submit(&tenant(), sync_request(CHECKOUT))
+        .await;
+
+    assert!(matches!(result, Err(RepositoryIngestionError::Disabled)));
+}
+
+#[tokio::test]
+async fn given_invalid_requests_when_submitting_then_rejected() {
+    let fixture = Fixture::enabled();
+    let mut nested_name = sync_request(CHECKOUT);
+    nested_name.name = "a/b".to_string();
+
+    for request in [sync_request(""), sync_request("/missing"), nested_name] {
+        let result = fixture.service.submit(&tenant(), request).await;
+        assert!(matches!(
+            result,
+            Err(RepositoryIngestionError::InvalidRequest(_))
+        ));
+    }
+}
+
+#[tokio::test]
+async fn given_new_checkout_when_syncing_then_source_and_markdown_files_are_submitted() {
+    let fixture = Fixture::enabled();
+    fixture.tree.write("src/lib.rs", "pub fn a() {}\n");
+    fixture.tree.write("README.md", "# Widgets\n");
+    fixture.tree.write("logo.png", "not code");
+
+    let (result, job) = fixture.sync().await;
+
+    assert_eq!(result.unwrap(), outcome(2, 0, 0, 0));
+    assert_eq!(
+        fixture.documents.filenames().await,
+        vec!["widgets/README.md", "widgets/src/lib.rs"]
+    );
+    let records = fixture.documents.records.lock().await;
+    let lib = records
+        .iter()
+        .find(|r| r.filename == "widgets/src/lib.rs")
+        .unwrap();
+    assert_eq!(lib.content_type, ContentType::Code);
+    assert_eq!(job.progress.files_ingested, 2);
+}
+
+#[tokio::test]
+async fn given_previous_sync_when_resyncing_then_only_changed_and_removed_files_are_touched() {
+    let fixture = Fixture::enabled();
+    fixture.tree.write("src/lib.rs", "pub fn a() {}\n");
+    fixture.tree.write("src/old.rs", "pub fn old() {}\n");
+    fixture.tree.write("src/main.rs", "fn main() {}\n");
+    fixture.sync().await.0.unwrap();
+    fixture.documents.ingest_all().await;
+    let ingestion_jobs = fixture.jobs.count_of_type("document_ingestion").await;
+
+    fixture
+        .tree
+        .write("src/lib.rs", "pub fn a() -> u32 { 1 }\n");
+    fixture.tree.remove("src/old.rs");
+    let (result, job) = fixture.sync().await;
+
+    assert_eq!(result.unwrap(), outcome(1, 1, 1, 0));
+    assert_eq!(
+        fixture.documents.filenames().await,
+        vec!["widgets/src/lib.rs", "widgets/src/main.rs"]
+    );
+    assert_eq!(
+        fixture.jobs.count_of_type("document_ingestion").await,
+        ingestion_jobs + 1
+    );
+    assert_eq!(
+        (
+            job.progress.files_ingested,
+            job.progress.files_unchanged,
+            job.progress.files_removed
+        ),
+        (1, 1, 1)
+    );
+}
+
+#[tokio::test]
+async fn given_name_bound_to_other_checkout_when_submitting_then_conflict_and_documents_kept() {
+    let fixture = Fixture::enabled();
+    fixture.tree.write("src/lib.rs", "pub fn a() {}\n");
+    fixture.sync().await.0.unwrap();
+
+    let result = fixture
+        .service
+        .submit(&tenant(), sync_request("/srv/repos/fork/widgets"))
+        .await;
+
+    assert!(matches!(result, Err(RepositoryIngestionError::Conflict(_))));
+    let records = fixture.documents.records.lock().await;
+    assert_eq!(records.len(), 1);
+    assert_eq!(
+        records[0].source.attributes.get(REPOSITORY_ROOT_ATTRIBUTE),
+        Some(&CHECKOUT.to_string())
+    );
+}
+
+#[tokio::test]
+async fn given_document_not_synced_from_checkout_when_resyncing_then_it_is_not_removed() {
+    let fixture = Fixture::enabled();
+    fixture.tree.write("src/lib.rs", "pub fn a() {}\n");
+    let upload = Document::new("widgets/notes.md".to_string(), ContentType::Markdown, 8);
+    let storage_path = StoragePath::new(&upload.id, &upload.filename);
+    fixture
+        .documents
+        .create(&DocumentRecord::new(&upload, storage_path, true).with_tenant(tenant()))
+        .await
+        .unwrap();
+
+    let (result, _) = fixture.sync().await;
+
+    assert_eq!(result.unwrap(), outcome(1, 0, 0, 0));
+    assert_eq!(
+        fixture.documents.filenames().await,
+        vec!["widgets/notes.md", "widgets/src/lib.rs"]
+    );
+}
+
+#[tokio::test]
+async fn given_oversized_and_binary_files_when_syncing_then_recorded_as_failures() {
+    let fixture = Fixture::new(RepositoryIngestionOptions {
+        allowed_roots: vec![PathBuf::from(ROOT)],
+        max_file_bytes: 32,
+        ..RepositoryIngestionOptions::default()
+    });
+    fixture.tree.write("src/big.rs", &"// padding\n".repeat(10));
+    fixture.tree.write("src/blob.rs", "fn a() {}\0");
+    fixture.tree.write("src/ok.rs", "fn ok() {}\n");
+
+    let (result, job) = fixture.sync().await;
+
+    assert_eq!(result.unwrap(), outcome(1, 0, 0, 2));
+    let failed: Vec<&str> = job.fetch_failures.iter().map(|f| f.url.as_str()).collect();
+    assert_eq!(failed, vec!["widgets/src/big.rs", "widgets/src/blob.rs"]);
+}
+
+#[tokio::test]
+async fn given_more_files_than_limit_when_syncing_then_rest_is_summarized_in_one_failure() {
+    let fixture = Fixture::new(RepositoryIngestionOptions {
+        allowed_roots: vec![PathBuf::from(ROOT)],
+        max_files: 2,
+        ..RepositoryIngestionOptions::default()
+    });
+    for i in 0..5 {
+        fixture
+            .tree
+            .write(&format!("src/f{i}.rs"), &format!("fn f{i}() {{}}\n"));
+    }
+
+    let (result, job) = fixture.sync().await;
+
+    assert_eq!(result.unwrap(), outcome(2, 0, 0, 1));
+    assert_eq!(job.fetch_failures.len(), 1);
+    assert!(job.fetch_failures[0].error.contains("3 files skipped"));
+}
+
+#[tokio::test]
+async fn given_cancelled_sync_when_running_then_stops_with_cancelled() {
+    let fixture = Fixture::enabled();
+    fixture.tree.write("src/lib.rs", "pub fn a() {}\n");
+    let job_id = fixture
+        .service
+        .submit(&tenant(), sync_request(CHECKOUT))
+        .await
+        .unwrap();
+    let job = fixture.jobs.job(job_id).await;
+    let Some(JobPayload::RepositorySync(request)) = job.payload.clone() else {
+        panic!("sync job carries its request");
+    };
+    let (_cancel, cancelled) = watch::channel(true);
+
+    let result = fixture.service.sync(&job, &request, cancelled).await;
+
+    assert!(matches!(result, Err(RepositoryIngestionError::Cancelled)));
+    assert!(fixture.documents.filenames().await.is_empty());
+}
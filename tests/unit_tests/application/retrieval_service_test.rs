@@ -2,12 +2,13 @@ use std::sync::Arc;
 
 use sandakan::application::ports::{
     AgentMessage, CollectionConfig, ConversationRepository, Embedder, EmbedderError, LlmClient,
-    LlmClientError, LlmToolResponse, RepositoryError, SearchResult, ToolSchema, VectorStore,
-    VectorStoreError,
+    LlmClientError, LlmToolResponse, RepositoryError, Reranker, RerankerError, SearchFilter,
+    SearchResult, ToolSchema, VectorStore, VectorStoreError,
 };
 use sandakan::application::services::RetrievalService;
 use sandakan::domain::{
     Chunk, ChunkId, Conversation, ConversationId, DocumentId, DocumentMetadata, Embedding, Message,
+    TenantId,
 };
 
 const TEST_TOP_K: usize = 5;
@@ -116,6 +117,7 @@ impl VectorStore for MockVectorStoreHighScore {
         &self,
         _embedding: &Embedding,
         _top_k: usize,
+        _filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         Ok(vec![SearchResult {
             chunk: Chunk::new("test chunk".to_string(), DocumentId::new(), Some(1), 0),
@@ -126,6 +128,14 @@ impl VectorStore for MockVectorStoreHighScore {
     async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
         Ok(())
     }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
 }
 
 struct MockVectorStoreLowScore;
@@ -163,6 +173,7 @@ impl VectorStore for MockVectorStoreLowScore {
         &self,
         _embedding: &Embedding,
         _top_k: usize,
+        _filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         Ok(vec![SearchResult {
             chunk: Chunk::new("test chunk".to_string(), DocumentId::new(), Some(1), 0),
@@ -173,6 +184,14 @@ impl VectorStore for MockVectorStoreLowScore {
     async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
         Ok(())
     }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
 }
 
 struct MockVectorStoreEmpty;
@@ -210,6 +229,7 @@ impl VectorStore for MockVectorStoreEmpty {
         &self,
         _embedding: &Embedding,
         _top_k: usize,
+        _filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         Ok(vec![])
     }
@@ -217,6 +237,14 @@ impl VectorStore for MockVectorStoreEmpty {
     async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
         Ok(())
     }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
 }
 
 struct MockVectorStoreManyChunks;
@@ -254,6 +282,7 @@ impl VectorStore for MockVectorStoreManyChunks {
         &self,
         _embedding: &Embedding,
         _top_k: usize,
+        _filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         let long_text = "This is a very long chunk that contains many tokens to test the context window management. ".repeat(100);
         let mut results = Vec::new();
@@ -269,6 +298,14 @@ impl VectorStore for MockVectorStoreManyChunks {
     async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
         Ok(())
     }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
 }
 
 struct MockVectorStoreBoundaryScore;
@@ -306,6 +343,7 @@ impl VectorStore for MockVectorStoreBoundaryScore {
         &self,
         _embedding: &Embedding,
         _top_k: usize,
+        _filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         Ok(vec![SearchResult {
             chunk: Chunk::new("boundary test".to_string(), DocumentId::new(), Some(1), 0),
@@ -316,6 +354,14 @@ impl VectorStore for MockVectorStoreBoundaryScore {
     async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
         Ok(())
     }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
 }
 
 struct MockConversationRepository;
@@ -336,6 +382,13 @@ impl ConversationRepository for MockConversationRepository {
         Ok(None)
     }
 
+    async fn get_conversation_tenant(
+        &self,
+        _id: ConversationId,
+    ) -> Result<Option<TenantId>, RepositoryError> {
+        Ok(None)
+    }
+
     async fn append_message(&self, _message: &Message) -> Result<(), RepositoryError> {
         Ok(())
     }
@@ -374,7 +427,10 @@ async fn given_high_similarity_results_when_querying_then_returns_llm_answer() {
         TEST_FALLBACK_MESSAGE.to_string(),
     );
 
-    let result = service.query("test question", None, None).await.unwrap();
+    let result = service
+        .query("test question", None, None, &SearchFilter::default())
+        .await
+        .unwrap();
 
     assert_eq!(result.answer, "Mock answer");
     assert!(!result.sources.is_empty());
@@ -401,7 +457,10 @@ async fn given_low_similarity_results_when_querying_then_returns_fallback() {
         TEST_FALLBACK_MESSAGE.to_string(),
     );
 
-    let result = service.query("test question", None, None).await.unwrap();
+    let result = service
+        .query("test question", None, None, &SearchFilter::default())
+        .await
+        .unwrap();
 
     assert_eq!(result.answer, TEST_FALLBACK_MESSAGE);
     assert!(result.sources.is_empty());
@@ -428,7 +487,10 @@ async fn given_no_search_results_when_querying_then_returns_fallback() {
         TEST_FALLBACK_MESSAGE.to_string(),
     );
 
-    let result = service.query("test question", None, None).await.unwrap();
+    let result = service
+        .query("test question", None, None, &SearchFilter::default())
+        .await
+        .unwrap();
 
     assert_eq!(result.answer, TEST_FALLBACK_MESSAGE);
     assert!(result.sources.is_empty());
@@ -455,7 +517,10 @@ async fn given_many_chunks_exceeding_budget_when_querying_then_context_is_trimme
         TEST_FALLBACK_MESSAGE.to_string(),
     );
 
-    let result = service.query("test question", None, None).await.unwrap();
+    let result = service
+        .query("test question", None, None, &SearchFilter::default())
+        .await
+        .unwrap();
 
     assert_eq!(result.answer, "Mock answer");
     assert!(!result.sources.is_empty());
@@ -482,7 +547,10 @@ async fn given_threshold_boundary_score_when_querying_then_includes_exact_match(
         TEST_FALLBACK_MESSAGE.to_string(),
     );
 
-    let result = service.query("test question", None, None).await.unwrap();
+    let result = service
+        .query("test question", None, None, &SearchFilter::default())
+        .await
+        .unwrap();
 
     assert_eq!(result.answer, "Mock answer");
     assert_eq!(result.sources.len(), 1);
@@ -526,6 +594,7 @@ impl VectorStore for MockVectorStoreWithMetadata {
         &self,
         _embedding: &Embedding,
         _top_k: usize,
+        _filter: &SearchFilter,
     ) -> Result<Vec<SearchResult>, VectorStoreError> {
         use sandakan::domain::ContentType;
         use std::sync::Arc;
@@ -533,6 +602,10 @@ impl VectorStore for MockVectorStoreWithMetadata {
             title: "Annual Report 2024".to_string(),
             content_type: ContentType::Pdf,
             source_url: Some("https://example.com/report.pdf".to_string()),
+            author: None,
+            tags: vec![],
+            attributes: Default::default(),
+            tenant_id: TenantId::default(),
         });
         Ok(vec![SearchResult {
             chunk: Chunk::with_metadata(
@@ -549,6 +622,14 @@ impl VectorStore for MockVectorStoreWithMetadata {
     async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
         Ok(())
     }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
 }
 
 #[tokio::test]
@@ -573,7 +654,12 @@ async fn given_chunks_with_metadata_when_querying_then_sources_include_title_and
     );
 
     let result = service
-        .query("What was revenue growth?", None, None)
+        .query(
+            "What was revenue growth?",
+            None,
+            None,
+            &SearchFilter::default(),
+        )
         .await
         .unwrap();
 
@@ -586,3 +672,277 @@ async fn given_chunks_with_metadata_when_querying_then_sources_include_title_and
     );
     assert_eq!(source.content_type.as_deref(), Some("application/pdf"));
 }
+
+// ─── Metadata filtering ──────────────────────────────────────────────────────
+
+#[derive(Default)]
+struct FilterRecordingVectorStore {
+    filters: tokio::sync::Mutex<Vec<SearchFilter>>,
+}
+
+#[async_trait::async_trait]
+impl VectorStore for FilterRecordingVectorStore {
+    async fn create_collection(
+        &self,
+        _config: &CollectionConfig,
+    ) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn collection_exists(&self) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn get_collection_vector_size(&self) -> Result<Option<u64>, VectorStoreError> {
+        Ok(Some(384))
+    }
+
+    async fn delete_collection(&self) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn upsert(
+        &self,
+        _chunks: &[Chunk],
+        _embeddings: &[Embedding],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _embedding: &Embedding,
+        _top_k: usize,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SearchResult>, VectorStoreError> {
+        self.filters.lock().await.push(filter.clone());
+        Ok(vec![])
+    }
+
+    async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn given_search_filter_when_searching_chunks_then_filter_reaches_vector_store() {
+    let vector_store = Arc::new(FilterRecordingVectorStore::default());
+    let service = RetrievalService::new(
+        Arc::new(MockEmbedder),
+        Arc::new(MockLlmClient),
+        Arc::clone(&vector_store),
+        mock_conversation_repository(),
+        None,
+        None,
+        None,
+        "test/mock-model".to_string(),
+        TEST_TOP_K,
+        TEST_SIMILARITY_THRESHOLD,
+        TEST_MAX_CONTEXT_TOKENS,
+        TEST_FALLBACK_MESSAGE.to_string(),
+    );
+    let filter = SearchFilter {
+        document_ids: vec![DocumentId::new()],
+        tags: vec!["physics".to_string()],
+        ..SearchFilter::default()
+    };
+
+    service.search_chunks("entropy", &filter).await.unwrap();
+
+    assert_eq!(*vector_store.filters.lock().await, vec![filter]);
+}
+
+// ─── Reranking ───────────────────────────────────────────────────────────────
+
+const RERANK_CANDIDATES: usize = 8;
+const RERANK_TOP_N: usize = 3;
+
+/// Returns `limit` chunks `chunk-0..` with descending vector scores, recording each limit.
+#[derive(Default)]
+struct LimitRecordingVectorStore {
+    limits: tokio::sync::Mutex<Vec<usize>>,
+}
+
+#[async_trait::async_trait]
+impl VectorStore for LimitRecordingVectorStore {
+    async fn create_collection(
+        &self,
+        _config: &CollectionConfig,
+    ) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn collection_exists(&self) -> Result<bool, VectorStoreError> {
+        Ok(true)
+    }
+
+    async fn get_collection_vector_size(&self) -> Result<Option<u64>, VectorStoreError> {
+        Ok(Some(384))
+    }
+
+    async fn delete_collection(&self) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn upsert(
+        &self,
+        _chunks: &[Chunk],
+        _embeddings: &[Embedding],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn search(
+        &self,
+        _embedding: &Embedding,
+        top_k: usize,
+        _filter: &SearchFilter,
+    ) -> Result<Vec<SearchResult>, VectorStoreError> {
+        self.limits.lock().await.push(top_k);
+        Ok((0..top_k)
+            .map(|i| SearchResult {
+                chunk: Chunk::new(format!("chunk-{}", i), DocumentId::new(), None, i),
+                score: 0.95 - i as f32 * 0.01,
+            })
+            .collect())
+    }
+
+    async fn delete(&self, _chunk_ids: &[ChunkId]) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+
+    async fn delete_by_document(
+        &self,
+        _document_id: DocumentId,
+        _keep: &[ChunkId],
+    ) -> Result<(), VectorStoreError> {
+        Ok(())
+    }
+}
+
+/// Scores candidates in reverse of the order it receives them.
+struct ReversingReranker;
+
+#[async_trait::async_trait]
+impl Reranker for ReversingReranker {
+    async fn rerank(&self, _query: &str, documents: &[&str]) -> Result<Vec<f32>, RerankerError> {
+        Ok((0..documents.len()).map(|i| i as f32).collect())
+    }
+}
+
+struct FailingReranker;
+
+#[async_trait::async_trait]
+impl Reranker for FailingReranker {
+    async fn rerank(&self, _query: &str, _documents: &[&str]) -> Result<Vec<f32>, RerankerError> {
+        Err(RerankerError::ApiRequestFailed(
+            "connection refused".to_string(),
+        ))
+    }
+}
+
+fn reranking_service(
+    vector_store: Arc<LimitRecordingVectorStore>,
+    reranker: Arc<dyn Reranker>,
+) -> RetrievalService<MockLlmClient, LimitRecordingVectorStore> {
+    RetrievalService::new(
+        Arc::new(MockEmbedder),
+        Arc::new(MockLlmClient),
+        vector_store,
+        mock_conversation_repository(),
+        None,
+        None,
+        None,
+        "test/mock-model".to_string(),
+        TEST_TOP_K,
+        TEST_SIMILARITY_THRESHOLD,
+        TEST_MAX_CONTEXT_TOKENS,
+        TEST_FALLBACK_MESSAGE.to_string(),
+    )
+    .with_reranker(reranker, RERANK_CANDIDATES, RERANK_TOP_N)
+}
+
+#[tokio::test]
+async fn given_reranker_when_searching_chunks_then_fetches_candidates_and_keeps_top_n_in_rerank_order()
+ {
+    let vector_store = Arc::new(LimitRecordingVectorStore::default());
+    let service = reranking_service(Arc::clone(&vector_store), Arc::new(ReversingReranker));
+
+    let chunks = service
+        .search_chunks("entropy", &SearchFilter::default())
+        .await
+        .unwrap();
+
+    assert_eq!(*vector_store.limits.lock().await, vec![RERANK_CANDIDATES]);
+    let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+    assert_eq!(texts, vec!["chunk-7", "chunk-6", "chunk-5"]);
+    assert_eq!(chunks[0].rerank_score, Some(7.0));
+    assert!((chunks[0].score - 0.88).abs() < 1e-6);
+}
+
+#[tokio::test]
+async fn given_reranker_when_querying_then_sources_follow_rerank_order() {
+    let vector_store = Arc::new(LimitRecordingVectorStore::default());
+    let service = reranking_service(vector_store, Arc::new(ReversingReranker));
+
+    let result = service
+        .query("test question", None, None, &SearchFilter::default())
+        .await
+        .unwrap();
+
+    assert_eq!(result.sources.len(), RERANK_TOP_N);
+    assert_eq!(result.sources[0].text, "chunk-7");
+    assert!(result.sources.iter().all(|s| s.rerank_score.is_some()));
+}
+
+#[tokio::test]
+async fn given_failing_reranker_when_searching_chunks_then_keeps_vector_order_without_rerank_scores()
+ {
+    let vector_store = Arc::new(LimitRecordingVectorStore::default());
+    let service = reranking_service(vector_store, Arc::new(FailingReranker));
+
+    let chunks = service
+        .search_chunks("entropy", &SearchFilter::default())
+        .await
+        .unwrap();
+
+    let texts: Vec<&str> = chunks.iter().map(|c| c.text.as_str()).collect();
+    assert_eq!(texts, vec!["chunk-0", "chunk-1", "chunk-2"]);
+    assert!(chunks.iter().all(|c| c.rerank_score.is_none()));
+}
+
+#[tokio::test]
+async fn given_no_reranker_when_searching_chunks_then_requests_top_k() {
+    let vector_store = Arc::new(LimitRecordingVectorStore::default());
+    let service = RetrievalService::new(
+        Arc::new(MockEmbedder),
+        Arc::new(MockLlmClient),
+        Arc::clone(&vector_store),
+        mock_conversation_repository(),
+        None,
+        None,
+        None,
+        "test/mock-model".to_string(),
+        TEST_TOP_K,
+        TEST_SIMILARITY_THRESHOLD,
+        TEST_MAX_CONTEXT_TOKENS,
+        TEST_FALLBACK_MESSAGE.to_string(),
+    );
+
+    let chunks = service
+        .search_chunks("entropy", &SearchFilter::default())
+        .await
+        .unwrap();
+
+    assert_eq!(*vector_store.limits.lock().await, vec![TEST_TOP_K]);
+    assert_eq!(chunks.len(), TEST_TOP_K);
+    assert!(chunks.iter().all(|c| c.rerank_score.is_none()));
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use sandakan::application::services::{FailureClass, RetryPolicy};
+
+fn policy() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 5,
+        base_delay: Duration::from_secs(2),
+        rate_limited_base_delay: Duration::from_secs(30),
+        max_delay: Duration::from_secs(60),
+    }
+}
+
+#[test]
+fn given_transient_failures_when_computing_backoff_then_delay_doubles_per_attempt() {
+    let policy = policy();
+
+    let delays: Vec<Option<Duration>> = (1..=3)
+        .map(|attempt| policy.backoff(FailureClass::Transient, attempt))
+        .collect();
+
+    assert_eq!(
+        delays,
+        vec![
+            Some(Duration::from_secs(2)),
+            Some(Duration::from_secs(4)),
+            Some(Duration::from_secs(8)),
+        ]
+    );
+}
+
+#[test]
+fn given_rate_limit_when_computing_backoff_then_uses_longer_base_capped_at_max_delay() {
+    let policy = policy();
+
+    assert_eq!(
+        policy.backoff(FailureClass::RateLimited, 1),
+        Some(Duration::from_secs(30))
+    );
+    assert_eq!(
+        policy.backoff(FailureClass::RateLimited, 3),
+        Some(Duration::from_secs(60))
+    );
+}
+
+#[test]
+fn given_permanent_failure_when_computing_backoff_then_no_retry() {
+    assert_eq!(policy().backoff(FailureClass::Permanent, 1), None);
+}
+
+#[test]
+fn given_last_attempt_failed_when_computing_backoff_then_no_retry() {
+    assert_eq!(policy().backoff(FailureClass::Transient, 5), None);
+}
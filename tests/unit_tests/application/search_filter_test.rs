@@ -0,0 +1,78 @@
+use chrono::{TimeZone, Utc};
+use serde_json::json;
+
+use sandakan::application::ports::{SearchFilter, SearchFilterError};
+use sandakan::domain::ContentType;
+
+#[test]
+fn given_empty_object_when_parsing_filter_then_matches_everything() {
+    let filter = SearchFilter::from_json(&json!({})).unwrap();
+
+    assert!(filter.is_empty());
+}
+
+#[test]
+fn given_all_fields_when_parsing_filter_then_every_field_is_populated() {
+    let filter = SearchFilter::from_json(&json!({
+        "document_ids": ["8a4f1f8e-7a1c-4d8b-9e61-2f4d5b6c7d8e"],
+        "content_types": ["application/pdf", "audio/mpeg"],
+        "titles": ["Lecture 1"],
+        "tags": ["physics"],
+        "ingested_after": "2025-01-01T00:00:00Z",
+        "ingested_before": "2025-02-01T00:00:00Z"
+    }))
+    .unwrap();
+
+    assert_eq!(
+        filter.document_ids[0].as_uuid().to_string(),
+        "8a4f1f8e-7a1c-4d8b-9e61-2f4d5b6c7d8e"
+    );
+    assert_eq!(
+        filter.content_types,
+        vec![ContentType::Pdf, ContentType::Audio]
+    );
+    assert_eq!(filter.titles, vec!["Lecture 1".to_string()]);
+    assert_eq!(filter.tags, vec!["physics".to_string()]);
+    assert_eq!(
+        filter.ingested_after,
+        Some(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap())
+    );
+    assert!(!filter.is_empty());
+}
+
+#[test]
+fn given_invalid_document_id_when_parsing_filter_then_returns_error() {
+    let result = SearchFilter::from_json(&json!({ "document_ids": ["not-a-uuid"] }));
+
+    assert!(matches!(
+        result,
+        Err(SearchFilterError::InvalidDocumentId(id)) if id == "not-a-uuid"
+    ));
+}
+
+#[test]
+fn given_unknown_content_type_when_parsing_filter_then_returns_error() {
+    let result = SearchFilter::from_json(&json!({ "content_types": ["image/png"] }));
+
+    assert!(matches!(
+        result,
+        Err(SearchFilterError::UnknownContentType(_))
+    ));
+}
+
+#[test]
+fn given_inverted_date_range_when_parsing_filter_then_returns_error() {
+    let result = SearchFilter::from_json(&json!({
+        "ingested_after": "2025-02-01T00:00:00Z",
+        "ingested_before": "2025-01-01T00:00:00Z"
+    }));
+
+    assert!(matches!(result, Err(SearchFilterError::EmptyDateRange)));
+}
+
+#[test]
+fn given_unknown_field_when_parsing_filter_then_returns_malformed() {
+    let result = SearchFilter::from_json(&json!({ "author": "someone" }));
+
+    assert!(matches!(result, Err(SearchFilterError::Malformed(_))));
+}
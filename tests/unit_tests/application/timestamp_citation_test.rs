@@ -1,6 +1,8 @@
 use sandakan::application::ports::SourceChunk;
 use sandakan::application::ports::TextSplitter;
-use sandakan::domain::{ContentType, DocumentId, DocumentMetadata, TranscriptSegment};
+use sandakan::domain::{
+    ContentType, DocumentId, DocumentMetadata, TenantId, TranscriptSegment, TranscriptWord,
+};
 use sandakan::infrastructure::text_processing::SemanticSplitter;
 use std::sync::Arc;
 
@@ -11,11 +13,18 @@ fn given_source_chunk_without_url_when_timestamped_url_called_then_returns_none(
     let chunk = SourceChunk {
         text: "some text".to_string(),
         page: None,
+        end_page: None,
         score: 0.9,
         title: None,
         source_url: None,
         content_type: None,
+        author: None,
+        tags: vec![],
+        attributes: Default::default(),
+        links: vec![],
         start_time: Some(45.0),
+        code: None,
+        rerank_score: None,
     };
     assert_eq!(chunk.timestamped_url(), None);
 }
@@ -26,11 +35,18 @@ fn given_source_chunk_with_url_and_no_start_time_when_timestamped_url_called_the
     let chunk = SourceChunk {
         text: "some text".to_string(),
         page: None,
+        end_page: None,
         score: 0.9,
         title: None,
         source_url: Some("https://example.com/lecture.mp4".to_string()),
         content_type: None,
+        author: None,
+        tags: vec![],
+        attributes: Default::default(),
+        links: vec![],
         start_time: None,
+        code: None,
+        rerank_score: None,
     };
     assert_eq!(
         chunk.timestamped_url(),
@@ -43,11 +59,18 @@ fn given_source_chunk_with_url_and_start_time_when_timestamped_url_called_then_a
     let chunk = SourceChunk {
         text: "some text".to_string(),
         page: None,
+        end_page: None,
         score: 0.9,
         title: None,
         source_url: Some("https://example.com/lecture.mp4".to_string()),
         content_type: None,
+        author: None,
+        tags: vec![],
+        attributes: Default::default(),
+        links: vec![],
         start_time: Some(1045.3),
+        code: None,
+        rerank_score: None,
     };
     assert_eq!(
         chunk.timestamped_url(),
@@ -61,11 +84,18 @@ fn given_source_chunk_with_query_string_url_and_start_time_when_timestamped_url_
     let chunk = SourceChunk {
         text: "some text".to_string(),
         page: None,
+        end_page: None,
         score: 0.9,
         title: None,
         source_url: Some("https://youtube.com/watch?v=XYZ".to_string()),
         content_type: None,
+        author: None,
+        tags: vec![],
+        attributes: Default::default(),
+        links: vec![],
         start_time: Some(1045.0),
+        code: None,
+        rerank_score: None,
     };
     assert_eq!(
         chunk.timestamped_url(),
@@ -79,11 +109,18 @@ fn given_start_time_with_fractional_seconds_when_timestamped_url_called_then_rou
     let chunk = SourceChunk {
         text: "text".to_string(),
         page: None,
+        end_page: None,
         score: 0.9,
         title: None,
         source_url: Some("https://example.com/video".to_string()),
         content_type: None,
+        author: None,
+        tags: vec![],
+        attributes: Default::default(),
+        links: vec![],
         start_time: Some(30.7),
+        code: None,
+        rerank_score: None,
     };
     // 30.7 rounds to 31
     assert_eq!(
@@ -127,6 +164,34 @@ async fn given_single_segment_when_split_segments_called_then_chunk_carries_star
     );
 }
 
+#[tokio::test]
+async fn given_segments_with_timed_words_when_split_segments_called_then_chunks_start_at_first_word()
+ {
+    let splitter = SemanticSplitter::new(8, 0).unwrap();
+    let doc_id = DocumentId::new();
+    let segments = vec![
+        TranscriptSegment::new("Cells need energy.", 0.0, 30.0).with_words(vec![
+            TranscriptWord::new("Cells", 3.2, 3.7),
+            TranscriptWord::new("need", 3.7, 4.2),
+            TranscriptWord::new("energy.", 4.2, 5.0),
+        ]),
+        TranscriptSegment::new("Mitochondria make it.", 30.0, 60.0).with_words(vec![
+            TranscriptWord::new("Mitochondria", 41.5, 42.8),
+            TranscriptWord::new("make", 42.8, 43.4),
+            TranscriptWord::new("it.", 43.4, 44.0),
+        ]),
+    ];
+
+    let chunks = splitter
+        .split_segments(&segments, doc_id, None)
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.len(), 2);
+    assert!((chunks[0].start_time.unwrap() - 3.2).abs() < 1e-4);
+    assert!((chunks[1].start_time.unwrap() - 41.5).abs() < 1e-4);
+}
+
 #[tokio::test]
 async fn given_many_segments_fitting_one_chunk_when_split_segments_called_then_start_time_is_first_segment()
  {
@@ -187,6 +252,10 @@ async fn given_segments_with_metadata_when_split_segments_called_then_metadata_a
         title: "CS101 Lecture".to_string(),
         content_type: ContentType::Video,
         source_url: Some("https://example.com/lecture.mp4".to_string()),
+        author: None,
+        tags: vec![],
+        attributes: Default::default(),
+        tenant_id: TenantId::default(),
     });
     let segments = vec![TranscriptSegment::new(
         "Topic: sorting algorithms.",
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::watch;
+
+use sandakan::application::ports::{
+    DocumentRepository, JobRepository, WebFetchError, WebFetcher, WebResource,
+};
+use sandakan::application::services::{
+    CrawlOutcome, DocumentService, UrlIngestionError, UrlIngestionService,
+};
+use sandakan::domain::{CrawlRequest, FetchFailure, Job, JobPayload, SourceMetadata, TenantId};
+use sandakan::infrastructure::persistence::{MockTranscriptRepository, MockVectorStore};
+use sandakan::infrastructure::storage::MockStagingStore;
+
+use crate::helpers::{InMemoryDocumentRepository, InMemoryJobRepository};
+
+// --- Hand-written mocks ---
+
+/// Serves canned resources; unknown URLs answer 404.
+#[derive(Default)]
+struct ScriptedWebFetcher {
+    resources: HashMap<String, Result<WebResource, u16>>,
+    fetched: std::sync::Mutex<Vec<String>>,
+}
+
+impl ScriptedWebFetcher {
+    fn with(mut self, url: &str, resource: Result<WebResource, u16>) -> Self {
+        self.resources.insert(url.to_string(), resource);
+        self
+    }
+
+    fn with_page(self, url: &str, links: &[&str]) -> Self {
+        self.with(url, Ok(page(url, links)))
+    }
+
+    fn fetched(&self) -> Vec<String> {
+        self.fetched.lock().unwrap().clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl WebFetcher for ScriptedWebFetcher {
+    async fn fetch(
+        &self,
+        url: &str,
+        _allowed_domains: &[String],
+    ) -> Result<WebResource, WebFetchError> {
+        self.fetched.lock().unwrap().push(url.to_string());
+        match self.resources.get(url) {
+            Some(Ok(resource)) => Ok(resource.clone()),
+            Some(Err(status)) => Err(WebFetchError::Status(*status)),
+            None => Err(WebFetchError::Status(404)),
+        }
+    }
+}
+
+fn page(url: &str, links: &[&str]) -> WebResource {
+    WebResource {
+        url: url.to_string(),
+        content_type: Some("text/html".to_string()),
+        body: format!("<html><body><p>Content of {url}</p></body></html>").into_bytes(),
+        title: Some(format!("Title of {url}")),
+        links: links.iter().map(|l| l.to_string()).collect(),
+        ..WebResource::default()
+    }
+}
+
+fn sitemap(url: &str, entries: &[&str], index: bool) -> WebResource {
+    WebResource {
+        url: url.to_string(),
+        content_type: Some("application/xml".to_string()),
+        body: b"<urlset/>".to_vec(),
+        links: entries.iter().map(|l| l.to_string()).collect(),
+        sitemap_index: index,
+        ..WebResource::default()
+    }
+}
+
+fn robots(url: &str, body: &str) -> Result<WebResource, u16> {
+    Ok(WebResource {
+        url: url.to_string(),
+        content_type: Some("text/plain".to_string()),
+        body: body.as_bytes().to_vec(),
+        ..WebResource::default()
+    })
+}
+
+// --- Fixture ---
+
+struct Fixture {
+    fetcher: Arc<ScriptedWebFetcher>,
+    documents: Arc<InMemoryDocumentRepository>,
+    jobs: Arc<InMemoryJobRepository>,
+    service: UrlIngestionService<MockVectorStore>,
+}
+
+impl Fixture {
+    fn new(fetcher: ScriptedWebFetcher) -> Self {
+        let fetcher = Arc::new(fetcher);
+        let documents = Arc::new(InMemoryDocumentRepository::default());
+        let jobs = Arc::new(InMemoryJobRepository::default());
+        let document_service = Arc::new(DocumentService::new(
+            Arc::clone(&documents) as Arc<dyn DocumentRepository>,
+            Arc::clone(&jobs) as Arc<dyn JobRepository>,
+            Arc::new(MockVectorStore),
+            Arc::new(MockStagingStore),
+            Arc::new(MockTranscriptRepository),
+        ));
+        let service = UrlIngestionService::new(
+            Arc::clone(&fetcher) as Arc<dyn WebFetcher>,
+            Arc::new(MockStagingStore),
+            document_service,
+            Arc::clone(&jobs) as Arc<dyn JobRepository>,
+        );
+        Self {
+            fetcher,
+            documents,
+            jobs,
+            service,
+        }
+    }
+
+    /// Queues `request` and runs the crawl as a worker would after claiming it.
+    async fn crawl(&self, request: CrawlRequest) -> (Result<CrawlOutcome, UrlIngestionError>, Job) {
+        let job_id = self.service.submit(&tenant(), request).await.unwrap();
+        let job = self.jobs.job(job_id).await;
+        let Some(JobPayload::Crawl(request)) = job.payload.clone() else {
+            panic!("crawl job carries its request");
+        };
+        let (_cancel, cancelled) = watch::channel(false);
+        let result = self.service.crawl(&job, &request, cancelled).await;
+        (result, self.jobs.job(job_id).await)
+    }
+
+    /// Source URLs of the documents submitted so far, in submission order.
+    async fn ingested_urls(&self) -> Vec<String> {
+        self.documents
+            .records
+            .lock()
+            .await
+            .iter()
+            .map(|r| r.source.source_url.clone().unwrap_or_default())
+            .collect()
+    }
+}
+
+fn tenant() -> TenantId {
+    TenantId::parse("acme").unwrap()
+}
+
+fn crawl_request(url: &str, max_depth: u32) -> CrawlRequest {
+    CrawlRequest {
+        url: url.to_string(),
+        sitemap: false,
+        max_depth,
+        max_pages: 50,
+        allowed_domains: vec![],
+        principal: None,
+        source: SourceMetadata::default(),
+    }
+}
+
+fn failure(url: &str, error: &str) -> FetchFailure {
+    FetchFailure {
+        url: url.to_string(),
+        error: error.to_string(),
+    }
+}
+
+// --- Tests ---
+
+#[tokio::test]
+async fn given_crawl_request_when_submitting_then_job_is_queued_with_start_host_as_domain() {
+    let fixture = Fixture::new(ScriptedWebFetcher::default());
+
+    let job_id = fixture
+        .service
+        .submit(
+            &tenant(),
+            crawl_request("https://Docs.Example.com/start#intro", 1),
+        )
+        .await
+        .unwrap();
+
+    let job = fixture.jobs.job(job_id).await;
+    assert_eq!(job.job_type, "url_ingestion");
+    assert_eq!(job.tenant_id, tenant());
+    let Some(JobPayload::Crawl(request)) = job.payload else {
+        panic!("expected a crawl payload");
+    };
+    assert_eq!(request.url, "https://docs.example.com/start");
+    assert_eq!(
+        request.allowed_domains,
+        vec!["docs.example.com".to_string()]
+    );
+    assert!(fixture.fetcher.fetched().is_empty());
+}
+
+#[tokio::test]
+async fn given_invalid_crawl_requests_when_submitting_then_rejected() {
+    let fixture = Fixture::new(ScriptedWebFetcher::default());
+
+    for request in [
+        crawl_request("ftp://example.com/file", 0),
+        crawl_request("not a url", 0),
+        crawl_request("https://example.com/", 99),
+        CrawlRequest {
+            max_pages: 0,
+            ..crawl_request("https://example.com/", 0)
+        },
+    ] {
+        let result = fixture.service.submit(&tenant(), request).await;
+        assert!(matches!(result, Err(UrlIngestionError::InvalidRequest(_))));
+    }
+}
+
+#[tokio::test]
+async fn given_linked_site_when_crawling_then_in_scope_pages_are_ingested_and_failures_recorded() {
+    let fetcher = ScriptedWebFetcher::default()
+        .with(
+            "https://example.com/robots.txt",
+            robots(
+                "https://example.com/robots.txt",
+                "User-agent: *\nDisallow: /admin\n",
+            ),
+        )
+        .with_page(
+            "https://example.com/",
+            &[
+                "https://example.com/guide",
+                "https://example.com/guide#install",
+                "https://example.com/admin/users",
+                "https://example.com/missing",
+                "https://other.org/elsewhere",
+            ],
+        )
+        .with_page("https://example.com/guide", &["https://example.com/deeper"])
+        .with_page("https://example.com/deeper", &[]);
+    let fixture = Fixture::new(fetcher);
+
+    let (result, job) = fixture
+        .crawl(crawl_request("https://example.com/", 1))
+        .await;
+
+    assert_eq!(
+        result.unwrap(),
+        CrawlOutcome {
+            ingested: 2,
+            failed: 2
+        }
+    );
+    assert_eq!(
+        fixture.ingested_urls().await,
+        vec!["https://example.com/", "https://example.com/guide"]
+    );
+    assert_eq!(
+        job.fetch_failures,
+        vec![
+            failure(
+                "https://example.com/admin/users",
+                "disallowed by robots.txt"
+            ),
+            failure("https://example.com/missing", "HTTP 404"),
+        ]
+    );
+    assert_eq!(job.progress.urls_ingested, 2);
+    let fetched = fixture.fetcher.fetched();
+    assert!(!fetched.iter().any(|url| url.contains("other.org")));
+    assert!(!fetched.contains(&"https://example.com/deeper".to_string()));
+}
+
+#[tokio::test]
+async fn given_pages_sharing_canonical_url_when_crawling_then_page_is_ingested_once() {
+    let canonical = |url: &str| {
+        Ok(WebResource {
+            canonical_url: Some("https://example.com/article".to_string()),
+            ..page(url, &[])
+        })
+    };
+    let fetcher = ScriptedWebFetcher::default()
+        .with_page(
+            "https://example.com/",
+            &[
+                "https://example.com/article?utm_source=feed",
+                "https://example.com/article?page=all",
+            ],
+        )
+        .with(
+            "https://example.com/article?utm_source=feed",
+            canonical("https://example.com/article?utm_source=feed"),
+        )
+        .with(
+            "https://example.com/article?page=all",
+            canonical("https://example.com/article?page=all"),
+        );
+    let fixture = Fixture::new(fetcher);
+
+    let (result, _) = fixture
+        .crawl(crawl_request("https://example.com/", 1))
+        .await;
+
+    assert_eq!(result.unwrap().ingested, 2);
+    let records = fixture.documents.records.lock().await;
+    let article: Vec<_> = records
+        .iter()
+        .filter(|r| r.filename == "https://example.com/article")
+        .collect();
+    assert_eq!(article.len(), 1);
+    assert_eq!(
+        article[0].source.title.as_deref(),
+        Some("Title of https://example.com/article?utm_source=feed")
+    );
+}
+
+#[tokio::test]
+async fn given_allowed_subdomains_when_crawling_then_links_to_them_are_followed() {
+    let fetcher = ScriptedWebFetcher::default()
+        .with_page(
+            "https://www.example.com/",
+            &["https://blog.example.com/post", "https://example.org/"],
+        )
+        .with_page("https://blog.example.com/post", &[]);
+    let fixture = Fixture::new(fetcher);
+
+    let (result, _) = fixture
+        .crawl(CrawlRequest {
+            allowed_domains: vec!["Example.com".to_string()],
+            ..crawl_request("https://www.example.com/", 2)
+        })
+        .await;
+
+    assert_eq!(result.unwrap().ingested, 2);
+    assert_eq!(
+        fixture.ingested_urls().await,
+        vec!["https://www.example.com/", "https://blog.example.com/post"]
+    );
+}
+
+#[tokio::test]
+async fn given_sitemap_index_when_crawling_then_listed_pages_are_ingested_without_following_links()
+{
+    let fetcher = ScriptedWebFetcher::default()
+        .with(
+            "https://example.com/sitemap.xml",
+            Ok(sitemap(
+                "https://example.com/sitemap.xml",
+                &[
+                    "https://example.com/sitemap-docs.xml",
+                    "https://example.com/sitemap-gone.xml",
+                ],
+                true,
+            )),
+        )
+        .with(
+            "https://example.com/sitemap-docs.xml",
+            Ok(sitemap(
+                "https://example.com/sitemap-docs.xml",
+                &["https://example.com/a", "https://example.com/b"],
+                false,
+            )),
+        )
+        .with("https://example.com/sitemap-gone.xml", Err(410))
+        .with_page("https://example.com/a", &["https://example.com/c"])
+        .with_page("https://example.com/b", &[]);
+    let fixture = Fixture::new(fetcher);
+
+    let (result, job) = fixture
+        .crawl(CrawlRequest {
+            sitemap: true,
+            ..crawl_request("https://example.com/sitemap.xml", 0)
+        })
+        .await;
+
+    assert_eq!(result.unwrap().ingested, 2);
+    assert_eq!(
+        fixture.ingested_urls().await,
+        vec!["https://example.com/a", "https://example.com/b"]
+    );
+    assert_eq!(
+        job.fetch_failures,
+        vec![failure("https://example.com/sitemap-gone.xml", "HTTP 410")]
+    );
+}
+
+#[tokio::test]
+async fn given_unreachable_robots_txt_when_crawling_then_site_is_not_fetched() {
+    let fetcher = ScriptedWebFetcher::default()
+        .with("https://example.com/robots.txt", Err(503))
+        .with_page("https://example.com/", &[]);
+    let fixture = Fixture::new(fetcher);
+
+    let (result, job) = fixture
+        .crawl(crawl_request("https://example.com/", 0))
+        .await;
+
+    assert_eq!(
+        result.unwrap(),
+        CrawlOutcome {
+            ingested: 0,
+            failed: 1
+        }
+    );
+    assert_eq!(
+        fixture.fetcher.fetched(),
+        vec!["https://example.com/robots.txt"]
+    );
+    assert_eq!(
+        job.fetch_failures,
+        vec![failure("https://example.com/", "disallowed by robots.txt")]
+    );
+}
+
+#[tokio::test]
+async fn given_page_budget_when_crawling_then_crawl_stops_at_max_pages() {
+    let fetcher = ScriptedWebFetcher::default()
+        .with_page(
+            "https://example.com/",
+            &["https://example.com/1", "https://example.com/2"],
+        )
+        .with_page("https://example.com/1", &[])
+        .with_page("https://example.com/2", &[]);
+    let fixture = Fixture::new(fetcher);
+
+    let (result, _) = fixture
+        .crawl(CrawlRequest {
+            max_pages: 2,
+            ..crawl_request("https://example.com/", 1)
+        })
+        .await;
+
+    assert_eq!(result.unwrap().ingested, 2);
+    assert!(
+        !fixture
+            .fetcher
+            .fetched()
+            .contains(&"https://example.com/2".to_string())
+    );
+}
+
+#[tokio::test]
+async fn given_cancelled_crawl_when_crawling_then_returns_cancelled() {
+    let fixture =
+        Fixture::new(ScriptedWebFetcher::default().with_page("https://example.com/", &[]));
+    let job_id = fixture
+        .service
+        .submit(&tenant(), crawl_request("https://example.com/", 0))
+        .await
+        .unwrap();
+    let job = fixture.jobs.job(job_id).await;
+    let Some(JobPayload::Crawl(request)) = job.payload.clone() else {
+        panic!("crawl job carries its request");
+    };
+    let (_cancel, cancelled) = watch::channel(true);
+
+    let result = fixture.service.crawl(&job, &request, cancelled).await;
+
+    assert!(matches!(result, Err(UrlIngestionError::Cancelled)));
+    assert!(fixture.ingested_urls().await.is_empty());
+}
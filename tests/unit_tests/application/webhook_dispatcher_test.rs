@@ -0,0 +1,312 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+
+use sandakan::application::ports::{
+    RepositoryError, WebhookRepository, WebhookSendError, WebhookSender,
+};
+use sandakan::application::services::{RetryPolicy, WebhookDispatchOptions, WebhookDispatcher};
+use sandakan::domain::{
+    ClaimedWebhookDelivery, TenantId, WebhookDelivery, WebhookDeliveryStatus, WebhookEvent,
+    WebhookEventType, WebhookSubscription, WebhookSubscriptionId,
+};
+
+/// Outbox holding deliveries of a single subscription, with the claim and lease semantics
+/// of the Postgres adapter.
+struct InMemoryOutbox {
+    subscription: WebhookSubscription,
+    deliveries: Mutex<Vec<WebhookDelivery>>,
+}
+
+impl InMemoryOutbox {
+    fn with_event(event_type: WebhookEventType) -> Self {
+        let subscription = WebhookSubscription::generate(
+            TenantId::default(),
+            "http://hooks.test",
+            vec![event_type],
+        );
+        let event = WebhookEvent::new(
+            TenantId::default(),
+            event_type,
+            serde_json::json!({ "n": 1 }),
+        );
+        let now = Utc::now();
+        let delivery = WebhookDelivery {
+            id: Uuid::new_v4(),
+            subscription_id: subscription.id,
+            event_id: event.id,
+            event_type,
+            payload: event.payload(),
+            status: WebhookDeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            response_status: None,
+            last_error: None,
+            created_at: now,
+            delivered_at: None,
+        };
+        Self {
+            subscription,
+            deliveries: Mutex::new(vec![delivery]),
+        }
+    }
+
+    fn only(&self) -> WebhookDelivery {
+        self.deliveries.lock().unwrap()[0].clone()
+    }
+
+    fn make_due(&self) {
+        for delivery in self.deliveries.lock().unwrap().iter_mut() {
+            delivery.next_attempt_at = Utc::now();
+        }
+    }
+
+    fn update(&self, id: Uuid, apply: impl FnOnce(&mut WebhookDelivery)) {
+        let mut deliveries = self.deliveries.lock().unwrap();
+        if let Some(delivery) = deliveries.iter_mut().find(|d| d.id == id) {
+            apply(delivery);
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookRepository for InMemoryOutbox {
+    async fn create_subscription(
+        &self,
+        _subscription: &WebhookSubscription,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn list_subscriptions(
+        &self,
+        _tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        Ok(vec![self.subscription.clone()])
+    }
+
+    async fn get_subscription(
+        &self,
+        _id: WebhookSubscriptionId,
+    ) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        Ok(Some(self.subscription.clone()))
+    }
+
+    async fn delete_subscription(
+        &self,
+        _id: WebhookSubscriptionId,
+    ) -> Result<bool, RepositoryError> {
+        Ok(false)
+    }
+
+    async fn enqueue(&self, _event: &WebhookEvent) -> Result<usize, RepositoryError> {
+        Ok(0)
+    }
+
+    async fn claim_due(
+        &self,
+        batch_size: usize,
+        lease: Duration,
+    ) -> Result<Vec<ClaimedWebhookDelivery>, RepositoryError> {
+        let now = Utc::now();
+        let mut deliveries = self.deliveries.lock().unwrap();
+        Ok(deliveries
+            .iter_mut()
+            .filter(|d| d.status == WebhookDeliveryStatus::Pending && d.next_attempt_at <= now)
+            .take(batch_size)
+            .map(|d| {
+                d.attempts += 1;
+                d.next_attempt_at = now + lease;
+                ClaimedWebhookDelivery {
+                    delivery: d.clone(),
+                    url: self.subscription.url.clone(),
+                    secret: self.subscription.secret.clone(),
+                }
+            })
+            .collect())
+    }
+
+    async fn mark_delivered(&self, id: Uuid, response_status: u16) -> Result<(), RepositoryError> {
+        self.update(id, |d| {
+            d.status = WebhookDeliveryStatus::Delivered;
+            d.response_status = Some(response_status);
+            d.delivered_at = Some(Utc::now());
+        });
+        Ok(())
+    }
+
+    async fn schedule_retry(
+        &self,
+        id: Uuid,
+        delay: Duration,
+        error: &str,
+        response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        self.update(id, |d| {
+            d.next_attempt_at = Utc::now() + delay;
+            d.last_error = Some(error.to_string());
+            d.response_status = response_status;
+        });
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        self.update(id, |d| {
+            d.status = WebhookDeliveryStatus::Failed;
+            d.last_error = Some(error.to_string());
+            d.response_status = response_status;
+        });
+        Ok(())
+    }
+
+    async fn list_deliveries(
+        &self,
+        _subscription_id: WebhookSubscriptionId,
+        _limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        Ok(self.deliveries.lock().unwrap().clone())
+    }
+}
+
+/// Answers each send with the next scripted outcome and records what was sent.
+#[derive(Default)]
+struct ScriptedSender {
+    outcomes: Mutex<VecDeque<Result<u16, WebhookSendError>>>,
+    sent: Mutex<Vec<ClaimedWebhookDelivery>>,
+}
+
+impl ScriptedSender {
+    fn answering(outcomes: Vec<Result<u16, WebhookSendError>>) -> Self {
+        Self {
+            outcomes: Mutex::new(outcomes.into()),
+            sent: Mutex::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookSender for ScriptedSender {
+    async fn send(&self, delivery: &ClaimedWebhookDelivery) -> Result<u16, WebhookSendError> {
+        self.sent.lock().unwrap().push(delivery.clone());
+        self.outcomes.lock().unwrap().pop_front().unwrap_or(Ok(200))
+    }
+}
+
+fn dispatcher(outbox: &Arc<InMemoryOutbox>, sender: &Arc<ScriptedSender>) -> WebhookDispatcher {
+    WebhookDispatcher::new(
+        Arc::clone(outbox) as Arc<dyn WebhookRepository>,
+        Arc::clone(sender) as Arc<dyn WebhookSender>,
+    )
+    .with_options(WebhookDispatchOptions {
+        poll_interval: Duration::from_millis(10),
+        batch_size: 10,
+        lease: Duration::from_secs(60),
+        retry: RetryPolicy {
+            max_attempts: 2,
+            base_delay: Duration::from_secs(10),
+            rate_limited_base_delay: Duration::from_secs(120),
+            max_delay: Duration::from_secs(600),
+        },
+    })
+}
+
+fn assert_due_in(delivery: &WebhookDelivery, expected: Duration) {
+    let remaining = (delivery.next_attempt_at - Utc::now()).to_std().unwrap();
+    assert!(
+        remaining <= expected && remaining > expected - Duration::from_secs(5),
+        "next attempt in {remaining:?}, expected about {expected:?}"
+    );
+}
+
+#[tokio::test]
+async fn given_due_delivery_when_endpoint_accepts_then_it_is_marked_delivered() {
+    let outbox = Arc::new(InMemoryOutbox::with_event(
+        WebhookEventType::DocumentIngested,
+    ));
+    let sender = Arc::new(ScriptedSender::answering(vec![Ok(204)]));
+
+    let claimed = dispatcher(&outbox, &sender).process_batch().await.unwrap();
+
+    assert_eq!(claimed, 1);
+    let delivery = outbox.only();
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Delivered);
+    assert_eq!(delivery.response_status, Some(204));
+    assert_eq!(delivery.attempts, 1);
+    let sent = sender.sent.lock().unwrap();
+    assert_eq!(sent[0].url, "http://hooks.test");
+    assert_eq!(sent[0].secret, outbox.subscription.secret);
+}
+
+#[tokio::test]
+async fn given_endpoint_error_when_attempts_remain_then_delivery_is_retried_with_backoff() {
+    let outbox = Arc::new(InMemoryOutbox::with_event(WebhookEventType::JobFailed));
+    let sender = Arc::new(ScriptedSender::answering(vec![Err(
+        WebhookSendError::Rejected(503),
+    )]));
+
+    dispatcher(&outbox, &sender).process_batch().await.unwrap();
+
+    let delivery = outbox.only();
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Pending);
+    assert_eq!(delivery.response_status, Some(503));
+    assert_eq!(delivery.last_error.as_deref(), Some("webhook HTTP 503"));
+    assert_due_in(&delivery, Duration::from_secs(10));
+}
+
+#[tokio::test]
+async fn given_rate_limited_response_when_retrying_then_rate_limited_delay_applies() {
+    let outbox = Arc::new(InMemoryOutbox::with_event(WebhookEventType::JobFailed));
+    let sender = Arc::new(ScriptedSender::answering(vec![Err(
+        WebhookSendError::Rejected(429),
+    )]));
+
+    dispatcher(&outbox, &sender).process_batch().await.unwrap();
+
+    assert_due_in(&outbox.only(), Duration::from_secs(120));
+}
+
+#[tokio::test]
+async fn given_last_attempt_fails_when_dispatching_then_delivery_is_marked_failed() {
+    let outbox = Arc::new(InMemoryOutbox::with_event(
+        WebhookEventType::EvalBelowThreshold,
+    ));
+    let sender = Arc::new(ScriptedSender::answering(vec![
+        Err(WebhookSendError::Timeout),
+        Err(WebhookSendError::Timeout),
+    ]));
+    let dispatcher = dispatcher(&outbox, &sender);
+
+    dispatcher.process_batch().await.unwrap();
+    outbox.make_due();
+    dispatcher.process_batch().await.unwrap();
+
+    let delivery = outbox.only();
+    assert_eq!(delivery.status, WebhookDeliveryStatus::Failed);
+    assert_eq!(delivery.attempts, 2);
+    assert_eq!(delivery.last_error.as_deref(), Some("webhook timeout"));
+    assert_eq!(delivery.response_status, None);
+}
+
+#[tokio::test]
+async fn given_delivery_awaiting_retry_when_polling_then_it_is_not_sent_early() {
+    let outbox = Arc::new(InMemoryOutbox::with_event(WebhookEventType::JobFailed));
+    let sender = Arc::new(ScriptedSender::answering(vec![Err(
+        WebhookSendError::RequestFailed("connection refused".to_string()),
+    )]));
+    let dispatcher = dispatcher(&outbox, &sender);
+
+    dispatcher.process_batch().await.unwrap();
+    let claimed = dispatcher.process_batch().await.unwrap();
+
+    assert_eq!(claimed, 0);
+    assert_eq!(sender.sent.lock().unwrap().len(), 1);
+}
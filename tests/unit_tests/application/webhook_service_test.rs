@@ -0,0 +1,239 @@
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use sandakan::application::ports::{RepositoryError, WebhookRepository};
+use sandakan::application::services::{WebhookService, WebhookServiceError};
+use sandakan::domain::{
+    ClaimedWebhookDelivery, TenantId, WebhookDelivery, WebhookEvent, WebhookEventType,
+    WebhookSubscription, WebhookSubscriptionId,
+};
+
+#[derive(Default)]
+struct InMemoryWebhookRepository {
+    subscriptions: RwLock<Vec<WebhookSubscription>>,
+}
+
+#[async_trait]
+impl WebhookRepository for InMemoryWebhookRepository {
+    async fn create_subscription(
+        &self,
+        subscription: &WebhookSubscription,
+    ) -> Result<(), RepositoryError> {
+        self.subscriptions
+            .write()
+            .unwrap()
+            .push(subscription.clone());
+        Ok(())
+    }
+
+    async fn list_subscriptions(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Vec<WebhookSubscription>, RepositoryError> {
+        Ok(self
+            .subscriptions
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|s| s.tenant_id == *tenant_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn get_subscription(
+        &self,
+        id: WebhookSubscriptionId,
+    ) -> Result<Option<WebhookSubscription>, RepositoryError> {
+        Ok(self
+            .subscriptions
+            .read()
+            .unwrap()
+            .iter()
+            .find(|s| s.id == id)
+            .cloned())
+    }
+
+    async fn delete_subscription(
+        &self,
+        id: WebhookSubscriptionId,
+    ) -> Result<bool, RepositoryError> {
+        let mut subscriptions = self.subscriptions.write().unwrap();
+        let before = subscriptions.len();
+        subscriptions.retain(|s| s.id != id);
+        Ok(subscriptions.len() < before)
+    }
+
+    async fn enqueue(&self, _event: &WebhookEvent) -> Result<usize, RepositoryError> {
+        Ok(0)
+    }
+
+    async fn claim_due(
+        &self,
+        _batch_size: usize,
+        _lease: Duration,
+    ) -> Result<Vec<ClaimedWebhookDelivery>, RepositoryError> {
+        Ok(Vec::new())
+    }
+
+    async fn mark_delivered(
+        &self,
+        _id: Uuid,
+        _response_status: u16,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn schedule_retry(
+        &self,
+        _id: Uuid,
+        _delay: Duration,
+        _error: &str,
+        _response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        _id: Uuid,
+        _error: &str,
+        _response_status: Option<u16>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn list_deliveries(
+        &self,
+        _subscription_id: WebhookSubscriptionId,
+        _limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        Ok(Vec::new())
+    }
+}
+
+fn service() -> WebhookService {
+    WebhookService::new(Arc::new(InMemoryWebhookRepository::default()))
+}
+
+fn tenant(name: &str) -> TenantId {
+    TenantId::parse(name).unwrap()
+}
+
+#[tokio::test]
+async fn given_valid_request_when_subscribing_then_event_types_are_deduplicated_and_secret_issued()
+{
+    let service = service();
+
+    let subscription = service
+        .subscribe(
+            tenant("acme"),
+            " https://hooks.example.com/sandakan ",
+            vec![
+                WebhookEventType::JobFailed,
+                WebhookEventType::DocumentIngested,
+                WebhookEventType::JobFailed,
+            ],
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(subscription.url, "https://hooks.example.com/sandakan");
+    assert_eq!(
+        subscription.event_types,
+        vec![
+            WebhookEventType::DocumentIngested,
+            WebhookEventType::JobFailed
+        ]
+    );
+    assert!(subscription.secret.starts_with("whsec_"));
+    assert_eq!(service.list(&tenant("acme")).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn given_relative_url_or_no_event_types_when_subscribing_then_request_is_rejected() {
+    let service = service();
+
+    let relative = service
+        .subscribe(
+            tenant("acme"),
+            "/hooks",
+            vec![WebhookEventType::DocumentIngested],
+        )
+        .await;
+    let empty = service
+        .subscribe(tenant("acme"), "https://hooks.example.com", vec![])
+        .await;
+
+    assert!(matches!(
+        relative,
+        Err(WebhookServiceError::InvalidRequest(_))
+    ));
+    assert!(matches!(empty, Err(WebhookServiceError::InvalidRequest(_))));
+}
+
+#[tokio::test]
+async fn given_non_public_or_non_http_url_when_subscribing_then_request_is_rejected() {
+    let service = service();
+
+    for url in [
+        "http://169.254.169.254/latest/meta-data",
+        "http://127.0.0.1:8080/hook",
+        "https://[::1]/hook",
+        "http://10.0.0.5/hook",
+        "ftp://hooks.example.com/hook",
+        "hooks.example.com/hook",
+    ] {
+        let result = service
+            .subscribe(tenant("acme"), url, vec![WebhookEventType::JobFailed])
+            .await;
+
+        assert!(
+            matches!(result, Err(WebhookServiceError::InvalidRequest(_))),
+            "{url}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn given_private_networks_allowed_when_subscribing_to_internal_url_then_it_is_accepted() {
+    let service = service().with_private_networks(true);
+
+    let result = service
+        .subscribe(
+            tenant("acme"),
+            "http://10.0.0.5/hook",
+            vec![WebhookEventType::JobFailed],
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn given_subscription_of_other_tenant_when_unsubscribing_then_not_found_and_kept() {
+    let service = service();
+    let subscription = service
+        .subscribe(
+            tenant("acme"),
+            "https://hooks.example.com",
+            vec![WebhookEventType::JobFailed],
+        )
+        .await
+        .unwrap();
+
+    let result = service
+        .unsubscribe(&tenant("intruder"), subscription.id)
+        .await;
+
+    assert!(matches!(result, Err(WebhookServiceError::NotFound(_))));
+    assert_eq!(service.list(&tenant("acme")).await.unwrap().len(), 1);
+
+    service
+        .unsubscribe(&tenant("acme"), subscription.id)
+        .await
+        .unwrap();
+    assert!(service.list(&tenant("acme")).await.unwrap().is_empty());
+}
@@ -0,0 +1,50 @@
+use sandakan::domain::{ApiKey, ApiScope, Principal, TenantId};
+
+#[test]
+fn given_generated_key_when_inspected_then_only_hash_and_prefix_of_secret_are_kept() {
+    let (key, secret) = ApiKey::generate("ci", TenantId::default(), vec![ApiScope::Ingest]);
+
+    assert!(secret.starts_with("sk-"));
+    assert!(secret.starts_with(&key.key_prefix));
+    assert!(key.key_prefix.len() < secret.len());
+    assert_eq!(key.key_hash, ApiKey::hash_secret(&secret));
+    assert_ne!(key.key_hash, secret);
+    assert!(!key.is_revoked());
+}
+
+#[test]
+fn given_two_generated_keys_when_compared_then_secrets_differ() {
+    let (_, first) = ApiKey::generate("a", TenantId::default(), vec![ApiScope::Query]);
+    let (_, second) = ApiKey::generate("b", TenantId::default(), vec![ApiScope::Query]);
+
+    assert_ne!(first, second);
+}
+
+#[test]
+fn given_scope_names_when_parsed_then_round_trip() {
+    for scope in ApiScope::ALL {
+        assert_eq!(scope.as_str().parse::<ApiScope>(), Ok(scope));
+    }
+    assert!("superuser".parse::<ApiScope>().is_err());
+}
+
+#[test]
+fn given_query_only_principal_when_checking_scopes_then_only_query_is_granted() {
+    let (key, _) = ApiKey::generate("reader", TenantId::default(), vec![ApiScope::Query]);
+    let principal = Principal::from_api_key(&key);
+
+    assert!(principal.has_scope(ApiScope::Query));
+    assert!(!principal.has_scope(ApiScope::Ingest));
+    assert!(!principal.has_scope(ApiScope::Admin));
+    assert_eq!(principal.subject, format!("api_key:{}", key.id));
+}
+
+#[test]
+fn given_admin_principal_when_checking_scopes_then_every_scope_is_granted() {
+    let (key, _) = ApiKey::generate("ops", TenantId::default(), vec![ApiScope::Admin]);
+    let principal = Principal::from_api_key(&key);
+
+    for scope in ApiScope::ALL {
+        assert!(principal.has_scope(scope));
+    }
+}
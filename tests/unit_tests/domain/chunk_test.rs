@@ -1,5 +1,5 @@
 use sandakan::domain::ContentType;
-use sandakan::domain::{Chunk, ChunkId, DocumentId, DocumentMetadata};
+use sandakan::domain::{Chunk, ChunkId, DocumentId, DocumentMetadata, TenantId};
 use std::sync::Arc;
 
 #[test]
@@ -36,6 +36,10 @@ fn given_media_chunk_with_start_time_when_as_contextual_string_then_shows_second
         title: "Lecture 1".to_string(),
         content_type: ContentType::Video,
         source_url: None,
+        author: None,
+        tags: vec![],
+        attributes: Default::default(),
+        tenant_id: TenantId::default(),
     });
     let chunk = Chunk::with_metadata(
         "Neural networks explained.".to_string(),
@@ -59,6 +63,10 @@ fn given_pdf_chunk_with_page_when_as_contextual_string_then_shows_page_number()
         title: "Report".to_string(),
         content_type: ContentType::Pdf,
         source_url: None,
+        author: None,
+        tags: vec![],
+        attributes: Default::default(),
+        tenant_id: TenantId::default(),
     });
     let chunk = Chunk::with_metadata("Some PDF text.".to_string(), doc_id, Some(5), 0, meta);
 
@@ -66,3 +74,21 @@ fn given_pdf_chunk_with_page_when_as_contextual_string_then_shows_page_number()
     assert!(ctx.contains("Page: 5"));
     assert!(!ctx.contains("s")); // no seconds label
 }
+
+#[test]
+fn given_chunk_with_page_range_when_labelling_then_shows_both_pages() {
+    let chunk =
+        Chunk::new("spans a break".to_string(), DocumentId::new(), None, 0).with_pages(3, 4);
+
+    assert_eq!(chunk.page, Some(3));
+    assert_eq!(chunk.end_page, Some(4));
+    assert_eq!(chunk.page_label().as_deref(), Some("3-4"));
+}
+
+#[test]
+fn given_single_page_chunk_when_labelling_then_shows_one_page() {
+    let chunk = Chunk::new("one page".to_string(), DocumentId::new(), Some(7), 0);
+
+    assert_eq!(chunk.end_page, Some(7));
+    assert_eq!(chunk.page_label().as_deref(), Some("7"));
+}
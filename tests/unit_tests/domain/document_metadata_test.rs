@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use sandakan::domain::{Chunk, Document};
-use sandakan::domain::{ContentType, DocumentId, DocumentMetadata};
+use sandakan::domain::{ContentType, DocumentId, DocumentMetadata, SourceMetadata};
 
 fn make_document(filename: &str) -> Document {
     Document::new(filename.to_string(), ContentType::Pdf, 1024)
@@ -46,6 +46,79 @@ fn given_document_with_no_source_url_when_creating_metadata_then_source_url_is_n
     assert!(meta.source_url.is_none());
 }
 
+#[test]
+fn given_source_metadata_when_applied_then_overrides_title_and_carries_tags() {
+    let doc = make_document("lecture_03.mp4");
+    let source = SourceMetadata {
+        source_url: Some("https://www.youtube.com/watch?v=abc123".to_string()),
+        title: Some("Entropy explained".to_string()),
+        author: Some("Prof. Lee".to_string()),
+        tags: vec!["physics".to_string()],
+        attributes: [("course".to_string(), "PHYS-201".to_string())].into(),
+    };
+
+    let meta = DocumentMetadata::from_document(&doc, None).with_source(&source);
+
+    assert_eq!(meta.title, "Entropy explained");
+    assert_eq!(meta.source_url, source.source_url);
+    assert_eq!(meta.author.as_deref(), Some("Prof. Lee"));
+    assert_eq!(meta.tags, vec!["physics"]);
+    assert_eq!(meta.attributes["course"], "PHYS-201");
+}
+
+#[test]
+fn given_empty_source_metadata_when_applied_then_keeps_derived_title() {
+    let doc = make_document("lecture_03.mp4");
+
+    let meta = DocumentMetadata::from_document(&doc, None).with_source(&SourceMetadata::default());
+
+    assert_eq!(meta.title, "lecture_03");
+    assert!(meta.attributes.is_empty());
+}
+
+// ─── SourceMetadata::normalized ──────────────────────────────────────────────
+
+#[test]
+fn given_padded_values_when_normalizing_then_trims_and_drops_blank_and_duplicate_tags() {
+    let source = SourceMetadata {
+        source_url: Some("  https://example.com/talk  ".to_string()),
+        title: Some("   ".to_string()),
+        author: None,
+        tags: vec![
+            " ml ".to_string(),
+            "".to_string(),
+            "ml".to_string(),
+            "nlp".to_string(),
+        ],
+        attributes: [(" team ".to_string(), " search ".to_string())].into(),
+    };
+
+    let normalized = source.normalized().unwrap();
+
+    assert_eq!(
+        normalized.source_url.as_deref(),
+        Some("https://example.com/talk")
+    );
+    assert!(normalized.title.is_none());
+    assert_eq!(normalized.tags, vec!["ml", "nlp"]);
+    assert_eq!(normalized.attributes["team"], "search");
+}
+
+#[test]
+fn given_non_http_source_url_or_blank_attribute_key_when_normalizing_then_rejected() {
+    let bad_url = SourceMetadata {
+        source_url: Some("ftp://example.com/talk".to_string()),
+        ..SourceMetadata::default()
+    };
+    let blank_key = SourceMetadata {
+        attributes: [("  ".to_string(), "x".to_string())].into(),
+        ..SourceMetadata::default()
+    };
+
+    assert!(bad_url.normalized().is_err());
+    assert!(blank_key.normalized().is_err());
+}
+
 // ─── Chunk::as_contextual_string ─────────────────────────────────────────────
 
 #[test]
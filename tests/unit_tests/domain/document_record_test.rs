@@ -0,0 +1,45 @@
+use sandakan::domain::{ContentType, Document, DocumentRecord, StoragePath};
+
+#[test]
+fn given_same_bytes_when_hashing_then_hash_is_stable_hex_sha256() {
+    let hash = DocumentRecord::hash_content(b"abc");
+
+    assert_eq!(
+        hash,
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(hash, DocumentRecord::hash_content(b"abc"));
+}
+
+#[test]
+fn given_new_record_when_created_then_not_yet_ingested() {
+    let document = Document::new("notes.txt".to_string(), ContentType::Text, 10);
+    let record = DocumentRecord::new(&document, StoragePath::from_raw("notes.txt"), true);
+
+    assert_eq!(record.id, document.id);
+    assert_eq!(record.chunk_count, 0);
+    assert!(record.content_hash.is_none());
+    assert!(record.ingested_at.is_none());
+}
+
+#[test]
+fn given_record_when_converted_to_document_then_fields_round_trip() {
+    let document = Document::new("talk.mp4".to_string(), ContentType::Video, 2048);
+    let record = DocumentRecord::new(&document, StoragePath::from_raw("talk.mp4"), false);
+
+    assert_eq!(record.to_document(), document);
+}
+
+#[test]
+fn given_media_hash_when_captions_change_then_captioned_hash_changes() {
+    let media = DocumentRecord::hash_content(b"audio bytes");
+    let first = DocumentRecord::hash_captioned(&media, b"WEBVTT\n\n00:01.000 --> 00:02.000\nHi");
+    let second = DocumentRecord::hash_captioned(&media, b"WEBVTT\n\n00:01.000 --> 00:02.000\nBye");
+
+    assert_ne!(first, media);
+    assert_ne!(first, second);
+    assert_eq!(
+        first,
+        DocumentRecord::hash_captioned(&media, b"WEBVTT\n\n00:01.000 --> 00:02.000\nHi")
+    );
+}
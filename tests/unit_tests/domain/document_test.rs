@@ -36,3 +36,45 @@ fn given_video_quicktime_mime_when_parsing_then_returns_video_content_type() {
 fn given_unknown_mime_when_parsing_then_returns_none() {
     assert_eq!(ContentType::from_mime("application/unknown"), None);
 }
+
+#[test]
+fn given_office_mimes_when_parsing_then_round_trip_to_office_content_types() {
+    for content_type in [ContentType::Docx, ContentType::Pptx, ContentType::Xlsx] {
+        assert_eq!(
+            ContentType::from_mime(content_type.as_mime()),
+            Some(content_type)
+        );
+        assert!(content_type.is_markdown_source());
+    }
+    assert!(!ContentType::Text.is_markdown_source());
+}
+
+#[test]
+fn given_markdown_and_html_mimes_when_parsing_then_return_markdown_sources() {
+    assert_eq!(
+        ContentType::from_mime("text/x-markdown"),
+        Some(ContentType::Markdown)
+    );
+    assert_eq!(
+        ContentType::from_mime("application/xhtml+xml"),
+        Some(ContentType::Html)
+    );
+    for content_type in [ContentType::Markdown, ContentType::Html] {
+        assert_eq!(
+            ContentType::from_mime(content_type.as_mime()),
+            Some(content_type)
+        );
+        assert!(content_type.is_markdown_source());
+    }
+}
+
+#[test]
+fn given_caption_mimes_when_parsing_then_return_subtitles() {
+    for mime in ["text/vtt", "application/x-subrip", "text/srt"] {
+        assert_eq!(ContentType::from_mime(mime), Some(ContentType::Subtitles));
+    }
+    assert_eq!(
+        ContentType::from_mime(ContentType::Subtitles.as_mime()),
+        Some(ContentType::Subtitles)
+    );
+}
@@ -42,6 +42,7 @@ fn given_eval_event_when_built_then_sources_match_input() {
     let sources = vec![EvalSource {
         text: "chunk content".to_string(),
         page: Some(5),
+        end_page: Some(5),
         score: 0.88,
     }];
     let event = EvalEvent::new(
@@ -66,11 +67,13 @@ fn given_eval_event_with_multiple_sources_when_getting_context_text_then_joined_
         EvalSource {
             text: "First chunk".to_string(),
             page: Some(1),
+            end_page: Some(1),
             score: 0.9,
         },
         EvalSource {
             text: "Second chunk".to_string(),
             page: Some(2),
+            end_page: Some(2),
             score: 0.8,
         },
     ];
@@ -95,6 +98,7 @@ fn given_new_agentic_constructor_when_creating_eval_event_then_operation_type_is
     let sources = vec![EvalSource {
         text: "context".to_string(),
         page: None,
+        end_page: None,
         score: 0.9,
     }];
     let event = EvalEvent::new_agentic(
@@ -155,3 +159,16 @@ fn given_ingestion_event_with_zero_chunks_when_checking_answer_then_chunk_count_
     let chunk_count: usize = event.generated_answer.parse().unwrap();
     assert_eq!(chunk_count, 0);
 }
+
+#[test]
+fn given_event_serialized_before_principal_existed_when_deserialized_then_principal_is_none() {
+    let event = EvalEvent::new("q", "a", Vec::new(), "model", None)
+        .with_principal(Some("api_key:1".to_string()));
+    let mut json = serde_json::to_value(&event).unwrap();
+    json.as_object_mut().unwrap().remove("principal");
+
+    let restored: EvalEvent = serde_json::from_value(json).unwrap();
+
+    assert!(restored.principal.is_none());
+    assert_eq!(event.principal.as_deref(), Some("api_key:1"));
+}
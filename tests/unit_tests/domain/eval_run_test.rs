@@ -0,0 +1,89 @@
+use sandakan::domain::{EVAL_REPORT_SCHEMA_VERSION, EvalRunId, EvalRunItem, EvalRunReport};
+
+fn item(faithfulness: Option<f32>, below_threshold: bool) -> EvalRunItem {
+    EvalRunItem {
+        event_id: None,
+        question: "q".to_string(),
+        generated_answer: "a".to_string(),
+        context_recall: faithfulness,
+        faithfulness,
+        correctness: faithfulness,
+        answer_relevancy: None,
+        context_precision: None,
+        failed: below_threshold,
+        below_threshold,
+        error: None,
+    }
+}
+
+fn errored_item() -> EvalRunItem {
+    EvalRunItem {
+        failed: true,
+        error: Some("judge: unparseable score".to_string()),
+        ..item(None, false)
+    }
+}
+
+#[test]
+fn given_items_when_report_built_then_means_skip_missing_metrics() {
+    let report = EvalRunReport::from_items(
+        EvalRunId::new(),
+        "judge/model",
+        vec![
+            item(Some(0.8), false),
+            item(Some(0.4), true),
+            errored_item(),
+        ],
+    );
+
+    assert_eq!(report.total_evaluated, 3);
+    assert_eq!(report.schema_version, EVAL_REPORT_SCHEMA_VERSION);
+    assert_eq!(report.failed_count, 2);
+    assert_eq!(report.below_threshold_count, 1);
+    assert_eq!(report.errored_count, 1);
+    assert!((report.mean_faithfulness.unwrap() - 0.6).abs() < 1e-6);
+    assert!(report.mean_answer_relevancy.is_none());
+}
+
+#[test]
+fn given_no_items_when_report_built_then_means_are_none() {
+    let report = EvalRunReport::from_items(EvalRunId::new(), "judge/model", vec![]);
+
+    assert_eq!(report.total_evaluated, 0);
+    assert!(report.mean_faithfulness.is_none());
+}
+
+#[test]
+fn given_report_when_serialized_then_round_trips() {
+    let report = EvalRunReport::from_items(
+        EvalRunId::new(),
+        "judge/model",
+        vec![item(Some(0.9), false)],
+    );
+
+    let json = serde_json::to_string(&report).unwrap();
+    let decoded: EvalRunReport = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(decoded.run_id, report.run_id);
+    assert_eq!(decoded.results.len(), 1);
+}
+
+#[test]
+fn given_committed_version_1_baseline_when_deserialized_then_failures_are_kept() {
+    let baseline: EvalRunReport =
+        serde_json::from_str(include_str!("../../../evaluation_report.json")).unwrap();
+
+    assert_eq!(baseline.schema_version, 1);
+    assert_eq!(baseline.failed_count, 1);
+    assert!(baseline.results[0].failed);
+}
+
+#[test]
+fn given_errored_item_when_built_then_failed_is_kept_for_version_1_readers() {
+    let item = EvalRunItem::errored("q", "judge: timeout".to_string());
+
+    assert!(item.failed);
+    assert!(!item.below_threshold);
+    assert_eq!(item.error.as_deref(), Some("judge: timeout"));
+    assert!(item.faithfulness.is_none());
+}
@@ -1,11 +1,16 @@
+mod api_key_test;
 mod chunk_test;
 mod document_metadata_test;
+mod document_record_test;
 mod document_test;
 mod embedding_test;
 mod eval_event_test;
 mod eval_outbox_test;
 mod eval_result_test;
+mod eval_run_test;
+mod robots_test;
 mod sparse_embedding_test;
 mod storage_path_test;
 mod tool_call_test;
 mod transcript_segment_test;
+mod webhook_test;
@@ -0,0 +1,55 @@
+use sandakan::domain::RobotsRules;
+
+const ROBOTS_TXT: &str = "\
+# Example site
+User-agent: *
+Disallow: /private/
+Allow: /private/press/
+Disallow: /*.pdf$
+
+User-agent: Sandakan
+User-agent: OtherBot
+Disallow: /drafts
+Disallow:
+";
+
+#[test]
+fn given_generic_group_when_checking_then_longest_match_decides() {
+    let rules = RobotsRules::parse(ROBOTS_TXT, "somebot");
+
+    assert!(rules.allows("/docs/intro"));
+    assert!(!rules.allows("/private/keys"));
+    assert!(rules.allows("/private/press/2024"));
+    assert!(!rules.allows("/files/report.pdf"));
+    assert!(rules.allows("/files/report.pdf?download=1"));
+}
+
+#[test]
+fn given_group_naming_crawler_when_checking_then_only_that_group_applies() {
+    let rules = RobotsRules::parse(ROBOTS_TXT, "sandakan");
+
+    assert!(!rules.allows("/drafts/next"));
+    assert!(rules.allows("/private/keys"));
+}
+
+#[test]
+fn given_equal_length_allow_and_disallow_when_checking_then_allow_wins() {
+    let rules = RobotsRules::parse("User-agent: *\nDisallow: /page\nAllow: /page\n", "sandakan");
+
+    assert!(rules.allows("/page"));
+}
+
+#[test]
+fn given_wildcard_in_middle_when_checking_then_pattern_spans_segments() {
+    let rules = RobotsRules::parse("User-agent: *\nDisallow: /*/print\n", "sandakan");
+
+    assert!(!rules.allows("/guides/deploy/print"));
+    assert!(rules.allows("/print"));
+}
+
+#[test]
+fn given_empty_or_missing_robots_when_checking_then_everything_is_allowed() {
+    assert!(RobotsRules::parse("", "sandakan").allows("/anything"));
+    assert!(RobotsRules::allow_all().allows("/anything"));
+    assert!(!RobotsRules::disallow_all().allows("/"));
+}
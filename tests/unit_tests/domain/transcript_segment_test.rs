@@ -1,4 +1,4 @@
-use sandakan::domain::TranscriptSegment;
+use sandakan::domain::{TranscriptSegment, TranscriptWord};
 
 #[test]
 fn given_segment_text_and_times_when_created_then_fields_are_stored() {
@@ -34,3 +34,140 @@ fn given_segments_with_whitespace_text_when_merge_text_called_then_blank_segment
     let merged = TranscriptSegment::merge_text(&segments);
     assert_eq!(merged, "Real content.");
 }
+
+#[test]
+fn given_srt_captions_when_parsing_then_cues_become_timed_segments() {
+    let srt = "1\r\n00:00:01,000 --> 00:00:03,500\r\nHello <i>there</i>,\r\ngeneral.\r\n\r\n2\r\n01:02:03,250 --> 01:02:05,000\r\nSecond cue.\r\n";
+
+    let segments = TranscriptSegment::parse_captions(srt).unwrap();
+
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].text, "Hello there, general.");
+    assert!((segments[0].start_time - 1.0).abs() < f32::EPSILON);
+    assert!((segments[0].end_time - 3.5).abs() < f32::EPSILON);
+    assert!((segments[1].start_time - 3723.25).abs() < 0.01);
+}
+
+#[test]
+fn given_webvtt_with_header_notes_and_settings_when_parsing_then_only_cue_text_is_kept() {
+    let vtt = "\u{feff}WEBVTT - talk\n\nNOTE speaker notes\nare ignored\n\nintro\n00:05.000 --> 00:07.000 align:start position:10%\n<v Ada>Welcome &amp; hello</v>\n\n00:00:07.000 --> 00:00:09.000\nNext topic.\n";
+
+    let segments = TranscriptSegment::parse_captions(vtt).unwrap();
+
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].text, "Welcome & hello");
+    assert!((segments[0].start_time - 5.0).abs() < f32::EPSILON);
+    assert!((segments[0].end_time - 7.0).abs() < f32::EPSILON);
+    assert_eq!(segments[1].text, "Next topic.");
+}
+
+#[test]
+fn given_repeated_cue_text_when_parsing_then_cues_are_merged() {
+    let vtt = "WEBVTT\n\n00:01.000 --> 00:02.000\nRolling line\n\n00:02.000 --> 00:03.000\nRolling line\n";
+
+    let segments = TranscriptSegment::parse_captions(vtt).unwrap();
+
+    assert_eq!(segments.len(), 1);
+    assert!((segments[0].end_time - 3.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn given_invalid_timestamp_when_parsing_captions_then_error_names_the_line() {
+    let srt = "1\n00:00:01 --> 00:00:02,000\nBroken\n";
+
+    let error = TranscriptSegment::parse_captions(srt).unwrap_err();
+
+    assert!(error.contains("line 2"), "{error}");
+}
+
+#[test]
+fn given_text_without_cues_when_parsing_captions_then_error_is_returned() {
+    assert!(TranscriptSegment::parse_captions("just some text").is_err());
+}
+
+#[test]
+fn given_segments_when_rendered_as_srt_then_cues_are_numbered_with_comma_millis() {
+    let segments = vec![
+        TranscriptSegment::new("First line.", 1.5, 3.25),
+        TranscriptSegment::new("Second\nline.", 3723.0, 3725.0),
+    ];
+
+    let srt = TranscriptSegment::to_srt(&segments);
+
+    assert_eq!(
+        srt,
+        "1\n00:00:01,500 --> 00:00:03,250\nFirst line.\n\n2\n01:02:03,000 --> 01:02:05,000\nSecond line.\n\n"
+    );
+}
+
+#[test]
+fn given_segments_with_markup_characters_when_rendered_as_vtt_then_they_round_trip() {
+    let segments = vec![
+        TranscriptSegment::new("a < b & c > d", 0.0, 2.0),
+        TranscriptSegment::new("Closing remarks.", 2.0, 4.5),
+    ];
+
+    let vtt = TranscriptSegment::to_vtt(&segments);
+
+    assert!(vtt.starts_with("WEBVTT\n\n00:00:00.000 --> 00:00:02.000\na &lt; b &amp; c &gt; d\n"));
+    assert_eq!(TranscriptSegment::parse_captions(&vtt).unwrap(), segments);
+}
+
+#[test]
+fn given_segments_when_rendered_as_plain_text_then_each_segment_is_a_line() {
+    let segments = vec![
+        TranscriptSegment::new("First.", 0.0, 1.0),
+        TranscriptSegment::new("  ", 1.0, 2.0),
+        TranscriptSegment::new("Second.", 2.0, 3.0),
+    ];
+
+    assert_eq!(
+        TranscriptSegment::to_plain_text(&segments),
+        "First.\nSecond.\n"
+    );
+}
+
+#[test]
+fn given_invalid_segments_when_validating_transcript_then_the_segment_is_named() {
+    assert!(TranscriptSegment::validate_transcript(&[]).is_err());
+    let error = TranscriptSegment::validate_transcript(&[
+        TranscriptSegment::new("Fine.", 0.0, 1.0),
+        TranscriptSegment::new("Runs backwards.", 3.0, 2.0),
+    ])
+    .unwrap_err();
+    assert!(error.starts_with("segment 2"), "{error}");
+    assert!(
+        TranscriptSegment::validate_transcript(&[TranscriptSegment::new("", 0.0, 1.0)]).is_err()
+    );
+}
+
+#[test]
+fn given_separately_reported_words_when_attaching_then_each_lands_in_its_segment() {
+    let mut segments = vec![
+        TranscriptSegment::new("Hello there.", 0.0, 2.0),
+        TranscriptSegment::new("General Kenobi.", 2.0, 4.0),
+    ];
+    let words = vec![
+        TranscriptWord::new("Hello", 0.1, 0.6),
+        TranscriptWord::new("there.", 0.7, 1.9),
+        TranscriptWord::new("General", 1.95, 2.6),
+        TranscriptWord::new("Kenobi.", 2.7, 3.5),
+    ];
+
+    TranscriptSegment::attach_words(&mut segments, words);
+
+    assert_eq!(segments[0].words.len(), 2);
+    assert_eq!(segments[1].words[0].text, "General");
+    assert_eq!(segments[1].words.len(), 2);
+}
+
+#[test]
+fn given_timed_words_when_asking_speech_start_then_first_word_wins_over_segment_start() {
+    let padded = TranscriptSegment::new("Welcome.", 4.8, 7.2);
+    let timed = padded
+        .clone()
+        .with_words(vec![TranscriptWord::new("Welcome.", 5.03, 5.6)]);
+
+    assert!((padded.speech_start() - 4.8).abs() < f32::EPSILON);
+    assert!((timed.speech_start() - 5.03).abs() < f32::EPSILON);
+}
@@ -0,0 +1,50 @@
+use sandakan::domain::{TenantId, WebhookEvent, WebhookEventType, WebhookSubscription};
+
+#[test]
+fn given_event_type_names_when_parsed_then_round_trip() {
+    for event_type in WebhookEventType::ALL {
+        assert_eq!(
+            event_type.as_str().parse::<WebhookEventType>(),
+            Ok(event_type)
+        );
+        assert_eq!(
+            serde_json::to_value(event_type).unwrap(),
+            serde_json::json!(event_type.as_str())
+        );
+    }
+    assert!("document.deleted".parse::<WebhookEventType>().is_err());
+}
+
+#[test]
+fn given_event_when_building_payload_then_envelope_carries_id_type_and_data() {
+    let event = WebhookEvent::new(
+        TenantId::default(),
+        WebhookEventType::DocumentIngested,
+        serde_json::json!({ "chunk_count": 3 }),
+    );
+
+    let payload = event.payload();
+
+    assert_eq!(payload["id"], serde_json::json!(event.id));
+    assert_eq!(payload["type"], "document.ingested");
+    assert_eq!(payload["data"]["chunk_count"], 3);
+    assert!(payload["created_at"].is_string());
+}
+
+#[test]
+fn given_generated_subscriptions_when_compared_then_secrets_differ_and_filter_events() {
+    let first = WebhookSubscription::generate(
+        TenantId::default(),
+        "https://a.test",
+        vec![WebhookEventType::JobFailed],
+    );
+    let second = WebhookSubscription::generate(
+        TenantId::default(),
+        "https://b.test",
+        vec![WebhookEventType::JobFailed],
+    );
+
+    assert_ne!(first.secret, second.secret);
+    assert!(first.subscribes_to(WebhookEventType::JobFailed));
+    assert!(!first.subscribes_to(WebhookEventType::DocumentIngested));
+}
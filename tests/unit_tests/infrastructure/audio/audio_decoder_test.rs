@@ -151,3 +151,50 @@ fn given_ffmpeg_in_path_when_checking_binary_then_returns_ok() {
 
     assert!(result.is_ok());
 }
+
+#[test]
+fn given_wav_file_when_opening_stream_then_reads_all_samples_in_buffers() {
+    if !ffmpeg_available() {
+        return;
+    }
+
+    let wav = build_wav(16_000, &vec![1000i16; 16_000]);
+    let input = tempfile::Builder::new().suffix(".wav").tempfile().unwrap();
+    std::fs::write(input.path(), &wav).unwrap();
+
+    let mut reader = FfmpegAudioDecoder.open(input.path()).unwrap();
+    let mut buffer = vec![0.0f32; 480];
+    let mut total = 0;
+    loop {
+        let read = reader.read(&mut buffer).unwrap();
+        if read == 0 {
+            break;
+        }
+        assert!(read <= buffer.len());
+        total += read;
+    }
+
+    assert_eq!(total, 16_000);
+}
+
+#[test]
+fn given_corrupted_file_when_reading_stream_then_returns_decoding_error() {
+    if !ffmpeg_available() {
+        return;
+    }
+
+    let input = tempfile::Builder::new()
+        .suffix(".media")
+        .tempfile()
+        .unwrap();
+    std::fs::write(input.path(), vec![0xFFu8; 128]).unwrap();
+
+    let result = FfmpegAudioDecoder
+        .open(input.path())
+        .and_then(|mut reader| reader.read(&mut [0.0f32; 480]));
+
+    assert!(matches!(
+        result,
+        Err(sandakan::application::ports::AudioDecoderError::DecodingFailed(_))
+    ));
+}
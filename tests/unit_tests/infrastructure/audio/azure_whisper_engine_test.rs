@@ -4,7 +4,7 @@ use axum::routing::post;
 use tokio::net::TcpListener;
 use tokio::sync::oneshot;
 
-use sandakan::application::ports::TranscriptionEngine;
+use sandakan::application::ports::{TranscriptionEngine, TranscriptionOptions};
 use sandakan::infrastructure::audio::AzureWhisperEngine;
 
 async fn start_mock_azure_server(
@@ -48,7 +48,7 @@ async fn given_valid_audio_bytes_when_azure_transcribes_then_returns_timed_segme
     let result = engine.transcribe(audio_data).await;
 
     assert!(result.is_ok());
-    let segments = result.unwrap();
+    let segments = result.unwrap().segments;
     assert_eq!(segments.len(), 1);
     assert_eq!(segments[0].text, "Hello from Azure Whisper");
     assert!((segments[0].start_time - 0.0).abs() < f32::EPSILON);
@@ -84,7 +84,7 @@ async fn given_azure_api_returns_empty_segments_when_transcribing_then_returns_e
     let result = engine.transcribe(audio_data).await;
 
     assert!(result.is_ok());
-    assert!(result.unwrap().is_empty());
+    assert!(result.unwrap().segments.is_empty());
     shutdown_tx.send(()).ok();
 }
 
@@ -102,7 +102,7 @@ async fn given_multiple_segments_when_azure_transcribes_then_all_segments_carry_
 
     let engine = AzureWhisperEngine::new(&base_url, "my-deployment", "test-key", "2024-02-01");
 
-    let result = engine.transcribe(b"audio").await.unwrap();
+    let result = engine.transcribe(b"audio").await.unwrap().segments;
 
     assert_eq!(result.len(), 3);
     assert!((result[0].start_time - 0.0).abs() < f32::EPSILON);
@@ -110,3 +110,105 @@ async fn given_multiple_segments_when_azure_transcribes_then_all_segments_carry_
     assert!((result[2].start_time - 10.5).abs() < 0.01);
     shutdown_tx.send(()).ok();
 }
+
+type RecordedRequests = std::sync::Arc<std::sync::Mutex<Vec<(String, String)>>>;
+
+/// Answers both Whisper tasks with `response_body`, recording each request's path and body.
+async fn start_recording_azure_server(
+    response_body: &'static str,
+) -> (String, oneshot::Sender<()>, RecordedRequests) {
+    let (shutdown_tx, shutdown_rx) = oneshot::channel::<()>();
+    let requests = RecordedRequests::default();
+
+    let record = |requests: RecordedRequests| {
+        post(
+            move |uri: axum::http::Uri, body: axum::body::Bytes| async move {
+                requests.lock().unwrap().push((
+                    uri.path().to_string(),
+                    String::from_utf8_lossy(&body).into_owned(),
+                ));
+                response_body
+            },
+        )
+    };
+    let app = Router::new()
+        .route(
+            "/openai/deployments/my-deployment/audio/transcriptions",
+            record(requests.clone()),
+        )
+        .route(
+            "/openai/deployments/my-deployment/audio/translations",
+            record(requests.clone()),
+        );
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async {
+                shutdown_rx.await.ok();
+            })
+            .await
+            .ok();
+    });
+
+    (base_url, shutdown_tx, requests)
+}
+
+#[tokio::test]
+async fn given_word_timestamps_when_azure_transcribes_then_words_and_language_are_returned() {
+    let response_body = r#"{
+        "language": "german",
+        "segments": [
+            {"id": 0, "start": 0.0, "end": 2.0, "text": "Guten Morgen."},
+            {"id": 1, "start": 2.0, "end": 4.0, "text": "Willkommen."}
+        ],
+        "words": [
+            {"word": "Guten", "start": 0.3, "end": 0.8},
+            {"word": "Morgen.", "start": 0.8, "end": 1.6},
+            {"word": "Willkommen.", "start": 2.4, "end": 3.4}
+        ]
+    }"#;
+    let (base_url, shutdown_tx, requests) = start_recording_azure_server(response_body).await;
+
+    let engine = AzureWhisperEngine::new(&base_url, "my-deployment", "test-key", "2024-02-01")
+        .with_options(TranscriptionOptions {
+            language: Some("de".to_string()),
+            word_timestamps: true,
+            ..TranscriptionOptions::default()
+        });
+
+    let transcript = engine.transcribe(b"audio").await.unwrap();
+
+    assert_eq!(transcript.language.as_deref(), Some("de"));
+    assert_eq!(transcript.segments[0].words.len(), 2);
+    assert_eq!(transcript.segments[1].words[0].text, "Willkommen.");
+    assert!((transcript.segments[1].speech_start() - 2.4).abs() < 0.01);
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].0.ends_with("/audio/transcriptions"));
+    assert!(requests[0].1.contains("timestamp_granularities[]"));
+    assert!(requests[0].1.contains("name=\"language\""));
+    shutdown_tx.send(()).ok();
+}
+
+#[tokio::test]
+async fn given_translate_option_when_azure_transcribes_then_translations_endpoint_is_used() {
+    let response_body = r#"{"language": "english", "segments": [{"id": 0, "start": 0.0, "end": 2.0, "text": "Good morning."}]}"#;
+    let (base_url, shutdown_tx, requests) = start_recording_azure_server(response_body).await;
+
+    let engine = AzureWhisperEngine::new(&base_url, "my-deployment", "test-key", "2024-02-01")
+        .with_options(TranscriptionOptions {
+            language: Some("de".to_string()),
+            translate: true,
+            word_timestamps: true,
+        });
+
+    let transcript = engine.transcribe(b"audio").await.unwrap();
+
+    assert_eq!(transcript.segments[0].text, "Good morning.");
+    assert!(transcript.segments[0].words.is_empty());
+    let requests = requests.lock().unwrap();
+    assert!(requests[0].0.ends_with("/audio/translations"));
+    assert!(!requests[0].1.contains("name=\"language\""));
+    shutdown_tx.send(()).ok();
+}
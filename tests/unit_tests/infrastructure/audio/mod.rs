@@ -1,3 +1,5 @@
 mod audio_decoder_test;
 mod azure_whisper_engine_test;
 mod candle_whisper_engine_test;
+mod transcription_engine_factory_test;
+mod voice_activity_test;
@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use sandakan::application::ports::{TranscriptionError, TranscriptionOptions};
+use sandakan::infrastructure::audio::{
+    TranscriptionEngineFactory, TranscriptionProvider, whisper_language_code,
+};
+
+fn create_openai(language: &str) -> Result<(), TranscriptionError> {
+    TranscriptionEngineFactory::create(
+        TranscriptionProvider::OpenAi,
+        "whisper-1",
+        Some("key".to_string()),
+        None,
+        None,
+        None,
+        None,
+        HashMap::new(),
+        TranscriptionOptions {
+            language: Some(language.to_string()),
+            ..TranscriptionOptions::default()
+        },
+    )
+    .map(|_| ())
+}
+
+#[test]
+fn given_language_names_and_codes_when_resolving_then_whisper_codes_are_returned() {
+    assert_eq!(whisper_language_code("de"), Some("de"));
+    assert_eq!(whisper_language_code("German"), Some("de"));
+    assert_eq!(whisper_language_code(" UK "), Some("uk"));
+    assert_eq!(whisper_language_code("klingon"), None);
+}
+
+#[test]
+fn given_unsupported_language_when_creating_engine_then_configuration_is_rejected() {
+    assert!(matches!(
+        create_openai("klingon"),
+        Err(TranscriptionError::ModelLoadFailed(message)) if message.contains("klingon")
+    ));
+}
+
+#[test]
+fn given_auto_or_named_language_when_creating_engine_then_engine_is_built() {
+    assert!(create_openai("auto").is_ok());
+    assert!(create_openai("French").is_ok());
+}
@@ -0,0 +1,139 @@
+use sandakan::application::ports::{AudioDecoderError, PcmReader};
+use sandakan::infrastructure::audio::{
+    PcmSliceReader, SpeechWindow, SpeechWindows, VoiceActivityOptions,
+};
+
+const RATE: usize = 16_000;
+
+fn speech(secs: f32) -> Vec<f32> {
+    let len = (secs * RATE as f32) as usize;
+    (0..len)
+        .map(|i| 0.3 * (i as f32 * 440.0 * std::f32::consts::TAU / RATE as f32).sin())
+        .collect()
+}
+
+fn silence(secs: f32) -> Vec<f32> {
+    vec![0.0; (secs * RATE as f32) as usize]
+}
+
+fn windows(pcm: &[f32], options: VoiceActivityOptions) -> Vec<SpeechWindow> {
+    let mut reader = PcmSliceReader::new(pcm);
+    SpeechWindows::new(&mut reader, options)
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+/// Synthetic speech of any length, generated on demand rather than held in memory.
+struct EndlessSpeech {
+    remaining: usize,
+}
+
+impl PcmReader for EndlessSpeech {
+    fn read(&mut self, buffer: &mut [f32]) -> Result<usize, AudioDecoderError> {
+        let len = buffer.len().min(self.remaining);
+        for (i, sample) in buffer[..len].iter_mut().enumerate() {
+            *sample = if i % 2 == 0 { 0.3 } else { -0.3 };
+        }
+        self.remaining -= len;
+        Ok(len)
+    }
+}
+
+struct FailingReader;
+
+impl PcmReader for FailingReader {
+    fn read(&mut self, _buffer: &mut [f32]) -> Result<usize, AudioDecoderError> {
+        Err(AudioDecoderError::DecodingFailed(
+            "ffmpeg exited".to_string(),
+        ))
+    }
+}
+
+#[test]
+fn given_only_silence_when_windowing_then_no_windows_are_produced() {
+    let pcm = silence(10.0);
+
+    assert!(windows(&pcm, VoiceActivityOptions::default()).is_empty());
+}
+
+#[test]
+fn given_speech_after_silence_when_windowing_then_window_starts_at_speech_minus_padding() {
+    let pcm = [silence(5.0), speech(2.0), silence(3.0)].concat();
+
+    let windows = windows(&pcm, VoiceActivityOptions::default());
+
+    assert_eq!(windows.len(), 1);
+    assert!((windows[0].start_time - 4.8).abs() < 0.05);
+    assert!((windows[0].end_time - 7.2).abs() < 0.05);
+    let duration = windows[0].end_time - windows[0].start_time;
+    assert!((windows[0].samples.len() as f32 / RATE as f32 - duration).abs() < 1e-3);
+}
+
+#[test]
+fn given_utterances_separated_by_long_pause_when_windowing_then_each_gets_its_own_window() {
+    let pcm = [speech(3.0), silence(4.0), speech(2.0)].concat();
+
+    let windows = windows(&pcm, VoiceActivityOptions::default());
+
+    assert_eq!(windows.len(), 2);
+    assert!(windows[0].start_time < 0.05);
+    assert!((windows[1].start_time - 6.8).abs() < 0.05);
+    assert!(windows[1].end_time <= 9.0 + f32::EPSILON);
+}
+
+#[test]
+fn given_short_click_when_windowing_then_it_is_dropped_as_noise() {
+    let pcm = [silence(2.0), speech(0.05), silence(2.0)].concat();
+
+    assert!(windows(&pcm, VoiceActivityOptions::default()).is_empty());
+}
+
+#[test]
+fn given_continuous_speech_when_windowing_then_windows_are_capped_and_contiguous() {
+    let pcm = speech(75.0);
+
+    let windows = windows(&pcm, VoiceActivityOptions::default());
+
+    assert_eq!(windows.len(), 3);
+    assert!(windows.iter().all(|w| w.samples.len() <= 30 * RATE));
+    assert!((windows[1].start_time - windows[0].end_time).abs() < f32::EPSILON);
+    assert!((windows[2].end_time - 75.0).abs() < 0.05);
+}
+
+#[test]
+fn given_short_pause_late_in_full_window_when_windowing_then_cut_falls_in_the_pause() {
+    let pcm = [speech(25.0), silence(0.3), speech(10.0)].concat();
+
+    let windows = windows(&pcm, VoiceActivityOptions::default());
+
+    assert_eq!(windows.len(), 2);
+    assert!(windows[0].end_time > 25.0 && windows[0].end_time < 25.3);
+}
+
+#[test]
+fn given_hours_of_speech_when_windowing_then_no_window_exceeds_the_cap() {
+    let mut reader = EndlessSpeech {
+        remaining: 3 * 3600 * RATE,
+    };
+    let options = VoiceActivityOptions::default();
+    let cap = options.max_window_samples;
+
+    let mut count = 0;
+    for window in SpeechWindows::new(&mut reader, options) {
+        assert!(window.unwrap().samples.len() <= cap);
+        count += 1;
+    }
+
+    assert_eq!(count, 3 * 3600 / 30);
+}
+
+#[test]
+fn given_failing_reader_when_windowing_then_error_is_yielded_once() {
+    let mut reader = FailingReader;
+
+    let results: Vec<_> =
+        SpeechWindows::new(&mut reader, VoiceActivityOptions::default()).collect();
+
+    assert_eq!(results.len(), 1);
+    assert!(results[0].is_err());
+}
@@ -0,0 +1,61 @@
+use axum::Json;
+use axum::Router;
+use axum::routing::post;
+use serde_json::{Value, json};
+use tokio::net::TcpListener;
+
+use sandakan::application::ports::{Reranker, RerankerError};
+use sandakan::infrastructure::llm::HttpReranker;
+
+async fn serve_rerank(response: Value) -> String {
+    let app = Router::new().route(
+        "/v1/rerank",
+        post(move |Json(_): Json<Value>| {
+            let response = response.clone();
+            async move { Json(response) }
+        }),
+    );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{}/v1", addr)
+}
+
+#[tokio::test]
+async fn given_results_sorted_by_relevance_when_reranking_then_scores_follow_input_order() {
+    let base_url = serve_rerank(json!({
+        "results": [
+            { "index": 2, "relevance_score": 0.9 },
+            { "index": 0, "relevance_score": 0.5 },
+            { "index": 1, "relevance_score": 0.1 }
+        ]
+    }))
+    .await;
+    let reranker = HttpReranker::new(&base_url, None, "bge-reranker".to_string());
+
+    let scores = reranker.rerank("q", &["a", "b", "c"]).await.unwrap();
+
+    assert_eq!(scores, vec![0.5, 0.1, 0.9]);
+}
+
+#[tokio::test]
+async fn given_missing_result_when_reranking_then_returns_invalid_response() {
+    let base_url = serve_rerank(json!({
+        "results": [{ "index": 0, "relevance_score": 0.5 }]
+    }))
+    .await;
+    let reranker = HttpReranker::new(&base_url, None, "bge-reranker".to_string());
+
+    let result = reranker.rerank("q", &["a", "b"]).await;
+
+    assert!(matches!(result, Err(RerankerError::InvalidResponse(_))));
+}
+
+#[tokio::test]
+async fn given_no_documents_when_reranking_then_returns_empty_without_request() {
+    let reranker = HttpReranker::new("http://127.0.0.1:1", None, "bge-reranker".to_string());
+
+    let scores = reranker.rerank("q", &[]).await.unwrap();
+
+    assert!(scores.is_empty());
+}
@@ -1,3 +1,5 @@
 mod embedder_factory_test;
+mod http_reranker_test;
 mod local_candle_embedder_test;
 mod openai_embedder_test;
+mod reranker_factory_test;
@@ -0,0 +1,26 @@
+use sandakan::infrastructure::llm::{RerankerFactory, RerankerFactoryError};
+use sandakan::presentation::config::RerankProvider;
+
+#[test]
+fn given_http_provider_with_base_url_when_creating_then_succeeds() {
+    let result = RerankerFactory::create(
+        RerankProvider::Http,
+        "bge-reranker-v2-m3".to_string(),
+        Some("http://localhost:1234/v1".to_string()),
+        None,
+    );
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn given_http_provider_without_base_url_when_creating_then_returns_missing_base_url() {
+    let result = RerankerFactory::create(
+        RerankProvider::Http,
+        "bge-reranker-v2-m3".to_string(),
+        None,
+        None,
+    );
+
+    assert!(matches!(result, Err(RerankerFactoryError::MissingBaseUrl)));
+}
@@ -2,6 +2,9 @@ mod audio;
 mod llm;
 mod observability;
 mod persistence;
+mod source_tree;
 mod storage;
 mod text_processing;
 mod tools;
+mod web;
+mod webhooks;
@@ -0,0 +1,38 @@
+use sandakan::infrastructure::persistence::{load_ground_truth, parse_ground_truth};
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+#[test]
+fn given_jsonl_with_blank_lines_when_parsed_then_returns_all_entries() {
+    let content = r#"{"question":"What is RAG?","expected_answer":"Retrieval augmented generation","expected_source_pages":[1,2]}
+
+{"question":"What is BM25?","expected_answer":"A ranking function"}
+"#;
+
+    let entries = parse_ground_truth(content).unwrap();
+
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].expected_source_pages, Some(vec![1, 2]));
+    assert_eq!(entries[1].expected_source_pages, None);
+}
+
+#[test]
+fn given_malformed_line_when_parsed_then_error_names_line_number() {
+    let content = r#"{"question":"q","expected_answer":"a"}
+not json"#;
+
+    let err = parse_ground_truth(content).unwrap_err();
+
+    assert!(err.to_string().contains("line 2"));
+}
+
+#[tokio::test]
+async fn given_dataset_file_when_loaded_then_returns_entries() {
+    let mut file = NamedTempFile::new().unwrap();
+    writeln!(file, r#"{{"question":"q","expected_answer":"a"}}"#).unwrap();
+
+    let entries = load_ground_truth(file.path()).await.unwrap();
+
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].question, "q");
+}
@@ -10,6 +10,7 @@ fn make_event(question: &str) -> EvalEvent {
         vec![EvalSource {
             text: "Chunk text".to_string(),
             page: Some(1),
+            end_page: Some(1),
             score: 0.9,
         }],
         "lmstudio/llama3",
@@ -91,11 +92,13 @@ async fn given_eval_event_when_serialized_and_recorded_then_all_fields_preserved
             EvalSource {
                 text: "Source 1".to_string(),
                 page: Some(3),
+                end_page: Some(3),
                 score: 0.85,
             },
             EvalSource {
                 text: "Source 2".to_string(),
                 page: None,
+                end_page: None,
                 score: 0.72,
             },
         ],
@@ -1,2 +1,3 @@
+mod ground_truth_loader_test;
 mod jsonl_eval_event_repository_test;
 mod qdrant_adapter_test;
@@ -1,6 +1,12 @@
+use chrono::{TimeZone, Utc};
+use qdrant_client::qdrant::condition::ConditionOneOf;
+use qdrant_client::qdrant::r#match::MatchValue;
+
 use sandakan::application::ports::{
-    CollectionConfig, DistanceMetric, PayloadFieldType, PayloadIndex,
+    CollectionConfig, DistanceMetric, PayloadFieldType, PayloadIndex, SearchFilter,
 };
+use sandakan::domain::{ContentType, DocumentId, TenantId};
+use sandakan::infrastructure::persistence::QdrantAdapter;
 
 #[test]
 fn given_new_config_when_created_with_dimensions_then_has_specified_dimensions() {
@@ -31,6 +37,116 @@ fn given_new_config_when_created_then_has_required_payload_indexes() {
     assert!(field_names.contains(&"tenant_id"));
 }
 
+#[test]
+fn given_new_config_when_created_then_indexes_filterable_metadata_fields() {
+    let config = CollectionConfig::new(384);
+
+    let index_type = |name: &str| {
+        config
+            .payload_indexes
+            .iter()
+            .find(|idx| idx.field_name == name)
+            .map(|idx| idx.field_type)
+    };
+
+    assert_eq!(index_type("content_type"), Some(PayloadFieldType::Keyword));
+    assert_eq!(index_type("title"), Some(PayloadFieldType::Keyword));
+    assert_eq!(index_type("tags"), Some(PayloadFieldType::Keyword));
+    assert_eq!(index_type("ingested_at"), Some(PayloadFieldType::Integer));
+}
+
+fn tenant_match(condition: &ConditionOneOf) -> Option<String> {
+    match condition {
+        ConditionOneOf::Field(field) if field.key == "tenant_id" => {
+            match field.r#match.as_ref()?.match_value.as_ref()? {
+                MatchValue::Keyword(tenant) => Some(tenant.clone()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn given_default_tenant_filter_when_building_qdrant_filter_then_untagged_legacy_chunks_also_match()
+{
+    let qdrant_filter = QdrantAdapter::build_filter(&SearchFilter::default());
+
+    assert_eq!(qdrant_filter.must.len(), 1);
+    let Some(ConditionOneOf::Filter(nested)) = &qdrant_filter.must[0].condition_one_of else {
+        panic!("expected nested tenant filter");
+    };
+    let alternatives: Vec<&ConditionOneOf> = nested
+        .should
+        .iter()
+        .filter_map(|c| c.condition_one_of.as_ref())
+        .collect();
+    assert_eq!(alternatives.len(), 2);
+    assert_eq!(tenant_match(alternatives[0]), Some("default".to_string()));
+    assert!(matches!(
+        alternatives[1],
+        ConditionOneOf::IsEmpty(is_empty) if is_empty.key == "tenant_id"
+    ));
+}
+
+#[test]
+fn given_named_tenant_filter_when_building_qdrant_filter_then_only_exact_tenant_matches() {
+    let tenant = TenantId::parse("acme").unwrap();
+
+    let qdrant_filter = QdrantAdapter::build_filter(&SearchFilter::for_tenant(tenant));
+
+    assert_eq!(qdrant_filter.must.len(), 1);
+    let condition = qdrant_filter.must[0].condition_one_of.as_ref().unwrap();
+    assert_eq!(tenant_match(condition), Some("acme".to_string()));
+}
+
+#[test]
+fn given_populated_search_filter_when_building_qdrant_filter_then_maps_each_field_to_must_condition()
+ {
+    let filter = SearchFilter {
+        tenant_id: TenantId::parse("acme").unwrap(),
+        document_ids: vec![DocumentId::new()],
+        content_types: vec![ContentType::Pdf],
+        titles: vec!["Lecture 1".to_string()],
+        ingested_after: Some(Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap()),
+        ingested_before: None,
+        tags: vec!["physics".to_string()],
+    };
+
+    let qdrant_filter = QdrantAdapter::build_filter(&filter);
+
+    let keys: Vec<String> = qdrant_filter
+        .must
+        .iter()
+        .filter_map(|c| match &c.condition_one_of {
+            Some(ConditionOneOf::Field(field)) => Some(field.key.clone()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            "tenant_id",
+            "document_id",
+            "content_type",
+            "title",
+            "tags",
+            "ingested_at"
+        ]
+    );
+
+    let range = qdrant_filter
+        .must
+        .iter()
+        .find_map(|c| match &c.condition_one_of {
+            Some(ConditionOneOf::Field(field)) if field.key == "ingested_at" => field.range,
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(range.gte, Some(1_735_689_600.0));
+    assert_eq!(range.lte, None);
+}
+
 #[test]
 fn given_payload_index_when_created_then_stores_field_name_and_type() {
     let index = PayloadIndex {
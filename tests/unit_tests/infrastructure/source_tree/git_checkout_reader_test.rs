@@ -0,0 +1,99 @@
+use std::fs;
+use std::path::Path;
+
+use sandakan::application::ports::{SourceTreeError, SourceTreeReader};
+use sandakan::infrastructure::source_tree::GitCheckoutReader;
+use tempfile::TempDir;
+
+fn write(root: &Path, path: &str, contents: &str) {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+fn checkout() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join(".git")).unwrap();
+    write(dir.path(), ".git/config", "[core]\n");
+    write(dir.path(), ".gitignore", "target/\n*.log\n");
+    write(dir.path(), "src/main.rs", "fn main() {}\n");
+    write(dir.path(), "src/lib/mod.rs", "pub mod lib;\n");
+    write(dir.path(), "target/debug/build.rs", "fn generated() {}\n");
+    write(dir.path(), "server.log", "noise\n");
+    write(dir.path(), ".env", "SECRET=1\n");
+    dir
+}
+
+#[tokio::test]
+async fn given_checkout_when_listing_then_ignored_hidden_and_git_files_are_skipped() {
+    let dir = checkout();
+    let reader = GitCheckoutReader::new();
+    let root = reader.resolve(dir.path()).await.unwrap();
+
+    let files = reader.list_files(&root).await.unwrap();
+
+    let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, vec!["src/lib/mod.rs", "src/main.rs"]);
+    assert_eq!(files[1].size_bytes, 13);
+}
+
+#[tokio::test]
+async fn given_nested_gitignore_when_listing_then_it_applies_to_its_directory() {
+    let dir = checkout();
+    write(dir.path(), "src/.gitignore", "lib/\n");
+    let reader = GitCheckoutReader::new();
+    let root = reader.resolve(dir.path()).await.unwrap();
+
+    let files = reader.list_files(&root).await.unwrap();
+
+    let paths: Vec<&str> = files.iter().map(|f| f.path.as_str()).collect();
+    assert_eq!(paths, vec!["src/main.rs"]);
+}
+
+#[tokio::test]
+async fn given_directory_without_git_when_resolving_then_returns_not_a_checkout() {
+    let dir = TempDir::new().unwrap();
+
+    let result = GitCheckoutReader::new().resolve(dir.path()).await;
+
+    assert!(matches!(result, Err(SourceTreeError::NotACheckout(_))));
+}
+
+#[tokio::test]
+async fn given_missing_directory_when_resolving_then_returns_not_found() {
+    let dir = TempDir::new().unwrap();
+
+    let result = GitCheckoutReader::new()
+        .resolve(&dir.path().join("missing"))
+        .await;
+
+    assert!(matches!(result, Err(SourceTreeError::NotFound(_))));
+}
+
+#[tokio::test]
+async fn given_file_in_checkout_when_reading_then_returns_its_contents() {
+    let dir = checkout();
+    let reader = GitCheckoutReader::new();
+    let root = reader.resolve(dir.path()).await.unwrap();
+
+    let bytes = reader.read(&root, "src/main.rs").await.unwrap();
+
+    assert_eq!(bytes, b"fn main() {}\n");
+}
+
+#[tokio::test]
+async fn given_path_escaping_checkout_when_reading_then_returns_outside_checkout() {
+    let outside = TempDir::new().unwrap();
+    write(outside.path(), "secret.txt", "secret\n");
+    let dir = checkout();
+    let reader = GitCheckoutReader::new();
+    let root = reader.resolve(dir.path()).await.unwrap();
+    let escape = format!(
+        "../{}/secret.txt",
+        outside.path().file_name().unwrap().to_str().unwrap()
+    );
+
+    let result = reader.read(&root, &escape).await;
+
+    assert!(matches!(result, Err(SourceTreeError::OutsideCheckout(_))));
+}
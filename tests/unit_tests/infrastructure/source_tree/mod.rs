@@ -0,0 +1 @@
+mod git_checkout_reader_test;
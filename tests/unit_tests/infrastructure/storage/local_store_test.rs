@@ -1,7 +1,7 @@
 use std::io;
 
 use bytes::Bytes;
-use futures::stream;
+use futures::{TryStreamExt, stream};
 
 use sandakan::application::ports::StagingStore;
 use sandakan::domain::{DocumentId, StoragePath};
@@ -40,6 +40,35 @@ async fn given_stored_file_when_fetching_then_bytes_match_original() {
     assert_eq!(fetched, content);
 }
 
+#[tokio::test]
+async fn given_stored_file_when_fetching_as_stream_then_chunks_concatenate_to_original() {
+    let (_dir, store) = create_test_store();
+    let doc_id = DocumentId::new();
+    let path = StoragePath::new(&doc_id, "long.mp3");
+
+    let content: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+    let byte_stream = Box::pin(stream::iter(vec![Ok(Bytes::from(content.clone()))]));
+    store.store(&path, byte_stream, None).await.unwrap();
+
+    let chunks: Vec<Bytes> = store
+        .fetch_stream(&path)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.concat(), content);
+}
+
+#[tokio::test]
+async fn given_missing_file_when_fetching_as_stream_then_returns_error() {
+    let (_dir, store) = create_test_store();
+    let path = StoragePath::new(&DocumentId::new(), "missing.mp3");
+
+    assert!(store.fetch_stream(&path).await.is_err());
+}
+
 #[tokio::test]
 async fn given_stored_file_when_deleting_then_fetch_returns_not_found() {
     let (_dir, store) = create_test_store();
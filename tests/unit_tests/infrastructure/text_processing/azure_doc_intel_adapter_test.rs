@@ -11,7 +11,7 @@ async fn given_non_pdf_content_type_when_extracting_then_returns_unsupported() {
     let adapter = AzureDocIntelAdapter::new("https://example.cognitiveservices.azure.com", "key");
     let document = make_document("audio.mp3", ContentType::Audio);
 
-    let result = adapter.extract_text(b"data", &document).await;
+    let result = adapter.extract_pages(b"data", &document).await;
 
     assert!(matches!(
         result,
@@ -1,45 +1,110 @@
 use sandakan::application::ports::SparseEmbedder;
-use sandakan::infrastructure::text_processing::Bm25SparseEmbedder;
+use sandakan::infrastructure::text_processing::{Bm25Params, Bm25SparseEmbedder};
+
+async fn embed_document(embedder: &Bm25SparseEmbedder, text: &str) -> (Vec<u32>, Vec<f32>) {
+    let mut results = embedder.embed_sparse_batch(&[text]).await.unwrap();
+    let embedding = results.remove(0);
+    (embedding.indices, embedding.values)
+}
+
+fn weight_of(indices: &[u32], values: &[f32], idx: u32) -> f32 {
+    let pos = indices.iter().position(|i| *i == idx).unwrap();
+    values[pos]
+}
 
 #[tokio::test]
-async fn given_normal_text_when_embedding_sparse_then_returns_non_empty_result() {
+async fn given_query_with_repeated_term_when_embedding_then_each_distinct_term_weighs_one() {
     let embedder = Bm25SparseEmbedder::new();
 
     let result = embedder
-        .embed_sparse("machine learning models")
+        .embed_sparse_query("rust rust programming")
         .await
         .unwrap();
 
-    assert!(!result.is_empty());
-    assert!(result.values.iter().all(|v| *v > 0.0 && *v <= 1.0));
+    assert_eq!(result.len(), 2);
+    assert!(
+        result
+            .values
+            .iter()
+            .all(|v| (*v - 1.0).abs() < f32::EPSILON)
+    );
 }
 
 #[tokio::test]
-async fn given_only_stop_words_when_embedding_sparse_then_returns_empty() {
+async fn given_only_stop_words_when_embedding_query_then_returns_empty() {
     let embedder = Bm25SparseEmbedder::new();
 
-    let result = embedder.embed_sparse("the is a an to for").await.unwrap();
+    let result = embedder
+        .embed_sparse_query("the is a an to for")
+        .await
+        .unwrap();
 
     assert!(result.is_empty());
 }
 
 #[tokio::test]
-async fn given_repeated_token_when_embedding_sparse_then_weight_increases() {
+async fn given_repeated_term_in_document_when_embedding_then_weight_increases_and_saturates() {
     let embedder = Bm25SparseEmbedder::new();
+    let params = Bm25Params::default();
 
-    let single = embedder.embed_sparse("rust").await.unwrap();
-    let repeated = embedder.embed_sparse("rust rust rust other").await.unwrap();
+    let (once_idx, once_val) = embed_document(&embedder, "rust other words here").await;
+    let (many_idx, many_val) =
+        embed_document(&embedder, "rust rust rust rust rust rust rust rust").await;
 
-    assert_eq!(single.len(), 1);
-    assert!((single.values[0] - 1.0).abs() < f32::EPSILON);
+    let rust_idx = embedder.embed_sparse_query("rust").await.unwrap().indices[0];
+    let once = weight_of(&once_idx, &once_val, rust_idx);
+    let many = weight_of(&many_idx, &many_val, rust_idx);
 
-    let rust_idx = single.indices[0];
-    let pos = repeated
-        .indices
-        .iter()
-        .position(|i| *i == rust_idx)
-        .unwrap();
-    assert!(repeated.values[pos] > 0.5);
+    assert!(many > once);
+    assert!(many < params.k1 + 1.0);
+}
+
+#[tokio::test]
+async fn given_same_term_frequency_when_document_is_longer_then_weight_is_lower() {
+    let embedder = Bm25SparseEmbedder::with_params(Bm25Params {
+        avg_doc_length: 4.0,
+        ..Bm25Params::default()
+    });
+    let rust_idx = embedder.embed_sparse_query("rust").await.unwrap().indices[0];
+
+    let (short_idx, short_val) = embed_document(&embedder, "rust compiler").await;
+    let (long_idx, long_val) = embed_document(
+        &embedder,
+        "rust compiler borrow checker lifetimes traits generics macros",
+    )
+    .await;
+
+    assert!(
+        weight_of(&short_idx, &short_val, rust_idx) > weight_of(&long_idx, &long_val, rust_idx)
+    );
+}
+
+#[tokio::test]
+async fn given_b_zero_when_embedding_documents_of_different_length_then_weights_match() {
+    let embedder = Bm25SparseEmbedder::with_params(Bm25Params {
+        b: 0.0,
+        ..Bm25Params::default()
+    });
+    let rust_idx = embedder.embed_sparse_query("rust").await.unwrap().indices[0];
+
+    let (short_idx, short_val) = embed_document(&embedder, "rust compiler").await;
+    let (long_idx, long_val) =
+        embed_document(&embedder, "rust compiler borrow checker lifetimes traits").await;
+
+    let short = weight_of(&short_idx, &short_val, rust_idx);
+    let long = weight_of(&long_idx, &long_val, rust_idx);
+    assert!((short - long).abs() < f32::EPSILON);
+}
+
+#[tokio::test]
+async fn given_query_and_document_with_same_term_when_embedding_then_indices_match() {
+    let embedder = Bm25SparseEmbedder::new();
+
+    let query = embedder.embed_sparse_query("E4021").await.unwrap();
+    let (doc_idx, _) = embed_document(&embedder, "Error E4021 means the disk is full").await;
+
+    assert_eq!(query.len(), 1);
+    assert!(doc_idx.contains(&query.indices[0]));
 }
 
 #[tokio::test]
@@ -51,28 +116,32 @@ async fn given_batch_input_when_embedding_sparse_batch_then_returns_matching_cou
 
     assert_eq!(results.len(), 3);
     assert!(results.iter().all(|r| !r.is_empty()));
+    assert!(
+        results
+            .iter()
+            .flat_map(|r| r.values.iter())
+            .all(|v| *v > 0.0)
+    );
 }
 
 #[tokio::test]
 async fn given_empty_text_when_embedding_sparse_then_returns_empty() {
     let embedder = Bm25SparseEmbedder::new();
 
-    let result = embedder.embed_sparse("").await.unwrap();
+    let query = embedder.embed_sparse_query("").await.unwrap();
+    let documents = embedder.embed_sparse_batch(&[""]).await.unwrap();
 
-    assert!(result.is_empty());
+    assert!(query.is_empty());
+    assert!(documents[0].is_empty());
 }
 
 #[tokio::test]
 async fn given_indices_when_embedding_sparse_then_indices_are_sorted() {
     let embedder = Bm25SparseEmbedder::new();
 
-    let result = embedder
-        .embed_sparse("alpha beta gamma delta epsilon zeta")
-        .await
-        .unwrap();
+    let (indices, _) = embed_document(&embedder, "alpha beta gamma delta epsilon zeta").await;
 
-    let sorted: Vec<u32> = result.indices.clone();
-    let mut check = sorted.clone();
+    let mut check = indices.clone();
     check.sort();
-    assert_eq!(sorted, check);
+    assert_eq!(indices, check);
 }
@@ -0,0 +1,233 @@
+use sandakan::application::ports::CodeSplitter;
+use sandakan::domain::{Chunk, DocumentId, SourceLanguage};
+use sandakan::infrastructure::text_processing::SyntacticCodeSplitter;
+
+const STANDARD_TOKEN_LIMIT: usize = 512;
+const TIGHT_TOKEN_LIMIT: usize = 60;
+
+const RUST_SOURCE: &str = r#"use std::fmt;
+
+/// A parsed token.
+#[derive(Debug)]
+pub struct Token {
+    kind: &'static str,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Braces in strings must not close the block: "}"
+        write!(f, "{}", self.kind)
+    }
+}
+
+pub fn tokenize(input: &str) -> Vec<Token> {
+    input
+        .split('{')
+        .map(|_| Token { kind: "word" })
+        .collect()
+}
+"#;
+
+async fn split(source: &str, path: &str, max_tokens: usize) -> Vec<Chunk> {
+    SyntacticCodeSplitter::new(max_tokens)
+        .unwrap()
+        .split_code(source, path, DocumentId::new(), None)
+        .await
+        .unwrap()
+}
+
+fn symbols(chunks: &[Chunk]) -> Vec<Option<String>> {
+    chunks
+        .iter()
+        .map(|c| c.code.as_ref().and_then(|code| code.symbol.clone()))
+        .collect()
+}
+
+fn chunk_for<'a>(chunks: &'a [Chunk], symbol: &str) -> &'a Chunk {
+    chunks
+        .iter()
+        .find(|c| c.code.as_ref().and_then(|code| code.symbol.as_deref()) == Some(symbol))
+        .unwrap_or_else(|| panic!("no chunk for {symbol}: {:?}", symbols(chunks)))
+}
+
+#[tokio::test]
+async fn given_rust_file_when_splitting_then_items_are_chunked_by_symbol() {
+    let chunks = split(RUST_SOURCE, "repo/src/token.rs", STANDARD_TOKEN_LIMIT).await;
+
+    // The struct and its impl share a symbol and fit together, so they form one chunk.
+    assert_eq!(
+        symbols(&chunks),
+        vec![
+            None,
+            Some("Token".to_string()),
+            Some("tokenize".to_string())
+        ]
+    );
+    assert!(chunks[1].text.contains("impl fmt::Display for Token"));
+}
+
+#[tokio::test]
+async fn given_rust_file_when_splitting_then_line_range_and_language_are_recorded() {
+    let chunks = split(RUST_SOURCE, "repo/src/token.rs", STANDARD_TOKEN_LIMIT).await;
+
+    let tokenize = chunk_for(&chunks, "tokenize").code.clone().unwrap();
+    assert_eq!(tokenize.path, "repo/src/token.rs");
+    assert_eq!(tokenize.language, SourceLanguage::Rust);
+    assert_eq!((tokenize.start_line, tokenize.end_line), (16, 21));
+}
+
+#[tokio::test]
+async fn given_doc_comment_and_attribute_when_splitting_then_they_stay_with_the_item() {
+    let chunks = split(RUST_SOURCE, "repo/src/token.rs", STANDARD_TOKEN_LIMIT).await;
+
+    let token = &chunks[1];
+    assert!(
+        token
+            .text
+            .starts_with("/// A parsed token.\n#[derive(Debug)]")
+    );
+    assert_eq!(token.code.as_ref().unwrap().start_line, 3);
+}
+
+#[tokio::test]
+async fn given_chunks_when_splitting_then_offsets_point_at_the_chunk_text() {
+    let chunks = split(RUST_SOURCE, "repo/src/token.rs", STANDARD_TOKEN_LIMIT).await;
+
+    for chunk in &chunks {
+        assert_eq!(
+            &RUST_SOURCE[chunk.offset..chunk.offset + chunk.text.len()],
+            chunk.text
+        );
+    }
+}
+
+#[tokio::test]
+async fn given_oversized_impl_when_splitting_then_methods_become_qualified_chunks() {
+    let methods: String = (0..6)
+        .map(|i| {
+            format!(
+                "    pub fn method_{i}(&self, value: u32) -> u32 {{\n        let doubled = value * 2;\n        doubled + {i}\n    }}\n\n"
+            )
+        })
+        .collect();
+    let source = format!("impl Calculator {{\n{methods}}}\n");
+
+    let chunks = split(&source, "repo/src/calc.rs", TIGHT_TOKEN_LIMIT).await;
+
+    let method = chunk_for(&chunks, "Calculator::method_3");
+    assert!(method.text.contains("doubled + 3"));
+    assert!(!method.text.contains("method_2"));
+    assert!(chunks.iter().all(|c| c.text.trim() != "}"));
+}
+
+#[tokio::test]
+async fn given_python_class_when_splitting_then_decorators_and_methods_are_kept() {
+    let source = r#"import os
+
+
+@dataclass
+class Config:
+    """Settings loaded from the environment.
+
+    def not_a_method(self):
+    """
+
+    path: str
+
+    def load(self):
+        return os.environ.get(self.path)
+
+
+def main():
+    print(Config("x").load())
+"#;
+
+    let chunks = split(source, "repo/app.py", STANDARD_TOKEN_LIMIT).await;
+
+    assert_eq!(
+        symbols(&chunks),
+        vec![None, Some("Config".to_string()), Some("main".to_string())]
+    );
+    let config = chunk_for(&chunks, "Config");
+    assert!(config.text.starts_with("@dataclass"));
+    assert!(
+        config
+            .text
+            .trim_end()
+            .ends_with("os.environ.get(self.path)")
+    );
+}
+
+#[tokio::test]
+async fn given_typescript_module_when_splitting_then_functions_and_classes_are_found() {
+    let source = r#"import { z } from "zod";
+
+export interface User {
+  id: string;
+}
+
+export const parseUser = (raw: unknown): User => {
+  return z.object({ id: z.string() }).parse(raw);
+};
+
+export class UserService {
+  async find(id: string): Promise<User> {
+    return { id };
+  }
+}
+"#;
+
+    let chunks = split(source, "repo/src/user.ts", STANDARD_TOKEN_LIMIT).await;
+
+    assert_eq!(
+        symbols(&chunks),
+        vec![
+            None,
+            Some("User".to_string()),
+            Some("parseUser".to_string()),
+            Some("UserService".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn given_go_method_when_splitting_then_symbol_is_qualified_with_receiver() {
+    let source = "package server\n\ntype Server struct {\n\taddr string\n}\n\nfunc (s *Server) Start() error {\n\treturn nil\n}\n";
+
+    let chunks = split(source, "repo/server.go", STANDARD_TOKEN_LIMIT).await;
+
+    assert!(symbols(&chunks).contains(&Some("Server.Start".to_string())));
+}
+
+#[tokio::test]
+async fn given_oversized_function_when_splitting_then_windows_keep_the_symbol_and_every_line() {
+    let body: String = (0..40)
+        .map(|i| format!("    let value_{i} = compute({i});\n"))
+        .collect();
+    let source = format!("fn long() {{\n{body}}}\n");
+
+    let chunks = split(&source, "repo/src/long.rs", TIGHT_TOKEN_LIMIT).await;
+
+    assert!(chunks.len() > 1);
+    assert!(
+        symbols(&chunks)
+            .iter()
+            .all(|s| s.as_deref() == Some("long"))
+    );
+    let spans: Vec<_> = chunks.iter().map(|c| c.code.clone().unwrap()).collect();
+    assert_eq!(spans.first().unwrap().start_line, 1);
+    assert_eq!(spans.last().unwrap().end_line, 42);
+    for pair in spans.windows(2) {
+        assert_eq!(pair[1].start_line, pair[0].end_line + 1);
+    }
+}
+
+#[tokio::test]
+async fn given_unsupported_extension_when_splitting_then_returns_error() {
+    let result = SyntacticCodeSplitter::new(STANDARD_TOKEN_LIMIT)
+        .unwrap()
+        .split_code("hello", "repo/notes.txt", DocumentId::new(), None)
+        .await;
+
+    assert!(result.is_err());
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use sandakan::application::ports::{FileLoader, FileLoaderError};
-use sandakan::domain::{ContentType, Document};
+use sandakan::domain::{ContentType, Document, PageSegment};
 use sandakan::infrastructure::text_processing::{
     CompositeFileLoader, MockFileLoader, PlainTextAdapter,
 };
@@ -22,7 +22,7 @@ async fn given_pdf_document_when_loading_then_delegates_to_registered_adapter()
         data.len() as u64,
     );
 
-    let result = loader.extract_text(data, &document).await;
+    let result = loader.extract_pages(data, &document).await;
 
     assert!(result.is_ok());
 }
@@ -43,10 +43,13 @@ async fn given_text_document_when_loading_then_delegates_to_text_adapter() {
         text_bytes.len() as u64,
     );
 
-    let result = loader.extract_text(text_bytes, &document).await;
+    let result = loader.extract_pages(text_bytes, &document).await;
 
     assert!(result.is_ok());
-    assert_eq!(result.unwrap(), "Hello plain text");
+    assert_eq!(
+        result.unwrap(),
+        vec![PageSegment::unpaged("Hello plain text")]
+    );
 }
 
 #[tokio::test]
@@ -61,7 +64,7 @@ async fn given_unregistered_content_type_when_loading_then_returns_unsupported()
         data.len() as u64,
     );
 
-    let result = loader.extract_text(data, &document).await;
+    let result = loader.extract_pages(data, &document).await;
 
     assert!(matches!(
         result,
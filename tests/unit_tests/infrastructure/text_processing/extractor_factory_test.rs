@@ -1,6 +1,6 @@
 use sandakan::infrastructure::text_processing::{ExtractorFactory, ExtractorFactoryError};
 use sandakan::presentation::ExtractorProvider;
-use sandakan::presentation::config::PdfExtractionSettings;
+use sandakan::presentation::config::{PdfExtractionSettings, PdfFallbackProvider};
 
 fn azure_settings(endpoint: Option<&str>, key: Option<&str>) -> PdfExtractionSettings {
     PdfExtractionSettings {
@@ -13,6 +13,8 @@ fn azure_settings(endpoint: Option<&str>, key: Option<&str>) -> PdfExtractionSet
         vlm_api_key: None,
         azure_endpoint: endpoint.map(str::to_string),
         azure_key: key.map(str::to_string),
+        fallback_provider: PdfFallbackProvider::None,
+        min_page_chars: 50,
     }
 }
 
@@ -27,6 +29,8 @@ fn lm_studio_settings(base_url: Option<&str>, model: Option<&str>) -> PdfExtract
         vlm_api_key: Some("lm-studio".to_string()),
         azure_endpoint: None,
         azure_key: None,
+        fallback_provider: PdfFallbackProvider::None,
+        min_page_chars: 50,
     }
 }
 
@@ -101,3 +105,42 @@ async fn given_lm_studio_provider_with_valid_config_when_creating_then_returns_o
 
     assert!(result.is_ok());
 }
+
+fn hybrid_settings(fallback: PdfFallbackProvider, base_url: Option<&str>) -> PdfExtractionSettings {
+    PdfExtractionSettings {
+        provider: ExtractorProvider::Hybrid,
+        fallback_provider: fallback,
+        ..lm_studio_settings(base_url, Some("xtuner/llava-phi-3-mini-gguf"))
+    }
+}
+
+#[tokio::test]
+async fn given_hybrid_provider_without_fallback_when_creating_then_returns_ok() {
+    let settings = hybrid_settings(PdfFallbackProvider::None, None);
+
+    let result = ExtractorFactory::create(&settings);
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn given_hybrid_provider_with_lm_studio_fallback_when_creating_then_returns_ok() {
+    let settings = hybrid_settings(PdfFallbackProvider::LmStudio, Some("http://localhost:1234"));
+
+    let result = ExtractorFactory::create(&settings);
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn given_hybrid_provider_with_unconfigured_lm_studio_fallback_when_creating_then_returns_missing_url_error()
+ {
+    let settings = hybrid_settings(PdfFallbackProvider::LmStudio, None);
+
+    let result = ExtractorFactory::create(&settings);
+
+    assert!(matches!(
+        result,
+        Err(ExtractorFactoryError::MissingVlmBaseUrl)
+    ));
+}
@@ -0,0 +1,131 @@
+use sandakan::application::ports::{FileLoader, FileLoaderError, TextSplitter};
+use sandakan::domain::{ContentType, Document, DocumentId, PageSegment};
+use sandakan::infrastructure::text_processing::{HtmlAdapter, MarkdownSemanticSplitter};
+
+async fn convert(html: &str) -> Result<Vec<PageSegment>, FileLoaderError> {
+    let document = Document::new(
+        "page.html".to_string(),
+        ContentType::Html,
+        html.len() as u64,
+    );
+    HtmlAdapter.extract_pages(html.as_bytes(), &document).await
+}
+
+const ARTICLE_PAGE: &str = r##"<!doctype html>
+<html>
+<head>
+  <title>Deploy guide</title>
+  <base href="https://docs.example.com/guides/">
+  <script>trackVisit();</script>
+</head>
+<body>
+  <header><a href="/">Home</a></header>
+  <nav><ul><li><a href="/pricing">Pricing</a></li></ul></nav>
+  <div class="cookie-banner">We use cookies.</div>
+  <main>
+    <h1>Deploying</h1>
+    <p>Read the <a href="setup.html">setup notes</a> and the
+       <a href="#faq">FAQ</a> first.</p>
+    <h2>Steps</h2>
+    <ol>
+      <li>Build the image
+        <ul><li>Tag it with <code>release</code></li></ul>
+      </li>
+      <li>Push it</li>
+    </ol>
+    <pre><code class="language-bash">docker build .
+docker push</code></pre>
+    <table>
+      <thead><tr><th>Env</th><th>Region</th></tr></thead>
+      <tbody><tr><td>prod</td><td>eu-west</td></tr></tbody>
+    </table>
+    <aside>Related posts</aside>
+  </main>
+  <footer>© Example</footer>
+</body>
+</html>"##;
+
+#[tokio::test]
+async fn given_page_with_main_when_extracting_then_content_becomes_markdown_without_boilerplate() {
+    let pages = convert(ARTICLE_PAGE).await.unwrap();
+
+    assert_eq!(
+        pages,
+        vec![PageSegment::unpaged(
+            "# Deploying\n\n\
+             Read the [setup notes](https://docs.example.com/guides/setup.html) and the FAQ first.\n\n\
+             ## Steps\n\n\
+             1. Build the image\n\
+             \x20 - Tag it with `release`\n\
+             1. Push it\n\n\
+             ```bash\ndocker build .\ndocker push\n```\n\n\
+             | Env | Region |\n\
+             | --- | --- |\n\
+             | prod | eu-west |"
+        )]
+    );
+}
+
+#[tokio::test]
+async fn given_page_without_main_when_extracting_then_body_is_used_without_header_and_footer() {
+    let html = r#"<html><head><title>Release notes</title></head><body>
+        <header>Site name</header>
+        <div id="main-menu"><a href="/a">A</a></div>
+        <h2>Version 2</h2><p>Faster   search.</p>
+        <div hidden>Draft</div>
+        <footer>Imprint</footer>
+    </body></html>"#;
+
+    let pages = convert(html).await.unwrap();
+
+    assert_eq!(
+        pages[0].text,
+        "# Release notes\n\n## Version 2\n\nFaster search."
+    );
+}
+
+#[tokio::test]
+async fn given_page_with_only_boilerplate_when_extracting_then_no_text_found() {
+    let html = "<html><body><nav>Menu</nav><script>x()</script></body></html>";
+
+    let result = convert(html).await;
+
+    assert!(matches!(result, Err(FileLoaderError::NoTextFound(_))));
+}
+
+#[tokio::test]
+async fn given_converted_page_when_splitting_then_chunks_carry_link_targets_and_plain_labels() {
+    let pages = convert(ARTICLE_PAGE).await.unwrap();
+    let splitter = MarkdownSemanticSplitter::new(512, 50).unwrap();
+
+    let chunks = splitter
+        .split_pages(&pages, DocumentId::new(), None)
+        .await
+        .unwrap();
+
+    let intro = chunks
+        .iter()
+        .find(|c| c.text.contains("setup notes"))
+        .unwrap();
+    assert!(
+        intro
+            .text
+            .contains("Read the setup notes and the FAQ first.")
+    );
+    assert_eq!(
+        intro.links,
+        vec!["https://docs.example.com/guides/setup.html".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn given_non_html_content_type_when_extracting_then_returns_unsupported() {
+    let document = Document::new("notes.md".to_string(), ContentType::Markdown, 3);
+
+    let result = HtmlAdapter.extract_pages(b"# x", &document).await;
+
+    assert!(matches!(
+        result,
+        Err(FileLoaderError::UnsupportedContentType(_))
+    ));
+}
@@ -1,6 +1,6 @@
 // @AI-BYPASS-LENGTH
 use sandakan::application::ports::TextSplitter;
-use sandakan::domain::{DocumentId, TranscriptSegment};
+use sandakan::domain::{DocumentId, PageSegment, TranscriptSegment};
 use sandakan::infrastructure::text_processing::{MarkdownSemanticSplitter, TextSplitterFactory};
 use sandakan::presentation::config::ChunkingStrategy;
 
@@ -181,11 +181,13 @@ async fn given_transcript_segments_when_markdown_splitter_splits_then_delegates_
             text: "Hello world.".to_string(),
             start_time: 0.0,
             end_time: 1.0,
+            words: vec![],
         },
         TranscriptSegment {
             text: "This is a test.".to_string(),
             start_time: 1.0,
             end_time: 2.0,
+            words: vec![],
         },
     ];
     let doc_id = DocumentId::new();
@@ -500,3 +502,106 @@ async fn given_mixed_empty_and_content_sections_when_splitting_then_only_content
         &chunks[1].text[..chunks[1].text.len().min(60)]
     );
 }
+
+#[tokio::test]
+async fn given_pages_when_splitting_pages_then_each_chunk_records_its_page() {
+    let splitter = MarkdownSemanticSplitter::new(STANDARD_TOKEN_LIMIT, STANDARD_OVERLAP).unwrap();
+    let pages = vec![
+        PageSegment::new(1, "# Pricing\n\nThe base plan costs ten euros."),
+        PageSegment::new(2, "# Support\n\nSupport is available on weekdays."),
+    ];
+
+    let chunks = splitter
+        .split_pages(&pages, DocumentId::new(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.len(), 2);
+    assert_eq!((chunks[0].page, chunks[0].end_page), (Some(1), Some(1)));
+    assert_eq!((chunks[1].page, chunks[1].end_page), (Some(2), Some(2)));
+}
+
+#[tokio::test]
+async fn given_section_continuing_on_next_page_when_splitting_pages_then_chunk_records_both_pages()
+{
+    let splitter = MarkdownSemanticSplitter::new(STANDARD_TOKEN_LIMIT, STANDARD_OVERLAP).unwrap();
+    let pages = vec![
+        PageSegment::new(4, "# Warranty\n\nThe warranty lasts two years."),
+        PageSegment::new(5, "It does not cover water damage."),
+    ];
+
+    let chunks = splitter
+        .split_pages(&pages, DocumentId::new(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.len(), 1);
+    assert!(chunks[0].text.contains("water damage"));
+    assert_eq!((chunks[0].page, chunks[0].end_page), (Some(4), Some(5)));
+}
+
+#[tokio::test]
+async fn given_long_section_across_pages_when_splitting_then_continuation_chunk_has_only_its_pages()
+{
+    let splitter = MarkdownSemanticSplitter::new(TIGHT_TOKEN_LIMIT, TIGHT_OVERLAP).unwrap();
+    let paragraph = "This paragraph has enough words to fill most of a tight chunk budget on its own, \
+         so the same paragraph repeated on the following page cannot share that chunk.";
+    let pages = vec![
+        PageSegment::new(1, format!("# Manual\n\n{paragraph}")),
+        PageSegment::new(2, paragraph),
+    ];
+
+    let chunks = splitter
+        .split_pages(&pages, DocumentId::new(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.len(), 2);
+    assert!(chunks[1].text.starts_with("# Manual"));
+    assert_eq!((chunks[0].page, chunks[0].end_page), (Some(1), Some(1)));
+    assert_eq!((chunks[1].page, chunks[1].end_page), (Some(2), Some(2)));
+}
+
+#[tokio::test]
+async fn given_plain_text_when_splitting_then_chunks_have_no_page() {
+    let splitter = MarkdownSemanticSplitter::new(STANDARD_TOKEN_LIMIT, STANDARD_OVERLAP).unwrap();
+
+    let chunks = splitter
+        .split("# Notes\n\nNo pages here.", DocumentId::new(), None)
+        .await
+        .unwrap();
+
+    assert_eq!((chunks[0].page, chunks[0].end_page), (None, None));
+}
+
+#[tokio::test]
+async fn given_markdown_links_when_splitting_then_labels_kept_and_targets_recorded() {
+    let splitter = MarkdownSemanticSplitter::new(STANDARD_TOKEN_LIMIT, STANDARD_OVERLAP).unwrap();
+    let text = "# Links\n\nSee [the docs](https://example.com/docs \"Docs\"), \
+                [again](https://example.com/docs), [the FAQ](#faq) and \
+                ![a diagram](https://example.com/diagram.png).";
+
+    let chunks = splitter.split(text, DocumentId::new(), None).await.unwrap();
+
+    assert_eq!(
+        chunks[0].text,
+        "# Links\nSee the docs, again, the FAQ and a diagram."
+    );
+    assert_eq!(
+        chunks[0].links,
+        vec!["https://example.com/docs".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn given_link_syntax_in_code_when_splitting_then_code_left_untouched() {
+    let splitter = MarkdownSemanticSplitter::new(STANDARD_TOKEN_LIMIT, STANDARD_OVERLAP).unwrap();
+    let text = "Use `[x](y)` inline.\n\n```md\n[label](https://example.com)\n```";
+
+    let chunks = splitter.split(text, DocumentId::new(), None).await.unwrap();
+
+    let combined: String = chunks.iter().map(|c| c.text.as_str()).collect();
+    assert!(combined.contains("`[x](y)`"));
+    assert!(combined.contains("[label](https://example.com)"));
+    assert!(chunks.iter().all(|c| c.links.is_empty()));
+}
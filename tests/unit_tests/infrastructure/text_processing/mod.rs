@@ -1,10 +1,15 @@
 mod azure_doc_intel_adapter_test;
 mod bm25_sparse_embedder_test;
+mod code_splitter_test;
 mod composite_file_loader_test;
 mod extractor_factory_test;
+mod html_adapter_test;
 mod lm_studio_vlm_pdf_adapter_test;
 mod local_vlm_pdf_adapters_test;
 mod markdown_splitter_test;
+mod ooxml_adapters_test;
+mod pdf_text_layer_test;
 mod plain_text_adapter_test;
+mod text_analyzer_test;
 mod text_sanitizer_test;
 mod text_splitter;
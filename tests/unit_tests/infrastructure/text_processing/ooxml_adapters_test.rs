@@ -0,0 +1,431 @@
+use std::io::{Cursor, Write};
+
+use sandakan::application::ports::{FileLoader, FileLoaderError, TextSplitter};
+use sandakan::domain::{ContentType, Document, DocumentId, PageSegment};
+use sandakan::infrastructure::text_processing::{
+    DocxAdapter, MarkdownSemanticSplitter, PptxAdapter, XlsxAdapter,
+};
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+const W: &str = r#"xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main""#;
+const PRESENTATION_NS: &str = r#"xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships""#;
+const SHEET_NS: &str = r#"xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships""#;
+const REL_NS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+
+fn package(parts: &[(&str, String)]) -> Vec<u8> {
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    for (name, xml) in parts {
+        writer
+            .start_file(*name, SimpleFileOptions::default())
+            .unwrap();
+        writer.write_all(xml.as_bytes()).unwrap();
+    }
+    writer.finish().unwrap().into_inner()
+}
+
+fn relationships(entries: &[(&str, &str, &str)]) -> String {
+    let body: String = entries
+        .iter()
+        .map(|(id, kind, target)| {
+            format!(r#"<Relationship Id="{id}" Type="{REL_NS}/{kind}" Target="{target}"/>"#)
+        })
+        .collect();
+    format!(
+        r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">{body}</Relationships>"#
+    )
+}
+
+fn document(filename: &str, content_type: ContentType, data: &[u8]) -> Document {
+    Document::new(filename.to_string(), content_type, data.len() as u64)
+}
+
+fn word_paragraph(properties: &str, text: &str) -> String {
+    format!(
+        r#"<w:p><w:pPr>{properties}</w:pPr><w:r><w:t xml:space="preserve">{text}</w:t></w:r></w:p>"#
+    )
+}
+
+fn word_cell(text: &str) -> String {
+    format!("<w:tc>{}</w:tc>", word_paragraph("", text))
+}
+
+fn sample_docx() -> Vec<u8> {
+    let styles = format!(
+        r#"<w:styles {W}>
+            <w:style w:type="paragraph" w:styleId="Heading1"><w:name w:val="heading 1"/></w:style>
+            <w:style w:type="paragraph" w:styleId="berschrift2"><w:name w:val="heading 2"/></w:style>
+        </w:styles>"#
+    );
+    let numbering = format!(
+        r#"<w:numbering {W}>
+            <w:abstractNum w:abstractNumId="0"><w:lvl w:ilvl="0"><w:numFmt w:val="bullet"/></w:lvl></w:abstractNum>
+            <w:abstractNum w:abstractNumId="1"><w:lvl w:ilvl="0"><w:numFmt w:val="decimal"/></w:lvl></w:abstractNum>
+            <w:num w:numId="1"><w:abstractNumId w:val="0"/></w:num>
+            <w:num w:numId="2"><w:abstractNumId w:val="1"/></w:num>
+        </w:numbering>"#
+    );
+    let bullet = r#"<w:numPr><w:ilvl w:val="0"/><w:numId w:val="1"/></w:numPr>"#;
+    let ordered = r#"<w:numPr><w:ilvl w:val="0"/><w:numId w:val="2"/></w:numPr>"#;
+    let body = [
+        word_paragraph(r#"<w:pStyle w:val="Heading1"/>"#, "Onboarding"),
+        word_paragraph("", "Welcome to the team &amp; the handbook."),
+        word_paragraph(r#"<w:pStyle w:val="berschrift2"/>"#, "Checklist"),
+        word_paragraph(bullet, "Laptop"),
+        word_paragraph(bullet, "Badge"),
+        word_paragraph(ordered, "Sign contract"),
+        format!(
+            "<w:tbl><w:tr>{}{}</w:tr><w:tr>{}{}</w:tr></w:tbl>",
+            word_cell("Team"),
+            word_cell("Lead"),
+            word_cell("Search"),
+            word_cell("Ana | Bo"),
+        ),
+    ]
+    .concat();
+    package(&[
+        ("word/styles.xml", styles),
+        ("word/numbering.xml", numbering),
+        (
+            "word/document.xml",
+            format!("<w:document {W}><w:body>{body}</w:body></w:document>"),
+        ),
+    ])
+}
+
+fn text_shape(placeholder: Option<&str>, paragraphs: &[&str]) -> String {
+    let ph = placeholder
+        .map(|t| format!(r#"<p:ph type="{t}"/>"#))
+        .unwrap_or_default();
+    let body: String = paragraphs
+        .iter()
+        .map(|p| format!("<a:p><a:r><a:t>{p}</a:t></a:r></a:p>"))
+        .collect();
+    format!(
+        "<p:sp><p:nvSpPr><p:cNvPr id=\"1\" name=\"s\"/><p:cNvSpPr/><p:nvPr>{ph}</p:nvPr></p:nvSpPr><p:txBody>{body}</p:txBody></p:sp>"
+    )
+}
+
+fn slide(shapes: &[String]) -> String {
+    format!(
+        "<p:sld {PRESENTATION_NS}><p:cSld><p:spTree>{}</p:spTree></p:cSld></p:sld>",
+        shapes.concat()
+    )
+}
+
+fn sample_pptx() -> Vec<u8> {
+    // Slide parts are deliberately numbered against presentation order.
+    let presentation = format!(
+        r#"<p:presentation {PRESENTATION_NS}><p:sldIdLst><p:sldId id="256" r:id="rId7"/><p:sldId id="257" r:id="rId3"/></p:sldIdLst></p:presentation>"#
+    );
+    let table = "<p:graphicFrame><a:graphic><a:graphicData><a:tbl>\
+        <a:tr><a:tc><a:txBody><a:p><a:r><a:t>Quarter</a:t></a:r></a:p></a:txBody></a:tc><a:tc><a:txBody><a:p><a:r><a:t>Revenue</a:t></a:r></a:p></a:txBody></a:tc></a:tr>\
+        <a:tr><a:tc><a:txBody><a:p><a:r><a:t>Q1</a:t></a:r></a:p></a:txBody></a:tc><a:tc><a:txBody><a:p><a:r><a:t>1.2M</a:t></a:r></a:p></a:txBody></a:tc></a:tr>\
+        </a:tbl></a:graphicData></a:graphic></p:graphicFrame>"
+        .to_string();
+    let notes = format!(
+        "<p:notes {PRESENTATION_NS}><p:cSld><p:spTree>{}{}</p:spTree></p:cSld></p:notes>",
+        text_shape(Some("sldImg"), &[]),
+        text_shape(Some("body"), &["Mention the hiring freeze."]),
+    );
+    package(&[
+        ("ppt/presentation.xml", presentation),
+        (
+            "ppt/_rels/presentation.xml.rels",
+            relationships(&[
+                ("rId7", "slide", "slides/slide2.xml"),
+                ("rId3", "slide", "slides/slide1.xml"),
+            ]),
+        ),
+        (
+            "ppt/slides/slide2.xml",
+            slide(&[
+                text_shape(Some("ctrTitle"), &["Roadmap 2025"]),
+                text_shape(None, &["Ship search", "Retire legacy API"]),
+                text_shape(Some("sldNum"), &["1"]),
+            ]),
+        ),
+        (
+            "ppt/slides/slide1.xml",
+            slide(&[text_shape(Some("title"), &["Budget"]), table]),
+        ),
+        (
+            "ppt/slides/_rels/slide1.xml.rels",
+            relationships(&[("rId2", "notesSlide", "../notesSlides/notesSlide1.xml")]),
+        ),
+        ("ppt/notesSlides/notesSlide1.xml", notes),
+    ])
+}
+
+fn sample_xlsx(data_rows: usize) -> Vec<u8> {
+    let workbook = format!(
+        r#"<workbook {SHEET_NS}><sheets><sheet name="Summary" sheetId="1" r:id="rId1"/><sheet name="Empty" sheetId="2" r:id="rId2"/><sheet name="Staff" sheetId="3" r:id="rId3"/></sheets></workbook>"#
+    );
+    let shared = format!(
+        r#"<sst {SHEET_NS}><si><t>Region</t></si><si><t>Total</t></si><si><r><t>North</t></r><r><t>-East</t></r></si></sst>"#
+    );
+    let summary = format!(
+        r#"<worksheet {SHEET_NS}><sheetData>
+            <row r="2"><c r="B2" t="s"><v>0</v></c><c r="C2" t="s"><v>1</v></c></row>
+            <row r="3"><c r="B3" t="s"><v>2</v></c><c r="C3"><f>SUM(D3:D9)</f><v>42.5</v></c><c r="D3" t="b"><v>1</v></c></row>
+        </sheetData></worksheet>"#
+    );
+    let staff_rows: String = (0..data_rows)
+        .map(|i| {
+            let r = i + 2;
+            format!(
+                r#"<row r="{r}"><c r="A{r}" t="inlineStr"><is><t>Person {i}</t></is></c></row>"#
+            )
+        })
+        .collect();
+    let staff = format!(
+        r#"<worksheet {SHEET_NS}><sheetData><row r="1"><c r="A1" t="inlineStr"><is><t>Name</t></is></c></row>{staff_rows}</sheetData></worksheet>"#
+    );
+    package(&[
+        ("xl/workbook.xml", workbook),
+        (
+            "xl/_rels/workbook.xml.rels",
+            relationships(&[
+                ("rId1", "worksheet", "worksheets/sheet1.xml"),
+                ("rId2", "worksheet", "worksheets/sheet2.xml"),
+                ("rId3", "worksheet", "/xl/worksheets/sheet3.xml"),
+            ]),
+        ),
+        ("xl/sharedStrings.xml", shared),
+        ("xl/worksheets/sheet1.xml", summary),
+        (
+            "xl/worksheets/sheet2.xml",
+            format!("<worksheet {SHEET_NS}><sheetData/></worksheet>"),
+        ),
+        ("xl/worksheets/sheet3.xml", staff),
+    ])
+}
+
+fn single_sheet_xlsx(sheet_data: &str) -> Vec<u8> {
+    package(&[
+        (
+            "xl/workbook.xml",
+            format!(
+                r#"<workbook {SHEET_NS}><sheets><sheet name="Data" sheetId="1" r:id="rId1"/></sheets></workbook>"#
+            ),
+        ),
+        (
+            "xl/_rels/workbook.xml.rels",
+            relationships(&[("rId1", "worksheet", "worksheets/sheet1.xml")]),
+        ),
+        (
+            "xl/worksheets/sheet1.xml",
+            format!("<worksheet {SHEET_NS}><sheetData>{sheet_data}</sheetData></worksheet>"),
+        ),
+    ])
+}
+
+fn inline_cell(reference: &str, text: &str) -> String {
+    format!(r#"<c r="{reference}" t="inlineStr"><is><t>{text}</t></is></c>"#)
+}
+
+#[tokio::test]
+async fn given_docx_when_extracting_then_headings_lists_and_tables_become_markdown() {
+    let data = sample_docx();
+
+    let pages = DocxAdapter
+        .extract_pages(&data, &document("handbook.docx", ContentType::Docx, &data))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        pages,
+        vec![PageSegment::unpaged(
+            "# Onboarding\n\n\
+             Welcome to the team & the handbook.\n\n\
+             ## Checklist\n\n\
+             - Laptop\n\
+             - Badge\n\
+             1. Sign contract\n\n\
+             | Team | Lead |\n\
+             | --- | --- |\n\
+             | Search | Ana \\| Bo |"
+        )]
+    );
+}
+
+#[tokio::test]
+async fn given_pptx_when_extracting_then_each_slide_is_a_section_with_notes_in_deck_order() {
+    let data = sample_pptx();
+
+    let pages = PptxAdapter
+        .extract_pages(&data, &document("deck.pptx", ContentType::Pptx, &data))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        pages,
+        vec![
+            PageSegment::new(
+                1,
+                "# Slide 1: Roadmap 2025\n\nShip search\nRetire legacy API"
+            ),
+            PageSegment::new(
+                2,
+                "# Slide 2: Budget\n\n\
+                 | Quarter | Revenue |\n\
+                 | --- | --- |\n\
+                 | Q1 | 1.2M |\n\n\
+                 ## Speaker notes\n\n\
+                 Mention the hiring freeze."
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn given_xlsx_when_extracting_then_non_empty_sheets_become_tables_numbered_by_position() {
+    let data = sample_xlsx(2);
+
+    let pages = XlsxAdapter
+        .extract_pages(&data, &document("report.xlsx", ContentType::Xlsx, &data))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        pages,
+        vec![
+            PageSegment::new(
+                1,
+                "# Sheet 1: Summary\n\n\
+                 | Region | Total |  |\n\
+                 | --- | --- | --- |\n\
+                 | North-East | 42.5 | TRUE |"
+            ),
+            PageSegment::new(
+                3,
+                "# Sheet 3: Staff\n\n\
+                 | Name |\n\
+                 | --- |\n\
+                 | Person 0 |\n\
+                 | Person 1 |"
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn given_long_sheet_when_extracting_then_header_row_repeats_in_each_table() {
+    let data = sample_xlsx(120);
+
+    let pages = XlsxAdapter
+        .extract_pages(&data, &document("report.xlsx", ContentType::Xlsx, &data))
+        .await
+        .unwrap();
+
+    let staff = &pages[1].text;
+    assert_eq!(staff.matches("| Name |").count(), 3);
+    assert!(staff.contains("| Person 119 |"));
+}
+
+#[tokio::test]
+async fn given_cell_in_last_excel_column_when_extracting_then_it_is_placed_there() {
+    let data = single_sheet_xlsx(&format!(
+        r#"<row r="1">{}{}</row>"#,
+        inline_cell("A1", "First"),
+        inline_cell("XFD1", "Last")
+    ));
+
+    let pages = XlsxAdapter
+        .extract_pages(&data, &document("wide.xlsx", ContentType::Xlsx, &data))
+        .await
+        .unwrap();
+
+    assert!(pages[0].text.contains("| First |"));
+    assert!(pages[0].text.contains("| Last |"));
+}
+
+#[tokio::test]
+async fn given_cell_reference_past_last_excel_column_when_extracting_then_extraction_fails() {
+    for reference in ["XFE1", "ZZZZZZ1", "ZZZZZZZZZZZZZZZZZZZZ1"] {
+        let data = single_sheet_xlsx(&format!(
+            r#"<row r="1">{}</row>"#,
+            inline_cell(reference, "Boom")
+        ));
+
+        let result = XlsxAdapter
+            .extract_pages(&data, &document("crafted.xlsx", ContentType::Xlsx, &data))
+            .await;
+
+        assert!(
+            matches!(result, Err(FileLoaderError::ExtractionFailed(ref m)) if m.contains("columns")),
+            "{reference}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn given_rows_padded_to_last_column_when_extracting_then_cell_limit_rejects_sheet() {
+    let rows: String = (1..=200)
+        .map(|r| {
+            format!(
+                r#"<row r="{r}">{}</row>"#,
+                inline_cell(&format!("XFD{r}"), "x")
+            )
+        })
+        .collect();
+    let data = single_sheet_xlsx(&rows);
+
+    let result = XlsxAdapter
+        .extract_pages(&data, &document("padded.xlsx", ContentType::Xlsx, &data))
+        .await;
+
+    assert!(matches!(result, Err(FileLoaderError::ExtractionFailed(ref m)) if m.contains("cells")));
+}
+
+#[tokio::test]
+async fn given_pptx_markdown_when_splitting_then_chunks_keep_slide_numbers() {
+    let data = sample_pptx();
+    let pages = PptxAdapter
+        .extract_pages(&data, &document("deck.pptx", ContentType::Pptx, &data))
+        .await
+        .unwrap();
+    let splitter = MarkdownSemanticSplitter::new(512, 50).unwrap();
+
+    let chunks = splitter
+        .split_pages(&pages, DocumentId::new(), None)
+        .await
+        .unwrap();
+
+    let notes = chunks
+        .iter()
+        .find(|c| c.text.contains("hiring freeze"))
+        .unwrap();
+    assert_eq!(notes.page, Some(2));
+    let roadmap = chunks
+        .iter()
+        .find(|c| c.text.contains("Ship search"))
+        .unwrap();
+    assert_eq!(roadmap.page, Some(1));
+    assert_eq!(roadmap.end_page, Some(1));
+}
+
+#[tokio::test]
+async fn given_non_zip_bytes_when_extracting_office_document_then_extraction_fails() {
+    let data = b"%PDF-1.7 not a zip";
+
+    let result = DocxAdapter
+        .extract_pages(data, &document("fake.docx", ContentType::Docx, data))
+        .await;
+
+    assert!(matches!(result, Err(FileLoaderError::ExtractionFailed(_))));
+}
+
+#[tokio::test]
+async fn given_other_content_type_when_extracting_with_office_adapter_then_unsupported() {
+    let data = sample_xlsx(1);
+
+    let result = PptxAdapter
+        .extract_pages(&data, &document("report.xlsx", ContentType::Xlsx, &data))
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(FileLoaderError::UnsupportedContentType(_))
+    ));
+}
@@ -0,0 +1,68 @@
+use sandakan::infrastructure::text_processing::{TextLine, layout_markdown};
+
+#[test]
+fn given_uniform_font_when_laying_out_then_returns_lines_without_headings() {
+    let lines = vec![
+        TextLine::new("First line of body text.", 11.0),
+        TextLine::new("Second line of body text.", 11.0),
+    ];
+
+    let markdown = layout_markdown(&lines);
+
+    assert_eq!(
+        markdown,
+        "First line of body text.\nSecond line of body text."
+    );
+}
+
+#[test]
+fn given_larger_lines_when_laying_out_then_promotes_them_to_headings_by_size() {
+    let lines = vec![
+        TextLine::new("Annual Report", 24.0),
+        TextLine::new("Revenue", 14.0),
+        TextLine::new(
+            "Revenue grew by twelve percent compared to last year.",
+            11.0,
+        ),
+        TextLine::new("Most of the growth came from the northern region.", 11.0),
+    ];
+
+    let markdown = layout_markdown(&lines);
+
+    assert_eq!(
+        markdown,
+        "# Annual Report\n\n## Revenue\n\nRevenue grew by twelve percent compared to last year.\nMost of the growth came from the northern region."
+    );
+}
+
+#[test]
+fn given_title_wrapped_over_two_lines_when_laying_out_then_joins_into_one_heading() {
+    let lines = vec![
+        TextLine::new("A Very Long Title That", 20.0),
+        TextLine::new("Wraps Onto a Second Line", 20.0),
+        TextLine::new("Body text that is long enough to dominate the page.", 10.0),
+    ];
+
+    let markdown = layout_markdown(&lines);
+
+    assert!(markdown.starts_with("# A Very Long Title That Wraps Onto a Second Line\n\n"));
+}
+
+#[test]
+fn given_long_line_in_large_font_when_laying_out_then_keeps_it_as_body_text() {
+    let long_line = "word ".repeat(30);
+    let body = "body text ".repeat(30);
+    let lines = vec![
+        TextLine::new(long_line.trim(), 16.0),
+        TextLine::new(body.trim(), 10.0),
+    ];
+
+    let markdown = layout_markdown(&lines);
+
+    assert!(!markdown.contains('#'));
+}
+
+#[test]
+fn given_no_lines_when_laying_out_then_returns_empty_string() {
+    assert_eq!(layout_markdown(&[]), "");
+}
@@ -1,5 +1,5 @@
 use sandakan::application::ports::{FileLoader, FileLoaderError};
-use sandakan::domain::{ContentType, Document};
+use sandakan::domain::{ContentType, Document, PageSegment};
 use sandakan::infrastructure::text_processing::PlainTextAdapter;
 
 #[tokio::test]
@@ -12,10 +12,13 @@ async fn given_valid_utf8_bytes_when_extracting_then_returns_string() {
         text_bytes.len() as u64,
     );
 
-    let result = adapter.extract_text(text_bytes, &document).await;
+    let result = adapter.extract_pages(text_bytes, &document).await;
 
     assert!(result.is_ok());
-    assert_eq!(result.unwrap(), "Hello, this is plain text.");
+    assert_eq!(
+        result.unwrap(),
+        vec![PageSegment::unpaged("Hello, this is plain text.")]
+    );
 }
 
 #[tokio::test]
@@ -28,7 +31,7 @@ async fn given_invalid_utf8_bytes_when_extracting_then_returns_extraction_failed
         invalid_bytes.len() as u64,
     );
 
-    let result = adapter.extract_text(invalid_bytes, &document).await;
+    let result = adapter.extract_pages(invalid_bytes, &document).await;
 
     assert!(matches!(result, Err(FileLoaderError::ExtractionFailed(_))));
 }
@@ -39,10 +42,28 @@ async fn given_non_text_content_type_when_extracting_then_returns_unsupported()
     let data = b"some data";
     let document = Document::new("file.pdf".to_string(), ContentType::Pdf, data.len() as u64);
 
-    let result = adapter.extract_text(data, &document).await;
+    let result = adapter.extract_pages(data, &document).await;
 
     assert!(matches!(
         result,
         Err(FileLoaderError::UnsupportedContentType(_))
     ));
 }
+
+#[tokio::test]
+async fn given_markdown_document_when_extracting_then_returns_text_unchanged() {
+    let adapter = PlainTextAdapter;
+    let markdown = b"# Title\n\n- item";
+    let document = Document::new(
+        "notes.md".to_string(),
+        ContentType::Markdown,
+        markdown.len() as u64,
+    );
+
+    let result = adapter.extract_pages(markdown, &document).await;
+
+    assert_eq!(
+        result.unwrap(),
+        vec![PageSegment::unpaged("# Title\n\n- item")]
+    );
+}
@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use sandakan::application::ports::SparseEmbedder;
+use sandakan::infrastructure::text_processing::{
+    AnalyzerLanguage, Bm25SparseEmbedder, TextAnalyzer,
+};
+
+fn single_term(analyzer: &TextAnalyzer, word: &str) -> String {
+    let mut terms = analyzer.analyze_document(word);
+    assert_eq!(terms.len(), 1, "expected one term for {word}");
+    terms.remove(0)
+}
+
+#[test]
+fn given_english_inflections_when_stemming_then_they_share_a_term() {
+    let analyzer = TextAnalyzer::new(&[AnalyzerLanguage::English], true);
+
+    let terms: Vec<String> = ["ingestion", "ingest", "ingested"]
+        .iter()
+        .map(|w| single_term(&analyzer, w))
+        .collect();
+
+    assert!(terms.iter().all(|t| *t == terms[0]), "{terms:?}");
+}
+
+#[test]
+fn given_stemming_disabled_when_analyzing_then_words_are_kept_verbatim() {
+    let analyzer = TextAnalyzer::new(&[AnalyzerLanguage::English], false);
+
+    assert_eq!(single_term(&analyzer, "Ingested"), "ingested");
+}
+
+#[test]
+fn given_german_plural_when_stemming_then_matches_singular() {
+    let analyzer = TextAnalyzer::new(&[AnalyzerLanguage::German], true);
+
+    assert_eq!(
+        single_term(&analyzer, "Häuser"),
+        single_term(&analyzer, "Haus")
+    );
+}
+
+#[test]
+fn given_ukrainian_case_forms_when_stemming_then_they_share_a_term() {
+    let analyzer = TextAnalyzer::new(&[AnalyzerLanguage::Ukrainian], true);
+
+    let terms: Vec<String> = ["документ", "документи", "документів", "документом"]
+        .iter()
+        .map(|w| single_term(&analyzer, w))
+        .collect();
+
+    assert!(terms.iter().all(|t| *t == terms[0]), "{terms:?}");
+}
+
+#[test]
+fn given_language_stop_words_when_analyzing_then_they_are_dropped() {
+    let german = TextAnalyzer::new(&[AnalyzerLanguage::German], false);
+    let ukrainian = TextAnalyzer::new(&[AnalyzerLanguage::Ukrainian], false);
+
+    assert_eq!(
+        german.analyze_document("der Vertrag und die Frist"),
+        vec!["vertrag", "frist"]
+    );
+    assert_eq!(
+        ukrainian.analyze_document("це договір та термін"),
+        vec!["договір", "термін"]
+    );
+}
+
+#[test]
+fn given_several_languages_when_analyzing_documents_then_language_is_detected_per_text() {
+    let analyzer = TextAnalyzer::new(
+        &[
+            AnalyzerLanguage::English,
+            AnalyzerLanguage::German,
+            AnalyzerLanguage::Ukrainian,
+        ],
+        true,
+    );
+
+    assert_eq!(
+        analyzer.detect_language(
+            "Die Kündigungsfrist beträgt drei Monate zum Ende eines Kalendervierteljahres."
+        ),
+        AnalyzerLanguage::German
+    );
+    assert_eq!(
+        analyzer
+            .detect_language("Термін повідомлення про розірвання договору становить три місяці."),
+        AnalyzerLanguage::Ukrainian
+    );
+    assert_eq!(
+        analyzer.detect_language("The notice period is three months before the end of a quarter."),
+        AnalyzerLanguage::English
+    );
+}
+
+#[test]
+fn given_undetectable_text_when_analyzing_document_then_first_language_is_used() {
+    let analyzer = TextAnalyzer::new(&[AnalyzerLanguage::German, AnalyzerLanguage::English], true);
+
+    assert_eq!(analyzer.detect_language("E4021"), AnalyzerLanguage::German);
+}
+
+#[test]
+fn given_several_languages_when_analyzing_query_then_terms_from_each_analyzer_are_merged() {
+    let analyzer = TextAnalyzer::new(&[AnalyzerLanguage::English, AnalyzerLanguage::German], true);
+    let english = TextAnalyzer::new(&[AnalyzerLanguage::English], true);
+
+    let terms = analyzer.analyze_query("ingestion ingestion");
+
+    assert!(terms.contains(&single_term(&english, "ingestion")));
+    let unique: HashSet<&String> = terms.iter().collect();
+    assert_eq!(unique.len(), terms.len());
+}
+
+#[tokio::test]
+async fn given_stemming_analyzer_when_embedding_query_and_document_then_inflections_match() {
+    let embedder = Bm25SparseEmbedder::new()
+        .with_analyzer(TextAnalyzer::new(&[AnalyzerLanguage::English], true));
+
+    let query = embedder.embed_sparse_query("ingestion").await.unwrap();
+    let documents = embedder
+        .embed_sparse_batch(&["Files are ingested nightly"])
+        .await
+        .unwrap();
+
+    assert_eq!(query.len(), 1);
+    assert!(documents[0].indices.contains(&query.indices[0]));
+}
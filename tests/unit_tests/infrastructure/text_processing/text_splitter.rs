@@ -1,5 +1,5 @@
 use sandakan::application::ports::TextSplitter;
-use sandakan::domain::DocumentId;
+use sandakan::domain::{DocumentId, PageSegment};
 use sandakan::infrastructure::text_processing::{RecursiveCharacterSplitter, SemanticSplitter};
 
 const SMALL_CHUNK_SIZE: usize = 10;
@@ -293,3 +293,71 @@ async fn given_multi_chunk_text_when_semantic_splitter_splits_then_offsets_point
         );
     }
 }
+
+#[tokio::test]
+async fn given_short_pages_when_semantic_splitter_splits_pages_then_merged_chunk_spans_both_pages()
+{
+    let splitter = SemanticSplitter::new(STANDARD_TOKEN_LIMIT, STANDARD_OVERLAP_TOKENS).unwrap();
+    let pages = vec![
+        PageSegment::new(1, "The sentence ends on the first page."),
+        PageSegment::new(2, "The next one is on the second page."),
+    ];
+
+    let chunks = splitter
+        .split_pages(&pages, DocumentId::new(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.len(), 1);
+    assert_eq!((chunks[0].page, chunks[0].end_page), (Some(1), Some(2)));
+}
+
+#[tokio::test]
+async fn given_many_pages_when_semantic_splitter_splits_pages_then_page_ranges_follow_the_text() {
+    let splitter = SemanticSplitter::new(TIGHT_TOKEN_LIMIT, 0).unwrap();
+    let page_text = |n: u32| {
+        format!(
+            "Page {n} starts with a sentence long enough to matter. \
+             It continues with a second sentence about page {n}. \
+             And closes page {n} with a third."
+        )
+    };
+    let pages: Vec<PageSegment> = (1..=3).map(|n| PageSegment::new(n, page_text(n))).collect();
+
+    let chunks = splitter
+        .split_pages(&pages, DocumentId::new(), None)
+        .await
+        .unwrap();
+
+    assert!(chunks.len() > 1);
+    assert_eq!(chunks.first().unwrap().page, Some(1));
+    assert_eq!(chunks.last().unwrap().end_page, Some(3));
+    for chunk in &chunks {
+        let (first, last) = (chunk.page.unwrap(), chunk.end_page.unwrap());
+        assert!(first <= last);
+        for n in 1..=3 {
+            if chunk.text.contains(&format!("page {n}")) {
+                assert!((first..=last).contains(&n), "chunk {:?}", chunk);
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn given_pages_when_recursive_character_splitter_splits_pages_then_chunks_record_page_range()
+{
+    let splitter = RecursiveCharacterSplitter::new(SMALL_CHUNK_SIZE, 0);
+    let pages = vec![
+        PageSegment::new(1, "äääää"),
+        PageSegment::new(2, "bbbbbbbbbb"),
+    ];
+
+    let chunks = splitter
+        .split_pages(&pages, DocumentId::new(), None)
+        .await
+        .unwrap();
+
+    assert_eq!(chunks[0].text, "äääää\n\nbbb");
+    assert_eq!((chunks[0].page, chunks[0].end_page), (Some(1), Some(2)));
+    assert_eq!((chunks[1].page, chunks[1].end_page), (Some(2), Some(2)));
+}
@@ -1,5 +1,9 @@
 use async_trait::async_trait;
-use sandakan::application::ports::{McpError, RetrievalError, RetrievalServicePort, SourceChunk};
+use sandakan::application::ports::{
+    McpError, RetrievalError, RetrievalServicePort, SearchFilter, SourceChunk,
+};
+use sandakan::application::services::run_as_tenant;
+use sandakan::domain::TenantId;
 use sandakan::infrastructure::mcp::ToolHandler;
 use sandakan::infrastructure::tools::RagSearchAdapter;
 use serde_json::json;
@@ -13,16 +17,43 @@ struct StubPortWithChunks {
 
 #[async_trait]
 impl RetrievalServicePort for StubPortWithChunks {
-    async fn search_chunks(&self, _query: &str) -> Result<Vec<SourceChunk>, RetrievalError> {
+    async fn search_chunks(
+        &self,
+        _query: &str,
+        _filter: &SearchFilter,
+    ) -> Result<Vec<SourceChunk>, RetrievalError> {
         Ok(self.chunks.clone())
     }
 }
 
+#[derive(Default)]
+struct FilterRecordingPort {
+    filters: std::sync::RwLock<Vec<SearchFilter>>,
+}
+
+#[async_trait]
+impl RetrievalServicePort for FilterRecordingPort {
+    async fn search_chunks(
+        &self,
+        _query: &str,
+        filter: &SearchFilter,
+    ) -> Result<Vec<SourceChunk>, RetrievalError> {
+        if let Ok(mut filters) = self.filters.write() {
+            filters.push(filter.clone());
+        }
+        Ok(Vec::new())
+    }
+}
+
 struct StubPortEmpty;
 
 #[async_trait]
 impl RetrievalServicePort for StubPortEmpty {
-    async fn search_chunks(&self, _query: &str) -> Result<Vec<SourceChunk>, RetrievalError> {
+    async fn search_chunks(
+        &self,
+        _query: &str,
+        _filter: &SearchFilter,
+    ) -> Result<Vec<SourceChunk>, RetrievalError> {
         Ok(Vec::new())
     }
 }
@@ -31,7 +62,11 @@ struct StubPortFailing;
 
 #[async_trait]
 impl RetrievalServicePort for StubPortFailing {
-    async fn search_chunks(&self, _query: &str) -> Result<Vec<SourceChunk>, RetrievalError> {
+    async fn search_chunks(
+        &self,
+        _query: &str,
+        _filter: &SearchFilter,
+    ) -> Result<Vec<SourceChunk>, RetrievalError> {
         Err(RetrievalError::Embedding(
             sandakan::application::ports::EmbedderError::ApiRequestFailed(
                 "vector store down".to_string(),
@@ -40,6 +75,14 @@ impl RetrievalServicePort for StubPortFailing {
     }
 }
 
+/// `rag_search` only runs inside a tenant scope, as established by `AgentService::chat`.
+async fn execute_in_default_tenant(
+    adapter: &RagSearchAdapter,
+    arguments: serde_json::Value,
+) -> Result<String, McpError> {
+    run_as_tenant(TenantId::default(), adapter.execute(&arguments)).await
+}
+
 // ─── Tests ───────────────────────────────────────────────────────────────────
 
 #[tokio::test]
@@ -48,25 +91,39 @@ async fn given_chunks_returned_by_port_when_executing_rag_search_then_formats_nu
         SourceChunk {
             text: "Rust is a systems programming language.".to_string(),
             page: Some(1),
+            end_page: Some(1),
             score: 0.95,
             title: None,
             source_url: None,
             content_type: None,
+            author: None,
+            tags: vec![],
+            attributes: Default::default(),
+            links: vec![],
             start_time: None,
+            code: None,
+            rerank_score: None,
         },
         SourceChunk {
             text: "It focuses on safety and performance.".to_string(),
             page: Some(2),
+            end_page: Some(2),
             score: 0.88,
             title: None,
             source_url: None,
             content_type: None,
+            author: None,
+            tags: vec![],
+            attributes: Default::default(),
+            links: vec![],
             start_time: None,
+            code: None,
+            rerank_score: None,
         },
     ];
     let adapter = RagSearchAdapter::new(Arc::new(StubPortWithChunks { chunks }), None);
 
-    let result = adapter.execute(&json!({"query": "what is Rust?"})).await;
+    let result = execute_in_default_tenant(&adapter, json!({"query": "what is Rust?"})).await;
 
     assert!(result.is_ok());
     let output = result.unwrap();
@@ -79,7 +136,7 @@ async fn given_chunks_returned_by_port_when_executing_rag_search_then_formats_nu
 async fn given_empty_knowledge_base_when_executing_rag_search_then_returns_not_found_message() {
     let adapter = RagSearchAdapter::new(Arc::new(StubPortEmpty), None);
 
-    let result = adapter.execute(&json!({"query": "anything"})).await;
+    let result = execute_in_default_tenant(&adapter, json!({"query": "anything"})).await;
 
     assert!(result.is_ok());
     assert_eq!(
@@ -92,7 +149,7 @@ async fn given_empty_knowledge_base_when_executing_rag_search_then_returns_not_f
 async fn given_missing_query_argument_when_executing_rag_search_then_returns_serialization_error() {
     let adapter = RagSearchAdapter::new(Arc::new(StubPortEmpty), None);
 
-    let result = adapter.execute(&json!({})).await;
+    let result = execute_in_default_tenant(&adapter, json!({})).await;
 
     assert!(matches!(result, Err(McpError::Serialization(_))));
     if let Err(McpError::Serialization(msg)) = result {
@@ -104,7 +161,7 @@ async fn given_missing_query_argument_when_executing_rag_search_then_returns_ser
 async fn given_port_failure_when_executing_rag_search_then_returns_execution_failed_error() {
     let adapter = RagSearchAdapter::new(Arc::new(StubPortFailing), None);
 
-    let result = adapter.execute(&json!({"query": "trigger failure"})).await;
+    let result = execute_in_default_tenant(&adapter, json!({"query": "trigger failure"})).await;
 
     assert!(matches!(result, Err(McpError::ExecutionFailed(_))));
 }
@@ -115,15 +172,22 @@ async fn given_chunk_text_exceeds_800_chars_when_formatting_response_then_text_i
     let chunks = vec![SourceChunk {
         text: long_text,
         page: None,
+        end_page: None,
         score: 0.80,
         title: None,
         source_url: None,
         content_type: None,
+        author: None,
+        tags: vec![],
+        attributes: Default::default(),
+        links: vec![],
         start_time: None,
+        code: None,
+        rerank_score: None,
     }];
     let adapter = RagSearchAdapter::new(Arc::new(StubPortWithChunks { chunks }), None);
 
-    let result = adapter.execute(&json!({"query": "long document"})).await;
+    let result = execute_in_default_tenant(&adapter, json!({"query": "long document"})).await;
 
     assert!(result.is_ok());
     let output = result.unwrap();
@@ -145,3 +209,88 @@ async fn given_rag_search_tool_schema_when_inspected_then_has_required_query_par
     let required = schema.parameters["required"].as_array().unwrap();
     assert!(required.iter().any(|v| v.as_str() == Some("query")));
 }
+
+#[tokio::test]
+async fn given_filter_arguments_when_executing_rag_search_then_port_receives_parsed_filter() {
+    let port = Arc::new(FilterRecordingPort::default());
+    let adapter = RagSearchAdapter::new(Arc::clone(&port) as Arc<dyn RetrievalServicePort>, None);
+
+    execute_in_default_tenant(
+        &adapter,
+        json!({
+            "query": "entropy",
+            "content_types": ["application/pdf"],
+            "tags": ["physics"],
+            "titles": null
+        }),
+    )
+    .await
+    .unwrap();
+
+    let filters = port.filters.read().unwrap();
+    assert_eq!(filters.len(), 1);
+    assert_eq!(filters[0].tags, vec!["physics".to_string()]);
+    assert_eq!(filters[0].content_types.len(), 1);
+    assert!(filters[0].titles.is_empty());
+}
+
+#[tokio::test]
+async fn given_invalid_filter_argument_when_executing_rag_search_then_returns_serialization_error()
+{
+    let adapter = RagSearchAdapter::new(Arc::new(StubPortEmpty), None);
+
+    let result = execute_in_default_tenant(
+        &adapter,
+        json!({ "query": "entropy", "document_ids": ["nope"] }),
+    )
+    .await;
+
+    assert!(matches!(result, Err(McpError::Serialization(_))));
+}
+
+#[test]
+fn given_tool_schema_when_inspected_then_exposes_optional_filter_arguments() {
+    let schema = RagSearchAdapter::tool_schema();
+
+    let properties = schema.parameters["properties"].as_object().unwrap();
+    for field in [
+        "document_ids",
+        "content_types",
+        "titles",
+        "tags",
+        "ingested_after",
+        "ingested_before",
+    ] {
+        assert!(properties.contains_key(field), "missing {field}");
+    }
+    assert_eq!(schema.parameters["required"], json!(["query"]));
+}
+
+#[tokio::test]
+async fn given_no_tenant_scope_when_executing_rag_search_then_fails_closed_without_searching() {
+    let port = Arc::new(FilterRecordingPort::default());
+    let adapter = RagSearchAdapter::new(Arc::clone(&port) as Arc<dyn RetrievalServicePort>, None);
+
+    let result = adapter.execute(&json!({ "query": "entropy" })).await;
+
+    assert!(matches!(result, Err(McpError::ExecutionFailed(_))));
+    assert!(port.filters.read().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn given_tenant_scope_when_executing_rag_search_then_port_filter_is_confined_to_that_tenant()
+{
+    let port = Arc::new(FilterRecordingPort::default());
+    let adapter = RagSearchAdapter::new(Arc::clone(&port) as Arc<dyn RetrievalServicePort>, None);
+    let tenant = TenantId::parse("acme").unwrap();
+
+    run_as_tenant(
+        tenant.clone(),
+        adapter.execute(&json!({ "query": "entropy" })),
+    )
+    .await
+    .unwrap();
+
+    let filters = port.filters.read().unwrap();
+    assert_eq!(filters[0].tenant_id, tenant);
+}
@@ -6,6 +6,7 @@ fn make_source(text: &str, page: Option<u32>, score: f32) -> EvalSource {
     EvalSource {
         text: text.to_string(),
         page,
+        end_page: page,
         score,
     }
 }
@@ -0,0 +1,245 @@
+use std::time::Duration;
+
+use axum::Router;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{StatusCode, Uri};
+use axum::response::{IntoResponse, Redirect};
+use axum::routing::get;
+use tokio::net::TcpListener;
+
+use sandakan::application::ports::{WebFetchError, WebFetcher, is_public_ip};
+use std::net::IpAddr;
+
+use sandakan::infrastructure::web::HttpWebFetcher;
+
+const ARTICLE: &str = r#"<!doctype html>
+<html><head>
+  <title>
+    Release   notes
+  </title>
+  <link rel="canonical" href="/articles/release-notes">
+</head><body>
+  <a href="/docs/intro#setup">Intro</a>
+  <a href="guide.html">Guide</a>
+  <a href="https://other.org/page">Elsewhere</a>
+  <a href="mailto:team@example.com">Mail</a>
+  <a href="/docs/intro">Intro again</a>
+  <a href="/login" rel="nofollow">Log in</a>
+</body></html>"#;
+
+const SITEMAP_INDEX: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap><loc>http://example.com/sitemap-1.xml</loc></sitemap>
+  <sitemap><loc> http://example.com/sitemap-2.xml?a=1&amp;b=2 </loc></sitemap>
+</sitemapindex>"#;
+
+const URLSET: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>http://example.com/a</loc><lastmod>2024-01-01</lastmod></url>
+  <url><loc>http://example.com/b</loc></url>
+</urlset>"#;
+
+/// Serves the fixtures on an ephemeral local port and returns its base URL.
+async fn serve() -> String {
+    let app = Router::new()
+        .route(
+            "/articles/current",
+            get(|| async { ([(CONTENT_TYPE, "text/html; charset=utf-8")], ARTICLE) }),
+        )
+        .route(
+            "/latest",
+            get(|| async { Redirect::temporary("/articles/current") }),
+        )
+        .route(
+            "/away",
+            get(|| async { Redirect::temporary("http://example.com/articles/current") }),
+        )
+        .route(
+            "/sitemap.xml",
+            get(|| async { ([(CONTENT_TYPE, "application/xml")], SITEMAP_INDEX) }),
+        )
+        .route(
+            "/sitemap-1.xml",
+            get(|| async { ([(CONTENT_TYPE, "text/xml")], URLSET) }),
+        )
+        .route(
+            "/big.txt",
+            get(|| async { ([(CONTENT_TYPE, "text/plain")], "x".repeat(4096)) }),
+        )
+        .fallback(|uri: Uri| async move {
+            (StatusCode::NOT_FOUND, format!("no route for {uri}")).into_response()
+        });
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{addr}")
+}
+
+/// Fetcher allowed to reach the loopback fixtures.
+fn fetcher() -> HttpWebFetcher {
+    HttpWebFetcher::new("sandakan-test", Duration::from_secs(5), 1024 * 1024)
+        .unwrap()
+        .with_private_networks(true)
+}
+
+#[tokio::test]
+async fn given_html_page_behind_redirect_when_fetching_then_links_title_and_canonical_are_extracted()
+ {
+    let base = serve().await;
+
+    let resource = fetcher()
+        .fetch(&format!("{base}/latest"), &[])
+        .await
+        .unwrap();
+
+    assert_eq!(resource.url, format!("{base}/articles/current"));
+    assert_eq!(resource.content_type.as_deref(), Some("text/html"));
+    assert_eq!(resource.title.as_deref(), Some("Release notes"));
+    assert_eq!(
+        resource.canonical_url,
+        Some(format!("{base}/articles/release-notes"))
+    );
+    assert_eq!(
+        resource.links,
+        vec![
+            format!("{base}/docs/intro"),
+            format!("{base}/articles/guide.html"),
+            "https://other.org/page".to_string(),
+        ]
+    );
+    assert_eq!(resource.body, ARTICLE.as_bytes());
+}
+
+#[tokio::test]
+async fn given_sitemaps_when_fetching_then_loc_entries_are_listed() {
+    let base = serve().await;
+
+    let index = fetcher()
+        .fetch(&format!("{base}/sitemap.xml"), &[])
+        .await
+        .unwrap();
+    let urlset = fetcher()
+        .fetch(&format!("{base}/sitemap-1.xml"), &[])
+        .await
+        .unwrap();
+
+    assert!(index.sitemap_index);
+    assert_eq!(
+        index.links,
+        vec![
+            "http://example.com/sitemap-1.xml",
+            "http://example.com/sitemap-2.xml?a=1&b=2"
+        ]
+    );
+    assert!(!urlset.sitemap_index);
+    assert_eq!(
+        urlset.links,
+        vec!["http://example.com/a", "http://example.com/b"]
+    );
+}
+
+#[tokio::test]
+async fn given_error_status_when_fetching_then_status_is_reported() {
+    let base = serve().await;
+
+    let result = fetcher().fetch(&format!("{base}/robots.txt"), &[]).await;
+
+    assert!(matches!(result, Err(WebFetchError::Status(404))));
+}
+
+#[tokio::test]
+async fn given_response_above_limit_when_fetching_then_too_large() {
+    let base = serve().await;
+    let fetcher = HttpWebFetcher::new("sandakan-test", Duration::from_secs(5), 1024)
+        .unwrap()
+        .with_private_networks(true);
+
+    let result = fetcher.fetch(&format!("{base}/big.txt"), &[]).await;
+
+    assert!(matches!(result, Err(WebFetchError::TooLarge(1024))));
+}
+
+#[tokio::test]
+async fn given_non_http_url_when_fetching_then_invalid_url() {
+    let result = fetcher().fetch("file:///etc/passwd", &[]).await;
+
+    assert!(matches!(result, Err(WebFetchError::InvalidUrl(_))));
+}
+
+#[tokio::test]
+async fn given_loopback_url_when_private_networks_are_not_allowed_then_blocked() {
+    let base = serve().await;
+    let fetcher = HttpWebFetcher::new("sandakan-test", Duration::from_secs(5), 1024).unwrap();
+
+    let by_ip = fetcher
+        .fetch(&format!("{base}/articles/current"), &[])
+        .await;
+    let by_name = fetcher
+        .fetch(&base.replace("127.0.0.1", "localhost"), &[])
+        .await;
+
+    assert!(matches!(by_ip, Err(WebFetchError::Blocked(_))), "{by_ip:?}");
+    assert!(
+        matches!(by_name, Err(WebFetchError::Blocked(_))),
+        "{by_name:?}"
+    );
+}
+
+#[tokio::test]
+async fn given_redirect_outside_allowed_domains_when_fetching_then_blocked() {
+    let base = serve().await;
+
+    let result = fetcher()
+        .fetch(&format!("{base}/away"), &["127.0.0.1".to_string()])
+        .await;
+
+    assert!(
+        matches!(result, Err(WebFetchError::Blocked(_))),
+        "{result:?}"
+    );
+}
+
+#[tokio::test]
+async fn given_redirect_inside_allowed_domains_when_fetching_then_followed() {
+    let base = serve().await;
+
+    let resource = fetcher()
+        .fetch(&format!("{base}/latest"), &["127.0.0.1".to_string()])
+        .await
+        .unwrap();
+
+    assert_eq!(resource.url, format!("{base}/articles/current"));
+}
+
+#[test]
+fn given_addresses_when_checking_public_then_only_global_ones_pass() {
+    let public = ["93.184.215.14", "8.8.8.8", "2606:4700::1111"];
+    let internal = [
+        "127.0.0.1",
+        "10.1.2.3",
+        "172.16.0.1",
+        "192.168.1.1",
+        "169.254.169.254",
+        "100.64.0.1",
+        "0.0.0.0",
+        "198.18.0.1",
+        "224.0.0.1",
+        "255.255.255.255",
+        "::1",
+        "::",
+        "fd00::1",
+        "fe80::1",
+        "::ffff:127.0.0.1",
+        "::ffff:169.254.169.254",
+        "64:ff9b::a00:1",
+        "2001:db8::1",
+    ];
+
+    for ip in public {
+        assert!(is_public_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+    }
+    for ip in internal {
+        assert!(!is_public_ip(ip.parse::<IpAddr>().unwrap()), "{ip}");
+    }
+}
@@ -0,0 +1 @@
+mod http_web_fetcher_test;
@@ -0,0 +1,21 @@
+use sandakan::infrastructure::webhooks::sign_payload;
+
+#[test]
+fn given_secret_and_body_when_signing_then_header_matches_reference_hmac() {
+    let signature = sign_payload("whsec_test", 1_700_000_000, br#"{"id":1}"#);
+
+    assert_eq!(
+        signature,
+        "t=1700000000,v1=2f441ba4b3b2d50d28a9ab9d9fd8880376ecd1eb5d0435401553f5d8d0a5dcf8"
+    );
+}
+
+#[test]
+fn given_different_timestamp_when_signing_then_signature_changes() {
+    let body = br#"{"id":1}"#;
+
+    assert_ne!(
+        sign_payload("whsec_test", 1_700_000_000, body),
+        sign_payload("whsec_test", 1_700_000_001, body)
+    );
+}
@@ -0,0 +1,2 @@
+mod hmac_webhook_sender_test;
+mod webhook_client_test;
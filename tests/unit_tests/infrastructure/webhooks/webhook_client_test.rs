@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use axum::Router;
+use axum::http::StatusCode;
+use axum::response::Redirect;
+use axum::routing::post;
+use tokio::net::TcpListener;
+
+use sandakan::application::ports::WebhookSendError;
+use sandakan::infrastructure::webhooks::WebhookClient;
+
+/// Serves a receiver and a redirecting endpoint on an ephemeral loopback port.
+async fn serve() -> String {
+    let app = Router::new()
+        .route("/hook", post(|| async { StatusCode::NO_CONTENT }))
+        .route(
+            "/moved",
+            post(|| async { Redirect::temporary("http://169.254.169.254/latest/meta-data") }),
+        );
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+    format!("http://{addr}")
+}
+
+fn tenant_client(allow_private_networks: bool) -> WebhookClient {
+    WebhookClient::for_tenant_endpoints(Duration::from_secs(5), allow_private_networks).unwrap()
+}
+
+#[tokio::test]
+async fn given_tenant_client_when_posting_to_loopback_then_request_is_refused() {
+    let base = serve().await;
+    let port = base.rsplit(':').next().unwrap();
+
+    let literal = tenant_client(false)
+        .post_json(&format!("{base}/hook"), b"{}".to_vec(), &[])
+        .await;
+    let named = tenant_client(false)
+        .post_json(
+            &format!("http://localhost:{port}/hook"),
+            b"{}".to_vec(),
+            &[],
+        )
+        .await;
+
+    assert!(matches!(literal, Err(WebhookSendError::RequestFailed(_))));
+    assert!(matches!(named, Err(WebhookSendError::RequestFailed(_))));
+}
+
+#[tokio::test]
+async fn given_redirecting_endpoint_when_posting_then_redirect_is_not_followed() {
+    let base = serve().await;
+    let client = tenant_client(true);
+
+    let delivered = client
+        .post_json(&format!("{base}/hook"), b"{}".to_vec(), &[])
+        .await;
+    let redirected = client
+        .post_json(&format!("{base}/moved"), b"{}".to_vec(), &[])
+        .await;
+
+    assert_eq!(delivered.unwrap(), 204);
+    assert!(matches!(redirected, Err(WebhookSendError::Rejected(307))));
+}